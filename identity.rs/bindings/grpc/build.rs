@@ -1,14 +1,22 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::path::PathBuf;
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-  let proto_files = std::fs::read_dir("./proto")?
+  let out_dir = PathBuf::from(std::env::var("OUT_DIR")?);
+  let proto_files: Vec<_> = std::fs::read_dir("./proto")?
     .filter_map(|entry| entry.ok().map(|e| e.path()))
-    .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("proto"));
+    .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("proto"))
+    .collect();
 
-  for proto in proto_files {
-    tonic_build::compile_protos(proto)?;
-  }
+  // The file descriptor set is served through gRPC server reflection and describes the REST gateway's API.
+  // Messages are (de)serialized from/to JSON by the REST gateway.
+  tonic_build::configure()
+    .file_descriptor_set_path(out_dir.join("identity_descriptor.bin"))
+    .type_attribute(".", "#[derive(serde::Serialize, serde::Deserialize)]")
+    .message_attribute(".", "#[serde(default)]")
+    .compile_protos(&proto_files, &["./proto"])?;
 
   Ok(())
 }
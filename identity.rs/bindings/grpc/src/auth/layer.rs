@@ -0,0 +1,90 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tonic::codegen::BoxFuture;
+use tonic::codegen::Service;
+use tonic::Status;
+use tower::Layer;
+
+use super::AuthConfig;
+use super::AuthEnabled;
+
+/// Authenticates and authorizes every request according to an [`AuthConfig`].
+///
+/// Authenticated requests carry the client's [`Principal`](super::Principal) and its tenant's
+/// [`TenantStronghold`](super::TenantStronghold) as extensions. If no [`AuthConfig`] is set, requests are passed
+/// through.
+#[derive(Debug, Clone, Default)]
+pub struct AuthLayer {
+  config: Option<Arc<AuthConfig>>,
+}
+
+impl AuthLayer {
+  pub fn new(config: Option<AuthConfig>) -> Self {
+    Self {
+      config: config.map(Arc::new),
+    }
+  }
+}
+
+impl<S> Layer<S> for AuthLayer {
+  type Service = AuthMiddleware<S>;
+
+  fn layer(&self, inner: S) -> Self::Service {
+    AuthMiddleware {
+      inner,
+      config: self.config.clone(),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthMiddleware<S> {
+  inner: S,
+  config: Option<Arc<AuthConfig>>,
+}
+
+impl<S, B> Service<http::Request<B>> for AuthMiddleware<S>
+where
+  S: Service<http::Request<B>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+  S::Future: Send + 'static,
+  B: Send + 'static,
+{
+  type Response = S::Response;
+  type Error = S::Error;
+  type Future = BoxFuture<Self::Response, Self::Error>;
+
+  fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+    self.inner.poll_ready(cx)
+  }
+
+  fn call(&mut self, mut req: http::Request<B>) -> Self::Future {
+    if let Some(config) = self.config.as_deref() {
+      if let Err(status) = authorize(config, &mut req) {
+        return Box::pin(async move { Ok(status.into_http()) });
+      }
+    }
+
+    // The service that has been driven to readiness must be the one called.
+    let clone = self.inner.clone();
+    let mut inner = std::mem::replace(&mut self.inner, clone);
+    Box::pin(inner.call(req))
+  }
+}
+
+fn authorize<B>(config: &AuthConfig, req: &mut http::Request<B>) -> Result<(), Status> {
+  let path = req.uri().path().to_owned();
+  req.extensions_mut().insert(AuthEnabled);
+  if let Some((stronghold, principal)) = config.authorize(&path, req.headers(), req.extensions())? {
+    req.extensions_mut().insert(stronghold);
+    req.extensions_mut().insert(principal);
+  }
+
+  Ok(())
+}
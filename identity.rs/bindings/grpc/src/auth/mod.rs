@@ -0,0 +1,229 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Authentication, authorization and multi-tenancy.
+//!
+//! Clients authenticate either with a bearer token (`authorization: Bearer <token>`) or with a TLS client
+//! certificate. Each [`Principal`] belongs to a tenant, whose keys are kept in a dedicated Stronghold snapshot:
+//! services sign with the Stronghold of the tenant a request has been authenticated for, so that a tenant
+//! can never use another tenant's keys.
+
+mod layer;
+mod policy;
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use identity_iota::storage::Storage;
+use identity_stronghold::StrongholdStorage;
+use serde::Deserialize;
+use sha2::Digest;
+use sha2::Sha256;
+use tonic::codegen::http::header::AUTHORIZATION;
+use tonic::codegen::http::Extensions;
+use tonic::codegen::http::HeaderMap;
+use tonic::transport::server::TcpConnectInfo;
+use tonic::transport::server::TlsConnectInfo;
+use tonic::Request;
+use tonic::Status;
+
+pub use layer::AuthLayer;
+pub use layer::AuthMiddleware;
+pub use policy::Policy;
+pub use policy::PolicyRule;
+
+/// `tracing` target of the audit log events.
+pub const AUDIT_TARGET: &str = "audit";
+
+/// An authenticated client.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Principal {
+  /// The tenant the client belongs to.
+  pub tenant: String,
+  /// Name of the client, used in the audit log.
+  pub subject: String,
+  /// Roles granted to the client, checked against the [`Policy`].
+  #[serde(default)]
+  pub roles: BTreeSet<String>,
+}
+
+impl Principal {
+  pub fn new(tenant: impl Into<String>, subject: impl Into<String>) -> Self {
+    Self {
+      tenant: tenant.into(),
+      subject: subject.into(),
+      roles: BTreeSet::new(),
+    }
+  }
+
+  pub fn with_role(mut self, role: impl Into<String>) -> Self {
+    self.roles.insert(role.into());
+    self
+  }
+}
+
+/// The Stronghold holding the keys of the tenant a request has been authenticated for.
+#[derive(Debug, Clone)]
+pub struct TenantStronghold(pub StrongholdStorage);
+
+/// Marks requests received while authentication is enabled, including calls to public RPCs.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AuthEnabled;
+
+/// Tenants, client credentials and authorization policy of the server.
+#[derive(Debug, Clone, Default)]
+pub struct AuthConfig {
+  tenants: HashMap<String, StrongholdStorage>,
+  // Indexed by the hex encoded SHA-256 digest of the token.
+  bearer_tokens: HashMap<String, Principal>,
+  // Indexed by the hex encoded SHA-256 fingerprint of the DER encoded certificate.
+  client_certificates: HashMap<String, Principal>,
+  policy: Policy,
+}
+
+impl AuthConfig {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds a tenant whose keys are stored in `stronghold`.
+  pub fn tenant(mut self, id: impl Into<String>, stronghold: StrongholdStorage) -> Self {
+    self.tenants.insert(id.into(), stronghold);
+    self
+  }
+
+  /// Authenticates requests bearing `token` as `principal`.
+  pub fn bearer_token(self, token: &str, principal: Principal) -> Self {
+    self.bearer_token_hash(sha256_hex(token.as_bytes()), principal)
+  }
+
+  /// Same as [`AuthConfig::bearer_token`], from the hex encoded SHA-256 digest of the token.
+  /// Allows to keep tokens out of configuration files.
+  pub fn bearer_token_hash(mut self, token_sha256: impl Into<String>, principal: Principal) -> Self {
+    self
+      .bearer_tokens
+      .insert(token_sha256.into().to_ascii_lowercase(), principal);
+    self
+  }
+
+  /// Authenticates clients presenting the TLS certificate with the given hex encoded SHA-256 fingerprint as
+  /// `principal`.
+  pub fn client_certificate(mut self, fingerprint_sha256: impl Into<String>, principal: Principal) -> Self {
+    self
+      .client_certificates
+      .insert(fingerprint_sha256.into().to_ascii_lowercase(), principal);
+    self
+  }
+
+  pub fn policy(mut self, policy: Policy) -> Self {
+    self.policy = policy;
+    self
+  }
+
+  /// Returns the Stronghold of every tenant.
+  pub(crate) fn tenant_strongholds(&self) -> impl Iterator<Item = &StrongholdStorage> {
+    self.tenants.values()
+  }
+
+  /// Authenticates and authorizes a call to the RPC at `path`, returning the client and its tenant's Stronghold.
+  /// Returns `None` for public RPCs.
+  pub(crate) fn authorize(
+    &self,
+    path: &str,
+    headers: &HeaderMap,
+    extensions: &Extensions,
+  ) -> Result<Option<(TenantStronghold, Principal)>, Status> {
+    if self.policy.is_public(path) {
+      return Ok(None);
+    }
+
+    let principal = self.authenticate(headers, extensions)?.clone();
+    if !self.policy.is_allowed(&principal, path) {
+      tracing::warn!(
+        tenant = %principal.tenant,
+        subject = %principal.subject,
+        rpc = %path,
+        "unauthorized call"
+      );
+      return Err(Status::permission_denied(format!("not allowed to call {path}")));
+    }
+    let stronghold = self
+      .tenants
+      .get(&principal.tenant)
+      .cloned()
+      .ok_or_else(|| Status::permission_denied(format!("unknown tenant \"{}\"", principal.tenant)))?;
+
+    Ok(Some((TenantStronghold(stronghold), principal)))
+  }
+
+  fn authenticate(&self, headers: &HeaderMap, extensions: &Extensions) -> Result<&Principal, Status> {
+    if let Some(value) = headers.get(AUTHORIZATION) {
+      let token = value
+        .to_str()
+        .ok()
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| Status::unauthenticated("malformed authorization header"))?;
+      return self
+        .bearer_tokens
+        .get(&sha256_hex(token.as_bytes()))
+        .ok_or_else(|| Status::unauthenticated("invalid bearer token"));
+    }
+
+    let peer_certs = extensions
+      .get::<TlsConnectInfo<TcpConnectInfo>>()
+      .and_then(TlsConnectInfo::peer_certs);
+    if let Some(certificate) = peer_certs.as_ref().and_then(|certs| certs.first()) {
+      return self
+        .client_certificates
+        .get(&sha256_hex(certificate.as_ref()))
+        .ok_or_else(|| Status::unauthenticated("unknown client certificate"));
+    }
+
+    Err(Status::unauthenticated("missing credentials"))
+  }
+}
+
+/// Returns the hex encoded SHA-256 digest of `bytes`.
+pub fn sha256_hex(bytes: &[u8]) -> String {
+  Sha256::digest(bytes).iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Returns the Stronghold of the tenant `req` has been authenticated for, or `default` if authentication is
+/// disabled.
+///
+/// Fails if authentication is enabled but `req` has not been authenticated for any tenant, e.g. when it is a call
+/// to a public RPC: the shared `default` Stronghold is never used while authentication is enabled.
+pub fn stronghold<T>(req: &Request<T>, default: &StrongholdStorage) -> Result<StrongholdStorage, Status> {
+  let extensions = req.extensions();
+  match extensions.get::<TenantStronghold>() {
+    Some(tenant) => Ok(tenant.0.clone()),
+    None if extensions.get::<AuthEnabled>().is_some() => {
+      Err(Status::permission_denied("no tenant storage for this request"))
+    }
+    None => Ok(default.clone()),
+  }
+}
+
+/// Same as [`stronghold`], wrapped in a [`Storage`].
+pub fn storage<T>(
+  req: &Request<T>,
+  default: &StrongholdStorage,
+) -> Result<Storage<StrongholdStorage, StrongholdStorage>, Status> {
+  let stronghold = stronghold(req, default)?;
+  Ok(Storage::new(stronghold.clone(), stronghold))
+}
+
+/// Records a signing operation performed with `key` on behalf of the client of `req` in the audit log.
+pub fn audit_signing<T>(req: &Request<T>, operation: &str, key: &str) {
+  match req.extensions().get::<Principal>() {
+    Some(principal) => tracing::info!(
+      target: AUDIT_TARGET,
+      tenant = %principal.tenant,
+      subject = %principal.subject,
+      operation,
+      key,
+      "signing operation"
+    ),
+    None => tracing::info!(target: AUDIT_TARGET, operation, key, "signing operation"),
+  }
+}
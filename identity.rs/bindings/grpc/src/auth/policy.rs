@@ -0,0 +1,100 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+
+use super::Principal;
+
+/// RPCs that can be called without credentials by default.
+const DEFAULT_PUBLIC_RPCS: &[&str] = &["/health_check.HealthCheck/*"];
+
+/// Per-RPC authorization policy.
+///
+/// RPCs are identified by their path, e.g. `/utils.Signing/sign`. A pattern ending with `*` matches every path
+/// starting with the preceding prefix, e.g. `/utils.Signing/*`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Policy {
+  /// RPCs that can be called without credentials.
+  #[serde(default = "default_public_rpcs")]
+  pub public: Vec<String>,
+  /// Roles required to call RPCs. The first matching rule applies; RPCs without a rule are allowed to any
+  /// authenticated client, unless `deny_unlisted` is set.
+  #[serde(default)]
+  pub rules: Vec<PolicyRule>,
+  /// Denies the RPCs no rule matches to every client.
+  #[serde(default)]
+  pub deny_unlisted: bool,
+}
+
+/// Requires one of `roles` to call the RPCs matching `rpc`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PolicyRule {
+  pub rpc: String,
+  pub roles: Vec<String>,
+}
+
+impl Default for Policy {
+  fn default() -> Self {
+    Self {
+      public: default_public_rpcs(),
+      rules: Vec::new(),
+      deny_unlisted: false,
+    }
+  }
+}
+
+impl Policy {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Allows the RPCs matching `rpc` to be called without credentials.
+  pub fn allow_unauthenticated(mut self, rpc: impl Into<String>) -> Self {
+    self.public.push(rpc.into());
+    self
+  }
+
+  /// Requires one of `roles` to call the RPCs matching `rpc`.
+  pub fn require_roles<R>(mut self, rpc: impl Into<String>, roles: impl IntoIterator<Item = R>) -> Self
+  where
+    R: Into<String>,
+  {
+    self.rules.push(PolicyRule {
+      rpc: rpc.into(),
+      roles: roles.into_iter().map(Into::into).collect(),
+    });
+    self
+  }
+
+  /// Denies the RPCs no rule matches, instead of allowing them to any authenticated client.
+  pub fn deny_unlisted(mut self) -> Self {
+    self.deny_unlisted = true;
+    self
+  }
+
+  /// Returns `true` if the RPC at `path` can be called without credentials.
+  pub fn is_public(&self, path: &str) -> bool {
+    self.public.iter().any(|pattern| matches(pattern, path))
+  }
+
+  /// Returns `true` if `principal` is allowed to call the RPC at `path`.
+  pub fn is_allowed(&self, principal: &Principal, path: &str) -> bool {
+    self
+      .rules
+      .iter()
+      .find(|rule| matches(&rule.rpc, path))
+      .map(|rule| rule.roles.iter().any(|role| principal.roles.contains(role)))
+      .unwrap_or(!self.deny_unlisted)
+  }
+}
+
+fn matches(pattern: &str, path: &str) -> bool {
+  match pattern.strip_suffix('*') {
+    Some(prefix) => path.starts_with(prefix),
+    None => pattern == path,
+  }
+}
+
+fn default_public_rpcs() -> Vec<String> {
+  DEFAULT_PUBLIC_RPCS.iter().map(|rpc| rpc.to_string()).collect()
+}
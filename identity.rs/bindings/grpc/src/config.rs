@@ -0,0 +1,344 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Server configuration.
+//!
+//! The configuration is read from a TOML file, e.g.:
+//! ```toml
+//! listen_address = "0.0.0.0:50051"
+//! reflection = true
+//! auth_config_path = "/etc/identity-grpc/auth.json"
+//!
+//! [network]
+//! api_endpoint = "http://127.0.0.1:9000"
+//! identity_pkg_id = "0x..."
+//!
+//! [storage]
+//! backend = "stronghold"
+//! snapshot_path = "/stronghold/server.snapshot"
+//! password_file = "/run/secrets/stronghold_pwd"
+//!
+//! [tls]
+//! cert_path = "/etc/identity-grpc/server.pem"
+//! key_path = "/etc/identity-grpc/server.key"
+//! client_ca_path = "/etc/identity-grpc/clients_ca.pem"
+//!
+//! [metrics]
+//! listen_address = "0.0.0.0:9184"
+//!
+//! [gateway]
+//! listen_address = "0.0.0.0:8080"
+//! ```
+//! or, for backward compatibility, from the environment variables documented in the README.
+
+use std::env;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use identity_stronghold::StrongholdStorage;
+use iota_sdk_legacy::client::stronghold::StrongholdAdapter;
+use serde::Deserialize;
+use tonic::transport::Certificate;
+use tonic::transport::Identity;
+use tonic::transport::ServerTlsConfig;
+
+use crate::auth::AuthConfig;
+use crate::auth::Policy;
+use crate::auth::Principal;
+
+const DEFAULT_LISTEN_ADDRESS: &str = "0.0.0.0:50051";
+const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 30;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+  #[error("failed to read {}: {source}", path.display())]
+  Io { path: PathBuf, source: std::io::Error },
+  #[error("invalid configuration file: {0}")]
+  InvalidFile(#[from] toml::de::Error),
+  #[error("invalid authentication configuration: {0}")]
+  InvalidAuthConfig(#[from] serde_json::Error),
+  #[error("missing configuration: {0}")]
+  Missing(String),
+  #[error("invalid value for {name}: {message}")]
+  InvalidValue { name: &'static str, message: String },
+  #[error("failed to open stronghold snapshot {}: {message}", path.display())]
+  Stronghold { path: PathBuf, message: String },
+}
+
+/// Configuration of the `identity-grpc` binary.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+  #[serde(default = "default_listen_address")]
+  pub listen_address: SocketAddr,
+  pub network: NetworkConfig,
+  pub storage: StorageConfig,
+  #[serde(default)]
+  pub tls: Option<TlsConfig>,
+  /// JSON file with tenants, client credentials and the authorization policy. Authentication is disabled if unset.
+  #[serde(default)]
+  pub auth_config_path: Option<PathBuf>,
+  /// Enables gRPC server reflection.
+  #[serde(default = "default_reflection")]
+  pub reflection: bool,
+  /// Exposes Prometheus metrics over HTTP if set.
+  #[serde(default)]
+  pub metrics: Option<MetricsConfig>,
+  /// Serves the REST gateway if set.
+  #[serde(default)]
+  pub gateway: Option<GatewayConfig>,
+  /// Time given to in-flight requests to complete once a shutdown signal has been received.
+  #[serde(default = "default_shutdown_timeout_secs")]
+  pub shutdown_timeout_secs: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkConfig {
+  /// IOTA node address.
+  pub api_endpoint: String,
+  /// Package ID of `iota_identity`. Only required for networks whose package ID isn't known to the client.
+  #[serde(default)]
+  pub identity_pkg_id: Option<String>,
+}
+
+/// Where the server's keys are kept.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "backend", rename_all = "snake_case", deny_unknown_fields)]
+pub enum StorageConfig {
+  Stronghold {
+    snapshot_path: PathBuf,
+    /// File containing the snapshot's password. Falls back to the `STRONGHOLD_PWD` environment variable.
+    #[serde(default)]
+    password_file: Option<PathBuf>,
+  },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+  /// PEM encoded server certificate.
+  pub cert_path: PathBuf,
+  /// PEM encoded server private key.
+  pub key_path: PathBuf,
+  /// PEM encoded CA certificate verifying client certificates. Enables mutual TLS if set.
+  #[serde(default)]
+  pub client_ca_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetricsConfig {
+  pub listen_address: SocketAddr,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GatewayConfig {
+  pub listen_address: SocketAddr,
+}
+
+impl Config {
+  /// Reads the configuration from the TOML file at `path`.
+  pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+    let content = read_to_string(path.as_ref())?;
+    Ok(toml::from_str(&content)?)
+  }
+
+  /// Reads the configuration from the environment variables `API_ENDPOINT`, `IDENTITY_IOTA_PKG_ID`,
+  /// `SNAPSHOT_PATH`, `STRONGHOLD_PWD_FILE`, `LISTEN_ADDRESS`, `TLS_CERT_PATH`, `TLS_KEY_PATH`,
+  /// `TLS_CLIENT_CA_PATH`, `AUTH_CONFIG_PATH`, `METRICS_LISTEN_ADDRESS` and `GATEWAY_LISTEN_ADDRESS`.
+  pub fn from_env() -> Result<Self, ConfigError> {
+    let listen_address = match env::var("LISTEN_ADDRESS") {
+      Ok(address) => parse_address("LISTEN_ADDRESS", &address)?,
+      Err(_) => default_listen_address(),
+    };
+    let network = NetworkConfig {
+      api_endpoint: required_var("API_ENDPOINT")?,
+      identity_pkg_id: env::var("IDENTITY_IOTA_PKG_ID").ok(),
+    };
+    let storage = StorageConfig::Stronghold {
+      snapshot_path: required_var("SNAPSHOT_PATH")?.into(),
+      password_file: env::var("STRONGHOLD_PWD_FILE").ok().map(PathBuf::from),
+    };
+    let tls = match (env::var("TLS_CERT_PATH"), env::var("TLS_KEY_PATH")) {
+      (Ok(cert_path), Ok(key_path)) => Some(TlsConfig {
+        cert_path: cert_path.into(),
+        key_path: key_path.into(),
+        client_ca_path: env::var("TLS_CLIENT_CA_PATH").ok().map(PathBuf::from),
+      }),
+      _ => None,
+    };
+    let metrics = env::var("METRICS_LISTEN_ADDRESS")
+      .ok()
+      .map(|address| parse_address("METRICS_LISTEN_ADDRESS", &address))
+      .transpose()?
+      .map(|listen_address| MetricsConfig { listen_address });
+    let gateway = env::var("GATEWAY_LISTEN_ADDRESS")
+      .ok()
+      .map(|address| parse_address("GATEWAY_LISTEN_ADDRESS", &address))
+      .transpose()?
+      .map(|listen_address| GatewayConfig { listen_address });
+
+    Ok(Self {
+      listen_address,
+      network,
+      storage,
+      tls,
+      auth_config_path: env::var("AUTH_CONFIG_PATH").ok().map(PathBuf::from),
+      reflection: default_reflection(),
+      metrics,
+      gateway,
+      shutdown_timeout_secs: DEFAULT_SHUTDOWN_TIMEOUT_SECS,
+    })
+  }
+
+  pub fn shutdown_timeout(&self) -> Duration {
+    Duration::from_secs(self.shutdown_timeout_secs)
+  }
+
+  /// Opens the server's key storage.
+  pub fn storage(&self) -> Result<StrongholdStorage, ConfigError> {
+    match &self.storage {
+      StorageConfig::Stronghold {
+        snapshot_path,
+        password_file,
+      } => {
+        let password = match password_file {
+          Some(path) => read_to_string(path).map(sanitize_pwd)?,
+          None => env::var("STRONGHOLD_PWD")
+            .map_err(|_| ConfigError::Missing("no password for stronghold was provided".to_owned()))?,
+        };
+        if !snapshot_path.is_file() {
+          return Err(ConfigError::Missing(format!(
+            "no snapshot at provided path \"{}\"",
+            snapshot_path.display()
+          )));
+        }
+        open_stronghold(snapshot_path, password)
+      }
+    }
+  }
+
+  /// Loads the TLS certificates, if TLS is enabled.
+  pub fn tls(&self) -> Result<Option<ServerTlsConfig>, ConfigError> {
+    let Some(tls) = &self.tls else {
+      return Ok(None);
+    };
+    let cert = read_to_string(&tls.cert_path)?;
+    let key = read_to_string(&tls.key_path)?;
+    let mut config = ServerTlsConfig::new().identity(Identity::from_pem(cert, key));
+
+    if let Some(ca_path) = &tls.client_ca_path {
+      let ca = read_to_string(ca_path)?;
+      // Clients may still authenticate with a bearer token instead of a certificate.
+      config = config
+        .client_ca_root(Certificate::from_pem(ca))
+        .client_auth_optional(true);
+    }
+
+    Ok(Some(config))
+  }
+
+  /// Loads the authentication configuration and opens the tenants' storages, if authentication is enabled.
+  pub fn auth(&self) -> Result<Option<AuthConfig>, ConfigError> {
+    let Some(path) = &self.auth_config_path else {
+      return Ok(None);
+    };
+    let config: AuthConfigFile = serde_json::from_str(&read_to_string(path)?)?;
+
+    let mut auth = AuthConfig::new().policy(config.policy);
+    for tenant in config.tenants {
+      let password = read_to_string(&tenant.password_file).map(sanitize_pwd)?;
+      auth = auth.tenant(tenant.id, open_stronghold(&tenant.snapshot_path, password)?);
+    }
+    for token in config.bearer_tokens {
+      auth = auth.bearer_token_hash(token.sha256, token.principal);
+    }
+    for certificate in config.client_certificates {
+      auth = auth.client_certificate(certificate.sha256, certificate.principal);
+    }
+
+    Ok(Some(auth))
+  }
+}
+
+/// Content of the file at [`Config::auth_config_path`].
+#[derive(Debug, Deserialize)]
+struct AuthConfigFile {
+  tenants: Vec<TenantConfig>,
+  #[serde(default)]
+  bearer_tokens: Vec<CredentialConfig>,
+  #[serde(default)]
+  client_certificates: Vec<CredentialConfig>,
+  #[serde(default)]
+  policy: Policy,
+}
+
+#[derive(Debug, Deserialize)]
+struct TenantConfig {
+  id: String,
+  snapshot_path: PathBuf,
+  password_file: PathBuf,
+}
+
+/// A credential, identified by its hex encoded SHA-256 digest, and the principal it authenticates.
+#[derive(Debug, Deserialize)]
+struct CredentialConfig {
+  sha256: String,
+  principal: Principal,
+}
+
+fn open_stronghold(snapshot_path: &Path, password: String) -> Result<StrongholdStorage, ConfigError> {
+  StrongholdAdapter::builder()
+    .password(password)
+    .build(snapshot_path)
+    .map(StrongholdStorage::new)
+    .map_err(|e| ConfigError::Stronghold {
+      path: snapshot_path.to_owned(),
+      message: e.to_string(),
+    })
+}
+
+fn read_to_string(path: &Path) -> Result<String, ConfigError> {
+  fs::read_to_string(path).map_err(|source| ConfigError::Io {
+    path: path.to_owned(),
+    source,
+  })
+}
+
+fn required_var(name: &str) -> Result<String, ConfigError> {
+  env::var(name).map_err(|_| ConfigError::Missing(format!("unset \"{name}\" env variable")))
+}
+
+fn parse_address(name: &'static str, address: &str) -> Result<SocketAddr, ConfigError> {
+  address
+    .parse()
+    .map_err(|e: std::net::AddrParseError| ConfigError::InvalidValue {
+      name,
+      message: e.to_string(),
+    })
+}
+
+/// Remove any trailing whitespace in-place.
+fn sanitize_pwd(mut pwd: String) -> String {
+  let trimmed = pwd.trim_end();
+  pwd.truncate(trimmed.len());
+  pwd.shrink_to_fit();
+  pwd
+}
+
+fn default_listen_address() -> SocketAddr {
+  DEFAULT_LISTEN_ADDRESS.parse().expect("valid socket address")
+}
+
+fn default_reflection() -> bool {
+  true
+}
+
+fn default_shutdown_timeout_secs() -> u64 {
+  DEFAULT_SHUTDOWN_TIMEOUT_SECS
+}
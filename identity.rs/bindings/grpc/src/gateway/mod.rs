@@ -0,0 +1,400 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! HTTP+JSON gateway to the gRPC services.
+//!
+//! Every route calls the implementation of an RPC, with the RPC's request message as JSON body (or, for `GET`
+//! routes, as path and query parameters) and its response message as JSON response. Messages use the field names
+//! of their proto definition. The gateway enforces the same [`AuthConfig`] as the gRPC server, with the RPC's path
+//! being checked against the policy. Its OpenAPI description is served at [`OPENAPI_PATH`].
+
+mod openapi;
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::extract::Path;
+use axum::extract::Query;
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::routing::post;
+use axum::Json;
+use axum::Router;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_stronghold::StrongholdStorage;
+use serde::Serialize;
+use tonic::codegen::http::Extensions;
+use tonic::Code;
+use tonic::Status;
+
+use crate::auth::AuthConfig;
+use crate::auth::AuthEnabled;
+use crate::services::credential::jwt::_credentials::jwt_server::Jwt as _;
+use crate::services::credential::jwt::_credentials::JwtCreationRequest;
+use crate::services::credential::jwt::_credentials::JwtCreationResponse;
+use crate::services::credential::jwt::JwtService;
+use crate::services::credential::validation::_credentials::vc_validation_server::VcValidation as _;
+use crate::services::credential::validation::_credentials::VcValidationRequest;
+use crate::services::credential::validation::_credentials::VcValidationResponse;
+use crate::services::credential::validation::VcValidator;
+use crate::services::document::_document::document_service_server::DocumentService as _;
+use crate::services::document::_document::CreateDidRequest;
+use crate::services::document::_document::CreateDidResponse;
+use crate::services::document::_document::ResolveDidRequest;
+use crate::services::document::_document::ResolveDidResponse;
+use crate::services::document::DocumentSvc;
+use crate::services::domain_linkage::_domain_linkage::domain_linkage_server::DomainLinkage as _;
+use crate::services::domain_linkage::_domain_linkage::ValidateDidAgainstDidConfigurationsRequest;
+use crate::services::domain_linkage::_domain_linkage::ValidateDidRequest;
+use crate::services::domain_linkage::_domain_linkage::ValidateDidResponse;
+use crate::services::domain_linkage::_domain_linkage::ValidateDomainAgainstDidConfigurationRequest;
+use crate::services::domain_linkage::_domain_linkage::ValidateDomainRequest;
+use crate::services::domain_linkage::_domain_linkage::ValidateDomainResponse;
+use crate::services::domain_linkage::DomainLinkageService;
+use crate::services::status_list_2021::_status_list_2021::status_list2021_svc_server::StatusList2021Svc as _;
+use crate::services::status_list_2021::_status_list_2021::CreateRequest as StatusListCreateRequest;
+use crate::services::status_list_2021::_status_list_2021::StatusListCredential;
+use crate::services::status_list_2021::_status_list_2021::UpdateRequest as StatusListUpdateRequest;
+use crate::services::status_list_2021::StatusList2021Service;
+
+/// Path of the gateway's OpenAPI description.
+pub const OPENAPI_PATH: &str = "/v1/openapi.json";
+
+/// An HTTP route and the RPC it is mapped to.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Route {
+  /// Lowercase HTTP method.
+  pub(crate) method: &'static str,
+  /// Path, with parameters written as `:name`.
+  pub(crate) path: &'static str,
+  /// Path of the RPC, e.g. `/document.DocumentService/create`.
+  pub(crate) rpc: &'static str,
+}
+
+const CREATE_DID: Route = Route {
+  method: "post",
+  path: "/v1/dids",
+  rpc: "/document.DocumentService/create",
+};
+const RESOLVE_DID: Route = Route {
+  method: "get",
+  path: "/v1/dids/:did",
+  rpc: "/document.DocumentService/resolve",
+};
+const CREATE_JWT_CREDENTIAL: Route = Route {
+  method: "post",
+  path: "/v1/credentials/jwt",
+  rpc: "/credentials.Jwt/create",
+};
+const VALIDATE_JWT_CREDENTIAL: Route = Route {
+  method: "post",
+  path: "/v1/credentials/jwt/validate",
+  rpc: "/credentials.VcValidation/validate",
+};
+const CREATE_STATUS_LIST: Route = Route {
+  method: "post",
+  path: "/v1/status-lists",
+  rpc: "/status_list_2021.StatusList2021Svc/create",
+};
+const UPDATE_STATUS_LIST: Route = Route {
+  method: "post",
+  path: "/v1/status-lists/update",
+  rpc: "/status_list_2021.StatusList2021Svc/update",
+};
+const VALIDATE_DOMAIN: Route = Route {
+  method: "post",
+  path: "/v1/domain-linkage/domains/validate",
+  rpc: "/domain_linkage.DomainLinkage/validate_domain",
+};
+const VALIDATE_DOMAIN_AGAINST_DID_CONFIGURATION: Route = Route {
+  method: "post",
+  path: "/v1/domain-linkage/domains/validate-against-did-configuration",
+  rpc: "/domain_linkage.DomainLinkage/validate_domain_against_did_configuration",
+};
+const VALIDATE_DID: Route = Route {
+  method: "post",
+  path: "/v1/domain-linkage/dids/validate",
+  rpc: "/domain_linkage.DomainLinkage/validate_did",
+};
+const VALIDATE_DID_AGAINST_DID_CONFIGURATIONS: Route = Route {
+  method: "post",
+  path: "/v1/domain-linkage/dids/validate-against-did-configurations",
+  rpc: "/domain_linkage.DomainLinkage/validate_did_against_did_configurations",
+};
+
+/// Every route of the gateway, except [`OPENAPI_PATH`].
+pub(crate) const ROUTES: &[Route] = &[
+  CREATE_DID,
+  RESOLVE_DID,
+  CREATE_JWT_CREDENTIAL,
+  VALIDATE_JWT_CREDENTIAL,
+  CREATE_STATUS_LIST,
+  UPDATE_STATUS_LIST,
+  VALIDATE_DOMAIN,
+  VALIDATE_DOMAIN_AGAINST_DID_CONFIGURATION,
+  VALIDATE_DID,
+  VALIDATE_DID_AGAINST_DID_CONFIGURATIONS,
+];
+
+struct GatewayState {
+  auth: Option<AuthConfig>,
+  document: DocumentSvc,
+  jwt: JwtService,
+  vc_validation: VcValidator,
+  status_list: StatusList2021Service,
+  domain_linkage: DomainLinkageService,
+  openapi: serde_json::Value,
+}
+
+/// HTTP server exposing the gRPC services as JSON endpoints.
+pub struct Gateway {
+  router: Router,
+}
+
+impl Gateway {
+  /// Creates a gateway signing with `stronghold`, or with the caller's tenant Stronghold if `auth` is set.
+  pub fn new(client: &IdentityClientReadOnly, stronghold: &StrongholdStorage, auth: Option<AuthConfig>) -> Self {
+    let state = Arc::new(GatewayState {
+      auth,
+      document: DocumentSvc::new(client, stronghold),
+      jwt: JwtService::new(client, stronghold),
+      vc_validation: VcValidator::new(client),
+      status_list: StatusList2021Service,
+      domain_linkage: DomainLinkageService::new(client),
+      openapi: openapi::generate(ROUTES),
+    });
+    let router = Router::new()
+      .route(OPENAPI_PATH, get(openapi_description))
+      .route(CREATE_DID.path, post(create_did))
+      .route(RESOLVE_DID.path, get(resolve_did))
+      .route(CREATE_JWT_CREDENTIAL.path, post(create_jwt_credential))
+      .route(VALIDATE_JWT_CREDENTIAL.path, post(validate_jwt_credential))
+      .route(CREATE_STATUS_LIST.path, post(create_status_list))
+      .route(UPDATE_STATUS_LIST.path, post(update_status_list))
+      .route(VALIDATE_DOMAIN.path, post(validate_domain))
+      .route(
+        VALIDATE_DOMAIN_AGAINST_DID_CONFIGURATION.path,
+        post(validate_domain_against_did_configuration),
+      )
+      .route(VALIDATE_DID.path, post(validate_did))
+      .route(
+        VALIDATE_DID_AGAINST_DID_CONFIGURATIONS.path,
+        post(validate_did_against_did_configurations),
+      )
+      .with_state(state);
+
+    Self { router }
+  }
+
+  pub async fn serve(self, addr: SocketAddr) -> std::io::Result<()> {
+    self.serve_with_shutdown(addr, std::future::pending()).await
+  }
+
+  /// Serves until `signal` completes, then waits for in-flight requests to complete.
+  pub async fn serve_with_shutdown<F>(self, addr: SocketAddr, signal: F) -> std::io::Result<()>
+  where
+    F: Future<Output = ()> + Send + 'static,
+  {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, self.router).with_graceful_shutdown(signal).await
+  }
+
+  pub fn into_router(self) -> Router {
+    self.router
+  }
+}
+
+/// A failed RPC, mapped to the HTTP status code `grpc-gateway` uses for its status code.
+#[derive(Debug)]
+pub struct GatewayError(Status);
+
+#[derive(Debug, Serialize)]
+struct ErrorBody {
+  code: i32,
+  message: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  details: Option<serde_json::Value>,
+}
+
+impl From<Status> for GatewayError {
+  fn from(status: Status) -> Self {
+    Self(status)
+  }
+}
+
+impl IntoResponse for GatewayError {
+  fn into_response(self) -> axum::response::Response {
+    let status_code = match self.0.code() {
+      Code::Ok => StatusCode::OK,
+      Code::Cancelled => StatusCode::from_u16(499).expect("valid status code"),
+      Code::InvalidArgument | Code::FailedPrecondition | Code::OutOfRange => StatusCode::BAD_REQUEST,
+      Code::DeadlineExceeded => StatusCode::GATEWAY_TIMEOUT,
+      Code::NotFound => StatusCode::NOT_FOUND,
+      Code::AlreadyExists | Code::Aborted => StatusCode::CONFLICT,
+      Code::PermissionDenied => StatusCode::FORBIDDEN,
+      Code::Unauthenticated => StatusCode::UNAUTHORIZED,
+      Code::ResourceExhausted => StatusCode::TOO_MANY_REQUESTS,
+      Code::Unimplemented => StatusCode::NOT_IMPLEMENTED,
+      Code::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+      Code::Unknown | Code::Internal | Code::DataLoss => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    // Some services, e.g. domain linkage, detail their errors in JSON.
+    let body = ErrorBody {
+      code: self.0.code() as i32,
+      message: self.0.message().to_owned(),
+      details: serde_json::from_slice(self.0.details()).ok(),
+    };
+
+    (status_code, Json(body)).into_response()
+  }
+}
+
+/// Authorizes the call to `route`'s RPC and performs it with `call`.
+async fn call<Req, Res, F, Fut>(
+  state: &GatewayState,
+  route: Route,
+  headers: &HeaderMap,
+  message: Req,
+  call: F,
+) -> Result<Json<Res>, GatewayError>
+where
+  F: FnOnce(tonic::Request<Req>) -> Fut,
+  Fut: Future<Output = Result<tonic::Response<Res>, Status>>,
+{
+  let mut req = tonic::Request::new(message);
+  if let Some(auth) = &state.auth {
+    req.extensions_mut().insert(AuthEnabled);
+    // Clients authenticate with bearer tokens only, certificates are not forwarded.
+    if let Some((stronghold, principal)) = auth.authorize(route.rpc, headers, &Extensions::new())? {
+      req.extensions_mut().insert(stronghold);
+      req.extensions_mut().insert(principal);
+    }
+  }
+
+  Ok(Json(call(req).await?.into_inner()))
+}
+
+async fn openapi_description(State(state): State<Arc<GatewayState>>) -> Json<serde_json::Value> {
+  Json(state.openapi.clone())
+}
+
+async fn create_did(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Json(message): Json<CreateDidRequest>,
+) -> Result<Json<CreateDidResponse>, GatewayError> {
+  call(&state, CREATE_DID, &headers, message, |req| state.document.create(req)).await
+}
+
+async fn resolve_did(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Path(did): Path<String>,
+  Query(mut message): Query<ResolveDidRequest>,
+) -> Result<Json<ResolveDidResponse>, GatewayError> {
+  message.did = did;
+  call(&state, RESOLVE_DID, &headers, message, |req| {
+    state.document.resolve(req)
+  })
+  .await
+}
+
+async fn create_jwt_credential(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Json(message): Json<JwtCreationRequest>,
+) -> Result<Json<JwtCreationResponse>, GatewayError> {
+  call(&state, CREATE_JWT_CREDENTIAL, &headers, message, |req| {
+    state.jwt.create(req)
+  })
+  .await
+}
+
+async fn validate_jwt_credential(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Json(message): Json<VcValidationRequest>,
+) -> Result<Json<VcValidationResponse>, GatewayError> {
+  call(&state, VALIDATE_JWT_CREDENTIAL, &headers, message, |req| {
+    state.vc_validation.validate(req)
+  })
+  .await
+}
+
+async fn create_status_list(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Json(message): Json<StatusListCreateRequest>,
+) -> Result<Json<StatusListCredential>, GatewayError> {
+  call(&state, CREATE_STATUS_LIST, &headers, message, |req| {
+    state.status_list.create(req)
+  })
+  .await
+}
+
+async fn update_status_list(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Json(message): Json<StatusListUpdateRequest>,
+) -> Result<Json<StatusListCredential>, GatewayError> {
+  call(&state, UPDATE_STATUS_LIST, &headers, message, |req| {
+    state.status_list.update(req)
+  })
+  .await
+}
+
+async fn validate_domain(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Json(message): Json<ValidateDomainRequest>,
+) -> Result<Json<ValidateDomainResponse>, GatewayError> {
+  call(&state, VALIDATE_DOMAIN, &headers, message, |req| {
+    state.domain_linkage.validate_domain(req)
+  })
+  .await
+}
+
+async fn validate_domain_against_did_configuration(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Json(message): Json<ValidateDomainAgainstDidConfigurationRequest>,
+) -> Result<Json<ValidateDomainResponse>, GatewayError> {
+  call(
+    &state,
+    VALIDATE_DOMAIN_AGAINST_DID_CONFIGURATION,
+    &headers,
+    message,
+    |req| state.domain_linkage.validate_domain_against_did_configuration(req),
+  )
+  .await
+}
+
+async fn validate_did(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Json(message): Json<ValidateDidRequest>,
+) -> Result<Json<ValidateDidResponse>, GatewayError> {
+  call(&state, VALIDATE_DID, &headers, message, |req| {
+    state.domain_linkage.validate_did(req)
+  })
+  .await
+}
+
+async fn validate_did_against_did_configurations(
+  State(state): State<Arc<GatewayState>>,
+  headers: HeaderMap,
+  Json(message): Json<ValidateDidAgainstDidConfigurationsRequest>,
+) -> Result<Json<ValidateDidResponse>, GatewayError> {
+  call(
+    &state,
+    VALIDATE_DID_AGAINST_DID_CONFIGURATIONS,
+    &headers,
+    message,
+    |req| state.domain_linkage.validate_did_against_did_configurations(req),
+  )
+  .await
+}
@@ -0,0 +1,299 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! OpenAPI description of the gateway, generated from the services' file descriptor set.
+
+use std::collections::HashMap;
+
+use prost::Message;
+use prost_types::field_descriptor_proto::Label;
+use prost_types::field_descriptor_proto::Type;
+use prost_types::DescriptorProto;
+use prost_types::FieldDescriptorProto;
+use prost_types::FileDescriptorSet;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+use super::Route;
+use crate::services::FILE_DESCRIPTOR_SET;
+
+// Field numbers of `FileDescriptorProto` and `DescriptorProto`, used in source code locations.
+const FILE_MESSAGE_TYPE: i32 = 4;
+const FILE_SERVICE: i32 = 6;
+const MESSAGE_FIELD: i32 = 2;
+const MESSAGE_NESTED_TYPE: i32 = 3;
+const SERVICE_METHOD: i32 = 2;
+
+const ERROR_SCHEMA: &str = "Error";
+
+/// Messages, enums and RPCs of every proto file.
+#[derive(Default)]
+struct Descriptors {
+  // Indexed by fully qualified name, without the leading dot.
+  messages: HashMap<String, (DescriptorProto, Vec<i32>, usize)>,
+  // Values of the map entry messages, indexed by fully qualified name.
+  map_values: HashMap<String, FieldDescriptorProto>,
+  // Variants of the enums, indexed by fully qualified name.
+  enums: HashMap<String, Vec<(String, i32)>>,
+  // Input type, output type and source location of the RPCs, indexed by path.
+  methods: HashMap<String, (String, String, Vec<i32>, usize)>,
+  // Leading comments of every file, indexed by source location.
+  comments: Vec<HashMap<Vec<i32>, String>>,
+}
+
+impl Descriptors {
+  fn new() -> Self {
+    let set =
+      FileDescriptorSet::decode(FILE_DESCRIPTOR_SET).expect("the build script generates a valid descriptor set");
+    let mut descriptors = Self::default();
+
+    for (file_index, file) in set.file.iter().enumerate() {
+      let package = file.package();
+      let comments = file
+        .source_code_info
+        .iter()
+        .flat_map(|info| info.location.iter())
+        .filter_map(|location| {
+          let comment = location.leading_comments.as_deref().map(clean_comment)?;
+          Some((location.path.clone(), comment))
+        })
+        .collect();
+      descriptors.comments.push(comments);
+
+      for enumeration in &file.enum_type {
+        descriptors.add_enum(&format!("{package}.{}", enumeration.name()), enumeration);
+      }
+      for (index, message) in file.message_type.iter().enumerate() {
+        descriptors.add_message(package, message, vec![FILE_MESSAGE_TYPE, index as i32], file_index);
+      }
+      for (service_index, service) in file.service.iter().enumerate() {
+        for (method_index, method) in service.method.iter().enumerate() {
+          descriptors.methods.insert(
+            format!("/{package}.{}/{}", service.name(), method.name()),
+            (
+              method.input_type().trim_start_matches('.').to_owned(),
+              method.output_type().trim_start_matches('.').to_owned(),
+              vec![FILE_SERVICE, service_index as i32, SERVICE_METHOD, method_index as i32],
+              file_index,
+            ),
+          );
+        }
+      }
+    }
+
+    descriptors
+  }
+
+  fn add_enum(&mut self, name: &str, enumeration: &prost_types::EnumDescriptorProto) {
+    let variants = enumeration
+      .value
+      .iter()
+      .map(|value| (value.name().to_owned(), value.number()))
+      .collect();
+    self.enums.insert(name.to_owned(), variants);
+  }
+
+  fn add_message(&mut self, scope: &str, message: &DescriptorProto, path: Vec<i32>, file_index: usize) {
+    let name = format!("{scope}.{}", message.name());
+    if message.options.as_ref().is_some_and(|options| options.map_entry()) {
+      // Map entries have the fields `key` and `value`.
+      if let Some(value) = message.field.get(1) {
+        self.map_values.insert(name, value.clone());
+      }
+      return;
+    }
+
+    for enumeration in &message.enum_type {
+      self.add_enum(&format!("{name}.{}", enumeration.name()), enumeration);
+    }
+    for (index, nested) in message.nested_type.iter().enumerate() {
+      let mut nested_path = path.clone();
+      nested_path.extend([MESSAGE_NESTED_TYPE, index as i32]);
+      self.add_message(&name, nested, nested_path, file_index);
+    }
+    self.messages.insert(name, (message.clone(), path, file_index));
+  }
+
+  fn comment(&self, file_index: usize, path: &[i32]) -> Option<&str> {
+    self.comments[file_index].get(path).map(String::as_str)
+  }
+
+  fn message_schema(&self, message: &DescriptorProto, path: &[i32], file_index: usize) -> Value {
+    let properties: Map<String, Value> = message
+      .field
+      .iter()
+      .enumerate()
+      .map(|(index, field)| {
+        let mut field_path = path.to_vec();
+        field_path.extend([MESSAGE_FIELD, index as i32]);
+        let mut schema = self.field_schema(field);
+        if let Some(comment) = self.comment(file_index, &field_path) {
+          schema["description"] = comment.into();
+        }
+        (field.name().to_owned(), schema)
+      })
+      .collect();
+
+    let mut schema = json!({ "type": "object", "properties": properties });
+    if let Some(comment) = self.comment(file_index, path) {
+      schema["description"] = comment.into();
+    }
+    schema
+  }
+
+  fn field_schema(&self, field: &FieldDescriptorProto) -> Value {
+    let type_name = field.type_name().trim_start_matches('.');
+    if let Some(value) = self.map_values.get(type_name) {
+      return json!({ "type": "object", "additionalProperties": self.field_schema(value) });
+    }
+
+    let mut schema = match field.r#type() {
+      Type::String => json!({ "type": "string" }),
+      Type::Bool => json!({ "type": "boolean" }),
+      Type::Int32 | Type::Uint32 | Type::Sint32 | Type::Fixed32 | Type::Sfixed32 => {
+        json!({ "type": "integer", "format": "int32" })
+      }
+      Type::Int64 | Type::Uint64 | Type::Sint64 | Type::Fixed64 | Type::Sfixed64 => {
+        json!({ "type": "integer", "format": "int64" })
+      }
+      Type::Double => json!({ "type": "number", "format": "double" }),
+      Type::Float => json!({ "type": "number", "format": "float" }),
+      // Bytes are (de)serialized as arrays of numbers.
+      Type::Bytes => json!({ "type": "array", "items": { "type": "integer", "format": "int32" } }),
+      Type::Enum => {
+        let variants = self.enums.get(type_name).map(Vec::as_slice).unwrap_or_default();
+        let description = variants
+          .iter()
+          .map(|(name, number)| format!("{number} ({name})"))
+          .collect::<Vec<_>>()
+          .join(", ");
+        json!({
+          "type": "integer",
+          "format": "int32",
+          "enum": variants.iter().map(|(_, number)| *number).collect::<Vec<_>>(),
+          "description": format!("One of: {description}."),
+        })
+      }
+      Type::Message | Type::Group => json!({ "$ref": schema_ref(type_name) }),
+    };
+
+    if field.label() == Label::Repeated {
+      schema = json!({ "type": "array", "items": schema });
+    } else if field.proto3_optional() {
+      schema["nullable"] = true.into();
+    }
+    schema
+  }
+}
+
+/// Generates the OpenAPI description of `routes`.
+pub(crate) fn generate(routes: &[Route]) -> Value {
+  let descriptors = Descriptors::new();
+
+  let mut schemas: Map<String, Value> = descriptors
+    .messages
+    .iter()
+    .map(|(name, (message, path, file_index))| (name.clone(), descriptors.message_schema(message, path, *file_index)))
+    .collect();
+  schemas.insert(
+    ERROR_SCHEMA.to_owned(),
+    json!({
+      "type": "object",
+      "properties": {
+        "code": { "type": "integer", "format": "int32", "description": "gRPC status code." },
+        "message": { "type": "string" },
+        "details": { "description": "Service specific error details." },
+      },
+    }),
+  );
+
+  let mut paths = Map::new();
+  for route in routes {
+    let (input, output, path, file_index) = descriptors
+      .methods
+      .get(route.rpc)
+      .unwrap_or_else(|| panic!("{} is not described in the proto files", route.rpc));
+    let mut operation = json!({
+      "operationId": route.rpc.trim_start_matches('/').replace('/', "."),
+      "tags": [route.rpc.trim_start_matches('/').split('/').next()],
+      "security": [{}, { "bearerAuth": [] }],
+      "responses": {
+        "200": { "description": "OK", "content": json_content(output) },
+        "default": { "description": "Failed RPC", "content": json_content(ERROR_SCHEMA) },
+      },
+    });
+    if let Some(comment) = descriptors.comment(*file_index, path) {
+      operation["summary"] = comment.into();
+    }
+
+    if route.method == "get" {
+      let (message, _, _) = &descriptors.messages[input];
+      let parameters: Vec<Value> = message
+        .field
+        .iter()
+        .map(|field| {
+          let in_path = route
+            .path
+            .split('/')
+            .any(|segment| segment == format!(":{}", field.name()));
+          json!({
+            "name": field.name(),
+            "in": if in_path { "path" } else { "query" },
+            "required": in_path,
+            "schema": descriptors.field_schema(field),
+          })
+        })
+        .collect();
+      operation["parameters"] = parameters.into();
+    } else {
+      operation["requestBody"] = json!({ "required": true, "content": json_content(input) });
+    }
+
+    let path = route
+      .path
+      .split('/')
+      .map(|segment| match segment.strip_prefix(':') {
+        Some(parameter) => format!("{{{parameter}}}"),
+        None => segment.to_owned(),
+      })
+      .collect::<Vec<_>>()
+      .join("/");
+    let path_item = paths.entry(path).or_insert_with(|| json!({}));
+    path_item[route.method] = operation;
+  }
+
+  json!({
+    "openapi": "3.0.3",
+    "info": {
+      "title": "IOTA Identity gateway",
+      "description": "HTTP+JSON gateway to the IOTA Identity gRPC services.",
+      "version": env!("CARGO_PKG_VERSION"),
+    },
+    "paths": paths,
+    "components": {
+      "schemas": schemas,
+      "securitySchemes": {
+        "bearerAuth": { "type": "http", "scheme": "bearer" },
+      },
+    },
+  })
+}
+
+fn schema_ref(name: &str) -> String {
+  format!("#/components/schemas/{name}")
+}
+
+fn json_content(schema: &str) -> Value {
+  json!({ "application/json": { "schema": { "$ref": schema_ref(schema) } } })
+}
+
+/// Removes the comment markers left by `protoc`, e.g. the third slash of `///` comments.
+fn clean_comment(comment: &str) -> String {
+  comment
+    .lines()
+    .map(|line| line.trim_start_matches('/').trim())
+    .filter(|line| !line.is_empty())
+    .collect::<Vec<_>>()
+    .join(" ")
+}
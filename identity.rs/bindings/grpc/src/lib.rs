@@ -3,5 +3,9 @@
 
 #![allow(clippy::blocks_in_conditions)]
 
+pub mod auth;
+pub mod config;
+pub mod gateway;
+pub mod metrics;
 pub mod server;
 pub mod services;
@@ -2,11 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::str::FromStr;
+use std::sync::Arc;
 
-use anyhow::Context;
+use identity_grpc::config::Config;
+use identity_grpc::gateway::Gateway;
+use identity_grpc::metrics::Metrics;
 use identity_grpc::server::GRpcServer;
-use identity_stronghold::StrongholdStorage;
-use iota_sdk_legacy::client::stronghold::StrongholdAdapter;
+use identity_grpc::server::ServerOptions;
+use tokio::sync::watch;
 
 use identity_iota::iota::rebased::client::IdentityClientReadOnly;
 use iota_sdk::types::base_types::ObjectID;
@@ -17,55 +20,113 @@ async fn main() -> anyhow::Result<()> {
   tracing::subscriber::set_global_default(tracing_subscriber::fmt().compact().finish())
     .expect("Failed to setup global tracing subscriber.");
 
-  let api_endpoint = std::env::var("API_ENDPOINT")?;
-
-  let identity_iota_pkg_id = std::env::var("IDENTITY_IOTA_PKG_ID")?;
-
-  let identity_pkg_id = ObjectID::from_str(&identity_iota_pkg_id)?;
-
-  let iota_client = iota_sdk::IotaClientBuilder::default().build(api_endpoint).await?;
-
-  let read_only_client = IdentityClientReadOnly::new_with_pkg_id(iota_client, identity_pkg_id).await?;
-
-  let stronghold = init_stronghold()?;
-
-  let addr = "0.0.0.0:50051".parse()?;
+  let config = match std::env::var("CONFIG_PATH") {
+    Ok(path) => Config::from_file(path)?,
+    Err(_) => Config::from_env()?,
+  };
+
+  let iota_client = iota_sdk::IotaClientBuilder::default()
+    .build(&config.network.api_endpoint)
+    .await?;
+
+  let read_only_client = match config.network.identity_pkg_id.as_deref() {
+    Some(identity_pkg_id) => {
+      IdentityClientReadOnly::new_with_pkg_id(iota_client, ObjectID::from_str(identity_pkg_id)?).await?
+    }
+    None => IdentityClientReadOnly::new(iota_client).await?,
+  };
+
+  let stronghold = config.storage()?;
+
+  let metrics = config
+    .metrics
+    .as_ref()
+    .map(|_| Metrics::new().map(Arc::new))
+    .transpose()?;
+  let metrics_server = config
+    .metrics
+    .as_ref()
+    .zip(metrics.clone())
+    .map(|(metrics_config, metrics)| {
+      let addr = metrics_config.listen_address;
+      tracing::info!("metrics server listening on {}", addr);
+      tokio::spawn(identity_grpc::metrics::serve(metrics, addr))
+    });
+
+  let (shutdown_tx, shutdown_rx) = watch::channel(false);
+  let auth = config.auth()?;
+
+  let mut gateway_server = config.gateway.as_ref().map(|gateway_config| {
+    let addr = gateway_config.listen_address;
+    tracing::info!("REST gateway listening on {}", addr);
+    let gateway = Gateway::new(&read_only_client, &stronghold, auth.clone());
+    tokio::spawn(gateway.serve_with_shutdown(addr, shutdown_requested(shutdown_rx.clone())))
+  });
+
+  let options = ServerOptions {
+    tls: config.tls()?,
+    auth,
+    metrics,
+    reflection: config.reflection,
+  };
+
+  let addr = config.listen_address;
   tracing::info!("gRPC server listening on {}", addr);
-  GRpcServer::new(read_only_client, stronghold).serve(addr).await?;
+  let server = GRpcServer::new_with_options(read_only_client, stronghold, options)?
+    .serve_with_shutdown(addr, shutdown_requested(shutdown_rx));
+  tokio::pin!(server);
+
+  tokio::select! {
+    result = &mut server => result?,
+    _ = shutdown_signal() => {
+      tracing::info!("shutdown signal received, waiting for in-flight requests to complete");
+      let _ = shutdown_tx.send(true);
+      let gateway_drained = async {
+        if let Some(gateway_server) = gateway_server.as_mut() {
+          let _ = gateway_server.await;
+        }
+      };
+      let drained = async { futures::join!(server, gateway_drained).0 };
+      match tokio::time::timeout(config.shutdown_timeout(), drained).await {
+        Ok(result) => result?,
+        Err(_) => tracing::warn!("in-flight requests didn't complete in time, shutting down anyway"),
+      }
+    }
+  }
+
+  for server in [metrics_server, gateway_server].into_iter().flatten() {
+    server.abort();
+  }
 
   Ok(())
 }
 
-#[tracing::instrument]
-fn init_stronghold() -> anyhow::Result<StrongholdStorage> {
-  use std::env;
-  use std::fs;
-  let stronghold_password = env::var("STRONGHOLD_PWD_FILE")
-    .context("Unset \"STRONGHOLD_PWD_FILE\" env variable")
-    .and_then(|path| fs::read_to_string(&path).context(format!("{path} does not exists")))
-    .map(sanitize_pwd)
-    .or(env::var("STRONGHOLD_PWD"))
-    .context("No password for stronghold was provided")?;
-  let snapshot_path = env::var("SNAPSHOT_PATH")?;
-
-  // Check for snapshot file at specified path
-  let metadata = fs::metadata(&snapshot_path)?;
-  if !metadata.is_file() {
-    return Err(anyhow::anyhow!("No snapshot at provided path \"{}\"", &snapshot_path));
-  }
-
-  Ok(
-    StrongholdAdapter::builder()
-      .password(stronghold_password)
-      .build(snapshot_path)
-      .map(StrongholdStorage::new)?,
-  )
+/// Completes once `true` has been sent through `shutdown`.
+async fn shutdown_requested(mut shutdown: watch::Receiver<bool>) {
+  // A dropped sender requests the shutdown too.
+  let _ = shutdown.wait_for(|requested| *requested).await;
 }
 
-/// Remove any trailing whitespace in-place.
-fn sanitize_pwd(mut pwd: String) -> String {
-  let trimmed = pwd.trim_end();
-  pwd.truncate(trimmed.len());
-  pwd.shrink_to_fit();
-  pwd
+/// Completes on Ctrl+C or, on Unix, on SIGTERM.
+async fn shutdown_signal() {
+  let ctrl_c = async {
+    tokio::signal::ctrl_c()
+      .await
+      .expect("Failed to install Ctrl+C handler.");
+  };
+
+  #[cfg(unix)]
+  let terminate = async {
+    tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+      .expect("Failed to install SIGTERM handler.")
+      .recv()
+      .await;
+  };
+  #[cfg(not(unix))]
+  let terminate = std::future::pending::<()>();
+
+  tokio::select! {
+    _ = ctrl_c => {},
+    _ = terminate => {},
+  }
 }
@@ -0,0 +1,166 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Prometheus metrics of the handled RPCs.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Instant;
+
+use axum::routing::get;
+use prometheus::HistogramOpts;
+use prometheus::HistogramVec;
+use prometheus::IntCounterVec;
+use prometheus::Opts;
+use prometheus::Registry;
+use prometheus::TextEncoder;
+use tonic::body::BoxBody;
+use tonic::codegen::http;
+use tonic::codegen::BoxFuture;
+use tonic::codegen::Service;
+use tonic::Code;
+use tower::Layer;
+
+/// RPC metrics, named after the ones of `go-grpc-prometheus`.
+#[derive(Debug, Clone)]
+pub struct Metrics {
+  registry: Registry,
+  handled: IntCounterVec,
+  handling_seconds: HistogramVec,
+}
+
+impl Metrics {
+  pub fn new() -> Result<Self, prometheus::Error> {
+    let registry = Registry::new();
+    let handled = IntCounterVec::new(
+      Opts::new(
+        "grpc_server_handled_total",
+        "Total number of RPCs completed on the server.",
+      ),
+      &["grpc_service", "grpc_method", "grpc_code"],
+    )?;
+    let handling_seconds = HistogramVec::new(
+      HistogramOpts::new(
+        "grpc_server_handling_seconds",
+        "Response latency of the RPCs handled by the server.",
+      ),
+      &["grpc_service", "grpc_method"],
+    )?;
+    registry.register(Box::new(handled.clone()))?;
+    registry.register(Box::new(handling_seconds.clone()))?;
+
+    Ok(Self {
+      registry,
+      handled,
+      handling_seconds,
+    })
+  }
+
+  /// Returns the registry the metrics are registered to, allowing to add further metrics.
+  pub fn registry(&self) -> &Registry {
+    &self.registry
+  }
+
+  /// Encodes the metrics in the Prometheus text format.
+  pub fn encode(&self) -> Result<String, prometheus::Error> {
+    TextEncoder::new().encode_to_string(&self.registry.gather())
+  }
+
+  fn observe(&self, path: &str, code: Code, started_at: Instant) {
+    // Paths have the form `/<package>.<service>/<method>`.
+    let (service, method) = path.trim_start_matches('/').split_once('/').unwrap_or((path, ""));
+    self
+      .handled
+      .with_label_values(&[service, method, &format!("{code:?}")])
+      .inc();
+    self
+      .handling_seconds
+      .with_label_values(&[service, method])
+      .observe(started_at.elapsed().as_secs_f64());
+  }
+}
+
+/// Serves the metrics in the Prometheus text format at `http://<addr>/metrics`.
+pub async fn serve(metrics: Arc<Metrics>, addr: SocketAddr) -> std::io::Result<()> {
+  let app = axum::Router::new().route(
+    "/metrics",
+    get(move || async move {
+      match metrics.encode() {
+        Ok(body) => Ok(([(http::header::CONTENT_TYPE, prometheus::TEXT_FORMAT)], body)),
+        Err(e) => Err((http::StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+      }
+    }),
+  );
+  let listener = tokio::net::TcpListener::bind(addr).await?;
+  axum::serve(listener, app).await
+}
+
+/// Records the outcome and latency of every RPC in [`Metrics`].
+#[derive(Debug, Clone, Default)]
+pub struct MetricsLayer {
+  metrics: Option<Arc<Metrics>>,
+}
+
+impl MetricsLayer {
+  pub fn new(metrics: Option<Arc<Metrics>>) -> Self {
+    Self { metrics }
+  }
+}
+
+impl<S> Layer<S> for MetricsLayer {
+  type Service = MetricsMiddleware<S>;
+
+  fn layer(&self, inner: S) -> Self::Service {
+    MetricsMiddleware {
+      inner,
+      metrics: self.metrics.clone(),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct MetricsMiddleware<S> {
+  inner: S,
+  metrics: Option<Arc<Metrics>>,
+}
+
+impl<S, B> Service<http::Request<B>> for MetricsMiddleware<S>
+where
+  S: Service<http::Request<B>, Response = http::Response<BoxBody>> + Clone + Send + 'static,
+  S::Future: Send + 'static,
+  B: Send + 'static,
+{
+  type Response = S::Response;
+  type Error = S::Error;
+  type Future = BoxFuture<Self::Response, Self::Error>;
+
+  fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+    self.inner.poll_ready(cx)
+  }
+
+  fn call(&mut self, req: http::Request<B>) -> Self::Future {
+    // The service that has been driven to readiness must be the one called.
+    let clone = self.inner.clone();
+    let mut inner = std::mem::replace(&mut self.inner, clone);
+    let Some(metrics) = self.metrics.clone() else {
+      return Box::pin(inner.call(req));
+    };
+
+    let path = req.uri().path().to_owned();
+    let started_at = Instant::now();
+    Box::pin(async move {
+      let response = inner.call(req).await?;
+      // Failed RPCs carry their status in the headers, successful ones in the trailers.
+      let code = response
+        .headers()
+        .get("grpc-status")
+        .map(|status| Code::from_bytes(status.as_bytes()))
+        .unwrap_or(Code::Ok);
+      metrics.observe(&path, code, started_at);
+
+      Ok(response)
+    })
+  }
+}
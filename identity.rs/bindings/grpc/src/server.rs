@@ -1,30 +1,85 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::future::Future;
 use std::net::SocketAddr;
+use std::sync::Arc;
 
 use identity_stronghold::StrongholdStorage;
 use identity_iota::iota::rebased::client::IdentityClientReadOnly;
 use tonic::transport::server::Router;
 use tonic::transport::server::Server;
+use tonic::transport::server::ServerTlsConfig;
+use tower::layer::util::Identity;
+use tower::layer::util::Stack;
 
+use crate::auth::AuthConfig;
+use crate::auth::AuthLayer;
+use crate::metrics::Metrics;
+use crate::metrics::MetricsLayer;
 use crate::services;
 
+pub type GRpcRouter = Router<Stack<AuthLayer, Stack<MetricsLayer, Identity>>>;
+
+/// Transport security, access control and observability of a [`GRpcServer`].
+#[derive(Debug, Default)]
+pub struct ServerOptions {
+  /// Serves over TLS. Configure a client CA root to enable mutual TLS.
+  pub tls: Option<ServerTlsConfig>,
+  /// Requires clients to authenticate. If unset, every client can use the server's Stronghold.
+  pub auth: Option<AuthConfig>,
+  /// Records the outcome and latency of every RPC, including rejected ones.
+  pub metrics: Option<Arc<Metrics>>,
+  /// Enables gRPC server reflection.
+  pub reflection: bool,
+}
+
 #[derive(Debug)]
 pub struct GRpcServer {
-  router: Router,
+  router: GRpcRouter,
   stronghold: StrongholdStorage,
 }
 
 impl GRpcServer {
   pub fn new(client: IdentityClientReadOnly, stronghold: StrongholdStorage) -> Self {
-    let router = Server::builder().add_routes(services::routes(&client, &stronghold));
-    Self { router, stronghold }
+    Self::new_with_options(client, stronghold, ServerOptions::default()).expect("no TLS configuration to apply")
+  }
+  pub fn new_with_options(
+    client: IdentityClientReadOnly,
+    stronghold: StrongholdStorage,
+    options: ServerOptions,
+  ) -> Result<Self, tonic::transport::Error> {
+    let ServerOptions {
+      tls,
+      auth,
+      metrics,
+      reflection,
+    } = options;
+    let mut server = Server::builder();
+    if let Some(tls) = tls {
+      server = server.tls_config(tls)?;
+    }
+    let routes = services::routes(&client, &stronghold, auth.as_ref());
+    let mut router = server
+      .layer(MetricsLayer::new(metrics))
+      .layer(AuthLayer::new(auth))
+      .add_routes(routes);
+    if reflection {
+      router = router.add_service(services::reflection_service());
+    }
+    Ok(Self { router, stronghold })
   }
   pub async fn serve(self, addr: SocketAddr) -> Result<(), tonic::transport::Error> {
     self.router.serve(addr).await
   }
-  pub fn into_router(self) -> Router {
+  /// Serves until `signal` completes, then waits for in-flight requests to complete.
+  pub async fn serve_with_shutdown<F>(self, addr: SocketAddr, signal: F) -> Result<(), tonic::transport::Error>
+  where
+    F: Future<Output = ()>,
+  {
+    self.router.serve_with_shutdown(addr, signal).await
+  }
+  pub fn into_router(self) -> GRpcRouter {
     self.router
   }
   pub fn stronghold(&self) -> StrongholdStorage {
@@ -10,7 +10,6 @@ use identity_iota::iota::IotaDocument;
 use identity_iota::resolver::Resolver;
 use identity_iota::storage::JwkDocumentExt;
 use identity_iota::storage::JwsSignatureOptions;
-use identity_iota::storage::Storage;
 use identity_stronghold::StrongholdStorage;
 use identity_iota::iota::rebased::client::IdentityClientReadOnly;
 use tonic::Request;
@@ -20,14 +19,15 @@ use tonic::Status;
 use self::_credentials::jwt_server::JwtServer;
 use self::_credentials::JwtCreationRequest;
 use self::_credentials::JwtCreationResponse;
+use crate::auth;
 
-mod _credentials {
+pub(crate) mod _credentials {
   tonic::include_proto!("credentials");
 }
 
 pub struct JwtService {
   resolver: Resolver<IotaDocument>,
-  storage: Storage<StrongholdStorage, StrongholdStorage>,
+  stronghold: StrongholdStorage,
 }
 
 impl JwtService {
@@ -36,7 +36,7 @@ impl JwtService {
     resolver.attach_iota_handler(client.clone());
     Self {
       resolver,
-      storage: Storage::new(stronghold.clone(), stronghold.clone()),
+      stronghold: stronghold.clone(),
     }
   }
 }
@@ -51,6 +51,8 @@ impl JwtSvc for JwtService {
     err,
   )]
   async fn create(&self, req: Request<JwtCreationRequest>) -> Result<Response<JwtCreationResponse>, Status> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "create_jwt_credential", &req.get_ref().issuer_fragment);
     let JwtCreationRequest {
       credential_json,
       issuer_fragment,
@@ -68,7 +70,7 @@ impl JwtSvc for JwtService {
     let jwt = issuer_document
       .create_credential_jwt(
         &credential,
-        &self.storage,
+        &storage,
         &issuer_fragment,
         &JwsSignatureOptions::default(),
         None,
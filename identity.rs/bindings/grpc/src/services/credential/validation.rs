@@ -27,7 +27,7 @@ use tonic::Request;
 use tonic::Response;
 use tonic::Status;
 
-mod _credentials {
+pub(crate) mod _credentials {
   tonic::include_proto!("credentials");
 }
 
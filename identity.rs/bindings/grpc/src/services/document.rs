@@ -3,11 +3,28 @@
 
 use _document::document_service_server::DocumentService;
 use _document::document_service_server::DocumentServiceServer;
+use _document::AddMethodRequest;
+use _document::AddMethodResponse;
+use _document::AddServiceRequest;
 use _document::CreateDidRequest;
 use _document::CreateDidResponse;
+use _document::DeactivateDidRequest;
+use _document::DocumentUpdateResponse;
+use _document::MethodScope as GrpcMethodScope;
+use _document::RemoveMethodRequest;
+use _document::RemoveServiceRequest;
+use _document::ResolveDidRequest;
+use _document::ResolveDidResponse;
+use identity_iota::core::FromJson;
 use identity_iota::core::ToJson;
+use identity_iota::did::DID;
+use identity_iota::document::Service;
+use identity_iota::iota::rebased::client::get_object_id_from_did;
 use identity_iota::iota::rebased::client::IdentityClient;
 use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_iota::iota::rebased::migration::ControllerToken;
+use identity_iota::iota::rebased::migration::Identity;
+use identity_iota::iota::rebased::migration::OnChainIdentity;
 use identity_iota::iota::rebased::transaction::Transaction;
 use identity_iota::iota::IotaDID;
 use identity_iota::iota::IotaDocument;
@@ -27,7 +44,9 @@ use tonic::Request;
 use tonic::Response;
 use tonic::Status;
 
-mod _document {
+use crate::auth;
+
+pub(crate) mod _document {
   tonic::include_proto!("document");
 }
 
@@ -43,26 +62,147 @@ pub enum Error {
   IdentityClientError(identity_iota::iota::rebased::Error),
   #[error("did error : {0}")]
   DIDError(String),
+  #[error("invalid argument: {0}")]
+  InvalidArgument(String),
+  #[error("could not resolve DID: {0}")]
+  ResolutionError(identity_iota::iota::rebased::Error),
+  #[error("{0} not found")]
+  NotFound(String),
+  #[error(transparent)]
+  SerializationError(identity_iota::core::Error),
 }
 
 impl From<Error> for Status {
   fn from(value: Error) -> Self {
-    Status::new(Code::Internal, value.to_string())
+    let code = match &value {
+      Error::InvalidArgument(_) => Code::InvalidArgument,
+      Error::NotFound(_) => Code::NotFound,
+      Error::ResolutionError(error) => resolution_error_code(error),
+      _ => Code::Internal,
+    };
+    Status::new(code, value.to_string())
+  }
+}
+
+/// Only a DID that doesn't exist, or whose document was deleted, is reported as not found. Errors reaching the node
+/// are reported as unavailable, as retrying the request may succeed.
+fn resolution_error_code(error: &identity_iota::iota::rebased::Error) -> Code {
+  use identity_iota::iota::rebased::Error as ClientError;
+
+  match error {
+    ClientError::DIDResolutionError(_) => Code::NotFound,
+    ClientError::Network(..) | ClientError::RpcError(_) | ClientError::ObjectLookup(_) => Code::Unavailable,
+    _ => Code::Internal,
   }
 }
 
+/// Number of document versions returned by `resolve` if no history size is requested.
+const DEFAULT_HISTORY_SIZE: u32 = 10;
+
 pub struct DocumentSvc {
-  storage: Storage<StrongholdStorage, StrongholdStorage>,
+  stronghold: StrongholdStorage,
   client: IdentityClientReadOnly,
 }
 
 impl DocumentSvc {
   pub fn new(client: &IdentityClientReadOnly, stronghold: &StrongholdStorage) -> Self {
     Self {
-      storage: Storage::new(stronghold.clone(), stronghold.clone()),
+      stronghold: stronghold.clone(),
       client: client.clone(),
     }
   }
+
+  /// Returns a client signing transactions with the key of `storage` identified by `key_id`.
+  async fn identity_client<'s>(
+    &self,
+    storage: &'s Storage<StrongholdStorage, StrongholdStorage>,
+    key_id: String,
+  ) -> Result<IdentityClient<StorageSigner<'s, StrongholdStorage, StrongholdStorage>>, Error> {
+    let key_id = KeyId::new(key_id);
+    let pub_key = storage
+      .key_id_storage()
+      .get_public_key_with_type(&key_id, StrongholdKeyType::Ed25519)
+      .await
+      .map_err(Error::StrongholdError)?;
+    let signer = StorageSigner::new(storage, key_id, pub_key);
+
+    IdentityClient::new(self.client.clone(), signer)
+      .await
+      .map_err(Error::IdentityClientError)
+  }
+
+  async fn resolve_document(&self, did: &str) -> Result<IotaDocument, Error> {
+    let did = IotaDID::parse(did).map_err(|e| Error::InvalidArgument(e.to_string()))?;
+    self.client.resolve_did(&did).await.map_err(Error::ResolutionError)
+  }
+
+  /// Returns the on-chain identity of `did` and a token proving the control of `client`'s sender over it.
+  async fn controlled_identity<S>(
+    &self,
+    client: &IdentityClient<S>,
+    did: &IotaDID,
+  ) -> Result<(OnChainIdentity, ControllerToken), Error> {
+    let object_id = get_object_id_from_did(did).map_err(|e| Error::InvalidArgument(e.to_string()))?;
+    let Identity::FullFledged(identity) = self
+      .client
+      .get_identity(object_id)
+      .await
+      .map_err(Error::ResolutionError)?
+    else {
+      return Err(Error::InvalidArgument(
+        "only on-chain identities can be updated".to_owned(),
+      ));
+    };
+    let controller_token = identity
+      .get_controller_token(client)
+      .await
+      .map_err(Error::IdentityClientError)?
+      .ok_or_else(|| Error::InvalidArgument(format!("the controller key has no control over {did}")))?;
+
+    Ok((identity, controller_token))
+  }
+
+  /// Publishes the updated `document` using the identity controlled by the key `controller_key_id`.
+  ///
+  /// The gas budget of the transaction is estimated by dry running it.
+  async fn publish_update(
+    &self,
+    storage: &Storage<StrongholdStorage, StrongholdStorage>,
+    controller_key_id: String,
+    document: IotaDocument,
+  ) -> Result<IotaDocument, Error> {
+    let client = self.identity_client(storage, controller_key_id).await?;
+    let (mut identity, controller_token) = self.controlled_identity(&client, document.id()).await?;
+
+    identity
+      .update_did_document(document.clone(), &controller_token)
+      .finish(&client)
+      .await
+      .map_err(Error::IdentityClientError)?
+      .build_and_execute(&client)
+      .await
+      .map_err(Error::IdentityClientError)?;
+
+    Ok(document)
+  }
+}
+
+fn method_scope(scope: i32) -> Result<MethodScope, Error> {
+  let scope = GrpcMethodScope::try_from(scope).map_err(|e| Error::InvalidArgument(e.to_string()))?;
+  Ok(match scope {
+    GrpcMethodScope::VerificationMethod => MethodScope::VerificationMethod,
+    GrpcMethodScope::Authentication => MethodScope::authentication(),
+    GrpcMethodScope::AssertionMethod => MethodScope::assertion_method(),
+    GrpcMethodScope::KeyAgreement => MethodScope::key_agreement(),
+    GrpcMethodScope::CapabilityDelegation => MethodScope::capability_delegation(),
+    GrpcMethodScope::CapabilityInvocation => MethodScope::capability_invocation(),
+  })
+}
+
+fn update_response(document: &IotaDocument) -> Result<Response<DocumentUpdateResponse>, Status> {
+  Ok(Response::new(DocumentUpdateResponse {
+    document_json: document.to_json().map_err(Error::SerializationError)?,
+  }))
 }
 
 #[tonic::async_trait]
@@ -75,11 +215,12 @@ impl DocumentService for DocumentSvc {
     err,
   )]
   async fn create(&self, req: Request<CreateDidRequest>) -> Result<Response<CreateDidResponse>, Status> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "create_did_document", &req.get_ref().key_id);
     let CreateDidRequest { key_id } = req.into_inner();
 
     let key_id = KeyId::new(key_id);
-    let pub_key = self
-      .storage
+    let pub_key = storage
       .key_id_storage()
       .get_public_key_with_type(&key_id, StrongholdKeyType::Ed25519)
       .await
@@ -87,9 +228,9 @@ impl DocumentService for DocumentSvc {
 
     let network_name = self.client.network();
 
-    let storage = StorageSigner::new(&self.storage, key_id, pub_key);
+    let signer = StorageSigner::new(&storage, key_id, pub_key);
 
-    let identity_client = IdentityClient::new(self.client.clone(), storage)
+    let identity_client = IdentityClient::new(self.client.clone(), signer)
       .await
       .map_err(Error::IdentityClientError)?;
 
@@ -107,7 +248,7 @@ impl DocumentService for DocumentSvc {
     let mut document = IotaDocument::new_with_id(did.clone());
     let fragment = document
       .generate_method(
-        &self.storage,
+        &storage,
         ED25519_KEY_TYPE.clone(),
         JwsAlgorithm::EdDSA,
         Some(identity_client.signer().key_id().as_str()),
@@ -131,6 +272,225 @@ impl DocumentService for DocumentSvc {
       did: did.to_string(),
     }))
   }
+
+  #[tracing::instrument(
+    name = "resolve_did_document",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn resolve(&self, req: Request<ResolveDidRequest>) -> Result<Response<ResolveDidResponse>, Status> {
+    let ResolveDidRequest {
+      did,
+      include_history,
+      history_size,
+    } = req.into_inner();
+
+    let document = self.resolve_document(&did).await?;
+    let history_json = if include_history {
+      let did = document.id();
+      let object_id = get_object_id_from_did(did).map_err(Error::ResolutionError)?;
+      let Identity::FullFledged(identity) = self
+        .client
+        .get_identity(object_id)
+        .await
+        .map_err(Error::ResolutionError)?
+      else {
+        return Err(Error::InvalidArgument("history is only available for on-chain identities".to_owned()).into());
+      };
+      let page_size = history_size.unwrap_or(DEFAULT_HISTORY_SIZE) as usize;
+
+      identity
+        .get_history(&self.client, None, Some(page_size))
+        .await
+        .map_err(Error::ResolutionError)?
+        .iter()
+        .map(|data| {
+          IotaDocument::unpack_from_iota_object_data(did, data, true)
+            .map_err(Error::IotaClientError)
+            .and_then(|document| document.to_json().map_err(Error::SerializationError))
+        })
+        .collect::<Result<Vec<String>, Error>>()?
+    } else {
+      Vec::new()
+    };
+
+    Ok(Response::new(ResolveDidResponse {
+      document_json: document.to_json().map_err(Error::SerializationError)?,
+      metadata_json: document.metadata.to_json().map_err(Error::SerializationError)?,
+      history_json,
+    }))
+  }
+
+  #[tracing::instrument(
+    name = "add_verification_method",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn add_method(&self, req: Request<AddMethodRequest>) -> Result<Response<AddMethodResponse>, Status> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "add_verification_method", &req.get_ref().controller_key_id);
+    let AddMethodRequest {
+      did,
+      controller_key_id,
+      fragment,
+      scope,
+    } = req.into_inner();
+    let scope = method_scope(scope)?;
+
+    let mut document = self.resolve_document(&did).await?;
+    let fragment = document
+      .generate_method(
+        &storage,
+        ED25519_KEY_TYPE.clone(),
+        JwsAlgorithm::EdDSA,
+        fragment.as_deref(),
+        scope,
+      )
+      .await
+      .map_err(Error::StorageError)?;
+
+    let document = match self.publish_update(&storage, controller_key_id, document.clone()).await {
+      Ok(document) => document,
+      Err(error) => {
+        // Don't leave behind a key for a method that was never published.
+        let method_id = document
+          .id()
+          .to_url()
+          .join(format!("#{fragment}"))
+          .map_err(|e| Error::DIDError(e.to_string()))?;
+        let _ = document.purge_method(&storage, &method_id).await;
+        return Err(error.into());
+      }
+    };
+
+    Ok(Response::new(AddMethodResponse {
+      document_json: document.to_json().map_err(Error::SerializationError)?,
+      fragment,
+    }))
+  }
+
+  #[tracing::instrument(
+    name = "remove_verification_method",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn remove_method(&self, req: Request<RemoveMethodRequest>) -> Result<Response<DocumentUpdateResponse>, Status> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "remove_verification_method", &req.get_ref().controller_key_id);
+    let RemoveMethodRequest {
+      did,
+      controller_key_id,
+      fragment,
+    } = req.into_inner();
+
+    let mut document = self.resolve_document(&did).await?;
+    let method_id = document
+      .resolve_method(fragment.as_str(), None)
+      .map(|method| method.id().clone())
+      .ok_or_else(|| Error::NotFound(format!("method #{fragment}")))?;
+
+    // Publish the update first, so that a failure doesn't leave a method without its key.
+    let mut updated = document.clone();
+    updated.remove_method(&method_id);
+    let updated = self.publish_update(&storage, controller_key_id, updated).await?;
+    document
+      .purge_method(&storage, &method_id)
+      .await
+      .map_err(Error::StorageError)?;
+
+    update_response(&updated)
+  }
+
+  #[tracing::instrument(
+    name = "add_service",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn add_service(&self, req: Request<AddServiceRequest>) -> Result<Response<DocumentUpdateResponse>, Status> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "add_service", &req.get_ref().controller_key_id);
+    let AddServiceRequest {
+      did,
+      controller_key_id,
+      service_json,
+    } = req.into_inner();
+    let service = Service::from_json(&service_json).map_err(|e| Error::InvalidArgument(e.to_string()))?;
+
+    let mut document = self.resolve_document(&did).await?;
+    document
+      .insert_service(service)
+      .map_err(|e| Error::InvalidArgument(e.to_string()))?;
+    let document = self.publish_update(&storage, controller_key_id, document).await?;
+
+    update_response(&document)
+  }
+
+  #[tracing::instrument(
+    name = "remove_service",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn remove_service(
+    &self,
+    req: Request<RemoveServiceRequest>,
+  ) -> Result<Response<DocumentUpdateResponse>, Status> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "remove_service", &req.get_ref().controller_key_id);
+    let RemoveServiceRequest {
+      did,
+      controller_key_id,
+      fragment,
+    } = req.into_inner();
+
+    let mut document = self.resolve_document(&did).await?;
+    let service_id = document
+      .resolve_service(fragment.as_str())
+      .map(|service| service.id().clone())
+      .ok_or_else(|| Error::NotFound(format!("service #{fragment}")))?;
+    document.remove_service(&service_id);
+    let document = self.publish_update(&storage, controller_key_id, document).await?;
+
+    update_response(&document)
+  }
+
+  #[tracing::instrument(
+    name = "deactivate_did_document",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn deactivate(&self, req: Request<DeactivateDidRequest>) -> Result<Response<DocumentUpdateResponse>, Status> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "deactivate_did_document", &req.get_ref().controller_key_id);
+    let DeactivateDidRequest { did, controller_key_id } = req.into_inner();
+    let did = IotaDID::parse(did).map_err(|e| Error::InvalidArgument(e.to_string()))?;
+
+    let client = self.identity_client(&storage, controller_key_id).await?;
+    let (mut identity, controller_token) = self.controlled_identity(&client, &did).await?;
+    // The gas budget of the transaction is estimated by dry running it.
+    identity
+      .deactivate_did(&controller_token)
+      .finish(&client)
+      .await
+      .map_err(Error::IdentityClientError)?
+      .build_and_execute(&client)
+      .await
+      .map_err(Error::IdentityClientError)?;
+    let document = self.client.resolve_did(&did).await.map_err(Error::ResolutionError)?;
+
+    update_response(&document)
+  }
 }
 
 pub fn service(client: &IdentityClientReadOnly, stronghold: &StrongholdStorage) -> DocumentServiceServer<DocumentSvc> {
@@ -38,7 +38,7 @@ use tonic::Response;
 use tonic::Status;
 use url::Origin;
 
-mod _domain_linkage {
+pub(crate) mod _domain_linkage {
   tonic::include_proto!("domain_linkage");
 }
 
@@ -3,8 +3,14 @@
 
 use health_check::health_check_server::HealthCheck;
 use health_check::health_check_server::HealthCheckServer;
+use health_check::DependencyStatus;
 use health_check::HealthCheckRequest;
 use health_check::HealthCheckResponse;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_iota::iota_interaction::IotaClientTrait;
+use identity_storage::JwkStorage;
+use identity_storage::KeyId;
+use identity_stronghold::StrongholdStorage;
 use tonic::Request;
 use tonic::Response;
 use tonic::Status;
@@ -14,8 +20,56 @@ mod health_check {
   tonic::include_proto!("health_check");
 }
 
-#[derive(Debug, Default)]
-pub struct HealthChecker {}
+/// Key looked up to probe the storage. It doesn't need to exist.
+const PROBE_KEY_ID: &str = "health_check_probe";
+
+/// Reports the health of the server's dependencies: the IOTA node and the key storage.
+///
+/// Only the status of each dependency is reported, since the check can be called without credentials: errors are
+/// logged instead.
+pub struct HealthChecker {
+  client: IdentityClientReadOnly,
+  strongholds: Vec<StrongholdStorage>,
+}
+
+impl HealthChecker {
+  /// Creates a health check probing `stronghold` and the Strongholds of the `tenants`.
+  pub fn new(client: &IdentityClientReadOnly, stronghold: &StrongholdStorage, tenants: Vec<StrongholdStorage>) -> Self {
+    let mut strongholds = vec![stronghold.clone()];
+    strongholds.extend(tenants);
+    Self {
+      client: client.clone(),
+      strongholds,
+    }
+  }
+
+  async fn probe_iota_node(&self) -> DependencyStatus {
+    let result = self.client.read_api().get_chain_identifier().await;
+    if let Err(e) = &result {
+      tracing::warn!(error = %e, "IOTA node is unhealthy");
+    }
+    dependency_status("iota_node", result.is_ok())
+  }
+
+  async fn probe_storage(&self) -> DependencyStatus {
+    let probe_key = KeyId::new(PROBE_KEY_ID);
+    let results =
+      futures::future::join_all(self.strongholds.iter().map(|stronghold| stronghold.exists(&probe_key))).await;
+    let mut healthy = true;
+    for e in results.into_iter().filter_map(Result::err) {
+      tracing::warn!(error = %e, "storage is unhealthy");
+      healthy = false;
+    }
+    dependency_status("storage", healthy)
+  }
+}
+
+fn dependency_status(name: &str, healthy: bool) -> DependencyStatus {
+  DependencyStatus {
+    name: name.to_owned(),
+    healthy,
+  }
+}
 
 #[tonic::async_trait]
 impl HealthCheck for HealthChecker {
@@ -27,10 +81,25 @@ impl HealthCheck for HealthChecker {
     err,
   )]
   async fn check(&self, _req: Request<HealthCheckRequest>) -> Result<Response<HealthCheckResponse>, Status> {
-    Ok(Response::new(HealthCheckResponse { status: "OK".into() }))
+    let (iota_node, storage) = futures::join!(self.probe_iota_node(), self.probe_storage());
+    let dependencies = vec![iota_node, storage];
+    let status = if dependencies.iter().all(|dependency| dependency.healthy) {
+      "OK"
+    } else {
+      "UNAVAILABLE"
+    };
+
+    Ok(Response::new(HealthCheckResponse {
+      status: status.into(),
+      dependencies,
+    }))
   }
 }
 
-pub fn service() -> HealthCheckServer<HealthChecker> {
-  HealthCheckServer::new(HealthChecker::default())
+pub fn service(
+  client: &IdentityClientReadOnly,
+  stronghold: &StrongholdStorage,
+  tenants: Vec<StrongholdStorage>,
+) -> HealthCheckServer<HealthChecker> {
+  HealthCheckServer::new(HealthChecker::new(client, stronghold, tenants))
 }
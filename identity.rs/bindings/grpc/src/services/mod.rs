@@ -5,7 +5,9 @@ pub mod credential;
 pub mod document;
 pub mod domain_linkage;
 pub mod health_check;
+pub mod presentation;
 pub mod sd_jwt;
+pub mod sd_jwt_vc;
 pub mod status_list_2021;
 pub mod utils;
 
@@ -13,12 +15,23 @@ use identity_stronghold::StrongholdStorage;
 use identity_iota::iota::rebased::client::IdentityClientReadOnly;
 use tonic::service::Routes;
 use tonic::service::RoutesBuilder;
+use tonic_reflection::server::v1::ServerReflection;
+use tonic_reflection::server::v1::ServerReflectionServer;
 
-pub fn routes(client: &IdentityClientReadOnly, stronghold: &StrongholdStorage) -> Routes {
+use crate::auth::AuthConfig;
+
+/// Encoded file descriptor set of every service, served through gRPC server reflection.
+pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("identity_descriptor");
+
+/// Routes to every service, signing with `stronghold` or, if `auth` is set, with the caller's tenant Stronghold.
+pub fn routes(client: &IdentityClientReadOnly, stronghold: &StrongholdStorage, auth: Option<&AuthConfig>) -> Routes {
+  let tenants = auth.map(|auth| auth.tenant_strongholds().cloned().collect()).unwrap_or_default();
   let mut routes = RoutesBuilder::default();
-  routes.add_service(health_check::service());
+  routes.add_service(health_check::service(client, stronghold, tenants));
   credential::init_services(&mut routes, client, stronghold);
+  presentation::init_services(&mut routes, client, stronghold);
   routes.add_service(sd_jwt::service(client));
+  routes.add_service(sd_jwt_vc::service(client, stronghold));
   routes.add_service(domain_linkage::service(client));
   routes.add_service(document::service(client, stronghold));
   routes.add_service(status_list_2021::service());
@@ -26,3 +39,10 @@ pub fn routes(client: &IdentityClientReadOnly, stronghold: &StrongholdStorage) -
 
   routes.routes()
 }
+
+pub fn reflection_service() -> ServerReflectionServer<impl ServerReflection> {
+  tonic_reflection::server::Builder::configure()
+    .register_encoded_file_descriptor_set(FILE_DESCRIPTOR_SET)
+    .build_v1()
+    .expect("the file descriptor set generated by the build script is valid")
+}
@@ -0,0 +1,169 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use _presentation::jpt_presentation_server::JptPresentation;
+use _presentation::jpt_presentation_server::JptPresentationServer;
+use _presentation::JptPresentationCreationRequest;
+use _presentation::JptPresentationCreationResponse;
+use _presentation::JptPresentationValidationRequest;
+use _presentation::JptPresentationValidationResponse;
+use identity_iota::core::Object;
+use identity_iota::core::ToJson;
+use identity_iota::credential::FailFast;
+use identity_iota::credential::Jpt;
+use identity_iota::credential::JptCredentialValidationOptions;
+use identity_iota::credential::JptCredentialValidator;
+use identity_iota::credential::JptCredentialValidatorUtils;
+use identity_iota::credential::JptPresentationValidationOptions;
+use identity_iota::credential::JptPresentationValidator;
+use identity_iota::credential::JptPresentationValidatorUtils;
+use identity_iota::credential::JwpPresentationOptions;
+use identity_iota::credential::SelectiveDisclosurePresentation;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::IotaDocument;
+use identity_iota::resolver::Resolver;
+use identity_iota::storage::JwpDocumentExt;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+
+use super::check_audience;
+use super::parse_audience;
+use super::PresentationError;
+
+mod _presentation {
+  tonic::include_proto!("presentation");
+}
+
+pub struct JptPresentationService {
+  resolver: Resolver<IotaDocument>,
+}
+
+impl JptPresentationService {
+  pub fn new(client: &IdentityClientReadOnly) -> Self {
+    let mut resolver = Resolver::new();
+    resolver.attach_iota_handler(client.clone());
+    Self { resolver }
+  }
+}
+
+#[tonic::async_trait]
+impl JptPresentation for JptPresentationService {
+  #[tracing::instrument(
+    name = "create_jpt_presentation",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn create(
+    &self,
+    req: Request<JptPresentationCreationRequest>,
+  ) -> Result<Response<JptPresentationCreationResponse>, Status> {
+    let JptPresentationCreationRequest {
+      credential_jpt,
+      concealed_paths,
+      nonce,
+      audience,
+    } = req.into_inner();
+    let credential_jpt = Jpt::new(credential_jpt);
+
+    let issuer_did = JptCredentialValidatorUtils::extract_issuer_from_issued_jpt::<IotaDID>(&credential_jpt)
+      .map_err(|e| PresentationError::InvalidArgument(e.to_string()))?;
+    let issuer_document = self
+      .resolver
+      .resolve(&issuer_did)
+      .await
+      .map_err(PresentationError::DidResolutionError)?;
+    let credential = JptCredentialValidator::validate::<_, Object>(
+      &credential_jpt,
+      &issuer_document,
+      &JptCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .map_err(|e| PresentationError::InvalidArgument(e.to_string()))?;
+
+    let method_id = credential
+      .decoded_jwp
+      .get_issuer_protected_header()
+      .kid()
+      .ok_or_else(|| PresentationError::InvalidArgument("the credential's header is missing a `kid`".to_owned()))?;
+
+    let mut presentation = SelectiveDisclosurePresentation::new(&credential.decoded_jwp);
+    for path in concealed_paths {
+      presentation
+        .conceal_in_subject(&path)
+        .map_err(|e| PresentationError::InvalidArgument(e.to_string()))?;
+    }
+
+    let mut presentation_options = JwpPresentationOptions::default();
+    if let Some(nonce) = nonce {
+      presentation_options = presentation_options.nonce(nonce);
+    }
+    if let Some(audience) = parse_audience(audience)? {
+      presentation_options = presentation_options.audience(audience);
+    }
+
+    let presentation_jpt = issuer_document
+      .create_presentation_jpt(&mut presentation, method_id, &presentation_options)
+      .await
+      .map_err(PresentationError::SigningError)?;
+
+    Ok(Response::new(JptPresentationCreationResponse {
+      presentation_jpt: presentation_jpt.as_str().to_owned(),
+    }))
+  }
+
+  #[tracing::instrument(
+    name = "validate_jpt_presentation",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn validate(
+    &self,
+    req: Request<JptPresentationValidationRequest>,
+  ) -> Result<Response<JptPresentationValidationResponse>, Status> {
+    let JptPresentationValidationRequest {
+      presentation_jpt,
+      nonce,
+      audience,
+    } = req.into_inner();
+    let presentation_jpt = Jpt::new(presentation_jpt);
+    let audience = parse_audience(audience)?;
+
+    let issuer_did = JptPresentationValidatorUtils::extract_issuer_from_presented_jpt::<IotaDID>(&presentation_jpt)
+      .map_err(|e| PresentationError::InvalidPresentation(e.to_string()))?;
+    let issuer_document = self
+      .resolver
+      .resolve(&issuer_did)
+      .await
+      .map_err(PresentationError::DidResolutionError)?;
+
+    let mut validation_options = JptPresentationValidationOptions::default();
+    if let Some(nonce) = nonce {
+      validation_options = validation_options.nonce(nonce);
+    }
+    let presentation = JptPresentationValidator::validate::<_, Object>(
+      &presentation_jpt,
+      &issuer_document,
+      &validation_options,
+      FailFast::FirstError,
+    )
+    .map_err(|e| PresentationError::InvalidPresentation(e.to_string()))?;
+    check_audience(audience.as_ref(), presentation.aud.as_ref())?;
+
+    let credential_json = presentation
+      .credential
+      .to_json()
+      .map_err(|e| PresentationError::InvalidPresentation(e.to_string()))?;
+
+    Ok(Response::new(JptPresentationValidationResponse { credential_json }))
+  }
+}
+
+pub fn service(client: &IdentityClientReadOnly) -> JptPresentationServer<JptPresentationService> {
+  JptPresentationServer::new(JptPresentationService::new(client))
+}
@@ -0,0 +1,243 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use _presentation::jwt_presentation_server::JwtPresentation;
+use _presentation::jwt_presentation_server::JwtPresentationServer;
+use _presentation::JwtPresentationCreationRequest;
+use _presentation::JwtPresentationCreationResponse;
+use _presentation::JwtPresentationValidationRequest;
+use _presentation::JwtPresentationValidationResponse;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota::core::FromJson;
+use identity_iota::core::Object;
+use identity_iota::core::Timestamp;
+use identity_iota::core::ToJson;
+use identity_iota::credential::DecodedJwtPresentation;
+use identity_iota::credential::FailFast;
+use identity_iota::credential::Jwt;
+use identity_iota::credential::JwtCredentialValidationOptions;
+use identity_iota::credential::JwtCredentialValidator;
+use identity_iota::credential::JwtCredentialValidatorUtils;
+use identity_iota::credential::JwtPresentationOptions;
+use identity_iota::credential::JwtPresentationValidationOptions;
+use identity_iota::credential::JwtPresentationValidator;
+use identity_iota::credential::JwtPresentationValidatorUtils;
+use identity_iota::credential::Presentation;
+use identity_iota::credential::SubjectHolderRelationship;
+use identity_iota::did::DID;
+use identity_iota::document::verifiable::JwsVerificationOptions;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::IotaDocument;
+use identity_iota::resolver::Resolver;
+use identity_iota::storage::JwkDocumentExt;
+use identity_iota::storage::JwsSignatureOptions;
+use identity_stronghold::StrongholdStorage;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status;
+
+use super::check_audience;
+use super::parse_audience;
+use super::PresentationError;
+use crate::auth;
+
+mod _presentation {
+  tonic::include_proto!("presentation");
+}
+
+pub struct JwtPresentationService {
+  resolver: Resolver<IotaDocument>,
+  stronghold: StrongholdStorage,
+}
+
+impl JwtPresentationService {
+  pub fn new(client: &IdentityClientReadOnly, stronghold: &StrongholdStorage) -> Self {
+    let mut resolver = Resolver::new();
+    resolver.attach_iota_handler(client.clone());
+    Self {
+      resolver,
+      stronghold: stronghold.clone(),
+    }
+  }
+
+  /// Validates every credential contained in `presentation`, requiring the holder to be the subject.
+  async fn validate_credentials(
+    &self,
+    presentation: &DecodedJwtPresentation<Jwt>,
+    holder_did: &IotaDID,
+  ) -> Result<Vec<String>, PresentationError> {
+    let credentials = &presentation.presentation.verifiable_credential;
+    let issuers = credentials
+      .iter()
+      .map(JwtCredentialValidatorUtils::extract_issuer_from_jwt::<IotaDID>)
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(|e| PresentationError::InvalidPresentation(e.to_string()))?;
+    let issuer_documents: HashMap<IotaDID, IotaDocument> = self
+      .resolver
+      .resolve_multiple(&issuers)
+      .await
+      .map_err(PresentationError::DidResolutionError)?;
+
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+    let validation_options = JwtCredentialValidationOptions::default()
+      .subject_holder_relationship(holder_did.to_url().into(), SubjectHolderRelationship::AlwaysSubject);
+
+    credentials
+      .iter()
+      .zip(issuers.iter())
+      .enumerate()
+      .map(|(index, (credential_jwt, issuer))| {
+        // Every issuer has been resolved above.
+        let issuer_document = &issuer_documents[issuer];
+        let credential = validator
+          .validate::<_, Object>(
+            credential_jwt,
+            issuer_document,
+            &validation_options,
+            FailFast::FirstError,
+          )
+          .map_err(|e| PresentationError::InvalidCredential {
+            index,
+            message: e.to_string(),
+          })?
+          .credential;
+
+        credential.to_json().map_err(|e| PresentationError::InvalidCredential {
+          index,
+          message: e.to_string(),
+        })
+      })
+      .collect()
+  }
+}
+
+#[tonic::async_trait]
+impl JwtPresentation for JwtPresentationService {
+  #[tracing::instrument(
+    name = "create_jwt_presentation",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn create(
+    &self,
+    req: Request<JwtPresentationCreationRequest>,
+  ) -> Result<Response<JwtPresentationCreationResponse>, Status> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "create_jwt_presentation", &req.get_ref().holder_fragment);
+    let JwtPresentationCreationRequest {
+      presentation_json,
+      holder_fragment,
+      nonce,
+      audience,
+      expiration_date,
+      custom_claims_json,
+    } = req.into_inner();
+    let presentation = Presentation::<Jwt>::from_json(presentation_json.as_str())
+      .map_err(|e| PresentationError::InvalidArgument(e.to_string()))?;
+
+    let mut signature_options = JwsSignatureOptions::default();
+    if let Some(nonce) = nonce {
+      signature_options = signature_options.nonce(nonce);
+    }
+    let mut presentation_options = JwtPresentationOptions::default();
+    if let Some(audience) = parse_audience(audience)? {
+      presentation_options = presentation_options.audience(audience);
+    }
+    if let Some(expiration_date) = expiration_date {
+      let expiration_date =
+        Timestamp::parse(&expiration_date).map_err(|e| PresentationError::InvalidArgument(e.to_string()))?;
+      presentation_options = presentation_options.expiration_date(expiration_date);
+    }
+    if let Some(custom_claims_json) = custom_claims_json {
+      let custom_claims =
+        Object::from_json(&custom_claims_json).map_err(|e| PresentationError::InvalidArgument(e.to_string()))?;
+      presentation_options.custom_claims = Some(custom_claims);
+    }
+
+    let holder_did =
+      IotaDID::parse(presentation.holder.as_str()).map_err(|e| PresentationError::InvalidArgument(e.to_string()))?;
+    let holder_document = self
+      .resolver
+      .resolve(&holder_did)
+      .await
+      .map_err(PresentationError::DidResolutionError)?;
+
+    let presentation_jwt = holder_document
+      .create_presentation_jwt(
+        &presentation,
+        &storage,
+        &holder_fragment,
+        &signature_options,
+        &presentation_options,
+      )
+      .await
+      .map_err(PresentationError::SigningError)?;
+
+    Ok(Response::new(JwtPresentationCreationResponse {
+      presentation_jwt: presentation_jwt.into(),
+    }))
+  }
+
+  #[tracing::instrument(
+    name = "validate_jwt_presentation",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn validate(
+    &self,
+    req: Request<JwtPresentationValidationRequest>,
+  ) -> Result<Response<JwtPresentationValidationResponse>, Status> {
+    let JwtPresentationValidationRequest {
+      presentation_jwt,
+      nonce,
+      audience,
+    } = req.into_inner();
+    let presentation_jwt = Jwt::new(presentation_jwt);
+    let audience = parse_audience(audience)?;
+
+    let holder_did = JwtPresentationValidatorUtils::extract_holder::<IotaDID>(&presentation_jwt)
+      .map_err(|e| PresentationError::InvalidPresentation(e.to_string()))?;
+    let holder_document = self
+      .resolver
+      .resolve(&holder_did)
+      .await
+      .map_err(PresentationError::DidResolutionError)?;
+
+    let mut verifier_options = JwsVerificationOptions::default();
+    if let Some(nonce) = nonce {
+      verifier_options = verifier_options.nonce(nonce);
+    }
+    let validation_options =
+      JwtPresentationValidationOptions::default().presentation_verifier_options(verifier_options);
+    let presentation: DecodedJwtPresentation<Jwt> =
+      JwtPresentationValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+        .validate(&presentation_jwt, &holder_document, &validation_options)
+        .map_err(|e| PresentationError::InvalidPresentation(e.to_string()))?;
+    check_audience(audience.as_ref(), presentation.aud.as_ref())?;
+
+    let credentials_json = self.validate_credentials(&presentation, &holder_did).await?;
+    let presentation_json = presentation
+      .presentation
+      .to_json()
+      .map_err(|e| PresentationError::InvalidPresentation(e.to_string()))?;
+
+    Ok(Response::new(JwtPresentationValidationResponse {
+      presentation_json,
+      credentials_json,
+    }))
+  }
+}
+
+pub fn service(
+  client: &IdentityClientReadOnly,
+  stronghold: &StrongholdStorage,
+) -> JwtPresentationServer<JwtPresentationService> {
+  JwtPresentationServer::new(JwtPresentationService::new(client, stronghold))
+}
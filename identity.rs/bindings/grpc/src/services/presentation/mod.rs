@@ -0,0 +1,67 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "jpt-bbs-plus")]
+pub mod jpt;
+pub mod jwt;
+
+use identity_iota::core::Url;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_iota::resolver;
+use identity_iota::storage::JwkStorageDocumentError;
+use identity_stronghold::StrongholdStorage;
+use tonic::service::RoutesBuilder;
+use tonic::Code;
+use tonic::Status;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PresentationError {
+  #[error("{0}")]
+  InvalidArgument(String),
+  #[error("DID resolution error")]
+  DidResolutionError(#[source] resolver::Error),
+  #[error("failed to sign the presentation: {0}")]
+  SigningError(#[source] JwkStorageDocumentError),
+  #[error("invalid presentation: {0}")]
+  InvalidPresentation(String),
+  #[error("invalid credential at index {index}: {message}")]
+  InvalidCredential { index: usize, message: String },
+  #[error("the presentation is not addressed to the expected audience")]
+  AudienceMismatch,
+}
+
+impl From<PresentationError> for Status {
+  fn from(error: PresentationError) -> Self {
+    let code = match &error {
+      PresentationError::InvalidArgument(_)
+      | PresentationError::InvalidPresentation(_)
+      | PresentationError::InvalidCredential { .. }
+      | PresentationError::AudienceMismatch => Code::InvalidArgument,
+      PresentationError::DidResolutionError(_) => Code::NotFound,
+      PresentationError::SigningError(_) => Code::Internal,
+    };
+
+    Status::new(code, error.to_string())
+  }
+}
+
+/// Parses an optional audience provided in a request.
+fn parse_audience(audience: Option<String>) -> Result<Option<Url>, PresentationError> {
+  audience
+    .map(|audience| Url::parse(audience).map_err(|e| PresentationError::InvalidArgument(e.to_string())))
+    .transpose()
+}
+
+/// Checks that a presentation's `aud` matches the `expected` one, if any.
+fn check_audience(expected: Option<&Url>, audience: Option<&Url>) -> Result<(), PresentationError> {
+  match expected {
+    Some(expected) if audience != Some(expected) => Err(PresentationError::AudienceMismatch),
+    _ => Ok(()),
+  }
+}
+
+pub fn init_services(routes: &mut RoutesBuilder, client: &IdentityClientReadOnly, stronghold: &StrongholdStorage) {
+  routes.add_service(jwt::service(client, stronghold));
+  #[cfg(feature = "jpt-bbs-plus")]
+  routes.add_service(jpt::service(client));
+}
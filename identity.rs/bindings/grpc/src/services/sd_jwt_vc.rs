@@ -0,0 +1,464 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use _sd_jwt::sd_jwt_vc_service_server::SdJwtVcService as SdJwtVcSvc;
+use _sd_jwt::sd_jwt_vc_service_server::SdJwtVcServiceServer;
+use _sd_jwt::SdJwtVcIssuanceRequest;
+use _sd_jwt::SdJwtVcIssuanceResponse;
+use _sd_jwt::SdJwtVcKeyBindingOptions;
+use _sd_jwt::SdJwtVcPresentationRequest;
+use _sd_jwt::SdJwtVcPresentationResponse;
+use _sd_jwt::SdJwtVcVerificationRequest;
+use _sd_jwt::SdJwtVcVerificationResponse;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota::core::Object;
+use identity_iota::core::StringOrUrl;
+use identity_iota::core::Timestamp;
+use identity_iota::core::Url;
+use identity_iota::credential::sd_jwt_vc::resolver;
+use identity_iota::credential::sd_jwt_vc::Resolver as SdJwtVcResolver;
+use identity_iota::credential::sd_jwt_vc::SdJwtVc;
+use identity_iota::credential::sd_jwt_vc::SdJwtVcBuilder;
+use identity_iota::credential::sd_jwt_vc::Status;
+use identity_iota::credential::KeyBindingJWTValidationOptions;
+use identity_iota::did::DIDUrl;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::IotaDocument;
+use identity_iota::resolver::Resolver;
+use identity_iota::sd_jwt_rework::JsonObject;
+use identity_iota::sd_jwt_rework::JwsSigner;
+use identity_iota::sd_jwt_rework::KeyBindingJwtBuilder;
+use identity_iota::sd_jwt_rework::RequiredKeyBinding;
+use identity_iota::sd_jwt_rework::Sha256Hasher;
+use identity_iota::storage::JwkDocumentExt;
+use identity_iota::storage::JwkStorageDocumentError;
+use identity_iota::storage::JwsSignatureOptions;
+use identity_iota::storage::Storage;
+use identity_iota::verification::jwk::Jwk;
+use identity_stronghold::StrongholdStorage;
+use serde_json::Value;
+use tonic::Code;
+use tonic::Request;
+use tonic::Response;
+use tonic::Status as GrpcStatus;
+
+use crate::auth;
+
+mod _sd_jwt {
+  tonic::include_proto!("sd_jwt");
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SdJwtVcError {
+  #[error("{0}")]
+  InvalidArgument(String),
+  #[error("Failed to resolve DID Document: {0}")]
+  DidResolutionError(String),
+  #[error("Failed to sign: {0}")]
+  SigningError(String),
+  #[error("SD-JWT VC verification failed: {0}")]
+  VerificationError(String),
+  #[error("Missing \"kb_options\".")]
+  MissingKbOptions,
+}
+
+impl From<SdJwtVcError> for GrpcStatus {
+  fn from(error: SdJwtVcError) -> Self {
+    let code = match &error {
+      SdJwtVcError::InvalidArgument(_) | SdJwtVcError::VerificationError(_) | SdJwtVcError::MissingKbOptions => {
+        Code::InvalidArgument
+      }
+      SdJwtVcError::DidResolutionError(_) => Code::NotFound,
+      SdJwtVcError::SigningError(_) => Code::Internal,
+    };
+
+    GrpcStatus::new(code, error.to_string())
+  }
+}
+
+/// Signs SD-JWTs and KB-JWTs with the key of a DID document's verification method, stored in stronghold.
+struct DocumentJwsSigner<'a> {
+  document: &'a IotaDocument,
+  storage: &'a Storage<StrongholdStorage, StrongholdStorage>,
+  fragment: &'a str,
+}
+
+#[tonic::async_trait]
+impl JwsSigner for DocumentJwsSigner<'_> {
+  type Error = JwkStorageDocumentError;
+  async fn sign(&self, header: &JsonObject, payload: &JsonObject) -> Result<Vec<u8>, Self::Error> {
+    // `alg` is set according to the verification method, `kid` defaults to the method's ID.
+    // Every other header parameter is forwarded.
+    let mut options = JwsSignatureOptions::default();
+    let mut custom = Object::new();
+    for (name, value) in header {
+      match (name.as_str(), value.as_str()) {
+        ("alg", _) => (),
+        ("typ", Some(typ)) => options = options.typ(typ),
+        ("cty", Some(cty)) => options = options.cty(cty),
+        ("kid", Some(kid)) => options = options.kid(kid),
+        ("nonce", Some(nonce)) => options = options.nonce(nonce),
+        ("typ" | "cty" | "kid" | "nonce", None) => {
+          return Err(JwkStorageDocumentError::EncodingError(
+            format!("header parameter `{name}` must be a string").into(),
+          ))
+        }
+        _ => {
+          custom.insert(name.clone(), value.clone());
+        }
+      }
+    }
+    if !custom.is_empty() {
+      options = options.custom_header_parameters(custom);
+    }
+    let payload = serde_json::to_vec(payload).map_err(|e| JwkStorageDocumentError::EncodingError(e.into()))?;
+    let jws = self
+      .document
+      .create_jws(self.storage, self.fragment, &payload, &options)
+      .await?;
+
+    Ok(jws.as_str().as_bytes().to_vec())
+  }
+}
+
+/// Serves the type metadata and JSON resources provided by the verifier.
+struct TypeMetadataResolver {
+  type_metadata: Vec<u8>,
+  resources: HashMap<String, Value>,
+}
+
+#[tonic::async_trait]
+impl SdJwtVcResolver<StringOrUrl, Vec<u8>> for TypeMetadataResolver {
+  async fn resolve(&self, _vct: &StringOrUrl) -> Result<Vec<u8>, resolver::Error> {
+    Ok(self.type_metadata.clone())
+  }
+}
+
+#[tonic::async_trait]
+impl SdJwtVcResolver<Url, Value> for TypeMetadataResolver {
+  async fn resolve(&self, input: &Url) -> Result<Value, resolver::Error> {
+    self
+      .resources
+      .get(input.as_str())
+      .cloned()
+      .ok_or_else(|| resolver::Error::NotFound(input.to_string()))
+  }
+}
+
+impl TryFrom<SdJwtVcKeyBindingOptions> for KeyBindingJWTValidationOptions {
+  type Error = SdJwtVcError;
+  fn try_from(value: SdJwtVcKeyBindingOptions) -> Result<Self, Self::Error> {
+    let parse_timestamp = |timestamp: Option<String>| {
+      timestamp
+        .map(|t| Timestamp::parse(&t).map_err(|e| SdJwtVcError::InvalidArgument(e.to_string())))
+        .transpose()
+    };
+    let mut kb_options = Self::default();
+    kb_options.nonce = value.nonce;
+    kb_options.aud = value.aud;
+    kb_options.earliest_issuance_date = parse_timestamp(value.earliest_issuance_date)?;
+    kb_options.latest_issuance_date = parse_timestamp(value.latest_issuance_date)?;
+
+    Ok(kb_options)
+  }
+}
+
+pub struct SdJwtVcService {
+  resolver: Resolver<IotaDocument>,
+  stronghold: StrongholdStorage,
+}
+
+impl SdJwtVcService {
+  pub fn new(client: &IdentityClientReadOnly, stronghold: &StrongholdStorage) -> Self {
+    let mut resolver = Resolver::new();
+    resolver.attach_iota_handler(client.clone());
+    Self {
+      resolver,
+      stronghold: stronghold.clone(),
+    }
+  }
+
+  async fn resolve_document(&self, did: &str) -> Result<IotaDocument, SdJwtVcError> {
+    let did = IotaDID::parse(did).map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    self
+      .resolver
+      .resolve(&did)
+      .await
+      .map_err(|e| SdJwtVcError::DidResolutionError(e.to_string()))
+  }
+
+  /// Resolves the verification method identified by the DID URL `method_id`, returning its document,
+  /// fragment and public key.
+  async fn resolve_method(&self, method_id: &str) -> Result<(IotaDocument, String, Jwk), SdJwtVcError> {
+    let method_id = DIDUrl::parse(method_id).map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    let fragment = method_id
+      .fragment()
+      .ok_or_else(|| SdJwtVcError::InvalidArgument("the method id is missing a fragment".to_owned()))?
+      .to_owned();
+    let document = self.resolve_document(method_id.did().as_str()).await?;
+    let jwk = document_jwk(&document, &fragment)?;
+
+    Ok((document, fragment, jwk))
+  }
+}
+
+/// Returns the public key of the verification method with the given fragment.
+fn document_jwk(document: &IotaDocument, fragment: &str) -> Result<Jwk, SdJwtVcError> {
+  document
+    .resolve_method(fragment, None)
+    .and_then(|method| method.data().public_key_jwk())
+    .cloned()
+    .ok_or_else(|| SdJwtVcError::InvalidArgument(format!("no JWK verification method with fragment \"{fragment}\"")))
+}
+
+/// Returns the JWS algorithm to use with `jwk`.
+fn jwk_alg(jwk: &Jwk) -> Result<String, SdJwtVcError> {
+  jwk
+    .alg()
+    .map(ToOwned::to_owned)
+    .ok_or_else(|| SdJwtVcError::InvalidArgument("the verification method's JWK has no `alg`".to_owned()))
+}
+
+#[tonic::async_trait]
+impl SdJwtVcSvc for SdJwtVcService {
+  #[tracing::instrument(
+    name = "sd_jwt_vc_issuance",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn issue(&self, req: Request<SdJwtVcIssuanceRequest>) -> Result<Response<SdJwtVcIssuanceResponse>, GrpcStatus> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "sd_jwt_vc_issuance", &req.get_ref().issuer_fragment);
+    let SdJwtVcIssuanceRequest {
+      claims_json,
+      issuer_did,
+      issuer_fragment,
+      vct,
+      concealable_paths,
+      decoys,
+      holder_method_id,
+      status_json,
+      sub,
+      expiration_date,
+    } = req.into_inner();
+    let claims: JsonObject =
+      serde_json::from_str(&claims_json).map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    let issuer_document = self.resolve_document(&issuer_did).await?;
+    let alg = jwk_alg(&document_jwk(&issuer_document, &issuer_fragment)?)?;
+    let iss = Url::parse(issuer_document.id().as_str()).map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+
+    let mut builder = SdJwtVcBuilder::new(claims)
+      .map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?
+      .iss(iss)
+      .iat(Timestamp::now_utc())
+      .vct(vct);
+    for path in concealable_paths {
+      builder = builder
+        .make_concealable(&path)
+        .map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    }
+    for (path, number_of_decoys) in decoys {
+      builder = builder
+        .add_decoys(&path, number_of_decoys as usize)
+        .map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    }
+    if let Some(holder_method_id) = holder_method_id {
+      let (_, _, holder_jwk) = self.resolve_method(&holder_method_id).await?;
+      let Ok(Value::Object(holder_jwk)) = serde_json::to_value(holder_jwk) else {
+        unreachable!("a JWK is serialized as a JSON object");
+      };
+      builder = builder.require_key_binding(RequiredKeyBinding::Jwk(holder_jwk));
+    }
+    if let Some(status_json) = status_json {
+      let status: Status =
+        serde_json::from_str(&status_json).map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+      builder = builder.status(status);
+    }
+    if let Some(sub) = sub {
+      builder = builder.sub(sub);
+    }
+    if let Some(expiration_date) = expiration_date {
+      let exp = Timestamp::parse(&expiration_date).map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+      builder = builder.exp(exp);
+    }
+
+    let signer = DocumentJwsSigner {
+      document: &issuer_document,
+      storage: &storage,
+      fragment: &issuer_fragment,
+    };
+    let sd_jwt_vc = builder
+      .finish(&signer, &alg)
+      .await
+      .map_err(|e| SdJwtVcError::SigningError(e.to_string()))?;
+
+    Ok(Response::new(SdJwtVcIssuanceResponse {
+      sd_jwt_vc: sd_jwt_vc.to_string(),
+    }))
+  }
+
+  #[tracing::instrument(
+    name = "sd_jwt_vc_presentation",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn present(
+    &self,
+    req: Request<SdJwtVcPresentationRequest>,
+  ) -> Result<Response<SdJwtVcPresentationResponse>, GrpcStatus> {
+    let storage = auth::storage(&req, &self.stronghold)?;
+    if let Some(holder_method_id) = &req.get_ref().holder_method_id {
+      auth::audit_signing(&req, "sd_jwt_vc_presentation", holder_method_id);
+    }
+    let SdJwtVcPresentationRequest {
+      sd_jwt_vc,
+      concealed_paths,
+      holder_method_id,
+      nonce,
+      aud,
+    } = req.into_inner();
+    let sd_jwt_vc = SdJwtVc::parse(&sd_jwt_vc).map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+
+    let mut builder = sd_jwt_vc
+      .into_presentation(&Sha256Hasher::new())
+      .map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    for path in concealed_paths {
+      builder = builder
+        .conceal(&path)
+        .map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    }
+    let (mut presentation, _) = builder
+      .finish()
+      .map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+
+    // The KB-JWT is computed over the disclosures that are actually presented.
+    if let Some(holder_method_id) = holder_method_id {
+      let (holder_document, fragment, holder_jwk) = self.resolve_method(&holder_method_id).await?;
+      let alg = jwk_alg(&holder_jwk)?;
+      let signer = DocumentJwsSigner {
+        document: &holder_document,
+        storage: &storage,
+        fragment: &fragment,
+      };
+
+      let mut kb_builder = KeyBindingJwtBuilder::default().iat(Timestamp::now_utc().to_unix());
+      if let Some(nonce) = nonce {
+        kb_builder = kb_builder.nonce(nonce);
+      }
+      if let Some(aud) = aud {
+        kb_builder = kb_builder.aud(aud);
+      }
+      let kb_jwt = kb_builder
+        .finish(&presentation, &Sha256Hasher::new(), &alg, &signer)
+        .await
+        .map_err(|e| SdJwtVcError::SigningError(e.to_string()))?;
+
+      (presentation, _) = presentation
+        .into_presentation(&Sha256Hasher::new())
+        .map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?
+        .attach_key_binding_jwt(kb_jwt)
+        .finish()
+        .map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    }
+
+    Ok(Response::new(SdJwtVcPresentationResponse {
+      sd_jwt_vc: presentation.to_string(),
+    }))
+  }
+
+  #[tracing::instrument(
+    name = "sd_jwt_vc_verification",
+    skip_all,
+    fields(request = ?req.get_ref())
+    ret,
+    err,
+  )]
+  async fn verify(
+    &self,
+    req: Request<SdJwtVcVerificationRequest>,
+  ) -> Result<Response<SdJwtVcVerificationResponse>, GrpcStatus> {
+    let SdJwtVcVerificationRequest {
+      sd_jwt_vc,
+      type_metadata_json,
+      resources_json,
+      kb_options,
+    } = req.into_inner();
+    let sd_jwt_vc = SdJwtVc::parse(&sd_jwt_vc).map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    let resources = resources_json
+      .into_iter()
+      .map(|(uri, json)| serde_json::from_str(&json).map(|value| (uri, value)))
+      .collect::<Result<_, _>>()
+      .map_err(|e| SdJwtVcError::InvalidArgument(e.to_string()))?;
+    let metadata_resolver = TypeMetadataResolver {
+      type_metadata: type_metadata_json.into_bytes(),
+      resources,
+    };
+
+    // The issuer is identified by a DID: its key is the one referenced by the `kid` header.
+    let kid = sd_jwt_vc
+      .header()
+      .get("kid")
+      .and_then(Value::as_str)
+      .ok_or_else(|| SdJwtVcError::VerificationError("missing header claim `kid`".to_owned()))?;
+    let kid = DIDUrl::parse(kid).map_err(|e| SdJwtVcError::VerificationError(e.to_string()))?;
+    if kid.did().as_str() != sd_jwt_vc.claims().iss.as_str() {
+      return Err(SdJwtVcError::VerificationError("`kid` doesn't belong to the issuer".to_owned()).into());
+    }
+    let issuer_document = self.resolve_document(kid.did().as_str()).await?;
+    let issuer_jwk = document_jwk(&issuer_document, kid.fragment().unwrap_or_default())
+      .map_err(|e| SdJwtVcError::VerificationError(e.to_string()))?;
+
+    sd_jwt_vc
+      .validate_with_issuer_jwk(
+        &issuer_jwk,
+        &metadata_resolver,
+        &EdDSAJwsVerifier::default(),
+        &Sha256Hasher::new(),
+      )
+      .await
+      .map_err(|e| SdJwtVcError::VerificationError(e.to_string()))?;
+
+    // A credential bound to a holder key, or a caller expecting key binding, requires a KB-JWT.
+    if kb_options.is_some() || sd_jwt_vc.required_key_bind().is_some() {
+      if sd_jwt_vc.key_binding_jwt().is_none() {
+        return Err(SdJwtVcError::VerificationError("missing key binding JWT".to_owned()).into());
+      }
+      let kb_options: KeyBindingJWTValidationOptions = kb_options.ok_or(SdJwtVcError::MissingKbOptions)?.try_into()?;
+      let Some(RequiredKeyBinding::Jwk(holder_jwk)) = sd_jwt_vc.required_key_bind() else {
+        return Err(SdJwtVcError::VerificationError("the credential isn't bound to a JWK".to_owned()).into());
+      };
+      let holder_jwk: Jwk = serde_json::from_value(Value::Object(holder_jwk.clone()))
+        .map_err(|e| SdJwtVcError::VerificationError(e.to_string()))?;
+      sd_jwt_vc
+        .validate_key_binding(
+          &EdDSAJwsVerifier::default(),
+          &holder_jwk,
+          &Sha256Hasher::new(),
+          &kb_options,
+        )
+        .map_err(|e| SdJwtVcError::VerificationError(e.to_string()))?;
+    }
+
+    let claims = sd_jwt_vc
+      .into_disclosed_object(&Sha256Hasher::new())
+      .map_err(|e| SdJwtVcError::VerificationError(e.to_string()))?;
+
+    Ok(Response::new(SdJwtVcVerificationResponse {
+      claims_json: Value::Object(claims).to_string(),
+    }))
+  }
+}
+
+pub fn service(
+  client: &IdentityClientReadOnly,
+  stronghold: &StrongholdStorage,
+) -> SdJwtVcServiceServer<SdJwtVcService> {
+  SdJwtVcServiceServer::new(SdJwtVcService::new(client, stronghold))
+}
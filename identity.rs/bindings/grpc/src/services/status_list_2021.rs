@@ -27,7 +27,7 @@ use tonic::Request;
 use tonic::Response;
 use tonic::Status;
 
-mod _status_list_2021 {
+pub(crate) mod _status_list_2021 {
   use identity_iota::credential::status_list_2021::StatusPurpose;
 
   tonic::include_proto!("status_list_2021");
@@ -14,6 +14,8 @@ use tonic::Request;
 use tonic::Response;
 use tonic::Status;
 
+use crate::auth;
+
 mod _utils {
   tonic::include_proto!("utils");
 }
@@ -29,13 +31,13 @@ impl From<Error> for Status {
 }
 
 pub struct SigningService {
-  storage: StrongholdStorage,
+  stronghold: StrongholdStorage,
 }
 
 impl SigningService {
   pub fn new(stronghold: &StrongholdStorage) -> Self {
     Self {
-      storage: stronghold.clone(),
+      stronghold: stronghold.clone(),
     }
   }
 }
@@ -50,15 +52,15 @@ impl SigningSvc for SigningService {
     err,
   )]
   async fn sign(&self, req: Request<DataSigningRequest>) -> Result<Response<DataSigningResponse>, Status> {
+    let stronghold = auth::stronghold(&req, &self.stronghold)?;
+    auth::audit_signing(&req, "utils/sign", &req.get_ref().key_id);
     let DataSigningRequest { data, key_id } = req.into_inner();
     let key_id = KeyId::new(key_id);
-    let public_key_jwk = self
-      .storage
+    let public_key_jwk = stronghold
       .get_public_key_with_type(&key_id, StrongholdKeyType::Ed25519)
       .await
       .map_err(Error)?;
-    let signature = self
-      .storage
+    let signature = stronghold
       .sign(&key_id, &data, &public_key_jwk)
       .await
       .map_err(Error)?;
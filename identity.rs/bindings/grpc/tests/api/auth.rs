@@ -0,0 +1,178 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use _health_check::health_check_client::HealthCheckClient;
+use _health_check::HealthCheckRequest;
+use _status_list_2021::status_list2021_svc_client::StatusList2021SvcClient;
+use _status_list_2021::CreateRequest;
+use _utils::signing_client::SigningClient;
+use _utils::DataSigningRequest;
+use identity_grpc::auth::AuthConfig;
+use identity_grpc::auth::Policy;
+use identity_grpc::auth::Principal;
+use identity_grpc::server::ServerOptions;
+use identity_iota::verification::jws::JwsAlgorithm;
+use identity_storage::JwkStorage;
+use identity_storage::KeyId;
+use identity_storage::KeyType;
+use identity_stronghold::StrongholdStorage;
+use tonic::metadata::MetadataValue;
+use tonic::Code;
+use tonic::Request;
+
+use crate::helpers::make_stronghold;
+use crate::helpers::TestServer;
+
+mod _health_check {
+  tonic::include_proto!("health_check");
+}
+
+mod _status_list_2021 {
+  tonic::include_proto!("status_list_2021");
+}
+
+mod _utils {
+  tonic::include_proto!("utils");
+}
+
+const TENANT_A_TOKEN: &str = "tenant-a-token";
+const TENANT_B_TOKEN: &str = "tenant-b-token";
+const READER_TOKEN: &str = "reader-token";
+const SAMPLE_SIGNING_DATA: &[u8] = b"I'm just some random data to be signed :)";
+
+struct Tenants {
+  server: TestServer,
+  tenant_a: StrongholdStorage,
+}
+
+async fn setup() -> Tenants {
+  let tenant_a = StrongholdStorage::new(make_stronghold());
+  let tenant_b = StrongholdStorage::new(make_stronghold());
+  let auth = AuthConfig::new()
+    .tenant("a", tenant_a.clone())
+    .tenant("b", tenant_b)
+    .bearer_token(TENANT_A_TOKEN, Principal::new("a", "alice").with_role("signer"))
+    .bearer_token(TENANT_B_TOKEN, Principal::new("b", "bob").with_role("signer"))
+    .bearer_token(READER_TOKEN, Principal::new("a", "carol"))
+    .policy(Policy::new().require_roles("/utils.Signing/*", ["signer"]));
+  let options = ServerOptions {
+    auth: Some(auth),
+    ..Default::default()
+  };
+  let server = TestServer::new_with_options(StrongholdStorage::new(make_stronghold()), options).await;
+
+  Tenants { server, tenant_a }
+}
+
+async fn generate_key(stronghold: &StrongholdStorage) -> anyhow::Result<KeyId> {
+  Ok(
+    stronghold
+      .generate(KeyType::from_static_str("Ed25519"), JwsAlgorithm::EdDSA)
+      .await?
+      .key_id,
+  )
+}
+
+fn signing_request(key_id: &KeyId, token: Option<&str>) -> Request<DataSigningRequest> {
+  let mut req = Request::new(DataSigningRequest {
+    data: SAMPLE_SIGNING_DATA.to_owned(),
+    key_id: key_id.to_string(),
+  });
+  if let Some(token) = token {
+    let value: MetadataValue<_> = format!("Bearer {token}").parse().unwrap();
+    req.metadata_mut().insert("authorization", value);
+  }
+  req
+}
+
+#[tokio::test]
+async fn health_check_is_public() -> anyhow::Result<()> {
+  let Tenants { server, .. } = setup().await;
+
+  let mut grpc_client = HealthCheckClient::connect(server.endpoint()).await?;
+  let response = grpc_client.check(HealthCheckRequest {}).await?.into_inner();
+  // The storage dependency covers the tenants' Strongholds.
+  assert_eq!(response.status, "OK");
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn missing_or_invalid_credentials_are_rejected() -> anyhow::Result<()> {
+  let Tenants { server, tenant_a } = setup().await;
+  let key_id = generate_key(&tenant_a).await?;
+
+  let mut grpc_client = SigningClient::connect(server.endpoint()).await?;
+  let status = grpc_client.sign(signing_request(&key_id, None)).await.unwrap_err();
+  assert_eq!(status.code(), Code::Unauthenticated);
+
+  let status = grpc_client
+    .sign(signing_request(&key_id, Some("not-a-token")))
+    .await
+    .unwrap_err();
+  assert_eq!(status.code(), Code::Unauthenticated);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn policy_requires_role() -> anyhow::Result<()> {
+  let Tenants { server, tenant_a } = setup().await;
+  let key_id = generate_key(&tenant_a).await?;
+
+  let mut grpc_client = SigningClient::connect(server.endpoint()).await?;
+  let status = grpc_client
+    .sign(signing_request(&key_id, Some(READER_TOKEN)))
+    .await
+    .unwrap_err();
+  assert_eq!(status.code(), Code::PermissionDenied);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn tenants_cannot_use_each_others_keys() -> anyhow::Result<()> {
+  let Tenants { server, tenant_a } = setup().await;
+  let key_id = generate_key(&tenant_a).await?;
+
+  let mut grpc_client = SigningClient::connect(server.endpoint()).await?;
+  grpc_client.sign(signing_request(&key_id, Some(TENANT_A_TOKEN))).await?;
+
+  let status = grpc_client
+    .sign(signing_request(&key_id, Some(TENANT_B_TOKEN)))
+    .await
+    .unwrap_err();
+  assert_eq!(status.code(), Code::Internal);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn unlisted_rpcs_are_denied() -> anyhow::Result<()> {
+  let auth = AuthConfig::new()
+    .tenant("a", StrongholdStorage::new(make_stronghold()))
+    .bearer_token(TENANT_A_TOKEN, Principal::new("a", "alice").with_role("signer"))
+    .policy(
+      Policy::new()
+        .require_roles("/utils.Signing/*", ["signer"])
+        .deny_unlisted(),
+    );
+  let options = ServerOptions {
+    auth: Some(auth),
+    ..Default::default()
+  };
+  let server = TestServer::new_with_options(StrongholdStorage::new(make_stronghold()), options).await;
+
+  let mut req = Request::new(CreateRequest {
+    issuer: "http://example.com/issuers/1".to_owned(),
+    ..Default::default()
+  });
+  let value: MetadataValue<_> = format!("Bearer {TENANT_A_TOKEN}").parse()?;
+  req.metadata_mut().insert("authorization", value);
+
+  let mut grpc_client = StatusList2021SvcClient::connect(server.endpoint()).await?;
+  let status = grpc_client.create(req).await.unwrap_err();
+  assert_eq!(status.code(), Code::PermissionDenied);
+
+  Ok(())
+}
@@ -0,0 +1,160 @@
+// Copyright 2020-2024 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::core::FromJson;
+use identity_iota::iota::rebased::utils::request_funds;
+use identity_iota::iota::IotaDocument;
+use identity_stronghold::StrongholdStorage;
+use serde_json::json;
+use tonic::Code;
+use tonic::Request;
+
+use crate::helpers::get_address;
+use crate::helpers::make_stronghold;
+use crate::helpers::Entity;
+use crate::helpers::TestServer;
+
+use _document::document_service_client::DocumentServiceClient;
+use _document::AddMethodRequest;
+use _document::AddServiceRequest;
+use _document::CreateDidRequest;
+use _document::DeactivateDidRequest;
+use _document::MethodScope;
+use _document::RemoveMethodRequest;
+use _document::RemoveServiceRequest;
+use _document::ResolveDidRequest;
+
+mod _document {
+  tonic::include_proto!("document");
+}
+
+#[tokio::test]
+async fn did_document_update() -> anyhow::Result<()> {
+  let stronghold = StrongholdStorage::new(make_stronghold());
+  let server = TestServer::new_with_stronghold(stronghold.clone()).await;
+
+  let user = Entity::new_with_stronghold(stronghold);
+  let (user_address, key_id, _) = get_address(user.storage()).await?;
+  request_funds(&user_address).await?;
+  let controller_key_id = key_id.as_str().to_string();
+
+  let mut grpc_client = DocumentServiceClient::connect(server.endpoint()).await?;
+  let did = grpc_client
+    .create(Request::new(CreateDidRequest {
+      key_id: controller_key_id.clone(),
+    }))
+    .await?
+    .into_inner()
+    .did;
+
+  // Add and remove a verification method.
+  let response = grpc_client
+    .add_method(Request::new(AddMethodRequest {
+      did: did.clone(),
+      controller_key_id: controller_key_id.clone(),
+      fragment: Some("assertion-key".to_owned()),
+      scope: MethodScope::AssertionMethod.into(),
+    }))
+    .await?
+    .into_inner();
+  assert_eq!(response.fragment, "assertion-key");
+  let document = IotaDocument::from_json(&response.document_json)?;
+  assert!(document.resolve_method("assertion-key", None).is_some());
+
+  let response = grpc_client
+    .remove_method(Request::new(RemoveMethodRequest {
+      did: did.clone(),
+      controller_key_id: controller_key_id.clone(),
+      fragment: "assertion-key".to_owned(),
+    }))
+    .await?
+    .into_inner();
+  let document = IotaDocument::from_json(&response.document_json)?;
+  assert!(document.resolve_method("assertion-key", None).is_none());
+
+  // Add and remove a service.
+  let service = json!({
+    "id": format!("{did}#linked-domain"),
+    "type": "LinkedDomains",
+    "serviceEndpoint": "https://iota.org/",
+  });
+  grpc_client
+    .add_service(Request::new(AddServiceRequest {
+      did: did.clone(),
+      controller_key_id: controller_key_id.clone(),
+      service_json: service.to_string(),
+    }))
+    .await?;
+
+  let response = grpc_client
+    .resolve(Request::new(ResolveDidRequest {
+      did: did.clone(),
+      include_history: true,
+      history_size: None,
+    }))
+    .await?
+    .into_inner();
+  let document = IotaDocument::from_json(&response.document_json)?;
+  assert!(document.resolve_service("linked-domain").is_some());
+  assert!(response.history_json.len() > 1);
+
+  let response = grpc_client
+    .remove_service(Request::new(RemoveServiceRequest {
+      did: did.clone(),
+      controller_key_id: controller_key_id.clone(),
+      fragment: "linked-domain".to_owned(),
+    }))
+    .await?
+    .into_inner();
+  let document = IotaDocument::from_json(&response.document_json)?;
+  assert!(document.service().is_empty());
+
+  let status = grpc_client
+    .remove_service(Request::new(RemoveServiceRequest {
+      did: did.clone(),
+      controller_key_id: controller_key_id.clone(),
+      fragment: "linked-domain".to_owned(),
+    }))
+    .await
+    .unwrap_err();
+  assert_eq!(status.code(), Code::NotFound);
+
+  // Deactivate the DID.
+  grpc_client
+    .deactivate(Request::new(DeactivateDidRequest {
+      did: did.clone(),
+      controller_key_id,
+    }))
+    .await?;
+  let response = grpc_client
+    .resolve(Request::new(ResolveDidRequest {
+      did,
+      include_history: false,
+      history_size: None,
+    }))
+    .await?
+    .into_inner();
+  let document = IotaDocument::from_json(&response.document_json)?;
+  assert_eq!(document.metadata.deactivated, Some(true));
+  assert!(response.history_json.is_empty());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn did_document_resolution_of_invalid_did_fails() -> anyhow::Result<()> {
+  let server = TestServer::new().await;
+  let mut grpc_client = DocumentServiceClient::connect(server.endpoint()).await?;
+
+  let status = grpc_client
+    .resolve(Request::new(ResolveDidRequest {
+      did: "did:example:123".to_owned(),
+      include_history: false,
+      history_size: None,
+    }))
+    .await
+    .unwrap_err();
+  assert_eq!(status.code(), Code::InvalidArgument);
+
+  Ok(())
+}
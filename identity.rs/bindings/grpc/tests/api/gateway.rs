@@ -0,0 +1,134 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::body::Body;
+use axum::http::header::AUTHORIZATION;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::Request;
+use axum::http::StatusCode;
+use axum::Router;
+use identity_grpc::auth::AuthConfig;
+use identity_grpc::auth::Principal;
+use identity_grpc::gateway::Gateway;
+use identity_grpc::gateway::OPENAPI_PATH;
+use identity_iota::core::FromJson;
+use identity_iota::credential::status_list_2021::StatusList2021Credential;
+use identity_iota::credential::status_list_2021::StatusPurpose;
+use identity_stronghold::StrongholdStorage;
+use serde_json::json;
+use serde_json::Value;
+use tower::ServiceExt;
+
+use crate::helpers::make_stronghold;
+use crate::helpers::TestServer;
+
+const TOKEN: &str = "gateway-token";
+
+async fn gateway(auth: Option<AuthConfig>) -> Router {
+  let server = TestServer::new().await;
+  let stronghold = StrongholdStorage::new(make_stronghold());
+  Gateway::new(server.client(), &stronghold, auth).into_router()
+}
+
+async fn send(router: Router, req: Request<Body>) -> anyhow::Result<(StatusCode, Value)> {
+  let response = router.oneshot(req).await?;
+  let status = response.status();
+  let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+  Ok((status, serde_json::from_slice(&body)?))
+}
+
+fn post(path: &str, body: Value) -> Request<Body> {
+  Request::post(path)
+    .header(CONTENT_TYPE, "application/json")
+    .body(Body::from(body.to_string()))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn status_list_creation() -> anyhow::Result<()> {
+  let router = gateway(None).await;
+  let body = json!({
+    "purpose": 1,
+    "id": "http://example.com/credentials/status/1",
+    "issuer": "http://example.com/issuers/1",
+  });
+
+  let (status, response) = send(router, post("/v1/status-lists", body)).await?;
+  assert_eq!(status, StatusCode::OK);
+  let credential = StatusList2021Credential::from_json(response["credential_json"].as_str().unwrap())?;
+  assert_eq!(credential.purpose(), StatusPurpose::Suspension);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn rpc_errors_are_mapped_to_http_status_codes() -> anyhow::Result<()> {
+  let router = gateway(None).await;
+
+  let (status, response) = send(
+    router,
+    post(
+      "/v1/status-lists",
+      json!({ "issuer": "http://example.com/issuers/1", "length": 1 }),
+    ),
+  )
+  .await?;
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(response["code"], tonic::Code::InvalidArgument as i32);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn requests_are_authenticated() -> anyhow::Result<()> {
+  let auth = AuthConfig::new()
+    .tenant("a", StrongholdStorage::new(make_stronghold()))
+    .bearer_token(TOKEN, Principal::new("a", "alice"));
+  let router = gateway(Some(auth)).await;
+  let body = json!({ "issuer": "http://example.com/issuers/1" });
+
+  let (status, _) = send(router.clone(), post("/v1/status-lists", body.clone())).await?;
+  assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+  let mut req = post("/v1/status-lists", body);
+  req
+    .headers_mut()
+    .insert(AUTHORIZATION, format!("Bearer {TOKEN}").parse()?);
+  let (status, _) = send(router, req).await?;
+  assert_eq!(status, StatusCode::OK);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn openapi_description_is_generated_from_protos() -> anyhow::Result<()> {
+  let router = gateway(None).await;
+
+  let (status, openapi) = send(router, Request::get(OPENAPI_PATH).body(Body::empty())?).await?;
+  assert_eq!(status, StatusCode::OK);
+
+  let create_did = &openapi["paths"]["/v1/dids"]["post"];
+  assert_eq!(create_did["operationId"], "document.DocumentService.create");
+  assert_eq!(
+    create_did["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+    "#/components/schemas/document.CreateDIDRequest"
+  );
+
+  let resolve_did = &openapi["paths"]["/v1/dids/{did}"]["get"];
+  let parameters = resolve_did["parameters"].as_array().unwrap();
+  assert!(parameters
+    .iter()
+    .any(|parameter| parameter["name"] == "did" && parameter["in"] == "path"));
+  assert!(parameters
+    .iter()
+    .any(|parameter| parameter["name"] == "history_size" && parameter["in"] == "query"));
+
+  let update_request = &openapi["components"]["schemas"]["status_list_2021.UpdateRequest"];
+  assert_eq!(update_request["properties"]["entries"]["type"], "object");
+  assert_eq!(
+    update_request["properties"]["entries"]["additionalProperties"]["type"],
+    "boolean"
+  );
+
+  Ok(())
+}
@@ -17,8 +17,11 @@ async fn health_check() -> anyhow::Result<()> {
   let mut grpc_client = HealthCheckClient::connect(server.endpoint()).await?;
   let request = tonic::Request::new(HealthCheckRequest {});
 
-  let response = grpc_client.check(request).await?;
-  assert_eq!(response.into_inner(), HealthCheckResponse { status: "OK".into() });
+  let HealthCheckResponse { status, dependencies } = grpc_client.check(request).await?.into_inner();
+  assert_eq!(status, "OK");
+  let dependency_names: Vec<&str> = dependencies.iter().map(|dependency| dependency.name.as_str()).collect();
+  assert_eq!(dependency_names, ["iota_node", "storage"]);
+  assert!(dependencies.iter().all(|dependency| dependency.healthy));
 
   Ok(())
 }
@@ -19,6 +19,7 @@ use identity_storage::KeyIdStorage;
 use identity_storage::KeyType;
 use identity_storage::Storage;
 use identity_storage::StorageSigner;
+use identity_grpc::server::ServerOptions;
 use identity_stronghold::StrongholdStorage;
 use identity_iota::iota::rebased::client::IdentityClient;
 use identity_iota::iota::rebased::client::IdentityClientReadOnly;
@@ -79,6 +80,10 @@ impl TestServer {
   }
 
   pub async fn new_with_stronghold(stronghold: StrongholdStorage) -> Self {
+    Self::new_with_options(stronghold, ServerOptions::default()).await
+  }
+
+  pub async fn new_with_options(stronghold: StrongholdStorage, options: ServerOptions) -> Self {
     let _ = tracing::subscriber::set_global_default(tracing_subscriber::fmt().compact().finish());
 
     let listener = TcpListener::bind("127.0.0.1:0")
@@ -101,7 +106,8 @@ impl TestServer {
       .await
       .expect("Failed to build Identity client");
 
-    let server = identity_grpc::server::GRpcServer::new(identity_client.clone(), stronghold)
+    let server = identity_grpc::server::GRpcServer::new_with_options(identity_client.clone(), stronghold, options)
+      .expect("Failed to build gRPC server")
       .into_router()
       .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener));
     TestServer {
@@ -1,6 +1,8 @@
 // Copyright 2020-2024 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(test)]
+mod auth;
 #[cfg(test)]
 mod credential_revocation_check;
 #[cfg(test)]
@@ -8,15 +10,25 @@ mod credential_validation;
 #[cfg(test)]
 mod did_document_creation;
 #[cfg(test)]
+mod did_document_update;
+#[cfg(test)]
 mod domain_linkage;
 #[cfg(test)]
+mod gateway;
+#[cfg(test)]
 mod health_check;
 #[cfg(test)]
 mod helpers;
 #[cfg(test)]
 mod jwt;
 #[cfg(test)]
+mod metrics;
+#[cfg(test)]
+mod presentation;
+#[cfg(test)]
 mod sd_jwt_validation;
 #[cfg(test)]
+mod sd_jwt_vc;
+#[cfg(test)]
 mod status_list_2021;
 mod utils;
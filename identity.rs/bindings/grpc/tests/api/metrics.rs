@@ -0,0 +1,56 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use _health_check::health_check_client::HealthCheckClient;
+use _health_check::HealthCheckRequest;
+use _utils::signing_client::SigningClient;
+use _utils::DataSigningRequest;
+use identity_grpc::metrics::Metrics;
+use identity_grpc::server::ServerOptions;
+use identity_stronghold::StrongholdStorage;
+
+use crate::helpers::make_stronghold;
+use crate::helpers::TestServer;
+
+mod _health_check {
+  tonic::include_proto!("health_check");
+}
+
+mod _utils {
+  tonic::include_proto!("utils");
+}
+
+#[tokio::test]
+async fn rpcs_are_recorded_by_outcome() -> anyhow::Result<()> {
+  let metrics = Arc::new(Metrics::new()?);
+  let options = ServerOptions {
+    metrics: Some(metrics.clone()),
+    ..Default::default()
+  };
+  let server = TestServer::new_with_options(StrongholdStorage::new(make_stronghold()), options).await;
+
+  let mut health_client = HealthCheckClient::connect(server.endpoint()).await?;
+  health_client.check(HealthCheckRequest {}).await?;
+  health_client.check(HealthCheckRequest {}).await?;
+
+  let mut signing_client = SigningClient::connect(server.endpoint()).await?;
+  let _ = signing_client
+    .sign(DataSigningRequest {
+      data: b"data".to_vec(),
+      key_id: "missing-key".to_owned(),
+    })
+    .await
+    .unwrap_err();
+
+  let encoded = metrics.encode()?;
+  assert!(encoded.contains(
+    r#"grpc_server_handled_total{grpc_code="Ok",grpc_method="Check",grpc_service="health_check.HealthCheck"} 2"#
+  ));
+  assert!(encoded
+    .contains(r#"grpc_server_handled_total{grpc_code="Internal",grpc_method="sign",grpc_service="utils.Signing"} 1"#));
+  assert!(encoded.contains("grpc_server_handling_seconds_count"));
+
+  Ok(())
+}
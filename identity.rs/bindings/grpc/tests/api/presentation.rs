@@ -0,0 +1,116 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use _credentials::jwt_client::JwtClient;
+use _credentials::JwtCreationRequest;
+use _presentation::jwt_presentation_client::JwtPresentationClient;
+use _presentation::JwtPresentationCreationRequest;
+use _presentation::JwtPresentationValidationRequest;
+use identity_iota::core::Object;
+use identity_iota::core::Timestamp;
+use identity_iota::core::ToJson;
+use identity_iota::credential::CredentialBuilder;
+use identity_iota::credential::Jwt;
+use identity_iota::credential::Presentation;
+use identity_iota::credential::PresentationBuilder;
+use identity_iota::did::DID;
+use identity_stronghold::StrongholdStorage;
+use iota_sdk_legacy::Url;
+use serde_json::json;
+use tonic::Code;
+
+use crate::helpers::make_stronghold;
+use crate::helpers::Entity;
+use crate::helpers::TestServer;
+
+mod _credentials {
+  tonic::include_proto!("credentials");
+}
+
+mod _presentation {
+  tonic::include_proto!("presentation");
+}
+
+#[tokio::test]
+async fn jwt_presentation_creation_and_validation() -> anyhow::Result<()> {
+  let stronghold = StrongholdStorage::new(make_stronghold());
+  let server = TestServer::new_with_stronghold(stronghold.clone()).await;
+  let api_client = server.client();
+
+  let mut issuer = Entity::new_with_stronghold(stronghold.clone());
+  issuer.create_did(api_client).await?;
+
+  let mut holder = Entity::new_with_stronghold(stronghold);
+  holder.create_did(api_client).await?;
+  let holder_did = holder.document().unwrap().id().clone();
+
+  let credential = CredentialBuilder::<Object>::default()
+    .issuance_date(Timestamp::now_utc())
+    .issuer(Url::parse(issuer.document().unwrap().id().as_str())?)
+    .subject(serde_json::from_value(json!({
+        "id": holder_did.as_str(),
+        "type": "UniversityDegree",
+        "gpa": "4.0",
+    }))?)
+    .build()?;
+  let credential_jwt = JwtClient::connect(server.endpoint())
+    .await?
+    .create(JwtCreationRequest {
+      credential_json: credential.to_json()?,
+      issuer_fragment: issuer.fragment().unwrap().to_owned(),
+    })
+    .await?
+    .into_inner()
+    .jwt;
+
+  let presentation: Presentation<Jwt> = PresentationBuilder::new(holder_did.to_url().into(), Object::new())
+    .credential(Jwt::new(credential_jwt))
+    .build()?;
+
+  let mut grpc_client = JwtPresentationClient::connect(server.endpoint()).await?;
+  let presentation_jwt = grpc_client
+    .create(JwtPresentationCreationRequest {
+      presentation_json: presentation.to_json()?,
+      holder_fragment: holder.fragment().unwrap().to_owned(),
+      nonce: Some("475a7984-1bb5-4c4c-a56f-822bccd46440".to_owned()),
+      audience: Some("https://verifier.example.com/".to_owned()),
+      expiration_date: None,
+      custom_claims_json: None,
+    })
+    .await?
+    .into_inner()
+    .presentation_jwt;
+
+  let response = grpc_client
+    .validate(JwtPresentationValidationRequest {
+      presentation_jwt: presentation_jwt.clone(),
+      nonce: Some("475a7984-1bb5-4c4c-a56f-822bccd46440".to_owned()),
+      audience: Some("https://verifier.example.com/".to_owned()),
+    })
+    .await?
+    .into_inner();
+  assert_eq!(response.credentials_json.len(), 1);
+
+  // A replayed presentation doesn't contain the verifier's challenge.
+  let error = grpc_client
+    .validate(JwtPresentationValidationRequest {
+      presentation_jwt: presentation_jwt.clone(),
+      nonce: Some("another-challenge".to_owned()),
+      audience: None,
+    })
+    .await
+    .unwrap_err();
+  assert_eq!(error.code(), Code::InvalidArgument);
+
+  let error = grpc_client
+    .validate(JwtPresentationValidationRequest {
+      presentation_jwt,
+      nonce: None,
+      audience: Some("https://another-verifier.example.com/".to_owned()),
+    })
+    .await
+    .unwrap_err();
+  assert_eq!(error.code(), Code::InvalidArgument);
+
+  Ok(())
+}
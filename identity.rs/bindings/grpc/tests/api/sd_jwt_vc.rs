@@ -0,0 +1,125 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use _sd_jwt::sd_jwt_vc_service_client::SdJwtVcServiceClient;
+use _sd_jwt::SdJwtVcIssuanceRequest;
+use _sd_jwt::SdJwtVcKeyBindingOptions;
+use _sd_jwt::SdJwtVcPresentationRequest;
+use _sd_jwt::SdJwtVcVerificationRequest;
+use identity_stronghold::StrongholdStorage;
+use serde_json::json;
+use serde_json::Value;
+use tonic::Code;
+
+use crate::helpers::make_stronghold;
+use crate::helpers::Entity;
+use crate::helpers::TestServer;
+
+mod _sd_jwt {
+  tonic::include_proto!("sd_jwt");
+}
+
+#[tokio::test]
+async fn sd_jwt_vc_issuance_presentation_and_verification() -> anyhow::Result<()> {
+  let stronghold = StrongholdStorage::new(make_stronghold());
+  let server = TestServer::new_with_stronghold(stronghold.clone()).await;
+  let api_client = server.client();
+
+  let mut issuer = Entity::new_with_stronghold(stronghold.clone());
+  issuer.create_did(api_client).await?;
+
+  let mut holder = Entity::new_with_stronghold(stronghold);
+  holder.create_did(api_client).await?;
+  let holder_method_id = format!("{}#{}", holder.document().unwrap().id(), holder.fragment().unwrap());
+
+  let mut grpc_client = SdJwtVcServiceClient::connect(server.endpoint()).await?;
+  let sd_jwt_vc = grpc_client
+    .issue(SdJwtVcIssuanceRequest {
+      claims_json: json!({
+        "name": "Alice",
+        "address": {
+          "locality": "Maxstadt",
+          "street_address": "Weidenstraße 22"
+        }
+      })
+      .to_string(),
+      issuer_did: issuer.document().unwrap().id().to_string(),
+      issuer_fragment: issuer.fragment().unwrap().to_owned(),
+      vct: "https://example.com/address_credential".to_owned(),
+      concealable_paths: vec!["/address/locality".to_owned(), "/address/street_address".to_owned()],
+      decoys: [("/address".to_owned(), 2)].into_iter().collect(),
+      holder_method_id: Some(holder_method_id.clone()),
+      status_json: None,
+      sub: None,
+      expiration_date: None,
+    })
+    .await?
+    .into_inner()
+    .sd_jwt_vc;
+
+  let presentation = grpc_client
+    .present(SdJwtVcPresentationRequest {
+      sd_jwt_vc,
+      concealed_paths: vec!["/address/street_address".to_owned()],
+      holder_method_id: Some(holder_method_id),
+      nonce: Some("nonce-1234".to_owned()),
+      aud: Some("https://verifier.example.com".to_owned()),
+    })
+    .await?
+    .into_inner()
+    .sd_jwt_vc;
+
+  let verification_request = |nonce: &str| SdJwtVcVerificationRequest {
+    sd_jwt_vc: presentation.clone(),
+    type_metadata_json: json!({
+      "name": "AddressCredential",
+      "schema": {
+        "type": "object",
+        "required": ["name", "address"]
+      }
+    })
+    .to_string(),
+    resources_json: Default::default(),
+    kb_options: Some(SdJwtVcKeyBindingOptions {
+      nonce: Some(nonce.to_owned()),
+      aud: Some("https://verifier.example.com".to_owned()),
+      earliest_issuance_date: None,
+      latest_issuance_date: None,
+    }),
+  };
+
+  let claims_json = grpc_client
+    .verify(verification_request("nonce-1234"))
+    .await?
+    .into_inner()
+    .claims_json;
+  let claims: Value = serde_json::from_str(&claims_json)?;
+  assert_eq!(claims["address"]["locality"], "Maxstadt");
+  assert!(claims["address"].get("street_address").is_none());
+
+  let error = grpc_client
+    .verify(verification_request("another-nonce"))
+    .await
+    .unwrap_err();
+  assert_eq!(error.code(), Code::InvalidArgument);
+
+  // The credential is bound to the holder's key: a presentation stripped of its KB-JWT is rejected,
+  // whether the verifier expects key binding or not.
+  let stripped = presentation[..=presentation.rfind('~').unwrap()].to_owned();
+  let with_kb_options = SdJwtVcVerificationRequest {
+    sd_jwt_vc: stripped.clone(),
+    ..verification_request("nonce-1234")
+  };
+  let without_kb_options = SdJwtVcVerificationRequest {
+    sd_jwt_vc: stripped,
+    kb_options: None,
+    ..verification_request("nonce-1234")
+  };
+  for request in [with_kb_options, without_kb_options] {
+    let error = grpc_client.verify(request).await.unwrap_err();
+    assert_eq!(error.code(), Code::InvalidArgument);
+    assert!(error.message().contains("missing key binding JWT"));
+  }
+
+  Ok(())
+}
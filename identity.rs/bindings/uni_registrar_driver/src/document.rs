@@ -0,0 +1,318 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions between the DID documents of registration requests and [`IotaDocument`]s.
+//!
+//! Requests carry DID documents as plain JSON: identifiers may be relative to the DID (`#key-1`), verification
+//! methods may omit their controller and list their verification relationships in a `purpose` array, and
+//! verification methods without public key material are templates of keys to be generated by the driver.
+
+use identity_iota::core::FromJson;
+use identity_iota::did::DID;
+use identity_iota::document::CoreDocument;
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::IotaDocument;
+use identity_iota::iota::IotaDocumentMetadata;
+use identity_iota::verification::MethodRelationship;
+use serde::Deserialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::Error;
+
+/// Properties holding the public key material of a verification method.
+const PUBLIC_KEY_PROPERTIES: [&str; 3] = ["publicKeyJwk", "publicKeyMultibase", "publicKeyBase58"];
+/// Verification method types the driver can generate keys for.
+const TEMPLATE_TYPES: [&str; 2] = ["JsonWebKey", "JsonWebKey2020"];
+/// Verification relationships, by their DID document property.
+const RELATIONSHIPS: [(&str, MethodRelationship); 5] = [
+  ("authentication", MethodRelationship::Authentication),
+  ("assertionMethod", MethodRelationship::AssertionMethod),
+  ("keyAgreement", MethodRelationship::KeyAgreement),
+  ("capabilityDelegation", MethodRelationship::CapabilityDelegation),
+  ("capabilityInvocation", MethodRelationship::CapabilityInvocation),
+];
+
+/// Operations of an update request, each applied to the DID document with the DID document at the same index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DidDocumentOperation {
+  /// Replaces the DID document.
+  SetDidDocument,
+  /// Appends array entries, e.g. verification methods and services, and sets the other properties.
+  AddToDidDocument,
+  /// Removes array entries with the same identifier, and removes the other properties.
+  RemoveFromDidDocument,
+}
+
+/// A verification method without public key material, whose Ed25519 key is to be generated by the driver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyTemplate {
+  pub fragment: String,
+  pub relationships: Vec<MethodRelationship>,
+}
+
+/// Applies `operation` with the `input` DID document to `document`, a DID document of `did` as JSON.
+pub fn apply_operation(
+  did: &IotaDID,
+  document: &mut Value,
+  operation: DidDocumentOperation,
+  input: Value,
+) -> Result<(), Error> {
+  if operation == DidDocumentOperation::SetDidDocument {
+    *document = input;
+    return Ok(());
+  }
+
+  let (Value::Object(document), Value::Object(input)) = (document, input) else {
+    return Err(Error::InvalidDidDocument(
+      "DID documents must be JSON objects".to_owned(),
+    ));
+  };
+  for (property, value) in input {
+    if property == "id" || property == "@context" {
+      continue;
+    }
+    match (operation, value, document.get_mut(&property)) {
+      (DidDocumentOperation::AddToDidDocument, Value::Array(entries), Some(Value::Array(current))) => {
+        current.extend(entries)
+      }
+      (DidDocumentOperation::AddToDidDocument, value, _) => {
+        document.insert(property, value);
+      }
+      (DidDocumentOperation::RemoveFromDidDocument, Value::Array(entries), Some(Value::Array(current))) => {
+        let removed: Vec<String> = entries.iter().filter_map(|entry| entry_id(did, entry)).collect();
+        current.retain(|entry| entry_id(did, entry).map_or(true, |id| !removed.contains(&id)));
+        if property == "verificationMethod" {
+          // Don't leave references to removed verification methods behind.
+          for (relationship, _) in RELATIONSHIPS {
+            if let Some(Value::Array(references)) = document.get_mut(relationship) {
+              references.retain(|entry| {
+                entry
+                  .as_str()
+                  .map_or(true, |id| !removed.contains(&absolute_id(did, id)))
+              });
+            }
+          }
+        }
+      }
+      (DidDocumentOperation::RemoveFromDidDocument, _, _) => {
+        document.remove(&property);
+      }
+      (DidDocumentOperation::SetDidDocument, _, _) => unreachable!(),
+    }
+  }
+
+  Ok(())
+}
+
+/// Converts `document`, a DID document of `did` as JSON, into an [`IotaDocument`], extracting the templates of the
+/// keys to generate.
+pub fn prepare_document(did: &IotaDID, document: Value) -> Result<(IotaDocument, Vec<KeyTemplate>), Error> {
+  let Value::Object(mut document) = document else {
+    return Err(Error::InvalidDidDocument(
+      "DID documents must be JSON objects".to_owned(),
+    ));
+  };
+  document.remove("@context");
+  match document.get("id") {
+    Some(id) if id.as_str() != Some(did.as_str()) => {
+      return Err(Error::InvalidDidDocument(format!(
+        "the DID document's id must be {did}"
+      )));
+    }
+    _ => {
+      document.insert("id".to_owned(), did.to_string().into());
+    }
+  }
+
+  let mut templates: Vec<KeyTemplate> = Vec::new();
+  // References added for the `purpose` of verification methods, by property.
+  let mut references: Vec<(&str, String)> = Vec::new();
+
+  if let Some(methods) = document.remove("verificationMethod") {
+    let Value::Array(methods) = methods else {
+      return Err(Error::InvalidDidDocument(
+        "verificationMethod must be an array".to_owned(),
+      ));
+    };
+    let mut kept = Vec::with_capacity(methods.len());
+    for method in methods {
+      let (method, purpose) = normalize_method(did, method)?;
+      let id = method["id"].as_str().unwrap_or_default().to_owned();
+      if is_template(&method) {
+        templates.push(template(did, &method, purpose)?);
+      } else {
+        references.extend(
+          purpose
+            .into_iter()
+            .map(|relationship| (property(relationship), id.clone())),
+        );
+        kept.push(Value::Object(method));
+      }
+    }
+    document.insert("verificationMethod".to_owned(), Value::Array(kept));
+  }
+
+  for (property, relationship) in RELATIONSHIPS {
+    let Some(entries) = document.remove(property) else {
+      continue;
+    };
+    let Value::Array(entries) = entries else {
+      return Err(Error::InvalidDidDocument(format!("{property} must be an array")));
+    };
+    let mut kept = Vec::with_capacity(entries.len());
+    for entry in entries {
+      match entry {
+        Value::String(id) => kept.push(Value::String(absolute_id(did, &id))),
+        method => {
+          let (method, mut purpose) = normalize_method(did, method)?;
+          if is_template(&method) {
+            purpose.insert(0, relationship);
+            templates.push(template(did, &method, purpose)?);
+          } else {
+            kept.push(Value::Object(method));
+          }
+        }
+      }
+    }
+    document.insert(property.to_owned(), Value::Array(kept));
+  }
+
+  // References to templates become relationships of the generated methods.
+  for (property, relationship) in RELATIONSHIPS {
+    if let Some(Value::Array(entries)) = document.get_mut(property) {
+      entries.retain(|entry| {
+        let Some(template) = entry
+          .as_str()
+          .and_then(|id| fragment(did, id))
+          .and_then(|fragment| templates.iter_mut().find(|template| template.fragment == fragment))
+        else {
+          return true;
+        };
+        if !template.relationships.contains(&relationship) {
+          template.relationships.push(relationship);
+        }
+        false
+      });
+    }
+  }
+  for (property, id) in references {
+    let entries = document
+      .entry(property.to_owned())
+      .or_insert_with(|| Value::Array(Vec::new()));
+    if let Value::Array(entries) = entries {
+      entries.push(id.into());
+    }
+  }
+
+  if let Some(Value::Array(services)) = document.get_mut("service") {
+    for service in services {
+      if let Some(Value::String(id)) = service.get_mut("id") {
+        *id = absolute_id(did, id);
+      }
+    }
+  }
+
+  let document = CoreDocument::from_json_value(Value::Object(document))
+    .map_err(|error| Error::InvalidDidDocument(error.to_string()))?;
+  let document = IotaDocument::try_from((document, IotaDocumentMetadata::new()))
+    .map_err(|error| Error::InvalidDidDocument(error.to_string()))?;
+
+  Ok((document, templates))
+}
+
+/// Makes the id of a verification method absolute, sets its controller to `did` if missing and removes its
+/// `purpose`, which is returned.
+fn normalize_method(did: &IotaDID, method: Value) -> Result<(Map<String, Value>, Vec<MethodRelationship>), Error> {
+  let Value::Object(mut method) = method else {
+    return Err(Error::InvalidDidDocument(
+      "verification methods must be JSON objects".to_owned(),
+    ));
+  };
+  let id = method
+    .get("id")
+    .and_then(Value::as_str)
+    .map(|id| absolute_id(did, id))
+    .ok_or_else(|| Error::InvalidDidDocument("verification methods must have an id".to_owned()))?;
+  method.insert("id".to_owned(), id.into());
+  method.entry("controller").or_insert_with(|| did.to_string().into());
+
+  let purpose = match method.remove("purpose") {
+    None => Vec::new(),
+    Some(Value::Array(purpose)) => purpose
+      .iter()
+      .map(|purpose| {
+        purpose
+          .as_str()
+          .and_then(|purpose| RELATIONSHIPS.iter().find(|(property, _)| *property == purpose))
+          .map(|(_, relationship)| *relationship)
+          .ok_or_else(|| Error::InvalidDidDocument(format!("unknown verification method purpose {purpose}")))
+      })
+      .collect::<Result<_, _>>()?,
+    Some(_) => return Err(Error::InvalidDidDocument("purpose must be an array".to_owned())),
+  };
+
+  Ok((method, purpose))
+}
+
+fn is_template(method: &Map<String, Value>) -> bool {
+  !PUBLIC_KEY_PROPERTIES
+    .iter()
+    .any(|property| method.contains_key(*property))
+}
+
+fn template(
+  did: &IotaDID,
+  method: &Map<String, Value>,
+  relationships: Vec<MethodRelationship>,
+) -> Result<KeyTemplate, Error> {
+  let id = method["id"].as_str().unwrap_or_default();
+  if let Some(method_type) = method.get("type").and_then(Value::as_str) {
+    if !TEMPLATE_TYPES.contains(&method_type) {
+      return Err(Error::InvalidDidDocument(format!(
+        "keys can't be generated for the verification method {id} of type {method_type}"
+      )));
+    }
+  }
+  let fragment = fragment(did, id)
+    .ok_or_else(|| Error::InvalidDidDocument(format!("the verification method {id} doesn't belong to {did}")))?;
+
+  Ok(KeyTemplate {
+    fragment: fragment.to_owned(),
+    relationships,
+  })
+}
+
+/// Returns the DID document property of `relationship`.
+fn property(relationship: MethodRelationship) -> &'static str {
+  RELATIONSHIPS
+    .iter()
+    .find(|(_, r)| *r == relationship)
+    .map(|(property, _)| *property)
+    .expect("every relationship has a property")
+}
+
+/// Resolves an identifier relative to `did`, e.g. `#key-1`.
+fn absolute_id(did: &IotaDID, id: &str) -> String {
+  if id.starts_with('#') {
+    format!("{did}{id}")
+  } else {
+    id.to_owned()
+  }
+}
+
+/// Returns the fragment of `id`, if it's a DID URL of `did`.
+fn fragment<'i>(did: &IotaDID, id: &'i str) -> Option<&'i str> {
+  id.strip_prefix('#')
+    .or_else(|| id.strip_prefix(did.as_str())?.strip_prefix('#'))
+}
+
+/// Identifies an array entry: references and other strings by themselves, objects by their `id`.
+fn entry_id(did: &IotaDID, entry: &Value) -> Option<String> {
+  match entry {
+    Value::String(id) => Some(absolute_id(did, id)),
+    Value::Object(object) => object.get("id").and_then(Value::as_str).map(|id| absolute_id(did, id)),
+    _ => None,
+  }
+}
@@ -0,0 +1,862 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! [DIF Universal Registrar](https://github.com/decentralized-identity/universal-registrar) driver for `did:iota`.
+//!
+//! The driver serves `POST /1.0/create`, `POST /1.0/update` and `POST /1.0/deactivate`. The transactions carrying
+//! out a registration are either signed with a key of the driver's Stronghold storage (internal secret mode), or by
+//! the client, which is answered with a signing request and resumes the job with its signature (client-managed
+//! secret mode).
+
+mod document;
+mod signing;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+use axum::extract::State;
+use axum::http::header::AUTHORIZATION;
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::routing::post;
+use axum::Json;
+use axum::Router;
+use identity_iota::did::DID;
+use identity_iota::iota::rebased::client::get_object_id_from_did;
+use identity_iota::iota::rebased::client::IdentityClient;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_iota::iota::rebased::migration::Identity;
+use identity_iota::iota::rebased::migration::IdentityBuilder;
+use identity_iota::iota::rebased::migration::OnChainIdentity;
+use identity_iota::iota::rebased::proposals::ProposalResult;
+use identity_iota::iota::rebased::transaction::Transaction;
+use identity_iota::iota::rebased::transaction_builder::TransactionBuilder;
+use identity_iota::iota::rebased::unsigned_transaction::UnsignedTransaction;
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::IotaDocument;
+use identity_iota::iota_interaction::types::base_types::IotaAddress;
+use identity_iota::iota_interaction::types::crypto::PublicKey;
+use identity_iota::iota_interaction::types::crypto::Signature;
+use identity_iota::storage::JwkDocumentExt;
+use identity_iota::storage::KeyId;
+use identity_iota::storage::Storage;
+use identity_iota::storage::StorageSigner;
+use identity_iota::verification::jws::JwsAlgorithm;
+use identity_iota::verification::MethodScope;
+use identity_stronghold::StrongholdKeyType;
+use identity_stronghold::StrongholdStorage;
+use identity_stronghold::ED25519_KEY_TYPE;
+use serde::Deserialize;
+use serde_json::json;
+use serde_json::Value;
+
+pub use document::*;
+pub use signing::*;
+
+/// Path of the creation endpoint.
+pub const CREATE_PATH: &str = "/1.0/create";
+/// Path of the update endpoint.
+pub const UPDATE_PATH: &str = "/1.0/update";
+/// Path of the deactivation endpoint.
+pub const DEACTIVATE_PATH: &str = "/1.0/deactivate";
+/// Gas budget of the registration transactions, unless configured otherwise.
+pub const DEFAULT_GAS_BUDGET: u64 = 50_000_000;
+/// Number of registrations that can await the client's signature at once, unless configured otherwise.
+pub const DEFAULT_MAX_JOBS: usize = 1024;
+
+const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+/// Time after which registrations awaiting the client's signature are dropped.
+const JOB_TTL: Duration = Duration::from_secs(60 * 60);
+
+type StrongholdSigner<'s> = StorageSigner<'s, StrongholdStorage, StrongholdStorage>;
+
+/// Creates the driver's HTTP router.
+pub fn router(registrar: Registrar) -> Router {
+  Router::new()
+    .route(CREATE_PATH, post(create))
+    .route(UPDATE_PATH, post(update))
+    .route(DEACTIVATE_PATH, post(deactivate))
+    .with_state(Arc::new(registrar))
+}
+
+/// Registration errors, reported as the `error` of a failed DID state.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+  #[error("invalid options: {0}")]
+  InvalidOptions(String),
+  #[error("\"{0}\" is not a valid did:iota DID")]
+  InvalidDid(String),
+  #[error("invalid DID document: {0}")]
+  InvalidDidDocument(String),
+  #[error("invalid signature: {0}")]
+  InvalidSignature(String),
+  #[error("no registration awaits a signature for job {0}")]
+  UnknownJob(String),
+  #[error("{0}")]
+  NotFound(String),
+  #[error("{0}")]
+  Unauthorized(String),
+  #[error("the internal secret mode requires a valid bearer token")]
+  Unauthenticated,
+  #[error("too many registrations await a signature, retry later")]
+  TooManyJobs,
+  #[error("storage error: {0}")]
+  Storage(String),
+  #[error(transparent)]
+  Ledger(identity_iota::iota::rebased::Error),
+  /// The registration's transaction failed, or its outcome couldn't be processed.
+  #[error(transparent)]
+  Execution(identity_iota::iota::rebased::Error),
+}
+
+impl Error {
+  /// The error code of the failed DID state.
+  pub fn code(&self) -> &'static str {
+    match self {
+      Self::InvalidOptions(_) => "invalidOptions",
+      Self::InvalidDid(_) => "invalidDid",
+      Self::InvalidDidDocument(_) => "invalidDidDocument",
+      Self::InvalidSignature(_) => "invalidSignature",
+      Self::UnknownJob(_) => "unknownJob",
+      Self::NotFound(_) => "notFound",
+      Self::Unauthorized(_) => "unauthorized",
+      Self::Unauthenticated => "unauthenticated",
+      Self::TooManyJobs => "tooManyJobs",
+      Self::Storage(_) | Self::Ledger(_) | Self::Execution(_) => "internalError",
+    }
+  }
+
+  fn status_code(&self) -> StatusCode {
+    match self {
+      Self::InvalidOptions(_) | Self::InvalidDid(_) | Self::InvalidDidDocument(_) | Self::InvalidSignature(_) => {
+        StatusCode::BAD_REQUEST
+      }
+      Self::UnknownJob(_) | Self::NotFound(_) => StatusCode::NOT_FOUND,
+      Self::Unauthorized(_) => StatusCode::FORBIDDEN,
+      Self::Unauthenticated => StatusCode::UNAUTHORIZED,
+      Self::TooManyJobs => StatusCode::SERVICE_UNAVAILABLE,
+      Self::Storage(_) | Self::Ledger(_) | Self::Execution(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+  }
+
+  /// Returns whether the transaction of a registration that failed with this error may have been executed.
+  fn may_have_executed(&self) -> bool {
+    use identity_iota::iota::rebased::Error as LedgerError;
+
+    // Building, gas and signing errors happen before the transaction is submitted.
+    matches!(self, Self::Execution(error) if !matches!(
+      error,
+      LedgerError::TransactionBuildingFailed(_) | LedgerError::TransactionSigningFailed(_) | LedgerError::GasIssue(_)
+    ))
+  }
+}
+
+impl IntoResponse for Error {
+  fn into_response(self) -> Response {
+    let body = json!({
+      "jobId": null,
+      "didState": {
+        "state": "failed",
+        "error": self.code(),
+        "reason": self.to_string(),
+      },
+      "didRegistrationMetadata": {},
+      "didDocumentMetadata": {},
+    });
+
+    (self.status_code(), Json(body)).into_response()
+  }
+}
+
+/// Options of a registration request.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Options {
+  /// Whether the client signs the transactions, instead of the driver.
+  #[serde(default)]
+  client_secret_mode: bool,
+  /// Key of the storage controlling the identity, in internal secret mode. Ignored unless allowed by the
+  /// operator.
+  controller_key_id: Option<String>,
+  /// Base64 encoded IOTA public key controlling the identity, in client-managed secret mode.
+  controller_public_key: Option<String>,
+  /// Network of the DID to create.
+  network: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Secret {
+  #[serde(default)]
+  signing_response: HashMap<String, SigningResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateRequest {
+  job_id: Option<String>,
+  #[serde(default)]
+  options: Options,
+  #[serde(default)]
+  secret: Secret,
+  did_document: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdateRequest {
+  job_id: Option<String>,
+  did: Option<String>,
+  #[serde(default)]
+  options: Options,
+  #[serde(default)]
+  secret: Secret,
+  #[serde(default = "default_operations")]
+  did_document_operation: Vec<DidDocumentOperation>,
+  #[serde(default)]
+  did_document: Vec<Value>,
+}
+
+fn default_operations() -> Vec<DidDocumentOperation> {
+  vec![DidDocumentOperation::SetDidDocument]
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DeactivateRequest {
+  job_id: Option<String>,
+  did: Option<String>,
+  #[serde(default)]
+  options: Options,
+  #[serde(default)]
+  secret: Secret,
+}
+
+/// The state of a registration, see <https://identity.foundation/did-registration/#didstate>.
+#[derive(Debug)]
+enum DidState {
+  /// The registration's transaction was executed.
+  Finished(IotaDocument),
+  /// The client must sign the registration's transaction.
+  Action {
+    did: Option<IotaDID>,
+    signing_request: SigningRequest,
+  },
+  /// The registration awaits the approval of the identity's other controllers.
+  Wait { did: IotaDID, proposal_id: String },
+}
+
+/// The result of a registration request.
+#[derive(Debug)]
+struct Registration {
+  job_id: Option<String>,
+  state: DidState,
+}
+
+impl IntoResponse for Registration {
+  fn into_response(self) -> Response {
+    let (did_state, registration_metadata, document_metadata) = match self.state {
+      DidState::Finished(document) => {
+        let mut did_document =
+          serde_json::to_value(document.core_document()).expect("DID documents are serializable to JSON");
+        if let Value::Object(did_document) = &mut did_document {
+          did_document.insert("@context".to_owned(), DID_CONTEXT.into());
+        }
+        (
+          json!({
+            "state": "finished",
+            "did": document.id(),
+            "didDocument": did_document,
+          }),
+          json!({}),
+          json!(document.metadata),
+        )
+      }
+      DidState::Action { did, signing_request } => (
+        json!({
+          "state": "action",
+          "action": "signPayload",
+          "did": did,
+          "signingRequest": HashMap::from([(SIGNING_REQUEST_ID, signing_request)]),
+        }),
+        json!({}),
+        json!({}),
+      ),
+      DidState::Wait { did, proposal_id } => (
+        json!({
+          "state": "wait",
+          "did": did,
+          "wait": "the update awaits the approval of the identity's other controllers",
+        }),
+        json!({ "proposalId": proposal_id }),
+        json!({}),
+      ),
+    };
+
+    Json(json!({
+      "jobId": self.job_id,
+      "didState": did_state,
+      "didRegistrationMetadata": registration_metadata,
+      "didDocumentMetadata": document_metadata,
+    }))
+    .into_response()
+  }
+}
+
+/// A registration, as carried out by a transaction.
+#[derive(Debug, Clone)]
+enum Operation {
+  Create(IotaDocument),
+  Update(IotaDocument),
+  Deactivate(IotaDID),
+}
+
+impl Operation {
+  /// The DID of the registration, unknown until an identity is created.
+  fn did(&self) -> Option<IotaDID> {
+    match self {
+      Self::Create(_) => None,
+      Self::Update(document) => Some(document.id().clone()),
+      Self::Deactivate(did) => Some(did.clone()),
+    }
+  }
+}
+
+/// A registration awaiting the client's signature.
+#[derive(Debug, Clone)]
+struct Job {
+  operation: Operation,
+  tx: UnsignedTransaction,
+  public_key: PublicKey,
+  created: Instant,
+}
+
+/// How a registration's transaction gets signed.
+enum Authorization<'a> {
+  /// Signed with a key of the storage.
+  Storage(&'a IdentityClient<StrongholdSigner<'a>>),
+  /// Exported for the client to sign it.
+  Export(PublicKey),
+  /// Exported earlier and signed by the client.
+  Signed(UnsignedTransaction, Signature),
+}
+
+enum Outcome<T> {
+  Executed(T),
+  Exported(Registration),
+}
+
+/// Registers `did:iota` DIDs on the network of its client.
+pub struct Registrar {
+  client: IdentityClientReadOnly,
+  storage: Option<StrongholdStorage>,
+  controller_key_id: Option<String>,
+  allowed_controller_key_ids: HashSet<String>,
+  auth_tokens: Vec<String>,
+  gas_budget: u64,
+  max_jobs: usize,
+  jobs: Mutex<HashMap<String, Job>>,
+}
+
+impl Registrar {
+  /// Creates a registrar supporting only the client-managed secret mode.
+  pub fn new(client: IdentityClientReadOnly) -> Self {
+    Self {
+      client,
+      storage: None,
+      controller_key_id: None,
+      allowed_controller_key_ids: HashSet::new(),
+      auth_tokens: Vec::new(),
+      gas_budget: DEFAULT_GAS_BUDGET,
+      max_jobs: DEFAULT_MAX_JOBS,
+      jobs: Mutex::default(),
+    }
+  }
+
+  /// Enables the internal secret mode, signing with the Ed25519 key `controller_key_id` of `storage` unless a
+  /// request names a key allowed by [`Registrar::with_allowed_controller_key_ids`].
+  ///
+  /// Requests in internal secret mode must be authenticated with one of the tokens added by
+  /// [`Registrar::with_auth_token`].
+  pub fn with_storage(mut self, storage: StrongholdStorage, controller_key_id: Option<String>) -> Self {
+    self.storage = Some(storage);
+    self.controller_key_id = controller_key_id;
+    self
+  }
+
+  /// Allows requests to name the keys `key_ids` as `controllerKeyId`, in internal secret mode.
+  pub fn with_allowed_controller_key_ids<I, S>(mut self, key_ids: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    self
+      .allowed_controller_key_ids
+      .extend(key_ids.into_iter().map(Into::into));
+    self
+  }
+
+  /// Authenticates requests bearing `token` (`Authorization: Bearer <token>`) for the internal secret mode.
+  pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+    self.auth_tokens.push(token.into());
+    self
+  }
+
+  /// Sets the number of registrations that can await the client's signature at once.
+  pub fn with_max_jobs(mut self, max_jobs: usize) -> Self {
+    self.max_jobs = max_jobs;
+    self
+  }
+
+  /// Sets the gas budget of the registration transactions.
+  pub fn with_gas_budget(mut self, gas_budget: u64) -> Self {
+    self.gas_budget = gas_budget;
+    self
+  }
+
+  /// Whether `headers` carry one of the tokens of the internal secret mode.
+  fn is_authenticated(&self, headers: &HeaderMap) -> bool {
+    let Some(token) = headers
+      .get(AUTHORIZATION)
+      .and_then(|value| value.to_str().ok())
+      .and_then(|value| value.strip_prefix("Bearer "))
+    else {
+      return false;
+    };
+    self
+      .auth_tokens
+      .iter()
+      .any(|expected| constant_time_eq(expected.as_bytes(), token.as_bytes()))
+  }
+
+  async fn create(&self, request: CreateRequest, authenticated: bool) -> Result<Registration, Error> {
+    if let Some(job_id) = request.job_id {
+      return self.resume(job_id, request.secret).await;
+    }
+    if let Some(network) = &request.options.network {
+      if network != self.client.network().as_ref() {
+        return Err(Error::InvalidOptions(format!(
+          "the network \"{network}\" is not supported"
+        )));
+      }
+    }
+
+    let document = request.did_document.unwrap_or_else(|| json!({}));
+    if document.get("id").is_some() {
+      return Err(Error::InvalidDidDocument(
+        "the DID is derived from the created identity and can't be chosen".to_owned(),
+      ));
+    }
+    let did = IotaDID::placeholder(self.client.network());
+    let (document, templates) = prepare_document(&did, document)?;
+
+    self
+      .register(Operation::Create(document), &templates, &request.options, authenticated)
+      .await
+  }
+
+  async fn update(&self, request: UpdateRequest, authenticated: bool) -> Result<Registration, Error> {
+    if let Some(job_id) = request.job_id {
+      return self.resume(job_id, request.secret).await;
+    }
+    if request.did_document_operation.len() != request.did_document.len() {
+      return Err(Error::InvalidDidDocument(
+        "didDocumentOperation and didDocument must have the same length".to_owned(),
+      ));
+    }
+
+    let did = parse_did(request.did)?;
+    let current = self.resolve(&did).await?;
+    let mut document = serde_json::to_value(current.core_document()).expect("DID documents are serializable to JSON");
+    for (operation, input) in request.did_document_operation.into_iter().zip(request.did_document) {
+      apply_operation(&did, &mut document, operation, input)?;
+    }
+    let (mut document, templates) = prepare_document(&did, document)?;
+    document.metadata = current.metadata;
+
+    self
+      .register(Operation::Update(document), &templates, &request.options, authenticated)
+      .await
+  }
+
+  async fn deactivate(&self, request: DeactivateRequest, authenticated: bool) -> Result<Registration, Error> {
+    if let Some(job_id) = request.job_id {
+      return self.resume(job_id, request.secret).await;
+    }
+    let did = parse_did(request.did)?;
+
+    self
+      .register(Operation::Deactivate(did), &[], &request.options, authenticated)
+      .await
+  }
+
+  /// Carries out `operation` in the secret mode of `options`, generating the keys of `templates` in internal secret
+  /// mode, which requires the request to be `authenticated`.
+  async fn register(
+    &self,
+    operation: Operation,
+    templates: &[KeyTemplate],
+    options: &Options,
+    authenticated: bool,
+  ) -> Result<Registration, Error> {
+    if options.client_secret_mode {
+      if let Some(template) = templates.first() {
+        return Err(Error::InvalidDidDocument(format!(
+          "the verification method #{} has no public key material, which is only generated in internal secret mode",
+          template.fragment
+        )));
+      }
+      let public_key = options.controller_public_key.as_deref().ok_or_else(|| {
+        Error::InvalidOptions("controllerPublicKey is required in client-managed secret mode".to_owned())
+      })?;
+      let public_key = decode_public_key(public_key)?;
+      let controller = IotaAddress::from(&public_key);
+
+      return self
+        .execute(operation, controller, Authorization::Export(public_key))
+        .await;
+    }
+
+    if !authenticated {
+      return Err(Error::Unauthenticated);
+    }
+    let stronghold = self.storage.as_ref().ok_or_else(|| {
+      Error::InvalidOptions("the driver has no storage, use the client-managed secret mode".to_owned())
+    })?;
+    let requested_key_id = options
+      .controller_key_id
+      .as_ref()
+      .filter(|key_id| self.allowed_controller_key_ids.contains(*key_id));
+    if options.controller_key_id.is_some() && requested_key_id.is_none() {
+      tracing::warn!("ignoring a controllerKeyId that isn't allowed");
+    }
+    let key_id = requested_key_id
+      .or(self.controller_key_id.as_ref())
+      .ok_or_else(|| Error::InvalidOptions("controllerKeyId is required in internal secret mode".to_owned()))?;
+    let storage = Storage::new(stronghold.clone(), stronghold.clone());
+    let client = self.identity_client(&storage, key_id).await?;
+
+    let (operation, mut generated) = match operation {
+      Operation::Create(mut document) => {
+        generate_keys(&mut document, templates, &storage).await?;
+        (Operation::Create(document.clone()), Some(document))
+      }
+      Operation::Update(mut document) => {
+        generate_keys(&mut document, templates, &storage).await?;
+        (Operation::Update(document.clone()), Some(document))
+      }
+      operation => (operation, None),
+    };
+
+    let result = self
+      .execute(operation, client.sender_address(), Authorization::Storage(&client))
+      .await;
+    if let (Err(error), Some(document)) = (&result, &mut generated) {
+      // Don't leave behind keys of methods that were never published. Keys are kept when the transaction
+      // may have been executed, as their methods might be published.
+      if error.may_have_executed() {
+        tracing::warn!(
+          "keeping the keys generated for {}, its transaction may have been executed",
+          document.id()
+        );
+      } else {
+        purge_keys(document, templates, &storage).await;
+      }
+    }
+
+    result
+  }
+
+  /// Executes the transaction carrying out `operation` on behalf of `controller`.
+  async fn execute(
+    &self,
+    operation: Operation,
+    controller: IotaAddress,
+    authorization: Authorization<'_>,
+  ) -> Result<Registration, Error> {
+    let did = match &operation {
+      Operation::Create(document) => {
+        let tx = IdentityBuilder::new(document.clone())
+          .controller(controller, 1)
+          .threshold(1)
+          .finish();
+        return match self.authorize(tx, authorization, &operation).await? {
+          Outcome::Executed(identity) => Ok(Registration {
+            job_id: None,
+            state: DidState::Finished(identity.did_document().clone()),
+          }),
+          Outcome::Exported(registration) => Ok(registration),
+        };
+      }
+      Operation::Update(document) => document.id().clone(),
+      Operation::Deactivate(did) => did.clone(),
+    };
+
+    let mut identity = self.identity(&did).await?;
+    let controller_token = identity
+      .get_controller_token_for_address(controller, &self.client)
+      .await
+      .map_err(Error::Ledger)?
+      .ok_or_else(|| Error::Unauthorized(format!("address {controller} has no control over {did}")))?;
+    let proposal = match &operation {
+      Operation::Update(document) => identity.update_did_document(document.clone(), &controller_token),
+      _ => identity.deactivate_did(&controller_token),
+    };
+    let tx = proposal.finish(&self.client).await.map_err(Error::Ledger)?;
+
+    let state = match self.authorize(tx, authorization, &operation).await? {
+      // The update is executed: failing to resolve it doesn't mean the transaction failed.
+      Outcome::Executed(ProposalResult::Executed(_)) => {
+        DidState::Finished(self.client.resolve_did(&did).await.map_err(Error::Execution)?)
+      }
+      Outcome::Executed(ProposalResult::Pending(proposal)) => DidState::Wait {
+        did,
+        proposal_id: proposal.id().to_string(),
+      },
+      Outcome::Exported(registration) => return Ok(registration),
+    };
+
+    Ok(Registration { job_id: None, state })
+  }
+
+  /// Signs and executes `tx`, or exports it for the client to sign it.
+  async fn authorize<Tx>(
+    &self,
+    tx: TransactionBuilder<Tx>,
+    authorization: Authorization<'_>,
+    operation: &Operation,
+  ) -> Result<Outcome<Tx::Output>, Error>
+  where
+    Tx: Transaction,
+  {
+    let tx = tx.with_gas_budget(self.gas_budget);
+    let output = match authorization {
+      Authorization::Storage(client) => tx.build_and_execute(client).await,
+      Authorization::Export(public_key) => {
+        let mut tx = tx.with_sender(IotaAddress::from(&public_key));
+        let unsigned = tx.export_unsigned(&self.client).await.map_err(Error::Ledger)?;
+        return self
+          .store_job(operation.clone(), unsigned, public_key)
+          .map(Outcome::Exported);
+      }
+      Authorization::Signed(unsigned, signature) => {
+        TransactionBuilder::from_unsigned_transaction(unsigned, tx.into_inner())
+          .and_then(|tx| tx.with_external_signature(signature))
+          .map_err(|error| Error::InvalidSignature(error.to_string()))?
+          .execute(&self.client)
+          .await
+      }
+    };
+
+    output
+      .map(|output| Outcome::Executed(output.output))
+      .map_err(Error::Execution)
+  }
+
+  /// Keeps `tx` until the client signs it, returning the signing request.
+  fn store_job(
+    &self,
+    operation: Operation,
+    tx: UnsignedTransaction,
+    public_key: PublicKey,
+  ) -> Result<Registration, Error> {
+    let signing_request = SigningRequest::new(&tx, &public_key)?;
+    let job_id: String = tx
+      .signing_digest()
+      .map_err(Error::Ledger)?
+      .iter()
+      .map(|byte| format!("{byte:02x}"))
+      .collect();
+    let did = operation.did();
+
+    let mut jobs = self.jobs.lock().expect("the job store isn't poisoned");
+    jobs.retain(|_, job| job.created.elapsed() < JOB_TTL);
+    if jobs.len() >= self.max_jobs && !jobs.contains_key(&job_id) {
+      return Err(Error::TooManyJobs);
+    }
+    jobs.insert(
+      job_id.clone(),
+      Job {
+        operation,
+        tx,
+        public_key,
+        created: Instant::now(),
+      },
+    );
+
+    Ok(Registration {
+      job_id: Some(job_id),
+      state: DidState::Action { did, signing_request },
+    })
+  }
+
+  /// Executes the transaction of the job `job_id` with the client's signature.
+  async fn resume(&self, job_id: String, secret: Secret) -> Result<Registration, Error> {
+    let job = self
+      .jobs
+      .lock()
+      .expect("the job store isn't poisoned")
+      .get(&job_id)
+      .filter(|job| job.created.elapsed() < JOB_TTL)
+      .cloned()
+      .ok_or_else(|| Error::UnknownJob(job_id.clone()))?;
+    let signature = secret
+      .signing_response
+      .get(SIGNING_REQUEST_ID)
+      .ok_or_else(|| Error::InvalidSignature(format!("secret.signingResponse.{SIGNING_REQUEST_ID} is missing")))?;
+    let signature = decode_signature(&signature.signature, &job.public_key)?;
+    let controller = IotaAddress::from(&job.public_key);
+
+    let mut registration = self
+      .execute(job.operation, controller, Authorization::Signed(job.tx, signature))
+      .await?;
+    self.jobs.lock().expect("the job store isn't poisoned").remove(&job_id);
+    registration.job_id = Some(job_id);
+
+    Ok(registration)
+  }
+
+  async fn identity_client<'s>(
+    &self,
+    storage: &'s Storage<StrongholdStorage, StrongholdStorage>,
+    key_id: &str,
+  ) -> Result<IdentityClient<StrongholdSigner<'s>>, Error> {
+    let key_id = KeyId::new(key_id);
+    let public_key = storage
+      .key_id_storage()
+      .get_public_key_with_type(&key_id, StrongholdKeyType::Ed25519)
+      .await
+      .map_err(|error| Error::Storage(error.to_string()))?;
+
+    IdentityClient::new(self.client.clone(), StorageSigner::new(storage, key_id, public_key))
+      .await
+      .map_err(Error::Ledger)
+  }
+
+  async fn resolve(&self, did: &IotaDID) -> Result<IotaDocument, Error> {
+    self
+      .client
+      .resolve_did(did)
+      .await
+      .map_err(|error| Error::NotFound(error.to_string()))
+  }
+
+  async fn identity(&self, did: &IotaDID) -> Result<OnChainIdentity, Error> {
+    let object_id = get_object_id_from_did(did).map_err(|_| Error::InvalidDid(did.to_string()))?;
+    match self.client.get_identity(object_id).await.map_err(Error::Ledger)? {
+      Identity::FullFledged(identity) => Ok(identity),
+      _ => Err(Error::Unauthorized(format!(
+        "{did} is controlled by a legacy alias output and can't be updated"
+      ))),
+    }
+  }
+}
+
+/// Compares `a` and `b` in a time that doesn't depend on where they differ.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+  a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+fn parse_did(did: Option<String>) -> Result<IotaDID, Error> {
+  let did = did.ok_or_else(|| Error::InvalidDid(String::new()))?;
+  IotaDID::parse(&did).map_err(|_| Error::InvalidDid(did))
+}
+
+/// Generates the Ed25519 keys of `templates` in `storage`, inserting their methods into `document`.
+async fn generate_keys(
+  document: &mut IotaDocument,
+  templates: &[KeyTemplate],
+  storage: &Storage<StrongholdStorage, StrongholdStorage>,
+) -> Result<(), Error> {
+  for (generated, template) in templates.iter().enumerate() {
+    if let Err(error) = generate_key(document, template, storage).await {
+      purge_keys(document, &templates[..generated], storage).await;
+      return Err(error);
+    }
+  }
+
+  Ok(())
+}
+
+async fn generate_key(
+  document: &mut IotaDocument,
+  template: &KeyTemplate,
+  storage: &Storage<StrongholdStorage, StrongholdStorage>,
+) -> Result<(), Error> {
+  document
+    .generate_method(
+      storage,
+      ED25519_KEY_TYPE.clone(),
+      JwsAlgorithm::EdDSA,
+      Some(&template.fragment),
+      MethodScope::VerificationMethod,
+    )
+    .await
+    .map_err(|error| Error::Storage(error.to_string()))?;
+
+  let method_id = document
+    .id()
+    .to_url()
+    .join(format!("#{}", template.fragment))
+    .map_err(|error| Error::InvalidDidDocument(error.to_string()))?;
+  for relationship in &template.relationships {
+    document
+      .attach_method_relationship(&method_id, *relationship)
+      .map_err(|error| Error::InvalidDidDocument(error.to_string()))?;
+  }
+
+  Ok(())
+}
+
+async fn purge_keys(
+  document: &mut IotaDocument,
+  templates: &[KeyTemplate],
+  storage: &Storage<StrongholdStorage, StrongholdStorage>,
+) {
+  for template in templates {
+    if let Ok(method_id) = document.id().to_url().join(format!("#{}", template.fragment)) {
+      let _ = document.purge_method(storage, &method_id).await;
+    }
+  }
+}
+
+#[tracing::instrument(skip_all, err)]
+async fn create(
+  State(registrar): State<Arc<Registrar>>,
+  headers: HeaderMap,
+  Json(request): Json<CreateRequest>,
+) -> Result<Response, Error> {
+  let registration = registrar.create(request, registrar.is_authenticated(&headers)).await?;
+  let status_code = match registration.state {
+    DidState::Finished(_) => StatusCode::CREATED,
+    _ => StatusCode::OK,
+  };
+
+  Ok((status_code, registration).into_response())
+}
+
+#[tracing::instrument(skip_all, err)]
+async fn update(
+  State(registrar): State<Arc<Registrar>>,
+  headers: HeaderMap,
+  Json(request): Json<UpdateRequest>,
+) -> Result<Registration, Error> {
+  registrar.update(request, registrar.is_authenticated(&headers)).await
+}
+
+#[tracing::instrument(skip_all, err)]
+async fn deactivate(
+  State(registrar): State<Arc<Registrar>>,
+  headers: HeaderMap,
+  Json(request): Json<DeactivateRequest>,
+) -> Result<Registration, Error> {
+  registrar
+    .deactivate(request, registrar.is_authenticated(&headers))
+    .await
+}
@@ -0,0 +1,89 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use anyhow::Context;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_stronghold::StrongholdStorage;
+use identity_uni_registrar_driver::Registrar;
+use iota_sdk::types::base_types::ObjectID;
+use iota_sdk_legacy::client::stronghold::StrongholdAdapter;
+
+const DEFAULT_LISTEN_ADDRESS: &str = "0.0.0.0:8080";
+
+#[tokio::main]
+#[tracing::instrument(err)]
+async fn main() -> anyhow::Result<()> {
+  tracing::subscriber::set_global_default(tracing_subscriber::fmt().compact().finish())
+    .expect("Failed to setup global tracing subscriber.");
+
+  let api_endpoint = std::env::var("API_ENDPOINT").context("API_ENDPOINT must be set")?;
+  let iota_client = iota_sdk::IotaClientBuilder::default().build(&api_endpoint).await?;
+  let client = match std::env::var("IDENTITY_IOTA_PKG_ID") {
+    Ok(identity_pkg_id) => {
+      IdentityClientReadOnly::new_with_pkg_id(iota_client, ObjectID::from_str(&identity_pkg_id)?).await?
+    }
+    Err(_) => IdentityClientReadOnly::new(iota_client).await?,
+  };
+  tracing::info!(
+    "registering DIDs of network \"{}\" through {}",
+    client.network().as_ref(),
+    api_endpoint
+  );
+
+  let mut registrar = Registrar::new(client);
+  if let Ok(snapshot_path) = std::env::var("SNAPSHOT_PATH") {
+    let password = secret_var("STRONGHOLD_PWD")?.context("STRONGHOLD_PWD or STRONGHOLD_PWD_FILE must be set")?;
+    let stronghold = StrongholdAdapter::builder()
+      .password(password)
+      .build(&snapshot_path)
+      .map(StrongholdStorage::new)
+      .with_context(|| format!("failed to open the Stronghold snapshot {snapshot_path}"))?;
+    registrar = registrar.with_storage(stronghold, std::env::var("CONTROLLER_KEY_ID").ok());
+    if let Ok(key_ids) = std::env::var("ALLOWED_CONTROLLER_KEY_IDS") {
+      registrar = registrar
+        .with_allowed_controller_key_ids(key_ids.split(',').map(str::trim).filter(|key_id| !key_id.is_empty()));
+    }
+    match secret_var("AUTH_TOKEN")? {
+      Some(token) => registrar = registrar.with_auth_token(token),
+      None => tracing::warn!("AUTH_TOKEN is not set, requests in internal secret mode will be rejected"),
+    }
+    tracing::info!(
+      "internal secret mode enabled with the Stronghold snapshot {}",
+      snapshot_path
+    );
+  }
+  if let Ok(gas_budget) = std::env::var("GAS_BUDGET") {
+    registrar = registrar.with_gas_budget(gas_budget.parse().context("GAS_BUDGET must be an integer")?);
+  }
+  if let Ok(max_jobs) = std::env::var("MAX_JOBS") {
+    registrar = registrar.with_max_jobs(max_jobs.parse().context("MAX_JOBS must be an integer")?);
+  }
+
+  let addr: SocketAddr = std::env::var("LISTEN_ADDRESS")
+    .unwrap_or_else(|_| DEFAULT_LISTEN_ADDRESS.to_owned())
+    .parse()?;
+  let router = identity_uni_registrar_driver::router(registrar);
+
+  tracing::info!("Universal Registrar driver listening on {}", addr);
+  let listener = tokio::net::TcpListener::bind(addr).await?;
+  axum::serve(listener, router)
+    .with_graceful_shutdown(async {
+      let _ = tokio::signal::ctrl_c().await;
+    })
+    .await?;
+
+  Ok(())
+}
+
+/// Reads the secret in the file named by the environment variable `<name>_FILE`, or in the variable `name` itself.
+fn secret_var(name: &str) -> anyhow::Result<Option<String>> {
+  if let Ok(file) = std::env::var(format!("{name}_FILE")) {
+    let secret = std::fs::read_to_string(&file).with_context(|| format!("failed to read {name} from {file}"))?;
+    return Ok(Some(secret.trim().to_owned()));
+  }
+
+  Ok(std::env::var(name).ok())
+}
@@ -0,0 +1,87 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Signing requests and responses of the client-managed secret mode.
+
+use fastcrypto::encoding::Base64;
+use fastcrypto::encoding::Encoding;
+use fastcrypto::traits::EncodeDecodeBase64;
+use fastcrypto::traits::ToFromBytes;
+use identity_iota::iota::rebased::unsigned_transaction::UnsignedTransaction;
+use identity_iota::iota_interaction::types::base_types::IotaAddress;
+use identity_iota::iota_interaction::types::crypto::PublicKey;
+use identity_iota::iota_interaction::types::crypto::Signature;
+use identity_iota::iota_interaction::types::crypto::SignatureScheme;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::Error;
+
+/// Identifier of the request to sign a registration's transaction, in `signingRequest` and `signingResponse`.
+pub const SIGNING_REQUEST_ID: &str = "signingRequestTx";
+
+/// A request to sign the transaction carrying out a registration.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SigningRequest {
+  /// The transaction, as base64 encoded BCS, e.g. for `iota keytool sign --data`.
+  pub payload: Value,
+  /// The digest to sign, as base64.
+  pub serialized_payload: String,
+  /// The address of the controller, whose key must sign the transaction.
+  pub kid: String,
+  pub alg: &'static str,
+  pub purpose: &'static str,
+}
+
+impl SigningRequest {
+  /// Requests the signature of `tx` by the key `public_key`.
+  pub fn new(tx: &UnsignedTransaction, public_key: &PublicKey) -> Result<Self, Error> {
+    let tx_bytes = tx.to_bcs().map_err(Error::Ledger)?;
+    let digest = tx.signing_digest().map_err(Error::Ledger)?;
+
+    Ok(Self {
+      payload: json!({ "transactionData": Base64::encode(tx_bytes) }),
+      serialized_payload: Base64::encode(digest),
+      kid: IotaAddress::from(public_key).to_string(),
+      alg: jws_algorithm(public_key.scheme()),
+      purpose: "capabilityInvocation",
+    })
+  }
+}
+
+/// The signature of a [`SigningRequest`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct SigningResponse {
+  pub signature: String,
+}
+
+/// Decodes the base64 `signature` made with `public_key`: either an IOTA signature - flag || signature || public key,
+/// as output by `iota keytool sign` - or a raw signature.
+pub fn decode_signature(signature: &str, public_key: &PublicKey) -> Result<Signature, Error> {
+  let bytes = Base64::decode(signature.trim())
+    .map_err(|_| Error::InvalidSignature("the signature must be base64 encoded".to_owned()))?;
+  let bytes = if bytes.first() == Some(&public_key.flag()) && bytes.ends_with(public_key.as_ref()) {
+    bytes
+  } else {
+    [&[public_key.flag()], bytes.as_slice(), public_key.as_ref()].concat()
+  };
+
+  Signature::from_bytes(&bytes).map_err(|error| Error::InvalidSignature(error.to_string()))
+}
+
+/// Decodes a base64 IOTA public key - flag || public key.
+pub fn decode_public_key(public_key: &str) -> Result<PublicKey, Error> {
+  PublicKey::decode_base64(public_key.trim())
+    .map_err(|_| Error::InvalidOptions("controllerPublicKey must be a base64 encoded IOTA public key".to_owned()))
+}
+
+fn jws_algorithm(scheme: SignatureScheme) -> &'static str {
+  match scheme {
+    SignatureScheme::Secp256k1 => "ES256K",
+    SignatureScheme::Secp256r1 | SignatureScheme::PasskeyAuthenticator => "ES256",
+    _ => "EdDSA",
+  }
+}
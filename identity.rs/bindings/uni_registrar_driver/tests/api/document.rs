@@ -0,0 +1,140 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::did::DID;
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::NetworkName;
+use identity_iota::verification::MethodRelationship;
+use identity_iota::verification::MethodScope;
+use identity_uni_registrar_driver::apply_operation;
+use identity_uni_registrar_driver::prepare_document;
+use identity_uni_registrar_driver::DidDocumentOperation;
+use identity_uni_registrar_driver::KeyTemplate;
+use serde_json::json;
+
+const DID: &str = "did:iota:0x0101010101010101010101010101010101010101010101010101010101010101";
+const JWK: &str = r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+
+fn did() -> IotaDID {
+  IotaDID::parse(DID).unwrap()
+}
+
+#[test]
+fn relative_ids_and_controllers_are_resolved() -> anyhow::Result<()> {
+  let (document, templates) = prepare_document(
+    &did(),
+    json!({
+      "@context": "https://www.w3.org/ns/did/v1",
+      "verificationMethod": [{
+        "id": "#key-1",
+        "type": "JsonWebKey2020",
+        "publicKeyJwk": serde_json::from_str::<serde_json::Value>(JWK)?,
+        "purpose": ["authentication", "assertionMethod"],
+      }],
+      "service": [{ "id": "#linked-domain", "type": "LinkedDomains", "serviceEndpoint": "https://example.com/" }],
+    }),
+  )?;
+
+  assert!(templates.is_empty());
+  assert_eq!(document.id(), &did());
+  let method = document
+    .resolve_method("key-1", Some(MethodScope::authentication()))
+    .expect("the method is an authentication method");
+  assert_eq!(method.id().to_string(), format!("{DID}#key-1"));
+  assert_eq!(method.controller().as_str(), DID);
+  assert!(document
+    .resolve_method("key-1", Some(MethodScope::assertion_method()))
+    .is_some());
+  assert!(document.resolve_service("linked-domain").is_some());
+
+  Ok(())
+}
+
+#[test]
+fn methods_without_key_material_are_templates() -> anyhow::Result<()> {
+  let (document, templates) = prepare_document(
+    &did(),
+    json!({
+      "verificationMethod": [{ "id": "#key-1", "type": "JsonWebKey2020", "purpose": ["assertionMethod"] }],
+      "authentication": ["#key-1", { "id": "#key-2" }],
+    }),
+  )?;
+
+  assert!(document.core_document().verification_method().is_empty());
+  assert!(document.core_document().authentication().is_empty());
+  assert!(document.core_document().assertion_method().is_empty());
+  assert_eq!(
+    templates,
+    vec![
+      KeyTemplate {
+        fragment: "key-1".to_owned(),
+        relationships: vec![MethodRelationship::AssertionMethod, MethodRelationship::Authentication],
+      },
+      KeyTemplate {
+        fragment: "key-2".to_owned(),
+        relationships: vec![MethodRelationship::Authentication],
+      },
+    ]
+  );
+
+  let unsupported = prepare_document(
+    &did(),
+    json!({ "verificationMethod": [{ "id": "#key-1", "type": "EcdsaSecp256k1VerificationKey2019" }] }),
+  );
+  assert!(unsupported.is_err());
+
+  Ok(())
+}
+
+#[test]
+fn documents_of_other_dids_are_rejected() {
+  let other = IotaDID::placeholder(&NetworkName::try_from("iota".to_owned()).unwrap());
+  assert!(prepare_document(&did(), json!({ "id": other.to_string() })).is_err());
+  assert!(prepare_document(&did(), json!({ "id": DID })).is_ok());
+  assert!(prepare_document(&did(), json!([])).is_err());
+}
+
+#[test]
+fn operations_add_and_remove_entries() -> anyhow::Result<()> {
+  let did = did();
+  let mut document = json!({
+    "id": DID,
+    "verificationMethod": [{
+      "id": format!("{DID}#key-1"),
+      "controller": DID,
+      "type": "JsonWebKey2020",
+      "publicKeyJwk": serde_json::from_str::<serde_json::Value>(JWK)?,
+    }],
+    "authentication": [format!("{DID}#key-1")],
+  });
+
+  apply_operation(
+    &did,
+    &mut document,
+    DidDocumentOperation::AddToDidDocument,
+    json!({
+      "service": [{ "id": "#linked-domain", "type": "LinkedDomains", "serviceEndpoint": "https://example.com/" }],
+      "verificationMethod": [{ "id": "#key-2", "purpose": ["keyAgreement"] }],
+    }),
+  )?;
+  let (prepared, templates) = prepare_document(&did, document.clone())?;
+  assert!(prepared.resolve_method("key-1", None).is_some());
+  assert!(prepared.resolve_service("linked-domain").is_some());
+  assert_eq!(templates[0].fragment, "key-2");
+
+  apply_operation(
+    &did,
+    &mut document,
+    DidDocumentOperation::RemoveFromDidDocument,
+    json!({ "verificationMethod": [{ "id": "#key-1" }], "service": ["#linked-domain"] }),
+  )?;
+  let (prepared, _) = prepare_document(&did, document.clone())?;
+  assert!(prepared.resolve_method("key-1", None).is_none());
+  assert!(prepared.core_document().authentication().is_empty());
+  assert!(prepared.resolve_service("linked-domain").is_none());
+
+  apply_operation(&did, &mut document, DidDocumentOperation::SetDidDocument, json!({}))?;
+  assert_eq!(document, json!({}));
+
+  Ok(())
+}
@@ -0,0 +1,9 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod document;
+#[cfg(test)]
+mod registrar;
+#[cfg(test)]
+mod signing;
@@ -0,0 +1,110 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::body::Body;
+use axum::http::header::AUTHORIZATION;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::Request;
+use axum::http::StatusCode;
+use axum::Router;
+use fastcrypto::encoding::Base64;
+use fastcrypto::encoding::Encoding;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_iota::iota::rebased::local_ledger::LocalLedger;
+use identity_iota::iota_interaction::types::base_types::IotaAddress;
+use identity_iota::iota_interaction::types::crypto::PublicKey;
+use identity_iota::iota_interaction::types::crypto::SignatureScheme;
+use identity_uni_registrar_driver::Registrar;
+use identity_uni_registrar_driver::CREATE_PATH;
+use serde_json::json;
+use serde_json::Value;
+use tower::ServiceExt;
+
+const TOKEN: &str = "registrar-token";
+// Test vector 1 of RFC 8032.
+const PUBLIC_KEY: [u8; 32] = [
+  0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9, 0x64, 0x07, 0x3a, 0x0e, 0xe1, 0x72,
+  0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68, 0xf7, 0x07, 0x51, 0x1a,
+];
+
+/// Returns a registrar of a new local ledger, on which the controller of [`PUBLIC_KEY`] owns gas.
+async fn registrar() -> anyhow::Result<Registrar> {
+  let ledger = LocalLedger::new();
+  let public_key = PublicKey::try_from_bytes(SignatureScheme::ED25519, &PUBLIC_KEY)?;
+  ledger.request_funds(IotaAddress::from(&public_key));
+  let client = IdentityClientReadOnly::new_with_local_ledger(ledger).await?;
+
+  Ok(Registrar::new(client))
+}
+
+async fn post(router: Router, body: Value, token: Option<&str>) -> anyhow::Result<(StatusCode, Value)> {
+  let mut req = Request::post(CREATE_PATH).header(CONTENT_TYPE, "application/json");
+  if let Some(token) = token {
+    req = req.header(AUTHORIZATION, format!("Bearer {token}"));
+  }
+  let response = router.oneshot(req.body(Body::from(body.to_string()))?).await?;
+  let status = response.status();
+  let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+
+  Ok((status, serde_json::from_slice(&body)?))
+}
+
+fn client_secret_mode_request(service_endpoint: &str) -> Value {
+  let public_key = [&[SignatureScheme::ED25519.flag()], PUBLIC_KEY.as_slice()].concat();
+  json!({
+    "options": { "clientSecretMode": true, "controllerPublicKey": Base64::encode(public_key) },
+    "didDocument": {
+      "service": [{ "id": "#linked-domain", "type": "LinkedDomains", "serviceEndpoint": service_endpoint }]
+    },
+  })
+}
+
+#[tokio::test]
+async fn internal_secret_mode_requires_authentication() -> anyhow::Result<()> {
+  let router = identity_uni_registrar_driver::router(registrar().await?.with_auth_token(TOKEN));
+
+  let (status, body) = post(router.clone(), json!({ "didDocument": {} }), None).await?;
+  assert_eq!(status, StatusCode::UNAUTHORIZED);
+  assert_eq!(body["didState"]["error"], "unauthenticated");
+
+  let (status, _) = post(router.clone(), json!({ "didDocument": {} }), Some("other-token")).await?;
+  assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+  // Authenticated requests get through, and fail since the driver has no storage.
+  let (status, body) = post(router, json!({ "didDocument": {} }), Some(TOKEN)).await?;
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["didState"]["error"], "invalidOptions");
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn internal_secret_mode_is_unavailable_without_tokens() -> anyhow::Result<()> {
+  let router = identity_uni_registrar_driver::router(registrar().await?);
+
+  let (status, _) = post(router, json!({ "didDocument": {} }), Some(TOKEN)).await?;
+  assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn jobs_are_limited() -> anyhow::Result<()> {
+  let router = identity_uni_registrar_driver::router(registrar().await?.with_max_jobs(1));
+
+  let (status, body) = post(
+    router.clone(),
+    client_secret_mode_request("https://a.example.com"),
+    None,
+  )
+  .await?;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["didState"]["state"], "action");
+  assert!(body["jobId"].is_string());
+
+  let (status, body) = post(router, client_secret_mode_request("https://b.example.com"), None).await?;
+  assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+  assert_eq!(body["didState"]["error"], "tooManyJobs");
+
+  Ok(())
+}
@@ -0,0 +1,63 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::encoding::Base64;
+use fastcrypto::encoding::Encoding;
+use identity_iota::iota_interaction::types::crypto::PublicKey;
+use identity_iota::iota_interaction::types::crypto::SignatureScheme;
+use identity_uni_registrar_driver::decode_public_key;
+use identity_uni_registrar_driver::decode_signature;
+
+// Test vector 1 of RFC 8032.
+const PUBLIC_KEY: [u8; 32] = [
+  0xd7, 0x5a, 0x98, 0x01, 0x82, 0xb1, 0x0a, 0xb7, 0xd5, 0x4b, 0xfe, 0xd3, 0xc9, 0x64, 0x07, 0x3a, 0x0e, 0xe1, 0x72,
+  0xf3, 0xda, 0xa6, 0x23, 0x25, 0xaf, 0x02, 0x1a, 0x68, 0xf7, 0x07, 0x51, 0x1a,
+];
+const SIGNATURE: [u8; 64] = [
+  0xe5, 0x56, 0x43, 0x00, 0xc3, 0x60, 0xac, 0x72, 0x90, 0x86, 0xe2, 0xcc, 0x80, 0x6e, 0x82, 0x8a, 0x84, 0x87, 0x7f,
+  0x1e, 0xb8, 0xe5, 0xd9, 0x74, 0xd8, 0x73, 0xe0, 0x65, 0x22, 0x49, 0x01, 0x55, 0x5f, 0xb8, 0x82, 0x15, 0x90, 0xa3,
+  0x3b, 0xac, 0xc6, 0x1e, 0x39, 0x70, 0x1c, 0xf9, 0xb4, 0x6b, 0xd2, 0x5b, 0xf5, 0xf0, 0x59, 0x5b, 0xbe, 0x24, 0x65,
+  0x51, 0x41, 0x43, 0x8e, 0x7a, 0x10, 0x0b,
+];
+
+fn public_key() -> PublicKey {
+  PublicKey::try_from_bytes(SignatureScheme::ED25519, &PUBLIC_KEY).unwrap()
+}
+
+fn iota_signature() -> Vec<u8> {
+  [
+    &[SignatureScheme::ED25519.flag()],
+    SIGNATURE.as_slice(),
+    PUBLIC_KEY.as_slice(),
+  ]
+  .concat()
+}
+
+#[test]
+fn raw_signatures_are_completed_with_the_public_key() -> anyhow::Result<()> {
+  let signature = decode_signature(&Base64::encode(SIGNATURE), &public_key())?;
+  assert_eq!(signature.as_ref(), iota_signature().as_slice());
+
+  Ok(())
+}
+
+#[test]
+fn iota_signatures_are_decoded() -> anyhow::Result<()> {
+  let signature = decode_signature(&Base64::encode(iota_signature()), &public_key())?;
+  assert_eq!(signature.as_ref(), iota_signature().as_slice());
+
+  assert!(decode_signature("not base64!", &public_key()).is_err());
+  assert!(decode_signature(&Base64::encode([0; 12]), &public_key()).is_err());
+
+  Ok(())
+}
+
+#[test]
+fn public_keys_are_decoded() -> anyhow::Result<()> {
+  let encoded = Base64::encode([&[SignatureScheme::ED25519.flag()], PUBLIC_KEY.as_slice()].concat());
+  assert_eq!(decode_public_key(&encoded)?, public_key());
+
+  assert!(decode_public_key(&Base64::encode(PUBLIC_KEY)).is_err());
+
+  Ok(())
+}
@@ -0,0 +1,206 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! [DIF Universal Resolver](https://github.com/decentralized-identity/universal-resolver) driver for `did:iota`.
+//!
+//! The driver serves `GET /1.0/identifiers/{did}` and answers with either the DID document or a
+//! [DID resolution result](https://w3c-ccg.github.io/did-resolution/#did-resolution-result), depending on the
+//! request's `Accept` header.
+
+use std::sync::Arc;
+
+use axum::extract::Path;
+use axum::extract::State;
+use axum::http::header;
+use axum::http::HeaderMap;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::response::Response;
+use axum::routing::get;
+use axum::Router;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use identity_iota::iota::IotaDocument;
+use identity_iota::resolver::ErrorCause;
+use identity_iota::resolver::Resolver;
+use serde_json::json;
+use serde_json::Value;
+
+/// Path of the resolution endpoint.
+pub const RESOLVE_PATH: &str = "/1.0/identifiers/:did";
+/// Media type of a DID document in its JSON-LD representation.
+pub const DID_LD_JSON: &str = "application/did+ld+json";
+/// Media type of a DID resolution result.
+pub const RESOLUTION_RESULT: &str = "application/ld+json;profile=\"https://w3id.org/did-resolution\"";
+
+const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+const RESOLUTION_CONTEXT: &str = "https://w3id.org/did-resolution/v1";
+const RESOLUTION_PROFILE: &str = "https://w3id.org/did-resolution";
+
+/// Resolver of IOTA DID documents.
+pub type IotaResolver = Resolver<IotaDocument>;
+
+/// Creates a resolver dispatching every DID to the client of the network named in the DID.
+pub fn resolver(clients: impl IntoIterator<Item = IdentityClientReadOnly>) -> IotaResolver {
+  let clients = clients
+    .into_iter()
+    .map(|client| (client.network().as_ref().to_owned(), client));
+
+  let mut resolver = IotaResolver::new();
+  resolver.attach_multiple_iota_handlers(clients);
+  resolver
+}
+
+/// Creates the driver's HTTP router.
+pub fn router(resolver: IotaResolver) -> Router {
+  Router::new()
+    .route(RESOLVE_PATH, get(resolve))
+    .with_state(Arc::new(resolver))
+}
+
+/// DID resolution errors, named after the ones of the DID resolution specification.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+  #[error("\"{0}\" is not a valid DID")]
+  InvalidDid(String),
+  #[error("the DID method \"{0}\" is not supported")]
+  MethodNotSupported(String),
+  #[error("{0}")]
+  NotFound(String),
+  #[error("none of the requested representations is supported")]
+  RepresentationNotSupported,
+  #[error(transparent)]
+  Internal(identity_iota::resolver::Error),
+}
+
+impl Error {
+  fn new(did: &str, error: identity_iota::resolver::Error) -> Self {
+    match error.error_cause() {
+      ErrorCause::DIDParsingError { .. } => Self::InvalidDid(did.to_owned()),
+      ErrorCause::UnsupportedMethodError { method } => Self::MethodNotSupported(method.clone()),
+      ErrorCause::UnsupportedNetwork(network) => Self::NotFound(format!("the network \"{network}\" is not supported")),
+      ErrorCause::HandlerError { source, .. } => match source.downcast_ref::<identity_iota::iota::Error>() {
+        Some(identity_iota::iota::Error::DIDResolutionError(message)) => Self::NotFound(message.clone()),
+        _ => Self::Internal(error),
+      },
+      _ => Self::Internal(error),
+    }
+  }
+
+  /// The error code of the DID resolution metadata.
+  pub fn code(&self) -> &'static str {
+    match self {
+      Self::InvalidDid(_) => "invalidDid",
+      Self::MethodNotSupported(_) => "methodNotSupported",
+      Self::NotFound(_) => "notFound",
+      Self::RepresentationNotSupported => "representationNotSupported",
+      Self::Internal(_) => "internalError",
+    }
+  }
+
+  fn status_code(&self) -> StatusCode {
+    match self {
+      Self::InvalidDid(_) => StatusCode::BAD_REQUEST,
+      Self::MethodNotSupported(_) => StatusCode::NOT_IMPLEMENTED,
+      Self::NotFound(_) => StatusCode::NOT_FOUND,
+      Self::RepresentationNotSupported => StatusCode::NOT_ACCEPTABLE,
+      Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+  }
+}
+
+impl IntoResponse for Error {
+  fn into_response(self) -> Response {
+    let body = json!({
+      "@context": RESOLUTION_CONTEXT,
+      "didDocument": null,
+      "didResolutionMetadata": {
+        "error": self.code(),
+        "errorMessage": self.to_string(),
+      },
+      "didDocumentMetadata": {},
+    });
+
+    (
+      self.status_code(),
+      [(header::CONTENT_TYPE, RESOLUTION_RESULT)],
+      body.to_string(),
+    )
+      .into_response()
+  }
+}
+
+/// Representations of a resolved DID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Representation {
+  DidDocument,
+  ResolutionResult,
+}
+
+impl Representation {
+  /// Picks the first supported media range of the `Accept` header. Quality values are ignored, and a missing header
+  /// selects the resolution result.
+  fn negotiate(headers: &HeaderMap) -> Result<Self, Error> {
+    let Some(accept) = headers.get(header::ACCEPT) else {
+      return Ok(Self::ResolutionResult);
+    };
+    let accept = accept.to_str().map_err(|_| Error::RepresentationNotSupported)?;
+
+    accept
+      .split(',')
+      .find_map(|media_range| {
+        let mut parts = media_range.split(';').map(str::trim);
+        match parts.next()? {
+          DID_LD_JSON => Some(Self::DidDocument),
+          "application/ld+json" => parts
+            .filter_map(|parameter| parameter.split_once('='))
+            .any(|(name, value)| name.trim() == "profile" && value.trim().trim_matches('"') == RESOLUTION_PROFILE)
+            .then_some(Self::ResolutionResult),
+          "application/json" | "application/*" | "*/*" => Some(Self::ResolutionResult),
+          _ => None,
+        }
+      })
+      .ok_or(Error::RepresentationNotSupported)
+  }
+}
+
+#[tracing::instrument(skip_all, fields(did = %did), err)]
+async fn resolve(
+  State(resolver): State<Arc<IotaResolver>>,
+  Path(did): Path<String>,
+  headers: HeaderMap,
+) -> Result<Response, Error> {
+  let representation = Representation::negotiate(&headers)?;
+  let parsed_did = identity_iota::did::CoreDID::parse(&did).map_err(|_| Error::InvalidDid(did.clone()))?;
+  let document = resolver
+    .resolve(&parsed_did)
+    .await
+    .map_err(|error| Error::new(&did, error))?;
+
+  let mut did_document =
+    serde_json::to_value(document.core_document()).expect("DID documents are serializable to JSON");
+  if let Value::Object(did_document) = &mut did_document {
+    did_document.insert("@context".to_owned(), DID_CONTEXT.into());
+  }
+
+  // Deactivated DIDs still resolve, but are answered with `410 Gone` like the other drivers do.
+  let status_code = if document.metadata.deactivated == Some(true) {
+    StatusCode::GONE
+  } else {
+    StatusCode::OK
+  };
+
+  let (content_type, body) = match representation {
+    Representation::DidDocument => (DID_LD_JSON, did_document),
+    Representation::ResolutionResult => (
+      RESOLUTION_RESULT,
+      json!({
+        "@context": RESOLUTION_CONTEXT,
+        "didDocument": did_document,
+        "didResolutionMetadata": { "contentType": DID_LD_JSON },
+        "didDocumentMetadata": document.metadata,
+      }),
+    ),
+  };
+
+  Ok((status_code, [(header::CONTENT_TYPE, content_type)], body.to_string()).into_response())
+}
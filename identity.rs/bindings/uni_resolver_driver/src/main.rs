@@ -0,0 +1,60 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use anyhow::Context;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+use iota_sdk::types::base_types::ObjectID;
+
+const DEFAULT_LISTEN_ADDRESS: &str = "0.0.0.0:8080";
+
+#[tokio::main]
+#[tracing::instrument(err)]
+async fn main() -> anyhow::Result<()> {
+  tracing::subscriber::set_global_default(tracing_subscriber::fmt().compact().finish())
+    .expect("Failed to setup global tracing subscriber.");
+
+  let networks = std::env::var("NETWORKS").context("NETWORKS must be set")?;
+  let mut clients = Vec::new();
+  for network in networks.split(',').map(str::trim).filter(|network| !network.is_empty()) {
+    let (api_endpoint, identity_pkg_id) = parse_network(network);
+    let iota_client = iota_sdk::IotaClientBuilder::default().build(api_endpoint).await?;
+    let client = match identity_pkg_id {
+      Some(identity_pkg_id) => {
+        IdentityClientReadOnly::new_with_pkg_id(iota_client, ObjectID::from_str(identity_pkg_id)?).await?
+      }
+      None => IdentityClientReadOnly::new(iota_client).await?,
+    };
+    tracing::info!(
+      "resolving DIDs of network \"{}\" through {}",
+      client.network().as_ref(),
+      api_endpoint
+    );
+    clients.push(client);
+  }
+
+  let addr: SocketAddr = std::env::var("LISTEN_ADDRESS")
+    .unwrap_or_else(|_| DEFAULT_LISTEN_ADDRESS.to_owned())
+    .parse()?;
+  let router = identity_uni_resolver_driver::router(identity_uni_resolver_driver::resolver(clients));
+
+  tracing::info!("Universal Resolver driver listening on {}", addr);
+  let listener = tokio::net::TcpListener::bind(addr).await?;
+  axum::serve(listener, router)
+    .with_graceful_shutdown(async {
+      let _ = tokio::signal::ctrl_c().await;
+    })
+    .await?;
+
+  Ok(())
+}
+
+/// Splits a `<api_endpoint>[=<identity_pkg_id>]` entry of `NETWORKS`.
+fn parse_network(network: &str) -> (&str, Option<&str>) {
+  match network.rsplit_once('=') {
+    Some((api_endpoint, identity_pkg_id)) if identity_pkg_id.starts_with("0x") => (api_endpoint, Some(identity_pkg_id)),
+    _ => (network, None),
+  }
+}
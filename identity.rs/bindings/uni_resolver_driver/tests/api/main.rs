@@ -0,0 +1,5 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(test)]
+mod resolve;
@@ -0,0 +1,148 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use axum::body::Body;
+use axum::http::header::ACCEPT;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::Request;
+use axum::http::StatusCode;
+use axum::Router;
+use identity_iota::iota::DidResolutionHandler;
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::IotaDocument;
+use identity_uni_resolver_driver::IotaResolver;
+use identity_uni_resolver_driver::DID_LD_JSON;
+use identity_uni_resolver_driver::RESOLUTION_RESULT;
+use serde_json::Value;
+use tower::ServiceExt;
+
+const DID: &str = "did:iota:0x0101010101010101010101010101010101010101010101010101010101010101";
+const UNKNOWN_DID: &str = "did:iota:0x0202020202020202020202020202020202020202020202020202020202020202";
+const TESTNET_DID: &str = "did:iota:testnet:0x0303030303030303030303030303030303030303030303030303030303030303";
+const UNKNOWN_NETWORK_DID: &str = "did:iota:devnet:0x0101010101010101010101010101010101010101010101010101010101010101";
+
+struct DummyClient(IotaDocument);
+
+#[async_trait::async_trait]
+impl DidResolutionHandler for DummyClient {
+  async fn resolve_did(&self, did: &IotaDID) -> identity_iota::iota::Result<IotaDocument> {
+    if self.0.id() == did {
+      Ok(self.0.clone())
+    } else {
+      Err(identity_iota::iota::Error::DIDResolutionError(format!(
+        "{did} was not found"
+      )))
+    }
+  }
+}
+
+/// Router resolving `document` on the `iota` network, and nothing on the `testnet` network.
+fn router(document: IotaDocument) -> Router {
+  let testnet_document = IotaDocument::new_with_id(IotaDID::parse(TESTNET_DID).unwrap());
+  let mut resolver = IotaResolver::new();
+  resolver.attach_multiple_iota_handlers([
+    ("iota", DummyClient(document)),
+    ("testnet", DummyClient(testnet_document)),
+  ]);
+
+  identity_uni_resolver_driver::router(resolver)
+}
+
+fn document() -> IotaDocument {
+  IotaDocument::new_with_id(IotaDID::parse(DID).unwrap())
+}
+
+async fn resolve(router: Router, did: &str, accept: Option<&str>) -> anyhow::Result<(StatusCode, String, Value)> {
+  let mut req = Request::get(format!("/1.0/identifiers/{did}"));
+  if let Some(accept) = accept {
+    req = req.header(ACCEPT, accept);
+  }
+  let response = router.oneshot(req.body(Body::empty())?).await?;
+  let status = response.status();
+  let content_type = response.headers()[CONTENT_TYPE].to_str()?.to_owned();
+  let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+
+  Ok((status, content_type, serde_json::from_slice(&body)?))
+}
+
+#[tokio::test]
+async fn resolution_result_is_the_default_representation() -> anyhow::Result<()> {
+  let (status, content_type, body) = resolve(router(document()), DID, None).await?;
+
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(content_type, RESOLUTION_RESULT);
+  assert_eq!(body["didDocument"]["id"], DID);
+  assert_eq!(body["didDocument"]["@context"], "https://www.w3.org/ns/did/v1");
+  assert_eq!(body["didResolutionMetadata"]["contentType"], DID_LD_JSON);
+  assert!(body["didDocumentMetadata"]["created"].is_string());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn did_document_is_negotiated() -> anyhow::Result<()> {
+  let (status, content_type, body) = resolve(router(document()), DID, Some(DID_LD_JSON)).await?;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(content_type, DID_LD_JSON);
+  assert_eq!(body["id"], DID);
+  assert_eq!(body["@context"], "https://www.w3.org/ns/did/v1");
+
+  let accept = "application/ld+json; profile=\"https://w3id.org/did-resolution\", application/did+ld+json";
+  let (_, content_type, body) = resolve(router(document()), DID, Some(accept)).await?;
+  assert_eq!(content_type, RESOLUTION_RESULT);
+  assert_eq!(body["didDocument"]["id"], DID);
+
+  let (status, _, body) = resolve(router(document()), DID, Some("text/html")).await?;
+  assert_eq!(status, StatusCode::NOT_ACCEPTABLE);
+  assert_eq!(body["didResolutionMetadata"]["error"], "representationNotSupported");
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn dids_are_resolved_on_their_network() -> anyhow::Result<()> {
+  let (status, _, body) = resolve(router(document()), TESTNET_DID, Some(DID_LD_JSON)).await?;
+  assert_eq!(status, StatusCode::OK);
+  assert_eq!(body["id"], TESTNET_DID);
+
+  let (status, _, body) = resolve(router(document()), UNKNOWN_NETWORK_DID, None).await?;
+  assert_eq!(status, StatusCode::NOT_FOUND);
+  assert_eq!(body["didResolutionMetadata"]["error"], "notFound");
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn errors_are_mapped_to_resolution_errors() -> anyhow::Result<()> {
+  let (status, content_type, body) = resolve(router(document()), UNKNOWN_DID, None).await?;
+  assert_eq!(status, StatusCode::NOT_FOUND);
+  assert_eq!(content_type, RESOLUTION_RESULT);
+  assert_eq!(body["didResolutionMetadata"]["error"], "notFound");
+  assert!(body["didDocument"].is_null());
+
+  let (status, _, body) = resolve(router(document()), "did:iota:0xinvalid", None).await?;
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["didResolutionMetadata"]["error"], "invalidDid");
+
+  let (status, _, body) = resolve(router(document()), "not-a-did", None).await?;
+  assert_eq!(status, StatusCode::BAD_REQUEST);
+  assert_eq!(body["didResolutionMetadata"]["error"], "invalidDid");
+
+  let (status, _, body) = resolve(router(document()), "did:example:123", None).await?;
+  assert_eq!(status, StatusCode::NOT_IMPLEMENTED);
+  assert_eq!(body["didResolutionMetadata"]["error"], "methodNotSupported");
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn deactivated_dids_are_gone() -> anyhow::Result<()> {
+  let mut document = document();
+  document.metadata.deactivated = Some(true);
+
+  let (status, _, body) = resolve(router(document), DID, None).await?;
+  assert_eq!(status, StatusCode::GONE);
+  assert_eq!(body["didDocumentMetadata"]["deactivated"], true);
+
+  Ok(())
+}
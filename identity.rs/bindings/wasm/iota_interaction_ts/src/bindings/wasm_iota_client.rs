@@ -3,6 +3,7 @@
 
 use identity_iota_interaction::error::Error as IotaRpcError;
 use identity_iota_interaction::error::IotaRpcResult;
+use identity_iota_interaction::generated_types::DryRunTransactionBlockParams;
 use identity_iota_interaction::generated_types::ExecuteTransactionBlockParams;
 use identity_iota_interaction::generated_types::GetCoinsParams;
 use identity_iota_interaction::generated_types::GetDynamicFieldObjectParams;
@@ -13,6 +14,7 @@ use identity_iota_interaction::generated_types::QueryEventsParams;
 use identity_iota_interaction::generated_types::SortOrder;
 use identity_iota_interaction::generated_types::WaitForTransactionParams;
 use identity_iota_interaction::rpc_types::CoinPage;
+use identity_iota_interaction::rpc_types::DryRunTransactionBlockResponse;
 use identity_iota_interaction::rpc_types::EventFilter;
 use identity_iota_interaction::rpc_types::EventPage;
 use identity_iota_interaction::rpc_types::IotaObjectDataOptions;
@@ -172,6 +174,29 @@ impl ManagedWasmIotaClient {
     Ok(WasmIotaTransactionBlockResponseWrapper::new(result.into()))
   }
 
+  pub async fn dry_run_transaction_block(
+    &self,
+    tx_data: TransactionData,
+  ) -> IotaRpcResult<DryRunTransactionBlockResponse> {
+    let params: WasmDryRunTransactionBlockParams = DryRunTransactionBlockParams::new(&tx_data)
+      .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+      .map_err(|e| {
+        IotaRpcError::FfiError(format!(
+          "failed to convert DryRunTransactionBlockParams to JS value: {e}"
+        ))
+      })?
+      .into();
+
+    let promise: Promise = Promise::resolve(&WasmIotaClient::dry_run_transaction_block(&self.0, &params));
+    let result: JsValue = JsFuture::from(promise).await.map_err(|e| {
+      console_log!("Error executing JsFuture::from(promise): {:?}", e);
+      IotaRpcError::FfiError(format!("{:?}", e))
+    })?;
+
+    serde_wasm_bindgen::from_value(result)
+      .map_err(|e| IotaRpcError::FfiError(format!("failed to deserialize dry run response from JS value: {e}")))
+  }
+
   /**
    * Return the dynamic field object information for a specified object
    */
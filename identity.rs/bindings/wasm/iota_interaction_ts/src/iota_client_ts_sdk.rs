@@ -15,6 +15,7 @@ use secret_storage::Signer;
 use identity_iota_interaction::error::Error as IotaRpcError;
 use identity_iota_interaction::error::IotaRpcResult;
 use identity_iota_interaction::rpc_types::CoinPage;
+use identity_iota_interaction::rpc_types::DryRunTransactionBlockResponse;
 use identity_iota_interaction::rpc_types::EventFilter;
 use identity_iota_interaction::rpc_types::EventPage;
 use identity_iota_interaction::rpc_types::IotaObjectData;
@@ -163,6 +164,10 @@ impl ReadTrait for ReadAdapter {
     Ok(self.client.get_chain_identifier().await.unwrap())
   }
 
+  async fn dry_run_transaction_block(&self, tx_data: TransactionData) -> IotaRpcResult<DryRunTransactionBlockResponse> {
+    self.client.dry_run_transaction_block(tx_data).await
+  }
+
   async fn get_dynamic_field_object(
     &self,
     parent_object_id: ObjectID,
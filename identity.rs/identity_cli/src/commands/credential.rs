@@ -0,0 +1,371 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use clap::Subcommand;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota::core::FromJson;
+use identity_iota::core::Object;
+use identity_iota::core::StringOrUrl;
+use identity_iota::core::Timestamp;
+use identity_iota::core::Url;
+use identity_iota::credential::sd_jwt_vc::resolver;
+use identity_iota::credential::sd_jwt_vc::Resolver as SdJwtVcResolver;
+use identity_iota::credential::sd_jwt_vc::SdJwtVc;
+use identity_iota::credential::sd_jwt_vc::SdJwtVcBuilder;
+use identity_iota::credential::Credential;
+use identity_iota::credential::FailFast;
+use identity_iota::credential::Jpt;
+use identity_iota::credential::JptCredentialValidationOptions;
+use identity_iota::credential::JptCredentialValidator;
+use identity_iota::credential::JptCredentialValidatorUtils;
+use identity_iota::credential::JwpCredentialOptions;
+use identity_iota::credential::Jwt;
+use identity_iota::credential::JwtCredentialValidationOptions;
+use identity_iota::credential::JwtCredentialValidator;
+use identity_iota::credential::JwtCredentialValidatorUtils;
+use identity_iota::credential::KeyBindingJWTValidationOptions;
+use identity_iota::credential::StatusCheck;
+use identity_iota::credential::StatusList2021Credential;
+use identity_iota::did::DIDUrl;
+use identity_iota::did::DID;
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::IotaDocument;
+use identity_iota::sd_jwt_rework::JsonObject;
+use identity_iota::sd_jwt_rework::JwsSigner;
+use identity_iota::sd_jwt_rework::RequiredKeyBinding;
+use identity_iota::sd_jwt_rework::Sha256Hasher;
+use identity_iota::storage::JwkDocumentExt;
+use identity_iota::storage::JwkStorage;
+use identity_iota::storage::JwkStorageDocumentError;
+use identity_iota::storage::JwpDocumentExt;
+use identity_iota::storage::JwsSignatureOptions;
+use identity_iota::storage::KeyIdStorage;
+use identity_iota::storage::Storage;
+use identity_iota::verification::jwk::Jwk;
+use serde_json::json;
+use serde_json::Value;
+
+use super::parse_timestamp;
+use super::read_arg;
+use crate::context::Context;
+use crate::storage::with_bbs_storage;
+use crate::storage::with_storage;
+
+#[derive(Debug, Subcommand)]
+pub enum CredentialCommand {
+  /// Signs a credential as a JWT with the issuer's verification method.
+  IssueJwt {
+    /// Credential as JSON: inline, `@<path>` or `-` for the standard input.
+    credential: String,
+    /// Fragment of the issuer's verification method.
+    #[arg(long)]
+    fragment: String,
+  },
+  /// Validates a credential JWT against its issuer's DID document, printing the credential.
+  ValidateJwt {
+    jwt: String,
+    /// Status list 2021 credential the credential's status is checked against, as JSON.
+    #[arg(long)]
+    status_list: Option<String>,
+  },
+  /// Issues an SD-JWT VC signed with the issuer's verification method.
+  IssueSdJwtVc {
+    /// Claims as a JSON object: inline, `@<path>` or `-` for the standard input.
+    claims: String,
+    /// DID of the issuer.
+    #[arg(long)]
+    issuer: String,
+    /// Fragment of the issuer's verification method.
+    #[arg(long)]
+    fragment: String,
+    /// Type of the credential.
+    #[arg(long)]
+    vct: String,
+    /// JSON pointer of a claim to make selectively disclosable; may be repeated.
+    #[arg(long)]
+    conceal: Vec<String>,
+    /// DID URL of the holder's verification method the credential is bound to.
+    #[arg(long)]
+    holder: Option<String>,
+    /// Subject of the credential.
+    #[arg(long)]
+    sub: Option<String>,
+    /// Expiration date, as an RFC 3339 timestamp.
+    #[arg(long)]
+    expiration_date: Option<String>,
+  },
+  /// Validates an SD-JWT VC against its issuer's DID document, printing the disclosed claims.
+  ValidateSdJwtVc {
+    sd_jwt_vc: String,
+    /// Type metadata the credential is validated against, as JSON.
+    #[arg(long)]
+    type_metadata: Option<String>,
+    /// Nonce expected in the key binding JWT.
+    #[arg(long)]
+    nonce: Option<String>,
+    /// Audience expected in the key binding JWT.
+    #[arg(long)]
+    aud: Option<String>,
+  },
+  /// Signs a credential as a JPT with the issuer's BBS+ verification method.
+  IssueJpt {
+    /// Credential as JSON: inline, `@<path>` or `-` for the standard input.
+    credential: String,
+    /// Fragment of the issuer's verification method.
+    #[arg(long)]
+    fragment: String,
+  },
+  /// Validates a credential JPT against its issuer's DID document, printing the credential.
+  ValidateJpt { jpt: String },
+}
+
+/// Signs SD-JWTs and KB-JWTs with the key of a DID document's verification method.
+pub(crate) struct DocumentJwsSigner<'a, K, I> {
+  pub document: &'a IotaDocument,
+  pub storage: &'a Storage<K, I>,
+  pub fragment: &'a str,
+}
+
+#[async_trait]
+impl<K, I> JwsSigner for DocumentJwsSigner<'_, K, I>
+where
+  K: JwkStorage,
+  I: KeyIdStorage,
+{
+  type Error = JwkStorageDocumentError;
+  async fn sign(&self, header: &JsonObject, payload: &JsonObject) -> Result<Vec<u8>, Self::Error> {
+    // `alg` and `kid` are set according to the verification method.
+    let mut options = JwsSignatureOptions::default();
+    if let Some(typ) = header.get("typ").and_then(Value::as_str) {
+      options = options.typ(typ);
+    }
+    let payload = serde_json::to_vec(payload).map_err(|e| JwkStorageDocumentError::EncodingError(e.into()))?;
+    let jws = self
+      .document
+      .create_jws(self.storage, self.fragment, &payload, &options)
+      .await?;
+
+    Ok(jws.as_str().as_bytes().to_vec())
+  }
+}
+
+/// Serves the type metadata given on the command line.
+struct TypeMetadataResolver(Vec<u8>);
+
+#[async_trait]
+impl SdJwtVcResolver<StringOrUrl, Vec<u8>> for TypeMetadataResolver {
+  async fn resolve(&self, _vct: &StringOrUrl) -> Result<Vec<u8>, resolver::Error> {
+    Ok(self.0.clone())
+  }
+}
+
+#[async_trait]
+impl SdJwtVcResolver<Url, Value> for TypeMetadataResolver {
+  async fn resolve(&self, input: &Url) -> Result<Value, resolver::Error> {
+    Err(resolver::Error::NotFound(input.to_string()))
+  }
+}
+
+/// Returns the public key of the verification method with the given fragment.
+pub(crate) fn document_jwk(document: &IotaDocument, fragment: &str) -> anyhow::Result<Jwk> {
+  document
+    .resolve_method(fragment, None)
+    .and_then(|method| method.data().public_key_jwk())
+    .cloned()
+    .with_context(|| format!("no JWK verification method with fragment \"{fragment}\""))
+}
+
+/// Returns the JWS algorithm to use with `jwk`.
+pub(crate) fn jwk_alg(jwk: &Jwk) -> anyhow::Result<String> {
+  jwk
+    .alg()
+    .map(ToOwned::to_owned)
+    .context("the verification method's JWK has no `alg`")
+}
+
+/// Resolves the verification method identified by the DID URL `method_id`, returning its document,
+/// fragment and public key.
+pub(crate) async fn resolve_method(ctx: &Context, method_id: &str) -> anyhow::Result<(IotaDocument, String, Jwk)> {
+  let method_id = DIDUrl::parse(method_id).with_context(|| format!("\"{method_id}\" is not a valid DID URL"))?;
+  let fragment = method_id
+    .fragment()
+    .context("the method id is missing a fragment")?
+    .to_owned();
+  let document = ctx.resolve(method_id.did().as_str()).await?;
+  let jwk = document_jwk(&document, &fragment)?;
+
+  Ok((document, fragment, jwk))
+}
+
+pub async fn run(command: CredentialCommand, ctx: &Context) -> anyhow::Result<Value> {
+  match command {
+    CredentialCommand::IssueJwt { credential, fragment } => {
+      let credential = Credential::<Object>::from_json(&read_arg(&credential)?).context("invalid credential")?;
+      let issuer_document = ctx.resolve(credential.issuer.url().as_str()).await?;
+      let storage = ctx.open_storage()?;
+      let jwt = with_storage!(storage, storage => issuer_document
+        .create_credential_jwt(&credential, storage, &fragment, &JwsSignatureOptions::default(), None)
+        .await
+        .context("failed to sign the credential")?);
+
+      Ok(json!({ "jwt": jwt.as_str() }))
+    }
+    CredentialCommand::ValidateJwt { jwt, status_list } => {
+      let jwt = Jwt::new(read_arg(&jwt)?);
+      let issuer_did =
+        JwtCredentialValidatorUtils::extract_issuer_from_jwt::<IotaDID>(&jwt).context("invalid credential JWT")?;
+      let issuer_document = ctx.resolve(issuer_did.as_str()).await?;
+
+      let mut options = JwtCredentialValidationOptions::default();
+      if status_list.is_some() {
+        options = options.status_check(StatusCheck::SkipAll);
+      }
+      let credential = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+        .validate::<_, Object>(&jwt, &issuer_document, &options, FailFast::FirstError)
+        .context("invalid credential")?
+        .credential;
+
+      if let Some(status_list) = status_list {
+        let status_list =
+          StatusList2021Credential::from_json(&read_arg(&status_list)?).context("invalid status list credential")?;
+        JwtCredentialValidatorUtils::check_status_with_status_list_2021(&credential, &status_list, StatusCheck::Strict)
+          .context("invalid credential status")?;
+      }
+
+      Ok(json!({ "credential": credential }))
+    }
+    CredentialCommand::IssueSdJwtVc {
+      claims,
+      issuer,
+      fragment,
+      vct,
+      conceal,
+      holder,
+      sub,
+      expiration_date,
+    } => {
+      let claims: JsonObject = serde_json::from_str(&read_arg(&claims)?).context("the claims aren't a JSON object")?;
+      let issuer_document = ctx.resolve(&issuer).await?;
+      let alg = jwk_alg(&document_jwk(&issuer_document, &fragment)?)?;
+      let iss = Url::parse(issuer_document.id().as_str())?;
+
+      let mut builder = SdJwtVcBuilder::new(claims)?.iss(iss).iat(Timestamp::now_utc()).vct(vct);
+      for path in conceal {
+        builder = builder
+          .make_concealable(&path)
+          .with_context(|| format!("cannot conceal \"{path}\""))?;
+      }
+      if let Some(holder) = holder {
+        let (_, _, holder_jwk) = resolve_method(ctx, &holder).await?;
+        let Ok(Value::Object(holder_jwk)) = serde_json::to_value(holder_jwk) else {
+          unreachable!("a JWK is serialized as a JSON object");
+        };
+        builder = builder.require_key_binding(RequiredKeyBinding::Jwk(holder_jwk));
+      }
+      if let Some(sub) = sub {
+        builder = builder.sub(sub);
+      }
+      if let Some(expiration_date) = expiration_date {
+        builder = builder.exp(parse_timestamp(&expiration_date)?);
+      }
+
+      let storage = ctx.open_storage()?;
+      let sd_jwt_vc = with_storage!(storage, storage => {
+        let signer = DocumentJwsSigner {
+          document: &issuer_document,
+          storage,
+          fragment: &fragment,
+        };
+        builder.finish(&signer, &alg).await.context("failed to sign the credential")?
+      });
+
+      Ok(json!({ "sd_jwt_vc": sd_jwt_vc.to_string() }))
+    }
+    CredentialCommand::ValidateSdJwtVc {
+      sd_jwt_vc,
+      type_metadata,
+      nonce,
+      aud,
+    } => {
+      let sd_jwt_vc = SdJwtVc::parse(&read_arg(&sd_jwt_vc)?).context("invalid SD-JWT VC")?;
+
+      // The issuer is identified by a DID: its key is the one referenced by the `kid` header.
+      let kid = sd_jwt_vc
+        .header()
+        .get("kid")
+        .and_then(Value::as_str)
+        .context("missing header claim `kid`")?;
+      let kid = DIDUrl::parse(kid).context("`kid` is not a DID URL")?;
+      if kid.did().as_str() != sd_jwt_vc.claims().iss.as_str() {
+        anyhow::bail!("`kid` doesn't belong to the issuer");
+      }
+      let issuer_document = ctx.resolve(kid.did().as_str()).await?;
+      let issuer_jwk = document_jwk(&issuer_document, kid.fragment().unwrap_or_default())?;
+
+      match type_metadata {
+        Some(type_metadata) => sd_jwt_vc
+          .validate_with_issuer_jwk(
+            &issuer_jwk,
+            &TypeMetadataResolver(read_arg(&type_metadata)?.into_bytes()),
+            &EdDSAJwsVerifier::default(),
+            &Sha256Hasher::new(),
+          )
+          .await
+          .context("invalid SD-JWT VC")?,
+        None => sd_jwt_vc
+          .verify_signature(&EdDSAJwsVerifier::default(), &issuer_jwk)
+          .context("invalid SD-JWT VC signature")?,
+      }
+
+      if sd_jwt_vc.key_binding_jwt().is_some() {
+        let Some(RequiredKeyBinding::Jwk(holder_jwk)) = sd_jwt_vc.required_key_bind() else {
+          anyhow::bail!("the credential isn't bound to a JWK");
+        };
+        let holder_jwk: Jwk = serde_json::from_value(Value::Object(holder_jwk.clone()))?;
+        let mut kb_options = KeyBindingJWTValidationOptions::default();
+        kb_options.nonce = nonce;
+        kb_options.aud = aud;
+        sd_jwt_vc
+          .validate_key_binding(
+            &EdDSAJwsVerifier::default(),
+            &holder_jwk,
+            &Sha256Hasher::new(),
+            &kb_options,
+          )
+          .context("invalid key binding JWT")?;
+      }
+
+      let claims = sd_jwt_vc.into_disclosed_object(&Sha256Hasher::new())?;
+      Ok(json!({ "claims": claims }))
+    }
+    CredentialCommand::IssueJpt { credential, fragment } => {
+      let credential = Credential::<Object>::from_json(&read_arg(&credential)?).context("invalid credential")?;
+      let issuer_document = ctx.resolve(credential.issuer.url().as_str()).await?;
+      let storage = ctx.open_storage()?;
+      let jpt = with_bbs_storage!(storage, storage => issuer_document
+        .create_credential_jpt(&credential, storage, &fragment, &JwpCredentialOptions::default(), None)
+        .await
+        .context("failed to sign the credential")?);
+
+      Ok(json!({ "jpt": jpt.as_str() }))
+    }
+    CredentialCommand::ValidateJpt { jpt } => {
+      let jpt = Jpt::new(read_arg(&jpt)?);
+      let issuer_did = JptCredentialValidatorUtils::extract_issuer_from_issued_jpt::<IotaDID>(&jpt)
+        .context("invalid credential JPT")?;
+      let issuer_document = ctx.resolve(issuer_did.as_str()).await?;
+      let credential = JptCredentialValidator::validate::<_, Object>(
+        &jpt,
+        &issuer_document,
+        &JptCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .context("invalid credential")?
+      .credential;
+
+      Ok(json!({ "credential": credential }))
+    }
+  }
+}
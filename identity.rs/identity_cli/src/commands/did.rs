@@ -0,0 +1,214 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use anyhow::Context as _;
+use clap::Args;
+use clap::Subcommand;
+use identity_iota::core::FromJson;
+use identity_iota::did::DIDUrl;
+use identity_iota::did::DID;
+use identity_iota::document::Service;
+use identity_iota::iota::IotaDocument;
+use identity_iota::storage::JwkDocumentExt;
+use identity_iota::storage::JwpDocumentExt;
+use identity_iota::storage::KeyType;
+use identity_iota::verification::jws::JwsAlgorithm;
+use serde_json::json;
+use serde_json::Value;
+
+use super::read_arg;
+use super::ProofAlg;
+use super::Scope;
+use crate::context::resolve;
+use crate::context::Context;
+use crate::storage::with_bbs_storage;
+use crate::storage::with_storage;
+use crate::storage::CliStorage;
+
+#[derive(Debug, Subcommand)]
+pub enum DidCommand {
+  /// Publishes a new DID document with a verification method, printing the DID and the method's fragment.
+  Create {
+    #[command(flatten)]
+    method: MethodArgs,
+  },
+  /// Resolves a DID document.
+  Resolve { did: String },
+  /// Adds a verification method whose key is generated in the storage.
+  AddMethod {
+    did: String,
+    #[command(flatten)]
+    method: MethodArgs,
+  },
+  /// Removes a verification method and deletes its key from the storage.
+  RemoveMethod {
+    did: String,
+    /// Fragment of the verification method.
+    #[arg(long)]
+    fragment: String,
+  },
+  /// Adds a service.
+  AddService {
+    did: String,
+    /// Service as JSON, with an `id` relative to the DID: inline, `@<path>` or `-` for the standard input.
+    service: String,
+  },
+  /// Removes a service.
+  RemoveService {
+    did: String,
+    /// Fragment of the service.
+    #[arg(long)]
+    fragment: String,
+  },
+  /// Deactivates a DID document.
+  Deactivate { did: String },
+}
+
+/// Options of a verification method generated in the storage.
+#[derive(Debug, Args)]
+pub struct MethodArgs {
+  /// Key type; defaults to the storage's Ed25519 type, or its BLS12381G2 type with `--proof-alg`.
+  #[arg(long)]
+  pub key_type: Option<String>,
+  /// JWS algorithm of the method.
+  #[arg(long, default_value = "EdDSA", conflicts_with = "proof_alg")]
+  pub alg: String,
+  /// Generates a BBS+ method for this JSON proof algorithm instead, to issue JSON proof tokens.
+  #[arg(long, value_enum)]
+  pub proof_alg: Option<ProofAlg>,
+  /// Fragment of the method; a random one is used if unset.
+  #[arg(long)]
+  pub fragment: Option<String>,
+  /// Verification relationship of the method.
+  #[arg(long, value_enum, default_value = "verification-method")]
+  pub scope: Scope,
+}
+
+impl MethodArgs {
+  /// Generates the method's key in `storage` and inserts the method in `document`, returning its fragment.
+  async fn generate(&self, ctx: &Context, document: &mut IotaDocument, storage: &CliStorage) -> anyhow::Result<String> {
+    let fragment = self.fragment.as_deref();
+    let scope = self.scope.into();
+
+    if let Some(proof_alg) = self.proof_alg {
+      let key_type = self
+        .key_type
+        .clone()
+        .map(KeyType::new)
+        .unwrap_or_else(|| ctx.storage.default_bbs_key_type());
+      return Ok(with_bbs_storage!(storage, storage => document
+        .generate_method_jwp(storage, key_type, proof_alg.into(), fragment, scope)
+        .await
+        .context("failed to generate the verification method")?));
+    }
+
+    let key_type = self
+      .key_type
+      .clone()
+      .map(KeyType::new)
+      .unwrap_or_else(|| ctx.storage.default_key_type());
+    let alg = JwsAlgorithm::from_str(&self.alg).with_context(|| format!("unknown JWS algorithm \"{}\"", self.alg))?;
+    Ok(with_storage!(storage, storage => document
+      .generate_method(storage, key_type, alg, fragment, scope)
+      .await
+      .context("failed to generate the verification method")?))
+  }
+}
+
+pub async fn run(command: DidCommand, ctx: &Context) -> anyhow::Result<Value> {
+  if let DidCommand::Resolve { did } = &command {
+    return Ok(json!(ctx.resolve(did).await?));
+  }
+
+  let storage = ctx.open_storage()?;
+  let client = ctx.identity_client(storage).await?;
+  let gas_budget = ctx.network.gas_budget;
+
+  match command {
+    DidCommand::Create { method } => {
+      let mut unpublished = IotaDocument::new(client.network());
+      let fragment = method.generate(ctx, &mut unpublished, storage).await?;
+      let document = client
+        .publish_did_document(unpublished)
+        .with_gas_budget(gas_budget)
+        .build_and_execute(&client)
+        .await
+        .context("failed to publish the DID document")?
+        .output;
+
+      Ok(json!({ "did": document.id().to_string(), "fragment": fragment, "document": document }))
+    }
+    DidCommand::Resolve { .. } => unreachable!("handled without a storage"),
+    DidCommand::AddMethod { did, method } => {
+      let mut document = resolve(&client, &did).await?;
+      let fragment = method.generate(ctx, &mut document, storage).await?;
+      let document = client
+        .publish_did_document_update(document, gas_budget)
+        .await
+        .context("failed to update the DID document")?;
+
+      Ok(json!({ "fragment": fragment, "document": document }))
+    }
+    DidCommand::RemoveMethod { did, fragment } => {
+      let mut document = resolve(&client, &did).await?;
+      let method_id = fragment_url(&document, &fragment)?;
+
+      // Publish the update before deleting the key, so that a failed update leaves the method usable.
+      let mut updated = document.clone();
+      updated
+        .remove_method(&method_id)
+        .with_context(|| format!("no verification method \"{method_id}\""))?;
+      let updated = client
+        .publish_did_document_update(updated, gas_budget)
+        .await
+        .context("failed to update the DID document")?;
+      let key_deleted = with_storage!(storage, storage => document.purge_method(storage, &method_id).await.is_ok());
+
+      Ok(json!({ "document": updated, "key_deleted": key_deleted }))
+    }
+    DidCommand::AddService { did, service } => {
+      let mut document = resolve(&client, &did).await?;
+      let service = Service::from_json(&read_arg(&service)?).context("invalid service")?;
+      document.insert_service(service).context("failed to add the service")?;
+      let document = client
+        .publish_did_document_update(document, gas_budget)
+        .await
+        .context("failed to update the DID document")?;
+
+      Ok(json!(document))
+    }
+    DidCommand::RemoveService { did, fragment } => {
+      let mut document = resolve(&client, &did).await?;
+      let service_id = fragment_url(&document, &fragment)?;
+      document
+        .remove_service(&service_id)
+        .with_context(|| format!("no service \"{service_id}\""))?;
+      let document = client
+        .publish_did_document_update(document, gas_budget)
+        .await
+        .context("failed to update the DID document")?;
+
+      Ok(json!(document))
+    }
+    DidCommand::Deactivate { did } => {
+      let document = resolve(&client, &did).await?;
+      client
+        .deactivate_did_output(document.id(), gas_budget)
+        .await
+        .context("failed to deactivate the DID document")?;
+
+      Ok(json!({ "did": document.id().to_string(), "deactivated": true }))
+    }
+  }
+}
+
+/// Returns the URL of the document's DID with `fragment`.
+pub(crate) fn fragment_url(document: &IotaDocument, fragment: &str) -> anyhow::Result<DIDUrl> {
+  document
+    .id()
+    .to_url()
+    .join(format!("#{}", fragment.trim_start_matches('#')))
+    .with_context(|| format!("\"{fragment}\" is not a valid fragment"))
+}
@@ -0,0 +1,120 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Context as _;
+use clap::Subcommand;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota::core::Duration;
+use identity_iota::core::FromJson;
+use identity_iota::core::Timestamp;
+use identity_iota::credential::DomainLinkageConfiguration;
+use identity_iota::credential::DomainLinkageCredentialBuilder;
+use identity_iota::credential::Jwt;
+use identity_iota::credential::JwtCredentialValidationOptions;
+use identity_iota::credential::JwtDomainLinkageValidator;
+use identity_iota::storage::JwkDocumentExt;
+use identity_iota::storage::JwsSignatureOptions;
+use serde_json::json;
+use serde_json::Value;
+
+use super::parse_timestamp;
+use super::parse_url;
+use super::read_arg;
+use crate::context::Context;
+use crate::storage::with_storage;
+
+#[derive(Debug, Subcommand)]
+pub enum DomainLinkageCommand {
+  /// Issues a domain linkage credential JWT linking a DID to an origin.
+  CreateCredential {
+    /// DID to link.
+    #[arg(long)]
+    did: String,
+    /// Fragment of the DID's verification method signing the credential.
+    #[arg(long)]
+    fragment: String,
+    /// Origin to link, e.g. `https://example.com`.
+    #[arg(long)]
+    origin: String,
+    /// Expiration date, as an RFC 3339 timestamp; a year from now by default.
+    #[arg(long)]
+    expiration_date: Option<String>,
+  },
+  /// Creates a DID configuration resource, to be served at `/.well-known/did-configuration.json`.
+  CreateConfiguration {
+    /// Domain linkage credential JWT; may be repeated.
+    #[arg(required = true)]
+    jwt: Vec<String>,
+  },
+  /// Validates the linkage between a DID and a domain through the domain's DID configuration resource.
+  Validate {
+    #[arg(long)]
+    did: String,
+    /// Domain, e.g. `https://example.com`.
+    #[arg(long)]
+    domain: String,
+    /// DID configuration resource as JSON: inline, `@<path>` or `-` for the standard input.
+    configuration: String,
+  },
+}
+
+pub async fn run(command: DomainLinkageCommand, ctx: &Context) -> anyhow::Result<Value> {
+  match command {
+    DomainLinkageCommand::CreateCredential {
+      did,
+      fragment,
+      origin,
+      expiration_date,
+    } => {
+      let document = ctx.resolve(&did).await?;
+      let expiration_date = match expiration_date {
+        Some(expiration_date) => parse_timestamp(&expiration_date)?,
+        None => Timestamp::now_utc()
+          .checked_add(Duration::days(365))
+          .context("calculation should not overflow")?,
+      };
+      let credential = DomainLinkageCredentialBuilder::new()
+        .issuer(document.id().clone().into())
+        .origin(parse_url(&origin)?)
+        .issuance_date(Timestamp::now_utc())
+        .expiration_date(expiration_date)
+        .build()
+        .context("invalid domain linkage credential")?;
+
+      let storage = ctx.open_storage()?;
+      let jwt = with_storage!(storage, storage => document
+        .create_credential_jwt(&credential, storage, &fragment, &JwsSignatureOptions::default(), None)
+        .await
+        .context("failed to sign the credential")?);
+
+      Ok(json!({ "jwt": jwt.as_str() }))
+    }
+    DomainLinkageCommand::CreateConfiguration { jwt } => {
+      let jwts = jwt
+        .iter()
+        .map(|jwt| read_arg(jwt).map(Jwt::new))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+      Ok(json!(DomainLinkageConfiguration::new(jwts)))
+    }
+    DomainLinkageCommand::Validate {
+      did,
+      domain,
+      configuration,
+    } => {
+      let document = ctx.resolve(&did).await?;
+      let configuration =
+        DomainLinkageConfiguration::from_json(&read_arg(&configuration)?).context("invalid DID configuration")?;
+      JwtDomainLinkageValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+        .validate_linkage(
+          &document,
+          &configuration,
+          &parse_url(&domain)?,
+          &JwtCredentialValidationOptions::default(),
+        )
+        .context("invalid domain linkage")?;
+
+      Ok(json!({ "valid": true }))
+    }
+  }
+}
@@ -0,0 +1,113 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use anyhow::Context as _;
+use clap::Subcommand;
+use identity_iota::core::FromJson;
+use identity_iota::storage::JwkStorage;
+use identity_iota::storage::JwkStorageBbsPlusExt;
+use identity_iota::storage::KeyId;
+use identity_iota::storage::KeyType;
+use identity_iota::verification::jwk::Jwk;
+use identity_iota::verification::jws::JwsAlgorithm;
+use serde_json::json;
+use serde_json::Value;
+
+use super::read_arg;
+use super::ProofAlg;
+use crate::context::Context;
+use crate::storage::with_bbs_storage;
+use crate::storage::with_storage;
+
+#[derive(Debug, Subcommand)]
+pub enum KeyCommand {
+  /// Generates a key, printing its ID and public JWK.
+  Generate {
+    /// Key type, e.g. `Ed25519` for the memory and stronghold storages, `ed25519` or `secp256r1` for the keytool.
+    #[arg(long)]
+    key_type: Option<String>,
+    /// JWS algorithm the key is used with.
+    #[arg(long, default_value = "EdDSA", conflicts_with = "proof_alg")]
+    alg: String,
+    /// Generates a BBS+ key for this JSON proof algorithm instead.
+    #[arg(long, value_enum)]
+    proof_alg: Option<ProofAlg>,
+  },
+  /// Inserts a private JWK, printing the ID of the key.
+  Insert {
+    /// Private JWK: inline JSON, `@<path>` or `-` for the standard input.
+    jwk: String,
+  },
+  /// Deletes a key.
+  Delete { key_id: String },
+  /// Checks whether a key exists.
+  Exists { key_id: String },
+}
+
+pub async fn run(command: KeyCommand, ctx: &Context) -> anyhow::Result<Value> {
+  let storage = ctx.open_storage()?;
+
+  match command {
+    KeyCommand::Generate {
+      key_type,
+      proof_alg: Some(proof_alg),
+      ..
+    } => {
+      let key_type = key_type
+        .map(KeyType::new)
+        .unwrap_or_else(|| ctx.storage.default_bbs_key_type());
+      let output = with_bbs_storage!(storage, storage => storage
+        .key_storage()
+        .generate_bbs(key_type, proof_alg.into())
+        .await
+        .context("failed to generate the key")?);
+
+      Ok(json!({ "key_id": output.key_id.to_string(), "jwk": output.jwk }))
+    }
+    KeyCommand::Generate { key_type, alg, .. } => {
+      let key_type = key_type
+        .map(KeyType::new)
+        .unwrap_or_else(|| ctx.storage.default_key_type());
+      let alg = JwsAlgorithm::from_str(&alg).with_context(|| format!("unknown JWS algorithm \"{alg}\""))?;
+      let output = with_storage!(storage, storage => storage
+        .key_storage()
+        .generate(key_type, alg)
+        .await
+        .context("failed to generate the key")?);
+
+      Ok(json!({ "key_id": output.key_id.to_string(), "jwk": output.jwk }))
+    }
+    KeyCommand::Insert { jwk } => {
+      let jwk = Jwk::from_json(&read_arg(&jwk)?).context("invalid JWK")?;
+      let key_id = with_storage!(storage, storage => storage
+        .key_storage()
+        .insert(jwk)
+        .await
+        .context("failed to insert the key")?);
+
+      Ok(json!({ "key_id": key_id.to_string() }))
+    }
+    KeyCommand::Delete { key_id } => {
+      let key_id = KeyId::new(key_id);
+      with_storage!(storage, storage => storage
+        .key_storage()
+        .delete(&key_id)
+        .await
+        .context("failed to delete the key")?);
+
+      Ok(json!({ "key_id": key_id.to_string(), "deleted": true }))
+    }
+    KeyCommand::Exists { key_id } => {
+      let key_id = KeyId::new(key_id);
+      let exists = with_storage!(storage, storage => storage
+        .key_storage()
+        .exists(&key_id)
+        .await
+        .context("failed to look up the key")?);
+
+      Ok(json!({ "key_id": key_id.to_string(), "exists": exists }))
+    }
+  }
+}
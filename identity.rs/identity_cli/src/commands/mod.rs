@@ -0,0 +1,85 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod credential;
+pub mod did;
+pub mod domain_linkage;
+pub mod key;
+pub mod presentation;
+pub mod status_list;
+
+use std::io::Read;
+
+use anyhow::Context as _;
+use clap::ValueEnum;
+use identity_iota::core::Timestamp;
+use identity_iota::core::Url;
+use identity_iota::verification::MethodScope;
+use jsonprooftoken::jpa::algs::ProofAlgorithm;
+
+/// Reads an argument given inline, as `@<path>` of a file or as `-` for the standard input.
+pub(crate) fn read_arg(value: &str) -> anyhow::Result<String> {
+  if value == "-" {
+    let mut input = String::new();
+    std::io::stdin()
+      .read_to_string(&mut input)
+      .context("failed to read the standard input")?;
+    Ok(input.trim_end().to_owned())
+  } else if let Some(path) = value.strip_prefix('@') {
+    std::fs::read_to_string(path)
+      .map(|content| content.trim_end().to_owned())
+      .with_context(|| format!("failed to read \"{path}\""))
+  } else {
+    Ok(value.to_owned())
+  }
+}
+
+pub(crate) fn parse_timestamp(timestamp: &str) -> anyhow::Result<Timestamp> {
+  Timestamp::parse(timestamp).with_context(|| format!("\"{timestamp}\" is not an RFC 3339 timestamp"))
+}
+
+pub(crate) fn parse_url(url: &str) -> anyhow::Result<Url> {
+  Url::parse(url).with_context(|| format!("\"{url}\" is not a valid URL"))
+}
+
+/// Algorithms of BBS+ keys and JSON proof tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ProofAlg {
+  #[value(name = "BLS12381-SHA256")]
+  Bls12381Sha256,
+  #[value(name = "BLS12381-SHAKE256")]
+  Bls12381Shake256,
+}
+
+impl From<ProofAlg> for ProofAlgorithm {
+  fn from(value: ProofAlg) -> Self {
+    match value {
+      ProofAlg::Bls12381Sha256 => ProofAlgorithm::BLS12381_SHA256,
+      ProofAlg::Bls12381Shake256 => ProofAlgorithm::BLS12381_SHAKE256,
+    }
+  }
+}
+
+/// Verification relationships of verification methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Scope {
+  VerificationMethod,
+  Authentication,
+  AssertionMethod,
+  KeyAgreement,
+  CapabilityDelegation,
+  CapabilityInvocation,
+}
+
+impl From<Scope> for MethodScope {
+  fn from(value: Scope) -> Self {
+    match value {
+      Scope::VerificationMethod => MethodScope::VerificationMethod,
+      Scope::Authentication => MethodScope::authentication(),
+      Scope::AssertionMethod => MethodScope::assertion_method(),
+      Scope::KeyAgreement => MethodScope::key_agreement(),
+      Scope::CapabilityDelegation => MethodScope::capability_delegation(),
+      Scope::CapabilityInvocation => MethodScope::capability_invocation(),
+    }
+  }
+}
@@ -0,0 +1,327 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Context as _;
+use clap::Subcommand;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_iota::core::Object;
+use identity_iota::core::Timestamp;
+use identity_iota::core::Url;
+use identity_iota::credential::sd_jwt_vc::SdJwtVc;
+use identity_iota::credential::FailFast;
+use identity_iota::credential::Jpt;
+use identity_iota::credential::JptCredentialValidationOptions;
+use identity_iota::credential::JptCredentialValidator;
+use identity_iota::credential::JptCredentialValidatorUtils;
+use identity_iota::credential::JptPresentationValidationOptions;
+use identity_iota::credential::JptPresentationValidator;
+use identity_iota::credential::JptPresentationValidatorUtils;
+use identity_iota::credential::JwpPresentationOptions;
+use identity_iota::credential::Jwt;
+use identity_iota::credential::JwtCredentialValidationOptions;
+use identity_iota::credential::JwtCredentialValidator;
+use identity_iota::credential::JwtCredentialValidatorUtils;
+use identity_iota::credential::JwtPresentationOptions;
+use identity_iota::credential::JwtPresentationValidationOptions;
+use identity_iota::credential::JwtPresentationValidator;
+use identity_iota::credential::JwtPresentationValidatorUtils;
+use identity_iota::credential::PresentationBuilder;
+use identity_iota::credential::SelectiveDisclosurePresentation;
+use identity_iota::credential::SubjectHolderRelationship;
+use identity_iota::did::DID;
+use identity_iota::document::verifiable::JwsVerificationOptions;
+use identity_iota::iota::IotaDID;
+use identity_iota::sd_jwt_rework::KeyBindingJwtBuilder;
+use identity_iota::sd_jwt_rework::Sha256Hasher;
+use identity_iota::storage::JwkDocumentExt;
+use identity_iota::storage::JwpDocumentExt;
+use identity_iota::storage::JwsSignatureOptions;
+use serde_json::json;
+use serde_json::Value;
+
+use super::credential::jwk_alg;
+use super::credential::resolve_method;
+use super::credential::DocumentJwsSigner;
+use super::parse_timestamp;
+use super::parse_url;
+use super::read_arg;
+use crate::context::Context;
+use crate::storage::with_storage;
+
+#[derive(Debug, Subcommand)]
+pub enum PresentationCommand {
+  /// Creates a presentation of credential JWTs, signed as a JWT with the holder's verification method.
+  CreateJwt {
+    /// DID of the holder.
+    #[arg(long)]
+    holder: String,
+    /// Fragment of the holder's verification method.
+    #[arg(long)]
+    fragment: String,
+    /// Credential JWT to present; may be repeated.
+    #[arg(long, required = true)]
+    credential: Vec<String>,
+    /// Nonce of the verifier.
+    #[arg(long)]
+    nonce: Option<String>,
+    /// Audience of the presentation.
+    #[arg(long)]
+    audience: Option<String>,
+    /// Expiration date, as an RFC 3339 timestamp.
+    #[arg(long)]
+    expiration_date: Option<String>,
+  },
+  /// Validates a presentation JWT and the credentials it contains.
+  ValidateJwt {
+    jwt: String,
+    /// Expected nonce.
+    #[arg(long)]
+    nonce: Option<String>,
+    /// Expected audience.
+    #[arg(long)]
+    audience: Option<String>,
+  },
+  /// Presents an SD-JWT VC, concealing some of its claims and optionally attaching a key binding JWT.
+  CreateSdJwtVc {
+    sd_jwt_vc: String,
+    /// JSON pointer of a disclosable claim to conceal; may be repeated.
+    #[arg(long)]
+    conceal: Vec<String>,
+    /// DID URL of the holder's verification method signing the key binding JWT.
+    #[arg(long)]
+    holder_method_id: Option<String>,
+    /// Nonce of the key binding JWT.
+    #[arg(long, requires = "holder_method_id")]
+    nonce: Option<String>,
+    /// Audience of the key binding JWT.
+    #[arg(long, requires = "holder_method_id")]
+    aud: Option<String>,
+  },
+  /// Derives a presentation JPT from a credential JPT, concealing some of its subject's claims.
+  CreateJpt {
+    jpt: String,
+    /// Path of a claim of the credential subject to conceal, e.g. `degree.name`; may be repeated.
+    #[arg(long)]
+    conceal: Vec<String>,
+    /// Nonce of the verifier.
+    #[arg(long)]
+    nonce: Option<String>,
+    /// Audience of the presentation.
+    #[arg(long)]
+    audience: Option<String>,
+  },
+  /// Validates a presentation JPT, printing the disclosed credential.
+  ValidateJpt {
+    jpt: String,
+    /// Expected nonce.
+    #[arg(long)]
+    nonce: Option<String>,
+    /// Expected audience.
+    #[arg(long)]
+    audience: Option<String>,
+  },
+}
+
+/// Checks that a presentation's `aud` matches the `expected` one, if any.
+fn check_audience(expected: Option<&str>, audience: Option<&Url>) -> anyhow::Result<()> {
+  match expected.map(parse_url).transpose()? {
+    Some(expected) if audience != Some(&expected) => anyhow::bail!("the presentation's audience doesn't match"),
+    _ => Ok(()),
+  }
+}
+
+pub async fn run(command: PresentationCommand, ctx: &Context) -> anyhow::Result<Value> {
+  match command {
+    PresentationCommand::CreateJwt {
+      holder,
+      fragment,
+      credential,
+      nonce,
+      audience,
+      expiration_date,
+    } => {
+      let holder_document = ctx.resolve(&holder).await?;
+      let mut builder = PresentationBuilder::new(holder_document.id().to_url().into(), Object::default());
+      for credential in credential {
+        builder = builder.credential(Jwt::new(read_arg(&credential)?));
+      }
+      let presentation = builder.build().context("invalid presentation")?;
+
+      let mut signature_options = JwsSignatureOptions::default();
+      if let Some(nonce) = nonce {
+        signature_options = signature_options.nonce(nonce);
+      }
+      let mut presentation_options = JwtPresentationOptions::default();
+      if let Some(audience) = audience {
+        presentation_options = presentation_options.audience(parse_url(&audience)?);
+      }
+      if let Some(expiration_date) = expiration_date {
+        presentation_options = presentation_options.expiration_date(parse_timestamp(&expiration_date)?);
+      }
+
+      let storage = ctx.open_storage()?;
+      let jwt = with_storage!(storage, storage => holder_document
+        .create_presentation_jwt(&presentation, storage, &fragment, &signature_options, &presentation_options)
+        .await
+        .context("failed to sign the presentation")?);
+
+      Ok(json!({ "jwt": jwt.as_str() }))
+    }
+    PresentationCommand::ValidateJwt { jwt, nonce, audience } => {
+      let jwt = Jwt::new(read_arg(&jwt)?);
+      let holder_did =
+        JwtPresentationValidatorUtils::extract_holder::<IotaDID>(&jwt).context("invalid presentation JWT")?;
+      let holder_document = ctx.resolve(holder_did.as_str()).await?;
+
+      let mut verifier_options = JwsVerificationOptions::default();
+      if let Some(nonce) = nonce {
+        verifier_options = verifier_options.nonce(nonce);
+      }
+      let validation_options =
+        JwtPresentationValidationOptions::default().presentation_verifier_options(verifier_options);
+      let presentation = JwtPresentationValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+        .validate::<_, Jwt, Object>(&jwt, &holder_document, &validation_options)
+        .context("invalid presentation")?;
+      check_audience(audience.as_deref(), presentation.aud.as_ref())?;
+
+      // Every credential must be valid and have the holder as subject.
+      let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+      let credential_options = JwtCredentialValidationOptions::default()
+        .subject_holder_relationship(holder_did.to_url().into(), SubjectHolderRelationship::AlwaysSubject);
+      let mut credentials = Vec::new();
+      for (index, credential_jwt) in presentation.presentation.verifiable_credential.iter().enumerate() {
+        let issuer_did = JwtCredentialValidatorUtils::extract_issuer_from_jwt::<IotaDID>(credential_jwt)
+          .with_context(|| format!("invalid credential {index}"))?;
+        let issuer_document = ctx.resolve(issuer_did.as_str()).await?;
+        let credential = validator
+          .validate::<_, Object>(
+            credential_jwt,
+            &issuer_document,
+            &credential_options,
+            FailFast::FirstError,
+          )
+          .with_context(|| format!("invalid credential {index}"))?
+          .credential;
+        credentials.push(credential);
+      }
+
+      Ok(json!({ "presentation": presentation.presentation, "credentials": credentials }))
+    }
+    PresentationCommand::CreateSdJwtVc {
+      sd_jwt_vc,
+      conceal,
+      holder_method_id,
+      nonce,
+      aud,
+    } => {
+      let sd_jwt_vc = SdJwtVc::parse(&read_arg(&sd_jwt_vc)?).context("invalid SD-JWT VC")?;
+      let mut builder = sd_jwt_vc.into_presentation(&Sha256Hasher::new())?;
+      for path in conceal {
+        builder = builder
+          .conceal(&path)
+          .with_context(|| format!("cannot conceal \"{path}\""))?;
+      }
+      let (mut presentation, _) = builder.finish()?;
+
+      // The KB-JWT is computed over the disclosures that are actually presented.
+      if let Some(holder_method_id) = holder_method_id {
+        let (holder_document, fragment, holder_jwk) = resolve_method(ctx, &holder_method_id).await?;
+        let alg = jwk_alg(&holder_jwk)?;
+
+        let mut kb_builder = KeyBindingJwtBuilder::default().iat(Timestamp::now_utc().to_unix());
+        if let Some(nonce) = nonce {
+          kb_builder = kb_builder.nonce(nonce);
+        }
+        if let Some(aud) = aud {
+          kb_builder = kb_builder.aud(aud);
+        }
+        let storage = ctx.open_storage()?;
+        let kb_jwt = with_storage!(storage, storage => {
+          let signer = DocumentJwsSigner {
+            document: &holder_document,
+            storage,
+            fragment: &fragment,
+          };
+          kb_builder
+            .finish(&presentation, &Sha256Hasher::new(), &alg, &signer)
+            .await
+            .context("failed to sign the key binding JWT")?
+        });
+
+        (presentation, _) = presentation
+          .into_presentation(&Sha256Hasher::new())?
+          .attach_key_binding_jwt(kb_jwt)
+          .finish()?;
+      }
+
+      Ok(json!({ "sd_jwt_vc": presentation.to_string() }))
+    }
+    PresentationCommand::CreateJpt {
+      jpt,
+      conceal,
+      nonce,
+      audience,
+    } => {
+      let credential_jpt = Jpt::new(read_arg(&jpt)?);
+      let issuer_did = JptCredentialValidatorUtils::extract_issuer_from_issued_jpt::<IotaDID>(&credential_jpt)
+        .context("invalid credential JPT")?;
+      let issuer_document = ctx.resolve(issuer_did.as_str()).await?;
+      let credential = JptCredentialValidator::validate::<_, Object>(
+        &credential_jpt,
+        &issuer_document,
+        &JptCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .context("invalid credential")?;
+
+      let method_id = credential
+        .decoded_jwp
+        .get_issuer_protected_header()
+        .kid()
+        .context("the credential's header is missing a `kid`")?;
+
+      let mut presentation = SelectiveDisclosurePresentation::new(&credential.decoded_jwp);
+      for path in conceal {
+        presentation
+          .conceal_in_subject(&path)
+          .with_context(|| format!("cannot conceal \"{path}\""))?;
+      }
+
+      let mut presentation_options = JwpPresentationOptions::default();
+      if let Some(nonce) = nonce {
+        presentation_options = presentation_options.nonce(nonce);
+      }
+      if let Some(audience) = audience {
+        presentation_options = presentation_options.audience(parse_url(&audience)?);
+      }
+
+      let presentation_jpt = issuer_document
+        .create_presentation_jpt(&mut presentation, method_id, &presentation_options)
+        .await
+        .context("failed to derive the presentation")?;
+
+      Ok(json!({ "jpt": presentation_jpt.as_str() }))
+    }
+    PresentationCommand::ValidateJpt { jpt, nonce, audience } => {
+      let presentation_jpt = Jpt::new(read_arg(&jpt)?);
+      let issuer_did = JptPresentationValidatorUtils::extract_issuer_from_presented_jpt::<IotaDID>(&presentation_jpt)
+        .context("invalid presentation JPT")?;
+      let issuer_document = ctx.resolve(issuer_did.as_str()).await?;
+
+      let mut validation_options = JptPresentationValidationOptions::default();
+      if let Some(nonce) = nonce {
+        validation_options = validation_options.nonce(nonce);
+      }
+      let presentation = JptPresentationValidator::validate::<_, Object>(
+        &presentation_jpt,
+        &issuer_document,
+        &validation_options,
+        FailFast::FirstError,
+      )
+      .context("invalid presentation")?;
+      check_audience(audience.as_deref(), presentation.aud.as_ref())?;
+
+      Ok(json!({ "credential": presentation.credential }))
+    }
+  }
+}
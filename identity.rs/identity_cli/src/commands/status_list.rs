@@ -0,0 +1,130 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Context as _;
+use clap::Subcommand;
+use clap::ValueEnum;
+use identity_iota::core::FromJson;
+use identity_iota::credential::status_list_2021::CredentialStatus;
+use identity_iota::credential::status_list_2021::StatusList2021;
+use identity_iota::credential::status_list_2021::StatusList2021Credential;
+use identity_iota::credential::status_list_2021::StatusList2021CredentialBuilder;
+use identity_iota::credential::status_list_2021::StatusPurpose;
+use identity_iota::credential::Issuer;
+use serde_json::json;
+use serde_json::Value;
+
+use super::parse_url;
+use super::read_arg;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Purpose {
+  Revocation,
+  Suspension,
+}
+
+impl From<Purpose> for StatusPurpose {
+  fn from(value: Purpose) -> Self {
+    match value {
+      Purpose::Revocation => StatusPurpose::Revocation,
+      Purpose::Suspension => StatusPurpose::Suspension,
+    }
+  }
+}
+
+#[derive(Debug, Subcommand)]
+pub enum StatusListCommand {
+  /// Creates an unsigned status list 2021 credential with every entry unset.
+  Create {
+    #[arg(long, value_enum, default_value = "revocation")]
+    purpose: Purpose,
+    /// Number of entries, at least 131072 (16KB).
+    #[arg(long)]
+    length: Option<usize>,
+    /// URL of the issuer, e.g. its DID.
+    #[arg(long)]
+    issuer: String,
+    /// URL the status list is published at.
+    #[arg(long)]
+    id: Option<String>,
+  },
+  /// Sets entries of a status list 2021 credential.
+  Update {
+    /// Status list credential as JSON: inline, `@<path>` or `-` for the standard input.
+    credential: String,
+    /// Entry to set, as `<index>=<true|false>`; may be repeated.
+    #[arg(long, required = true, value_parser = parse_entry)]
+    set: Vec<(usize, bool)>,
+  },
+  /// Prints the status of an entry of a status list 2021 credential.
+  Check {
+    /// Status list credential as JSON: inline, `@<path>` or `-` for the standard input.
+    credential: String,
+    #[arg(long)]
+    index: usize,
+  },
+}
+
+fn parse_entry(entry: &str) -> Result<(usize, bool), String> {
+  let (index, value) = entry
+    .split_once('=')
+    .ok_or_else(|| format!("\"{entry}\" is not of the form <index>=<true|false>"))?;
+  let index = index.parse().map_err(|_| format!("\"{index}\" is not an index"))?;
+  let value = value
+    .parse()
+    .map_err(|_| format!("\"{value}\" is neither true nor false"))?;
+
+  Ok((index, value))
+}
+
+pub fn run(command: StatusListCommand) -> anyhow::Result<Value> {
+  match command {
+    StatusListCommand::Create {
+      purpose,
+      length,
+      issuer,
+      id,
+    } => {
+      let status_list = length
+        .map(StatusList2021::new)
+        .unwrap_or(Ok(StatusList2021::default()))
+        .context("a status list must have at least 131072 entries")?;
+
+      let mut builder = StatusList2021CredentialBuilder::new(status_list)
+        .issuer(Issuer::Url(parse_url(&issuer)?))
+        .purpose(purpose.into());
+      if let Some(id) = id {
+        builder = builder.subject_id(parse_url(&id)?);
+      }
+      let credential = builder.build().context("invalid status list credential")?;
+
+      Ok(json!(credential))
+    }
+    StatusListCommand::Update { credential, set } => {
+      let mut credential =
+        StatusList2021Credential::from_json(&read_arg(&credential)?).context("invalid status list credential")?;
+      credential
+        .update(move |status_list| {
+          for (index, value) in set {
+            status_list.set_entry(index, value)?;
+          }
+
+          Ok(())
+        })
+        .context("failed to update the status list")?;
+
+      Ok(json!(credential))
+    }
+    StatusListCommand::Check { credential, index } => {
+      let credential =
+        StatusList2021Credential::from_json(&read_arg(&credential)?).context("invalid status list credential")?;
+      let status = match credential.entry(index).context("failed to read the entry")? {
+        CredentialStatus::Revoked => "revoked",
+        CredentialStatus::Suspended => "suspended",
+        CredentialStatus::Valid => "valid",
+      };
+
+      Ok(json!({ "index": index, "status": status }))
+    }
+  }
+}
@@ -0,0 +1,124 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::OnceLock;
+
+use anyhow::Context as _;
+use clap::Args;
+use identity_iota::iota::rebased::client::IdentityClient;
+use identity_iota::iota::rebased::client::IdentityClientReadOnly;
+#[cfg(all(test, feature = "local-ledger"))]
+use identity_iota::iota::rebased::local_ledger::LocalLedger;
+#[cfg(all(test, feature = "local-ledger"))]
+use identity_iota::iota::rebased::local_ledger::LocalSigner;
+use identity_iota::iota::IotaDID;
+use identity_iota::iota::IotaDocument;
+use iota_sdk::types::base_types::ObjectID;
+use iota_sdk::IotaClientBuilder;
+
+use crate::storage::CliStorage;
+use crate::storage::StorageArgs;
+use crate::storage::TxSigner;
+
+const MAINNET_URL: &str = "https://api.mainnet.iota.cafe";
+
+#[derive(Debug, Args)]
+pub struct NetworkArgs {
+  /// Network to use: `localnet`, `devnet`, `testnet`, `mainnet` or the URL of a node's API.
+  #[arg(long, env = "IOTA_NETWORK", default_value = "localnet", global = true)]
+  pub network: String,
+  /// Package ID of `iota_identity`, required for networks whose package ID isn't known to the client.
+  #[arg(long, env = "IOTA_IDENTITY_PKG_ID", global = true)]
+  pub identity_pkg_id: Option<String>,
+  /// Gas budget of the transactions.
+  #[arg(long, default_value_t = 50_000_000, global = true)]
+  pub gas_budget: u64,
+}
+
+/// Options shared by every command, and the storage they use.
+pub struct Context {
+  pub network: NetworkArgs,
+  pub storage: StorageArgs,
+  opened_storage: OnceLock<CliStorage>,
+  /// Ledger used instead of the network, and the funded signer of its transactions.
+  #[cfg(all(test, feature = "local-ledger"))]
+  pub(crate) local_ledger: Option<(LocalLedger, LocalSigner)>,
+}
+
+impl Context {
+  pub fn new(network: NetworkArgs, storage: StorageArgs) -> Self {
+    Self {
+      network,
+      storage,
+      opened_storage: OnceLock::new(),
+      #[cfg(all(test, feature = "local-ledger"))]
+      local_ledger: None,
+    }
+  }
+
+  /// Opens the storage on first use. Commands run with the same context share it, which matters for the memory
+  /// storage.
+  pub fn open_storage(&self) -> anyhow::Result<&CliStorage> {
+    if let Some(storage) = self.opened_storage.get() {
+      return Ok(storage);
+    }
+    let storage = self.storage.open()?;
+    Ok(self.opened_storage.get_or_init(|| storage))
+  }
+
+  pub async fn read_only_client(&self) -> anyhow::Result<IdentityClientReadOnly> {
+    #[cfg(all(test, feature = "local-ledger"))]
+    if let Some((ledger, _)) = &self.local_ledger {
+      return IdentityClientReadOnly::new_with_local_ledger(ledger.clone())
+        .await
+        .context("failed to create the identity client");
+    }
+
+    let builder = IotaClientBuilder::default();
+    let iota_client = match self.network.network.as_str() {
+      "localnet" => builder.build_localnet().await,
+      "devnet" => builder.build_devnet().await,
+      "testnet" => builder.build_testnet().await,
+      "mainnet" => builder.build(MAINNET_URL).await,
+      api_endpoint => builder.build(api_endpoint).await,
+    }
+    .with_context(|| format!("failed to connect to {}", self.network.network))?;
+
+    let client = match &self.network.identity_pkg_id {
+      Some(identity_pkg_id) => {
+        let identity_pkg_id: ObjectID = identity_pkg_id.parse().context("invalid package ID")?;
+        IdentityClientReadOnly::new_with_pkg_id(iota_client, identity_pkg_id).await
+      }
+      None => IdentityClientReadOnly::new(iota_client).await,
+    };
+
+    client.context("failed to create the identity client")
+  }
+
+  /// Returns a client whose transactions are signed with the configured signer.
+  pub async fn identity_client<'s>(&self, storage: &'s CliStorage) -> anyhow::Result<IdentityClient<TxSigner<'s>>> {
+    #[cfg(all(test, feature = "local-ledger"))]
+    let signer = match &self.local_ledger {
+      Some((_, signer)) => TxSigner::Local(signer.clone()),
+      None => self.storage.tx_signer(storage).await?,
+    };
+    #[cfg(not(all(test, feature = "local-ledger")))]
+    let signer = self.storage.tx_signer(storage).await?;
+    IdentityClient::new(self.read_only_client().await?, signer)
+      .await
+      .context("failed to create the identity client")
+  }
+
+  pub async fn resolve(&self, did: &str) -> anyhow::Result<IotaDocument> {
+    let client = self.read_only_client().await?;
+    resolve(&client, did).await
+  }
+}
+
+pub async fn resolve(client: &IdentityClientReadOnly, did: &str) -> anyhow::Result<IotaDocument> {
+  let did = IotaDID::parse(did).with_context(|| format!("\"{did}\" is not a valid IOTA DID"))?;
+  client
+    .resolve_did(&did)
+    .await
+    .with_context(|| format!("failed to resolve {did}"))
+}
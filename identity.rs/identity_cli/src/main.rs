@@ -0,0 +1,113 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! `identity`: manages keys, DIDs and verifiable credentials from the command line.
+//!
+//! Every command prints its result as JSON on the standard output; errors are printed as
+//! `{"error": "<message>"}` on the standard error, with a non-zero exit code.
+
+mod commands;
+mod context;
+mod storage;
+#[cfg(test)]
+mod tests;
+
+use std::process::ExitCode;
+
+use clap::Parser;
+use clap::Subcommand;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::commands::credential::CredentialCommand;
+use crate::commands::did::DidCommand;
+use crate::commands::domain_linkage::DomainLinkageCommand;
+use crate::commands::key::KeyCommand;
+use crate::commands::presentation::PresentationCommand;
+use crate::commands::status_list::StatusListCommand;
+use crate::context::Context;
+use crate::context::NetworkArgs;
+use crate::storage::StorageArgs;
+use crate::storage::StorageKind;
+
+#[derive(Debug, Parser)]
+#[command(name = "identity", version, about)]
+struct Cli {
+  #[command(flatten)]
+  network: NetworkArgs,
+  #[command(flatten)]
+  storage: StorageArgs,
+  /// Prints the output on a single line.
+  #[arg(long, global = true)]
+  compact: bool,
+  #[command(subcommand)]
+  command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+  /// Manages keys in the storage.
+  #[command(subcommand)]
+  Key(KeyCommand),
+  /// Creates, resolves, updates and deactivates DID documents.
+  #[command(subcommand)]
+  Did(DidCommand),
+  /// Issues and validates credentials as JWTs, SD-JWT VCs and JPTs.
+  #[command(subcommand)]
+  Credential(CredentialCommand),
+  /// Creates and validates presentations.
+  #[command(subcommand)]
+  Presentation(PresentationCommand),
+  /// Creates and updates status list 2021 credentials.
+  #[command(subcommand)]
+  StatusList(StatusListCommand),
+  /// Links DIDs and domains.
+  #[command(subcommand)]
+  DomainLinkage(DomainLinkageCommand),
+}
+
+async fn run(command: Command, ctx: &Context) -> anyhow::Result<Value> {
+  // Every invocation gets a new memory storage.
+  if matches!(command, Command::Key(_)) && ctx.storage.storage == StorageKind::Memory {
+    anyhow::bail!("keys of the memory storage are lost when the command exits, use the stronghold or keytool storage");
+  }
+
+  match command {
+    Command::Key(command) => commands::key::run(command, ctx).await,
+    Command::Did(command) => commands::did::run(command, ctx).await,
+    Command::Credential(command) => commands::credential::run(command, ctx).await,
+    Command::Presentation(command) => commands::presentation::run(command, ctx).await,
+    Command::StatusList(command) => commands::status_list::run(command),
+    Command::DomainLinkage(command) => commands::domain_linkage::run(command, ctx).await,
+  }
+}
+
+fn print(value: &Value, compact: bool) -> String {
+  if compact {
+    value.to_string()
+  } else {
+    serde_json::to_string_pretty(value).expect("JSON values are serializable")
+  }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let Cli {
+    network,
+    storage,
+    compact,
+    command,
+  } = Cli::parse();
+  let ctx = Context::new(network, storage);
+
+  match run(command, &ctx).await {
+    Ok(output) => {
+      println!("{}", print(&output, compact));
+      ExitCode::SUCCESS
+    }
+    Err(error) => {
+      eprintln!("{}", print(&json!({ "error": format!("{error:#}") }), compact));
+      ExitCode::FAILURE
+    }
+  }
+}
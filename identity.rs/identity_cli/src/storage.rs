@@ -0,0 +1,234 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Key storages selectable on the command line, and the signer of IOTA transactions.
+
+use std::path::PathBuf;
+
+use anyhow::Context as _;
+use async_trait::async_trait;
+use clap::Args;
+use clap::ValueEnum;
+use identity_iota::iota::rebased::client::IotaKeySignature;
+#[cfg(all(test, feature = "local-ledger"))]
+use identity_iota::iota::rebased::local_ledger::LocalSigner;
+use identity_iota::iota_interaction::types::base_types::IotaAddress;
+use identity_iota::iota_interaction::types::crypto::PublicKey;
+use identity_iota::iota_interaction::types::crypto::Signature;
+use identity_iota::iota_interaction::types::transaction::TransactionData;
+use identity_iota::iota_interaction::KeytoolSigner;
+use identity_iota::iota_interaction::KeytoolStorage as Keytool;
+use identity_iota::storage::JwkMemStore;
+use identity_iota::storage::KeyId;
+use identity_iota::storage::KeyIdMemstore;
+use identity_iota::storage::KeyType;
+use identity_iota::storage::KeytoolStorage;
+use identity_iota::storage::Storage;
+use identity_iota::storage::StorageSigner;
+use identity_stronghold::StrongholdKeyType;
+use identity_stronghold::StrongholdStorage;
+use iota_sdk_legacy::client::stronghold::StrongholdAdapter;
+use secret_storage::Error as SecretStorageError;
+use secret_storage::Signer;
+
+pub type MemStorage = Storage<JwkMemStore, KeyIdMemstore>;
+pub type StrongholdKeyStorage = Storage<StrongholdStorage, StrongholdStorage>;
+
+/// Runs `$body` with `$storage` bound to the concrete storage of a [`CliStorage`].
+macro_rules! with_storage {
+  ($cli_storage:expr, $storage:ident => $body:expr) => {
+    match $cli_storage {
+      $crate::storage::CliStorage::Memory($storage) => $body,
+      $crate::storage::CliStorage::Stronghold($storage) => $body,
+      $crate::storage::CliStorage::Keytool($storage) => $body,
+    }
+  };
+}
+
+/// Like [`with_storage`], for operations on BBS+ keys which the keytool doesn't support.
+macro_rules! with_bbs_storage {
+  ($cli_storage:expr, $storage:ident => $body:expr) => {
+    match $cli_storage {
+      $crate::storage::CliStorage::Memory($storage) => $body,
+      $crate::storage::CliStorage::Stronghold($storage) => $body,
+      $crate::storage::CliStorage::Keytool(_) => {
+        anyhow::bail!("the keytool storage doesn't support BBS+ keys")
+      }
+    }
+  };
+}
+
+pub(crate) use with_bbs_storage;
+pub(crate) use with_storage;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StorageKind {
+  /// Keys only live for the duration of the command; meant for trying things out. Not available to `key`
+  /// commands.
+  Memory,
+  /// Keys are kept in a Stronghold snapshot.
+  Stronghold,
+  /// Keys are kept by the IOTA keytool.
+  Keytool,
+}
+
+#[derive(Debug, Args)]
+pub struct StorageArgs {
+  /// Storage holding the keys.
+  #[arg(
+    long,
+    value_enum,
+    env = "IDENTITY_STORAGE",
+    default_value = "stronghold",
+    global = true
+  )]
+  pub storage: StorageKind,
+  /// Stronghold snapshot, created if it doesn't exist.
+  #[arg(
+    long,
+    env = "STRONGHOLD_SNAPSHOT_PATH",
+    default_value = "identity.stronghold",
+    global = true
+  )]
+  pub stronghold_path: PathBuf,
+  /// Password of the Stronghold snapshot.
+  #[arg(long, env = "STRONGHOLD_PASSWORD", hide_env_values = true, global = true)]
+  pub stronghold_password: Option<String>,
+  /// `iota` binary used to access the keytool, looked up in `PATH` by default.
+  #[arg(long, env = "IOTA_BIN", global = true)]
+  pub iota_bin: Option<PathBuf>,
+  /// Stronghold key that signs and pays for IOTA transactions; the keytool signs them if unset.
+  #[arg(long, env = "IDENTITY_TX_KEY_ID", global = true)]
+  pub tx_key_id: Option<String>,
+  /// Keytool address that signs and pays for IOTA transactions; the keytool's active address by default.
+  #[arg(long, global = true)]
+  pub tx_address: Option<String>,
+}
+
+impl StorageArgs {
+  pub fn open(&self) -> anyhow::Result<CliStorage> {
+    Ok(match self.storage {
+      StorageKind::Memory => CliStorage::Memory(MemStorage::new(JwkMemStore::new(), KeyIdMemstore::new())),
+      StorageKind::Stronghold => {
+        let stronghold = self.stronghold()?;
+        CliStorage::Stronghold(Storage::new(stronghold.clone(), stronghold))
+      }
+      StorageKind::Keytool => CliStorage::Keytool(KeytoolStorage::from(self.keytool())),
+    })
+  }
+
+  /// Returns the signer of IOTA transactions.
+  pub async fn tx_signer<'s>(&self, storage: &'s CliStorage) -> anyhow::Result<TxSigner<'s>> {
+    if let Some(key_id) = &self.tx_key_id {
+      let CliStorage::Stronghold(storage) = storage else {
+        anyhow::bail!("--tx-key-id requires the stronghold storage");
+      };
+      let key_id = KeyId::new(key_id);
+      let public_key = storage
+        .key_id_storage()
+        .get_public_key_with_type(&key_id, StrongholdKeyType::Ed25519)
+        .await
+        .with_context(|| format!("no Ed25519 key \"{key_id}\" in the Stronghold"))?;
+
+      return Ok(TxSigner::Storage(StorageSigner::new(storage, key_id, public_key)));
+    }
+
+    let mut builder = self.keytool().signer();
+    if let Some(address) = &self.tx_address {
+      let address: IotaAddress = address.parse().context("invalid --tx-address")?;
+      builder = builder.with_address(address);
+    }
+    builder
+      .build()
+      .map(TxSigner::Keytool)
+      .context("failed to access the keytool")
+  }
+
+  /// Default key type of the verification methods generated in the storage.
+  pub fn default_key_type(&self) -> KeyType {
+    match self.storage {
+      StorageKind::Memory => JwkMemStore::ED25519_KEY_TYPE,
+      StorageKind::Stronghold => identity_stronghold::ED25519_KEY_TYPE,
+      StorageKind::Keytool => KeyType::from_static_str("ed25519"),
+    }
+  }
+
+  /// Default key type of the BBS+ verification methods generated in the storage.
+  pub fn default_bbs_key_type(&self) -> KeyType {
+    match self.storage {
+      StorageKind::Stronghold => identity_stronghold::BLS12381G2_KEY_TYPE,
+      _ => JwkMemStore::BLS12381G2_KEY_TYPE,
+    }
+  }
+
+  fn stronghold(&self) -> anyhow::Result<StrongholdStorage> {
+    let password = self
+      .stronghold_password
+      .clone()
+      .context("the stronghold storage requires a password, set STRONGHOLD_PASSWORD or --stronghold-password")?;
+    let adapter = StrongholdAdapter::builder()
+      .password(password)
+      .build(&self.stronghold_path)
+      .with_context(|| {
+        format!(
+          "failed to open the Stronghold at \"{}\"",
+          self.stronghold_path.display()
+        )
+      })?;
+
+    Ok(StrongholdStorage::new(adapter))
+  }
+
+  fn keytool(&self) -> Keytool {
+    match &self.iota_bin {
+      Some(iota_bin) => Keytool::new_with_custom_bin(iota_bin),
+      None => Keytool::new(),
+    }
+  }
+}
+
+pub enum CliStorage {
+  Memory(MemStorage),
+  Stronghold(StrongholdKeyStorage),
+  Keytool(KeytoolStorage),
+}
+
+/// Signer of IOTA transactions.
+pub enum TxSigner<'s> {
+  Keytool(KeytoolSigner),
+  Storage(StorageSigner<'s, StrongholdStorage, StrongholdStorage>),
+  #[cfg(all(test, feature = "local-ledger"))]
+  Local(LocalSigner),
+}
+
+#[async_trait]
+impl Signer<IotaKeySignature> for TxSigner<'_> {
+  type KeyId = String;
+
+  fn key_id(&self) -> String {
+    match self {
+      Self::Keytool(signer) => signer.address().to_string(),
+      Self::Storage(signer) => signer.key_id().to_string(),
+      #[cfg(all(test, feature = "local-ledger"))]
+      Self::Local(signer) => signer.address().to_string(),
+    }
+  }
+
+  async fn public_key(&self) -> Result<PublicKey, SecretStorageError> {
+    match self {
+      Self::Keytool(signer) => Signer::public_key(signer).await,
+      Self::Storage(signer) => Signer::public_key(signer).await,
+      #[cfg(all(test, feature = "local-ledger"))]
+      Self::Local(signer) => Signer::public_key(signer).await,
+    }
+  }
+
+  async fn sign(&self, data: &TransactionData) -> Result<Signature, SecretStorageError> {
+    match self {
+      Self::Keytool(signer) => signer.sign(data).await,
+      Self::Storage(signer) => signer.sign(data).await,
+      #[cfg(all(test, feature = "local-ledger"))]
+      Self::Local(signer) => signer.sign(data).await,
+    }
+  }
+}
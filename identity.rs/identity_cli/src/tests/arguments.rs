@@ -0,0 +1,89 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::error::ErrorKind;
+use clap::CommandFactory;
+
+use super::parse;
+use crate::commands::key::KeyCommand;
+use crate::commands::ProofAlg;
+use crate::context::Context;
+use crate::storage::StorageKind;
+use crate::Cli;
+use crate::Command;
+
+#[test]
+fn command_is_well_formed() {
+  Cli::command().debug_assert();
+}
+
+#[test]
+fn defaults() {
+  let cli = parse(&["key", "exists", "key-1"]).unwrap();
+
+  assert_eq!(cli.network.network, "localnet");
+  assert_eq!(cli.network.gas_budget, 50_000_000);
+  assert_eq!(cli.storage.storage, StorageKind::Stronghold);
+  assert_eq!(cli.storage.stronghold_path.to_str(), Some("identity.stronghold"));
+  assert!(!cli.compact);
+  assert!(matches!(
+    cli.command,
+    Command::Key(KeyCommand::Exists { key_id }) if key_id == "key-1"
+  ));
+}
+
+#[test]
+fn global_options_follow_subcommands() {
+  let cli = parse(&[
+    "key",
+    "generate",
+    "--storage",
+    "keytool",
+    "--network",
+    "devnet",
+    "--compact",
+  ])
+  .unwrap();
+
+  assert_eq!(cli.storage.storage, StorageKind::Keytool);
+  assert_eq!(cli.network.network, "devnet");
+  assert!(cli.compact);
+}
+
+#[test]
+fn key_generation_options() {
+  let cli = parse(&["key", "generate", "--proof-alg", "BLS12381-SHA256"]).unwrap();
+  assert!(matches!(
+    cli.command,
+    Command::Key(KeyCommand::Generate {
+      proof_alg: Some(ProofAlg::Bls12381Sha256),
+      ..
+    })
+  ));
+
+  let error = parse(&["key", "generate", "--alg", "ES256", "--proof-alg", "BLS12381-SHA256"]).unwrap_err();
+  assert_eq!(error.kind(), ErrorKind::ArgumentConflict);
+
+  let error = parse(&["key", "generate", "--proof-alg", "BBS"]).unwrap_err();
+  assert_eq!(error.kind(), ErrorKind::InvalidValue);
+}
+
+#[test]
+fn unknown_storages_are_rejected() {
+  let error = parse(&["--storage", "file", "key", "exists", "key-1"]).unwrap_err();
+  assert_eq!(error.kind(), ErrorKind::InvalidValue);
+}
+
+#[tokio::test]
+async fn key_commands_reject_the_memory_storage() {
+  let Cli {
+    network,
+    storage,
+    command,
+    ..
+  } = parse(&["--storage", "memory", "key", "generate"]).unwrap();
+  let ctx = Context::new(network, storage);
+
+  let error = crate::run(command, &ctx).await.unwrap_err();
+  assert!(error.to_string().contains("memory storage"));
+}
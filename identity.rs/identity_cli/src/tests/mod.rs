@@ -0,0 +1,15 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod arguments;
+#[cfg(feature = "local-ledger")]
+mod round_trip;
+
+use clap::Parser;
+
+use crate::Cli;
+
+/// Parses the arguments of an `identity` invocation.
+fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+  Cli::try_parse_from(std::iter::once("identity").chain(args.iter().copied()))
+}
@@ -0,0 +1,101 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_iota::iota::rebased::local_ledger::LocalLedger;
+use identity_iota::iota_interaction::types::crypto::SignatureScheme;
+use serde_json::json;
+use serde_json::Value;
+
+use super::parse;
+use crate::commands;
+use crate::context::Context;
+use crate::Cli;
+use crate::Command;
+
+/// Runs the commands of `args` against the memory storage and the ledger of `ctx`, bypassing the checks meant
+/// for single invocations.
+async fn run(ctx: &Context, args: &[&str]) -> anyhow::Result<Value> {
+  match parse(args)?.command {
+    Command::Key(command) => commands::key::run(command, ctx).await,
+    command => crate::run(command, ctx).await,
+  }
+}
+
+/// Returns a context using the memory storage and a new local ledger, whose transactions are paid for by a funded
+/// signer.
+fn local_context() -> anyhow::Result<Context> {
+  let Cli { network, storage, .. } = parse(&["--storage", "memory", "did", "resolve", "did:iota:0x0"])?;
+  let ledger = LocalLedger::new();
+  let signer = ledger.new_signer(SignatureScheme::ED25519)?;
+  ledger.request_funds(signer.address());
+
+  let mut ctx = Context::new(network, storage);
+  ctx.local_ledger = Some((ledger, signer));
+  Ok(ctx)
+}
+
+#[tokio::test]
+async fn keys_outlive_commands_of_the_same_context() -> anyhow::Result<()> {
+  let ctx = local_context()?;
+
+  let generated = run(&ctx, &["key", "generate"]).await?;
+  let key_id = generated["key_id"].as_str().unwrap();
+  assert_eq!(generated["jwk"]["alg"], "EdDSA");
+  assert!(generated["jwk"].get("d").is_none());
+
+  let output = run(&ctx, &["key", "exists", key_id]).await?;
+  assert_eq!(output["exists"], true);
+
+  run(&ctx, &["key", "delete", key_id]).await?;
+  let output = run(&ctx, &["key", "exists", key_id]).await?;
+  assert_eq!(output["exists"], false);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn credentials_are_signed_and_verified_with_a_created_did() -> anyhow::Result<()> {
+  let ctx = local_context()?;
+
+  let created = run(
+    &ctx,
+    &["did", "create", "--scope", "assertion-method", "--fragment", "key-1"],
+  )
+  .await?;
+  let did = created["did"].as_str().unwrap();
+  assert_eq!(created["fragment"], "key-1");
+
+  let resolved = run(&ctx, &["did", "resolve", did]).await?;
+  assert_eq!(resolved["id"], did);
+
+  let credential = json!({
+    "@context": "https://www.w3.org/2018/credentials/v1",
+    "type": "VerifiableCredential",
+    "issuer": did,
+    "issuanceDate": "2025-01-01T00:00:00Z",
+    "credentialSubject": { "id": "did:example:alice", "name": "Alice" }
+  })
+  .to_string();
+  let issued = run(&ctx, &["credential", "issue-jwt", &credential, "--fragment", "key-1"]).await?;
+  let jwt = issued["jwt"].as_str().unwrap();
+
+  let validated = run(&ctx, &["credential", "validate-jwt", jwt]).await?;
+  assert_eq!(validated["credential"]["issuer"], did);
+  assert_eq!(validated["credential"]["credentialSubject"]["name"], "Alice");
+
+  // A credential signed by another key of the storage doesn't verify against the DID document.
+  let other = run(&ctx, &["did", "create", "--fragment", "key-1"]).await?;
+  let other_did = other["did"].as_str().unwrap();
+  let header_and_payload = jwt.rsplit_once('.').unwrap().0;
+  let forged_credential = credential.replace(did, other_did);
+  let forged = run(
+    &ctx,
+    &["credential", "issue-jwt", &forged_credential, "--fragment", "key-1"],
+  )
+  .await?;
+  let forged_signature = forged["jwt"].as_str().unwrap().rsplit_once('.').unwrap().1;
+  let tampered = format!("{header_and_payload}.{forged_signature}");
+  assert!(run(&ctx, &["credential", "validate-jwt", &tampered]).await.is_err());
+
+  Ok(())
+}
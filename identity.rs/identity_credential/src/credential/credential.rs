@@ -5,6 +5,8 @@ use core::fmt::Display;
 use core::fmt::Formatter;
 
 use identity_core::convert::ToJson;
+#[cfg(feature = "cose")]
+use identity_verification::jose::cwt::CwtClaims;
 #[cfg(feature = "jpt-bbs-plus")]
 use jsonprooftoken::jpt::claims::JptClaims;
 use once_cell::sync::Lazy;
@@ -177,6 +179,22 @@ impl<T> Credential<T> {
       .map_err(|err| Error::JwtClaimsSetSerializationError(err.into()))
   }
 
+  /// Serializes the [`Credential`] as a CWT claims set.
+  ///
+  /// The claims are those of [`Self::serialize_jwt`], with the registered JWT claims mapped to their CWT
+  /// counterparts and every other claim, such as `vc`, kept under its name as a text label.
+  #[cfg(feature = "cose")]
+  pub fn serialize_cwt(&self, custom_claims: Option<Object>) -> Result<CwtClaims>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    let jwt_representation: CredentialJwtClaims<'_, T> = CredentialJwtClaims::new(self, custom_claims)?;
+    let jwt_claims = jwt_representation
+      .to_json_value()
+      .map_err(|err| Error::JwtClaimsSetSerializationError(err.into()))?;
+    super::cwt::jwt_to_cwt_claims(jwt_claims)
+  }
+
   ///Serializes the [`Credential`] as a JPT claims set
   #[cfg(feature = "jpt-bbs-plus")]
   pub fn serialize_jpt(&self, custom_claims: Option<Object>) -> Result<JptClaims>
@@ -0,0 +1,106 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use ciborium::Value as CborValue;
+use coset::Label;
+use identity_verification::jose::cwt::CwtClaims;
+use serde_json::Value;
+
+use crate::Error;
+use crate::Result;
+
+/// A wrapper around a CBOR Web Token (CWT), i.e. an encoded `COSE_Sign1` whose payload is a CWT claims set.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Cwt(Vec<u8>);
+
+impl Cwt {
+  /// Creates a new `Cwt` from the given bytes.
+  pub fn new(cwt_bytes: Vec<u8>) -> Self {
+    Self(cwt_bytes)
+  }
+
+  /// Returns a reference of the CWT bytes.
+  pub fn as_bytes(&self) -> &[u8] {
+    &self.0
+  }
+}
+
+impl From<Vec<u8>> for Cwt {
+  fn from(cwt: Vec<u8>) -> Self {
+    Self::new(cwt)
+  }
+}
+
+impl From<Cwt> for Vec<u8> {
+  fn from(cwt: Cwt) -> Self {
+    cwt.0
+  }
+}
+
+/// Converts a JWT claims set into a CWT claims set.
+///
+/// The registered claims `iss`, `sub`, `exp`, `nbf`, `iat` and `jti` are mapped to their CWT counterparts,
+/// the latter becoming the UTF-8 encoded `cti`. All other claims, such as `vc`, keep their name as a text label.
+pub(crate) fn jwt_to_cwt_claims(jwt_claims: Value) -> Result<CwtClaims> {
+  let Value::Object(jwt_claims) = jwt_claims else {
+    return Err(Error::JwtClaimsSetSerializationError("expected a JSON object".into()));
+  };
+
+  let mut claims = CwtClaims::new();
+  for (name, value) in jwt_claims {
+    match (name.as_str(), value) {
+      ("iss", Value::String(iss)) => claims.set_iss(iss),
+      ("sub", Value::String(sub)) => claims.set_sub(sub),
+      ("jti", Value::String(jti)) => claims.set_cti(jti.into_bytes()),
+      ("exp", Value::Number(exp)) if exp.is_i64() => claims.set_exp(exp.as_i64().unwrap_or_default()),
+      ("nbf", Value::Number(nbf)) if nbf.is_i64() => claims.set_nbf(nbf.as_i64().unwrap_or_default()),
+      ("iat", Value::Number(iat)) if iat.is_i64() => claims.set_iat(iat.as_i64().unwrap_or_default()),
+      (_, value) => {
+        let value = CborValue::serialized(&value).map_err(|err| Error::JwtClaimsSetSerializationError(err.into()))?;
+        claims.set_custom(Label::Text(name), value);
+      }
+    }
+  }
+
+  Ok(claims)
+}
+
+/// Converts a CWT claims set back into a JWT claims set, see [`jwt_to_cwt_claims`].
+///
+/// Custom claims with an integer label have no JWT counterpart and are ignored.
+pub(crate) fn cwt_to_jwt_claims(claims: &CwtClaims) -> Result<Value> {
+  let mut jwt_claims = serde_json::Map::new();
+  for (label, value) in claims.custom() {
+    if let Label::Text(name) = label {
+      let value: Value = value
+        .deserialized()
+        .map_err(|err| Error::JwtClaimsSetDeserializationError(err.into()))?;
+      jwt_claims.insert(name.clone(), value);
+    }
+  }
+
+  if let Some(iss) = claims.iss() {
+    jwt_claims.insert("iss".to_owned(), iss.into());
+  }
+  if let Some(sub) = claims.sub() {
+    jwt_claims.insert("sub".to_owned(), sub.into());
+  }
+  if let Some(aud) = claims.aud() {
+    jwt_claims.insert("aud".to_owned(), aud.into());
+  }
+  if let Some(exp) = claims.exp() {
+    jwt_claims.insert("exp".to_owned(), exp.into());
+  }
+  if let Some(nbf) = claims.nbf() {
+    jwt_claims.insert("nbf".to_owned(), nbf.into());
+  }
+  if let Some(iat) = claims.iat() {
+    jwt_claims.insert("iat".to_owned(), iat.into());
+  }
+  if let Some(cti) = claims.cti() {
+    let jti = std::str::from_utf8(cti).map_err(|err| Error::JwtClaimsSetDeserializationError(err.into()))?;
+    jwt_claims.insert("jti".to_owned(), jti.into());
+  }
+
+  Ok(Value::Object(jwt_claims))
+}
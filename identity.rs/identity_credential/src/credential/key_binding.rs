@@ -0,0 +1,121 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use identity_verification::jwk::Jwk;
+
+use crate::error::Error;
+use crate::error::Result;
+
+/// The name of the JWT claim holding a [`KeyBinding`].
+pub const CNF_CLAIM: &str = "cnf";
+
+/// A proof-of-possession key binding of a credential, expressed as the JWT confirmation (`cnf`) claim.
+///
+/// A credential bound to a key can only be presented by the holder of the corresponding private key,
+/// independently of the DID identifying the credential's subject.
+///
+/// [More Info](https://www.rfc-editor.org/rfc/rfc7800#section-3.1)
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum KeyBinding {
+  /// The public key the credential is bound to.
+  Jwk(Jwk),
+  /// The identifier of the key the credential is bound to.
+  Kid(String),
+  /// The base64url encoded JWK SHA-256 thumbprint of the key the credential is bound to.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc9449#section-6.1)
+  Jkt(String),
+}
+
+impl KeyBinding {
+  /// Creates a [`KeyBinding`] embedding the public parameters of `jwk`.
+  ///
+  /// # Errors
+  /// Fails if `jwk` has no public representation, e.g. because it is a symmetric key.
+  pub fn from_jwk(jwk: &Jwk) -> Result<Self> {
+    jwk
+      .to_public()
+      .map(Self::Jwk)
+      .ok_or(Error::InvalidKeyBinding("the key has no public representation"))
+  }
+
+  /// Creates a [`KeyBinding`] referencing `jwk` by its SHA-256 thumbprint.
+  pub fn from_jwk_thumbprint(jwk: &Jwk) -> Self {
+    Self::Jkt(jwk.thumbprint_sha256_b64())
+  }
+
+  /// Returns the public key embedded in this binding, if any.
+  pub fn jwk(&self) -> Option<&Jwk> {
+    match self {
+      Self::Jwk(jwk) => Some(jwk),
+      _ => None,
+    }
+  }
+
+  /// Returns `true` if `jwk` is the key this binding refers to.
+  ///
+  /// Embedded keys and thumbprints are compared by the thumbprint of the public key, whereas a key identifier must
+  /// match the `kid` of `jwk`.
+  pub fn matches(&self, jwk: &Jwk) -> bool {
+    match self {
+      Self::Jwk(bound) => bound.thumbprint_sha256_b64() == jwk.thumbprint_sha256_b64(),
+      Self::Kid(kid) => jwk.kid() == Some(kid.as_str()),
+      Self::Jkt(thumbprint) => *thumbprint == jwk.thumbprint_sha256_b64(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_core::convert::ToJson;
+
+  use super::*;
+
+  fn jwk() -> Jwk {
+    Jwk::from_json_value(serde_json::json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+      "kid": "key-1"
+    }))
+    .unwrap()
+  }
+
+  #[test]
+  fn test_cnf_serialization() {
+    let jwk = jwk();
+    let expected = serde_json::json!({ "jkt": jwk.thumbprint_sha256_b64() });
+    assert_eq!(KeyBinding::from_jwk_thumbprint(&jwk).to_json_value().unwrap(), expected);
+
+    let binding = KeyBinding::from_json_value(serde_json::json!({ "kid": "key-1" })).unwrap();
+    assert_eq!(binding, KeyBinding::Kid("key-1".to_owned()));
+
+    let binding = KeyBinding::from_json_value(serde_json::json!({ "jwk": jwk.to_json_value().unwrap() })).unwrap();
+    assert_eq!(binding.jwk(), Some(&jwk));
+  }
+
+  #[test]
+  fn test_matches() {
+    let jwk = jwk();
+    let mut other = jwk.clone();
+    other.set_kid("key-2");
+
+    assert!(KeyBinding::from_jwk(&jwk).unwrap().matches(&other));
+    assert!(KeyBinding::from_jwk_thumbprint(&jwk).matches(&other));
+    assert!(KeyBinding::Kid("key-1".to_owned()).matches(&jwk));
+    assert!(!KeyBinding::Kid("key-1".to_owned()).matches(&other));
+    assert!(!KeyBinding::Jkt("invalid".to_owned()).matches(&jwk));
+  }
+
+  #[test]
+  fn test_symmetric_keys_cannot_be_embedded() {
+    let jwk = Jwk::from_json_value(serde_json::json!({ "kty": "oct", "k": "c2VjcmV0" })).unwrap();
+    assert!(matches!(KeyBinding::from_jwk(&jwk), Err(Error::InvalidKeyBinding(_))));
+  }
+}
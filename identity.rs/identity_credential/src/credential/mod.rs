@@ -7,6 +7,8 @@
 
 mod builder;
 mod credential;
+#[cfg(feature = "cose")]
+mod cwt;
 mod evidence;
 mod issuer;
 #[cfg(feature = "jpt-bbs-plus")]
@@ -16,6 +18,7 @@ mod jwp_credential_options;
 mod jws;
 mod jwt;
 mod jwt_serialization;
+mod key_binding;
 mod linked_domain_service;
 mod linked_verifiable_presentation_service;
 mod policy;
@@ -29,6 +32,8 @@ mod subject;
 
 pub use self::builder::CredentialBuilder;
 pub use self::credential::Credential;
+#[cfg(feature = "cose")]
+pub use self::cwt::Cwt;
 pub use self::evidence::Evidence;
 pub use self::issuer::Issuer;
 #[cfg(feature = "jpt-bbs-plus")]
@@ -38,6 +43,8 @@ pub use self::jwp_credential_options::JwpCredentialOptions;
 pub use self::jws::Jws;
 pub use self::jwt::Jwt;
 pub use self::jwt_serialization::JwtCredential;
+pub use self::key_binding::KeyBinding;
+pub use self::key_binding::CNF_CLAIM;
 pub use self::linked_domain_service::LinkedDomainService;
 pub use self::linked_verifiable_presentation_service::LinkedVerifiablePresentationService;
 pub use self::policy::Policy;
@@ -51,6 +58,8 @@ pub use self::schema::Schema;
 pub use self::status::Status;
 pub use self::subject::Subject;
 
+#[cfg(feature = "cose")]
+pub(crate) use self::cwt::cwt_to_jwt_claims;
 #[cfg(feature = "validator")]
 pub(crate) use self::jwt_serialization::CredentialJwtClaims;
 #[cfg(feature = "presentation")]
@@ -80,6 +80,10 @@ pub enum Error {
   #[error("Attribute Not found")]
   SelectiveDisclosureError,
 
+  /// Caused by a key that cannot be used for a [`KeyBinding`](crate::credential::KeyBinding).
+  #[error("invalid key binding: {0}")]
+  InvalidKeyBinding(&'static str),
+
   /// Failure of an SD-JWT VC operation.
   #[cfg(feature = "sd-jwt-vc")]
   #[error(transparent)]
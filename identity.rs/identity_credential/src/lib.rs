@@ -19,6 +19,9 @@ pub mod credential;
 #[cfg(feature = "domain-linkage")]
 pub mod domain_linkage;
 pub mod error;
+/// Implementation of the ISO/IEC 18013-5 mdoc credential format.
+#[cfg(feature = "mdoc")]
+pub mod mdoc;
 #[cfg(feature = "presentation")]
 pub mod presentation;
 #[cfg(feature = "revocation-bitmap")]
@@ -34,6 +37,10 @@ pub mod sd_jwt_vc;
 pub use error::Error;
 pub use error::Result;
 
+#[cfg(feature = "cose")]
+pub use ciborium;
+#[cfg(feature = "cose")]
+pub use coset;
 #[cfg(feature = "sd-jwt")]
 pub use sd_jwt_payload;
 
@@ -0,0 +1,109 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Helpers to build and inspect the CBOR structures defined by ISO/IEC 18013-5.
+
+use ciborium::Value;
+use identity_core::common::Timestamp;
+
+use super::Error;
+use super::Result;
+
+/// The tag of an embedded CBOR data item, i.e. `#6.24(bstr .cbor T)`.
+const ENCODED_CBOR_TAG: u64 = 24;
+/// The tag of a date-time string, i.e. `tdate`.
+const DATE_TIME_TAG: u64 = 0;
+
+pub(crate) fn to_vec(value: &Value) -> Result<Vec<u8>> {
+  let mut bytes = Vec::new();
+  ciborium::into_writer(value, &mut bytes).map_err(|err| Error::Cbor(err.to_string()))?;
+  Ok(bytes)
+}
+
+pub(crate) fn from_slice(bytes: &[u8]) -> Result<Value> {
+  ciborium::from_reader(bytes).map_err(|err| Error::Cbor(err.to_string()))
+}
+
+/// Wraps the encoding of `value` into an embedded CBOR data item.
+pub(crate) fn embed(value: &Value) -> Result<Value> {
+  to_vec(value).map(embed_bytes)
+}
+
+pub(crate) fn embed_bytes(bytes: Vec<u8>) -> Value {
+  Value::Tag(ENCODED_CBOR_TAG, Box::new(Value::Bytes(bytes)))
+}
+
+/// Returns the bytes of the embedded CBOR data item `value`.
+pub(crate) fn embedded_bytes<'v>(value: &'v Value, field: &'static str) -> Result<&'v [u8]> {
+  match value {
+    Value::Tag(ENCODED_CBOR_TAG, inner) => inner.as_bytes().map(Vec::as_slice),
+    _ => None,
+  }
+  .ok_or(Error::InvalidField(field))
+}
+
+pub(crate) fn date_time(value: &Timestamp) -> Value {
+  Value::Tag(DATE_TIME_TAG, Box::new(Value::Text(value.to_rfc3339())))
+}
+
+pub(crate) fn parse_date_time(value: &Value, field: &'static str) -> Result<Timestamp> {
+  match value {
+    Value::Tag(DATE_TIME_TAG, inner) => inner.as_text().and_then(|text| Timestamp::parse(text).ok()),
+    _ => None,
+  }
+  .ok_or(Error::InvalidField(field))
+}
+
+/// Read-only view over a CBOR map with text keys.
+pub(crate) struct MapView<'v>(&'v [(Value, Value)]);
+
+impl<'v> MapView<'v> {
+  pub(crate) fn new(value: &'v Value, field: &'static str) -> Result<Self> {
+    value
+      .as_map()
+      .map(|map| Self(map.as_slice()))
+      .ok_or(Error::InvalidField(field))
+  }
+
+  pub(crate) fn get(&self, key: &str) -> Option<&'v Value> {
+    self.0.iter().find_map(|(k, v)| (k.as_text() == Some(key)).then_some(v))
+  }
+
+  pub(crate) fn required(&self, key: &'static str) -> Result<&'v Value> {
+    self.get(key).ok_or(Error::MissingField(key))
+  }
+
+  pub(crate) fn text(&self, key: &'static str) -> Result<&'v str> {
+    self.required(key)?.as_text().ok_or(Error::InvalidField(key))
+  }
+
+  pub(crate) fn bytes(&self, key: &'static str) -> Result<&'v [u8]> {
+    self
+      .required(key)?
+      .as_bytes()
+      .map(Vec::as_slice)
+      .ok_or(Error::InvalidField(key))
+  }
+
+  pub(crate) fn uint(&self, key: &'static str) -> Result<u64> {
+    as_uint(self.required(key)?).ok_or(Error::InvalidField(key))
+  }
+
+  pub(crate) fn entries(&self) -> &'v [(Value, Value)] {
+    self.0
+  }
+}
+
+pub(crate) fn as_uint(value: &Value) -> Option<u64> {
+  value.as_integer().and_then(|integer| u64::try_from(integer).ok())
+}
+
+/// Builds a CBOR map with text keys.
+pub(crate) fn text_map<'k>(entries: impl IntoIterator<Item = (&'k str, Value)>) -> Value {
+  Value::Map(
+    entries
+      .into_iter()
+      .map(|(key, value)| (Value::Text(key.to_owned()), value))
+      .collect(),
+  )
+}
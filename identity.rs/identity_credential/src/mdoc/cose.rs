@@ -0,0 +1,41 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Adapters to the COSE support of `identity_verification`, reporting mdoc errors.
+
+use coset::iana;
+use coset::CoseKey;
+use identity_verification::jose::cose;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jws::JwsAlgorithm;
+
+use super::Error;
+use super::Result;
+
+/// The COSE header parameter carrying an X.509 certificate chain.
+pub(crate) const X5CHAIN_LABEL: i64 = 33;
+
+pub(crate) fn cose_algorithm(alg: JwsAlgorithm) -> Result<iana::Algorithm> {
+  cose::cose_algorithm(&alg).map_err(|_| Error::UnsupportedAlgorithm(alg.name().to_owned()))
+}
+
+/// Encodes the public part of `jwk`, along with its algorithm, as a COSE_Key.
+pub(crate) fn jwk_to_cose_key(jwk: &Jwk) -> Result<CoseKey> {
+  let params = jwk
+    .params()
+    .to_public()
+    .ok_or(Error::UnsupportedKey("symmetric keys are not supported"))?;
+  let mut public_key = Jwk::from_params(params);
+  if let Some(alg) = jwk.alg() {
+    public_key.set_alg(alg);
+  }
+
+  public_key
+    .to_cose_key()
+    .map_err(|_| Error::UnsupportedKey("unsupported key type, curve or algorithm"))
+}
+
+/// Decodes a public COSE_Key into a [`Jwk`].
+pub(crate) fn cose_key_to_jwk(key: &CoseKey) -> Result<Jwk> {
+  Jwk::from_cose_key(key).map_err(|_| Error::UnsupportedKey("unsupported key type, curve or algorithm"))
+}
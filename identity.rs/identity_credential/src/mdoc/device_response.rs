@@ -0,0 +1,328 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use ciborium::Value;
+use coset::AsCborValue as _;
+use coset::CoseSign1;
+use coset::CoseSign1Builder;
+use coset::HeaderBuilder;
+use identity_verification::jose::jws::JwsAlgorithm;
+
+use super::cbor;
+use super::cbor::MapView;
+use super::cose;
+use super::Error;
+use super::IssuerSigned;
+use super::Mdoc;
+use super::Result;
+
+/// The version of the device response structure.
+pub const DEVICE_RESPONSE_VERSION: &str = "1.0";
+
+/// The data elements requested from an mdoc by a verifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemsRequest {
+  doc_type: String,
+  name_spaces: BTreeMap<String, BTreeMap<String, bool>>,
+}
+
+impl ItemsRequest {
+  /// Creates a new empty request for an mdoc of type `doc_type`.
+  pub fn new(doc_type: impl Into<String>) -> Self {
+    Self {
+      doc_type: doc_type.into(),
+      name_spaces: BTreeMap::new(),
+    }
+  }
+
+  /// Requests the data element `element_identifier` of `name_space`, declaring whether the verifier
+  /// intends to retain it.
+  pub fn add_element(
+    mut self,
+    name_space: impl Into<String>,
+    element_identifier: impl Into<String>,
+    intent_to_retain: bool,
+  ) -> Self {
+    self
+      .name_spaces
+      .entry(name_space.into())
+      .or_default()
+      .insert(element_identifier.into(), intent_to_retain);
+    self
+  }
+
+  /// Returns the requested document type.
+  pub fn doc_type(&self) -> &str {
+    &self.doc_type
+  }
+
+  /// Returns the requested data elements by name space, each with the verifier's intent to retain it.
+  pub fn name_spaces(&self) -> &BTreeMap<String, BTreeMap<String, bool>> {
+    &self.name_spaces
+  }
+
+  /// Returns whether the data element `element_identifier` of `name_space` is requested.
+  pub fn is_requested(&self, name_space: &str, element_identifier: &str) -> bool {
+    self
+      .name_spaces
+      .get(name_space)
+      .is_some_and(|elements| elements.contains_key(element_identifier))
+  }
+}
+
+impl Mdoc {
+  /// Prepares the presentation of the data elements of this mdoc requested in `request`, to be signed with the
+  /// device key using `alg`.
+  ///
+  /// Data elements that are not requested are not disclosed. Requested data elements the mdoc doesn't contain
+  /// are ignored.
+  ///
+  /// The `session_transcript` binds the presentation to the session established with the verifier.
+  pub fn present(
+    &self,
+    request: &ItemsRequest,
+    session_transcript: &Value,
+    alg: JwsAlgorithm,
+  ) -> Result<UnsignedDocument> {
+    if request.doc_type() != self.doc_type() {
+      return Err(Error::DocTypeMismatch {
+        expected: request.doc_type().to_owned(),
+        found: self.doc_type().to_owned(),
+      });
+    }
+
+    let issuer_signed = self
+      .issuer_signed()
+      .filter(|name_space, element_identifier| request.is_requested(name_space, element_identifier));
+    // Device-signed data elements are not supported, only the device signature is provided.
+    let device_name_spaces = cbor::to_vec(&Value::Map(vec![]))?;
+    let device_authentication =
+      device_authentication_bytes(session_transcript, self.doc_type(), device_name_spaces.clone())?;
+    let device_signature = CoseSign1Builder::new()
+      .protected(HeaderBuilder::new().algorithm(cose::cose_algorithm(alg)?).build())
+      .build();
+
+    Ok(UnsignedDocument {
+      doc_type: self.doc_type().to_owned(),
+      issuer_signed,
+      device_name_spaces,
+      device_signature,
+      device_authentication,
+    })
+  }
+}
+
+/// Computes the `DeviceAuthenticationBytes` signed by the device key: the encoding of
+/// `#6.24(bstr .cbor ["DeviceAuthentication", SessionTranscript, DocType, DeviceNameSpacesBytes])`.
+pub(crate) fn device_authentication_bytes(
+  session_transcript: &Value,
+  doc_type: &str,
+  device_name_spaces: Vec<u8>,
+) -> Result<Vec<u8>> {
+  let device_authentication = Value::Array(vec![
+    Value::Text("DeviceAuthentication".to_owned()),
+    session_transcript.clone(),
+    Value::Text(doc_type.to_owned()),
+    cbor::embed_bytes(device_name_spaces),
+  ]);
+  cbor::to_vec(&cbor::embed(&device_authentication)?)
+}
+
+/// The device-signed part of a [`Document`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceSigned {
+  name_spaces: Vec<u8>,
+  device_signature: CoseSign1,
+}
+
+impl DeviceSigned {
+  /// Returns the CBOR encoding of the device-signed name spaces.
+  pub fn name_spaces(&self) -> &[u8] {
+    &self.name_spaces
+  }
+
+  /// Returns the `COSE_Sign1`, with detached payload, signed by the device key.
+  pub fn device_signature(&self) -> &CoseSign1 {
+    &self.device_signature
+  }
+
+  fn to_cbor(&self) -> Result<Value> {
+    let device_signature = self
+      .device_signature
+      .clone()
+      .to_cbor_value()
+      .map_err(|err| Error::Cose(err.to_string()))?;
+    Ok(cbor::text_map([
+      ("nameSpaces", cbor::embed_bytes(self.name_spaces.clone())),
+      ("deviceAuth", cbor::text_map([("deviceSignature", device_signature)])),
+    ]))
+  }
+
+  fn from_cbor(value: &Value) -> Result<Self> {
+    let map = MapView::new(value, "DeviceSigned")?;
+    let name_spaces = cbor::embedded_bytes(map.required("nameSpaces")?, "nameSpaces")?.to_vec();
+    let device_auth = MapView::new(map.required("deviceAuth")?, "deviceAuth")?;
+    let device_signature = device_auth
+      .get("deviceSignature")
+      .ok_or(Error::MissingField("deviceSignature"))?;
+    let device_signature =
+      CoseSign1::from_cbor_value(device_signature.clone()).map_err(|err| Error::Cose(err.to_string()))?;
+
+    Ok(Self {
+      name_spaces,
+      device_signature,
+    })
+  }
+}
+
+/// A presented mdoc: the disclosed issuer-signed data elements and the device's signature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+  doc_type: String,
+  issuer_signed: IssuerSigned,
+  device_signed: DeviceSigned,
+}
+
+impl Document {
+  /// Returns the document type.
+  pub fn doc_type(&self) -> &str {
+    &self.doc_type
+  }
+
+  /// Returns the disclosed issuer-signed data.
+  pub fn issuer_signed(&self) -> &IssuerSigned {
+    &self.issuer_signed
+  }
+
+  /// Returns the device-signed data.
+  pub fn device_signed(&self) -> &DeviceSigned {
+    &self.device_signed
+  }
+
+  fn to_cbor(&self) -> Result<Value> {
+    Ok(cbor::text_map([
+      ("docType", Value::Text(self.doc_type.clone())),
+      ("issuerSigned", self.issuer_signed.to_cbor()?),
+      ("deviceSigned", self.device_signed.to_cbor()?),
+    ]))
+  }
+
+  fn from_cbor(value: &Value) -> Result<Self> {
+    let map = MapView::new(value, "Document")?;
+    Ok(Self {
+      doc_type: map.text("docType")?.to_owned(),
+      issuer_signed: IssuerSigned::from_cbor(map.required("issuerSigned")?)?,
+      device_signed: DeviceSigned::from_cbor(map.required("deviceSigned")?)?,
+    })
+  }
+}
+
+impl From<Document> for Mdoc {
+  fn from(document: Document) -> Self {
+    Mdoc::new(document.doc_type, document.issuer_signed)
+  }
+}
+
+/// A [`Document`] whose presentation still needs to be signed by the device key.
+#[derive(Debug, Clone)]
+pub struct UnsignedDocument {
+  doc_type: String,
+  issuer_signed: IssuerSigned,
+  device_name_spaces: Vec<u8>,
+  device_signature: CoseSign1,
+  device_authentication: Vec<u8>,
+}
+
+impl UnsignedDocument {
+  /// Returns the bytes the device key must sign, i.e. the `Sig_structure` of the `deviceSignature` `COSE_Sign1`
+  /// over the detached `DeviceAuthenticationBytes`.
+  pub fn signing_input(&self) -> Vec<u8> {
+    self
+      .device_signature
+      .tbs_detached_data(&self.device_authentication, &[])
+  }
+
+  /// Completes the document with the device's `signature` over [`Self::signing_input`].
+  pub fn finish(mut self, signature: Vec<u8>) -> Document {
+    self.device_signature.signature = signature;
+    Document {
+      doc_type: self.doc_type,
+      issuer_signed: self.issuer_signed,
+      device_signed: DeviceSigned {
+        name_spaces: self.device_name_spaces,
+        device_signature: self.device_signature,
+      },
+    }
+  }
+}
+
+/// The response of an mdoc holder to a verifier's request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceResponse {
+  version: String,
+  documents: Vec<Document>,
+  status: u64,
+}
+
+impl DeviceResponse {
+  /// Creates a successful [`DeviceResponse`] presenting `documents`.
+  pub fn new(documents: Vec<Document>) -> Self {
+    Self {
+      version: DEVICE_RESPONSE_VERSION.to_owned(),
+      documents,
+      status: 0,
+    }
+  }
+
+  /// Returns the version of the structure.
+  pub fn version(&self) -> &str {
+    &self.version
+  }
+
+  /// Returns the presented documents.
+  pub fn documents(&self) -> &[Document] {
+    &self.documents
+  }
+
+  /// Returns the status code of the response, `0` meaning OK.
+  pub fn status(&self) -> u64 {
+    self.status
+  }
+
+  /// Encodes this response as CBOR.
+  pub fn to_bytes(&self) -> Result<Vec<u8>> {
+    let documents = self
+      .documents
+      .iter()
+      .map(Document::to_cbor)
+      .collect::<Result<Vec<_>>>()?;
+    cbor::to_vec(&cbor::text_map([
+      ("version", Value::Text(self.version.clone())),
+      ("documents", Value::Array(documents)),
+      ("status", Value::from(self.status)),
+    ]))
+  }
+
+  /// Decodes a [`DeviceResponse`] from its CBOR encoding.
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+    let value = cbor::from_slice(bytes)?;
+    let map = MapView::new(&value, "DeviceResponse")?;
+    let documents = match map.get("documents") {
+      Some(documents) => documents
+        .as_array()
+        .ok_or(Error::InvalidField("documents"))?
+        .iter()
+        .map(Document::from_cbor)
+        .collect::<Result<Vec<_>>>()?,
+      None => vec![],
+    };
+
+    Ok(Self {
+      version: map.text("version")?.to_owned(),
+      documents,
+      status: map.uint("status")?,
+    })
+  }
+}
@@ -0,0 +1,37 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Error type that represents failures that might arise when dealing with mdocs.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum Error {
+  /// A value could not be encoded to or decoded from CBOR.
+  #[error("CBOR error: {0}")]
+  Cbor(String),
+  /// A COSE structure could not be encoded or decoded.
+  #[error("COSE error: {0}")]
+  Cose(String),
+  /// A required field is missing from an mdoc structure.
+  #[error("missing required field \"{0}\"")]
+  MissingField(&'static str),
+  /// A field of an mdoc structure has an invalid value.
+  #[error("invalid value for field \"{0}\"")]
+  InvalidField(&'static str),
+  /// The signature algorithm cannot be used with COSE.
+  #[error("unsupported signature algorithm \"{0}\"")]
+  UnsupportedAlgorithm(String),
+  /// The key cannot be represented as a COSE_Key or JWK.
+  #[error("unsupported key: {0}")]
+  UnsupportedKey(&'static str),
+  /// The mdoc's document type doesn't match the requested one.
+  #[error("document type mismatch; expected \"{expected}\", but found \"{found}\"")]
+  DocTypeMismatch {
+    /// The requested document type.
+    expected: String,
+    /// The mdoc's document type.
+    found: String,
+  },
+}
+
+/// Either a value of type `T` or an [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
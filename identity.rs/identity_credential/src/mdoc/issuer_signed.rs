@@ -0,0 +1,426 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use ciborium::Value;
+use coset::AsCborValue as _;
+use coset::CoseSign1;
+use coset::CoseSign1Builder;
+use coset::HeaderBuilder;
+use coset::Label;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jws::JwsAlgorithm;
+use rand::seq::SliceRandom as _;
+use rand::Rng as _;
+use serde::Serialize;
+
+use super::cbor;
+use super::cbor::MapView;
+use super::cose;
+use super::DigestAlgorithm;
+use super::Error;
+use super::MobileSecurityObject;
+use super::Result;
+use super::ValidityInfo;
+use super::MSO_VERSION;
+
+/// Length in bytes of the random salt of each [`IssuerSignedItem`].
+const RANDOM_LEN: usize = 32;
+
+/// A data element of an mdoc, salted and signed by the issuer through its digest in the
+/// [`MobileSecurityObject`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssuerSignedItem {
+  digest_id: u64,
+  random: Vec<u8>,
+  element_identifier: String,
+  element_value: Value,
+  /// The CBOR encoding of this item, as covered by its digest.
+  encoded: Vec<u8>,
+}
+
+impl IssuerSignedItem {
+  fn new(digest_id: u64, element_identifier: String, element_value: Value) -> Result<Self> {
+    let mut random = vec![0; RANDOM_LEN];
+    rand::thread_rng().fill(random.as_mut_slice());
+    let encoded = cbor::to_vec(&cbor::text_map([
+      ("digestID", Value::from(digest_id)),
+      ("random", Value::Bytes(random.clone())),
+      ("elementIdentifier", Value::Text(element_identifier.clone())),
+      ("elementValue", element_value.clone()),
+    ]))?;
+
+    Ok(Self {
+      digest_id,
+      random,
+      element_identifier,
+      element_value,
+      encoded,
+    })
+  }
+
+  /// Returns the ID of this item's digest in the [`MobileSecurityObject`].
+  pub fn digest_id(&self) -> u64 {
+    self.digest_id
+  }
+
+  /// Returns the random salt of this item.
+  pub fn random(&self) -> &[u8] {
+    &self.random
+  }
+
+  /// Returns the identifier of the data element.
+  pub fn element_identifier(&self) -> &str {
+    &self.element_identifier
+  }
+
+  /// Returns the value of the data element.
+  pub fn element_value(&self) -> &Value {
+    &self.element_value
+  }
+
+  /// Computes the digest of this item, as found in the [`MobileSecurityObject`].
+  pub fn digest(&self, alg: DigestAlgorithm) -> Result<Vec<u8>> {
+    cbor::to_vec(&self.to_cbor()).map(|bytes| alg.digest(&bytes))
+  }
+
+  fn to_cbor(&self) -> Value {
+    cbor::embed_bytes(self.encoded.clone())
+  }
+
+  fn from_cbor(value: &Value) -> Result<Self> {
+    let encoded = cbor::embedded_bytes(value, "IssuerSignedItemBytes")?.to_vec();
+    let item = cbor::from_slice(&encoded)?;
+    let map = MapView::new(&item, "IssuerSignedItem")?;
+
+    Ok(Self {
+      digest_id: map.uint("digestID")?,
+      random: map.bytes("random")?.to_vec(),
+      element_identifier: map.text("elementIdentifier")?.to_owned(),
+      element_value: map.required("elementValue")?.clone(),
+      encoded,
+    })
+  }
+}
+
+/// The issuer-signed part of an mdoc: the disclosed data elements, by name space, and the issuer's
+/// signature over the [`MobileSecurityObject`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssuerSigned {
+  name_spaces: BTreeMap<String, Vec<IssuerSignedItem>>,
+  issuer_auth: CoseSign1,
+}
+
+impl IssuerSigned {
+  /// Returns the data elements, by name space.
+  pub fn name_spaces(&self) -> &BTreeMap<String, Vec<IssuerSignedItem>> {
+    &self.name_spaces
+  }
+
+  /// Returns the `COSE_Sign1` signed by the issuer over the [`MobileSecurityObject`].
+  pub fn issuer_auth(&self) -> &CoseSign1 {
+    &self.issuer_auth
+  }
+
+  /// Decodes the [`MobileSecurityObject`] signed by the issuer.
+  ///
+  /// The issuer's signature is **not** verified.
+  pub fn mso(&self) -> Result<MobileSecurityObject> {
+    let payload = self
+      .issuer_auth
+      .payload
+      .as_deref()
+      .ok_or(Error::MissingField("payload"))?;
+    let mso_bytes = cbor::embedded_bytes(&cbor::from_slice(payload)?, "MobileSecurityObjectBytes")?.to_vec();
+    MobileSecurityObject::from_cbor(&cbor::from_slice(&mso_bytes)?)
+  }
+
+  /// Returns the DER-encoded X.509 certificate chain of the issuer, leaf certificate first, if it was
+  /// included in the `issuerAuth` headers.
+  pub fn x5chain(&self) -> Vec<Vec<u8>> {
+    let headers = self
+      .issuer_auth
+      .protected
+      .header
+      .rest
+      .iter()
+      .chain(&self.issuer_auth.unprotected.rest);
+    headers
+      .filter(|(label, _)| *label == Label::Int(cose::X5CHAIN_LABEL))
+      .flat_map(|(_, value)| match value {
+        Value::Bytes(certificate) => vec![certificate.clone()],
+        Value::Array(certificates) => certificates
+          .iter()
+          .filter_map(|certificate| certificate.as_bytes().cloned())
+          .collect(),
+        _ => vec![],
+      })
+      .collect()
+  }
+
+  /// Returns a copy of this structure only containing the data elements for which `predicate` returns
+  /// `true`.
+  pub(crate) fn filter(&self, predicate: impl Fn(&str, &str) -> bool) -> Self {
+    let name_spaces = self
+      .name_spaces
+      .iter()
+      .map(|(name_space, items)| {
+        let items = items
+          .iter()
+          .filter(|item| predicate(name_space, item.element_identifier()))
+          .cloned()
+          .collect::<Vec<_>>();
+        (name_space.clone(), items)
+      })
+      .filter(|(_, items)| !items.is_empty())
+      .collect();
+
+    Self {
+      name_spaces,
+      issuer_auth: self.issuer_auth.clone(),
+    }
+  }
+
+  pub(crate) fn to_cbor(&self) -> Result<Value> {
+    let name_spaces = self
+      .name_spaces
+      .iter()
+      .map(|(name_space, items)| {
+        let items = items.iter().map(IssuerSignedItem::to_cbor).collect();
+        (Value::Text(name_space.clone()), Value::Array(items))
+      })
+      .collect();
+    let issuer_auth = self
+      .issuer_auth
+      .clone()
+      .to_cbor_value()
+      .map_err(|err| Error::Cose(err.to_string()))?;
+
+    Ok(cbor::text_map([
+      ("nameSpaces", Value::Map(name_spaces)),
+      ("issuerAuth", issuer_auth),
+    ]))
+  }
+
+  pub(crate) fn from_cbor(value: &Value) -> Result<Self> {
+    let map = MapView::new(value, "IssuerSigned")?;
+    let mut name_spaces = BTreeMap::new();
+    if let Some(value) = map.get("nameSpaces") {
+      for (name_space, items) in MapView::new(value, "nameSpaces")?.entries() {
+        let name_space = name_space.as_text().ok_or(Error::InvalidField("nameSpaces"))?;
+        let items = items
+          .as_array()
+          .ok_or(Error::InvalidField("nameSpaces"))?
+          .iter()
+          .map(IssuerSignedItem::from_cbor)
+          .collect::<Result<Vec<_>>>()?;
+        name_spaces.insert(name_space.to_owned(), items);
+      }
+    }
+    let issuer_auth =
+      CoseSign1::from_cbor_value(map.required("issuerAuth")?.clone()).map_err(|err| Error::Cose(err.to_string()))?;
+
+    Ok(Self {
+      name_spaces,
+      issuer_auth,
+    })
+  }
+}
+
+/// An mdoc as held by its holder: the document type and the issuer-signed data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mdoc {
+  doc_type: String,
+  issuer_signed: IssuerSigned,
+}
+
+impl Mdoc {
+  /// Returns the document type.
+  pub fn doc_type(&self) -> &str {
+    &self.doc_type
+  }
+
+  /// Returns the issuer-signed data.
+  pub fn issuer_signed(&self) -> &IssuerSigned {
+    &self.issuer_signed
+  }
+
+  /// Returns the value of the data element `element_identifier` of `name_space`, if present.
+  pub fn element(&self, name_space: &str, element_identifier: &str) -> Option<&Value> {
+    self
+      .issuer_signed
+      .name_spaces
+      .get(name_space)?
+      .iter()
+      .find(|item| item.element_identifier == element_identifier)
+      .map(IssuerSignedItem::element_value)
+  }
+
+  /// Encodes this mdoc as CBOR.
+  pub fn to_bytes(&self) -> Result<Vec<u8>> {
+    cbor::to_vec(&cbor::text_map([
+      ("docType", Value::Text(self.doc_type.clone())),
+      ("issuerSigned", self.issuer_signed.to_cbor()?),
+    ]))
+  }
+
+  /// Decodes an mdoc from its CBOR encoding.
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+    let value = cbor::from_slice(bytes)?;
+    let map = MapView::new(&value, "Mdoc")?;
+    Ok(Self {
+      doc_type: map.text("docType")?.to_owned(),
+      issuer_signed: IssuerSigned::from_cbor(map.required("issuerSigned")?)?,
+    })
+  }
+
+  pub(crate) fn new(doc_type: String, issuer_signed: IssuerSigned) -> Self {
+    Self {
+      doc_type,
+      issuer_signed,
+    }
+  }
+}
+
+/// Builder for [`Mdoc`]s. The resulting [`UnsignedMdoc`] must be signed by the issuer,
+/// e.g. using a key stored in a `JwkStorage`.
+#[derive(Debug, Clone)]
+pub struct MdocBuilder {
+  doc_type: String,
+  device_key: Jwk,
+  validity_info: ValidityInfo,
+  digest_algorithm: DigestAlgorithm,
+  name_spaces: BTreeMap<String, Vec<(String, Value)>>,
+  x5chain: Vec<Vec<u8>>,
+}
+
+impl MdocBuilder {
+  /// Creates a new [`MdocBuilder`] for an mdoc of type `doc_type`, e.g. `org.iso.18013.5.1.mDL`, bound to the
+  /// device holding the private key of `device_key`.
+  pub fn new(doc_type: impl Into<String>, device_key: Jwk, validity_info: ValidityInfo) -> Self {
+    Self {
+      doc_type: doc_type.into(),
+      device_key,
+      validity_info,
+      digest_algorithm: DigestAlgorithm::default(),
+      name_spaces: BTreeMap::new(),
+      x5chain: vec![],
+    }
+  }
+
+  /// Adds the data element `element_identifier` with value `element_value` to `name_space`.
+  pub fn add_element(
+    mut self,
+    name_space: impl Into<String>,
+    element_identifier: impl Into<String>,
+    element_value: impl Serialize,
+  ) -> Result<Self> {
+    let element_value = Value::serialized(&element_value).map_err(|err| Error::Cbor(err.to_string()))?;
+    self
+      .name_spaces
+      .entry(name_space.into())
+      .or_default()
+      .push((element_identifier.into(), element_value));
+    Ok(self)
+  }
+
+  /// Sets the algorithm used to compute the digests of the data elements.
+  ///
+  /// Default: [`DigestAlgorithm::Sha256`].
+  pub fn digest_algorithm(mut self, digest_algorithm: DigestAlgorithm) -> Self {
+    self.digest_algorithm = digest_algorithm;
+    self
+  }
+
+  /// Includes the issuer's DER-encoded X.509 certificate chain, leaf certificate first, in the
+  /// unprotected header of the issuer's signature.
+  pub fn x5chain(mut self, certificates: Vec<Vec<u8>>) -> Self {
+    self.x5chain = certificates;
+    self
+  }
+
+  /// Builds the [`MobileSecurityObject`] and prepares it to be signed with `alg`.
+  pub fn build(self, alg: JwsAlgorithm) -> Result<UnsignedMdoc> {
+    let mut rng = rand::thread_rng();
+    // Digest IDs are assigned randomly so that they don't reveal the order in which elements were added.
+    let element_count = self.name_spaces.values().map(Vec::len).sum::<usize>() as u64;
+    let mut digest_ids: Vec<u64> = (0..element_count).collect();
+    digest_ids.shuffle(&mut rng);
+    let mut digest_ids = digest_ids.into_iter();
+
+    let mut name_spaces = BTreeMap::new();
+    let mut value_digests = BTreeMap::new();
+    for (name_space, elements) in self.name_spaces {
+      let mut items = Vec::with_capacity(elements.len());
+      let mut digests = BTreeMap::new();
+      for (element_identifier, element_value) in elements {
+        let digest_id = digest_ids.next().expect("one digest ID per element");
+        let item = IssuerSignedItem::new(digest_id, element_identifier, element_value)?;
+        digests.insert(digest_id, item.digest(self.digest_algorithm)?);
+        items.push(item);
+      }
+      value_digests.insert(name_space.clone(), digests);
+      name_spaces.insert(name_space, items);
+    }
+
+    let mso = MobileSecurityObject {
+      version: MSO_VERSION.to_owned(),
+      digest_algorithm: self.digest_algorithm,
+      value_digests,
+      device_key: cose::jwk_to_cose_key(&self.device_key)?,
+      doc_type: self.doc_type.clone(),
+      validity_info: self.validity_info,
+    };
+    let payload = cbor::to_vec(&cbor::embed(&mso.to_cbor()?)?)?;
+
+    let protected = HeaderBuilder::new().algorithm(cose::cose_algorithm(alg)?).build();
+    let mut unprotected = HeaderBuilder::new();
+    match self.x5chain.len() {
+      0 => (),
+      1 => unprotected = unprotected.value(cose::X5CHAIN_LABEL, Value::Bytes(self.x5chain[0].clone())),
+      _ => {
+        let certificates = self.x5chain.into_iter().map(Value::Bytes).collect();
+        unprotected = unprotected.value(cose::X5CHAIN_LABEL, Value::Array(certificates));
+      }
+    }
+    let issuer_auth = CoseSign1Builder::new()
+      .protected(protected)
+      .unprotected(unprotected.build())
+      .payload(payload)
+      .build();
+
+    Ok(UnsignedMdoc {
+      doc_type: self.doc_type,
+      issuer_signed: IssuerSigned {
+        name_spaces,
+        issuer_auth,
+      },
+    })
+  }
+}
+
+/// An [`Mdoc`] whose [`MobileSecurityObject`] still needs to be signed by the issuer.
+#[derive(Debug, Clone)]
+pub struct UnsignedMdoc {
+  doc_type: String,
+  issuer_signed: IssuerSigned,
+}
+
+impl UnsignedMdoc {
+  /// Returns the bytes the issuer must sign, i.e. the `Sig_structure` of the `issuerAuth` `COSE_Sign1`.
+  pub fn signing_input(&self) -> Vec<u8> {
+    self.issuer_signed.issuer_auth.tbs_data(&[])
+  }
+
+  /// Returns the [`MobileSecurityObject`] to be signed.
+  pub fn mso(&self) -> Result<MobileSecurityObject> {
+    self.issuer_signed.mso()
+  }
+
+  /// Completes the mdoc with the issuer's `signature` over [`Self::signing_input`].
+  pub fn finish(mut self, signature: Vec<u8>) -> Mdoc {
+    self.issuer_signed.issuer_auth.signature = signature;
+    Mdoc::new(self.doc_type, self.issuer_signed)
+  }
+}
@@ -0,0 +1,20 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+mod cbor;
+mod cose;
+mod device_response;
+mod error;
+mod issuer_signed;
+mod mso;
+#[cfg(test)]
+mod tests;
+
+pub use ciborium::Value as CborValue;
+pub use device_response::*;
+pub use error::Error;
+pub use error::Result;
+pub use issuer_signed::*;
+pub use mso::*;
+
+pub(crate) use device_response::device_authentication_bytes;
@@ -0,0 +1,193 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use ciborium::Value;
+use coset::AsCborValue as _;
+use coset::CoseKey;
+use identity_core::common::Timestamp;
+use identity_verification::jose::jwk::Jwk;
+use sha2::Digest as _;
+use sha2::Sha256;
+use sha2::Sha384;
+use sha2::Sha512;
+
+use super::cbor;
+use super::cbor::MapView;
+use super::cose;
+use super::Error;
+use super::Result;
+
+/// The version of the mobile security object structure.
+pub const MSO_VERSION: &str = "1.0";
+
+/// Hash algorithms used to compute the digests of the data elements of an mdoc.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DigestAlgorithm {
+  /// SHA-256.
+  #[default]
+  Sha256,
+  /// SHA-384.
+  Sha384,
+  /// SHA-512.
+  Sha512,
+}
+
+impl DigestAlgorithm {
+  /// Returns the identifier of this algorithm, as used in a [`MobileSecurityObject`].
+  pub const fn name(self) -> &'static str {
+    match self {
+      Self::Sha256 => "SHA-256",
+      Self::Sha384 => "SHA-384",
+      Self::Sha512 => "SHA-512",
+    }
+  }
+
+  /// Returns the [`DigestAlgorithm`] identified by `name`, if supported.
+  pub fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "SHA-256" => Some(Self::Sha256),
+      "SHA-384" => Some(Self::Sha384),
+      "SHA-512" => Some(Self::Sha512),
+      _ => None,
+    }
+  }
+
+  /// Computes the digest of `data`.
+  pub fn digest(self, data: &[u8]) -> Vec<u8> {
+    match self {
+      Self::Sha256 => Sha256::digest(data).to_vec(),
+      Self::Sha384 => Sha384::digest(data).to_vec(),
+      Self::Sha512 => Sha512::digest(data).to_vec(),
+    }
+  }
+}
+
+/// Validity information of an mdoc, as signed by its issuer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidityInfo {
+  /// When the mobile security object was signed.
+  pub signed: Timestamp,
+  /// The mdoc is not valid before this timestamp.
+  pub valid_from: Timestamp,
+  /// The mdoc is not valid after this timestamp.
+  pub valid_until: Timestamp,
+}
+
+impl ValidityInfo {
+  /// Creates a new [`ValidityInfo`] for an mdoc signed now and valid from now until `valid_until`.
+  pub fn new(valid_until: Timestamp) -> Self {
+    let now = Timestamp::now_utc();
+    Self {
+      signed: now,
+      valid_from: now,
+      valid_until,
+    }
+  }
+
+  fn to_cbor(&self) -> Value {
+    cbor::text_map([
+      ("signed", cbor::date_time(&self.signed)),
+      ("validFrom", cbor::date_time(&self.valid_from)),
+      ("validUntil", cbor::date_time(&self.valid_until)),
+    ])
+  }
+
+  fn from_cbor(value: &Value) -> Result<Self> {
+    let map = MapView::new(value, "validityInfo")?;
+    Ok(Self {
+      signed: cbor::parse_date_time(map.required("signed")?, "signed")?,
+      valid_from: cbor::parse_date_time(map.required("validFrom")?, "validFrom")?,
+      valid_until: cbor::parse_date_time(map.required("validUntil")?, "validUntil")?,
+    })
+  }
+}
+
+/// The mobile security object (MSO) of an mdoc: the structure signed by the issuer, binding the digests of all
+/// data elements and the device key to the document type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MobileSecurityObject {
+  /// Version of the structure.
+  pub version: String,
+  /// The algorithm used to compute `value_digests`.
+  pub digest_algorithm: DigestAlgorithm,
+  /// Digests of the issuer-signed data elements, by name space and digest ID.
+  pub value_digests: BTreeMap<String, BTreeMap<u64, Vec<u8>>>,
+  /// The public key of the device the mdoc is bound to.
+  pub device_key: CoseKey,
+  /// The document type.
+  pub doc_type: String,
+  /// Validity information.
+  pub validity_info: ValidityInfo,
+}
+
+impl MobileSecurityObject {
+  /// Returns the device key as a [`Jwk`].
+  pub fn device_key_jwk(&self) -> Result<Jwk> {
+    cose::cose_key_to_jwk(&self.device_key)
+  }
+
+  pub(crate) fn to_cbor(&self) -> Result<Value> {
+    let value_digests = self
+      .value_digests
+      .iter()
+      .map(|(name_space, digests)| {
+        let digests = digests
+          .iter()
+          .map(|(digest_id, digest)| (Value::from(*digest_id), Value::Bytes(digest.clone())))
+          .collect();
+        (Value::Text(name_space.clone()), Value::Map(digests))
+      })
+      .collect();
+    let device_key = self
+      .device_key
+      .clone()
+      .to_cbor_value()
+      .map_err(|err| Error::Cose(err.to_string()))?;
+
+    Ok(cbor::text_map([
+      ("version", Value::Text(self.version.clone())),
+      ("digestAlgorithm", Value::Text(self.digest_algorithm.name().to_owned())),
+      ("valueDigests", Value::Map(value_digests)),
+      ("deviceKeyInfo", cbor::text_map([("deviceKey", device_key)])),
+      ("docType", Value::Text(self.doc_type.clone())),
+      ("validityInfo", self.validity_info.to_cbor()),
+    ]))
+  }
+
+  pub(crate) fn from_cbor(value: &Value) -> Result<Self> {
+    let map = MapView::new(value, "MobileSecurityObject")?;
+    let digest_algorithm =
+      DigestAlgorithm::from_name(map.text("digestAlgorithm")?).ok_or(Error::InvalidField("digestAlgorithm"))?;
+
+    let mut value_digests = BTreeMap::new();
+    for (name_space, digests) in MapView::new(map.required("valueDigests")?, "valueDigests")?.entries() {
+      let name_space = name_space.as_text().ok_or(Error::InvalidField("valueDigests"))?;
+      let digests = MapView::new(digests, "valueDigests")?
+        .entries()
+        .iter()
+        .map(|(digest_id, digest)| {
+          let digest_id = cbor::as_uint(digest_id).ok_or(Error::InvalidField("valueDigests"))?;
+          let digest = digest.as_bytes().ok_or(Error::InvalidField("valueDigests"))?;
+          Ok((digest_id, digest.clone()))
+        })
+        .collect::<Result<BTreeMap<_, _>>>()?;
+      value_digests.insert(name_space.to_owned(), digests);
+    }
+
+    let device_key_info = MapView::new(map.required("deviceKeyInfo")?, "deviceKeyInfo")?;
+    let device_key = CoseKey::from_cbor_value(device_key_info.required("deviceKey")?.clone())
+      .map_err(|err| Error::Cose(err.to_string()))?;
+
+    Ok(Self {
+      version: map.text("version")?.to_owned(),
+      digest_algorithm,
+      value_digests,
+      device_key,
+      doc_type: map.text("docType")?.to_owned(),
+      validity_info: ValidityInfo::from_cbor(map.required("validityInfo")?)?,
+    })
+  }
+}
@@ -0,0 +1,227 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::signatures::ed25519::SecretKey;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jose::jwk::EdCurve;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkParamsOkp;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jwu;
+
+use super::*;
+use crate::validator::MdocValidationError;
+use crate::validator::MdocValidationOptions;
+use crate::validator::MdocValidator;
+use crate::validator::SignerContext;
+
+const MDL: &str = "org.iso.18013.5.1.mDL";
+const MDL_NAME_SPACE: &str = "org.iso.18013.5.1";
+
+fn public_jwk(secret_key: &SecretKey) -> Jwk {
+  let mut jwk = Jwk::from_params(JwkParamsOkp {
+    crv: EdCurve::Ed25519.name().to_owned(),
+    x: jwu::encode_b64(secret_key.public_key().to_bytes()),
+    d: None,
+  });
+  jwk.set_alg(JwsAlgorithm::EdDSA.name());
+  jwk
+}
+
+struct Setup {
+  issuer_key: SecretKey,
+  device_key: SecretKey,
+  mdoc: Mdoc,
+}
+
+fn setup(validity_info: ValidityInfo) -> Setup {
+  let issuer_key = SecretKey::generate().unwrap();
+  let device_key = SecretKey::generate().unwrap();
+  let unsigned = MdocBuilder::new(MDL, public_jwk(&device_key), validity_info)
+    .add_element(MDL_NAME_SPACE, "family_name", "Doe")
+    .unwrap()
+    .add_element(MDL_NAME_SPACE, "given_name", "Jane")
+    .unwrap()
+    .add_element(MDL_NAME_SPACE, "age_over_18", true)
+    .unwrap()
+    .x5chain(vec![vec![1, 2, 3]])
+    .build(JwsAlgorithm::EdDSA)
+    .unwrap();
+  let signature = issuer_key.sign(&unsigned.signing_input()).to_bytes().to_vec();
+  let mdoc = unsigned.finish(signature);
+
+  Setup {
+    issuer_key,
+    device_key,
+    mdoc,
+  }
+}
+
+fn valid_for_a_year() -> ValidityInfo {
+  ValidityInfo::new(Timestamp::now_utc().checked_add(Duration::days(365)).unwrap())
+}
+
+fn session_transcript() -> CborValue {
+  CborValue::Array(vec![
+    CborValue::Null,
+    CborValue::Null,
+    CborValue::Text("handover".to_owned()),
+  ])
+}
+
+fn present(setup: &Setup, request: &ItemsRequest, session_transcript: &CborValue) -> Document {
+  let unsigned = setup
+    .mdoc
+    .present(request, session_transcript, JwsAlgorithm::EdDSA)
+    .unwrap();
+  let signature = setup.device_key.sign(&unsigned.signing_input()).to_bytes().to_vec();
+  unsigned.finish(signature)
+}
+
+fn to_cbor_value(mdoc: &Mdoc) -> CborValue {
+  ciborium::from_reader(mdoc.to_bytes().unwrap().as_slice()).unwrap()
+}
+
+fn validator() -> MdocValidator<EdDSAJwsVerifier> {
+  MdocValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+}
+
+#[test]
+fn issued_mdoc_is_valid_and_roundtrips() {
+  let setup = setup(valid_for_a_year());
+  let mdoc = Mdoc::from_bytes(&setup.mdoc.to_bytes().unwrap()).unwrap();
+  assert_eq!(mdoc.to_bytes().unwrap(), setup.mdoc.to_bytes().unwrap());
+  assert_eq!(
+    mdoc.element(MDL_NAME_SPACE, "given_name"),
+    Some(&CborValue::from("Jane"))
+  );
+  assert_eq!(mdoc.issuer_signed().x5chain(), vec![vec![1, 2, 3]]);
+
+  let decoded = validator()
+    .validate_mdoc(&mdoc, &public_jwk(&setup.issuer_key), &MdocValidationOptions::default())
+    .unwrap();
+  assert_eq!(decoded.elements[MDL_NAME_SPACE].len(), 3);
+  assert_eq!(decoded.mso.device_key_jwk().unwrap(), public_jwk(&setup.device_key));
+}
+
+#[test]
+fn device_response_discloses_requested_elements_only() {
+  let setup = setup(valid_for_a_year());
+  let request = ItemsRequest::new(MDL)
+    .add_element(MDL_NAME_SPACE, "age_over_18", false)
+    .add_element(MDL_NAME_SPACE, "portrait", false);
+  let document = present(&setup, &request, &session_transcript());
+  let response = DeviceResponse::from_bytes(&DeviceResponse::new(vec![document]).to_bytes().unwrap()).unwrap();
+
+  let issuer_jwk = public_jwk(&setup.issuer_key);
+  let decoded = validator()
+    .validate_device_response(
+      &response,
+      &session_transcript(),
+      &MdocValidationOptions::new().doc_type(MDL),
+      |_| Some(issuer_jwk.clone()),
+    )
+    .unwrap();
+
+  assert_eq!(decoded.len(), 1);
+  let elements = &decoded[0].elements[MDL_NAME_SPACE];
+  assert_eq!(elements.len(), 1);
+  assert_eq!(elements["age_over_18"], CborValue::Bool(true));
+}
+
+#[test]
+fn presentation_requires_matching_doc_type() {
+  let setup = setup(valid_for_a_year());
+  let result = setup.mdoc.present(
+    &ItemsRequest::new("org.example.other"),
+    &session_transcript(),
+    JwsAlgorithm::EdDSA,
+  );
+  assert!(matches!(result, Err(Error::DocTypeMismatch { .. })));
+}
+
+#[test]
+fn device_signature_is_bound_to_session_transcript() {
+  let setup = setup(valid_for_a_year());
+  let request = ItemsRequest::new(MDL).add_element(MDL_NAME_SPACE, "family_name", true);
+  let document = present(&setup, &request, &session_transcript());
+
+  let other_transcript = CborValue::Array(vec![]);
+  let result = validator().validate_document(
+    &document,
+    &public_jwk(&setup.issuer_key),
+    &other_transcript,
+    &MdocValidationOptions::default(),
+  );
+  assert!(matches!(
+    result,
+    Err(MdocValidationError::Signature {
+      signer_ctx: SignerContext::Holder,
+      ..
+    })
+  ));
+}
+
+#[test]
+fn issuer_signature_is_verified() {
+  let setup = setup(valid_for_a_year());
+  let other_issuer = SecretKey::generate().unwrap();
+  let result = validator().validate_mdoc(
+    &setup.mdoc,
+    &public_jwk(&other_issuer),
+    &MdocValidationOptions::default(),
+  );
+  assert!(matches!(
+    result,
+    Err(MdocValidationError::Signature {
+      signer_ctx: SignerContext::Issuer,
+      ..
+    })
+  ));
+}
+
+#[test]
+fn tampered_element_is_rejected() {
+  let setup = setup(valid_for_a_year());
+  let other = self::setup(valid_for_a_year());
+  // Replace the data elements with the ones signed by another issuer.
+  // An mdoc is encoded as {"docType": .., "issuerSigned": {"nameSpaces": .., "issuerAuth": ..}}.
+  let foreign = to_cbor_value(&other.mdoc);
+  let mut value = to_cbor_value(&setup.mdoc);
+  value.as_map_mut().unwrap()[1].1.as_map_mut().unwrap()[0].1 =
+    foreign.as_map().unwrap()[1].1.as_map().unwrap()[0].1.clone();
+  let mut bytes = Vec::new();
+  ciborium::into_writer(&value, &mut bytes).unwrap();
+  let tampered = Mdoc::from_bytes(&bytes).unwrap();
+
+  let result = validator().validate_mdoc(
+    &tampered,
+    &public_jwk(&setup.issuer_key),
+    &MdocValidationOptions::default(),
+  );
+  assert!(matches!(result, Err(MdocValidationError::DigestMismatch { .. })));
+}
+
+#[test]
+fn validity_period_is_checked() {
+  let setup = setup(valid_for_a_year());
+  let issuer_jwk = public_jwk(&setup.issuer_key);
+  let in_two_years = Timestamp::now_utc().checked_add(Duration::days(730)).unwrap();
+
+  let result = validator().validate_mdoc(
+    &setup.mdoc,
+    &issuer_jwk,
+    &MdocValidationOptions::new().earliest_expiry_date(in_two_years),
+  );
+  assert!(matches!(result, Err(MdocValidationError::ExpirationDate)));
+
+  let yesterday = Timestamp::now_utc().checked_sub(Duration::days(1)).unwrap();
+  let result = validator().validate_mdoc(
+    &setup.mdoc,
+    &issuer_jwk,
+    &MdocValidationOptions::new().latest_issuance_date(yesterday),
+  );
+  assert!(matches!(result, Err(MdocValidationError::IssuanceDate)));
+}
@@ -8,6 +8,10 @@ use jsonprooftoken::jwp::issued::JwpIssued;
 use jsonprooftoken::jwp::presented::JwpPresentedBuilder;
 
 /// Used to construct a JwpPresentedBuilder and handle the selective disclosure of attributes.
+///
+/// Attributes can only be disclosed or concealed as a whole. Zero-knowledge predicates over concealed
+/// attributes (e.g. "age >= 18") and equality proofs across presentations are not supported: they must be
+/// bound to the BBS+ proof through shared blindings, which the underlying BBS+ implementation doesn't expose.
 // - @context MUST NOT be blinded
 // - id MUST be blinded
 // - type MUST NOT be blinded
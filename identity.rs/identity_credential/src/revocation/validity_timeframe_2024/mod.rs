@@ -2,6 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Implementation of a new Revocation mechanism for ZK Verifiable Credentials.
+//!
+//! The revocation index is disclosed in every presentation, making presentations of the same credential
+//! linkable. Unlinkable revocation through accumulator non-membership proofs is not supported: such a proof
+//! must be bound to the BBS+ proof of the concealed revocation handle, which the underlying BBS+ implementation
+//! doesn't allow.
 
 mod revocation_timeframe_status;
 
@@ -14,6 +14,13 @@ use crate::sd_jwt_vc::SdJwtVcClaims;
 /// Path used to query [`IssuerMetadata`] for a given JWT VC issuer.
 pub const WELL_KNOWN_VC_ISSUER: &str = "/.well-known/jwt-vc-issuer";
 
+/// Returns the URL of the [`IssuerMetadata`] of the JWT VC issuer identified by `iss`.
+pub(crate) fn issuer_metadata_url(iss: &Url) -> identity_core::Result<Url> {
+  let origin = iss.origin().ascii_serialization();
+  let path = iss.path();
+  Url::parse(format!("{origin}{WELL_KNOWN_VC_ISSUER}{path}"))
+}
+
 /// SD-JWT VC issuer's metadata. Contains information about one issuer's
 /// public keys, either as an embedded JWK Set or a reference to one.
 /// ## Notes
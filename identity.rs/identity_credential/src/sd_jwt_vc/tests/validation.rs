@@ -13,6 +13,10 @@ use crate::sd_jwt_vc::metadata::TypeMetadata;
 use crate::sd_jwt_vc::tests::TestJwsVerifier;
 use crate::sd_jwt_vc::Error;
 use crate::sd_jwt_vc::SdJwtVcBuilder;
+#[cfg(feature = "jwt-issuer-key-resolver")]
+use crate::validator::InMemoryFetcher;
+#[cfg(feature = "jwt-issuer-key-resolver")]
+use crate::validator::JwtIssuerKeyResolver;
 
 use super::TestResolver;
 use super::TestSigner;
@@ -93,6 +97,47 @@ async fn validation_of_invalid_token_fails() -> anyhow::Result<()> {
   Ok(())
 }
 
+#[cfg(feature = "jwt-issuer-key-resolver")]
+#[tokio::test]
+async fn validation_with_key_resolver_works() -> anyhow::Result<()> {
+  let sd_jwt_credential = SdJwtVcBuilder::new(json!({
+    "name": "John Doe",
+    "address": {
+      "street_address": "A random street",
+      "number": "3a"
+    },
+    "degree": []
+  }))?
+  .vct("https://example.com/education_credential".parse::<Url>()?)
+  .iat(Timestamp::now_utc())
+  .iss("https://example.com".parse()?)
+  .make_concealable("/address/street_address")?
+  .make_concealable("/address")?
+  .finish(&TestSigner, "HS256")
+  .await?;
+
+  // The issuer's keys are served through a JWKS URI referenced by its metadata.
+  let jwks_uri: Url = "https://example.com/jwks.json".parse()?;
+  let mut jwk_set = JwkSet::new();
+  jwk_set.add(super::signer_secret_jwk());
+  let mut fetcher = InMemoryFetcher::new();
+  fetcher.insert_json(&jwks_uri, &jwk_set)?;
+  fetcher.insert_json(
+    &"https://example.com/.well-known/jwt-vc-issuer/".parse()?,
+    &IssuerMetadata {
+      issuer: "https://example.com".parse()?,
+      jwks: Jwks::Uri(jwks_uri),
+    },
+  )?;
+  let key_resolver = JwtIssuerKeyResolver::new(fetcher);
+
+  // No `kid` is needed, since the issuer has a single key.
+  sd_jwt_credential
+    .validate_with_key_resolver(&key_resolver, &test_resolver(), &TestJwsVerifier, &Sha256Hasher::new())
+    .await?;
+  Ok(())
+}
+
 fn vc_metadata() -> TypeMetadata {
   serde_json::from_str(
     r#"{
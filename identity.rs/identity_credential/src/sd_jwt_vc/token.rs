@@ -6,18 +6,20 @@ use std::ops::Deref;
 use std::str::FromStr;
 
 use super::claims::SdJwtVcClaims;
+use super::metadata::issuer_metadata_url;
 use super::metadata::ClaimMetadata;
 use super::metadata::IssuerMetadata;
 use super::metadata::Jwks;
 use super::metadata::TypeMetadata;
 use super::metadata::WELL_KNOWN_VCT;
-use super::metadata::WELL_KNOWN_VC_ISSUER;
 use super::resolver::Error as ResolverErr;
 use super::Error;
 use super::Resolver;
 use super::Result;
 use super::SdJwtVcPresentationBuilder;
 use crate::validator::JwtCredentialValidator as JwsUtils;
+#[cfg(feature = "jwt-issuer-key-resolver")]
+use crate::validator::JwtIssuerKeyResolver;
 use crate::validator::KeyBindingJWTValidationOptions;
 use anyhow::anyhow;
 use identity_core::common::StringOrUrl;
@@ -26,6 +28,8 @@ use identity_core::common::Url;
 use identity_core::convert::ToJson as _;
 use identity_verification::jwk::Jwk;
 use identity_verification::jwk::JwkSet;
+#[cfg(feature = "jwt-issuer-key-resolver")]
+use identity_verification::jws::JwsHeader;
 use identity_verification::jws::JwsVerifier;
 use sd_jwt_payload_rework::Hasher;
 use sd_jwt_payload_rework::JsonObject;
@@ -93,11 +97,7 @@ impl SdJwtVc {
   where
     R: Resolver<Url, Vec<u8>>,
   {
-    let metadata_url = {
-      let origin = self.claims().iss.origin().ascii_serialization();
-      let path = self.claims().iss.path();
-      format!("{origin}{WELL_KNOWN_VC_ISSUER}{path}").parse().unwrap()
-    };
+    let metadata_url = issuer_metadata_url(&self.claims().iss).map_err(|e| Error::InvalidIssuerMetadata(e.into()))?;
     match resolver.resolve(&metadata_url).await {
       Err(ResolverErr::NotFound(_)) => Ok(None),
       Err(e) => Err(Error::Resolution {
@@ -173,6 +173,24 @@ impl SdJwtVc {
     }
   }
 
+  /// Resolves the issuer's public key in JWK format using `key_resolver`.
+  ///
+  /// Unlike [`SdJwtVc::issuer_jwk`], this supports `x5c` certificate chains, preconfigured JWK Set URIs and caching
+  /// of the issuer's keys. See [`JwtIssuerKeyResolver`].
+  #[cfg(feature = "jwt-issuer-key-resolver")]
+  pub async fn issuer_jwk_with_key_resolver<F>(&self, key_resolver: &JwtIssuerKeyResolver<F>) -> Result<Jwk>
+  where
+    F: Resolver<Url, Vec<u8>>,
+  {
+    let header: JwsHeader = serde_json::from_value(Value::Object(self.header().clone()))
+      .map_err(|e| Error::Verification(anyhow!("invalid JWS header: {e}")))?;
+
+    key_resolver
+      .resolve(self.claims().iss.as_str(), &header)
+      .await
+      .map_err(|e| Error::Verification(e.into()))
+  }
+
   async fn issuer_jwk_from_iss_metadata<R>(&self, resolver: &R, kid: &str) -> Result<Jwk>
   where
     R: Resolver<Url, Vec<u8>>,
@@ -241,10 +259,55 @@ impl SdJwtVc {
     R: Resolver<Url, Value>,
     V: JwsVerifier,
   {
-    // Signature verification.
     // Fetch issuer's JWK.
     let jwk = self.issuer_jwk(resolver).await?;
-    self.verify_signature(jws_verifier, &jwk)?;
+    self
+      .validate_with_issuer_jwk(&jwk, resolver, jws_verifier, hasher)
+      .await
+  }
+
+  /// Check whether this [`SdJwtVc`] is valid, using `key_resolver` to obtain the issuer's public key.
+  ///
+  /// The same checks as in [`SdJwtVc::validate`] are performed, see
+  /// [`SdJwtVc::issuer_jwk_with_key_resolver`].
+  #[cfg(feature = "jwt-issuer-key-resolver")]
+  pub async fn validate_with_key_resolver<F, R, V>(
+    &self,
+    key_resolver: &JwtIssuerKeyResolver<F>,
+    resolver: &R,
+    jws_verifier: &V,
+    hasher: &dyn Hasher,
+  ) -> Result<()>
+  where
+    F: Resolver<Url, Vec<u8>>,
+    R: Resolver<StringOrUrl, Vec<u8>>,
+    R: Resolver<Url, Value>,
+    V: JwsVerifier,
+  {
+    let jwk = self.issuer_jwk_with_key_resolver(key_resolver).await?;
+    self
+      .validate_with_issuer_jwk(&jwk, resolver, jws_verifier, hasher)
+      .await
+  }
+
+  /// Check whether this [`SdJwtVc`] is valid, using the issuer's public key `jwk`.
+  ///
+  /// The same checks as in [`SdJwtVc::validate`] are performed. Useful when the issuer's key is obtained
+  /// by other means than its metadata, e.g. from the DID document of a DID issuer.
+  pub async fn validate_with_issuer_jwk<R, V>(
+    &self,
+    jwk: &Jwk,
+    resolver: &R,
+    jws_verifier: &V,
+    hasher: &dyn Hasher,
+  ) -> Result<()>
+  where
+    R: Resolver<StringOrUrl, Vec<u8>>,
+    R: Resolver<Url, Value>,
+    V: JwsVerifier,
+  {
+    // Signature verification.
+    self.verify_signature(jws_verifier, jwk)?;
 
     // Credential type.
     // Fetch type metadata. Skip integrity check.
@@ -0,0 +1,383 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use coset::CoseSign1;
+use identity_core::convert::FromJson;
+use identity_did::CoreDID;
+use identity_did::DIDUrl;
+use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jose::cose;
+use identity_verification::jose::cwt::CwtClaims;
+use identity_verification::jose::error::Error as JoseError;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+
+use super::DecodedCwtCredential;
+use crate::credential::cwt_to_jwt_claims;
+use crate::credential::Credential;
+use crate::credential::CredentialJwtClaims;
+use crate::credential::Cwt;
+use crate::validator::CompoundCredentialValidationError;
+use crate::validator::FailFast;
+use crate::validator::JwtCredentialValidationOptions;
+use crate::validator::JwtCredentialValidatorUtils;
+use crate::validator::JwtValidationError;
+use crate::validator::SignerContext;
+
+/// A type for decoding and validating [`Credential`]s issued as CWTs.
+///
+/// This is the CBOR counterpart of the [`JwtCredentialValidator`](crate::validator::JwtCredentialValidator) and
+/// reports the same errors.
+#[non_exhaustive]
+pub struct CwtCredentialValidator<V: JwsVerifier>(V);
+
+impl<V: JwsVerifier> CwtCredentialValidator<V> {
+  /// Create a new [`CwtCredentialValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self(signature_verifier)
+  }
+
+  /// Decodes and validates a [`Credential`] issued as a CWT. A [`DecodedCwtCredential`] is returned upon success.
+  ///
+  /// The following properties are validated according to `options`:
+  /// - the issuer's signature on the `COSE_Sign1`,
+  /// - the expiration date,
+  /// - the issuance date,
+  /// - the semantic structure.
+  ///
+  /// # Warning
+  /// The same considerations as for
+  /// [`JwtCredentialValidator::validate`](crate::validator::JwtCredentialValidator::validate()) apply: the caller must
+  /// ensure that `issuer` represents an up-to-date DID Document, and many properties of the credential are not
+  /// validated.
+  ///
+  /// # Errors
+  /// An error is returned whenever a validated condition is not satisfied.
+  pub fn validate<DOC, T>(
+    &self,
+    credential_cwt: &Cwt,
+    issuer: &DOC,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedCwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let issuers = std::slice::from_ref(issuer.as_ref());
+    let credential_token = self
+      .verify_signature(credential_cwt, issuers, &options.verification_options)
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+
+    JwtCredentialValidatorUtils::check_credential(&credential_token.credential, issuers, options, fail_fast)?;
+    Ok(credential_token)
+  }
+
+  /// Decode and verify the `COSE_Sign1` signature of a [`Credential`] issued as a CWT using the DID Document of a
+  /// trusted issuer.
+  ///
+  /// Unless set in `options`, the verification method is identified by the `kid` of the protected header, which must
+  /// be the UTF-8 encoded DID Url of the method.
+  ///
+  /// A [`DecodedCwtCredential`] is returned upon success.
+  ///
+  /// # Errors
+  /// This method immediately returns an error if the `kid` cannot be parsed to a DID Url belonging to one of the
+  /// trusted issuers, or if `options` requires a nonce, which CWTs don't carry. Otherwise an attempt to verify the
+  /// credential's signature will be made and an error is returned upon failure.
+  pub fn verify_signature<DOC, T>(
+    &self,
+    credential: &Cwt,
+    trusted_issuers: &[DOC],
+    options: &JwsVerificationOptions,
+  ) -> Result<DecodedCwtCredential<T>, JwtValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    DOC: AsRef<CoreDocument>,
+  {
+    let sign1: CoseSign1 =
+      cose::decode_cose_sign1(credential.as_bytes()).map_err(JwtValidationError::JwsDecodingError)?;
+    if options.nonce.is_some() {
+      return Err(JwtValidationError::JwsDecodingError(JoseError::InvalidParam(
+        "invalid nonce value",
+      )));
+    }
+
+    let (public_key, method_id) = Self::parse_jwk(&sign1, trusted_issuers, options)?;
+    cose::verify_cose_sign1(&sign1, None, &self.0, public_key).map_err(|source| JwtValidationError::Signature {
+      source,
+      signer_ctx: SignerContext::Issuer,
+    })?;
+
+    let credential_token: DecodedCwtCredential<T> = Self::decode_credential(sign1)?;
+
+    // Check that the DID component of the `kid` does indeed correspond to the issuer in the credential.
+    let issuer_id: CoreDID = JwtCredentialValidatorUtils::extract_issuer(&credential_token.credential)?;
+    if &issuer_id != method_id.did() {
+      return Err(JwtValidationError::IdentifierMismatch {
+        signer_ctx: SignerContext::Issuer,
+      });
+    };
+    Ok(credential_token)
+  }
+
+  fn parse_jwk<'i, DOC>(
+    sign1: &CoseSign1,
+    trusted_issuers: &'i [DOC],
+    options: &JwsVerificationOptions,
+  ) -> Result<(&'i Jwk, DIDUrl), JwtValidationError>
+  where
+    DOC: AsRef<CoreDocument>,
+  {
+    let method_id: DIDUrl = match &options.method_id {
+      Some(method_id) => method_id.clone(),
+      None => {
+        let kid: &str = std::str::from_utf8(&sign1.protected.header.key_id)
+          .ok()
+          .filter(|kid| !kid.is_empty())
+          .ok_or(JwtValidationError::MethodDataLookupError {
+            source: None,
+            message: "could not extract kid from protected header",
+            signer_ctx: SignerContext::Issuer,
+          })?;
+
+        DIDUrl::parse(kid).map_err(|err| JwtValidationError::MethodDataLookupError {
+          source: Some(err.into()),
+          message: "could not parse kid as a DID Url",
+          signer_ctx: SignerContext::Issuer,
+        })?
+      }
+    };
+
+    let issuer: &CoreDocument = trusted_issuers
+      .iter()
+      .map(AsRef::as_ref)
+      .find(|issuer_doc| <CoreDocument>::id(issuer_doc) == method_id.did())
+      .ok_or(JwtValidationError::DocumentMismatch(SignerContext::Issuer))?;
+
+    issuer
+      .resolve_method(&method_id, options.method_scope)
+      .and_then(|method| method.data().public_key_jwk())
+      .ok_or(JwtValidationError::MethodDataLookupError {
+        source: None,
+        message: "could not extract JWK from a method identified by kid",
+        signer_ctx: SignerContext::Issuer,
+      })
+      .map(move |jwk| (jwk, method_id))
+  }
+
+  fn decode_credential<T>(sign1: CoseSign1) -> Result<DecodedCwtCredential<T>, JwtValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+  {
+    let payload: &[u8] = sign1.payload.as_deref().unwrap_or_default();
+    let jwt_claims = CwtClaims::from_slice(payload)
+      .map_err(|err| crate::Error::JwtClaimsSetDeserializationError(err.into()))
+      .and_then(|claims| cwt_to_jwt_claims(&claims))
+      .map_err(JwtValidationError::CredentialStructure)?;
+
+    let credential_claims: CredentialJwtClaims<'_, T> =
+      CredentialJwtClaims::from_json_value(jwt_claims).map_err(|err| {
+        JwtValidationError::CredentialStructure(crate::Error::JwtClaimsSetDeserializationError(err.into()))
+      })?;
+    let custom_claims = credential_claims.custom.clone();
+    let credential: Credential<T> = credential_claims
+      .try_into_credential()
+      .map_err(JwtValidationError::CredentialStructure)?;
+
+    Ok(DecodedCwtCredential {
+      credential,
+      header: Box::new(sign1.protected.header),
+      custom_claims,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use coset::iana;
+  use coset::HeaderBuilder;
+  use crypto::signatures::ed25519::SecretKey;
+  use identity_core::common::Duration;
+  use identity_core::common::Object;
+  use identity_core::common::Timestamp;
+  use identity_core::common::Url;
+  use identity_core::convert::FromJson;
+  use identity_document::document::CoreDocument;
+  use identity_eddsa_verifier::EdDSAJwsVerifier;
+  use identity_verification::jose::cose::CoseSign1Encoder;
+
+  use super::*;
+  use crate::credential::Subject;
+  use crate::validator::test_utils::generate_jwk_document_with_keys;
+
+  fn credential(issuer: &CoreDocument) -> Credential {
+    Credential::from_json_value(serde_json::json!({
+      "@context": "https://www.w3.org/2018/credentials/v1",
+      "id": "https://example.edu/credentials/3732",
+      "type": ["VerifiableCredential", "UniversityDegreeCredential"],
+      "issuer": issuer.id().to_string(),
+      "issuanceDate": Timestamp::now_utc(),
+      "expirationDate": Timestamp::now_utc().checked_add(Duration::days(365)).unwrap(),
+      "credentialSubject": {
+        "id": "did:example:ebfeb1f712ebc6f1c276e12ec21",
+        "degree": {
+          "type": "BachelorDegree",
+          "name": "Bachelor of Science in Mechanical Engineering"
+        }
+      }
+    }))
+    .unwrap()
+  }
+
+  fn sign(credential: &Credential, kid: &str, secret_key: &SecretKey) -> Cwt {
+    let payload = credential.serialize_cwt(None).unwrap().to_vec().unwrap();
+    let protected = HeaderBuilder::new()
+      .algorithm(iana::Algorithm::EdDSA)
+      .key_id(kid.as_bytes().to_vec())
+      .build();
+    let encoder = CoseSign1Encoder::new(protected, &payload, false).unwrap();
+    let signature = secret_key.sign(encoder.signing_input()).to_bytes();
+    Cwt::new(encoder.into_bytes(&signature).unwrap())
+  }
+
+  fn validator() -> CwtCredentialValidator<EdDSAJwsVerifier> {
+    CwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+  }
+
+  #[test]
+  fn cwt_credential_roundtrip() {
+    let (issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let credential = credential(&issuer);
+    let cwt = sign(&credential, &format!("{}{fragment}", issuer.id()), &secret_key);
+
+    let decoded: DecodedCwtCredential = validator()
+      .validate(
+        &cwt,
+        &issuer,
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .unwrap();
+    assert_eq!(decoded.credential, credential);
+    assert!(decoded.custom_claims.is_none());
+  }
+
+  #[test]
+  fn cwt_claims_use_registered_labels() {
+    let (issuer, _, _) = generate_jwk_document_with_keys();
+    let credential = credential(&issuer);
+    let claims = credential.serialize_cwt(None).unwrap();
+
+    assert_eq!(claims.iss(), Some(issuer.id().to_string().as_str()));
+    assert_eq!(claims.sub(), Some("did:example:ebfeb1f712ebc6f1c276e12ec21"));
+    assert_eq!(claims.cti(), Some(b"https://example.edu/credentials/3732".as_slice()));
+    assert_eq!(claims.exp(), credential.expiration_date.map(|date| date.to_unix()));
+    assert_eq!(claims.nbf(), Some(credential.issuance_date.to_unix()));
+    assert!(claims.custom().contains_key(&coset::Label::Text("vc".to_owned())));
+  }
+
+  #[test]
+  fn cwt_credential_custom_claims() {
+    let (issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let credential = credential(&issuer);
+    let custom_claims = Object::from_json_value(serde_json::json!({ "nonce": "abc" })).unwrap();
+    let payload = credential
+      .serialize_cwt(Some(custom_claims.clone()))
+      .unwrap()
+      .to_vec()
+      .unwrap();
+    let protected = HeaderBuilder::new()
+      .algorithm(iana::Algorithm::EdDSA)
+      .key_id(format!("{}{fragment}", issuer.id()).into_bytes())
+      .build();
+    let encoder = CoseSign1Encoder::new(protected, &payload, false).unwrap();
+    let signature = secret_key.sign(encoder.signing_input()).to_bytes();
+    let cwt = Cwt::new(encoder.into_bytes(&signature).unwrap());
+
+    let decoded: DecodedCwtCredential = validator()
+      .validate(
+        &cwt,
+        &issuer,
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .unwrap();
+    assert_eq!(decoded.custom_claims, Some(custom_claims));
+  }
+
+  #[test]
+  fn cwt_credential_signed_by_other_key_is_rejected() {
+    let (issuer, _, fragment) = generate_jwk_document_with_keys();
+    let credential = credential(&issuer);
+    let other_key = SecretKey::generate().unwrap();
+    let cwt = sign(&credential, &format!("{}{fragment}", issuer.id()), &other_key);
+
+    let result = validator().validate::<_, Object>(
+      &cwt,
+      &issuer,
+      &JwtCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    );
+    assert!(matches!(
+      result.unwrap_err().validation_errors.as_slice(),
+      [JwtValidationError::Signature {
+        signer_ctx: SignerContext::Issuer,
+        ..
+      }]
+    ));
+  }
+
+  #[test]
+  fn cwt_credential_issuer_must_match_kid() {
+    let (issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let mut credential = credential(&issuer);
+    credential.issuer = Url::parse("did:example:other").unwrap().into();
+    let cwt = sign(&credential, &format!("{}{fragment}", issuer.id()), &secret_key);
+
+    let result = validator().verify_signature::<_, Object>(&cwt, &[issuer], &JwsVerificationOptions::default());
+    assert!(matches!(result, Err(JwtValidationError::IdentifierMismatch { .. })));
+  }
+
+  #[test]
+  fn cwt_credential_expiration_is_checked() {
+    let (issuer, secret_key, fragment) = generate_jwk_document_with_keys();
+    let credential = credential(&issuer);
+    let cwt = sign(&credential, &format!("{}{fragment}", issuer.id()), &secret_key);
+    let options = JwtCredentialValidationOptions::default()
+      .earliest_expiry_date(Timestamp::now_utc().checked_add(Duration::days(730)).unwrap());
+
+    let result = validator().validate::<_, Object>(&cwt, &issuer, &options, FailFast::AllErrors);
+    assert!(matches!(
+      result.unwrap_err().validation_errors.as_slice(),
+      [JwtValidationError::ExpirationDate]
+    ));
+  }
+
+  #[test]
+  fn cwt_credential_subject_is_preserved() {
+    let (issuer, _, _) = generate_jwk_document_with_keys();
+    let credential = credential(&issuer);
+    let claims = credential.serialize_cwt(None).unwrap();
+    let jwt_claims = cwt_to_jwt_claims(&CwtClaims::from_slice(&claims.to_vec().unwrap()).unwrap()).unwrap();
+    let decoded: Credential = CredentialJwtClaims::from_json_value(jwt_claims)
+      .unwrap()
+      .try_into_credential()
+      .unwrap();
+    assert_eq!(
+      decoded.credential_subject.first(),
+      credential.credential_subject.first()
+    );
+    assert_eq!(
+      decoded
+        .credential_subject
+        .first()
+        .and_then(|subject: &Subject| subject.id.clone()),
+      Some(Url::parse("did:example:ebfeb1f712ebc6f1c276e12ec21").unwrap())
+    );
+  }
+}
@@ -0,0 +1,22 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use coset::Header;
+use identity_core::common::Object;
+
+use crate::credential::Credential;
+
+/// Decoded [`Credential`] from a cryptographically verified CWT.
+///
+/// Note that having an instance of this type only means the `COSE_Sign1` it was constructed from was verified.
+/// It does not imply anything about a potentially present proof property on the credential itself.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct DecodedCwtCredential<T = Object> {
+  /// The decoded credential parsed to the [Verifiable Credentials Data model](https://www.w3.org/TR/vc-data-model/).
+  pub credential: Credential<T>,
+  /// The protected header parsed from the `COSE_Sign1`.
+  pub header: Box<Header>,
+  /// The custom claims parsed from the CWT.
+  pub custom_claims: Option<Object>,
+}
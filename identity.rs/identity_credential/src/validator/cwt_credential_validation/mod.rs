@@ -0,0 +1,9 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Contains functionality for validating credentials issued as CWTs.
+mod cwt_credential_validator;
+mod decoded_cwt_credential;
+
+pub use cwt_credential_validator::*;
+pub use decoded_cwt_credential::*;
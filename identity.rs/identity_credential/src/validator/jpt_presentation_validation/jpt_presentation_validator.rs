@@ -27,6 +27,8 @@ use super::DecodedJptPresentation;
 use super::JptPresentationValidationOptions;
 
 /// A type for decoding and validating Presented [`Credential`]s in JPT format.
+///
+/// Only disclosed claims can be validated, see [`crate::presentation::SelectiveDisclosurePresentation`].
 #[non_exhaustive]
 pub struct JptPresentationValidator;
 
@@ -104,6 +104,9 @@ pub enum JwtValidationError {
   /// Indicates that the credential has been suspended.
   #[error("credential has been suspended")]
   Suspended,
+  /// Indicates that the presentation was not signed with the key a credential is bound to.
+  #[error("the presentation is not signed with the key the credential is bound to")]
+  KeyBindingMismatch,
   /// Indicates that the credential's timeframe interval is not valid
   #[cfg(feature = "jpt-bbs-plus")]
   #[error("timeframe interval not valid")]
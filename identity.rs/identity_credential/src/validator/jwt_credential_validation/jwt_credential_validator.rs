@@ -86,6 +86,88 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     )
   }
 
+  /// Decodes and validates a [`Credential`] issued as a JWT by an issuer that is not identified by a DID, such as an
+  /// `https` issuer. The issuer's public key is obtained through `key_resolver`, from the credential's `iss` claim and
+  /// its JWS protected header.
+  ///
+  /// The same properties as in [`Self::validate`] are validated, except for `RevocationBitmap2022` statuses, which
+  /// require the issuer's DID Document and are therefore rejected.
+  ///
+  /// # Errors
+  /// An error is returned whenever the issuer's key cannot be resolved or a validated condition is not satisfied.
+  #[cfg(feature = "jwt-issuer-key-resolver")]
+  pub async fn validate_with_key_resolver<T, F>(
+    &self,
+    credential_jwt: &Jwt,
+    key_resolver: &crate::validator::JwtIssuerKeyResolver<F>,
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<DecodedJwtCredential<T>, CompoundCredentialValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    F: crate::sd_jwt_vc::Resolver<identity_core::common::Url, Vec<u8>>,
+  {
+    let credential_token = self
+      .verify_signature_with_key_resolver(credential_jwt, key_resolver, &options.verification_options)
+      .await
+      .map_err(|err| CompoundCredentialValidationError {
+        validation_errors: [err].into(),
+      })?;
+
+    Self::validate_decoded_credential::<CoreDocument, T>(credential_token, &[], options, fail_fast)
+  }
+
+  /// Decode and verify the JWS signature of a [`Credential`] issued as a JWT, using `key_resolver` to obtain the
+  /// public key of its issuer.
+  ///
+  /// See [`Self::validate_with_key_resolver`].
+  #[cfg(feature = "jwt-issuer-key-resolver")]
+  pub async fn verify_signature_with_key_resolver<T, F>(
+    &self,
+    credential: &Jwt,
+    key_resolver: &crate::validator::JwtIssuerKeyResolver<F>,
+    options: &JwsVerificationOptions,
+  ) -> Result<DecodedJwtCredential<T>, JwtValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    F: crate::sd_jwt_vc::Resolver<identity_core::common::Url, Vec<u8>>,
+  {
+    let decoded: JwsValidationItem<'_> = Self::decode(credential.as_str())?;
+    if decoded.nonce() != options.nonce.as_deref() {
+      return Err(JwtValidationError::JwsDecodingError(
+        identity_verification::jose::error::Error::InvalidParam("invalid nonce value"),
+      ));
+    }
+
+    let issuer: String = serde_json::from_slice::<serde_json::Value>(decoded.claims())
+      .ok()
+      .and_then(|claims| claims.get("iss")?.as_str().map(ToOwned::to_owned))
+      .ok_or(JwtValidationError::MethodDataLookupError {
+        source: None,
+        message: "could not extract iss from the JWT claims",
+        signer_ctx: SignerContext::Issuer,
+      })?;
+    let header = decoded
+      .protected_header()
+      .ok_or(JwtValidationError::MethodDataLookupError {
+        source: None,
+        message: "missing protected header",
+        signer_ctx: SignerContext::Issuer,
+      })?;
+
+    let public_key: Jwk =
+      key_resolver
+        .resolve(&issuer, header)
+        .await
+        .map_err(|err| JwtValidationError::MethodDataLookupError {
+          source: Some(err.into()),
+          message: "could not resolve the issuer's public key",
+          signer_ctx: SignerContext::Issuer,
+        })?;
+
+    Self::verify_decoded_signature(decoded, &public_key, &self.0)
+  }
+
   /// Decode and verify the JWS signature of a [`Credential`] issued as a JWT using the DID Document of a trusted
   /// issuer.
   ///
@@ -128,58 +210,8 @@ impl<V: JwsVerifier> JwtCredentialValidator<V> {
     T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
     DOC: AsRef<CoreDocument>,
   {
-    let credential: &Credential<T> = &credential_token.credential;
-    // Run all single concern Credential validations in turn and fail immediately if `fail_fast` is true.
-
-    let expiry_date_validation = std::iter::once_with(|| {
-      JwtCredentialValidatorUtils::check_expires_on_or_after(
-        &credential_token.credential,
-        options.earliest_expiry_date.unwrap_or_default(),
-      )
-    });
-
-    let issuance_date_validation = std::iter::once_with(|| {
-      JwtCredentialValidatorUtils::check_issued_on_or_before(
-        credential,
-        options.latest_issuance_date.unwrap_or_default(),
-      )
-    });
-
-    let structure_validation = std::iter::once_with(|| JwtCredentialValidatorUtils::check_structure(credential));
-
-    let subject_holder_validation = std::iter::once_with(|| {
-      options
-        .subject_holder_relationship
-        .as_ref()
-        .map(|(holder, relationship)| {
-          JwtCredentialValidatorUtils::check_subject_holder_relationship(credential, holder, *relationship)
-        })
-        .unwrap_or(Ok(()))
-    });
-
-    let validation_units_iter = issuance_date_validation
-      .chain(expiry_date_validation)
-      .chain(structure_validation)
-      .chain(subject_holder_validation);
-
-    #[cfg(feature = "revocation-bitmap")]
-    let validation_units_iter = {
-      let revocation_validation =
-        std::iter::once_with(|| JwtCredentialValidatorUtils::check_status(credential, issuers, options.status));
-      validation_units_iter.chain(revocation_validation)
-    };
-
-    let validation_units_error_iter = validation_units_iter.filter_map(|result| result.err());
-    let validation_errors: Vec<JwtValidationError> = match fail_fast {
-      FailFast::FirstError => validation_units_error_iter.take(1).collect(),
-      FailFast::AllErrors => validation_units_error_iter.collect(),
-    };
-
-    if validation_errors.is_empty() {
-      Ok(credential_token)
-    } else {
-      Err(CompoundCredentialValidationError { validation_errors })
-    }
+    JwtCredentialValidatorUtils::check_credential(&credential_token.credential, issuers, options, fail_fast)?;
+    Ok(credential_token)
   }
 
   pub(crate) fn parse_jwk<'a, 'i, DOC>(
@@ -539,4 +571,75 @@ mod tests {
       assert!(JwtCredentialValidatorUtils::check_issued_on_or_before(&SIMPLE_CREDENTIAL, later_than_issuance_date).is_ok());
     }
   }
+
+  #[cfg(feature = "jwt-issuer-key-resolver")]
+  #[tokio::test]
+  async fn validate_with_key_resolver() {
+    use crate::sd_jwt_vc::metadata::IssuerMetadata;
+    use crate::sd_jwt_vc::metadata::Jwks;
+    use crate::validator::test_utils::encode_public_ed25519_jwk;
+    use crate::validator::InMemoryFetcher;
+    use crate::validator::JwtIssuerKeyResolver;
+    use crypto::signatures::ed25519::SecretKey;
+    use identity_eddsa_verifier::EdDSAJwsVerifier;
+    use identity_verification::jwk::JwkSet;
+    use identity_verification::jws::CompactJwsEncoder;
+    use identity_verification::jws::JwsAlgorithm;
+    use identity_verification::jws::JwsHeader;
+
+    let sign = |secret_key: &SecretKey| -> Jwt {
+      let mut credential: Credential = SIMPLE_CREDENTIAL.clone();
+      credential.expiration_date = None;
+      let payload: String = credential.serialize_jwt(None).unwrap();
+
+      let mut header = JwsHeader::new();
+      header.set_alg(JwsAlgorithm::EdDSA);
+      header.set_kid("key-1");
+      let encoder = CompactJwsEncoder::new(payload.as_bytes(), &header).unwrap();
+      let signature: [u8; 64] = secret_key.sign(encoder.signing_input()).to_bytes();
+      Jwt::new(encoder.into_jws(&signature))
+    };
+
+    let secret_key: SecretKey = SecretKey::generate().unwrap();
+    let mut jwk: Jwk = encode_public_ed25519_jwk(&secret_key.public_key());
+    jwk.set_kid("key-1");
+
+    let issuer: Url = SIMPLE_CREDENTIAL.issuer.url().clone();
+    let mut fetcher = InMemoryFetcher::new();
+    fetcher
+      .insert_json(
+        &"https://example.edu/.well-known/jwt-vc-issuer/issuers/14"
+          .parse()
+          .unwrap(),
+        &IssuerMetadata {
+          issuer,
+          jwks: Jwks::Object([jwk].into_iter().collect::<JwkSet>()),
+        },
+      )
+      .unwrap();
+    let key_resolver = JwtIssuerKeyResolver::new(fetcher);
+    let validator = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+
+    let credential: DecodedJwtCredential = validator
+      .validate_with_key_resolver(
+        &sign(&secret_key),
+        &key_resolver,
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .await
+      .unwrap();
+    assert_eq!(credential.credential.issuer.url(), SIMPLE_CREDENTIAL.issuer.url());
+
+    // A credential signed by a key the issuer doesn't publish is rejected.
+    assert!(validator
+      .validate_with_key_resolver::<Object, _>(
+        &sign(&SecretKey::generate().unwrap()),
+        &key_resolver,
+        &JwtCredentialValidationOptions::default(),
+        FailFast::FirstError,
+      )
+      .await
+      .is_err());
+  }
 }
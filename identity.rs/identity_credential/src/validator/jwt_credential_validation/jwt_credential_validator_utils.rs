@@ -10,13 +10,19 @@ use identity_core::convert::FromJson;
 use identity_did::DID;
 use identity_verification::jws::Decoder;
 
+use super::CompoundCredentialValidationError;
+use super::DecodedJwtCredential;
+use super::JwtCredentialValidationOptions;
 use super::JwtValidationError;
 use super::SignerContext;
 use crate::credential::Credential;
 use crate::credential::CredentialJwtClaims;
 use crate::credential::Jwt;
+use crate::credential::KeyBinding;
+use crate::credential::CNF_CLAIM;
 #[cfg(feature = "status-list-2021")]
 use crate::revocation::status_list_2021::StatusList2021Credential;
+use crate::validator::FailFast;
 use crate::validator::SubjectHolderRelationship;
 
 /// Utility functions for verifying JWT credentials.
@@ -192,6 +198,62 @@ impl JwtCredentialValidatorUtils {
     }
   }
 
+  /// Runs all single concern [`Credential`] validations configured in `options` in turn, stopping at the first
+  /// error if `fail_fast` requires it.
+  ///
+  /// This method takes a slice of issuers instead of a single issuer in order to better accommodate presentation
+  /// validation.
+  pub(crate) fn check_credential<DOC, T>(
+    credential: &Credential<T>,
+    issuers: &[DOC],
+    options: &JwtCredentialValidationOptions,
+    fail_fast: FailFast,
+  ) -> Result<(), CompoundCredentialValidationError>
+  where
+    DOC: AsRef<identity_document::document::CoreDocument>,
+  {
+    let expiry_date_validation = std::iter::once_with(|| {
+      Self::check_expires_on_or_after(credential, options.earliest_expiry_date.unwrap_or_default())
+    });
+
+    let issuance_date_validation = std::iter::once_with(|| {
+      Self::check_issued_on_or_before(credential, options.latest_issuance_date.unwrap_or_default())
+    });
+
+    let structure_validation = std::iter::once_with(|| Self::check_structure(credential));
+
+    let subject_holder_validation = std::iter::once_with(|| {
+      options
+        .subject_holder_relationship
+        .as_ref()
+        .map(|(holder, relationship)| Self::check_subject_holder_relationship(credential, holder, *relationship))
+        .unwrap_or(Ok(()))
+    });
+
+    let validation_units_iter = issuance_date_validation
+      .chain(expiry_date_validation)
+      .chain(structure_validation)
+      .chain(subject_holder_validation);
+
+    #[cfg(feature = "revocation-bitmap")]
+    let validation_units_iter = {
+      let revocation_validation = std::iter::once_with(|| Self::check_status(credential, issuers, options.status));
+      validation_units_iter.chain(revocation_validation)
+    };
+
+    let validation_units_error_iter = validation_units_iter.filter_map(|result| result.err());
+    let validation_errors: Vec<JwtValidationError> = match fail_fast {
+      FailFast::FirstError => validation_units_error_iter.take(1).collect(),
+      FailFast::AllErrors => validation_units_error_iter.collect(),
+    };
+
+    if validation_errors.is_empty() {
+      Ok(())
+    } else {
+      Err(CompoundCredentialValidationError { validation_errors })
+    }
+  }
+
   /// Utility for extracting the issuer field of a [`Credential`] as a DID.
   ///
   /// # Errors
@@ -232,4 +294,26 @@ impl JwtCredentialValidatorUtils {
       source: err.into(),
     })
   }
+
+  /// Utility for extracting the [`KeyBinding`] of a credential, i.e. the `cnf` claim of its JWT.
+  ///
+  /// Returns `None` if the credential is not bound to a key.
+  ///
+  /// # Errors
+  ///
+  /// Fails if the `cnf` claim is not a valid key binding.
+  pub fn extract_key_binding<T>(
+    credential: &DecodedJwtCredential<T>,
+  ) -> std::result::Result<Option<KeyBinding>, JwtValidationError> {
+    credential
+      .custom_claims
+      .as_ref()
+      .and_then(|claims| claims.get(CNF_CLAIM))
+      .map(|cnf| {
+        KeyBinding::from_json_value(cnf.clone()).map_err(|err| {
+          JwtValidationError::CredentialStructure(crate::Error::JwtClaimsSetDeserializationError(err.into()))
+        })
+      })
+      .transpose()
+  }
 }
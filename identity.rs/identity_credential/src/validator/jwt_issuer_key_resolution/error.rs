@@ -0,0 +1,37 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::sd_jwt_vc::resolver::Error as ResolverError;
+
+/// Errors that can occur when resolving the public key of a JWT issuer.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum IssuerKeyResolutionError {
+  /// The issuer is neither an `https` URL nor an issuer with a configured JWK Set URI.
+  #[error("cannot locate the keys of issuer \"{0}\"")]
+  UnsupportedIssuer(String),
+  /// Fetching a remote resource failed.
+  #[error("failed to fetch \"{input}\"")]
+  Fetch {
+    /// The resource's URL.
+    input: String,
+    /// Low level error.
+    #[source]
+    source: ResolverError,
+  },
+  /// The issuer's metadata is invalid or doesn't belong to the issuer.
+  #[error("invalid issuer metadata: {0}")]
+  InvalidIssuerMetadata(#[source] anyhow::Error),
+  /// The fetched JWK Set is invalid.
+  #[error("invalid JWK Set")]
+  InvalidJwkSet(#[source] serde_json::Error),
+  /// The `x5c` certificate chain of the JWS was rejected.
+  #[error("invalid x5c certificate chain: {0}")]
+  InvalidCertificateChain(#[source] anyhow::Error),
+  /// None of the issuer's keys matches the JWS header.
+  #[error("no key matching the JWS header was found among the issuer's keys")]
+  KeyNotFound,
+  /// Several of the issuer's keys match the JWS header.
+  #[error("several of the issuer's keys match the JWS header, a unique `kid` is required")]
+  AmbiguousKey,
+}
@@ -0,0 +1,57 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use serde::Serialize;
+
+use crate::sd_jwt_vc::resolver;
+use crate::sd_jwt_vc::Resolver;
+
+/// A [`Resolver`] serving resources stored in memory.
+///
+/// Useful as a local stand-in for an HTTP client, e.g. to serve an issuer's metadata and JWK Set
+/// to a [`JwtIssuerKeyResolver`](super::JwtIssuerKeyResolver) in tests.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryFetcher {
+  resources: HashMap<String, Vec<u8>>,
+}
+
+impl InMemoryFetcher {
+  /// Creates a new empty [`InMemoryFetcher`].
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Serves `bytes` at `url`, replacing any previous resource.
+  pub fn insert(&mut self, url: &Url, bytes: impl Into<Vec<u8>>) {
+    self.resources.insert(url.as_str().to_owned(), bytes.into());
+  }
+
+  /// Serves the JSON serialization of `value` at `url`, replacing any previous resource.
+  pub fn insert_json(&mut self, url: &Url, value: &impl Serialize) -> serde_json::Result<()> {
+    let bytes: Vec<u8> = serde_json::to_vec(value)?;
+    self.insert(url, bytes);
+
+    Ok(())
+  }
+
+  /// Stops serving the resource at `url`, returning it if present.
+  pub fn remove(&mut self, url: &Url) -> Option<Vec<u8>> {
+    self.resources.remove(url.as_str())
+  }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Resolver<Url, Vec<u8>> for InMemoryFetcher {
+  async fn resolve(&self, input: &Url) -> resolver::Result<Vec<u8>> {
+    self
+      .resources
+      .get(input.as_str())
+      .cloned()
+      .ok_or_else(|| resolver::Error::NotFound(input.to_string()))
+  }
+}
@@ -0,0 +1,499 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+use anyhow::anyhow;
+use identity_core::common::Duration;
+use identity_core::common::Timestamp;
+use identity_core::common::Url;
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkSet;
+use identity_verification::jws::JwsHeader;
+
+use super::IssuerKeyResolutionError;
+use crate::sd_jwt_vc::metadata::issuer_metadata_url;
+use crate::sd_jwt_vc::metadata::IssuerMetadata;
+use crate::sd_jwt_vc::metadata::Jwks;
+use crate::sd_jwt_vc::Resolver;
+
+/// Default amount of time fetched JWK Sets are cached for by a [`JwtIssuerKeyResolver`].
+pub const DEFAULT_JWKS_CACHE_TTL: Duration = Duration::minutes(5);
+
+/// Default minimum amount of time between two fetches of the same issuer's JWK Set triggered by an unknown `kid`.
+pub const DEFAULT_JWKS_MIN_REFRESH_INTERVAL: Duration = Duration::seconds(30);
+
+/// Validates the `x5c` certificate chain of a JWS and extracts the public key of its leaf certificate.
+pub trait X5cChainValidator: Send + Sync {
+  /// Validates the certificate chain `x5c` - a list of base64-encoded DER certificates, leaf first - of a JWS
+  /// issued by `issuer`, returning the public key of the leaf certificate.
+  fn validate_chain(&self, issuer: &str, x5c: &[String]) -> Result<Jwk, IssuerKeyResolutionError>;
+}
+
+/// Resolves the public key used by a JWT issuer that is not identified by a DID, such as an `https`
+/// SD-JWT VC or JWT VC issuer.
+///
+/// The key is obtained from the first of the following sources that applies:
+/// 1. The `x5c` certificate chain of the JWS header, if an [`X5cChainValidator`] is configured.
+/// 2. The JWK Set URI configured for the issuer through [`JwtIssuerKeyResolver::with_jwks_uri`].
+/// 3. The issuer's metadata published at `/.well-known/jwt-vc-issuer`, which either embeds the issuer's JWK Set or
+///    references it through `jwks_uri`.
+///
+/// The key is then selected from the issuer's JWK Set by the header's `kid`, which may either be a key ID or a
+/// JWK thumbprint, or by the header's `alg` if no `kid` is set.
+///
+/// Fetched JWK Sets are cached for [`DEFAULT_JWKS_CACHE_TTL`], unless configured otherwise. If the header's `kid`
+/// is not found in a cached JWK Set, e.g. because the issuer rotated its keys, the JWK Set is fetched again once,
+/// at most every [`DEFAULT_JWKS_MIN_REFRESH_INTERVAL`].
+pub struct JwtIssuerKeyResolver<F> {
+  fetcher: F,
+  jwks_uris: HashMap<String, Url>,
+  x5c_validator: Option<Box<dyn X5cChainValidator>>,
+  cache_ttl: Duration,
+  min_refresh_interval: Duration,
+  cache: RwLock<HashMap<String, CachedJwkSet>>,
+}
+
+struct CachedJwkSet {
+  jwks: JwkSet,
+  fetched_at: Timestamp,
+  expires_at: Timestamp,
+}
+
+impl<F> JwtIssuerKeyResolver<F>
+where
+  F: Resolver<Url, Vec<u8>>,
+{
+  /// Creates a new [`JwtIssuerKeyResolver`] fetching remote resources through `fetcher`.
+  pub fn new(fetcher: F) -> Self {
+    Self {
+      fetcher,
+      jwks_uris: HashMap::new(),
+      x5c_validator: None,
+      cache_ttl: DEFAULT_JWKS_CACHE_TTL,
+      min_refresh_interval: DEFAULT_JWKS_MIN_REFRESH_INTERVAL,
+      cache: RwLock::default(),
+    }
+  }
+
+  /// Fetches the keys of `issuer` from `jwks_uri`, instead of looking them up in the issuer's metadata.
+  pub fn with_jwks_uri(mut self, issuer: &str, jwks_uri: Url) -> Self {
+    self.jwks_uris.insert(normalize_issuer(issuer), jwks_uri);
+    self
+  }
+
+  /// Uses the leaf certificate key of the `x5c` chain, if present in the JWS header and accepted by `validator`.
+  pub fn with_x5c_validator(mut self, validator: impl X5cChainValidator + 'static) -> Self {
+    self.x5c_validator = Some(Box::new(validator));
+    self
+  }
+
+  /// Sets the amount of time fetched JWK Sets are cached for. A zero duration disables caching.
+  pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+    self.cache_ttl = ttl;
+    self
+  }
+
+  /// Sets the minimum amount of time between two fetches of the same issuer's JWK Set triggered by a `kid` that is
+  /// not found in the cached JWK Set.
+  pub fn with_min_refresh_interval(mut self, interval: Duration) -> Self {
+    self.min_refresh_interval = interval;
+    self
+  }
+
+  /// Removes all cached JWK Sets.
+  pub fn clear_cache(&self) {
+    self.cache.write().unwrap_or_else(PoisonError::into_inner).clear();
+  }
+
+  /// Resolves the public key that `issuer` used to sign a JWS with the given protected `header`.
+  pub async fn resolve(&self, issuer: &str, header: &JwsHeader) -> Result<Jwk, IssuerKeyResolutionError> {
+    if let (Some(x5c), Some(validator)) = (header.x5c(), self.x5c_validator.as_deref()) {
+      return validator.validate_chain(issuer, x5c);
+    }
+
+    let jwks: JwkSet = self.issuer_jwks(issuer).await?;
+    let alg: Option<String> = header.alg().map(|alg| alg.name().to_string());
+
+    match select_key(&jwks, header.kid(), alg.as_deref()) {
+      // The issuer might have rotated its keys since its JWK Set was cached.
+      Err(IssuerKeyResolutionError::KeyNotFound) if header.kid().is_some() => {
+        match self.refresh_issuer_jwks(&normalize_issuer(issuer)).await? {
+          Some(jwks) => select_key(&jwks, header.kid(), alg.as_deref()),
+          None => Err(IssuerKeyResolutionError::KeyNotFound),
+        }
+      }
+      result => result,
+    }
+  }
+
+  /// Returns the JWK Set of `issuer`, either from the cache or by fetching it.
+  pub async fn issuer_jwks(&self, issuer: &str) -> Result<JwkSet, IssuerKeyResolutionError> {
+    let issuer: String = normalize_issuer(issuer);
+    let now = Timestamp::now_utc();
+
+    let cached: Option<JwkSet> = self
+      .cache
+      .read()
+      .unwrap_or_else(PoisonError::into_inner)
+      .get(&issuer)
+      .filter(|entry| entry.expires_at > now)
+      .map(|entry| entry.jwks.clone());
+    if let Some(jwks) = cached {
+      return Ok(jwks);
+    }
+
+    let jwks: JwkSet = self.fetch_issuer_jwks(&issuer).await?;
+    self.cache_jwks(issuer, jwks.clone(), now);
+
+    Ok(jwks)
+  }
+
+  /// Fetches the JWK Set of `issuer` again, unless it is not cached or was fetched less than the minimum refresh
+  /// interval ago, in which case `None` is returned.
+  async fn refresh_issuer_jwks(&self, issuer: &str) -> Result<Option<JwkSet>, IssuerKeyResolutionError> {
+    let now = Timestamp::now_utc();
+    let refresh_allowed: bool = self
+      .cache
+      .read()
+      .unwrap_or_else(PoisonError::into_inner)
+      .get(issuer)
+      .and_then(|entry| entry.fetched_at.checked_add(self.min_refresh_interval))
+      .is_some_and(|earliest_refresh| earliest_refresh <= now);
+    if !refresh_allowed {
+      return Ok(None);
+    }
+
+    let jwks: JwkSet = self.fetch_issuer_jwks(issuer).await?;
+    self.cache_jwks(issuer.to_owned(), jwks.clone(), now);
+
+    Ok(Some(jwks))
+  }
+
+  fn cache_jwks(&self, issuer: String, jwks: JwkSet, fetched_at: Timestamp) {
+    if let Some(expires_at) = fetched_at
+      .checked_add(self.cache_ttl)
+      .filter(|expires_at| *expires_at > fetched_at)
+    {
+      self.cache.write().unwrap_or_else(PoisonError::into_inner).insert(
+        issuer,
+        CachedJwkSet {
+          jwks,
+          fetched_at,
+          expires_at,
+        },
+      );
+    }
+  }
+
+  async fn fetch_issuer_jwks(&self, issuer: &str) -> Result<JwkSet, IssuerKeyResolutionError> {
+    if let Some(jwks_uri) = self.jwks_uris.get(issuer) {
+      return self.fetch_jwk_set(jwks_uri).await;
+    }
+
+    let issuer_url: Url = Url::parse(issuer)
+      .ok()
+      .filter(|url| url.scheme() == "https")
+      .ok_or_else(|| IssuerKeyResolutionError::UnsupportedIssuer(issuer.to_owned()))?;
+
+    let metadata_url: Url =
+      issuer_metadata_url(&issuer_url).map_err(|_| IssuerKeyResolutionError::UnsupportedIssuer(issuer.to_owned()))?;
+    let metadata: IssuerMetadata = serde_json::from_slice(&self.fetch(&metadata_url).await?)
+      .map_err(|err| IssuerKeyResolutionError::InvalidIssuerMetadata(err.into()))?;
+    if metadata.issuer != issuer_url {
+      return Err(IssuerKeyResolutionError::InvalidIssuerMetadata(anyhow!(
+        "expected issuer \"{issuer_url}\", but found \"{}\"",
+        metadata.issuer
+      )));
+    }
+
+    match metadata.jwks {
+      Jwks::Object(jwks) => Ok(jwks),
+      Jwks::Uri(jwks_uri) => self.fetch_jwk_set(&jwks_uri).await,
+    }
+  }
+
+  async fn fetch_jwk_set(&self, jwks_uri: &Url) -> Result<JwkSet, IssuerKeyResolutionError> {
+    serde_json::from_slice(&self.fetch(jwks_uri).await?).map_err(IssuerKeyResolutionError::InvalidJwkSet)
+  }
+
+  async fn fetch(&self, url: &Url) -> Result<Vec<u8>, IssuerKeyResolutionError> {
+    self
+      .fetcher
+      .resolve(url)
+      .await
+      .map_err(|source| IssuerKeyResolutionError::Fetch {
+        input: url.to_string(),
+        source,
+      })
+  }
+}
+
+impl<F> std::fmt::Debug for JwtIssuerKeyResolver<F> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("JwtIssuerKeyResolver")
+      .field("jwks_uris", &self.jwks_uris)
+      .field("x5c_validator", &self.x5c_validator.is_some())
+      .finish_non_exhaustive()
+  }
+}
+
+/// Issuers that are URLs are compared after normalization, e.g. `https://example.com` and `https://example.com/`
+/// identify the same issuer.
+fn normalize_issuer(issuer: &str) -> String {
+  Url::parse(issuer)
+    .map(|url| url.to_string())
+    .unwrap_or_else(|_| issuer.to_owned())
+}
+
+/// Selects the key of `jwks` identified by `kid`, either as key ID or as JWK thumbprint. If `kid` is not set, the
+/// only key compatible with `alg` is selected.
+fn select_key(jwks: &JwkSet, kid: Option<&str>, alg: Option<&str>) -> Result<Jwk, IssuerKeyResolutionError> {
+  let candidates: Vec<&Jwk> = match kid {
+    Some(kid) => {
+      let by_kid: Vec<&Jwk> = jwks.get(kid);
+      if by_kid.is_empty() {
+        jwks.get_by_thumbprint(kid).into_iter().collect()
+      } else {
+        by_kid
+      }
+    }
+    None => jwks
+      .iter()
+      .filter(|jwk| match (jwk.alg(), alg) {
+        (Some(key_alg), Some(alg)) => key_alg == alg,
+        _ => true,
+      })
+      .collect(),
+  };
+
+  match candidates.as_slice() {
+    [jwk] => Ok((*jwk).clone()),
+    [] => Err(IssuerKeyResolutionError::KeyNotFound),
+    _ => Err(IssuerKeyResolutionError::AmbiguousKey),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::atomic::AtomicUsize;
+  use std::sync::atomic::Ordering;
+
+  use async_trait::async_trait;
+  use identity_core::convert::FromJson;
+  use identity_verification::jws::JwsAlgorithm;
+
+  use super::*;
+  use crate::sd_jwt_vc::resolver;
+  use crate::validator::InMemoryFetcher;
+
+  const ISSUER: &str = "https://example.com";
+
+  fn jwk(kid: &str, x: &str) -> Jwk {
+    Jwk::from_json_value(serde_json::json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "alg": "EdDSA",
+      "kid": kid,
+      "x": x,
+    }))
+    .unwrap()
+  }
+
+  fn jwk_set() -> JwkSet {
+    [
+      jwk("key-1", "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"),
+      jwk("key-2", "3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"),
+    ]
+    .into_iter()
+    .collect()
+  }
+
+  fn header(kid: Option<&str>) -> JwsHeader {
+    let mut header = JwsHeader::new();
+    header.set_alg(JwsAlgorithm::EdDSA);
+    if let Some(kid) = kid {
+      header.set_kid(kid);
+    }
+    header
+  }
+
+  fn fetcher_with_metadata(jwks: Jwks) -> InMemoryFetcher {
+    let metadata = IssuerMetadata {
+      issuer: Url::parse(ISSUER).unwrap(),
+      jwks,
+    };
+    let mut fetcher = InMemoryFetcher::new();
+    fetcher
+      .insert_json(&issuer_metadata_url(&metadata.issuer).unwrap(), &metadata)
+      .unwrap();
+    fetcher
+  }
+
+  /// Counts the requests made to the wrapped fetcher.
+  #[derive(Default)]
+  struct CountingFetcher {
+    inner: InMemoryFetcher,
+    requests: AtomicUsize,
+  }
+
+  #[async_trait]
+  impl Resolver<Url, Vec<u8>> for CountingFetcher {
+    async fn resolve(&self, input: &Url) -> resolver::Result<Vec<u8>> {
+      self.requests.fetch_add(1, Ordering::SeqCst);
+      self.inner.resolve(input).await
+    }
+  }
+
+  struct StaticX5cValidator(Jwk);
+
+  impl X5cChainValidator for StaticX5cValidator {
+    fn validate_chain(&self, _issuer: &str, x5c: &[String]) -> Result<Jwk, IssuerKeyResolutionError> {
+      if x5c.is_empty() {
+        Err(IssuerKeyResolutionError::InvalidCertificateChain(anyhow!(
+          "empty chain"
+        )))
+      } else {
+        Ok(self.0.clone())
+      }
+    }
+  }
+
+  #[tokio::test]
+  async fn resolves_key_from_embedded_issuer_metadata() {
+    let resolver = JwtIssuerKeyResolver::new(fetcher_with_metadata(Jwks::Object(jwk_set())));
+
+    let key = resolver.resolve(ISSUER, &header(Some("key-2"))).await.unwrap();
+    assert_eq!(key.kid(), Some("key-2"));
+
+    // The kid can also be the key's thumbprint.
+    let thumbprint = jwk_set()[0].thumbprint_sha256_b64();
+    let key = resolver.resolve(ISSUER, &header(Some(&thumbprint))).await.unwrap();
+    assert_eq!(key.kid(), Some("key-1"));
+
+    assert!(matches!(
+      resolver.resolve(ISSUER, &header(Some("key-3"))).await,
+      Err(IssuerKeyResolutionError::KeyNotFound)
+    ));
+    assert!(matches!(
+      resolver.resolve(ISSUER, &header(None)).await,
+      Err(IssuerKeyResolutionError::AmbiguousKey)
+    ));
+  }
+
+  #[tokio::test]
+  async fn jwks_uri_is_fetched_once_and_cached() {
+    let jwks_uri = Url::parse("https://keys.example.com/jwks.json").unwrap();
+    let mut fetcher = fetcher_with_metadata(Jwks::Uri(jwks_uri.clone()));
+    fetcher.insert_json(&jwks_uri, &jwk_set()).unwrap();
+    let resolver = JwtIssuerKeyResolver::new(CountingFetcher {
+      inner: fetcher,
+      ..Default::default()
+    });
+
+    for _ in 0..3 {
+      resolver.resolve(ISSUER, &header(Some("key-1"))).await.unwrap();
+    }
+    assert_eq!(resolver.fetcher.requests.load(Ordering::SeqCst), 2);
+
+    resolver.clear_cache();
+    resolver.resolve(ISSUER, &header(Some("key-1"))).await.unwrap();
+    assert_eq!(resolver.fetcher.requests.load(Ordering::SeqCst), 4);
+  }
+
+  #[tokio::test]
+  async fn jwks_is_fetched_again_on_unknown_kid() {
+    let jwks_uri = Url::parse("https://keys.example.com/jwks.json").unwrap();
+    let mut fetcher = fetcher_with_metadata(Jwks::Uri(jwks_uri.clone()));
+    fetcher.insert_json(&jwks_uri, &jwk_set()).unwrap();
+    let mut resolver = JwtIssuerKeyResolver::new(CountingFetcher {
+      inner: fetcher,
+      ..Default::default()
+    })
+    .with_min_refresh_interval(Duration::seconds(0));
+
+    resolver.resolve(ISSUER, &header(Some("key-1"))).await.unwrap();
+    assert_eq!(resolver.fetcher.requests.load(Ordering::SeqCst), 2);
+
+    // The issuer rotates its keys.
+    let rotated: JwkSet = [jwk("key-3", "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo")]
+      .into_iter()
+      .collect();
+    resolver.fetcher.inner.insert_json(&jwks_uri, &rotated).unwrap();
+
+    let key = resolver.resolve(ISSUER, &header(Some("key-3"))).await.unwrap();
+    assert_eq!(key.kid(), Some("key-3"));
+    assert_eq!(resolver.fetcher.requests.load(Ordering::SeqCst), 4);
+
+    // An unknown kid triggers a single refetch.
+    assert!(matches!(
+      resolver.resolve(ISSUER, &header(Some("key-4"))).await,
+      Err(IssuerKeyResolutionError::KeyNotFound)
+    ));
+    assert_eq!(resolver.fetcher.requests.load(Ordering::SeqCst), 6);
+  }
+
+  #[tokio::test]
+  async fn jwks_refetches_are_rate_limited() {
+    let resolver = JwtIssuerKeyResolver::new(CountingFetcher {
+      inner: fetcher_with_metadata(Jwks::Object(jwk_set())),
+      ..Default::default()
+    });
+
+    for _ in 0..3 {
+      assert!(matches!(
+        resolver.resolve(ISSUER, &header(Some("key-3"))).await,
+        Err(IssuerKeyResolutionError::KeyNotFound)
+      ));
+    }
+    assert_eq!(resolver.fetcher.requests.load(Ordering::SeqCst), 1);
+  }
+
+  #[tokio::test]
+  async fn configured_jwks_uri_takes_precedence() {
+    let issuer = "urn:example:issuer";
+    let jwks_uri = Url::parse("https://keys.example.com/jwks.json").unwrap();
+    let mut fetcher = InMemoryFetcher::new();
+    fetcher
+      .insert_json(
+        &jwks_uri,
+        &[jwk("key-1", "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo")]
+          .into_iter()
+          .collect::<JwkSet>(),
+      )
+      .unwrap();
+
+    let resolver = JwtIssuerKeyResolver::new(fetcher.clone()).with_jwks_uri(issuer, jwks_uri);
+    // With a single key, no `kid` is required.
+    let key = resolver.resolve(issuer, &header(None)).await.unwrap();
+    assert_eq!(key.kid(), Some("key-1"));
+
+    let resolver = JwtIssuerKeyResolver::new(fetcher);
+    assert!(matches!(
+      resolver.resolve(issuer, &header(None)).await,
+      Err(IssuerKeyResolutionError::UnsupportedIssuer(_))
+    ));
+  }
+
+  #[tokio::test]
+  async fn metadata_of_another_issuer_is_rejected() {
+    let resolver = JwtIssuerKeyResolver::new(fetcher_with_metadata(Jwks::Object(jwk_set())));
+    assert!(resolver
+      .resolve("https://example.com/other", &header(Some("key-1")))
+      .await
+      .is_err());
+  }
+
+  #[tokio::test]
+  async fn x5c_chain_is_used_when_a_validator_is_configured() {
+    let leaf_key = jwk("leaf", "3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08");
+    let resolver =
+      JwtIssuerKeyResolver::new(InMemoryFetcher::new()).with_x5c_validator(StaticX5cValidator(leaf_key.clone()));
+
+    let mut header = header(None);
+    header.set_x5c(["MIIB"]);
+    assert_eq!(resolver.resolve(ISSUER, &header).await.unwrap(), leaf_key);
+  }
+}
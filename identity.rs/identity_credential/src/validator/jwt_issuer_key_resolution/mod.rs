@@ -0,0 +1,12 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Resolution of the public keys of JWT issuers that are not identified by a DID.
+
+mod error;
+mod in_memory_fetcher;
+mod key_resolver;
+
+pub use error::*;
+pub use in_memory_fetcher::*;
+pub use key_resolver::*;
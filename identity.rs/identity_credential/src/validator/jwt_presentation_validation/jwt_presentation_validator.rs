@@ -7,11 +7,16 @@ use identity_core::common::Url;
 use identity_core::convert::FromJson;
 use identity_did::CoreDID;
 use identity_document::document::CoreDocument;
+use identity_document::verifiable::JwsVerificationOptions;
+use identity_verification::jose::error::Error as JoseError;
+use identity_verification::jwk::Jwk;
 use identity_verification::jws::DecodedJws;
+use identity_verification::jws::Decoder;
 use identity_verification::jws::JwsVerifier;
 use std::str::FromStr;
 
 use crate::credential::Jwt;
+use crate::credential::KeyBinding;
 use crate::presentation::Presentation;
 use crate::presentation::PresentationJwtClaims;
 use crate::validator::jwt_credential_validation::JwtValidationError;
@@ -105,6 +110,118 @@ where
       ));
     }
 
+    Self::validate_claims(decoded_jws, claims, options)
+  }
+
+  /// Validates a [`Presentation`] signed with the key its credentials are bound to, instead of a method of the holder's
+  /// DID Document.
+  ///
+  /// The key the presentation must be signed with is taken from `key_binding` if it embeds a JWK. Bindings by key
+  /// identifier or thumbprint are resolved to a verification method of the `holder`'s DID Document, which is then
+  /// required to be the issuer of the presentation; without `holder` such bindings are rejected. Keys carried by the
+  /// presentation itself, e.g. in the `jwk` header parameter, are never used. The binding of a credential can be
+  /// obtained with
+  /// [`JwtCredentialValidatorUtils::extract_key_binding`](crate::validator::JwtCredentialValidatorUtils::extract_key_binding).
+  ///
+  /// Apart from the signature, the same properties as in [`JwtPresentationValidator::validate`] are validated according
+  /// to `options`. The `method_scope` of the verification options restricts the methods of `holder` a binding can be
+  /// resolved to, whereas their `method_id` is not taken into account.
+  ///
+  /// # Warning
+  ///
+  /// The same caveats as for [`JwtPresentationValidator::validate`] apply. Moreover, without `holder`, the holder of
+  /// the presentation is not checked to be a DID, as it is the possession of the bound key that authenticates the
+  /// holder.
+  ///
+  /// # Errors
+  ///
+  /// An error is returned whenever a validated condition is not satisfied or when decoding fails.
+  pub fn validate_with_key_binding<CRED, T>(
+    &self,
+    presentation: &Jwt,
+    key_binding: &KeyBinding,
+    holder: Option<&CoreDocument>,
+    options: &JwtPresentationValidationOptions,
+  ) -> Result<DecodedJwtPresentation<CRED, T>, CompoundJwtPresentationValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    CRED: ToOwned<Owned = CRED> + serde::Serialize + serde::de::DeserializeOwned + Clone,
+  {
+    let decoded_jws: DecodedJws<'_> = self
+      .verify_key_bound_signature(
+        presentation,
+        key_binding,
+        holder,
+        &options.presentation_verifier_options,
+      )
+      .map_err(CompoundJwtPresentationValidationError::one_presentation_error)?;
+
+    let claims: PresentationJwtClaims<'_, CRED, T> = PresentationJwtClaims::from_json_slice(&decoded_jws.claims)
+      .map_err(|err| {
+        CompoundJwtPresentationValidationError::one_presentation_error(JwtValidationError::PresentationStructure(
+          crate::Error::JwtClaimsSetDeserializationError(err.into()),
+        ))
+      })?;
+
+    // A key resolved from the holder's document only authenticates that holder.
+    if let Some(holder) = holder {
+      let holder_did: CoreDID = CoreDID::from_str(claims.iss.as_str()).map_err(|err| {
+        CompoundJwtPresentationValidationError::one_presentation_error(JwtValidationError::SignerUrl {
+          signer_ctx: SignerContext::Holder,
+          source: err.into(),
+        })
+      })?;
+      if &holder_did != holder.id() {
+        return Err(CompoundJwtPresentationValidationError::one_presentation_error(
+          JwtValidationError::DocumentMismatch(SignerContext::Holder),
+        ));
+      }
+    }
+
+    Self::validate_claims(decoded_jws, claims, options)
+  }
+
+  fn verify_key_bound_signature<'jws>(
+    &self,
+    presentation: &'jws Jwt,
+    key_binding: &KeyBinding,
+    holder: Option<&CoreDocument>,
+    options: &JwsVerificationOptions,
+  ) -> Result<DecodedJws<'jws>, JwtValidationError> {
+    let validation_item = Decoder::new()
+      .decode_compact_serialization(presentation.as_str().as_bytes(), None)
+      .map_err(JwtValidationError::JwsDecodingError)?;
+
+    if validation_item.nonce() != options.nonce.as_deref() {
+      return Err(JwtValidationError::JwsDecodingError(JoseError::InvalidParam(
+        "invalid nonce value",
+      )));
+    }
+    if options.require_hybrid && !validation_item.alg().is_some_and(|alg| alg.is_hybrid()) {
+      return Err(JwtValidationError::JwsDecodingError(JoseError::InvalidParam(
+        "expected a hybrid signature",
+      )));
+    }
+
+    let public_key: Jwk = resolve_bound_key(key_binding, holder, options)?;
+
+    validation_item
+      .verify(&self.0, &public_key)
+      .map_err(|source| JwtValidationError::Signature {
+        source,
+        signer_ctx: SignerContext::Holder,
+      })
+  }
+
+  fn validate_claims<CRED, T>(
+    decoded_jws: DecodedJws<'_>,
+    claims: PresentationJwtClaims<'_, CRED, T>,
+    options: &JwtPresentationValidationOptions,
+  ) -> Result<DecodedJwtPresentation<CRED, T>, CompoundJwtPresentationValidationError>
+  where
+    T: ToOwned<Owned = T> + serde::Serialize + serde::de::DeserializeOwned,
+    CRED: ToOwned<Owned = CRED> + serde::Serialize + serde::de::DeserializeOwned + Clone,
+  {
     // Check the expiration date.
     let expiration_date: Option<Timestamp> = claims
       .exp
@@ -164,3 +281,38 @@ where
     Ok(decoded_jwt_presentation)
   }
 }
+
+/// Returns the public key `key_binding` refers to. Keys bound by identifier or thumbprint are looked up in `holder`.
+fn resolve_bound_key(
+  key_binding: &KeyBinding,
+  holder: Option<&CoreDocument>,
+  options: &JwsVerificationOptions,
+) -> Result<Jwk, JwtValidationError> {
+  if let Some(jwk) = key_binding.jwk() {
+    return Ok(jwk.clone());
+  }
+
+  let holder: &CoreDocument = holder.ok_or(JwtValidationError::MethodDataLookupError {
+    source: None,
+    message: "resolving a key bound by identifier or thumbprint requires the holder's DID document",
+    signer_ctx: SignerContext::Holder,
+  })?;
+  let methods: Vec<_> = match key_binding {
+    KeyBinding::Kid(kid) => holder
+      .resolve_method(kid.as_str(), options.method_scope)
+      .into_iter()
+      .collect(),
+    _ => holder.methods(options.method_scope),
+  };
+
+  methods
+    .into_iter()
+    .filter_map(|method| method.data().public_key_jwk())
+    .find(|jwk| match key_binding {
+      // The method was resolved by the bound identifier.
+      KeyBinding::Kid(_) => true,
+      _ => key_binding.matches(jwk),
+    })
+    .cloned()
+    .ok_or(JwtValidationError::KeyBindingMismatch)
+}
@@ -0,0 +1,19 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use crate::mdoc::CborValue;
+use crate::mdoc::MobileSecurityObject;
+
+/// The disclosed content of a validated mdoc.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct DecodedMdoc {
+  /// The document type.
+  pub doc_type: String,
+  /// The mobile security object signed by the issuer.
+  pub mso: MobileSecurityObject,
+  /// The values of the disclosed data elements, by name space and element identifier.
+  pub elements: BTreeMap<String, BTreeMap<String, CborValue>>,
+}
@@ -0,0 +1,51 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::validator::SignerContext;
+
+/// An error associated with validating mdocs.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum MdocValidationError {
+  /// Indicates that a CBOR or COSE structure of the mdoc could not be decoded.
+  #[error("could not decode mdoc")]
+  MdocDecodingError(#[source] crate::mdoc::Error),
+  /// Indicates that the device response reports an error.
+  #[error("the device response has error status {0}")]
+  ResponseStatus(u64),
+  /// Indicates that no issuer key was provided for one of the presented documents.
+  #[error("missing issuer key for document of type \"{0}\"")]
+  MissingIssuerKey(String),
+  /// Indicates that the document type is not the expected one, or differs from the one signed by the issuer.
+  #[error("document type mismatch; expected \"{expected}\", but found \"{found}\"")]
+  DocTypeMismatch {
+    /// The expected document type.
+    expected: String,
+    /// The document type that was found.
+    found: String,
+  },
+  /// Indicates that the issuer's (resp. device's) signature could not be verified.
+  #[error("could not verify the {signer_ctx}'s signature; {source}")]
+  #[non_exhaustive]
+  Signature {
+    /// Signature verification error.
+    source: identity_verification::jose::error::Error,
+    /// Specifies whether the error relates to the issuer's signature over the mobile security object or
+    /// the device's signature over the presentation.
+    signer_ctx: SignerContext,
+  },
+  /// Indicates that the `validUntil` date of the mdoc is not considered valid.
+  #[error("the expiration date is in the past or earlier than required")]
+  ExpirationDate,
+  /// Indicates that the `validFrom` date of the mdoc is not considered valid.
+  #[error("issuance date is in the future or later than required")]
+  IssuanceDate,
+  /// Indicates that a disclosed data element doesn't match its digest in the mobile security object.
+  #[error("data element \"{element_identifier}\" of name space \"{name_space}\" doesn't match its signed digest")]
+  DigestMismatch {
+    /// The name space of the data element.
+    name_space: String,
+    /// The identifier of the data element.
+    element_identifier: String,
+  },
+}
@@ -0,0 +1,53 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Options to declare validation criteria for mdocs.
+#[non_exhaustive]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MdocValidationOptions {
+  /// Declares that the mdoc is **not** considered valid if it expires before this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  #[serde(default)]
+  pub earliest_expiry_date: Option<Timestamp>,
+
+  /// Declares that the mdoc is **not** considered valid if it becomes valid later than this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  #[serde(default)]
+  pub latest_issuance_date: Option<Timestamp>,
+
+  /// Declares the document type the mdoc must have, e.g. `org.iso.18013.5.1.mDL`.
+  #[serde(default)]
+  pub doc_type: Option<String>,
+}
+
+impl MdocValidationOptions {
+  /// Constructor that sets all options to their defaults.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Declare that the mdoc is **not** considered valid if it expires before this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  pub fn earliest_expiry_date(mut self, timestamp: Timestamp) -> Self {
+    self.earliest_expiry_date = Some(timestamp);
+    self
+  }
+
+  /// Declare that the mdoc is **not** considered valid if it becomes valid later than this [`Timestamp`].
+  /// Uses the current datetime during validation if not set.
+  pub fn latest_issuance_date(mut self, timestamp: Timestamp) -> Self {
+    self.latest_issuance_date = Some(timestamp);
+    self
+  }
+
+  /// Declare the document type the mdoc must have.
+  pub fn doc_type(mut self, doc_type: impl Into<String>) -> Self {
+    self.doc_type = Some(doc_type.into());
+    self
+  }
+}
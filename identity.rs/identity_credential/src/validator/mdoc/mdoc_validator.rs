@@ -0,0 +1,189 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use coset::CoseSign1;
+use identity_core::common::Timestamp;
+use identity_verification::jose::cose;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsVerifier;
+
+use super::DecodedMdoc;
+use super::MdocValidationError;
+use super::MdocValidationOptions;
+use crate::mdoc;
+use crate::mdoc::CborValue;
+use crate::mdoc::DeviceResponse;
+use crate::mdoc::Document;
+use crate::mdoc::IssuerSigned;
+use crate::mdoc::Mdoc;
+use crate::validator::SignerContext;
+
+/// A type for validating ISO/IEC 18013-5 mdocs and device responses.
+#[non_exhaustive]
+pub struct MdocValidator<V: JwsVerifier>(V);
+
+impl<V: JwsVerifier> MdocValidator<V> {
+  /// Create a new [`MdocValidator`] that delegates cryptographic signature verification to the given
+  /// `signature_verifier`.
+  pub fn with_signature_verifier(signature_verifier: V) -> Self {
+    Self(signature_verifier)
+  }
+
+  /// Validates the issuer-signed data of an [`Mdoc`], as held by its holder.
+  ///
+  /// The following properties are validated according to `options`:
+  /// - the issuer's signature over the mobile security object, using `issuer_key`,
+  /// - the document type,
+  /// - the validity period,
+  /// - the digest of every data element.
+  ///
+  /// # Warning
+  /// The issuer's key must be trusted by the caller, e.g. by validating the X.509 certificate chain returned by
+  /// [`IssuerSigned::x5chain`].
+  pub fn validate_mdoc(
+    &self,
+    mdoc: &Mdoc,
+    issuer_key: &Jwk,
+    options: &MdocValidationOptions,
+  ) -> Result<DecodedMdoc, MdocValidationError> {
+    self.validate_issuer_signed(mdoc.doc_type(), mdoc.issuer_signed(), issuer_key, options)
+  }
+
+  /// Validates a presented [`Document`].
+  ///
+  /// On top of the properties validated by [`Self::validate_mdoc`], the device's signature over
+  /// `session_transcript` is verified using the device key signed by the issuer.
+  pub fn validate_document(
+    &self,
+    document: &Document,
+    issuer_key: &Jwk,
+    session_transcript: &CborValue,
+    options: &MdocValidationOptions,
+  ) -> Result<DecodedMdoc, MdocValidationError> {
+    let decoded = self.validate_issuer_signed(document.doc_type(), document.issuer_signed(), issuer_key, options)?;
+
+    let device_key = decoded
+      .mso
+      .device_key_jwk()
+      .map_err(MdocValidationError::MdocDecodingError)?;
+    let device_authentication = mdoc::device_authentication_bytes(
+      session_transcript,
+      document.doc_type(),
+      document.device_signed().name_spaces().to_vec(),
+    )
+    .map_err(MdocValidationError::MdocDecodingError)?;
+    self.verify_cose_sign1(
+      document.device_signed().device_signature(),
+      Some(&device_authentication),
+      &device_key,
+      SignerContext::Holder,
+    )?;
+
+    Ok(decoded)
+  }
+
+  /// Validates every document of a [`DeviceResponse`], see [`Self::validate_document`].
+  ///
+  /// The issuer key of each document is obtained through `issuer_key`.
+  ///
+  /// # Errors
+  /// Fails on the first document that is not valid, or if the response reports an error.
+  pub fn validate_device_response<F>(
+    &self,
+    response: &DeviceResponse,
+    session_transcript: &CborValue,
+    options: &MdocValidationOptions,
+    issuer_key: F,
+  ) -> Result<Vec<DecodedMdoc>, MdocValidationError>
+  where
+    F: Fn(&Document) -> Option<Jwk>,
+  {
+    if response.status() != 0 {
+      return Err(MdocValidationError::ResponseStatus(response.status()));
+    }
+
+    response
+      .documents()
+      .iter()
+      .map(|document| {
+        let key =
+          issuer_key(document).ok_or_else(|| MdocValidationError::MissingIssuerKey(document.doc_type().to_owned()))?;
+        self.validate_document(document, &key, session_transcript, options)
+      })
+      .collect()
+  }
+
+  fn validate_issuer_signed(
+    &self,
+    doc_type: &str,
+    issuer_signed: &IssuerSigned,
+    issuer_key: &Jwk,
+    options: &MdocValidationOptions,
+  ) -> Result<DecodedMdoc, MdocValidationError> {
+    self.verify_cose_sign1(issuer_signed.issuer_auth(), None, issuer_key, SignerContext::Issuer)?;
+    let mso = issuer_signed.mso().map_err(MdocValidationError::MdocDecodingError)?;
+
+    if mso.doc_type != doc_type {
+      return Err(MdocValidationError::DocTypeMismatch {
+        expected: mso.doc_type,
+        found: doc_type.to_owned(),
+      });
+    }
+    if let Some(expected) = options.doc_type.as_deref().filter(|expected| *expected != doc_type) {
+      return Err(MdocValidationError::DocTypeMismatch {
+        expected: expected.to_owned(),
+        found: doc_type.to_owned(),
+      });
+    }
+
+    let earliest_expiry_date = options.earliest_expiry_date.unwrap_or_else(Timestamp::now_utc);
+    if mso.validity_info.valid_until < earliest_expiry_date {
+      return Err(MdocValidationError::ExpirationDate);
+    }
+    let latest_issuance_date = options.latest_issuance_date.unwrap_or_else(Timestamp::now_utc);
+    if mso.validity_info.valid_from > latest_issuance_date {
+      return Err(MdocValidationError::IssuanceDate);
+    }
+
+    let mut elements = BTreeMap::new();
+    for (name_space, items) in issuer_signed.name_spaces() {
+      let mut name_space_elements = BTreeMap::new();
+      for item in items {
+        let digest = item
+          .digest(mso.digest_algorithm)
+          .map_err(MdocValidationError::MdocDecodingError)?;
+        let signed_digest = mso
+          .value_digests
+          .get(name_space)
+          .and_then(|digests| digests.get(&item.digest_id()));
+        if signed_digest != Some(&digest) {
+          return Err(MdocValidationError::DigestMismatch {
+            name_space: name_space.clone(),
+            element_identifier: item.element_identifier().to_owned(),
+          });
+        }
+        name_space_elements.insert(item.element_identifier().to_owned(), item.element_value().clone());
+      }
+      elements.insert(name_space.clone(), name_space_elements);
+    }
+
+    Ok(DecodedMdoc {
+      doc_type: doc_type.to_owned(),
+      mso,
+      elements,
+    })
+  }
+
+  fn verify_cose_sign1(
+    &self,
+    sign1: &CoseSign1,
+    detached_payload: Option<&[u8]>,
+    public_key: &Jwk,
+    signer_ctx: SignerContext,
+  ) -> Result<(), MdocValidationError> {
+    cose::verify_cose_sign1(sign1, detached_payload, &self.0, public_key)
+      .map_err(|source| MdocValidationError::Signature { source, signer_ctx })
+  }
+}
@@ -0,0 +1,13 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Contains functionality for validating ISO/IEC 18013-5 mdocs.
+mod decoded_mdoc;
+mod error;
+mod mdoc_validation_options;
+mod mdoc_validator;
+
+pub use decoded_mdoc::*;
+pub use error::*;
+pub use mdoc_validation_options::*;
+pub use mdoc_validator::*;
@@ -3,24 +3,36 @@
 
 //! Verifiable Credential and Presentation validators.
 
+#[cfg(feature = "cose")]
+pub use self::cwt_credential_validation::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use self::jpt_credential_validation::*;
 #[cfg(feature = "jpt-bbs-plus")]
 pub use self::jpt_presentation_validation::*;
 pub use self::jwt_credential_validation::*;
+#[cfg(feature = "jwt-issuer-key-resolver")]
+pub use self::jwt_issuer_key_resolution::*;
 pub use self::jwt_presentation_validation::*;
+#[cfg(feature = "mdoc")]
+pub use self::mdoc::*;
 pub use self::options::FailFast;
 pub use self::options::StatusCheck;
 pub use self::options::SubjectHolderRelationship;
 #[cfg(feature = "sd-jwt")]
 pub use self::sd_jwt::*;
 
+#[cfg(feature = "cose")]
+mod cwt_credential_validation;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jpt_credential_validation;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jpt_presentation_validation;
 mod jwt_credential_validation;
+#[cfg(feature = "jwt-issuer-key-resolver")]
+mod jwt_issuer_key_resolution;
 mod jwt_presentation_validation;
+#[cfg(feature = "mdoc")]
+mod mdoc;
 mod options;
 #[cfg(feature = "sd-jwt")]
 mod sd_jwt;
@@ -7,6 +7,7 @@ use std::str::FromStr;
 
 use identity_jose::jwk::Jwk;
 use identity_jose::jwu::decode_b64_json;
+use identity_jose::jwu::encode_b64_json;
 
 use crate::CoreDID;
 use crate::Error;
@@ -22,6 +23,19 @@ impl DIDJwk {
   /// [`DIDJwk`]'s method.
   pub const METHOD: &'static str = "jwk";
 
+  /// Creates a new [`DIDJwk`] encoding the given public key.
+  ///
+  /// # Errors
+  /// Fails if `jwk` contains private key material.
+  pub fn new(jwk: &Jwk) -> Result<Self, Error> {
+    if !jwk.is_public() {
+      return Err(Error::Other("did:jwk must not contain private key material"));
+    }
+    let method_id: String = encode_b64_json(jwk).map_err(|_| Error::InvalidMethodId)?;
+
+    format!("did:{}:{method_id}", Self::METHOD).parse()
+  }
+
   /// Tries to parse a [`DIDJwk`] from a string.
   pub fn parse(s: &str) -> Result<Self, Error> {
     s.parse()
@@ -108,6 +122,24 @@ mod tests {
     assert_eq!(did.jwk(), target_jwk);
   }
 
+  #[test]
+  fn test_new() {
+    let jwk = Jwk::from_json_value(serde_json::json!({
+      "kty":"OKP","crv":"X25519","use":"enc","x":"3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"
+    }))
+    .unwrap();
+    let did = DIDJwk::new(&jwk).unwrap();
+    assert_eq!(did.method(), DIDJwk::METHOD);
+    assert_eq!(did.jwk(), jwk);
+
+    let private_jwk = Jwk::from_json_value(serde_json::json!({
+      "kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+      "d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"
+    }))
+    .unwrap();
+    assert!(DIDJwk::new(&private_jwk).is_err());
+  }
+
   #[test]
   fn test_invalid_deserialization() {
     assert!(
@@ -962,6 +962,13 @@ impl CoreDocument {
       ));
     }
 
+    // Validate the algorithm
+    if options.require_hybrid && !validation_item.alg().is_some_and(|alg| alg.is_hybrid()) {
+      return Err(Error::JwsVerificationError(
+        identity_verification::jose::error::Error::InvalidParam("expected a hybrid signature"),
+      ));
+    }
+
     let method_url_query: DIDUrlQuery<'_> = match &options.method_id {
       Some(method_id) => method_id.into(),
       None => validation_item
@@ -19,6 +19,10 @@ pub struct JwsVerificationOptions {
   /// The DID URl of the method, whose JWK should be used to verify the JWS.
   /// If unset, the `kid` of the JWS is used as the DID Url.
   pub method_id: Option<DIDUrl>,
+  /// Verify that the JWS is secured with a hybrid signature, combining a post-quantum
+  /// and a traditional algorithm.
+  #[serde(default)]
+  pub require_hybrid: bool,
 }
 
 impl JwsVerificationOptions {
@@ -44,4 +48,10 @@ impl JwsVerificationOptions {
     self.method_id = Some(value);
     self
   }
+
+  /// Set whether the JWS is required to be secured with a hybrid signature.
+  pub fn require_hybrid(mut self, value: bool) -> Self {
+    self.require_hybrid = value;
+    self
+  }
 }
@@ -13,6 +13,8 @@ use identity_verification::jws::SignatureVerificationErrorKind;
 ///
 /// - [`JwsAlgorithm::ES256`](identity_verification::jws::JwsAlgorithm::ES256).
 /// - [`JwsAlgorithm::ES256K`](identity_verification::jws::JwsAlgorithm::ES256K).
+/// - [`JwsAlgorithm::ES384`](identity_verification::jws::JwsAlgorithm::ES384).
+/// - [`JwsAlgorithm::ES512`](identity_verification::jws::JwsAlgorithm::ES512).
 #[derive(Debug, Default)]
 #[non_exhaustive]
 pub struct EcDSAJwsVerifier {}
@@ -28,6 +30,10 @@ impl JwsVerifier for EcDSAJwsVerifier {
       JwsAlgorithm::ES256 => crate::Secp256R1Verifier::verify(&input, public_key),
       #[cfg(feature = "es256k")]
       JwsAlgorithm::ES256K => crate::Secp256K1Verifier::verify(&input, public_key),
+      #[cfg(feature = "es384")]
+      JwsAlgorithm::ES384 => crate::Secp384R1Verifier::verify(&input, public_key),
+      #[cfg(feature = "es512")]
+      JwsAlgorithm::ES512 => crate::Secp521R1Verifier::verify(&input, public_key),
       _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
     }
   }
@@ -18,12 +18,20 @@ mod ecdsa_jws_verifier;
 mod secp256k1;
 #[cfg(feature = "es256")]
 mod secp256r1;
+#[cfg(feature = "es384")]
+mod secp384r1;
+#[cfg(feature = "es512")]
+mod secp521r1;
 
 pub use ecdsa_jws_verifier::*;
 #[cfg(feature = "es256k")]
 pub use secp256k1::*;
 #[cfg(feature = "es256")]
 pub use secp256r1::*;
+#[cfg(feature = "es384")]
+pub use secp384r1::*;
+#[cfg(feature = "es512")]
+pub use secp521r1::*;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,92 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Deref;
+
+use identity_verification::jwk::JwkParamsEc;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use identity_verification::jwu::{self};
+use p384::ecdsa::Signature;
+use p384::ecdsa::VerifyingKey;
+use p384::elliptic_curve::sec1::FromEncodedPoint;
+use p384::elliptic_curve::subtle::CtOption;
+use p384::EncodedPoint;
+use p384::FieldBytes;
+use p384::PublicKey;
+
+/// A verifier that can handle the
+/// [`JwsAlgorithm::ES384`](identity_verification::jws::JwsAlgorithm::ES384)
+/// algorithm.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct Secp384R1Verifier {}
+
+impl Secp384R1Verifier {
+  /// Verify a JWS signature secured with the
+  /// [`JwsAlgorithm::ES384`](identity_verification::jws::JwsAlgorithm::ES384)
+  /// algorithm.
+  ///
+  /// This function is useful when one is building a
+  /// [`JwsVerifier`](identity_verification::jws::JwsVerifier) that
+  /// handles the
+  /// [`JwsAlgorithm::ES384`](identity_verification::jws::JwsAlgorithm::ES384)
+  /// in the same manner as the [`Secp384R1Verifier`] hence extending its
+  /// capabilities.
+  ///
+  /// # Warning
+  ///
+  /// This function does not check whether `alg = ES384` in the protected
+  /// header. Callers are expected to assert this prior to calling the
+  /// function.
+  pub fn verify(
+    input: &identity_verification::jws::VerificationInput,
+    public_key: &identity_verification::jwk::Jwk,
+  ) -> Result<(), SignatureVerificationError> {
+    // Obtain a P384 public key.
+    let params: &JwkParamsEc = public_key
+      .try_ec_params()
+      .map_err(|_| SignatureVerificationErrorKind::UnsupportedKeyType)?;
+
+    // The JWK contains the uncompressed x and y coordinates, each of which must be exactly one field
+    // element long.
+    let x: FieldBytes = decode_coordinate(&params.x)?;
+    let y: FieldBytes = decode_coordinate(&params.y)?;
+    let encoded_point: EncodedPoint = EncodedPoint::from_affine_coordinates(&x, &y, false);
+    let public_key: PublicKey = {
+      let opt_public_key: CtOption<PublicKey> = PublicKey::from_encoded_point(&encoded_point);
+      if opt_public_key.is_none().into() {
+        return Err(SignatureVerificationError::new(
+          SignatureVerificationErrorKind::KeyDecodingFailure,
+        ));
+      } else {
+        opt_public_key.unwrap()
+      }
+    };
+
+    let verifying_key: VerifyingKey = VerifyingKey::from(public_key);
+
+    let signature: Signature = Signature::try_from(input.decoded_signature.deref()).map_err(|err| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
+    })?;
+
+    match signature::Verifier::verify(&verifying_key, &input.signing_input, &signature) {
+      Ok(()) => Ok(()),
+      Err(err) => {
+        Err(SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err))
+      }
+    }
+  }
+}
+
+/// Decodes a base64url encoded coordinate of a P-384 point.
+fn decode_coordinate(coordinate: &str) -> Result<FieldBytes, SignatureVerificationError> {
+  let bytes: Vec<u8> = jwu::decode_b64(coordinate).map_err(|err| {
+    SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
+  })?;
+  if bytes.len() != FieldBytes::default().len() {
+    return Err(SignatureVerificationErrorKind::KeyDecodingFailure.into());
+  }
+
+  Ok(FieldBytes::clone_from_slice(&bytes))
+}
@@ -0,0 +1,80 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Deref;
+
+use identity_verification::jwk::JwkParamsEc;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use identity_verification::jwu::{self};
+use p521::ecdsa::Signature;
+use p521::ecdsa::VerifyingKey;
+use p521::EncodedPoint;
+use p521::FieldBytes;
+
+/// A verifier that can handle the
+/// [`JwsAlgorithm::ES512`](identity_verification::jws::JwsAlgorithm::ES512)
+/// algorithm.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct Secp521R1Verifier {}
+
+impl Secp521R1Verifier {
+  /// Verify a JWS signature secured with the
+  /// [`JwsAlgorithm::ES512`](identity_verification::jws::JwsAlgorithm::ES512)
+  /// algorithm.
+  ///
+  /// This function is useful when one is building a
+  /// [`JwsVerifier`](identity_verification::jws::JwsVerifier) that
+  /// handles the
+  /// [`JwsAlgorithm::ES512`](identity_verification::jws::JwsAlgorithm::ES512)
+  /// in the same manner as the [`Secp521R1Verifier`] hence extending its
+  /// capabilities.
+  ///
+  /// # Warning
+  ///
+  /// This function does not check whether `alg = ES512` in the protected
+  /// header. Callers are expected to assert this prior to calling the
+  /// function.
+  pub fn verify(
+    input: &identity_verification::jws::VerificationInput,
+    public_key: &identity_verification::jwk::Jwk,
+  ) -> Result<(), SignatureVerificationError> {
+    // Obtain a P521 public key.
+    let params: &JwkParamsEc = public_key
+      .try_ec_params()
+      .map_err(|_| SignatureVerificationErrorKind::UnsupportedKeyType)?;
+
+    // The JWK contains the uncompressed x and y coordinates, each of which must be exactly one field
+    // element long.
+    let x: FieldBytes = decode_coordinate(&params.x)?;
+    let y: FieldBytes = decode_coordinate(&params.y)?;
+    let encoded_point: EncodedPoint = EncodedPoint::from_affine_coordinates(&x, &y, false);
+    let verifying_key: VerifyingKey = VerifyingKey::from_encoded_point(&encoded_point).map_err(|err| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
+    })?;
+
+    let signature: Signature = Signature::try_from(input.decoded_signature.deref()).map_err(|err| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
+    })?;
+
+    match signature::Verifier::verify(&verifying_key, &input.signing_input, &signature) {
+      Ok(()) => Ok(()),
+      Err(err) => {
+        Err(SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err))
+      }
+    }
+  }
+}
+
+/// Decodes a base64url encoded coordinate of a P-521 point.
+fn decode_coordinate(coordinate: &str) -> Result<FieldBytes, SignatureVerificationError> {
+  let bytes: Vec<u8> = jwu::decode_b64(coordinate).map_err(|err| {
+    SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
+  })?;
+  if bytes.len() != FieldBytes::default().len() {
+    return Err(SignatureVerificationErrorKind::KeyDecodingFailure.into());
+  }
+
+  Ok(FieldBytes::clone_from_slice(&bytes))
+}
@@ -3,3 +3,25 @@
 
 mod secp256;
 mod secp256k;
+#[cfg(feature = "es384")]
+mod secp384;
+#[cfg(feature = "es512")]
+mod secp521;
+
+/// Returns a JWT signed by josekit using `alg`, alongside the public key it can be verified with.
+#[cfg(any(feature = "es384", feature = "es512"))]
+fn josekit_jwt(alg: josekit::jws::alg::ecdsa::EcdsaJwsAlgorithm) -> (String, identity_verification::jwk::Jwk) {
+  use josekit::jws::JwsHeader;
+  use josekit::jwt::JwtPayload;
+
+  let key_pair = alg.generate_key_pair().unwrap();
+  let mut src_header = JwsHeader::new();
+  src_header.set_token_type("JWT");
+  let mut src_payload = JwtPayload::new();
+  src_payload.set_claim("key", Some("value".into())).unwrap();
+  let signer = alg.signer_from_jwk(&key_pair.to_jwk_key_pair()).unwrap();
+  let jwt_string = josekit::jwt::encode_with_signer(&src_payload, &src_header, &signer).unwrap();
+
+  let public_jwk = serde_json::from_str(&key_pair.to_jwk_public_key().to_string()).unwrap();
+  (jwt_string, public_jwk)
+}
@@ -0,0 +1,52 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jwk::Jwk;
+use identity_verification::jws;
+use identity_verification::jwu;
+
+use super::josekit_jwt;
+use crate::EcDSAJwsVerifier;
+
+#[test]
+fn test_es512_josekit() {
+  let (jwt_string, jwk) = josekit_jwt(josekit::jws::ES512);
+
+  let jws_verifier = EcDSAJwsVerifier::default();
+  let decoder = jws::Decoder::new();
+  assert!(decoder
+    .decode_compact_serialization(jwt_string.as_bytes(), None)
+    .and_then(|decoded| decoded.verify(&jws_verifier, &jwk))
+    .is_ok());
+}
+
+#[test]
+fn test_es512_wrong_key_fails() {
+  let (jwt_string, _) = josekit_jwt(josekit::jws::ES512);
+  let (_, other_jwk) = josekit_jwt(josekit::jws::ES512);
+
+  let jws_verifier = EcDSAJwsVerifier::default();
+  let decoder = jws::Decoder::new();
+  assert!(decoder
+    .decode_compact_serialization(jwt_string.as_bytes(), None)
+    .and_then(|decoded| decoded.verify(&jws_verifier, &other_jwk))
+    .is_err());
+}
+
+#[test]
+fn test_es512_coordinate_of_wrong_length_fails() {
+  let (jwt_string, jwk) = josekit_jwt(josekit::jws::ES512);
+
+  let jws_verifier = EcDSAJwsVerifier::default();
+  let decoder = jws::Decoder::new();
+  for len in [0, 66 - 1, 66 + 1] {
+    let mut malformed_jwk: Jwk = jwk.clone();
+    let params = malformed_jwk.try_ec_params_mut().unwrap();
+    params.y = jwu::encode_b64(vec![1; len]);
+
+    assert!(decoder
+      .decode_compact_serialization(jwt_string.as_bytes(), None)
+      .and_then(|decoded| decoded.verify(&jws_verifier, &malformed_jwk))
+      .is_err());
+  }
+}
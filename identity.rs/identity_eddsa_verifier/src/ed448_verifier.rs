@@ -0,0 +1,78 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Deref;
+
+use ed448_goldilocks_plus::Signature;
+use ed448_goldilocks_plus::VerifyingKey;
+use ed448_goldilocks_plus::PUBLIC_KEY_LENGTH;
+use ed448_goldilocks_plus::SIGNATURE_LENGTH;
+use identity_jose::jwk::EdCurve;
+use identity_jose::jwk::Jwk;
+use identity_jose::jwk::JwkParamsOkp;
+use identity_jose::jws::SignatureVerificationError;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jws::VerificationInput;
+
+/// A verifier that can handle the [`JwsAlgorithm::EdDSA`](identity_jose::jws::JwsAlgorithm::EdDSA) algorithm with curve
+/// [`EdCurve::Ed448`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Ed448Verifier;
+
+impl Ed448Verifier {
+  /// Verify a JWS signature secured with the [`JwsAlgorithm::EdDSA`](identity_jose::jws::JwsAlgorithm::EdDSA)
+  /// algorithm and curve [`EdCurve::Ed448`]. This associated method is only available when the
+  /// `ed448` feature is enabled.
+  ///
+  /// This function is useful when one is composing a [`JwsVerifier`](identity_jose::jws::JwsVerifier) that delegates
+  /// [`JwsAlgorithm::EdDSA`](identity_jose::jws::JwsAlgorithm::EdDSA) verification with
+  /// curve [`EdCurve::Ed448`] to this function.
+  ///
+  /// # Warning
+  ///
+  /// This function does not check whether `alg = EdDSA` in the protected header. Callers are expected to assert this
+  /// prior to calling the function.
+  pub fn verify(input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    // Obtain an Ed448 public key.
+    let params: &JwkParamsOkp = public_key
+      .try_okp_params()
+      .map_err(|_| SignatureVerificationErrorKind::UnsupportedKeyType)?;
+
+    if params
+      .try_ed_curve()
+      .ok()
+      .filter(|curve_param| *curve_param == EdCurve::Ed448)
+      .is_none()
+    {
+      return Err(SignatureVerificationErrorKind::UnsupportedKeyParams.into());
+    }
+
+    let pk: [u8; PUBLIC_KEY_LENGTH] = identity_jose::jwu::decode_b64(params.x.as_str())
+      .map_err(|_| {
+        SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure)
+          .with_custom_message("could not decode x parameter from jwk")
+      })
+      .and_then(|value| {
+        TryInto::try_into(value).map_err(|_| {
+          SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure)
+            .with_custom_message("invalid public key length")
+        })
+      })?;
+
+    let public_key_ed448 = VerifyingKey::from_bytes(&pk).map_err(|_| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure)
+        .with_custom_message("invalid Ed448 public key")
+    })?;
+
+    let signature_arr = <[u8; SIGNATURE_LENGTH]>::try_from(input.decoded_signature.deref())
+      .map_err(|_| SignatureVerificationErrorKind::InvalidSignature)?;
+
+    let signature =
+      Signature::from_bytes(&signature_arr).map_err(|_| SignatureVerificationErrorKind::InvalidSignature)?;
+
+    public_key_ed448
+      .verify_raw(&signature, &input.signing_input)
+      .map_err(|_| SignatureVerificationErrorKind::InvalidSignature.into())
+  }
+}
@@ -1,6 +1,7 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use identity_jose::jwk::EdCurve;
 use identity_jose::jwk::Jwk;
 use identity_jose::jws::JwsVerifier;
 use identity_jose::jws::SignatureVerificationError;
@@ -9,6 +10,11 @@ use identity_jose::jws::VerificationInput;
 
 /// An implementor of [`JwsVerifier`] that can handle the
 /// [`JwsAlgorithm::EdDSA`](identity_jose::jws::JwsAlgorithm::EdDSA) algorithm.
+///
+/// The following curves are supported, if the respective feature on the crate is activated:
+///
+/// - [`EdCurve::Ed25519`].
+/// - [`EdCurve::Ed448`].
 #[derive(Debug)]
 #[non_exhaustive]
 pub struct EdDSAJwsVerifier;
@@ -27,9 +33,23 @@ impl JwsVerifier for EdDSAJwsVerifier {
   #[allow(unused_variables)]
   fn verify(&self, input: VerificationInput, public_key: &Jwk) -> std::result::Result<(), SignatureVerificationError> {
     match input.alg {
-      #[cfg(feature = "ed25519")]
-      identity_jose::jws::JwsAlgorithm::EdDSA => crate::Ed25519Verifier::verify(input, public_key),
+      identity_jose::jws::JwsAlgorithm::EdDSA => match ed_curve(public_key) {
+        #[cfg(feature = "ed448")]
+        Some(EdCurve::Ed448) => crate::Ed448Verifier::verify(input, public_key),
+        #[cfg(feature = "ed25519")]
+        _ => crate::Ed25519Verifier::verify(input, public_key),
+        #[cfg(not(feature = "ed25519"))]
+        _ => Err(SignatureVerificationErrorKind::UnsupportedKeyParams.into()),
+      },
       _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
     }
   }
 }
+
+/// Returns the curve of `public_key`, if it is an Okp key of a known Edwards curve.
+fn ed_curve(public_key: &Jwk) -> Option<EdCurve> {
+  public_key
+    .try_okp_params()
+    .ok()
+    .and_then(|params| params.try_ed_curve().ok())
+}
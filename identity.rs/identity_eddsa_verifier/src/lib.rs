@@ -3,8 +3,12 @@
 
 #[cfg(feature = "ed25519")]
 mod ed25519_verifier;
+#[cfg(feature = "ed448")]
+mod ed448_verifier;
 mod eddsa_verifier;
 
 #[cfg(feature = "ed25519")]
 pub use ed25519_verifier::*;
+#[cfg(feature = "ed448")]
+pub use ed448_verifier::*;
 pub use eddsa_verifier::*;
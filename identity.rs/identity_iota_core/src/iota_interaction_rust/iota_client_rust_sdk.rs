@@ -9,6 +9,8 @@ use std::result::Result;
 
 use secret_storage::Signer;
 
+#[cfg(feature = "local-ledger")]
+use crate::rebased::local_ledger::LocalLedger;
 use crate::rebased::Error;
 use identity_iota_interaction::apis::CoinReadApi;
 use identity_iota_interaction::apis::EventApi;
@@ -17,6 +19,7 @@ use identity_iota_interaction::apis::ReadApi;
 use identity_iota_interaction::error::IotaRpcResult;
 use identity_iota_interaction::rpc_types::Coin;
 use identity_iota_interaction::rpc_types::CoinPage;
+use identity_iota_interaction::rpc_types::DryRunTransactionBlockResponse;
 use identity_iota_interaction::rpc_types::EventFilter;
 use identity_iota_interaction::rpc_types::EventPage;
 use identity_iota_interaction::rpc_types::IotaExecutionStatus;
@@ -189,6 +192,10 @@ impl ReadTrait for ReadAdapter<'_> {
       .map_err(|e| Error::Network("SDK get_chain_identifier() call failed".to_string(), e))
   }
 
+  async fn dry_run_transaction_block(&self, tx_data: TransactionData) -> IotaRpcResult<DryRunTransactionBlockResponse> {
+    self.api.dry_run_transaction_block(tx_data).await
+  }
+
   async fn get_dynamic_field_object(
     &self,
     parent_object_id: ObjectID,
@@ -276,9 +283,17 @@ impl EventTrait for EventAdapter<'_> {
   }
 }
 
+/// Where transactions are executed and objects are read from.
+#[derive(Clone)]
+enum Backend {
+  Node(IotaClient),
+  #[cfg(feature = "local-ledger")]
+  Local(LocalLedger),
+}
+
 #[derive(Clone)]
 pub struct IotaClientRustSdk {
-  iota_client: IotaClient,
+  backend: Backend,
 }
 
 #[async_trait]
@@ -289,27 +304,43 @@ impl IotaClientTrait for IotaClientRustSdk {
   fn quorum_driver_api(
     &self,
   ) -> Box<dyn QuorumDriverTrait<Error = Error, NativeResponse = IotaTransactionBlockResponse> + Send + '_> {
-    Box::new(QuorumDriverAdapter {
-      api: self.iota_client.quorum_driver_api(),
-    })
+    match &self.backend {
+      Backend::Node(iota_client) => Box::new(QuorumDriverAdapter {
+        api: iota_client.quorum_driver_api(),
+      }),
+      #[cfg(feature = "local-ledger")]
+      Backend::Local(ledger) => Box::new(ledger.clone()),
+    }
   }
 
   fn read_api(&self) -> Box<dyn ReadTrait<Error = Error, NativeResponse = IotaTransactionBlockResponse> + Send + '_> {
-    Box::new(ReadAdapter {
-      api: self.iota_client.read_api(),
-    })
+    match &self.backend {
+      Backend::Node(iota_client) => Box::new(ReadAdapter {
+        api: iota_client.read_api(),
+      }),
+      #[cfg(feature = "local-ledger")]
+      Backend::Local(ledger) => Box::new(ledger.clone()),
+    }
   }
 
   fn coin_read_api(&self) -> Box<dyn CoinReadTrait<Error = Self::Error> + Send + '_> {
-    Box::new(CoinReadAdapter {
-      api: self.iota_client.coin_read_api(),
-    })
+    match &self.backend {
+      Backend::Node(iota_client) => Box::new(CoinReadAdapter {
+        api: iota_client.coin_read_api(),
+      }),
+      #[cfg(feature = "local-ledger")]
+      Backend::Local(ledger) => Box::new(ledger.clone()),
+    }
   }
 
   fn event_api(&self) -> Box<dyn EventTrait<Error = Self::Error> + Send + '_> {
-    Box::new(EventAdapter {
-      api: self.iota_client.event_api(),
-    })
+    match &self.backend {
+      Backend::Node(iota_client) => Box::new(EventAdapter {
+        api: iota_client.event_api(),
+      }),
+      #[cfg(feature = "local-ledger")]
+      Backend::Local(ledger) => Box::new(ledger.clone()),
+    }
   }
 
   async fn execute_transaction<S>(
@@ -339,7 +370,6 @@ impl IotaClientTrait for IotaClientRustSdk {
 
     // resolve previous tx
     let prev_tx_response = self
-      .iota_client
       .read_api()
       .get_transaction_with_options(
         prev_tx_digest,
@@ -348,7 +378,8 @@ impl IotaClientTrait for IotaClientRustSdk {
       .await
       .map_err(|err| {
         Error::InvalidIdentityHistory(format!("could not get previous transaction {prev_tx_digest}; {err}"))
-      })?;
+      })?
+      .clone_native_response();
 
     // check for updated/created changes
     let (created, other_changes): (Vec<ObjectChange>, _) = prev_tx_response
@@ -400,7 +431,6 @@ impl IotaClientTrait for IotaClientRustSdk {
     version: SequenceNumber,
   ) -> Result<IotaPastObjectResponse, Error> {
     self
-      .iota_client
       .read_api()
       .try_get_parsed_past_object(object_id, version, IotaObjectDataOptions::full_content())
       .await
@@ -412,7 +442,17 @@ impl IotaClientTrait for IotaClientRustSdk {
 
 impl IotaClientRustSdk {
   pub fn new(iota_client: IotaClient) -> Result<Self, Error> {
-    Ok(Self { iota_client })
+    Ok(Self {
+      backend: Backend::Node(iota_client),
+    })
+  }
+
+  /// Returns a client executing transactions on the given [`LocalLedger`].
+  #[cfg(feature = "local-ledger")]
+  pub(crate) fn new_local(ledger: LocalLedger) -> Self {
+    Self {
+      backend: Backend::Local(ledger),
+    }
   }
 
   async fn sdk_execute_transaction<S: Signer<IotaKeySignature>>(
@@ -437,15 +477,16 @@ impl IotaClientRustSdk {
 
     // execute tx
     let response = self
-      .iota_client
       .quorum_driver_api()
       .execute_transaction_block(
-        Transaction::from_data(tx, vec![signature]),
-        IotaTransactionBlockResponseOptions::full_content(),
+        tx,
+        vec![signature],
+        Some(IotaTransactionBlockResponseOptions::full_content()),
         Some(ExecuteTransactionRequestType::WaitForLocalExecution),
       )
       .await
-      .map_err(Error::TransactionExecutionFailed)?;
+      .map_err(Error::TransactionExecutionFailed)?
+      .clone_native_response();
 
     if let Some(IotaTransactionBlockEffects::V1(IotaTransactionBlockEffectsV1 {
       status: IotaExecutionStatus::Failure { error },
@@ -464,7 +505,6 @@ impl IotaClientRustSdk {
     tx: &ProgrammableTransaction,
   ) -> Result<u64, Error> {
     let gas_price = self
-      .iota_client
       .read_api()
       .get_reference_gas_price()
       .await
@@ -477,12 +517,7 @@ impl IotaClientRustSdk {
       50_000_000,
       gas_price,
     );
-    let dry_run_gas_result = self
-      .iota_client
-      .read_api()
-      .dry_run_transaction_block(tx_data)
-      .await?
-      .effects;
+    let dry_run_gas_result = self.read_api().dry_run_transaction_block(tx_data).await?.effects;
     if dry_run_gas_result.status().is_err() {
       let IotaExecutionStatus::Failure { error } = dry_run_gas_result.into_status() else {
         unreachable!();
@@ -504,7 +539,6 @@ impl IotaClientRustSdk {
 
     loop {
       let coins = self
-        .iota_client
         .coin_read_api()
         .get_coins(sender_address, None, cursor, Some(LIMIT))
         .await?;
@@ -7,6 +7,7 @@ use crate::iota_interaction_adapter::IdentityMoveCallsAdapter;
 use crate::rebased::migration::CreateIdentity;
 use crate::rebased::transaction_builder::Transaction;
 use crate::rebased::transaction_builder::TransactionBuilder;
+use crate::rebased::transaction_preview::DidDocumentDiff;
 use crate::IotaDID;
 use crate::IotaDocument;
 use crate::StateMetadataDocument;
@@ -290,6 +291,10 @@ impl Transaction for PublishDidDocument {
     self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
   }
 
+  fn did_document_diff(&self) -> Result<Option<DidDocumentDiff>, Error> {
+    Ok(Some(DidDocumentDiff::new(None, Some(self.did_document.clone()))))
+  }
+
   async fn apply(
     self,
     effects: IotaTransactionBlockEffects,
@@ -44,6 +44,9 @@ use serde::Deserialize;
 #[cfg(not(target_arch = "wasm32"))]
 use identity_iota_interaction::IotaClient;
 
+#[cfg(all(feature = "local-ledger", not(target_arch = "wasm32")))]
+use crate::rebased::local_ledger::LocalLedger;
+
 #[cfg(target_arch = "wasm32")]
 use iota_interaction_ts::bindings::WasmIotaClient;
 
@@ -155,6 +158,14 @@ impl IdentityClientReadOnly {
     }
   }
 
+  /// Creates a new [`IdentityClientReadOnly`] executing transactions on the given [`LocalLedger`],
+  /// using the identity package the ledger was created with.
+  #[cfg(all(feature = "local-ledger", not(target_arch = "wasm32")))]
+  pub async fn new_with_local_ledger(ledger: LocalLedger) -> Result<Self, Error> {
+    let package_id = ledger.package_id();
+    Self::new_with_pkg_id_internal(IotaClientAdapter::new_local(ledger), package_id).await
+  }
+
   async fn new_with_pkg_id_internal(
     iota_client: IotaClientAdapter,
     iota_identity_pkg_id: ObjectID,
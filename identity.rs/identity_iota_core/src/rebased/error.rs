@@ -61,6 +61,18 @@ pub enum Error {
     #[cfg(target_arch = "wasm32")]
     response: String,
   },
+  /// A sponsored transaction failed and releasing its gas reservation failed as well, leaving the reservation's
+  /// coins locked until it expires.
+  #[error("gas reservation {reservation_id} could not be released after the transaction failed: {release_error}")]
+  GasReservationNotReleased {
+    /// The error that made the transaction fail.
+    #[source]
+    source: Box<Self>,
+    /// The ID of the reservation that could not be released.
+    reservation_id: u64,
+    /// The error returned by the gas station when releasing the reservation.
+    release_error: Box<Self>,
+  },
   /// Config is invalid.
   #[error("invalid config: {0}")]
   InvalidConfig(String),
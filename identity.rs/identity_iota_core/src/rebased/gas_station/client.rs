@@ -0,0 +1,158 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use fastcrypto::encoding::Base64;
+use fastcrypto::encoding::Encoding as _;
+use identity_core::common::Url;
+use identity_iota_interaction::types::crypto::Signature;
+use identity_iota_interaction::types::transaction::TransactionData;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::GasReservation;
+use super::GasStation;
+use super::ReservationId;
+use crate::rebased::Error;
+
+/// A client for a remote gas station service, reached over HTTP.
+///
+/// The service is expected to expose the following JSON endpoints, all answering with a
+/// `{ "result": <value>, "error": <string or null> }` object:
+/// - `POST /v1/reserve_gas`, taking `{ "gas_budget": u64, "reserve_duration_secs": u64 }` and returning a
+///   [GasReservation];
+/// - `POST /v1/sponsor_tx`, taking `{ "reservation_id": u64, "tx_bytes": <base64 encoded BCS transaction data> }` and
+///   returning the sponsor's signature;
+/// - `POST /v1/release_reservation`, taking `{ "reservation_id": u64 }`.
+#[derive(Debug, Clone)]
+pub struct GasStationClient {
+  http_client: reqwest::Client,
+  url: Url,
+  auth_token: Option<String>,
+}
+
+impl GasStationClient {
+  /// Creates a new [GasStationClient] for the gas station service at `url`.
+  pub fn new(url: Url) -> Self {
+    Self {
+      http_client: reqwest::Client::new(),
+      url,
+      auth_token: None,
+    }
+  }
+
+  /// Sets the bearer token used to authenticate to the gas station service.
+  pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+    self.auth_token = Some(token.into());
+    self
+  }
+
+  /// Sets the HTTP client used to reach the gas station service.
+  pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+    self.http_client = http_client;
+    self
+  }
+
+  async fn post<B, T>(&self, path: &str, body: &B) -> Result<Option<T>, Error>
+  where
+    B: Serialize + Sync,
+    T: DeserializeOwned,
+  {
+    let endpoint = self
+      .url
+      .join(path)
+      .map_err(|e| Error::InvalidConfig(format!("invalid gas station url: {e}")))?;
+    let mut request = self.http_client.post(endpoint.as_str()).json(body);
+    if let Some(token) = self.auth_token.as_deref() {
+      request = request.bearer_auth(token);
+    }
+
+    let response = request
+      .send()
+      .await
+      .and_then(reqwest::Response::error_for_status)
+      .map_err(|e| Error::GasIssue(format!("gas station request to {path} failed: {e}")))?
+      .json::<GasStationResponse<T>>()
+      .await
+      .map_err(|e| Error::GasIssue(format!("invalid gas station response from {path}: {e}")))?;
+
+    if let Some(error) = response.error {
+      return Err(Error::GasIssue(format!("gas station error: {error}")));
+    }
+
+    Ok(response.result)
+  }
+}
+
+fn missing_result(path: &str) -> Error {
+  Error::GasIssue(format!("empty gas station response from {path}"))
+}
+
+#[derive(Deserialize)]
+struct GasStationResponse<T> {
+  result: Option<T>,
+  error: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ReserveGasRequest {
+  gas_budget: u64,
+  reserve_duration_secs: u64,
+}
+
+#[derive(Serialize)]
+struct SponsorTxRequest {
+  reservation_id: ReservationId,
+  tx_bytes: String,
+}
+
+#[derive(Deserialize)]
+struct SponsorTxResponse {
+  signature: Signature,
+}
+
+#[derive(Serialize)]
+struct ReleaseReservationRequest {
+  reservation_id: ReservationId,
+}
+
+#[cfg_attr(feature = "send-sync", async_trait)]
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+impl GasStation for GasStationClient {
+  async fn reserve_gas(&self, gas_budget: u64, reserve_duration: Duration) -> Result<GasReservation, Error> {
+    let request = ReserveGasRequest {
+      gas_budget,
+      reserve_duration_secs: reserve_duration.as_secs(),
+    };
+    self
+      .post("v1/reserve_gas", &request)
+      .await?
+      .ok_or_else(|| missing_result("v1/reserve_gas"))
+  }
+
+  async fn sponsor_transaction(
+    &self,
+    reservation_id: ReservationId,
+    tx_data: &TransactionData,
+  ) -> Result<Signature, Error> {
+    let request = SponsorTxRequest {
+      reservation_id,
+      tx_bytes: Base64::encode(bcs::to_bytes(tx_data)?),
+    };
+    self
+      .post::<_, SponsorTxResponse>("v1/sponsor_tx", &request)
+      .await?
+      .map(|response| response.signature)
+      .ok_or_else(|| missing_result("v1/sponsor_tx"))
+  }
+
+  async fn release_reservation(&self, reservation_id: ReservationId) -> Result<(), Error> {
+    self
+      .post::<_, serde_json::Value>("v1/release_reservation", &ReleaseReservationRequest { reservation_id })
+      .await
+      .map(|_| ())
+  }
+}
@@ -0,0 +1,237 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use identity_core::common::Duration as TimestampDuration;
+use identity_core::common::Timestamp;
+use identity_iota_interaction::rpc_types::IotaObjectRef;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::crypto::Signature;
+use identity_iota_interaction::types::transaction::TransactionData;
+use identity_iota_interaction::types::transaction::TransactionDataAPI as _;
+use identity_iota_interaction::IotaClientTrait as _;
+use identity_iota_interaction::IotaKeySignature;
+use identity_iota_interaction::OptionalSync;
+use secret_storage::Signer;
+
+use super::GasReservation;
+use super::GasStation;
+use super::ReservationId;
+use crate::rebased::client::IdentityClientReadOnly;
+use crate::rebased::Error;
+
+#[derive(Debug)]
+struct Reservation {
+  gas_coins: Vec<IotaObjectRef>,
+  gas_budget: u64,
+  /// `None` if the requested duration overflows the timestamp's range.
+  expires_at: Option<Timestamp>,
+}
+
+impl Reservation {
+  fn is_expired(&self, now: Timestamp) -> bool {
+    self.expires_at.is_some_and(|expires_at| expires_at <= now)
+  }
+}
+
+/// Drops the reservations that outlived their reserve duration, freeing their coins.
+fn prune_expired(reservations: &mut HashMap<ReservationId, Reservation>) {
+  let now = Timestamp::now_utc();
+  reservations.retain(|_, reservation| !reservation.is_expired(now));
+}
+
+/// A [GasStation] running in the current process, that sponsors transactions with the coins
+/// owned by its signer's address.
+///
+/// Reservations are only tracked in memory and expire once their reserve duration has elapsed, with a
+/// resolution of one second. This type is meant to stand in for an actual gas station service in tests and
+/// local setups.
+pub struct LocalGasStation<S> {
+  client: IdentityClientReadOnly,
+  signer: S,
+  sponsor_address: IotaAddress,
+  next_reservation_id: AtomicU64,
+  reservations: Mutex<HashMap<ReservationId, Reservation>>,
+}
+
+impl<S> LocalGasStation<S>
+where
+  S: Signer<IotaKeySignature>,
+{
+  /// Creates a new [LocalGasStation] that sponsors transactions with `signer`'s coins.
+  pub async fn new(client: IdentityClientReadOnly, signer: S) -> Result<Self, Error> {
+    let public_key = signer
+      .public_key()
+      .await
+      .map_err(|e| Error::InvalidKey(e.to_string()))?;
+
+    Ok(Self {
+      client,
+      signer,
+      sponsor_address: IotaAddress::from(&public_key),
+      next_reservation_id: AtomicU64::new(1),
+      reservations: Mutex::default(),
+    })
+  }
+}
+
+impl<S> LocalGasStation<S> {
+  /// Returns the address of the sponsor, i.e. the owner of the coins used to pay for gas.
+  pub fn sponsor_address(&self) -> IotaAddress {
+    self.sponsor_address
+  }
+
+  /// Returns the number of reservations that have been neither released nor expired.
+  pub fn active_reservations(&self) -> usize {
+    let mut reservations = self.reservations.lock().expect("not poisoned");
+    prune_expired(&mut reservations);
+    reservations.len()
+  }
+
+  fn reserved_coins(&self) -> HashSet<ObjectID> {
+    let mut reservations = self.reservations.lock().expect("not poisoned");
+    prune_expired(&mut reservations);
+    reservations
+      .values()
+      .flat_map(|reservation| reservation.gas_coins.iter().map(|coin| coin.object_id))
+      .collect()
+  }
+}
+
+#[cfg_attr(feature = "send-sync", async_trait)]
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+impl<S> GasStation for LocalGasStation<S>
+where
+  S: Signer<IotaKeySignature> + OptionalSync,
+{
+  async fn reserve_gas(&self, gas_budget: u64, reserve_duration: Duration) -> Result<GasReservation, Error> {
+    let mut cursor = None;
+    let mut gas_coins = vec![];
+    let mut balance = 0;
+
+    loop {
+      let page = self
+        .client
+        .coin_read_api()
+        .get_coins(
+          self.sponsor_address,
+          Some(String::from("0x2::iota::IOTA")),
+          cursor,
+          None,
+        )
+        .await
+        .map_err(|e| Error::RpcError(e.to_string()))?;
+      // Coins are checked against the current reservations only now, to avoid holding the lock across an await.
+      let reserved_coins = self.reserved_coins();
+      for coin in page
+        .data
+        .into_iter()
+        .filter(|coin| !reserved_coins.contains(&coin.coin_object_id))
+      {
+        balance += coin.balance;
+        gas_coins.push(IotaObjectRef::from(coin.object_ref()));
+        if balance >= gas_budget {
+          break;
+        }
+      }
+
+      if balance >= gas_budget || !page.has_next_page {
+        break;
+      }
+      cursor = page.next_cursor;
+    }
+
+    if balance < gas_budget {
+      return Err(Error::GasIssue(format!(
+        "sponsor {} does not have enough unreserved coins to cover a budget of {gas_budget}",
+        self.sponsor_address
+      )));
+    }
+
+    let reservation_id = self.next_reservation_id.fetch_add(1, Ordering::Relaxed);
+    let expires_at = u32::try_from(reserve_duration.as_secs())
+      .ok()
+      .and_then(|secs| Timestamp::now_utc().checked_add(TimestampDuration::seconds(secs)));
+    let mut reservations = self.reservations.lock().expect("not poisoned");
+    prune_expired(&mut reservations);
+    // Make sure no concurrent reservation grabbed the same coins in the meantime.
+    let already_reserved = reservations
+      .values()
+      .flat_map(|reservation| reservation.gas_coins.iter())
+      .any(|coin| gas_coins.iter().any(|reserved| reserved.object_id == coin.object_id));
+    if already_reserved {
+      return Err(Error::GasIssue(
+        "the selected gas coins have been concurrently reserved; try again".to_owned(),
+      ));
+    }
+    reservations.insert(
+      reservation_id,
+      Reservation {
+        gas_coins: gas_coins.clone(),
+        gas_budget,
+        expires_at,
+      },
+    );
+
+    Ok(GasReservation {
+      reservation_id,
+      sponsor_address: self.sponsor_address,
+      gas_coins,
+    })
+  }
+
+  async fn sponsor_transaction(
+    &self,
+    reservation_id: ReservationId,
+    tx_data: &TransactionData,
+  ) -> Result<Signature, Error> {
+    {
+      let mut reservations = self.reservations.lock().expect("not poisoned");
+      prune_expired(&mut reservations);
+      let reservation = reservations
+        .get(&reservation_id)
+        .ok_or_else(|| Error::GasIssue(format!("unknown gas reservation {reservation_id}")))?;
+      let gas_data = tx_data.gas_data();
+      let uses_reserved_coins = gas_data.payment.len() == reservation.gas_coins.len()
+        && gas_data
+          .payment
+          .iter()
+          .zip(&reservation.gas_coins)
+          .all(|(coin, reserved)| *coin == reserved.to_object_ref());
+      if !uses_reserved_coins || gas_data.owner != self.sponsor_address {
+        return Err(Error::GasIssue(format!(
+          "transaction's gas data doesn't match reservation {reservation_id}"
+        )));
+      }
+      if gas_data.budget > reservation.gas_budget {
+        return Err(Error::GasIssue(format!(
+          "transaction's gas budget {} exceeds the reserved budget {}",
+          gas_data.budget, reservation.gas_budget
+        )));
+      }
+    }
+
+    self
+      .signer
+      .sign(tx_data)
+      .await
+      .map_err(|e| Error::GasIssue(format!("failed to sponsor transaction: {e}")))
+  }
+
+  async fn release_reservation(&self, reservation_id: ReservationId) -> Result<(), Error> {
+    let mut reservations = self.reservations.lock().expect("not poisoned");
+    prune_expired(&mut reservations);
+    reservations
+      .remove(&reservation_id)
+      .map(|_| ())
+      .ok_or_else(|| Error::GasIssue(format!("unknown gas reservation {reservation_id}")))
+  }
+}
@@ -0,0 +1,84 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Integration with gas stations, i.e. services that sponsor transactions by
+//! providing the gas coins - and the gas owner's signature - required to execute them.
+
+#[cfg(all(feature = "gas-station", not(target_arch = "wasm32")))]
+mod client;
+mod local;
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use identity_iota_interaction::rpc_types::IotaObjectRef;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::base_types::ObjectRef;
+use identity_iota_interaction::types::crypto::Signature;
+use identity_iota_interaction::types::transaction::TransactionData;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[cfg(all(feature = "gas-station", not(target_arch = "wasm32")))]
+pub use client::*;
+pub use local::*;
+
+use super::Error;
+
+/// The default amount of time a gas reservation is held for.
+pub const DEFAULT_RESERVATION_DURATION: Duration = Duration::from_secs(60);
+
+/// The gas budget used for sponsored transactions that don't specify one.
+///
+/// Such transactions are dry run within this budget and only their estimated budget is reserved from the gas station.
+pub const DEFAULT_SPONSORED_GAS_BUDGET: u64 = 50_000_000;
+
+/// The identifier of a [GasReservation].
+pub type ReservationId = u64;
+
+/// A set of gas coins that a [GasStation] has set aside to pay for a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasReservation {
+  /// The identifier of this reservation.
+  pub reservation_id: ReservationId,
+  /// The address owning the reserved coins, i.e. the transaction's sponsor.
+  pub sponsor_address: IotaAddress,
+  /// The reserved gas coins.
+  pub gas_coins: Vec<IotaObjectRef>,
+}
+
+impl GasReservation {
+  /// Returns the reserved gas coins as [ObjectRef]s.
+  pub fn gas_payment(&self) -> Vec<ObjectRef> {
+    self.gas_coins.iter().map(IotaObjectRef::to_object_ref).collect()
+  }
+}
+
+/// A service that sponsors transactions.
+///
+/// Sponsoring a transaction happens in three steps:
+/// 1. gas coins able to cover the transaction's budget are reserved through [GasStation::reserve_gas];
+/// 2. the transaction, using the reserved coins as gas payment, is signed by the sponsor through
+///    [GasStation::sponsor_transaction];
+/// 3. once the transaction has been executed - or abandoned - the reservation is released through
+///    [GasStation::release_reservation], making the coins available to other transactions.
+///
+/// [TransactionBuilder::build_and_execute_with_gas_station](crate::rebased::transaction_builder::TransactionBuilder::build_and_execute_with_gas_station)
+/// takes care of the whole process.
+#[cfg_attr(feature = "send-sync", async_trait)]
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+pub trait GasStation {
+  /// Reserves gas coins with a total balance of at least `gas_budget` for `reserve_duration`.
+  async fn reserve_gas(&self, gas_budget: u64, reserve_duration: Duration) -> Result<GasReservation, Error>;
+
+  /// Requests the sponsor's signature for `tx_data`, whose gas payment must match the coins
+  /// reserved through reservation `reservation_id`.
+  async fn sponsor_transaction(
+    &self,
+    reservation_id: ReservationId,
+    tx_data: &TransactionData,
+  ) -> Result<Signature, Error>;
+
+  /// Releases the coins reserved through reservation `reservation_id`.
+  async fn release_reservation(&self, reservation_id: ReservationId) -> Result<(), Error>;
+}
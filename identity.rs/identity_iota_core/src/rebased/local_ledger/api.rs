@@ -0,0 +1,374 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of the IOTA client APIs used by identity on top of a [`LocalLedger`].
+
+use std::str::FromStr;
+
+use identity_iota_interaction::error::Error as IotaRpcError;
+use identity_iota_interaction::error::IotaRpcResult;
+use identity_iota_interaction::move_types::language_storage::StructTag;
+use identity_iota_interaction::rpc_types::Coin;
+use identity_iota_interaction::rpc_types::CoinPage;
+use identity_iota_interaction::rpc_types::DryRunTransactionBlockResponse;
+use identity_iota_interaction::rpc_types::EventFilter;
+use identity_iota_interaction::rpc_types::EventPage;
+use identity_iota_interaction::rpc_types::Filter as _;
+use identity_iota_interaction::rpc_types::IotaObjectDataFilter;
+use identity_iota_interaction::rpc_types::IotaObjectDataOptions;
+use identity_iota_interaction::rpc_types::IotaObjectRef;
+use identity_iota_interaction::rpc_types::IotaObjectResponse;
+use identity_iota_interaction::rpc_types::IotaObjectResponseQuery;
+use identity_iota_interaction::rpc_types::IotaPastObjectResponse;
+use identity_iota_interaction::rpc_types::IotaTransactionBlockResponse;
+use identity_iota_interaction::rpc_types::IotaTransactionBlockResponseOptions;
+use identity_iota_interaction::rpc_types::ObjectsPage;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::base_types::SequenceNumber;
+use identity_iota_interaction::types::crypto::Signature;
+use identity_iota_interaction::types::digests::TransactionDigest;
+use identity_iota_interaction::types::dynamic_field::DynamicFieldName;
+use identity_iota_interaction::types::error::IotaObjectResponseError;
+use identity_iota_interaction::types::event::EventID;
+use identity_iota_interaction::types::object::Owner;
+use identity_iota_interaction::types::quorum_driver_types::ExecuteTransactionRequestType;
+use identity_iota_interaction::types::transaction::TransactionData;
+use identity_iota_interaction::types::transaction::TransactionDataAPI as _;
+use identity_iota_interaction::types::TypeTag;
+use identity_iota_interaction::types::IOTA_FRAMEWORK_PACKAGE_ID;
+use identity_iota_interaction::CoinReadTrait;
+use identity_iota_interaction::EventTrait;
+use identity_iota_interaction::QuorumDriverTrait;
+use identity_iota_interaction::ReadTrait;
+use serde_json::json;
+
+use super::executor;
+use super::state::StoredObject;
+use super::state::CHAIN_IDENTIFIER;
+use super::values;
+use super::LocalLedger;
+use crate::iota_interaction_rust::iota_client_rust_sdk::IotaTransactionBlockResponseProvider;
+use crate::iota_interaction_rust::IotaTransactionBlockResponseAdaptedTraitObj;
+use crate::rebased::Error;
+
+/// Maximum number of items returned by a single paginated query, same as IOTA nodes.
+const MAX_PAGE_SIZE: usize = 50;
+
+/// Removes from `response` the data that wasn't requested through `options`.
+fn apply_options(
+  response: IotaTransactionBlockResponse,
+  options: &IotaTransactionBlockResponseOptions,
+) -> IotaTransactionBlockResponse {
+  IotaTransactionBlockResponse {
+    effects: response.effects.filter(|_| options.show_effects),
+    events: response.events.filter(|_| options.show_events),
+    object_changes: response.object_changes.filter(|_| options.show_object_changes),
+    balance_changes: response.balance_changes.filter(|_| options.show_balance_changes),
+    ..response
+  }
+}
+
+/// Returns a page of at most `limit` items, the first one being the one following `cursor`.
+fn paginate<T, C: PartialEq>(
+  items: impl IntoIterator<Item = T>,
+  cursor: Option<C>,
+  limit: Option<usize>,
+  item_cursor: impl Fn(&T) -> C,
+) -> (Vec<T>, Option<C>, bool) {
+  let limit = limit.unwrap_or(MAX_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+  let mut items = items.into_iter().peekable();
+  if let Some(cursor) = cursor {
+    for item in items.by_ref() {
+      if item_cursor(&item) == cursor {
+        break;
+      }
+    }
+  }
+  let page: Vec<T> = items.by_ref().take(limit).collect();
+  let has_next_page = items.peek().is_some();
+  let next_cursor = page.last().map(item_cursor);
+
+  (page, next_cursor, has_next_page)
+}
+
+fn matches_type(filter: &StructTag, type_: &StructTag) -> bool {
+  filter.address == type_.address
+    && filter.module == type_.module
+    && filter.name == type_.name
+    && (filter.type_params.is_empty() || filter.type_params == type_.type_params)
+}
+
+fn matches_filter(filter: &IotaObjectDataFilter, object: &StoredObject) -> bool {
+  match filter {
+    IotaObjectDataFilter::MatchAll(filters) => filters.iter().all(|filter| matches_filter(filter, object)),
+    IotaObjectDataFilter::MatchAny(filters) => filters.iter().any(|filter| matches_filter(filter, object)),
+    IotaObjectDataFilter::MatchNone(filters) => !filters.iter().any(|filter| matches_filter(filter, object)),
+    IotaObjectDataFilter::Package(package) => ObjectID::from(object.type_.address) == *package,
+    IotaObjectDataFilter::MoveModule { package, module } => {
+      ObjectID::from(object.type_.address) == *package && object.type_.module == *module
+    }
+    IotaObjectDataFilter::StructType(type_) => matches_type(type_, &object.type_),
+    IotaObjectDataFilter::AddressOwner(address) => object.owner == Owner::AddressOwner(*address),
+    IotaObjectDataFilter::ObjectOwner(parent) => object.owner == Owner::ObjectOwner((*parent).into()),
+    IotaObjectDataFilter::ObjectId(id) => object.id == *id,
+    IotaObjectDataFilter::ObjectIds(ids) => ids.contains(&object.id),
+    IotaObjectDataFilter::Version(version) => object.version.value() == *version,
+  }
+}
+
+#[async_trait::async_trait()]
+impl QuorumDriverTrait for LocalLedger {
+  type Error = Error;
+  type NativeResponse = IotaTransactionBlockResponse;
+
+  async fn execute_transaction_block(
+    &self,
+    tx_data: TransactionData,
+    signatures: Vec<Signature>,
+    options: Option<IotaTransactionBlockResponseOptions>,
+    _request_type: Option<ExecuteTransactionRequestType>,
+  ) -> IotaRpcResult<IotaTransactionBlockResponseAdaptedTraitObj> {
+    let response = executor::execute(&mut self.state(), &tx_data, Some(&signatures)).map_err(IotaRpcError::Data)?;
+    let response = apply_options(response, &options.unwrap_or_default());
+
+    Ok(Box::new(IotaTransactionBlockResponseProvider::new(response)))
+  }
+}
+
+#[async_trait::async_trait()]
+impl ReadTrait for LocalLedger {
+  type Error = Error;
+  type NativeResponse = IotaTransactionBlockResponse;
+
+  async fn get_chain_identifier(&self) -> Result<String, Self::Error> {
+    Ok(CHAIN_IDENTIFIER.to_owned())
+  }
+
+  async fn dry_run_transaction_block(&self, tx_data: TransactionData) -> IotaRpcResult<DryRunTransactionBlockResponse> {
+    // Dry runs are executed on a copy of the ledger, leaving it untouched.
+    let mut state = self.state().clone();
+    let response = executor::execute(&mut state, &tx_data, None).map_err(IotaRpcError::Data)?;
+    let gas_payment: Vec<IotaObjectRef> = tx_data.gas().iter().copied().map(IotaObjectRef::from).collect();
+    // The transaction's inputs and commands are not reported.
+    let input = json!({
+      "messageVersion": "v1",
+      "transaction": { "kind": "ProgrammableTransaction", "inputs": [], "transactions": [] },
+      "sender": tx_data.sender(),
+      "gasData": {
+        "payment": gas_payment,
+        "owner": tx_data.gas_owner(),
+        "price": tx_data.gas_price().to_string(),
+        "budget": tx_data.gas_budget().to_string(),
+      },
+    });
+
+    serde_json::from_value(json!({
+      "effects": response.effects,
+      "events": response.events.unwrap_or_default(),
+      "objectChanges": response.object_changes.unwrap_or_default(),
+      "balanceChanges": response.balance_changes.unwrap_or_default(),
+      "input": input,
+    }))
+    .map_err(|e| IotaRpcError::Data(format!("failed to build dry run response: {e}")))
+  }
+
+  async fn get_dynamic_field_object(
+    &self,
+    parent_object_id: ObjectID,
+    name: DynamicFieldName,
+  ) -> IotaRpcResult<IotaObjectResponse> {
+    let state = self.state();
+    let field_type = values::struct_tag(IOTA_FRAMEWORK_PACKAGE_ID, "dynamic_field", "Field", vec![]);
+    let parent = Owner::ObjectOwner(parent_object_id.into());
+    let field = state.objects.values().find(|object| {
+      let field_name = &object.contents["name"];
+      object.owner == parent
+        && matches_type(&field_type, &object.type_)
+        && (field_name == &name.value || field_name.get("name") == Some(&name.value))
+    });
+    let Some(field) = field else {
+      return Ok(IotaObjectResponse::new_with_error(
+        IotaObjectResponseError::DynamicFieldNotFound { parent_object_id },
+      ));
+    };
+    // Dynamic object fields hold the ID of the child object, which is what gets returned.
+    let is_object_field = field.contents["name"].get("name").is_some();
+    let object = if is_object_field {
+      values::as_id(&field.contents["value"]).and_then(|child| state.objects.get(&child))
+    } else {
+      Some(field)
+    };
+
+    Ok(match object {
+      Some(object) => IotaObjectResponse::new_with_data(object.to_object_data(&IotaObjectDataOptions::full_content())),
+      None => IotaObjectResponse::new_with_error(IotaObjectResponseError::DynamicFieldNotFound { parent_object_id }),
+    })
+  }
+
+  async fn get_object_with_options(
+    &self,
+    object_id: ObjectID,
+    options: IotaObjectDataOptions,
+  ) -> IotaRpcResult<IotaObjectResponse> {
+    let state = self.state();
+    let response = if let Some(object) = state.objects.get(&object_id) {
+      IotaObjectResponse::new_with_data(object.to_object_data(&options))
+    } else if let Some((object_id, version, digest)) = state.removed.get(&object_id).copied() {
+      IotaObjectResponse::new_with_error(IotaObjectResponseError::Deleted {
+        object_id,
+        version,
+        digest,
+      })
+    } else {
+      IotaObjectResponse::new_with_error(IotaObjectResponseError::NotExists { object_id })
+    };
+
+    Ok(response)
+  }
+
+  async fn get_owned_objects(
+    &self,
+    address: IotaAddress,
+    query: Option<IotaObjectResponseQuery>,
+    cursor: Option<ObjectID>,
+    limit: Option<usize>,
+  ) -> IotaRpcResult<ObjectsPage> {
+    let IotaObjectResponseQuery { filter, options } = query.unwrap_or_default();
+    let options = options.unwrap_or_default();
+    let state = self.state();
+    let owned_objects = state.objects.values().filter(|object| {
+      object.owner == Owner::AddressOwner(address)
+        && filter.as_ref().map_or(true, |filter| matches_filter(filter, object))
+    });
+    let (objects, next_cursor, has_next_page) = paginate(owned_objects, cursor, limit, |object| object.id);
+
+    Ok(ObjectsPage {
+      data: objects
+        .into_iter()
+        .map(|object| IotaObjectResponse::new_with_data(object.to_object_data(&options)))
+        .collect(),
+      next_cursor,
+      has_next_page,
+    })
+  }
+
+  async fn get_reference_gas_price(&self) -> IotaRpcResult<u64> {
+    Ok(self.state().gas_price)
+  }
+
+  async fn get_transaction_with_options(
+    &self,
+    digest: TransactionDigest,
+    options: IotaTransactionBlockResponseOptions,
+  ) -> IotaRpcResult<IotaTransactionBlockResponseAdaptedTraitObj> {
+    let response = self
+      .state()
+      .transactions
+      .get(&digest)
+      .cloned()
+      .ok_or_else(|| IotaRpcError::Data(format!("could not find transaction {digest}")))?;
+    let response = apply_options(response, &options);
+
+    Ok(Box::new(IotaTransactionBlockResponseProvider::new(response)))
+  }
+
+  async fn try_get_parsed_past_object(
+    &self,
+    object_id: ObjectID,
+    version: SequenceNumber,
+    options: IotaObjectDataOptions,
+  ) -> IotaRpcResult<IotaPastObjectResponse> {
+    let state = self.state();
+    let Some(versions) = state.history.get(&object_id) else {
+      return Ok(IotaPastObjectResponse::ObjectNotExists(object_id));
+    };
+    if let Some(object) = versions.get(&version) {
+      return Ok(IotaPastObjectResponse::VersionFound(object.to_object_data(&options)));
+    }
+    let removed = state.removed.get(&object_id).copied();
+    if let Some(removed) = removed.filter(|removed| removed.1 == version) {
+      return Ok(IotaPastObjectResponse::ObjectDeleted(removed.into()));
+    }
+    let latest_version = removed
+      .map(|removed| removed.1)
+      .or_else(|| versions.keys().next_back().copied())
+      .expect("objects have at least one version");
+
+    Ok(if version > latest_version {
+      IotaPastObjectResponse::VersionTooHigh {
+        object_id,
+        asked_version: version,
+        latest_version,
+      }
+    } else {
+      IotaPastObjectResponse::VersionNotFound(object_id, version)
+    })
+  }
+}
+
+#[async_trait::async_trait()]
+impl CoinReadTrait for LocalLedger {
+  type Error = Error;
+
+  async fn get_coins(
+    &self,
+    owner: IotaAddress,
+    coin_type: Option<String>,
+    cursor: Option<ObjectID>,
+    limit: Option<usize>,
+  ) -> IotaRpcResult<CoinPage> {
+    let coin_type = coin_type.as_deref().unwrap_or("0x2::iota::IOTA");
+    let coin_type =
+      TypeTag::from_str(coin_type).map_err(|e| IotaRpcError::Data(format!("invalid coin type {coin_type}: {e}")))?;
+    let state = self.state();
+    let coins = state.objects.values().filter(|object| {
+      object.is_coin() && object.owner == Owner::AddressOwner(owner) && object.type_.type_params[0] == coin_type
+    });
+    let (coins, next_cursor, has_next_page) = paginate(coins, cursor, limit, |object| object.id);
+
+    Ok(CoinPage {
+      data: coins
+        .into_iter()
+        .map(|object| Coin {
+          coin_type: coin_type.to_canonical_string(true),
+          coin_object_id: object.id,
+          version: object.version,
+          digest: object.digest,
+          balance: object.coin_balance().expect("object is a coin"),
+          previous_transaction: object.previous_transaction,
+        })
+        .collect(),
+      next_cursor,
+      has_next_page,
+    })
+  }
+}
+
+#[async_trait::async_trait()]
+impl EventTrait for LocalLedger {
+  type Error = Error;
+
+  async fn query_events(
+    &self,
+    query: EventFilter,
+    cursor: Option<EventID>,
+    limit: Option<usize>,
+    descending_order: bool,
+  ) -> IotaRpcResult<EventPage> {
+    let state = self.state();
+    let events: Box<dyn Iterator<Item = _>> = if descending_order {
+      Box::new(state.events.iter().rev())
+    } else {
+      Box::new(state.events.iter())
+    };
+    let events = events.filter(|event| query.matches(event)).cloned();
+    let (events, next_cursor, has_next_page) = paginate(events, cursor, limit, |event| event.id);
+
+    Ok(EventPage {
+      data: events,
+      next_cursor,
+      has_next_page,
+    })
+  }
+}
@@ -0,0 +1,1236 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Execution of programmable transactions against a [`LedgerState`].
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+use identity_iota_interaction::move_types::language_storage::StructTag;
+use identity_iota_interaction::rpc_types::BalanceChange;
+use identity_iota_interaction::rpc_types::BcsEvent;
+use identity_iota_interaction::rpc_types::IotaEvent;
+use identity_iota_interaction::rpc_types::IotaExecutionStatus;
+use identity_iota_interaction::rpc_types::IotaObjectRef;
+use identity_iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use identity_iota_interaction::rpc_types::IotaTransactionBlockEffectsV1;
+use identity_iota_interaction::rpc_types::IotaTransactionBlockEvents;
+use identity_iota_interaction::rpc_types::IotaTransactionBlockResponse;
+use identity_iota_interaction::rpc_types::ObjectChange;
+use identity_iota_interaction::rpc_types::OwnedObjectRef;
+use identity_iota_interaction::shared_crypto::intent::Intent;
+use identity_iota_interaction::shared_crypto::intent::IntentMessage;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::base_types::ObjectRef;
+use identity_iota_interaction::types::base_types::SequenceNumber;
+use identity_iota_interaction::types::crypto::IotaSignature;
+use identity_iota_interaction::types::crypto::Signature;
+use identity_iota_interaction::types::digests::ObjectDigest;
+use identity_iota_interaction::types::digests::TransactionDigest;
+use identity_iota_interaction::types::digests::TransactionEventsDigest;
+use identity_iota_interaction::types::event::EventID;
+use identity_iota_interaction::types::gas::GasCostSummary;
+use identity_iota_interaction::types::object::Owner;
+use identity_iota_interaction::types::transaction::Argument;
+use identity_iota_interaction::types::transaction::CallArg;
+use identity_iota_interaction::types::transaction::Command;
+use identity_iota_interaction::types::transaction::ObjectArg;
+use identity_iota_interaction::types::transaction::ProgrammableMoveCall;
+use identity_iota_interaction::types::transaction::ProgrammableTransaction;
+use identity_iota_interaction::types::transaction::TransactionData;
+use identity_iota_interaction::types::transaction::TransactionDataAPI as _;
+use identity_iota_interaction::types::transaction::TransactionKind;
+use identity_iota_interaction::types::TypeTag;
+use identity_iota_interaction::types::IOTA_CLOCK_OBJECT_ID;
+use identity_iota_interaction::types::IOTA_FRAMEWORK_PACKAGE_ID;
+use identity_iota_interaction::types::MOVE_STDLIB_PACKAGE_ID;
+use identity_iota_interaction::types::STARDUST_PACKAGE_ID;
+use serde_json::Value;
+
+use super::framework;
+use super::identity_package;
+use super::state::gas_coin_type;
+use super::state::hash;
+use super::state::LedgerState;
+use super::state::StoredObject;
+use super::values;
+use super::values::EventField;
+
+/// Price of a unit of storage, in NANOs.
+const STORAGE_PRICE: u64 = 76;
+/// Computation units charged for every transaction.
+const BASE_COMPUTATION_UNITS: u64 = 1_000;
+/// Computation units charged for every command of a transaction.
+const COMMAND_COMPUTATION_UNITS: u64 = 50;
+
+/// A value living in a transaction's argument slots.
+#[derive(Debug, Clone)]
+pub(crate) enum RuntimeValue {
+  /// BCS bytes of a pure input.
+  Pure(Vec<u8>),
+  /// A non-object Move value.
+  Data(DataValue),
+  /// An object of the working set.
+  Object(ObjectID),
+  /// A `0x2::transfer::Receiving` input.
+  Receiving(ObjectRef),
+}
+
+/// A non-object Move value, e.g. a `DelegationToken` borrowed from its `ControllerCap` or a proposal's `Action`.
+#[derive(Debug, Clone)]
+pub(crate) struct DataValue {
+  pub(crate) type_: TypeTag,
+  pub(crate) json: Value,
+  /// Whether this value has the `copy` and `drop` abilities.
+  pub(crate) droppable: bool,
+}
+
+impl DataValue {
+  /// A value with `copy` and `drop`.
+  pub(crate) fn plain(type_: TypeTag, json: Value) -> Self {
+    Self {
+      type_,
+      json,
+      droppable: true,
+    }
+  }
+
+  /// A value that must be consumed before the end of the transaction.
+  pub(crate) fn linear(type_: TypeTag, json: Value) -> Self {
+    Self {
+      type_,
+      json,
+      droppable: false,
+    }
+  }
+}
+
+/// Reasons for a command to fail.
+#[derive(Debug)]
+pub(crate) enum Failure {
+  /// A Move abort.
+  Abort {
+    package: ObjectID,
+    module: &'static str,
+    function: &'static str,
+    code: u64,
+  },
+  /// Any other execution error, reported in the transaction's effects.
+  Error(String),
+  /// The transaction uses a feature the local ledger doesn't model. The transaction is rejected.
+  Unsupported(String),
+}
+
+impl fmt::Display for Failure {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Abort {
+        package,
+        module,
+        function,
+        code,
+      } => write!(
+        f,
+        "MoveAbort(MoveLocation {{ module: ModuleId {{ address: {}, name: Identifier(\"{module}\") }}, function_name: Some(\"{function}\") }}, {code})",
+        package.to_hex()
+      ),
+      Self::Error(error) | Self::Unsupported(error) => f.write_str(error),
+    }
+  }
+}
+
+pub(crate) type ExecResult<T> = Result<T, Failure>;
+
+/// Aborts the current command as `package::module::function` would, with abort code `code`.
+pub(crate) fn abort<T>(package: ObjectID, module: &'static str, function: &'static str, code: u64) -> ExecResult<T> {
+  Err(Failure::Abort {
+    package,
+    module,
+    function,
+    code,
+  })
+}
+
+pub(crate) fn invalid_argument<T>(arg: Argument, kind: impl fmt::Display) -> ExecResult<T> {
+  Err(Failure::Error(format!(
+    "CommandArgumentError {{ arg: {arg:?}, kind: {kind} }}"
+  )))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Origin {
+  /// Object passed as a transaction input or as gas payment.
+  Input { mutable: bool },
+  /// Object loaded during execution, e.g. a dynamic field or a received object.
+  Loaded,
+  /// Object created by this transaction.
+  Created,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Location {
+  /// The object is stored with its `owner`.
+  Stored,
+  /// The object has been taken by value and must be transferred, shared or deleted.
+  InHand,
+  Deleted,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+  object: StoredObject,
+  /// The object's state before this transaction, if it existed.
+  previous: Option<StoredObject>,
+  origin: Origin,
+  location: Location,
+  modified: bool,
+}
+
+struct PendingEvent {
+  type_: StructTag,
+  module: String,
+  json: Value,
+  bcs: Vec<u8>,
+}
+
+/// Execution context of a single transaction.
+pub(crate) struct Session<'s> {
+  state: &'s LedgerState,
+  pub(crate) package_id: ObjectID,
+  pub(crate) sender: IotaAddress,
+  pub(crate) digest: TransactionDigest,
+  pub(crate) epoch: u64,
+  ids_created: u64,
+  objects: BTreeMap<ObjectID, Entry>,
+  inputs: Vec<Option<RuntimeValue>>,
+  results: Vec<Vec<Option<RuntimeValue>>>,
+  gas: Option<RuntimeValue>,
+  gas_coin: Option<ObjectID>,
+  events: Vec<PendingEvent>,
+  module: String,
+}
+
+impl<'s> Session<'s> {
+  fn new(state: &'s LedgerState, sender: IotaAddress, digest: TransactionDigest) -> Self {
+    Self {
+      state,
+      package_id: state.package_id,
+      sender,
+      digest,
+      epoch: state.epoch,
+      ids_created: 0,
+      objects: BTreeMap::new(),
+      inputs: Vec::new(),
+      results: Vec::new(),
+      gas: None,
+      gas_coin: None,
+      events: Vec::new(),
+      module: String::new(),
+    }
+  }
+
+  fn load_input(&mut self, object: &StoredObject, mutable: bool) -> Result<(), String> {
+    if self.objects.contains_key(&object.id) {
+      return Err(format!(
+        "object {} is used more than once as transaction input",
+        object.id
+      ));
+    }
+    self.objects.insert(
+      object.id,
+      Entry {
+        object: object.clone(),
+        previous: Some(object.clone()),
+        origin: Origin::Input { mutable },
+        location: Location::Stored,
+        modified: false,
+      },
+    );
+
+    Ok(())
+  }
+
+  /// Loads the gas coins, merging them into the first one.
+  fn load_gas(&mut self, payment: &[ObjectRef]) -> Result<(), String> {
+    for gas_ref in payment {
+      let object = self.state.objects.get(&gas_ref.0).expect("gas has been validated");
+      self.load_input(object, true)?;
+    }
+    let Some((primary, others)) = payment.split_first() else {
+      return Ok(());
+    };
+    let mut balance = 0;
+    for gas_ref in others {
+      let entry = self.objects.get_mut(&gas_ref.0).expect("just loaded");
+      balance += entry.object.coin_balance().expect("gas is a coin");
+      entry.location = Location::Deleted;
+    }
+    let primary = self.objects.get_mut(&primary.0).expect("just loaded");
+    let primary_balance = primary.object.coin_balance().expect("gas is a coin");
+    primary.object.set_coin_balance(primary_balance + balance);
+    self.gas_coin = Some(primary.object.id);
+    self.gas = Some(RuntimeValue::Object(primary.object.id));
+
+    Ok(())
+  }
+
+  fn load_inputs(&mut self, inputs: &[CallArg]) -> Result<(), String> {
+    for input in inputs {
+      let value = match input {
+        CallArg::Pure(bytes) => RuntimeValue::Pure(bytes.clone()),
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(object_ref)) => {
+          let object = self.state.live_object(*object_ref)?;
+          let mutable = match object.owner {
+            Owner::AddressOwner(owner) if owner == self.sender => true,
+            Owner::Immutable => false,
+            _ => {
+              return Err(format!(
+                "object {} is owned by {:?} and cannot be used by {}",
+                object.id, object.owner, self.sender
+              ))
+            }
+          };
+          self.load_input(object, mutable)?;
+          RuntimeValue::Object(object.id)
+        }
+        CallArg::Object(ObjectArg::SharedObject {
+          id,
+          initial_shared_version,
+          mutable,
+        }) => {
+          let object = self
+            .state
+            .objects
+            .get(id)
+            .ok_or_else(|| format!("shared object {id} doesn't exist"))?;
+          if !matches!(object.owner, Owner::Shared { initial_shared_version: v } if v == *initial_shared_version) {
+            return Err(format!(
+              "object {id} is not a shared object with initial shared version {initial_shared_version}"
+            ));
+          }
+          self.load_input(object, *mutable)?;
+          RuntimeValue::Object(*id)
+        }
+        CallArg::Object(ObjectArg::Receiving(object_ref)) => {
+          self.state.live_object(*object_ref)?;
+          RuntimeValue::Receiving(*object_ref)
+        }
+      };
+      self.inputs.push(Some(value));
+    }
+
+    Ok(())
+  }
+
+  fn run(&mut self, pt: &ProgrammableTransaction) -> Result<(), (usize, Failure)> {
+    for (i, command) in pt.commands.iter().enumerate() {
+      let results = self.run_command(command).map_err(|failure| (i, failure))?;
+      self.results.push(results.into_iter().map(Some).collect());
+    }
+    self
+      .check_unused_values()
+      .map_err(|failure| (pt.commands.len(), failure))
+  }
+
+  fn run_command(&mut self, command: &Command) -> ExecResult<Vec<RuntimeValue>> {
+    match command {
+      Command::MoveCall(call) => self.move_call(call),
+      Command::TransferObjects(objects, recipient) => framework::transfer_objects(self, objects, *recipient),
+      Command::SplitCoins(coin, amounts) => framework::split_coins(self, *coin, amounts),
+      Command::MergeCoins(coin, coins) => framework::merge_coins(self, *coin, coins),
+      Command::MakeMoveVec(type_, elements) => framework::make_move_vec(self, type_.as_ref(), elements),
+      Command::Publish(..) | Command::Upgrade(..) => Err(Failure::Unsupported(
+        "publishing or upgrading packages is not supported by the local ledger".to_owned(),
+      )),
+    }
+  }
+
+  fn move_call(&mut self, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+    self.module = call.module.to_string();
+    if call.package == MOVE_STDLIB_PACKAGE_ID || call.package == IOTA_FRAMEWORK_PACKAGE_ID {
+      framework::call(self, call)
+    } else if call.package == self.package_id {
+      identity_package::call(self, call)
+    } else {
+      Err(Failure::Unsupported(format!(
+        "package {} is not available on the local ledger",
+        call.package
+      )))
+    }
+  }
+
+  fn check_unused_values(&self) -> ExecResult<()> {
+    for (i, results) in self.results.iter().enumerate() {
+      for (j, value) in results.iter().enumerate() {
+        let unused = match value {
+          Some(RuntimeValue::Data(data)) => !data.droppable,
+          Some(RuntimeValue::Object(id)) => self.objects[id].location == Location::InHand,
+          _ => false,
+        };
+        if unused {
+          return Err(Failure::Error(format!(
+            "UnusedValueWithoutDrop {{ result_idx: {i}, secondary_idx: {j} }}"
+          )));
+        }
+      }
+    }
+    if let Some(entry) = self.objects.values().find(|entry| entry.location == Location::InHand) {
+      return Err(Failure::Error(format!(
+        "object {} has been taken by value but never transferred, shared or deleted",
+        entry.object.id
+      )));
+    }
+
+    Ok(())
+  }
+
+  fn slot(&mut self, arg: Argument) -> ExecResult<&mut Option<RuntimeValue>> {
+    let slot = match arg {
+      Argument::GasCoin => Some(&mut self.gas),
+      Argument::Input(i) => self.inputs.get_mut(i as usize),
+      Argument::Result(i) => self
+        .results
+        .get_mut(i as usize)
+        .filter(|results| results.len() == 1)
+        .and_then(|results| results.first_mut()),
+      Argument::NestedResult(i, j) => self
+        .results
+        .get_mut(i as usize)
+        .and_then(|results| results.get_mut(j as usize)),
+    };
+    match slot {
+      Some(slot) => Ok(slot),
+      None => invalid_argument(arg, "IndexOutOfBounds"),
+    }
+  }
+
+  fn take(&mut self, arg: Argument) -> ExecResult<RuntimeValue> {
+    let slot = self.slot(arg)?;
+    match slot {
+      None => invalid_argument(arg, "InvalidValueUsage"),
+      Some(RuntimeValue::Pure(_)) => Ok(slot.clone().expect("not empty")),
+      Some(RuntimeValue::Data(data)) if data.droppable => Ok(slot.clone().expect("not empty")),
+      Some(_) => Ok(slot.take().expect("not empty")),
+    }
+  }
+
+  /// Takes a non-object argument of type `type_` by value, returning its JSON representation.
+  pub(crate) fn take_value(&mut self, arg: Argument, type_: &TypeTag) -> ExecResult<Value> {
+    match self.take(arg)? {
+      RuntimeValue::Pure(bytes) => match values::pure_to_json(type_, &bytes) {
+        Ok(json) => Ok(json),
+        Err(e) => invalid_argument(arg, format!("InvalidBCSBytes: {e}")),
+      },
+      RuntimeValue::Data(data) if &data.type_ == type_ => Ok(data.json),
+      _ => invalid_argument(arg, format!("TypeMismatch, expected {type_}")),
+    }
+  }
+
+  /// Takes a non-object value of type `type_`.
+  pub(crate) fn take_data(&mut self, arg: Argument, type_: &TypeTag) -> ExecResult<DataValue> {
+    match self.take(arg)? {
+      RuntimeValue::Data(data) if &data.type_ == type_ => Ok(data),
+      _ => invalid_argument(arg, format!("TypeMismatch, expected {type_}")),
+    }
+  }
+
+  /// Mutably borrows a non-object value of type `type_`.
+  pub(crate) fn data_mut(&mut self, arg: Argument, type_: &TypeTag) -> ExecResult<&mut DataValue> {
+    match self.slot(arg)? {
+      Some(RuntimeValue::Data(data)) if &data.type_ == type_ => Ok(data),
+      _ => invalid_argument(arg, format!("TypeMismatch, expected {type_}")),
+    }
+  }
+
+  /// Takes a `Receiving` argument.
+  pub(crate) fn take_receiving(&mut self, arg: Argument) -> ExecResult<ObjectRef> {
+    match self.take(arg)? {
+      RuntimeValue::Receiving(object_ref) => Ok(object_ref),
+      _ => invalid_argument(arg, "TypeMismatch, expected a Receiving argument"),
+    }
+  }
+
+  fn check_object_type(&self, arg: Argument, id: ObjectID, type_: &StructTag) -> ExecResult<()> {
+    if &self.objects[&id].object.type_ == type_ {
+      Ok(())
+    } else {
+      invalid_argument(arg, format!("TypeMismatch, expected {type_}"))
+    }
+  }
+
+  fn object_id(&mut self, arg: Argument) -> ExecResult<ObjectID> {
+    match self.slot(arg)? {
+      Some(RuntimeValue::Object(id)) => Ok(*id),
+      _ => invalid_argument(arg, "TypeMismatch, expected an object"),
+    }
+  }
+
+  /// Returns the ID of an object argument of type `type_` that is used by reference.
+  pub(crate) fn object_arg(&mut self, arg: Argument, type_: &StructTag) -> ExecResult<ObjectID> {
+    let id = self.object_id(arg)?;
+    self.check_object_type(arg, id, type_)?;
+
+    Ok(id)
+  }
+
+  /// Returns the ID of an object argument of any type that is used by mutable reference.
+  pub(crate) fn any_object_arg_mut(&mut self, arg: Argument) -> ExecResult<ObjectID> {
+    let id = self.object_id(arg)?;
+    if matches!(self.objects[&id].origin, Origin::Input { mutable: false }) {
+      return invalid_argument(arg, "InvalidObjectByMutRef");
+    }
+
+    Ok(id)
+  }
+
+  /// Returns the ID of an object argument of type `type_` that is used by mutable reference.
+  pub(crate) fn object_arg_mut(&mut self, arg: Argument, type_: &StructTag) -> ExecResult<ObjectID> {
+    let id = self.any_object_arg_mut(arg)?;
+    self.check_object_type(arg, id, type_)?;
+
+    Ok(id)
+  }
+
+  /// Takes an object argument of type `type_` by value.
+  pub(crate) fn take_object(&mut self, arg: Argument, type_: Option<&StructTag>) -> ExecResult<ObjectID> {
+    let id = self.object_id(arg)?;
+    if let Some(type_) = type_ {
+      self.check_object_type(arg, id, type_)?;
+    }
+    let entry = self
+      .objects
+      .get_mut(&id)
+      .expect("object arguments are in the working set");
+    if matches!(entry.origin, Origin::Input { mutable: false }) {
+      return invalid_argument(arg, "InvalidObjectByValue");
+    }
+    *self.slot(arg)? = None;
+    let entry = self
+      .objects
+      .get_mut(&id)
+      .expect("object arguments are in the working set");
+    entry.location = Location::InHand;
+    entry.modified = true;
+
+    Ok(id)
+  }
+
+  pub(crate) fn object(&self, id: ObjectID) -> &StoredObject {
+    &self.objects[&id].object
+  }
+
+  /// Mutably borrows an object of the working set.
+  /// Callers must make sure the object is mutable, e.g. through [`Session::object_arg_mut`].
+  pub(crate) fn object_mut(&mut self, id: ObjectID) -> &mut StoredObject {
+    let entry = self.objects.get_mut(&id).expect("object is in the working set");
+    entry.modified = true;
+    &mut entry.object
+  }
+
+  /// Derives a fresh object ID, as `object::new` would.
+  pub(crate) fn fresh_id(&mut self) -> ObjectID {
+    let id = ObjectID::derive_id(self.digest, self.ids_created);
+    self.ids_created += 1;
+    id
+  }
+
+  /// Creates a new object with ID `id`. The object must be transferred, shared or deleted.
+  pub(crate) fn new_object(&mut self, id: ObjectID, type_: StructTag, contents: Value, has_public_transfer: bool) {
+    let object = StoredObject {
+      id,
+      version: SequenceNumber::new(),
+      digest: ObjectDigest::MIN,
+      type_,
+      owner: Owner::AddressOwner(self.sender),
+      previous_transaction: self.digest,
+      storage_rebate: 0,
+      has_public_transfer,
+      contents,
+    };
+    self.objects.insert(
+      id,
+      Entry {
+        object,
+        previous: None,
+        origin: Origin::Created,
+        location: Location::InHand,
+        modified: true,
+      },
+    );
+  }
+
+  fn set_owner(&mut self, id: ObjectID, owner: Owner) {
+    let entry = self.objects.get_mut(&id).expect("object is in the working set");
+    entry.object.owner = owner;
+    entry.location = Location::Stored;
+    entry.modified = true;
+  }
+
+  /// `transfer::transfer`.
+  pub(crate) fn transfer(&mut self, id: ObjectID, recipient: IotaAddress) {
+    self.set_owner(id, Owner::AddressOwner(recipient));
+  }
+
+  /// `transfer::share_object`. Only objects created by this transaction can be shared.
+  pub(crate) fn share(&mut self, id: ObjectID) {
+    // The initial shared version is set to the transaction's lamport version when effects are computed.
+    self.set_owner(
+      id,
+      Owner::Shared {
+        initial_shared_version: SequenceNumber::new(),
+      },
+    );
+  }
+
+  /// `object::delete`.
+  pub(crate) fn delete(&mut self, id: ObjectID) {
+    let entry = self.objects.get_mut(&id).expect("object is in the working set");
+    entry.location = Location::Deleted;
+    entry.modified = true;
+  }
+
+  /// `transfer::receive`: takes an object owned by `parent` out of the ledger.
+  pub(crate) fn receive(&mut self, parent: ObjectID, receiving: ObjectRef) -> ExecResult<ObjectID> {
+    let parent_address = IotaAddress::from(parent);
+    let object = self
+      .state
+      .objects
+      .get(&receiving.0)
+      .filter(|object| object.object_ref() == receiving && object.owner == Owner::AddressOwner(parent_address));
+    let Some(object) = object.filter(|_| !self.objects.contains_key(&receiving.0)) else {
+      return abort(IOTA_FRAMEWORK_PACKAGE_ID, "transfer", "receive_impl", 3);
+    };
+    self.objects.insert(
+      object.id,
+      Entry {
+        object: object.clone(),
+        previous: Some(object.clone()),
+        origin: Origin::Loaded,
+        location: Location::InHand,
+        modified: true,
+      },
+    );
+
+    Ok(object.id)
+  }
+
+  /// Loads `id` if it's a child object of `parent`.
+  fn load_child(&mut self, parent: ObjectID, id: ObjectID) -> Option<ObjectID> {
+    let owner = Owner::ObjectOwner(parent.into());
+    if let Some(entry) = self.objects.get(&id) {
+      return (entry.location == Location::Stored && entry.object.owner == owner).then_some(id);
+    }
+    let object = self.state.objects.get(&id).filter(|object| object.owner == owner)?;
+    self.objects.insert(
+      id,
+      Entry {
+        object: object.clone(),
+        previous: Some(object.clone()),
+        origin: Origin::Loaded,
+        location: Location::Stored,
+        modified: false,
+      },
+    );
+
+    Some(id)
+  }
+
+  /// `object_bag::add`.
+  pub(crate) fn object_bag_add(&mut self, bag: ObjectID, key: ObjectID, child: ObjectID) -> ExecResult<()> {
+    let field_id = dynamic_object_field_id(bag, key.as_ref());
+    if self.load_child(bag, field_id).is_some() {
+      return abort(IOTA_FRAMEWORK_PACKAGE_ID, "dynamic_field", "add", 0);
+    }
+    let field_contents = serde_json::json!({
+      "id": values::uid(field_id),
+      "name": { "name": key },
+      "value": child,
+    });
+    self.new_object(field_id, dynamic_object_field_type(), field_contents, false);
+    self.set_owner(field_id, Owner::ObjectOwner(bag.into()));
+    self.set_owner(child, Owner::ObjectOwner(field_id.into()));
+
+    Ok(())
+  }
+
+  /// Loads the object stored under `key` in `bag`, if any.
+  pub(crate) fn object_bag_get(&mut self, bag: ObjectID, key: ObjectID) -> Option<ObjectID> {
+    self.dynamic_object_field_get(bag, key.as_ref())
+  }
+
+  /// `object_bag::remove`: returns the removed object, which must then be consumed.
+  pub(crate) fn object_bag_remove(&mut self, bag: ObjectID, key: ObjectID) -> ExecResult<ObjectID> {
+    self.dynamic_object_field_remove(bag, key.as_ref())
+  }
+
+  /// Loads the object stored in the dynamic object field of `parent` whose key is encoded as `key`, if any.
+  fn dynamic_object_field_get(&mut self, parent: ObjectID, key: &[u8]) -> Option<ObjectID> {
+    let field_id = self.load_child(parent, dynamic_object_field_id(parent, key))?;
+    let child = values::as_id(&self.objects[&field_id].object.contents["value"])?;
+    self.load_child(field_id, child)
+  }
+
+  /// `dynamic_object_field::remove`: returns the removed object, which must then be consumed.
+  pub(crate) fn dynamic_object_field_remove(&mut self, parent: ObjectID, key: &[u8]) -> ExecResult<ObjectID> {
+    let Some(child) = self.dynamic_object_field_get(parent, key) else {
+      return abort(IOTA_FRAMEWORK_PACKAGE_ID, "dynamic_field", "remove", 1);
+    };
+    self.delete(dynamic_object_field_id(parent, key));
+    let entry = self.objects.get_mut(&child).expect("just loaded");
+    entry.location = Location::InHand;
+    entry.modified = true;
+
+    Ok(child)
+  }
+
+  /// `dynamic_field::add`: adds the field `name` -> `value` to `parent`, `key` being the encoding of `name`.
+  pub(crate) fn dynamic_field_add(
+    &mut self,
+    parent: ObjectID,
+    key: &[u8],
+    field_type: StructTag,
+    name: Value,
+    value: Value,
+  ) -> ExecResult<()> {
+    let field_id = dynamic_field_id(parent, key);
+    if self.load_child(parent, field_id).is_some() {
+      return abort(IOTA_FRAMEWORK_PACKAGE_ID, "dynamic_field", "add", 0);
+    }
+    let field_contents = serde_json::json!({
+      "id": values::uid(field_id),
+      "name": name,
+      "value": value,
+    });
+    self.new_object(field_id, field_type, field_contents, false);
+    self.set_owner(field_id, Owner::ObjectOwner(parent.into()));
+
+    Ok(())
+  }
+
+  /// Emits the event `<package>::<module>::<name>`.
+  pub(crate) fn emit(&mut self, module: &str, name: &str, fields: Vec<(&str, EventField)>) {
+    let type_ = StructTag::from_str(&format!("{}::{module}::{name}", self.package_id)).expect("valid struct tag");
+    let (json, bcs) = values::encode_event(fields);
+    self.events.push(PendingEvent {
+      type_,
+      module: self.module.clone(),
+      json,
+      bcs,
+    });
+  }
+}
+
+fn dynamic_object_field_type() -> StructTag {
+  StructTag::from_str("0x2::dynamic_field::Field<0x2::dynamic_object_field::Wrapper<0x2::object::ID>, 0x2::object::ID>")
+    .expect("valid struct tag")
+}
+
+pub(crate) fn dynamic_object_field_id(parent: ObjectID, key: &[u8]) -> ObjectID {
+  ObjectID::new(hash(&[b"dynamic_object_field", parent.as_ref(), key]))
+}
+
+fn dynamic_field_id(parent: ObjectID, key: &[u8]) -> ObjectID {
+  ObjectID::new(hash(&[b"dynamic_field", parent.as_ref(), key]))
+}
+
+impl LedgerState {
+  /// Returns the live object referenced by `object_ref`.
+  pub(crate) fn live_object(&self, object_ref: ObjectRef) -> Result<&StoredObject, String> {
+    let (id, version, digest) = object_ref;
+    match self.objects.get(&id) {
+      Some(object) if object.version == version && object.digest == digest => Ok(object),
+      Some(object) => Err(format!(
+        "Object ID {id} Version {version} Digest {digest} is not available for consumption, current version: {}",
+        object.version
+      )),
+      None => Err(format!("object {id} doesn't exist")),
+    }
+  }
+}
+
+/// Executes `tx_data` against `state`.
+/// When `signatures` is `None` the transaction is dry-run: signatures aren't checked and a missing gas payment
+/// is replaced by a temporary coin. Dry-runs are meant to be executed on a copy of the ledger's state.
+pub(crate) fn execute(
+  state: &mut LedgerState,
+  tx_data: &TransactionData,
+  signatures: Option<&[Signature]>,
+) -> Result<IotaTransactionBlockResponse, String> {
+  let digest = tx_data.digest();
+  if let Some(response) = state.transactions.get(&digest) {
+    return Ok(response.clone());
+  }
+  let TransactionKind::ProgrammableTransaction(pt) = tx_data.kind() else {
+    return Err("only programmable transactions are supported by the local ledger".to_owned());
+  };
+  let sender = tx_data.sender();
+  let gas_owner = tx_data.gas_owner();
+  let gas_budget = tx_data.gas_budget();
+
+  if let Some(signatures) = signatures {
+    let intent_msg = IntentMessage::new(Intent::iota_transaction(), tx_data.clone());
+    for signer in BTreeSet::from([sender, gas_owner]) {
+      let is_signed = signatures
+        .iter()
+        .any(|signature| signature.verify_secure(&intent_msg, signer, signature.scheme()).is_ok());
+      if !is_signed {
+        return Err(format!("transaction is missing a valid signature for address {signer}"));
+      }
+    }
+  }
+  if tx_data.gas_price() < state.gas_price {
+    return Err(format!(
+      "gas price {} is lower than the reference gas price {}",
+      tx_data.gas_price(),
+      state.gas_price
+    ));
+  }
+
+  let mut payment = tx_data.gas().to_vec();
+  if payment.is_empty() {
+    if signatures.is_some() {
+      return Err("transaction has no gas payment".to_owned());
+    }
+    payment.push(mint_coin(state, gas_owner, gas_budget));
+  }
+  let mut balance = 0;
+  for gas_ref in &payment {
+    let coin = state.live_object(*gas_ref)?;
+    if !coin.is_gas_coin() || coin.owner != Owner::AddressOwner(gas_owner) {
+      return Err(format!("object {} is not a gas coin owned by {gas_owner}", coin.id));
+    }
+    balance += coin.coin_balance().unwrap_or_default();
+  }
+  if balance < gas_budget {
+    return Err(format!(
+      "balance of gas object {balance} is lower than the needed amount: {gas_budget}"
+    ));
+  }
+
+  // Every transaction moves the clock forward by a second.
+  state.timestamp_ms += 1_000;
+  if let Some(clock) = state.objects.get_mut(&IOTA_CLOCK_OBJECT_ID) {
+    clock.contents["timestamp_ms"] = values::u64_value(state.timestamp_ms);
+  }
+
+  let gas_price = state.gas_price;
+  let computation_cost = gas_price * (BASE_COMPUTATION_UNITS + COMMAND_COMPUTATION_UNITS * pt.commands.len() as u64);
+  let outcome = {
+    let mut session = Session::new(state, sender, digest);
+    session.load_inputs(&pt.inputs)?;
+    session.load_gas(&payment)?;
+    let status = match session.run(pt) {
+      Ok(()) => Ok(()),
+      Err((_, Failure::Unsupported(error))) => return Err(error),
+      Err((command, failure)) => Err(format!("{failure} in command {command}")),
+    };
+    match status.and_then(|()| session.finish(computation_cost, gas_budget)) {
+      Ok(outcome) => outcome,
+      Err(error) => {
+        // Discard every change, only charge for gas and bump the version of the transaction's inputs.
+        let mut session = Session::new(state, sender, digest);
+        session.load_inputs(&pt.inputs)?;
+        session.load_gas(&payment)?;
+        session.mark_inputs_modified();
+        let mut outcome = session
+          .finish(computation_cost.min(gas_budget), u64::MAX)
+          .expect("charging for computation only never exceeds the budget");
+        outcome.status = IotaExecutionStatus::Failure { error };
+        outcome.events.clear();
+        outcome
+      }
+    }
+  };
+
+  Ok(outcome.commit(state, digest, signatures.is_some()))
+}
+
+/// Mints a new gas coin with `balance` NANOs for `owner`.
+pub(crate) fn mint_coin(state: &mut LedgerState, owner: IotaAddress, balance: u64) -> ObjectRef {
+  let digest = state.next_system_digest();
+  let id = ObjectID::derive_id(digest, 0);
+  let contents = serde_json::json!({ "id": values::uid(id), "balance": values::u64_value(balance) });
+
+  write_system_object(
+    state,
+    digest,
+    id,
+    gas_coin_type(),
+    Owner::AddressOwner(owner),
+    contents,
+    true,
+  )
+}
+
+/// Adds a legacy Stardust `AliasOutput<IOTA>` owned by `owner` to `state`, its `Alias` holding `state_metadata`.
+/// Returns the ID of the `Alias`.
+pub(crate) fn add_legacy_alias(state: &mut LedgerState, owner: IotaAddress, state_metadata: Option<&[u8]>) -> ObjectID {
+  let digest = state.next_system_digest();
+  let alias_output_id = ObjectID::derive_id(digest, 0);
+  let native_tokens_id = ObjectID::derive_id(digest, 1);
+  let alias_id = ObjectID::derive_id(digest, 2);
+  let field_id = dynamic_object_field_id(alias_output_id, LEGACY_ALIAS_FIELD_NAME);
+
+  let alias_output_contents = serde_json::json!({
+    "id": values::uid(alias_output_id),
+    "balance": values::u64_value(0),
+    "native_tokens": { "id": values::uid(native_tokens_id), "size": values::u64_value(0) },
+  });
+  write_system_object(
+    state,
+    digest,
+    alias_output_id,
+    alias_output_tag(),
+    Owner::AddressOwner(owner),
+    alias_output_contents,
+    false,
+  );
+
+  let field_type =
+    StructTag::from_str("0x2::dynamic_field::Field<0x2::dynamic_object_field::Wrapper<vector<u8>>, 0x2::object::ID>")
+      .expect("valid struct tag");
+  let field_contents = serde_json::json!({
+    "id": values::uid(field_id),
+    "name": { "name": values::bytes_value(LEGACY_ALIAS_FIELD_NAME) },
+    "value": alias_id,
+  });
+  write_system_object(
+    state,
+    digest,
+    field_id,
+    field_type,
+    Owner::ObjectOwner(alias_output_id.into()),
+    field_contents,
+    false,
+  );
+
+  let alias_contents = serde_json::json!({
+    "id": values::uid(alias_id),
+    "legacy_state_controller": owner,
+    "state_index": 1,
+    "state_metadata": state_metadata.map(values::bytes_value),
+    "sender": null,
+    "immutable_issuer": null,
+    "immutable_metadata": null,
+  });
+  write_system_object(
+    state,
+    digest,
+    alias_id,
+    values::struct_tag(STARDUST_PACKAGE_ID, "alias", "Alias", vec![]),
+    Owner::ObjectOwner(field_id.into()),
+    alias_contents,
+    false,
+  );
+
+  alias_id
+}
+
+/// The name of the dynamic object field an `AliasOutput` stores its `Alias` in.
+pub(crate) const LEGACY_ALIAS_FIELD_NAME: &[u8] = b"alias";
+
+/// `stardust::alias_output::AliasOutput<0x2::iota::IOTA>`.
+pub(crate) fn alias_output_tag() -> StructTag {
+  values::struct_tag(STARDUST_PACKAGE_ID, "alias_output", "AliasOutput", vec![iota_type()])
+}
+
+fn iota_type() -> TypeTag {
+  values::struct_type(IOTA_FRAMEWORK_PACKAGE_ID, "iota", "IOTA", vec![])
+}
+
+/// Writes an object created outside of any user transaction, e.g. by a faucet request.
+fn write_system_object(
+  state: &mut LedgerState,
+  digest: TransactionDigest,
+  id: ObjectID,
+  type_: StructTag,
+  owner: Owner,
+  contents: Value,
+  has_public_transfer: bool,
+) -> ObjectRef {
+  let mut object = StoredObject {
+    id,
+    version: SequenceNumber::from_u64(1),
+    digest: ObjectDigest::MIN,
+    type_,
+    owner,
+    previous_transaction: digest,
+    storage_rebate: 0,
+    has_public_transfer,
+    contents,
+  };
+  object.storage_rebate = object.size() * STORAGE_PRICE;
+  object.refresh_digest();
+  let object_ref = object.object_ref();
+  state.write(object);
+
+  object_ref
+}
+
+/// Computed effects of a transaction, yet to be applied.
+struct Outcome {
+  status: IotaExecutionStatus,
+  lamport_version: SequenceNumber,
+  written: Vec<Entry>,
+  deleted: Vec<Entry>,
+  shared_inputs: Vec<ObjectRef>,
+  dependencies: Vec<TransactionDigest>,
+  gas_coin: ObjectID,
+  gas_used: GasCostSummary,
+  events: Vec<PendingEvent>,
+  sender: IotaAddress,
+  timestamp_ms: u64,
+}
+
+impl Session<'_> {
+  fn mark_inputs_modified(&mut self) {
+    for entry in self.objects.values_mut() {
+      if entry.origin == (Origin::Input { mutable: true }) {
+        entry.modified = true;
+      }
+    }
+  }
+
+  /// Computes the effects of the transaction and charges gas for it.
+  fn finish(self, computation_cost: u64, gas_budget: u64) -> Result<Outcome, String> {
+    let gas_coin = self.gas_coin.expect("gas has been loaded");
+    let lamport_version = self
+      .objects
+      .values()
+      .filter_map(|entry| entry.previous.as_ref())
+      .map(|object| object.version)
+      .max()
+      .unwrap_or_default()
+      .value()
+      + 1;
+    let lamport_version = SequenceNumber::from_u64(lamport_version);
+
+    let mut written = Vec::new();
+    let mut deleted = Vec::new();
+    let mut shared_inputs = Vec::new();
+    let mut dependencies = BTreeSet::new();
+    let mut storage_cost = 0;
+    let mut storage_rebate = 0;
+    for (_, mut entry) in self.objects {
+      if let Some(previous) = &entry.previous {
+        if matches!(previous.owner, Owner::Shared { .. }) {
+          shared_inputs.push(previous.object_ref());
+        }
+        if matches!(entry.origin, Origin::Input { .. }) {
+          dependencies.insert(previous.previous_transaction);
+        }
+      }
+      let is_written = match entry.origin {
+        Origin::Input { mutable } => mutable || entry.modified,
+        Origin::Loaded => entry.modified,
+        Origin::Created => true,
+      };
+      if !is_written {
+        continue;
+      }
+      let is_gas = entry.object.id == gas_coin;
+      if !is_gas {
+        storage_rebate += entry
+          .previous
+          .as_ref()
+          .map(|object| object.storage_rebate)
+          .unwrap_or_default();
+      }
+      match entry.location {
+        Location::Deleted if entry.previous.is_some() => deleted.push(entry),
+        Location::Deleted => (),
+        Location::InHand => unreachable!("unused values have been checked"),
+        Location::Stored => {
+          if let Owner::Shared { initial_shared_version } = &mut entry.object.owner {
+            if entry.origin == Origin::Created {
+              *initial_shared_version = lamport_version;
+            }
+          }
+          if !is_gas {
+            entry.object.storage_rebate = entry.object.size() * STORAGE_PRICE;
+            storage_cost += entry.object.storage_rebate;
+          }
+          written.push(entry);
+        }
+      }
+    }
+
+    if computation_cost + storage_cost > gas_budget {
+      return Err("InsufficientGas".to_owned());
+    }
+    let gas_entry = written
+      .iter_mut()
+      .find(|entry| entry.object.id == gas_coin)
+      .ok_or_else(|| "InvalidGasObject: the gas coin has been consumed".to_owned())?;
+    let balance = gas_entry.object.coin_balance().expect("gas is a coin") as i128;
+    let charge = computation_cost as i128 + storage_cost as i128 - storage_rebate as i128;
+    let new_balance = u64::try_from(balance - charge).map_err(|_| "InsufficientCoinBalance".to_owned())?;
+    gas_entry.object.set_coin_balance(new_balance);
+
+    Ok(Outcome {
+      status: IotaExecutionStatus::Success,
+      lamport_version,
+      written,
+      deleted,
+      shared_inputs,
+      dependencies: dependencies.into_iter().collect(),
+      gas_coin,
+      gas_used: GasCostSummary {
+        computation_cost,
+        computation_cost_burned: computation_cost,
+        storage_cost,
+        storage_rebate,
+        non_refundable_storage_fee: 0,
+      },
+      events: self.events,
+      sender: self.sender,
+      timestamp_ms: self.state.timestamp_ms,
+    })
+  }
+}
+
+impl Outcome {
+  /// Applies this outcome to `state`, returning the transaction's full response.
+  fn commit(self, state: &mut LedgerState, digest: TransactionDigest, record: bool) -> IotaTransactionBlockResponse {
+    let mut created = Vec::new();
+    let mut mutated = Vec::new();
+    let mut deleted = Vec::new();
+    let mut object_changes = Vec::new();
+    let mut balances = BTreeMap::<(String, TypeTag), (Owner, i128)>::new();
+    let mut gas_object = None;
+
+    let mut track_balance = |object: &StoredObject, sign: i128| {
+      if let (Some(balance), Owner::AddressOwner(_)) = (object.coin_balance(), object.owner) {
+        let coin_type = object.type_.type_params[0].clone();
+        let entry = balances
+          .entry((format!("{:?}", object.owner), coin_type))
+          .or_insert((object.owner, 0));
+        entry.1 += sign * balance as i128;
+      }
+    };
+
+    for entry in self.deleted {
+      let previous = entry.previous.expect("deleted objects existed before");
+      track_balance(&previous, -1);
+      let object_ref = (previous.id, self.lamport_version, ObjectDigest::OBJECT_DIGEST_DELETED);
+      deleted.push(IotaObjectRef::from(object_ref));
+      object_changes.push(ObjectChange::Deleted {
+        sender: self.sender,
+        object_type: previous.type_.clone(),
+        object_id: previous.id,
+        version: self.lamport_version,
+      });
+      state.remove(object_ref);
+    }
+    for entry in self.written {
+      let mut object = entry.object;
+      object.version = self.lamport_version;
+      object.previous_transaction = digest;
+      object.refresh_digest();
+      track_balance(&object, 1);
+      let owned_ref = OwnedObjectRef {
+        owner: object.owner,
+        reference: object.object_ref().into(),
+      };
+      match entry.previous {
+        Some(previous) => {
+          track_balance(&previous, -1);
+          object_changes.push(ObjectChange::Mutated {
+            sender: self.sender,
+            owner: object.owner,
+            object_type: object.type_.clone(),
+            object_id: object.id,
+            version: object.version,
+            previous_version: previous.version,
+            digest: object.digest,
+          });
+          if object.id == self.gas_coin {
+            gas_object = Some(owned_ref.clone());
+          }
+          mutated.push(owned_ref);
+        }
+        None => {
+          object_changes.push(ObjectChange::Created {
+            sender: self.sender,
+            owner: object.owner,
+            object_type: object.type_.clone(),
+            object_id: object.id,
+            version: object.version,
+            digest: object.digest,
+          });
+          created.push(owned_ref);
+        }
+      }
+      state.write(object);
+    }
+
+    let events: Vec<IotaEvent> = self
+      .events
+      .into_iter()
+      .enumerate()
+      .map(|(seq, event)| IotaEvent {
+        id: EventID {
+          tx_digest: digest,
+          event_seq: seq as u64,
+        },
+        package_id: ObjectID::from(event.type_.address),
+        transaction_module: event.module.parse().expect("valid identifier"),
+        sender: self.sender,
+        type_: event.type_,
+        parsed_json: event.json,
+        bcs: BcsEvent::new(event.bcs),
+        timestamp_ms: Some(self.timestamp_ms),
+      })
+      .collect();
+    let events_digest = (!events.is_empty()).then(|| {
+      let bytes: Vec<u8> = events.iter().flat_map(|event| event.bcs.bytes().to_vec()).collect();
+      TransactionEventsDigest::new(hash(&[digest.inner(), &bytes]))
+    });
+
+    let effects = IotaTransactionBlockEffects::V1(IotaTransactionBlockEffectsV1 {
+      status: self.status,
+      executed_epoch: state.epoch,
+      gas_used: self.gas_used,
+      modified_at_versions: vec![],
+      shared_objects: self.shared_inputs.into_iter().map(IotaObjectRef::from).collect(),
+      transaction_digest: digest,
+      created,
+      mutated,
+      unwrapped: vec![],
+      deleted,
+      unwrapped_then_deleted: vec![],
+      wrapped: vec![],
+      gas_object: gas_object.expect("gas coin is always mutated"),
+      events_digest,
+      dependencies: self.dependencies,
+    });
+    let balance_changes = balances
+      .into_iter()
+      .filter(|(_, (_, amount))| *amount != 0)
+      .map(|((_, coin_type), (owner, amount))| BalanceChange {
+        owner,
+        coin_type,
+        amount,
+      })
+      .collect();
+
+    let response = IotaTransactionBlockResponse {
+      effects: Some(effects),
+      events: Some(IotaTransactionBlockEvents { data: events.clone() }),
+      object_changes: Some(object_changes),
+      balance_changes: Some(balance_changes),
+      timestamp_ms: Some(self.timestamp_ms),
+      confirmed_local_execution: Some(true),
+      ..IotaTransactionBlockResponse::new(digest)
+    };
+    if record {
+      state.events.extend(events);
+      state.transactions.insert(digest, response.clone());
+    }
+
+    response
+  }
+}
@@ -0,0 +1,252 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! The subset of the Move standard library and of the IOTA framework used by identity transactions,
+//! together with the built-in PTB commands.
+
+use identity_iota_interaction::move_types::language_storage::StructTag;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::transaction::Argument;
+use identity_iota_interaction::types::transaction::ProgrammableMoveCall;
+use identity_iota_interaction::types::TypeTag;
+use identity_iota_interaction::types::IOTA_FRAMEWORK_PACKAGE_ID;
+use identity_iota_interaction::types::MOVE_STDLIB_PACKAGE_ID;
+use serde_json::json;
+use serde_json::Value;
+
+use super::executor::abort;
+use super::executor::invalid_argument;
+use super::executor::DataValue;
+use super::executor::ExecResult;
+use super::executor::Failure;
+use super::executor::RuntimeValue;
+use super::executor::Session;
+use super::values;
+
+pub(crate) fn option_type(inner: TypeTag) -> TypeTag {
+  values::struct_type(MOVE_STDLIB_PACKAGE_ID, "option", "Option", vec![inner])
+}
+
+pub(crate) fn id_type() -> TypeTag {
+  values::struct_type(IOTA_FRAMEWORK_PACKAGE_ID, "object", "ID", vec![])
+}
+
+pub(crate) fn vec_map_type(key: TypeTag, value: TypeTag) -> TypeTag {
+  values::struct_type(IOTA_FRAMEWORK_PACKAGE_ID, "vec_map", "VecMap", vec![key, value])
+}
+
+fn coin_type(inner: TypeTag) -> TypeTag {
+  values::struct_type(IOTA_FRAMEWORK_PACKAGE_ID, "coin", "Coin", vec![inner])
+}
+
+/// Returns the arguments of `call`, checking there are exactly `N` of them.
+pub(crate) fn arguments<const N: usize>(call: &ProgrammableMoveCall) -> ExecResult<[Argument; N]> {
+  call.arguments.as_slice().try_into().map_err(|_| {
+    Failure::Error(format!(
+      "ArityMismatch: {}::{} expects {N} arguments, {} were given",
+      call.module,
+      call.function,
+      call.arguments.len()
+    ))
+  })
+}
+
+/// Returns the type arguments of `call`, checking there are exactly `N` of them.
+pub(crate) fn type_arguments<const N: usize>(call: &ProgrammableMoveCall) -> ExecResult<[TypeTag; N]> {
+  <[TypeTag; N]>::try_from(call.type_arguments.clone()).map_err(|type_args| {
+    Failure::Error(format!(
+      "TypeArityMismatch: {}::{} expects {N} type arguments, {} were given",
+      call.module,
+      call.function,
+      type_args.len()
+    ))
+  })
+}
+
+/// Takes a `u64` argument.
+pub(crate) fn take_u64(session: &mut Session<'_>, arg: Argument) -> ExecResult<u64> {
+  let value = session.take_value(arg, &TypeTag::U64)?;
+  Ok(values::as_u64(&value).expect("a valid u64 value"))
+}
+
+/// Executes a call to a function of the standard library or of the IOTA framework.
+pub(super) fn call(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let is_stdlib = call.package == MOVE_STDLIB_PACKAGE_ID;
+  let value = match (is_stdlib, call.module.as_str(), call.function.as_str()) {
+    (true, "option", "some") => {
+      let [inner_type] = type_arguments(call)?;
+      let [inner] = arguments(call)?;
+      let inner = session.take_value(inner, &inner_type)?;
+      RuntimeValue::Data(DataValue::plain(option_type(inner_type), inner))
+    }
+    (true, "option", "none") => {
+      let [inner_type] = type_arguments(call)?;
+      let [] = arguments(call)?;
+      RuntimeValue::Data(DataValue::plain(option_type(inner_type), Value::Null))
+    }
+    (false, "vec_map", "empty") => {
+      let [key_type, value_type] = type_arguments(call)?;
+      let [] = arguments(call)?;
+      RuntimeValue::Data(DataValue::plain(
+        vec_map_type(key_type, value_type),
+        values::vec_map([]),
+      ))
+    }
+    (false, "clock", "timestamp_ms") => {
+      let [] = type_arguments(call)?;
+      let [clock] = arguments(call)?;
+      let clock_type = values::struct_tag(IOTA_FRAMEWORK_PACKAGE_ID, "clock", "Clock", vec![]);
+      let clock = session.object_arg(clock, &clock_type)?;
+      let timestamp_ms = session.object(clock).contents["timestamp_ms"].clone();
+      RuntimeValue::Data(DataValue::plain(TypeTag::U64, timestamp_ms))
+    }
+    (false, "object", "id_to_address") => {
+      let [] = type_arguments(call)?;
+      let [id] = arguments(call)?;
+      let id = session.take_value(id, &id_type())?;
+      RuntimeValue::Data(DataValue::plain(TypeTag::Address, id))
+    }
+    (false, "coin", "zero") => {
+      let [inner_type] = type_arguments(call)?;
+      let [] = arguments(call)?;
+      let TypeTag::Struct(coin_type) = coin_type(inner_type) else {
+        unreachable!("coins are structs");
+      };
+      let id = session.fresh_id();
+      let contents = json!({ "id": values::uid(id), "balance": values::u64_value(0) });
+      session.new_object(id, *coin_type, contents, true);
+      RuntimeValue::Object(id)
+    }
+    (false, "coin", "value") => {
+      let [inner_type] = type_arguments(call)?;
+      let [coin] = arguments(call)?;
+      let TypeTag::Struct(coin_type) = coin_type(inner_type) else {
+        unreachable!("coins are structs");
+      };
+      let coin = session.object_arg(coin, &coin_type)?;
+      let balance = session.object(coin).coin_balance().expect("object is a coin");
+      RuntimeValue::Data(DataValue::plain(TypeTag::U64, values::u64_value(balance)))
+    }
+    (false, "transfer", "public_transfer") => {
+      let [object_type] = type_arguments(call)?;
+      let [object, recipient] = arguments(call)?;
+      let TypeTag::Struct(object_type) = object_type else {
+        return invalid_argument(object, "TypeMismatch, expected an object type");
+      };
+      transfer_object(session, object, Some(&object_type), recipient)?;
+      return Ok(vec![]);
+    }
+    _ => {
+      return Err(Failure::Unsupported(format!(
+        "function {}::{}::{} is not supported by the local ledger",
+        call.package, call.module, call.function
+      )))
+    }
+  };
+
+  Ok(vec![value])
+}
+
+fn transfer_object(
+  session: &mut Session<'_>,
+  object: Argument,
+  object_type: Option<&StructTag>,
+  recipient: Argument,
+) -> ExecResult<()> {
+  let recipient = session.take_value(recipient, &TypeTag::Address)?;
+  let recipient = values::as_address(&recipient).expect("a valid address");
+  let id = session.take_object(object, object_type)?;
+  if !session.object(id).has_public_transfer {
+    return invalid_argument(object, "InvalidTransferObject");
+  }
+  session.transfer(id, recipient);
+
+  Ok(())
+}
+
+/// The `TransferObjects` command.
+pub(super) fn transfer_objects(
+  session: &mut Session<'_>,
+  objects: &[Argument],
+  recipient: Argument,
+) -> ExecResult<Vec<RuntimeValue>> {
+  for object in objects {
+    transfer_object(session, *object, None, recipient)?;
+  }
+
+  Ok(vec![])
+}
+
+fn coin_arg_mut(session: &mut Session<'_>, coin: Argument) -> ExecResult<ObjectID> {
+  let id = session.any_object_arg_mut(coin)?;
+  if !session.object(id).is_coin() {
+    return invalid_argument(coin, "TypeMismatch, expected a coin");
+  }
+
+  Ok(id)
+}
+
+/// The `SplitCoins` command.
+pub(super) fn split_coins(
+  session: &mut Session<'_>,
+  coin: Argument,
+  amounts: &[Argument],
+) -> ExecResult<Vec<RuntimeValue>> {
+  let coin = coin_arg_mut(session, coin)?;
+  let coin_type = session.object(coin).type_.clone();
+  let mut balance = session.object(coin).coin_balance().expect("object is a coin");
+  let mut new_coins = Vec::with_capacity(amounts.len());
+  for amount in amounts {
+    let amount = take_u64(session, *amount)?;
+    if amount > balance {
+      return abort(IOTA_FRAMEWORK_PACKAGE_ID, "balance", "split", 2);
+    }
+    balance -= amount;
+    let id = session.fresh_id();
+    let contents = json!({ "id": values::uid(id), "balance": values::u64_value(amount) });
+    session.new_object(id, coin_type.clone(), contents, true);
+    new_coins.push(RuntimeValue::Object(id));
+  }
+  session.object_mut(coin).set_coin_balance(balance);
+
+  Ok(new_coins)
+}
+
+/// The `MergeCoins` command.
+pub(super) fn merge_coins(
+  session: &mut Session<'_>,
+  coin: Argument,
+  coins: &[Argument],
+) -> ExecResult<Vec<RuntimeValue>> {
+  let coin = coin_arg_mut(session, coin)?;
+  let coin_type = session.object(coin).type_.clone();
+  let mut balance = session.object(coin).coin_balance().expect("object is a coin");
+  for merged in coins {
+    let merged = session.take_object(*merged, Some(&coin_type))?;
+    balance += session.object(merged).coin_balance().expect("object is a coin");
+    session.delete(merged);
+  }
+  session.object_mut(coin).set_coin_balance(balance);
+
+  Ok(vec![])
+}
+
+/// The `MakeMoveVec` command. Only vectors of non-object values are supported.
+pub(super) fn make_move_vec(
+  session: &mut Session<'_>,
+  element_type: Option<&TypeTag>,
+  elements: &[Argument],
+) -> ExecResult<Vec<RuntimeValue>> {
+  let Some(element_type) = element_type else {
+    return Err(Failure::Unsupported(
+      "vectors of objects are not supported by the local ledger".to_owned(),
+    ));
+  };
+  let elements = elements
+    .iter()
+    .map(|element| session.take_value(*element, element_type))
+    .collect::<ExecResult<Vec<_>>>()?;
+  let vector_type = TypeTag::Vector(Box::new(element_type.clone()));
+
+  Ok(vec![RuntimeValue::Data(DataValue::plain(vector_type, elements.into()))])
+}
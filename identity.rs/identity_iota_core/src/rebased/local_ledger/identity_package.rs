@@ -0,0 +1,1741 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A model of the identity Move package (`packages/iota_identity`).
+//!
+//! Every entry point mirrors its Move counterpart closely: same ID derivation order, same events and
+//! same abort codes, so that transactions built by this crate behave on the local ledger as they would
+//! on an IOTA node.
+
+use std::str::FromStr;
+
+use identity_iota_interaction::move_types::language_storage::StructTag;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::transaction::Argument;
+use identity_iota_interaction::types::transaction::ProgrammableMoveCall;
+use identity_iota_interaction::types::TypeTag;
+use identity_iota_interaction::types::IOTA_FRAMEWORK_PACKAGE_ID;
+use identity_iota_interaction::types::MOVE_STDLIB_PACKAGE_ID;
+use serde_json::json;
+use serde_json::Value;
+
+use super::executor::abort;
+use super::executor::alias_output_tag;
+use super::executor::invalid_argument;
+use super::executor::DataValue;
+use super::executor::ExecResult;
+use super::executor::Failure;
+use super::executor::RuntimeValue;
+use super::executor::Session;
+use super::executor::LEGACY_ALIAS_FIELD_NAME;
+use super::framework::arguments;
+use super::framework::id_type;
+use super::framework::option_type;
+use super::framework::take_u64;
+use super::framework::type_arguments;
+use super::framework::vec_map_type;
+use super::state::gas_coin_type;
+use super::values;
+use super::values::EventField;
+
+const ALL_PERMISSIONS: u32 = 0xFFFF_FFFF;
+const CAN_CREATE_PROPOSAL: u32 = 0x1;
+const CAN_APPROVE_PROPOSAL: u32 = 0x1 << 1;
+const CAN_EXECUTE_PROPOSAL: u32 = 0x1 << 2;
+/// `0x1::option::EOPTION_NOT_SET`.
+const EOPTION_NOT_SET: u64 = 0x40001;
+/// Version of the identity package modeled by the local ledger.
+const PACKAGE_VERSION: u64 = 0;
+
+/// A function of the identity package, modeled by the local ledger.
+type Function = fn(&mut Session<'_>, &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>>;
+
+/// The functions of the identity package supported by the local ledger, by module and name.
+const FUNCTIONS: &[(&str, &str, Function)] = &[
+  ("controller", "borrow", controller_borrow),
+  ("controller", "put_back", controller_put_back),
+  ("identity", "new", identity_new),
+  ("identity", "new_with_controller", identity_new_with_controller),
+  ("identity", "new_with_controllers", identity_new_with_controllers),
+  ("identity", "approve_proposal", identity_approve_proposal),
+  ("identity", "execute_proposal", identity_execute_proposal),
+  ("identity", "propose_update", identity_propose_update),
+  ("identity", "execute_update", identity_execute_update),
+  ("identity", "propose_config_change", identity_propose_config_change),
+  ("identity", "execute_config_change", identity_execute_config_change),
+  ("identity", "propose_send", identity_propose_send),
+  ("identity", "execute_send", identity_execute_send),
+  ("identity", "propose_borrow", identity_propose_borrow),
+  ("identity", "execute_borrow", identity_execute_borrow),
+  (
+    "identity",
+    "propose_controller_execution",
+    identity_propose_controller_execution,
+  ),
+  ("identity", "borrow_controller_cap", identity_borrow_controller_cap),
+  ("identity", "propose_upgrade", identity_propose_upgrade),
+  ("identity", "execute_upgrade", identity_execute_upgrade),
+  ("identity", "revoke_token", identity_revoke_token),
+  ("identity", "unrevoke_token", identity_unrevoke_token),
+  ("transfer_proposal", "complete_send", transfer_proposal_complete_send),
+  ("borrow_proposal", "put_back", borrow_proposal_put_back),
+  ("borrow_proposal", "conclude_borrow", borrow_proposal_conclude_borrow),
+  ("controller_proposal", "put_back", controller_proposal_put_back),
+  ("utils", "vec_map_from_keys_values", utils_vec_map_from_keys_values),
+  ("public_vc", "new", public_vc_new),
+  ("migration", "migrate_alias_output", migration_migrate_alias_output),
+  ("asset", "new", asset_new),
+  ("asset", "new_with_config", asset_new_with_config),
+  ("asset", "set_content", asset_set_content),
+  ("asset", "delete", asset_delete),
+  ("asset", "transfer", asset_transfer),
+  ("asset", "accept", asset_accept),
+  ("asset", "conclude_or_cancel", asset_conclude_or_cancel),
+];
+
+/// Executes a call to a function of the identity package.
+pub(super) fn call(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let (module, function) = (call.module.as_str(), call.function.as_str());
+  match FUNCTIONS.iter().find(|(m, f, _)| *m == module && *f == function) {
+    Some((_, _, execute)) => execute(session, call),
+    None => Err(Failure::Unsupported(format!(
+      "function {module}::{function} of the identity package is not supported by the local ledger"
+    ))),
+  }
+}
+
+// ===== Types =====
+
+fn identity_tag(package: ObjectID) -> StructTag {
+  values::struct_tag(package, "identity", "Identity", vec![])
+}
+
+fn controller_cap_tag(package: ObjectID) -> StructTag {
+  values::struct_tag(package, "controller", "ControllerCap", vec![])
+}
+
+fn delegation_token_type(package: ObjectID) -> TypeTag {
+  values::struct_type(package, "controller", "DelegationToken", vec![])
+}
+
+fn borrow_type() -> TypeTag {
+  values::struct_type(IOTA_FRAMEWORK_PACKAGE_ID, "borrow", "Borrow", vec![])
+}
+
+fn proposal_tag(package: ObjectID, action: TypeTag) -> StructTag {
+  values::struct_tag(package, "multicontroller", "Proposal", vec![action])
+}
+
+fn action_type(package: ObjectID, action: TypeTag) -> TypeTag {
+  values::struct_type(package, "multicontroller", "Action", vec![action])
+}
+
+fn update_value_type(package: ObjectID) -> TypeTag {
+  let did_doc_type = option_type(TypeTag::Vector(Box::new(TypeTag::U8)));
+  values::struct_type(package, "update_value_proposal", "UpdateValue", vec![did_doc_type])
+}
+
+fn modify_type(package: ObjectID) -> TypeTag {
+  values::struct_type(package, "config_proposal", "Modify", vec![])
+}
+
+fn send_type(package: ObjectID) -> TypeTag {
+  values::struct_type(package, "transfer_proposal", "Send", vec![])
+}
+
+fn borrow_action_type(package: ObjectID) -> TypeTag {
+  values::struct_type(package, "borrow_proposal", "Borrow", vec![])
+}
+
+fn controller_execution_type(package: ObjectID) -> TypeTag {
+  values::struct_type(package, "controller_proposal", "ControllerExecution", vec![])
+}
+
+fn upgrade_type(package: ObjectID) -> TypeTag {
+  values::struct_type(package, "upgrade_proposal", "Upgrade", vec![])
+}
+
+fn asset_tag(package: ObjectID, inner: TypeTag) -> StructTag {
+  values::struct_tag(package, "asset", "AuthenticatedAsset", vec![inner])
+}
+
+fn transfer_proposal_tag(package: ObjectID) -> StructTag {
+  values::struct_tag(package, "asset", "TransferProposal", vec![])
+}
+
+fn sender_cap_tag(package: ObjectID) -> StructTag {
+  values::struct_tag(package, "asset", "SenderCap", vec![])
+}
+
+fn recipient_cap_tag(package: ObjectID) -> StructTag {
+  values::struct_tag(package, "asset", "RecipientCap", vec![])
+}
+
+fn object_type_param(arg: Argument, type_: TypeTag) -> ExecResult<StructTag> {
+  match type_ {
+    TypeTag::Struct(tag) => Ok(*tag),
+    _ => invalid_argument(arg, "TypeMismatch, expected an object type"),
+  }
+}
+
+// ===== Arguments and return values =====
+
+fn take_id(session: &mut Session<'_>, arg: Argument) -> ExecResult<ObjectID> {
+  let value = session.take_value(arg, &id_type())?;
+  Ok(values::as_id(&value).expect("a valid ID"))
+}
+
+fn take_bool(session: &mut Session<'_>, arg: Argument) -> ExecResult<bool> {
+  let value = session.take_value(arg, &TypeTag::Bool)?;
+  Ok(value.as_bool().expect("a valid bool"))
+}
+
+fn take_address(session: &mut Session<'_>, arg: Argument) -> ExecResult<IotaAddress> {
+  let value = session.take_value(arg, &TypeTag::Address)?;
+  Ok(values::as_address(&value).expect("a valid address"))
+}
+
+/// Takes an `Option<u64>` argument, returning its JSON representation.
+fn take_option_u64(session: &mut Session<'_>, arg: Argument) -> ExecResult<Value> {
+  session.take_value(arg, &option_type(TypeTag::U64))
+}
+
+/// Takes an `Option<vector<u8>>` argument.
+fn take_did_doc(session: &mut Session<'_>, arg: Argument) -> ExecResult<Option<Vec<u8>>> {
+  let value = session.take_value(arg, &option_type(TypeTag::Vector(Box::new(TypeTag::U8))))?;
+  Ok(values::as_bytes(&value))
+}
+
+fn take_vec_map(
+  session: &mut Session<'_>,
+  arg: Argument,
+  key: TypeTag,
+  value: TypeTag,
+) -> ExecResult<Vec<(Value, Value)>> {
+  let map = session.take_value(arg, &vec_map_type(key, value))?;
+  Ok(values::vec_map_entries(&map).expect("a valid VecMap"))
+}
+
+fn id_value(id: ObjectID) -> RuntimeValue {
+  RuntimeValue::Data(DataValue::plain(id_type(), json!(id)))
+}
+
+fn option_id_value(id: Option<ObjectID>) -> RuntimeValue {
+  RuntimeValue::Data(DataValue::plain(
+    option_type(id_type()),
+    id.map(|id| json!(id)).unwrap_or(Value::Null),
+  ))
+}
+
+fn ids(values: &[Value]) -> Vec<ObjectID> {
+  values.iter().filter_map(values::as_id).collect()
+}
+
+// ===== controller =====
+
+/// A `DelegationToken` used by reference.
+struct Token {
+  id: ObjectID,
+  controller: ObjectID,
+  permissions: u32,
+}
+
+fn borrow_token(session: &mut Session<'_>, arg: Argument) -> ExecResult<Token> {
+  let token_type = delegation_token_type(session.package_id);
+  let json = &session.data_mut(arg, &token_type)?.json;
+
+  Ok(Token {
+    id: values::as_uid(&json["id"]).expect("valid token"),
+    controller: values::as_id(&json["controller"]).expect("valid token"),
+    permissions: json["permissions"].as_u64().expect("valid token") as u32,
+  })
+}
+
+fn assert_has_permission(session: &Session<'_>, token: &Token, permission: u32) -> ExecResult<()> {
+  if token.permissions & permission == 0 {
+    return abort(session.package_id, "controller", "assert_has_permission", 1);
+  }
+
+  Ok(())
+}
+
+/// `controller::new`: creates a `ControllerCap` for `controller_of` and transfers it to `recipient`.
+fn new_controller_cap(
+  session: &mut Session<'_>,
+  can_delegate: bool,
+  controller_of: ObjectID,
+  recipient: IotaAddress,
+) -> ObjectID {
+  let cap_id = session.fresh_id();
+  let token_id = session.fresh_id();
+  session.emit(
+    "controller",
+    "NewDelegationTokenEvent",
+    vec![
+      ("controller", EventField::Id(cap_id)),
+      ("token", EventField::Id(token_id)),
+      ("permissions", EventField::U32(ALL_PERMISSIONS)),
+    ],
+  );
+  let referent_id = IotaAddress::from(session.fresh_id());
+  let token = json!({
+    "id": values::uid(token_id),
+    "permissions": ALL_PERMISSIONS,
+    "controller": cap_id,
+    "controller_of": controller_of,
+  });
+  let cap = json!({
+    "id": values::uid(cap_id),
+    "controller_of": controller_of,
+    "can_delegate": can_delegate,
+    "access_token": { "id": referent_id, "value": token },
+  });
+  session.new_object(cap_id, controller_cap_tag(session.package_id), cap, false);
+  session.transfer(cap_id, recipient);
+
+  cap_id
+}
+
+fn controller_borrow(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [cap] = arguments(call)?;
+  let package = session.package_id;
+  let cap = session.object_arg_mut(cap, &controller_cap_tag(package))?;
+  let access_token = &mut session.object_mut(cap).contents["access_token"];
+  let token = access_token["value"].take();
+  if token.is_null() {
+    return abort(MOVE_STDLIB_PACKAGE_ID, "option", "extract", EOPTION_NOT_SET);
+  }
+  let borrow = json!({ "ref": access_token["id"].clone(), "obj": token["id"]["id"].clone() });
+
+  Ok(vec![
+    RuntimeValue::Data(DataValue::linear(delegation_token_type(package), token)),
+    RuntimeValue::Data(DataValue::linear(borrow_type(), borrow)),
+  ])
+}
+
+fn controller_put_back(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [cap, token, borrow] = arguments(call)?;
+  let package = session.package_id;
+  let cap = session.object_arg_mut(cap, &controller_cap_tag(package))?;
+  let token = session.take_data(token, &delegation_token_type(package))?.json;
+  let borrow = session.take_data(borrow, &borrow_type())?.json;
+
+  let access_token = &mut session.object_mut(cap).contents["access_token"];
+  if access_token["id"] != borrow["ref"] {
+    return abort(IOTA_FRAMEWORK_PACKAGE_ID, "borrow", "put_back", 0);
+  }
+  if token["id"]["id"] != borrow["obj"] {
+    return abort(IOTA_FRAMEWORK_PACKAGE_ID, "borrow", "put_back", 1);
+  }
+  access_token["value"] = token;
+
+  Ok(vec![])
+}
+
+// ===== multicontroller =====
+
+fn did_doc(session: &Session<'_>, identity: ObjectID) -> &Value {
+  &session.object(identity).contents["did_doc"]
+}
+
+fn did_doc_mut<'a>(session: &'a mut Session<'_>, identity: ObjectID) -> &'a mut Value {
+  &mut session.object_mut(identity).contents["did_doc"]
+}
+
+/// Returns the controllers of a `Multicontroller` with their voting power.
+fn controllers(did_doc: &Value) -> Vec<(ObjectID, u64)> {
+  values::vec_map_entries(&did_doc["controllers"])
+    .unwrap_or_default()
+    .iter()
+    .filter_map(|(id, vp)| Some((values::as_id(id)?, values::as_u64(vp)?)))
+    .collect()
+}
+
+fn set_controllers(did_doc: &mut Value, controllers: &[(ObjectID, u64)]) {
+  did_doc["controllers"] = values::vec_map(controllers.iter().map(|(id, vp)| (json!(id), values::u64_value(*vp))));
+}
+
+fn voting_power(did_doc: &Value, controller: ObjectID) -> Option<u64> {
+  controllers(did_doc)
+    .into_iter()
+    .find_map(|(id, vp)| (id == controller).then_some(vp))
+}
+
+fn max_votes(did_doc: &Value) -> u64 {
+  controllers(did_doc).into_iter().map(|(_, vp)| vp).sum()
+}
+
+fn threshold(did_doc: &Value) -> u64 {
+  values::as_u64(&did_doc["threshold"]).expect("valid threshold")
+}
+
+fn proposals_bag(did_doc: &Value) -> ObjectID {
+  values::as_uid(&did_doc["proposals"]["id"]).expect("valid ObjectBag")
+}
+
+fn assert_is_member(session: &Session<'_>, identity: ObjectID, token: &Token) -> ExecResult<()> {
+  if voting_power(did_doc(session, identity), token.controller).is_none() {
+    return abort(session.package_id, "multicontroller", "assert_is_member", 0);
+  }
+
+  Ok(())
+}
+
+fn new_multicontroller(
+  session: &mut Session<'_>,
+  controlled_value: Value,
+  controllers: Vec<(Value, Value)>,
+  controllers_that_can_delegate: Vec<(Value, Value)>,
+  threshold: u64,
+  owner: ObjectID,
+) -> ExecResult<Value> {
+  let mut caps = Vec::new();
+  for (can_delegate, controllers) in [(false, controllers), (true, controllers_that_can_delegate)] {
+    for (address, vp) in controllers.into_iter().rev() {
+      let address = values::as_address(&address).expect("valid address");
+      let cap = new_controller_cap(session, can_delegate, owner, address);
+      caps.push((cap, values::as_u64(&vp).expect("valid u64")));
+    }
+  }
+  let bag = session.fresh_id();
+  let mut did_doc = json!({
+    "threshold": values::u64_value(threshold),
+    "owner": owner,
+    "controllers": {},
+    "controlled_value": controlled_value,
+    "active_proposals": [],
+    "proposals": { "id": values::uid(bag), "size": values::u64_value(0) },
+    "revoked_tokens": values::vec_set([]),
+  });
+  set_controllers(&mut did_doc, &caps);
+  if threshold > max_votes(&did_doc) {
+    return abort(session.package_id, "multicontroller", "set_threshold", 3);
+  }
+
+  Ok(did_doc)
+}
+
+/// Returns the proposal with ID `proposal_id` of type `Proposal<action>`.
+fn get_proposal(
+  session: &mut Session<'_>,
+  identity: ObjectID,
+  proposal_id: ObjectID,
+  action: &TypeTag,
+  function: &'static str,
+) -> ExecResult<ObjectID> {
+  let bag = proposals_bag(did_doc(session, identity));
+  let Some(proposal) = session.object_bag_get(bag, proposal_id) else {
+    return abort(IOTA_FRAMEWORK_PACKAGE_ID, "dynamic_field", function, 1);
+  };
+  if session.object(proposal).type_ != proposal_tag(session.package_id, action.clone()) {
+    return abort(IOTA_FRAMEWORK_PACKAGE_ID, "dynamic_field", function, 2);
+  }
+
+  Ok(proposal)
+}
+
+fn is_proposal_approved(
+  session: &mut Session<'_>,
+  identity: ObjectID,
+  proposal_id: ObjectID,
+  action: &TypeTag,
+) -> ExecResult<bool> {
+  let proposal = get_proposal(session, identity, proposal_id, action, "borrow_child_object")?;
+  let votes = values::as_u64(&session.object(proposal).contents["votes"]).expect("valid proposal");
+
+  Ok(votes >= threshold(did_doc(session, identity)))
+}
+
+/// `multicontroller::create_proposal`.
+fn create_proposal(
+  session: &mut Session<'_>,
+  identity: ObjectID,
+  token: &Token,
+  action: TypeTag,
+  action_value: Value,
+  expiration: Value,
+) -> ExecResult<ObjectID> {
+  assert_is_member(session, identity, token)?;
+  assert_has_permission(session, token, CAN_CREATE_PROPOSAL)?;
+
+  let voting_power = voting_power(did_doc(session, identity), token.controller).expect("is a member");
+  let proposal_id = session.fresh_id();
+  let proposal = json!({
+    "id": values::uid(proposal_id),
+    "votes": values::u64_value(voting_power),
+    "voters": values::vec_set([json!(token.controller)]),
+    "expiration_epoch": expiration,
+    "action": action_value,
+  });
+  session.new_object(proposal_id, proposal_tag(session.package_id, action), proposal, true);
+
+  let bag = proposals_bag(did_doc(session, identity));
+  session.object_bag_add(bag, proposal_id, proposal_id)?;
+  let did_doc = did_doc_mut(session, identity);
+  let size = values::as_u64(&did_doc["proposals"]["size"]).expect("valid ObjectBag");
+  did_doc["proposals"]["size"] = values::u64_value(size + 1);
+  did_doc["active_proposals"]
+    .as_array_mut()
+    .expect("valid active proposals")
+    .push(json!(proposal_id));
+
+  Ok(proposal_id)
+}
+
+/// `multicontroller::execute_proposal`: consumes a proposal returning its action.
+fn execute_proposal(
+  session: &mut Session<'_>,
+  identity: ObjectID,
+  token: &Token,
+  proposal_id: ObjectID,
+  action: &TypeTag,
+) -> ExecResult<Value> {
+  let package = session.package_id;
+  assert_is_member(session, identity, token)?;
+  assert_has_permission(session, token, CAN_EXECUTE_PROPOSAL)?;
+
+  get_proposal(session, identity, proposal_id, action, "remove_child_object")?;
+  let bag = proposals_bag(did_doc(session, identity));
+  let proposal = session.object_bag_remove(bag, proposal_id)?;
+  let contents = session.object(proposal).contents.clone();
+  let votes = values::as_u64(&contents["votes"]).expect("valid proposal");
+  if votes < threshold(did_doc(session, identity)) {
+    return abort(package, "multicontroller", "execute_proposal", 2);
+  }
+  if let Some(expiration) = values::as_u64(&contents["expiration_epoch"]) {
+    if expiration < session.epoch {
+      return abort(package, "multicontroller", "execute_proposal", 4);
+    }
+  }
+  session.delete(proposal);
+
+  let did_doc = did_doc_mut(session, identity);
+  let size = values::as_u64(&did_doc["proposals"]["size"]).expect("valid ObjectBag");
+  did_doc["proposals"]["size"] = values::u64_value(size - 1);
+  let active_proposals = did_doc["active_proposals"]
+    .as_array_mut()
+    .expect("valid active proposals");
+  let Some(idx) = active_proposals
+    .iter()
+    .position(|id| values::as_id(id) == Some(proposal_id))
+  else {
+    return abort(package, "multicontroller", "execute_proposal", 6);
+  };
+  active_proposals.remove(idx);
+
+  Ok(contents["action"].clone())
+}
+
+// ===== identity =====
+
+fn identity_arg(session: &mut Session<'_>, arg: Argument) -> ExecResult<ObjectID> {
+  let tag = identity_tag(session.package_id);
+  session.object_arg_mut(arg, &tag)
+}
+
+fn timestamp_ms(session: &mut Session<'_>, clock: Argument) -> ExecResult<Value> {
+  let clock_tag = values::struct_tag(IOTA_FRAMEWORK_PACKAGE_ID, "clock", "Clock", vec![]);
+  let clock = session.object_arg(clock, &clock_tag)?;
+  Ok(session.object(clock).contents["timestamp_ms"].clone())
+}
+
+fn assert_not_deleted(
+  session: &Session<'_>,
+  identity: ObjectID,
+  function: &'static str,
+  check_did: bool,
+) -> ExecResult<()> {
+  let contents = &session.object(identity).contents;
+  let deleted = contents["deleted"].as_bool() == Some(true);
+  let deleted_did = check_did && contents["deleted_did"].as_bool() == Some(true);
+  if deleted || deleted_did {
+    return abort(session.package_id, "identity", function, 6);
+  }
+
+  Ok(())
+}
+
+/// `identity::is_did_output`.
+fn is_did_output(session: &Session<'_>, data: &[u8]) -> ExecResult<bool> {
+  for (i, expected) in b"DID".iter().enumerate() {
+    match data.get(i) {
+      None => {
+        return Err(Failure::Error(format!(
+          "VMError with status VECTOR_OPERATION_ERROR at {}::identity::is_did_output",
+          session.package_id
+        )))
+      }
+      Some(byte) if byte != expected => return Ok(false),
+      Some(_) => (),
+    }
+  }
+
+  Ok(true)
+}
+
+fn emit_proposal_event(
+  session: &mut Session<'_>,
+  identity: ObjectID,
+  token: &Token,
+  proposal: ObjectID,
+  executed: bool,
+) {
+  session.emit(
+    "identity",
+    "ProposalEvent",
+    vec![
+      ("identity", EventField::Id(identity)),
+      ("controller", EventField::Id(token.id)),
+      ("proposal", EventField::Id(proposal)),
+      ("executed", EventField::Bool(executed)),
+    ],
+  );
+}
+
+fn create_identity(
+  session: &mut Session<'_>,
+  did_doc: Option<Vec<u8>>,
+  controllers: Vec<(Value, Value)>,
+  controllers_that_can_delegate: Vec<(Value, Value)>,
+  threshold: u64,
+  now: Value,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let identity_id = new_identity(
+    session,
+    did_doc,
+    controllers,
+    controllers_that_can_delegate,
+    threshold,
+    now,
+  )?;
+
+  Ok(vec![id_value(identity_id)])
+}
+
+/// Creates and shares a new identity, returning its ID.
+fn new_identity(
+  session: &mut Session<'_>,
+  did_doc: Option<Vec<u8>>,
+  controllers: Vec<(Value, Value)>,
+  controllers_that_can_delegate: Vec<(Value, Value)>,
+  threshold: u64,
+  now: Value,
+) -> ExecResult<ObjectID> {
+  let identity_id = session.fresh_id();
+  let controlled_value = did_doc.as_deref().map(values::bytes_value).unwrap_or(Value::Null);
+  let multicontroller = new_multicontroller(
+    session,
+    controlled_value,
+    controllers,
+    controllers_that_can_delegate,
+    threshold,
+    identity_id,
+  )?;
+  let identity = json!({
+    "id": values::uid(identity_id),
+    "did_doc": multicontroller,
+    "legacy_id": null,
+    "created": now.clone(),
+    "updated": now,
+    "version": values::u64_value(PACKAGE_VERSION),
+    "deleted": false,
+    "deleted_did": false,
+  });
+  session.new_object(identity_id, identity_tag(session.package_id), identity, false);
+  session.share(identity_id);
+
+  Ok(identity_id)
+}
+
+fn identity_new(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [did_doc, clock] = arguments(call)?;
+  let did_doc = take_did_doc(session, did_doc)?;
+  let now = timestamp_ms(session, clock)?;
+  let controllers = vec![(json!(session.sender), values::u64_value(1))];
+
+  create_identity(session, did_doc, controllers, vec![], 1, now)
+}
+
+fn identity_new_with_controller(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [did_doc, controller, can_delegate, clock] = arguments(call)?;
+  let did_doc = take_did_doc(session, did_doc)?;
+  let controller = take_address(session, controller)?;
+  let can_delegate = take_bool(session, can_delegate)?;
+  let now = timestamp_ms(session, clock)?;
+  let controllers = vec![(json!(controller), values::u64_value(1))];
+
+  if can_delegate {
+    create_identity(session, did_doc, vec![], controllers, 1, now)
+  } else {
+    create_identity(session, did_doc, controllers, vec![], 1, now)
+  }
+}
+
+fn identity_new_with_controllers(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [did_doc, controllers, controllers_that_can_delegate, threshold, clock] = arguments(call)?;
+  let did_doc = take_did_doc(session, did_doc)?;
+  let controllers = take_vec_map(session, controllers, TypeTag::Address, TypeTag::U64)?;
+  let controllers_that_can_delegate =
+    take_vec_map(session, controllers_that_can_delegate, TypeTag::Address, TypeTag::U64)?;
+  let threshold = take_u64(session, threshold)?;
+  let now = timestamp_ms(session, clock)?;
+
+  let package = session.package_id;
+  if threshold < 1 {
+    return abort(package, "identity", "new_with_controllers", 2);
+  }
+  if controllers.is_empty() {
+    return abort(package, "identity", "new_with_controllers", 3);
+  }
+  if let Some(did_doc) = &did_doc {
+    if !is_did_output(session, did_doc)? {
+      return abort(package, "identity", "new_with_controllers", 0);
+    }
+  }
+
+  create_identity(
+    session,
+    did_doc,
+    controllers,
+    controllers_that_can_delegate,
+    threshold,
+    now,
+  )
+}
+
+fn identity_approve_proposal(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [action] = type_arguments(call)?;
+  let [identity, token, proposal_id] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let proposal_id = take_id(session, proposal_id)?;
+
+  assert_is_member(session, identity, &token)?;
+  assert_has_permission(session, &token, CAN_APPROVE_PROPOSAL)?;
+  let voting_power = voting_power(did_doc(session, identity), token.controller).expect("is a member");
+  let proposal = get_proposal(session, identity, proposal_id, &action, "borrow_child_object")?;
+  let package = session.package_id;
+  let contents = &mut session.object_mut(proposal).contents;
+  let mut voters = ids(&values::vec_set_items(&contents["voters"]).expect("valid proposal"));
+  if voters.contains(&token.controller) {
+    return abort(package, "multicontroller", "approve_proposal", 1);
+  }
+  voters.push(token.controller);
+  let votes = values::as_u64(&contents["votes"]).expect("valid proposal");
+  contents["votes"] = values::u64_value(votes + voting_power);
+  contents["voters"] = values::vec_set(voters.into_iter().map(|id| json!(id)));
+
+  if is_proposal_approved(session, identity, proposal_id, &action)? {
+    session.emit(
+      "identity",
+      "ProposalApproved",
+      vec![
+        ("identity", EventField::Id(identity)),
+        ("proposal", EventField::Id(proposal_id)),
+      ],
+    );
+  }
+
+  Ok(vec![])
+}
+
+/// `identity::execute_proposal`.
+fn execute_identity_proposal(
+  session: &mut Session<'_>,
+  identity: ObjectID,
+  token: &Token,
+  proposal_id: ObjectID,
+  action: &TypeTag,
+) -> ExecResult<Value> {
+  assert_not_deleted(session, identity, "execute_proposal", false)?;
+  emit_proposal_event(session, identity, token, proposal_id, true);
+  execute_proposal(session, identity, token, proposal_id, action)
+}
+
+fn identity_execute_proposal(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [action] = type_arguments(call)?;
+  let [identity, token, proposal_id] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let proposal_id = take_id(session, proposal_id)?;
+
+  let inner = execute_identity_proposal(session, identity, &token, proposal_id, &action)?;
+  let action = action_type(session.package_id, action);
+
+  Ok(vec![RuntimeValue::Data(DataValue::linear(
+    action,
+    json!({ "inner": inner }),
+  ))])
+}
+
+fn identity_propose_update(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, token, did_doc, expiration, clock] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let did_doc = take_did_doc(session, did_doc)?;
+  let expiration = take_option_u64(session, expiration)?;
+  let now = timestamp_ms(session, clock)?;
+
+  let package = session.package_id;
+  assert_not_deleted(session, identity, "propose_update", true)?;
+  if let Some(did_doc) = &did_doc {
+    if !did_doc.is_empty() && !is_did_output(session, did_doc)? {
+      return abort(package, "identity", "propose_update", 0);
+    }
+  }
+  let new_value = did_doc.as_deref().map(values::bytes_value).unwrap_or(Value::Null);
+  let action = update_value_type(package);
+  let proposal_id = create_proposal(
+    session,
+    identity,
+    &token,
+    action.clone(),
+    json!({ "new_value": new_value }),
+    expiration,
+  )?;
+
+  if is_proposal_approved(session, identity, proposal_id, &action)? {
+    execute_update(session, identity, &token, proposal_id, now)?;
+    Ok(vec![option_id_value(None)])
+  } else {
+    emit_proposal_event(session, identity, &token, proposal_id, false);
+    Ok(vec![option_id_value(Some(proposal_id))])
+  }
+}
+
+fn execute_update(
+  session: &mut Session<'_>,
+  identity: ObjectID,
+  token: &Token,
+  proposal_id: ObjectID,
+  now: Value,
+) -> ExecResult<()> {
+  assert_not_deleted(session, identity, "execute_update", true)?;
+  let action = update_value_type(session.package_id);
+  let action = execute_identity_proposal(session, identity, token, proposal_id, &action)?;
+  let new_value = action["new_value"].clone();
+
+  let contents = &mut session.object_mut(identity).contents;
+  if new_value.is_null() {
+    contents["deleted_did"] = Value::Bool(true);
+  }
+  contents["did_doc"]["controlled_value"] = new_value;
+  contents["updated"] = now;
+  emit_proposal_event(session, identity, token, proposal_id, true);
+
+  Ok(())
+}
+
+fn identity_execute_update(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, token, proposal_id, clock] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let proposal_id = take_id(session, proposal_id)?;
+  let now = timestamp_ms(session, clock)?;
+
+  execute_update(session, identity, &token, proposal_id, now)?;
+
+  Ok(vec![])
+}
+
+fn identity_propose_config_change(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, token, expiration, new_threshold, to_add, to_remove, to_update] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let expiration = take_option_u64(session, expiration)?;
+  let new_threshold = take_option_u64(session, new_threshold)?;
+  let to_add = take_vec_map(session, to_add, TypeTag::Address, TypeTag::U64)?;
+  let to_remove = session.take_value(to_remove, &TypeTag::Vector(Box::new(id_type())))?;
+  let to_update = take_vec_map(session, to_update, id_type(), TypeTag::U64)?;
+
+  let package = session.package_id;
+  assert_not_deleted(session, identity, "propose_config_change", false)?;
+
+  // `config_proposal::propose_modify`.
+  let current = controllers(did_doc(session, identity));
+  let is_member = |id: ObjectID| current.iter().any(|(controller, _)| *controller == id);
+  let updates: Vec<(ObjectID, u64)> = to_update
+    .iter()
+    .map(|(id, vp)| {
+      (
+        values::as_id(id).expect("valid ID"),
+        values::as_u64(vp).expect("valid u64"),
+      )
+    })
+    .collect();
+  let mut max_votes = 0u64;
+  for (id, vp) in updates.iter().rev() {
+    if !is_member(*id) {
+      return abort(package, "config_proposal", "propose_modify", 0);
+    }
+    max_votes += vp;
+  }
+  let voting_power_increase: u64 = to_add
+    .iter()
+    .map(|(_, vp)| values::as_u64(vp).expect("valid u64"))
+    .sum();
+  let mut voting_power_decrease = 0u64;
+  for id in ids(to_remove.as_array().expect("valid vector")) {
+    let Some((_, current_vp)) = current.iter().find(|(controller, _)| *controller == id) else {
+      return abort(package, "config_proposal", "propose_modify", 0);
+    };
+    let vp = updates
+      .iter()
+      .find_map(|(updated, vp)| (*updated == id).then_some(*vp))
+      .unwrap_or(*current_vp);
+    voting_power_decrease += vp;
+  }
+  for (id, vp) in &current {
+    if !updates.iter().any(|(updated, _)| updated == id) {
+      max_votes += vp;
+    }
+  }
+  let Some(new_max_votes) = (max_votes + voting_power_increase).checked_sub(voting_power_decrease) else {
+    return Err(Failure::Error(format!(
+      "ArithmeticError at {package}::config_proposal::propose_modify"
+    )));
+  };
+  let new_threshold = values::as_u64(&new_threshold).unwrap_or_else(|| threshold(did_doc(session, identity)));
+  if new_threshold == 0 || new_threshold > new_max_votes {
+    return abort(package, "config_proposal", "propose_modify", 1);
+  }
+
+  let action = modify_type(package);
+  let action_value = json!({
+    "threshold": values::u64_value(new_threshold),
+    "controllers_to_add": values::vec_map(to_add),
+    "controllers_to_remove": to_remove,
+    "controllers_to_update": values::vec_map(to_update),
+  });
+  let proposal_id = create_proposal(session, identity, &token, action.clone(), action_value, expiration)?;
+
+  if is_proposal_approved(session, identity, proposal_id, &action)? {
+    execute_config_change(session, identity, &token, proposal_id)?;
+    Ok(vec![option_id_value(None)])
+  } else {
+    emit_proposal_event(session, identity, &token, proposal_id, false);
+    Ok(vec![option_id_value(Some(proposal_id))])
+  }
+}
+
+fn execute_config_change(
+  session: &mut Session<'_>,
+  identity: ObjectID,
+  token: &Token,
+  proposal_id: ObjectID,
+) -> ExecResult<()> {
+  let package = session.package_id;
+  assert_not_deleted(session, identity, "execute_config_change", false)?;
+
+  // `config_proposal::execute_modify`.
+  let action = execute_proposal(session, identity, token, proposal_id, &modify_type(package))?;
+  let mut controllers = controllers(did_doc(session, identity));
+  if let Some(new_threshold) = values::as_u64(&action["threshold"]) {
+    if new_threshold > controllers.iter().map(|(_, vp)| vp).sum() {
+      return abort(package, "multicontroller", "set_threshold", 3);
+    }
+    did_doc_mut(session, identity)["threshold"] = values::u64_value(new_threshold);
+  }
+  let to_update = values::vec_map_entries(&action["controllers_to_update"]).expect("valid action");
+  for (id, vp) in to_update.iter().rev() {
+    let id = values::as_id(id).expect("valid ID");
+    let Some(entry) = controllers.iter_mut().find(|(controller, _)| *controller == id) else {
+      return abort(package, "multicontroller", "set_voting_power", 0);
+    };
+    entry.1 = values::as_u64(vp).expect("valid u64");
+  }
+  let owner = identity;
+  for (address, vp) in values::vec_map_entries(&action["controllers_to_add"]).expect("valid action") {
+    let address = values::as_address(&address).expect("valid address");
+    let cap = new_controller_cap(session, false, owner, address);
+    controllers.push((cap, values::as_u64(&vp).expect("valid u64")));
+  }
+  for id in ids(action["controllers_to_remove"].as_array().expect("valid action"))
+    .into_iter()
+    .rev()
+  {
+    let Some(idx) = controllers.iter().position(|(controller, _)| *controller == id) else {
+      return abort(IOTA_FRAMEWORK_PACKAGE_ID, "vec_map", "get_idx", 1);
+    };
+    controllers.remove(idx);
+  }
+  set_controllers(did_doc_mut(session, identity), &controllers);
+  emit_proposal_event(session, identity, token, proposal_id, true);
+
+  Ok(())
+}
+
+fn identity_execute_config_change(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, token, proposal_id] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let proposal_id = take_id(session, proposal_id)?;
+
+  execute_config_change(session, identity, &token, proposal_id)?;
+
+  Ok(vec![])
+}
+
+fn identity_propose_send(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, token, expiration, objects, recipients] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let expiration = take_option_u64(session, expiration)?;
+  let objects = session.take_value(objects, &TypeTag::Vector(Box::new(id_type())))?;
+  let recipients = session.take_value(recipients, &TypeTag::Vector(Box::new(TypeTag::Address)))?;
+
+  let package = session.package_id;
+  assert_not_deleted(session, identity, "propose_send", false)?;
+  if objects.as_array().map(Vec::len) != recipients.as_array().map(Vec::len) {
+    return abort(package, "transfer_proposal", "propose_send", 0);
+  }
+  let action = json!({ "objects": objects, "recipients": recipients });
+  let proposal_id = create_proposal(session, identity, &token, send_type(package), action, expiration)?;
+  emit_proposal_event(session, identity, &token, proposal_id, false);
+
+  Ok(vec![id_value(proposal_id)])
+}
+
+/// `transfer::public_receive`: receives an object of type `type_` sent to `parent`.
+fn receive(
+  session: &mut Session<'_>,
+  parent: ObjectID,
+  receiving: Argument,
+  type_: &StructTag,
+  public: bool,
+) -> ExecResult<ObjectID> {
+  let object_ref = session.take_receiving(receiving)?;
+  let object = session.receive(parent, object_ref)?;
+  if &session.object(object).type_ != type_ || (public && !session.object(object).has_public_transfer) {
+    return invalid_argument(receiving, format!("TypeMismatch, expected Receiving<{type_}>"));
+  }
+
+  Ok(object)
+}
+
+fn identity_execute_send(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [object_type] = type_arguments(call)?;
+  let [identity, action, receiving] = arguments(call)?;
+  let object_type = object_type_param(receiving, object_type)?;
+  let identity = identity_arg(session, identity)?;
+  let package = session.package_id;
+  let action_type = action_type(package, send_type(package));
+  session.data_mut(action, &action_type)?;
+  let object_ref = session.take_receiving(receiving)?;
+
+  // `transfer_proposal::send`.
+  let send = &mut session.data_mut(action, &action_type)?.json["inner"];
+  let objects = send["objects"].as_array_mut().expect("valid action");
+  let Some(idx) = objects.iter().position(|id| values::as_id(id) == Some(object_ref.0)) else {
+    return abort(package, "transfer_proposal", "send", 2);
+  };
+  objects.swap_remove(idx);
+  let recipient = send["recipients"]
+    .as_array_mut()
+    .expect("valid action")
+    .swap_remove(idx);
+  let recipient = values::as_address(&recipient).expect("valid address");
+
+  let object = session.receive(identity, object_ref)?;
+  if session.object(object).type_ != object_type || !session.object(object).has_public_transfer {
+    return invalid_argument(receiving, format!("TypeMismatch, expected Receiving<{object_type}>"));
+  }
+  session.transfer(object, recipient);
+
+  Ok(vec![])
+}
+
+fn transfer_proposal_complete_send(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [action] = arguments(call)?;
+  let package = session.package_id;
+  let action = session
+    .take_data(action, &action_type(package, send_type(package)))?
+    .json;
+  let is_empty = |field: &str| action["inner"][field].as_array().map_or(true, Vec::is_empty);
+  if !is_empty("objects") || !is_empty("recipients") {
+    return abort(package, "transfer_proposal", "complete_send", 1);
+  }
+
+  Ok(vec![])
+}
+
+fn identity_propose_borrow(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, token, expiration, objects] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let expiration = take_option_u64(session, expiration)?;
+  let objects = session.take_value(objects, &TypeTag::Vector(Box::new(id_type())))?;
+
+  let package = session.package_id;
+  assert_not_deleted(session, identity, "propose_borrow", false)?;
+  let action = json!({
+    "objects": objects,
+    "objects_to_return": [],
+    "owner": IotaAddress::from(identity),
+  });
+  let proposal_id = create_proposal(
+    session,
+    identity,
+    &token,
+    borrow_action_type(package),
+    action,
+    expiration,
+  )?;
+  emit_proposal_event(session, identity, &token, proposal_id, false);
+
+  Ok(vec![id_value(proposal_id)])
+}
+
+fn identity_execute_borrow(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [object_type] = type_arguments(call)?;
+  let [identity, action, receiving] = arguments(call)?;
+  let object_type = object_type_param(receiving, object_type)?;
+  let identity = identity_arg(session, identity)?;
+  let package = session.package_id;
+  let action_type = action_type(package, borrow_action_type(package));
+  session.data_mut(action, &action_type)?;
+  let object_ref = session.take_receiving(receiving)?;
+
+  // `borrow_proposal::borrow`.
+  let borrow = &mut session.data_mut(action, &action_type)?.json["inner"];
+  if values::as_address(&borrow["owner"]) != Some(IotaAddress::from(identity)) {
+    return abort(package, "borrow_proposal", "borrow", 1);
+  }
+  let objects = borrow["objects"].as_array_mut().expect("valid action");
+  let Some(idx) = objects.iter().position(|id| values::as_id(id) == Some(object_ref.0)) else {
+    return abort(package, "borrow_proposal", "borrow", 0);
+  };
+  objects.swap_remove(idx);
+  borrow["objects_to_return"]
+    .as_array_mut()
+    .expect("valid action")
+    .push(json!(object_ref.0));
+
+  let object = session.receive(identity, object_ref)?;
+  if session.object(object).type_ != object_type || !session.object(object).has_public_transfer {
+    return invalid_argument(receiving, format!("TypeMismatch, expected Receiving<{object_type}>"));
+  }
+
+  Ok(vec![RuntimeValue::Object(object)])
+}
+
+fn borrow_proposal_put_back(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [object_type] = type_arguments(call)?;
+  let [action, object] = arguments(call)?;
+  let object_type = object_type_param(object, object_type)?;
+  let package = session.package_id;
+  let action_type = action_type(package, borrow_action_type(package));
+  session.data_mut(action, &action_type)?;
+  let object = session.take_object(object, Some(&object_type))?;
+
+  let borrow = &mut session.data_mut(action, &action_type)?.json["inner"];
+  let objects_to_return = borrow["objects_to_return"].as_array_mut().expect("valid action");
+  let Some(idx) = objects_to_return
+    .iter()
+    .position(|id| values::as_id(id) == Some(object))
+  else {
+    return abort(package, "borrow_proposal", "put_back", 0);
+  };
+  objects_to_return.swap_remove(idx);
+  let owner = values::as_address(&borrow["owner"]).expect("valid address");
+  session.transfer(object, owner);
+
+  Ok(vec![])
+}
+
+fn borrow_proposal_conclude_borrow(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [action] = arguments(call)?;
+  let package = session.package_id;
+  let action = session
+    .take_data(action, &action_type(package, borrow_action_type(package)))?
+    .json;
+  if !action["inner"]["objects_to_return"]
+    .as_array()
+    .map_or(true, Vec::is_empty)
+  {
+    return abort(package, "borrow_proposal", "conclude_borrow", 2);
+  }
+
+  Ok(vec![])
+}
+
+fn identity_propose_controller_execution(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, token, controller_cap, expiration] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let controller_cap = take_id(session, controller_cap)?;
+  let expiration = take_option_u64(session, expiration)?;
+
+  let package = session.package_id;
+  assert_not_deleted(session, identity, "propose_controller_execution", false)?;
+  let action = json!({
+    "controller_cap": controller_cap,
+    "identity": IotaAddress::from(identity),
+  });
+  let proposal_id = create_proposal(
+    session,
+    identity,
+    &token,
+    controller_execution_type(package),
+    action,
+    expiration,
+  )?;
+  emit_proposal_event(session, identity, &token, proposal_id, false);
+
+  Ok(vec![id_value(proposal_id)])
+}
+
+fn identity_borrow_controller_cap(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, action, receiving] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let package = session.package_id;
+  let action_type = action_type(package, controller_execution_type(package));
+  let execution = session.data_mut(action, &action_type)?.json["inner"].clone();
+
+  // `controller_proposal::receive`.
+  if values::as_address(&execution["identity"]) != Some(IotaAddress::from(identity)) {
+    return abort(package, "controller_proposal", "receive", 1);
+  }
+  let object_ref = session.take_receiving(receiving)?;
+  if values::as_id(&execution["controller_cap"]) != Some(object_ref.0) {
+    return abort(package, "controller_proposal", "receive", 0);
+  }
+  let cap = session.receive(identity, object_ref)?;
+  if session.object(cap).type_ != controller_cap_tag(package) {
+    return invalid_argument(receiving, "TypeMismatch, expected Receiving<ControllerCap>");
+  }
+
+  Ok(vec![RuntimeValue::Object(cap)])
+}
+
+fn controller_proposal_put_back(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [action, cap] = arguments(call)?;
+  let package = session.package_id;
+  let execution = session
+    .take_data(action, &action_type(package, controller_execution_type(package)))?
+    .json;
+  let cap = session.take_object(cap, Some(&controller_cap_tag(package)))?;
+  if values::as_id(&execution["inner"]["controller_cap"]) != Some(cap) {
+    return abort(package, "controller_proposal", "put_back", 0);
+  }
+  let identity = values::as_address(&execution["inner"]["identity"]).expect("valid address");
+  session.transfer(cap, identity);
+
+  Ok(vec![])
+}
+
+fn identity_propose_upgrade(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, token, expiration] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  borrow_token(session, token)?;
+  take_option_u64(session, expiration)?;
+
+  assert_not_deleted(session, identity, "propose_upgrade", false)?;
+  let version = values::as_u64(&session.object(identity).contents["version"]).expect("valid identity");
+  // This package is the latest version of the identity package: there's nothing to upgrade to.
+  debug_assert!(version >= PACKAGE_VERSION);
+  abort(session.package_id, "identity", "propose_upgrade", 4)
+}
+
+fn identity_execute_upgrade(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, token, proposal_id] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let token = borrow_token(session, token)?;
+  let proposal_id = take_id(session, proposal_id)?;
+
+  assert_not_deleted(session, identity, "execute_upgrade", false)?;
+  let action = upgrade_type(session.package_id);
+  execute_identity_proposal(session, identity, &token, proposal_id, &action)?;
+  session.object_mut(identity).contents["version"] = values::u64_value(PACKAGE_VERSION);
+  emit_proposal_event(session, identity, &token, proposal_id, true);
+
+  Ok(vec![])
+}
+
+fn identity_revoke_token(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  update_revoked_tokens(session, call, true)
+}
+
+fn identity_unrevoke_token(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  update_revoked_tokens(session, call, false)
+}
+
+fn update_revoked_tokens(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+  revoke: bool,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [identity, cap, token_id] = arguments(call)?;
+  let identity = identity_arg(session, identity)?;
+  let package = session.package_id;
+  let cap = session.object_arg(cap, &controller_cap_tag(package))?;
+  let token_id = take_id(session, token_id)?;
+
+  let function = if revoke { "revoke_token" } else { "unrevoke_token" };
+  if voting_power(did_doc(session, identity), cap).is_none() {
+    return abort(package, "multicontroller", function, 0);
+  }
+  let did_doc = did_doc_mut(session, identity);
+  let mut revoked_tokens = ids(&values::vec_set_items(&did_doc["revoked_tokens"]).expect("valid VecSet"));
+  let position = revoked_tokens.iter().position(|id| *id == token_id);
+  match (revoke, position) {
+    (true, None) => revoked_tokens.push(token_id),
+    (false, Some(idx)) => {
+      revoked_tokens.remove(idx);
+    }
+    (true, Some(_)) => return abort(IOTA_FRAMEWORK_PACKAGE_ID, "vec_set", "insert", 0),
+    (false, None) => return abort(IOTA_FRAMEWORK_PACKAGE_ID, "vec_set", "remove", 1),
+  }
+  did_doc["revoked_tokens"] = values::vec_set(revoked_tokens.into_iter().map(|id| json!(id)));
+
+  Ok(vec![])
+}
+
+// ===== migration =====
+
+fn migration_migrate_alias_output(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [alias_output, registry, creation_timestamp, clock] = arguments(call)?;
+  let alias_output = session.take_object(alias_output, Some(&alias_output_tag()))?;
+  let registry_tag = values::struct_tag(session.package_id, "migration_registry", "MigrationRegistry", vec![]);
+  let registry = session.object_arg_mut(registry, &registry_tag)?;
+  let creation_timestamp = take_u64(session, creation_timestamp)?;
+  let now = timestamp_ms(session, clock)?;
+
+  // `alias_output::extract_assets`.
+  let alias = session.dynamic_object_field_remove(alias_output, LEGACY_ALIAS_FIELD_NAME)?;
+  let output_contents = session.object(alias_output).contents.clone();
+  let balance = values::as_u64(&output_contents["balance"]).expect("valid alias output");
+  let native_tokens = output_contents["native_tokens"].clone();
+  session.delete(alias_output);
+
+  // `migration::migrate_alias`.
+  let state_metadata = values::as_bytes(&session.object(alias).contents["state_metadata"]);
+  session.delete(alias);
+  let package = session.package_id;
+  match &state_metadata {
+    Some(data) if is_did_output(session, data)? => (),
+    _ => return abort(package, "migration", "migrate_alias", 1),
+  }
+
+  // `identity::new_with_migration_data`.
+  if values::as_u64(&now).expect("valid clock") < creation_timestamp {
+    return abort(package, "identity", "new_with_migration_data", 1);
+  }
+  let controllers = vec![(json!(session.sender), values::u64_value(1))];
+  let identity_id = new_identity(session, state_metadata, controllers, vec![], 1, now)?;
+  let identity = &mut session.object_mut(identity_id).contents;
+  identity["legacy_id"] = json!(alias);
+  identity["created"] = values::u64_value(creation_timestamp);
+
+  // `migration_registry::add`.
+  let entry_type =
+    StructTag::from_str("0x2::dynamic_field::Field<0x2::object::ID, 0x2::object::ID>").expect("valid struct tag");
+  session.dynamic_field_add(registry, alias.as_ref(), entry_type, json!(alias), json!(identity_id))?;
+
+  let identity_address = IotaAddress::from(identity_id);
+  let coin_id = session.fresh_id();
+  let coin = json!({ "id": values::uid(coin_id), "balance": values::u64_value(balance) });
+  session.new_object(coin_id, gas_coin_type(), coin, true);
+  session.transfer(coin_id, identity_address);
+
+  let native_tokens_id = values::as_uid(&native_tokens).expect("valid bag");
+  let bag_tag = values::struct_tag(IOTA_FRAMEWORK_PACKAGE_ID, "bag", "Bag", vec![]);
+  session.new_object(native_tokens_id, bag_tag, native_tokens, true);
+  session.transfer(native_tokens_id, identity_address);
+
+  Ok(vec![])
+}
+
+// ===== utils & public_vc =====
+
+fn utils_vec_map_from_keys_values(
+  session: &mut Session<'_>,
+  call: &ProgrammableMoveCall,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let [key_type, value_type] = type_arguments(call)?;
+  let [keys, vps] = arguments(call)?;
+  let keys = session.take_value(keys, &TypeTag::Vector(Box::new(key_type.clone())))?;
+  let vps = session.take_value(vps, &TypeTag::Vector(Box::new(value_type.clone())))?;
+  let keys = keys.as_array().expect("valid vector");
+  let vps = vps.as_array().expect("valid vector");
+  if keys.len() != vps.len() {
+    return abort(session.package_id, "utils", "vec_map_from_keys_values", 0);
+  }
+  let mut entries: Vec<(Value, Value)> = Vec::with_capacity(keys.len());
+  for (key, value) in keys.iter().zip(vps) {
+    if entries.iter().any(|(existing, _)| existing == key) {
+      return abort(IOTA_FRAMEWORK_PACKAGE_ID, "vec_map", "insert", 0);
+    }
+    entries.push((key.clone(), value.clone()));
+  }
+
+  Ok(vec![RuntimeValue::Data(DataValue::plain(
+    vec_map_type(key_type, value_type),
+    values::vec_map(entries),
+  ))])
+}
+
+fn public_vc_new(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [] = type_arguments(call)?;
+  let [data] = arguments(call)?;
+  let data = session.take_value(data, &TypeTag::Vector(Box::new(TypeTag::U8)))?;
+  let public_vc_type = values::struct_type(session.package_id, "public_vc", "PublicVc", vec![]);
+
+  Ok(vec![RuntimeValue::Data(DataValue::linear(
+    public_vc_type,
+    json!({ "data": data }),
+  ))])
+}
+
+// ===== asset =====
+
+fn new_asset(
+  session: &mut Session<'_>,
+  inner_type: TypeTag,
+  inner: Value,
+  mutable: bool,
+  transferable: bool,
+  deletable: bool,
+) -> ExecResult<Vec<RuntimeValue>> {
+  let id = session.fresh_id();
+  let sender = session.sender;
+  let asset = json!({
+    "id": values::uid(id),
+    "inner": inner,
+    "origin": sender,
+    "owner": sender,
+    "mutable": mutable,
+    "transferable": transferable,
+    "deletable": deletable,
+  });
+  session.new_object(id, asset_tag(session.package_id, inner_type), asset, false);
+  session.transfer(id, sender);
+
+  Ok(vec![])
+}
+
+fn asset_new(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [inner_type] = type_arguments(call)?;
+  let [inner] = arguments(call)?;
+  let inner = session.take_value(inner, &inner_type)?;
+
+  new_asset(session, inner_type, inner, false, false, false)
+}
+
+fn asset_new_with_config(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [inner_type] = type_arguments(call)?;
+  let [inner, mutable, transferable, deletable] = arguments(call)?;
+  let inner = session.take_value(inner, &inner_type)?;
+  let mutable = take_bool(session, mutable)?;
+  let transferable = take_bool(session, transferable)?;
+  let deletable = take_bool(session, deletable)?;
+
+  new_asset(session, inner_type, inner, mutable, transferable, deletable)
+}
+
+fn asset_set_content(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [inner_type] = type_arguments(call)?;
+  let [asset, new_content] = arguments(call)?;
+  let package = session.package_id;
+  let asset = session.object_arg_mut(asset, &asset_tag(package, inner_type.clone()))?;
+  let new_content = session.take_value(new_content, &inner_type)?;
+
+  let contents = &mut session.object_mut(asset).contents;
+  if contents["mutable"].as_bool() != Some(true) {
+    return abort(package, "asset", "set_content", 0);
+  }
+  contents["inner"] = new_content;
+
+  Ok(vec![])
+}
+
+fn asset_delete(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [inner_type] = type_arguments(call)?;
+  let [asset] = arguments(call)?;
+  let package = session.package_id;
+  let asset = session.take_object(asset, Some(&asset_tag(package, inner_type)))?;
+  if session.object(asset).contents["deletable"].as_bool() != Some(true) {
+    return abort(package, "asset", "delete", 2);
+  }
+  session.delete(asset);
+
+  Ok(vec![])
+}
+
+fn asset_transfer(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [inner_type] = type_arguments(call)?;
+  let [asset, recipient] = arguments(call)?;
+  let package = session.package_id;
+  let asset = session.take_object(asset, Some(&asset_tag(package, inner_type)))?;
+  let recipient = take_address(session, recipient)?;
+
+  let contents = &session.object(asset).contents;
+  if contents["transferable"].as_bool() != Some(true) {
+    return abort(package, "asset", "transfer", 1);
+  }
+  let owner = values::as_address(&contents["owner"]).expect("valid asset");
+  let proposal_id = session.fresh_id();
+  let sender_cap_id = session.fresh_id();
+  let recipient_cap_id = session.fresh_id();
+  session.emit(
+    "asset",
+    "AssetTransferCreated",
+    vec![
+      ("asset", EventField::Id(asset)),
+      ("proposal", EventField::Id(proposal_id)),
+      ("sender", EventField::Address(owner)),
+      ("recipient", EventField::Address(recipient)),
+    ],
+  );
+
+  let sender_cap = json!({ "id": values::uid(sender_cap_id), "transfer_id": proposal_id });
+  session.new_object(sender_cap_id, sender_cap_tag(package), sender_cap, false);
+  session.transfer(sender_cap_id, owner);
+  let recipient_cap = json!({ "id": values::uid(recipient_cap_id), "transfer_id": proposal_id });
+  session.new_object(recipient_cap_id, recipient_cap_tag(package), recipient_cap, false);
+  session.transfer(recipient_cap_id, recipient);
+  session.transfer(asset, proposal_id.into());
+
+  let proposal = json!({
+    "id": values::uid(proposal_id),
+    "asset_id": asset,
+    "sender_address": owner,
+    "sender_cap_id": sender_cap_id,
+    "recipient_address": recipient,
+    "recipient_cap_id": recipient_cap_id,
+    "done": false,
+  });
+  session.new_object(proposal_id, transfer_proposal_tag(package), proposal, false);
+  session.share(proposal_id);
+
+  Ok(vec![])
+}
+
+fn emit_transfer_concluded(session: &mut Session<'_>, proposal: &Value, concluded: bool) {
+  session.emit(
+    "asset",
+    "AssetTransferConcluded",
+    vec![
+      (
+        "asset",
+        EventField::Id(values::as_id(&proposal["asset_id"]).expect("valid proposal")),
+      ),
+      (
+        "proposal",
+        EventField::Id(values::as_uid(&proposal["id"]).expect("valid proposal")),
+      ),
+      (
+        "sender",
+        EventField::Address(values::as_address(&proposal["sender_address"]).expect("valid proposal")),
+      ),
+      (
+        "recipient",
+        EventField::Address(values::as_address(&proposal["recipient_address"]).expect("valid proposal")),
+      ),
+      ("concluded", EventField::Bool(concluded)),
+    ],
+  );
+}
+
+fn asset_accept(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [inner_type] = type_arguments(call)?;
+  let [proposal, cap, receiving] = arguments(call)?;
+  let package = session.package_id;
+  let proposal = session.object_arg_mut(proposal, &transfer_proposal_tag(package))?;
+  let cap = session.take_object(cap, Some(&recipient_cap_tag(package)))?;
+
+  let contents = session.object(proposal).contents.clone();
+  if values::as_id(&contents["recipient_cap_id"]) != Some(cap) {
+    return abort(package, "asset", "accept", 3);
+  }
+  let asset = receive(session, proposal, receiving, &asset_tag(package, inner_type), false)?;
+  if values::as_id(&contents["asset_id"]) != Some(asset) {
+    return abort(package, "asset", "accept", 5);
+  }
+  let recipient = values::as_address(&contents["recipient_address"]).expect("valid proposal");
+  session.object_mut(asset).contents["owner"] = json!(recipient);
+  session.transfer(asset, recipient);
+  session.delete(cap);
+  session.object_mut(proposal).contents["done"] = Value::Bool(true);
+  emit_transfer_concluded(session, &contents, true);
+
+  Ok(vec![])
+}
+
+fn asset_conclude_or_cancel(session: &mut Session<'_>, call: &ProgrammableMoveCall) -> ExecResult<Vec<RuntimeValue>> {
+  let [inner_type] = type_arguments(call)?;
+  let [proposal, cap, receiving] = arguments(call)?;
+  let package = session.package_id;
+  let proposal = session.take_object(proposal, Some(&transfer_proposal_tag(package)))?;
+  let cap = session.take_object(cap, Some(&sender_cap_tag(package)))?;
+
+  let contents = session.object(proposal).contents.clone();
+  if values::as_id(&contents["sender_cap_id"]) != Some(cap) {
+    return abort(package, "asset", "conclude_or_cancel", 4);
+  }
+  if contents["done"].as_bool() != Some(true) {
+    let asset = receive(session, proposal, receiving, &asset_tag(package, inner_type), false)?;
+    if values::as_id(&contents["asset_id"]) != Some(asset) {
+      return abort(package, "asset", "conclude_or_cancel", 5);
+    }
+    let sender = values::as_address(&contents["sender_address"]).expect("valid proposal");
+    session.transfer(asset, sender);
+    emit_transfer_concluded(session, &contents, false);
+  }
+  session.delete(proposal);
+  session.delete(cap);
+
+  Ok(vec![])
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashSet;
+  use std::path::Path;
+  use std::path::PathBuf;
+
+  use super::super::state::hash;
+  use super::FUNCTIONS;
+
+  /// Digest of the identity Move package this module models, as computed by [`package_digest`].
+  const PACKAGE_DIGEST: &str = "ea5d1851b7f3aa1d06efba0b6949f18bf5b14896bbb60ac4a38344f290df3042";
+
+  /// Returns the paths, relative to the package's directory, and the contents of the package's manifest
+  /// and sources, sorted by path.
+  fn package_files() -> Vec<(String, String)> {
+    fn read(root: &Path, path: &Path) -> (String, String) {
+      let relative_path = path
+        .strip_prefix(root)
+        .expect("file is part of the package")
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
+      let contents = std::fs::read_to_string(path).expect("readable package file");
+      (relative_path, contents.replace("\r\n", "\n"))
+    }
+
+    fn read_sources(root: &Path, dir: &Path, files: &mut Vec<(String, String)>) {
+      for entry in std::fs::read_dir(dir).expect("readable package directory") {
+        let path = entry.expect("readable directory entry").path();
+        if path.is_dir() {
+          read_sources(root, &path, files);
+        } else if path.extension().is_some_and(|extension| extension == "move") {
+          files.push(read(root, &path));
+        }
+      }
+    }
+
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("packages/iota_identity");
+    let mut files = vec![read(&root, &root.join("Move.toml"))];
+    read_sources(&root, &root.join("sources"), &mut files);
+    files.sort();
+
+    files
+  }
+
+  /// Hashes the manifest and the sources of the identity Move package.
+  fn package_digest() -> String {
+    let files = package_files();
+    let parts: Vec<&[u8]> = files
+      .iter()
+      .flat_map(|(path, contents)| [path.as_bytes(), contents.as_bytes()])
+      .collect();
+
+    hash(&parts).iter().map(|byte| format!("{byte:02x}")).collect()
+  }
+
+  /// Returns the `public` and `entry` functions of the identity Move package, by module and name.
+  fn callable_functions() -> HashSet<(String, String)> {
+    let mut functions = HashSet::new();
+    for (_, contents) in package_files() {
+      let mut module = "";
+      for line in contents.lines().map(str::trim_start) {
+        if let Some(declaration) = line.strip_prefix("module iota_identity::") {
+          module = declaration.split([' ', '{', ';']).next().unwrap_or_default();
+          continue;
+        }
+        let signature = ["public fun ", "entry fun ", "public entry fun "]
+          .iter()
+          .find_map(|prefix| line.strip_prefix(prefix));
+        if let Some(signature) = signature {
+          let name = signature.split(['<', '(']).next().unwrap_or_default();
+          functions.insert((module.to_owned(), name.trim().to_owned()));
+        }
+      }
+    }
+
+    functions
+  }
+
+  #[test]
+  fn move_package_is_unchanged() {
+    assert_eq!(
+      package_digest(),
+      PACKAGE_DIGEST,
+      "the identity Move package changed: update its model in this module, then `PACKAGE_DIGEST`"
+    );
+  }
+
+  #[test]
+  fn modeled_functions_are_callable_functions_of_the_move_package() {
+    let callable = callable_functions();
+    for (module, function, _) in FUNCTIONS {
+      assert!(
+        callable.contains(&(module.to_string(), function.to_string())),
+        "{module}::{function} is not a public function of the identity Move package"
+      );
+    }
+  }
+}
@@ -0,0 +1,445 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An in-memory IOTA ledger that runs identity transactions without an IOTA node.
+//!
+//! [`LocalLedger`] models objects, ownership, shared objects, versions, events and gas payments,
+//! together with the semantics of the identity Move package, closely enough to create and manage
+//! identities, proposals and assets offline and deterministically. It is meant for tests:
+//! ```ignore
+//! let ledger = LocalLedger::new();
+//! let signer = ledger.new_signer(SignatureScheme::ED25519)?;
+//! ledger.request_funds(signer.address());
+//!
+//! let read_only_client = IdentityClientReadOnly::new_with_local_ledger(ledger).await?;
+//! let client = IdentityClient::new(read_only_client, signer).await?;
+//! ```
+//!
+//! # Limitations
+//! - Only programmable transactions calling the identity package, and the few framework functions it relies on, are
+//!   supported. Other transactions are rejected instead of failing during execution.
+//! - Packages can't be published nor upgraded. The only legacy Stardust outputs are the alias outputs added through
+//!   [`LocalLedger::add_legacy_alias`], which can only be migrated.
+//! - `MakeMoveVec` can only build vectors of non-object values.
+//! - Objects are only available in their parsed JSON representation, their BCS bytes are never returned.
+//! - Gas costs are an approximation of the costs of an IOTA node.
+//! - Wrapped objects are reported as deleted and effects' `modified_at_versions` is always empty.
+//! - Responses never contain the executed transaction's input; dry runs only report its sender and gas data.
+//! - Every transaction moves the ledger's clock forward by one second.
+
+mod api;
+mod executor;
+mod framework;
+mod identity_package;
+mod signer;
+mod state;
+mod values;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::time::Duration;
+
+use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::secp256k1::Secp256k1KeyPair;
+use fastcrypto::secp256r1::Secp256r1KeyPair;
+use fastcrypto::traits::KeyPair as _;
+use identity_iota_interaction::move_types::language_storage::StructTag;
+use identity_iota_interaction::rpc_types::BcsEvent;
+use identity_iota_interaction::rpc_types::IotaEvent;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::base_types::ObjectRef;
+use identity_iota_interaction::types::base_types::SequenceNumber;
+use identity_iota_interaction::types::crypto::IotaKeyPair;
+use identity_iota_interaction::types::crypto::SignatureScheme;
+use identity_iota_interaction::types::digests::ObjectDigest;
+use identity_iota_interaction::types::digests::TransactionDigest;
+use identity_iota_interaction::types::event::EventID;
+use identity_iota_interaction::types::object::Owner;
+use identity_iota_interaction::types::IOTA_CLOCK_OBJECT_ID;
+use identity_iota_interaction::types::IOTA_CLOCK_OBJECT_SHARED_VERSION;
+use identity_iota_interaction::types::IOTA_FRAMEWORK_PACKAGE_ID;
+use rand::rngs::StdRng;
+use rand::SeedableRng as _;
+use serde_json::json;
+use serde_json::Value;
+
+use self::state::hash;
+use self::state::LedgerState;
+use self::state::StoredObject;
+use self::values::EventField;
+use crate::rebased::Error;
+
+pub use signer::LocalSigner;
+
+/// Amount of NANOs sent by [`LocalLedger::request_funds`]: 1000 IOTA.
+const FAUCET_AMOUNT: u64 = 1_000_000_000_000;
+
+/// An in-memory IOTA ledger, with the identity package already published.
+///
+/// Cloning a [`LocalLedger`] returns a handle to the same ledger.
+#[derive(Clone)]
+pub struct LocalLedger(Arc<Mutex<LedgerState>>);
+
+impl Default for LocalLedger {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl LocalLedger {
+  /// Creates a new ledger, containing the identity package and its migration registry.
+  pub fn new() -> Self {
+    let mut state = LedgerState::empty();
+    state.package_id = ObjectID::new(hash(&[b"local-ledger-identity-package"]));
+    let genesis = state.next_system_digest();
+
+    let clock_type = values::struct_tag(IOTA_FRAMEWORK_PACKAGE_ID, "clock", "Clock", vec![]);
+    let clock_contents = json!({
+      "id": values::uid(IOTA_CLOCK_OBJECT_ID),
+      "timestamp_ms": values::u64_value(state.timestamp_ms),
+    });
+    let clock = genesis_object(
+      IOTA_CLOCK_OBJECT_ID,
+      IOTA_CLOCK_OBJECT_SHARED_VERSION,
+      clock_type,
+      clock_contents,
+      genesis,
+    );
+    state.write(clock);
+
+    let registry_id = ObjectID::derive_id(genesis, 0);
+    let registry_type = values::struct_tag(state.package_id, "migration_registry", "MigrationRegistry", vec![]);
+    let registry = genesis_object(
+      registry_id,
+      SequenceNumber::from_u64(1),
+      registry_type,
+      json!({ "id": values::uid(registry_id) }),
+      genesis,
+    );
+    state.write(registry);
+    state.migration_registry_id = registry_id;
+
+    let (parsed_json, bcs) = values::encode_event(vec![
+      ("id", EventField::Id(registry_id)),
+      ("beacon", EventField::Bytes(b"identity.rs_pkg".to_vec())),
+    ]);
+    state.events.push(IotaEvent {
+      id: EventID {
+        tx_digest: genesis,
+        event_seq: 0,
+      },
+      package_id: state.package_id,
+      transaction_module: "migration_registry".parse().expect("valid identifier"),
+      sender: IotaAddress::ZERO,
+      type_: values::struct_tag(
+        state.package_id,
+        "migration_registry",
+        "MigrationRegistryCreated",
+        vec![],
+      ),
+      parsed_json,
+      bcs: BcsEvent::new(bcs),
+      timestamp_ms: Some(state.timestamp_ms),
+    });
+
+    Self(Arc::new(Mutex::new(state)))
+  }
+
+  pub(crate) fn state(&self) -> MutexGuard<'_, LedgerState> {
+    self.0.lock().expect("local ledger's lock is never poisoned")
+  }
+
+  /// Returns the ID of the identity package.
+  pub fn package_id(&self) -> ObjectID {
+    self.state().package_id
+  }
+
+  /// Returns the ID of the identity package's migration registry.
+  pub fn migration_registry_id(&self) -> ObjectID {
+    self.state().migration_registry_id
+  }
+
+  /// Returns the current epoch.
+  pub fn epoch(&self) -> u64 {
+    self.state().epoch
+  }
+
+  /// Starts a new epoch.
+  pub fn advance_epoch(&self) {
+    self.state().epoch += 1;
+  }
+
+  /// Moves the ledger's clock forward by `duration`.
+  pub fn advance_clock(&self, duration: Duration) {
+    let mut state = self.state();
+    state.timestamp_ms += u64::try_from(duration.as_millis()).expect("duration fits in a u64");
+    let timestamp_ms = values::u64_value(state.timestamp_ms);
+    if let Some(clock) = state.objects.get_mut(&IOTA_CLOCK_OBJECT_ID) {
+      clock.contents["timestamp_ms"] = timestamp_ms;
+    }
+  }
+
+  /// Sends a new gas coin of 1000 IOTA to `address`, returning its reference.
+  pub fn request_funds(&self, address: IotaAddress) -> ObjectRef {
+    executor::mint_coin(&mut self.state(), address, FAUCET_AMOUNT)
+  }
+
+  /// Adds a legacy Stardust alias, whose `AliasOutput` is owned by `owner`, returning the alias' ID.
+  ///
+  /// The alias stores `state_metadata`, e.g. a packed DID Document, and can then be migrated like an alias
+  /// of a network that went through the Stardust migration.
+  pub fn add_legacy_alias(&self, owner: IotaAddress, state_metadata: Option<&[u8]>) -> ObjectID {
+    executor::add_legacy_alias(&mut self.state(), owner, state_metadata)
+  }
+
+  /// Returns a new [`LocalSigner`] using a key of type `scheme`.
+  /// Keys are derived deterministically: the n-th signer of every ledger has the same key.
+  ///
+  /// # Failures
+  /// This function fails if `scheme` is not one of `ED25519`, `Secp256k1` or `Secp256r1`.
+  pub fn new_signer(&self, scheme: SignatureScheme) -> Result<LocalSigner, Error> {
+    let seed = {
+      let mut state = self.state();
+      state.key_count += 1;
+      state.key_count
+    };
+    let mut rng = StdRng::seed_from_u64(seed);
+    let keypair = match scheme {
+      SignatureScheme::ED25519 => IotaKeyPair::Ed25519(Ed25519KeyPair::generate(&mut rng)),
+      SignatureScheme::Secp256k1 => IotaKeyPair::Secp256k1(Secp256k1KeyPair::generate(&mut rng)),
+      SignatureScheme::Secp256r1 => IotaKeyPair::Secp256r1(Secp256r1KeyPair::generate(&mut rng)),
+      scheme => {
+        return Err(Error::InvalidKey(format!(
+          "signature scheme {scheme:?} is not supported by the local ledger"
+        )))
+      }
+    };
+
+    Ok(LocalSigner::new(keypair))
+  }
+}
+
+/// Returns a shared object that is part of the ledger's genesis.
+fn genesis_object(
+  id: ObjectID,
+  initial_shared_version: SequenceNumber,
+  type_: StructTag,
+  contents: Value,
+  genesis: TransactionDigest,
+) -> StoredObject {
+  let mut object = StoredObject {
+    id,
+    version: initial_shared_version,
+    digest: ObjectDigest::MIN,
+    type_,
+    owner: Owner::Shared { initial_shared_version },
+    previous_transaction: genesis,
+    storage_rebate: 0,
+    has_public_transfer: false,
+    contents,
+  };
+  object.refresh_digest();
+
+  object
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
+  use identity_iota_interaction::types::transaction::Argument;
+  use identity_iota_interaction::types::transaction::CallArg;
+  use identity_iota_interaction::types::transaction::Command;
+  use identity_iota_interaction::types::transaction::ProgrammableMoveCall;
+  use identity_iota_interaction::types::transaction::ProgrammableTransaction;
+  use identity_iota_interaction::types::transaction::TransactionData;
+  use secret_storage::Signer as _;
+
+  use super::state::GENESIS_TIMESTAMP_MS;
+  use super::state::REFERENCE_GAS_PRICE;
+  use super::*;
+
+  const GAS_BUDGET: u64 = 10_000_000;
+
+  /// Returns a transaction of `sender` paying `amount` NANOs from its gas coin to `recipient`.
+  fn payment(sender: IotaAddress, gas: ObjectRef, recipient: IotaAddress, amount: u64) -> TransactionData {
+    let pt = ProgrammableTransaction {
+      inputs: vec![
+        CallArg::Pure(amount.to_le_bytes().to_vec()),
+        CallArg::Pure(recipient.as_ref().to_vec()),
+      ],
+      commands: vec![
+        Command::SplitCoins(Argument::GasCoin, vec![Argument::Input(0)]),
+        Command::TransferObjects(vec![Argument::NestedResult(0, 0)], Argument::Input(1)),
+      ],
+    };
+    TransactionData::new_programmable(sender, vec![gas], pt, GAS_BUDGET, REFERENCE_GAS_PRICE)
+  }
+
+  /// Returns a transaction of `sender` calling `package::module::function` without arguments.
+  fn move_call(
+    sender: IotaAddress,
+    gas: ObjectRef,
+    package: ObjectID,
+    module: &str,
+    function: &str,
+  ) -> TransactionData {
+    let pt = ProgrammableTransaction {
+      inputs: vec![],
+      commands: vec![Command::MoveCall(Box::new(ProgrammableMoveCall {
+        package,
+        module: module.parse().unwrap(),
+        function: function.parse().unwrap(),
+        type_arguments: vec![],
+        arguments: vec![],
+      }))],
+    };
+    TransactionData::new_programmable(sender, vec![gas], pt, GAS_BUDGET, REFERENCE_GAS_PRICE)
+  }
+
+  fn balance(ledger: &LocalLedger, coin: ObjectID) -> Option<u64> {
+    ledger.state().objects.get(&coin).and_then(StoredObject::coin_balance)
+  }
+
+  #[test]
+  fn new_ledger_contains_clock_and_migration_registry() {
+    let ledger = LocalLedger::new();
+    let state = ledger.state();
+
+    let clock = &state.objects[&IOTA_CLOCK_OBJECT_ID];
+    assert_eq!(
+      values::as_u64(&clock.contents["timestamp_ms"]),
+      Some(GENESIS_TIMESTAMP_MS)
+    );
+    assert!(matches!(clock.owner, Owner::Shared { .. }));
+
+    let registry = &state.objects[&state.migration_registry_id];
+    assert_eq!(registry.type_.module.as_str(), "migration_registry");
+    assert_eq!(ObjectID::from(registry.type_.address), state.package_id);
+
+    let [event] = state.events.as_slice() else {
+      panic!("genesis emits a single event");
+    };
+    assert_eq!(event.type_.name.as_str(), "MigrationRegistryCreated");
+    assert_eq!(event.parsed_json["id"], json!(state.migration_registry_id));
+  }
+
+  #[test]
+  fn ledgers_are_deterministic() {
+    let (first, second) = (LocalLedger::new(), LocalLedger::new());
+    assert_eq!(first.package_id(), second.package_id());
+    assert_eq!(first.migration_registry_id(), second.migration_registry_id());
+
+    let first_signers = [
+      first.new_signer(SignatureScheme::ED25519).unwrap(),
+      first.new_signer(SignatureScheme::ED25519).unwrap(),
+    ];
+    let second_signers = [
+      second.new_signer(SignatureScheme::ED25519).unwrap(),
+      second.new_signer(SignatureScheme::ED25519).unwrap(),
+    ];
+    assert_eq!(first_signers[0].address(), second_signers[0].address());
+    assert_eq!(first_signers[1].address(), second_signers[1].address());
+    assert_ne!(first_signers[0].address(), first_signers[1].address());
+  }
+
+  #[test]
+  fn signers_only_support_iota_key_schemes() {
+    let ledger = LocalLedger::new();
+    for scheme in [
+      SignatureScheme::ED25519,
+      SignatureScheme::Secp256k1,
+      SignatureScheme::Secp256r1,
+    ] {
+      assert!(ledger.new_signer(scheme).is_ok());
+    }
+    assert!(matches!(
+      ledger.new_signer(SignatureScheme::BLS12381),
+      Err(Error::InvalidKey(_))
+    ));
+  }
+
+  #[test]
+  fn requested_funds_are_a_gas_coin_of_the_address() {
+    let ledger = LocalLedger::new();
+    let address = ledger.new_signer(SignatureScheme::ED25519).unwrap().address();
+
+    let (coin_id, _, _) = ledger.request_funds(address);
+    let state = ledger.state();
+    let coin = &state.objects[&coin_id];
+    assert!(coin.is_gas_coin());
+    assert_eq!(coin.owner, Owner::AddressOwner(address));
+    assert_eq!(coin.coin_balance(), Some(FAUCET_AMOUNT));
+  }
+
+  #[test]
+  fn clock_and_epoch_advance() {
+    let ledger = LocalLedger::new();
+    ledger.advance_clock(Duration::from_secs(60));
+    ledger.advance_epoch();
+
+    let state = ledger.state();
+    let clock = &state.objects[&IOTA_CLOCK_OBJECT_ID];
+    assert_eq!(
+      values::as_u64(&clock.contents["timestamp_ms"]),
+      Some(GENESIS_TIMESTAMP_MS + 60_000)
+    );
+    assert_eq!(state.epoch, 1);
+  }
+
+  #[tokio::test]
+  async fn signed_transactions_are_executed_and_charged() {
+    let ledger = LocalLedger::new();
+    let sender = ledger.new_signer(SignatureScheme::ED25519).unwrap();
+    let recipient = ledger.new_signer(SignatureScheme::ED25519).unwrap().address();
+    let gas = ledger.request_funds(sender.address());
+
+    let tx_data = payment(sender.address(), gas, recipient, 1_000);
+    let signature = sender.sign(&tx_data).await.unwrap();
+    let response = executor::execute(&mut ledger.state(), &tx_data, Some(&[signature])).unwrap();
+
+    let effects = response.effects.expect("effects are returned");
+    assert!(effects.status().is_ok());
+    let [created] = effects.created() else {
+      panic!("the payment creates a single coin");
+    };
+    assert_eq!(created.owner, Owner::AddressOwner(recipient));
+    assert_eq!(balance(&ledger, created.object_id()), Some(1_000));
+
+    let gas_used = effects.gas_cost_summary().net_gas_usage();
+    let remaining = i128::from(FAUCET_AMOUNT) - 1_000 - i128::from(gas_used);
+    assert_eq!(balance(&ledger, gas.0).map(i128::from), Some(remaining));
+  }
+
+  #[test]
+  fn transactions_without_a_valid_signature_are_rejected() {
+    let ledger = LocalLedger::new();
+    let sender = ledger.new_signer(SignatureScheme::ED25519).unwrap().address();
+    let gas = ledger.request_funds(sender);
+
+    let tx_data = payment(sender, gas, IotaAddress::ZERO, 1_000);
+    let error = executor::execute(&mut ledger.state(), &tx_data, Some(&[])).unwrap_err();
+    assert!(error.contains("missing a valid signature"), "{error}");
+    assert_eq!(balance(&ledger, gas.0), Some(FAUCET_AMOUNT));
+  }
+
+  #[test]
+  fn unsupported_calls_are_rejected() {
+    let ledger = LocalLedger::new();
+    let sender = ledger.new_signer(SignatureScheme::ED25519).unwrap().address();
+    let gas = ledger.request_funds(sender);
+    let unknown_package = ObjectID::from_single_byte(0x42);
+
+    for (package, module, function) in [
+      (unknown_package, "module", "function"),
+      (ledger.package_id(), "identity", "unknown_function"),
+    ] {
+      let tx_data = move_call(sender, gas, package, module, function);
+      // Unsupported transactions are rejected rather than failing during execution.
+      assert!(executor::execute(&mut ledger.state(), &tx_data, None).is_err());
+      assert!(ledger.state().transactions.is_empty());
+      assert_eq!(balance(&ledger, gas.0), Some(FAUCET_AMOUNT));
+    }
+  }
+}
@@ -0,0 +1,61 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use identity_iota_interaction::shared_crypto::intent::Intent;
+use identity_iota_interaction::shared_crypto::intent::IntentMessage;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::crypto::IotaKeyPair;
+use identity_iota_interaction::types::crypto::PublicKey;
+use identity_iota_interaction::types::crypto::Signature;
+use identity_iota_interaction::types::transaction::TransactionData;
+use identity_iota_interaction::IotaKeySignature;
+use secret_storage::Error as SecretStorageError;
+use secret_storage::Signer;
+
+/// A [`Signer`] holding its private key in memory, obtained through
+/// [`LocalLedger::new_signer`](super::LocalLedger::new_signer).
+///
+/// # Warning
+/// This signer is meant for tests only.
+#[derive(Clone)]
+pub struct LocalSigner {
+  keypair: Arc<IotaKeyPair>,
+  address: IotaAddress,
+}
+
+impl LocalSigner {
+  pub(crate) fn new(keypair: IotaKeyPair) -> Self {
+    let address = IotaAddress::from(&keypair.public());
+    Self {
+      keypair: Arc::new(keypair),
+      address,
+    }
+  }
+
+  /// Returns the address of this signer.
+  pub fn address(&self) -> IotaAddress {
+    self.address
+  }
+}
+
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+impl Signer<IotaKeySignature> for LocalSigner {
+  type KeyId = IotaAddress;
+
+  fn key_id(&self) -> Self::KeyId {
+    self.address
+  }
+
+  async fn public_key(&self) -> Result<PublicKey, SecretStorageError> {
+    Ok(self.keypair.public())
+  }
+
+  async fn sign(&self, data: &TransactionData) -> Result<Signature, SecretStorageError> {
+    let intent_msg = IntentMessage::new(Intent::iota_transaction(), data.clone());
+    Ok(Signature::new_secure(&intent_msg, self.keypair.as_ref()))
+  }
+}
@@ -0,0 +1,204 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use fastcrypto::hash::Blake2b256;
+use fastcrypto::hash::HashFunction;
+use identity_iota_interaction::move_types::language_storage::StructTag;
+use identity_iota_interaction::rpc_types::IotaEvent;
+use identity_iota_interaction::rpc_types::IotaObjectData;
+use identity_iota_interaction::rpc_types::IotaObjectDataOptions;
+use identity_iota_interaction::rpc_types::IotaParsedData;
+use identity_iota_interaction::rpc_types::IotaTransactionBlockResponse;
+use identity_iota_interaction::types::base_types::MoveObjectType;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::base_types::ObjectRef;
+use identity_iota_interaction::types::base_types::ObjectType;
+use identity_iota_interaction::types::base_types::SequenceNumber;
+use identity_iota_interaction::types::digests::ObjectDigest;
+use identity_iota_interaction::types::digests::TransactionDigest;
+use identity_iota_interaction::types::object::Owner;
+use serde_json::json;
+use serde_json::Value;
+
+use super::values;
+
+/// Type of IOTA's gas coins.
+pub(crate) const GAS_COIN_TYPE: &str = "0x2::coin::Coin<0x2::iota::IOTA>";
+/// The chain identifier reported by the local ledger.
+pub(crate) const CHAIN_IDENTIFIER: &str = "10ca1ed9";
+/// Reference gas price of the local ledger.
+pub(crate) const REFERENCE_GAS_PRICE: u64 = 1_000;
+/// Timestamp of the local ledger's genesis: 2025-01-01T00:00:00Z.
+pub(crate) const GENESIS_TIMESTAMP_MS: u64 = 1_735_689_600_000;
+
+pub(crate) fn gas_coin_type() -> StructTag {
+  StructTag::from_str(GAS_COIN_TYPE).expect("valid struct tag")
+}
+
+/// Hashes `parts` into 32 bytes, used to derive digests on the local ledger.
+pub(crate) fn hash(parts: &[&[u8]]) -> [u8; 32] {
+  let mut hasher = Blake2b256::default();
+  for part in parts {
+    hasher.update((part.len() as u64).to_le_bytes());
+    hasher.update(part);
+  }
+
+  hasher.finalize().digest
+}
+
+/// A Move object stored on the local ledger.
+/// Its contents are kept in the same JSON representation IOTA nodes use when returning parsed objects.
+#[derive(Debug, Clone)]
+pub(crate) struct StoredObject {
+  pub(crate) id: ObjectID,
+  pub(crate) version: SequenceNumber,
+  pub(crate) digest: ObjectDigest,
+  pub(crate) type_: StructTag,
+  pub(crate) owner: Owner,
+  pub(crate) previous_transaction: TransactionDigest,
+  pub(crate) storage_rebate: u64,
+  pub(crate) has_public_transfer: bool,
+  pub(crate) contents: Value,
+}
+
+impl StoredObject {
+  pub(crate) fn object_ref(&self) -> ObjectRef {
+    (self.id, self.version, self.digest)
+  }
+
+  /// Recomputes this object's digest from its current state.
+  pub(crate) fn refresh_digest(&mut self) {
+    let owner = format!("{:?}", self.owner);
+    let contents = self.contents.to_string();
+    let digest = hash(&[
+      self.id.as_ref(),
+      &self.version.value().to_le_bytes(),
+      self.type_.to_canonical_string(true).as_bytes(),
+      owner.as_bytes(),
+      contents.as_bytes(),
+    ]);
+    self.digest = ObjectDigest::new(digest);
+  }
+
+  /// Approximate size of this object, used to compute storage costs.
+  pub(crate) fn size(&self) -> u64 {
+    self.contents.to_string().len() as u64 + 100
+  }
+
+  pub(crate) fn is_coin(&self) -> bool {
+    values::is_struct(&self.type_, ObjectID::from_single_byte(2), "coin", "Coin")
+  }
+
+  pub(crate) fn is_gas_coin(&self) -> bool {
+    self.type_ == gas_coin_type()
+  }
+
+  pub(crate) fn coin_balance(&self) -> Option<u64> {
+    self
+      .is_coin()
+      .then(|| self.contents.get("balance").and_then(values::as_u64))
+      .flatten()
+  }
+
+  pub(crate) fn set_coin_balance(&mut self, balance: u64) {
+    self.contents["balance"] = values::u64_value(balance);
+  }
+
+  pub(crate) fn parsed_data(&self) -> IotaParsedData {
+    serde_json::from_value(json!({
+      "dataType": "moveObject",
+      "type": self.type_.to_canonical_string(true),
+      "hasPublicTransfer": self.has_public_transfer,
+      "fields": self.contents,
+    }))
+    .expect("local ledger objects are valid parsed Move objects")
+  }
+
+  pub(crate) fn to_object_data(&self, options: &IotaObjectDataOptions) -> IotaObjectData {
+    IotaObjectData {
+      object_id: self.id,
+      version: self.version,
+      digest: self.digest,
+      type_: options
+        .show_type
+        .then(|| ObjectType::Struct(MoveObjectType::from(self.type_.clone()))),
+      owner: options.show_owner.then_some(self.owner),
+      previous_transaction: options.show_previous_transaction.then_some(self.previous_transaction),
+      storage_rebate: options.show_storage_rebate.then_some(self.storage_rebate),
+      display: None,
+      content: options.show_content.then(|| self.parsed_data()),
+      // Objects are not kept in their Move BCS layout.
+      bcs: None,
+    }
+  }
+}
+
+/// The whole state of a [`LocalLedger`](super::LocalLedger).
+#[derive(Debug, Clone)]
+pub(crate) struct LedgerState {
+  pub(crate) package_id: ObjectID,
+  pub(crate) migration_registry_id: ObjectID,
+  /// Live objects.
+  pub(crate) objects: BTreeMap<ObjectID, StoredObject>,
+  /// Every version ever written of every object.
+  pub(crate) history: HashMap<ObjectID, BTreeMap<SequenceNumber, StoredObject>>,
+  /// References of deleted objects.
+  pub(crate) removed: HashMap<ObjectID, ObjectRef>,
+  pub(crate) transactions: HashMap<TransactionDigest, IotaTransactionBlockResponse>,
+  pub(crate) events: Vec<IotaEvent>,
+  pub(crate) epoch: u64,
+  pub(crate) timestamp_ms: u64,
+  pub(crate) gas_price: u64,
+  /// Counter used to derive digests for transactions that aren't submitted by users, e.g. faucet requests.
+  pub(crate) system_tx_count: u64,
+  /// Counter used to derive the keys of new signers.
+  pub(crate) key_count: u64,
+}
+
+impl LedgerState {
+  pub(crate) fn empty() -> Self {
+    Self {
+      package_id: ObjectID::ZERO,
+      migration_registry_id: ObjectID::ZERO,
+      objects: BTreeMap::new(),
+      history: HashMap::new(),
+      removed: HashMap::new(),
+      transactions: HashMap::new(),
+      events: Vec::new(),
+      epoch: 0,
+      timestamp_ms: GENESIS_TIMESTAMP_MS,
+      gas_price: REFERENCE_GAS_PRICE,
+      system_tx_count: 0,
+      key_count: 0,
+    }
+  }
+
+  /// Returns a fresh digest for a transaction that is issued by the ledger itself.
+  pub(crate) fn next_system_digest(&mut self) -> TransactionDigest {
+    let count = self.system_tx_count;
+    self.system_tx_count += 1;
+
+    TransactionDigest::new(hash(&[b"local-ledger-system-tx", &count.to_le_bytes()]))
+  }
+
+  /// Writes a new version of `object`.
+  pub(crate) fn write(&mut self, object: StoredObject) {
+    self.removed.remove(&object.id);
+    self
+      .history
+      .entry(object.id)
+      .or_default()
+      .insert(object.version, object.clone());
+    self.objects.insert(object.id, object);
+  }
+
+  /// Removes an object from the live set, remembering the reference it was removed with.
+  pub(crate) fn remove(&mut self, object_ref: ObjectRef) {
+    self.objects.remove(&object_ref.0);
+    self.removed.insert(object_ref.0, object_ref);
+  }
+}
@@ -0,0 +1,258 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions between BCS encoded Move values and the JSON representation used by IOTA nodes.
+
+use identity_iota_interaction::move_types::identifier::Identifier;
+use identity_iota_interaction::move_types::language_storage::StructTag;
+use identity_iota_interaction::move_types::u256::U256;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::TypeTag;
+use serde_json::json;
+use serde_json::Value;
+
+/// Decodes the BCS encoded `bytes` of a pure argument of type `type_` into its JSON representation.
+pub(crate) fn pure_to_json(type_: &TypeTag, bytes: &[u8]) -> Result<Value, String> {
+  let mut reader = BcsReader { bytes, pos: 0 };
+  let value = reader.read(type_)?;
+  if reader.pos != bytes.len() {
+    return Err(format!("trailing bytes after a value of type {type_}"));
+  }
+
+  Ok(value)
+}
+
+/// Returns `true` if `tag` is `<address>::<module>::<name>`, regardless of its type parameters.
+pub(crate) fn is_struct(tag: &StructTag, address: ObjectID, module: &str, name: &str) -> bool {
+  ObjectID::from(tag.address) == address && tag.module.as_str() == module && tag.name.as_str() == name
+}
+
+/// Builds the struct tag `<address>::<module>::<name><type_params>`.
+pub(crate) fn struct_tag(address: ObjectID, module: &str, name: &str, type_params: Vec<TypeTag>) -> StructTag {
+  StructTag {
+    address: address.into(),
+    module: Identifier::new(module).expect("valid identifier"),
+    name: Identifier::new(name).expect("valid identifier"),
+    type_params,
+  }
+}
+
+/// Builds the type `<address>::<module>::<name><type_params>`.
+pub(crate) fn struct_type(address: ObjectID, module: &str, name: &str, type_params: Vec<TypeTag>) -> TypeTag {
+  TypeTag::Struct(Box::new(struct_tag(address, module, name, type_params)))
+}
+
+struct BcsReader<'b> {
+  bytes: &'b [u8],
+  pos: usize,
+}
+
+impl<'b> BcsReader<'b> {
+  fn take(&mut self, n: usize) -> Result<&'b [u8], String> {
+    let end = self.pos.checked_add(n).filter(|end| *end <= self.bytes.len());
+    let Some(end) = end else {
+      return Err("unexpected end of input".to_owned());
+    };
+    let slice = &self.bytes[self.pos..end];
+    self.pos = end;
+
+    Ok(slice)
+  }
+
+  fn take_array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+    Ok(self.take(N)?.try_into().expect("slice has length N"))
+  }
+
+  fn uleb128(&mut self) -> Result<usize, String> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+      let byte = self.take(1)?[0];
+      value |= u64::from(byte & 0x7f) << shift;
+      if byte & 0x80 == 0 {
+        return usize::try_from(value).map_err(|e| e.to_string());
+      }
+    }
+
+    Err("invalid ULEB128 length".to_owned())
+  }
+
+  fn read(&mut self, type_: &TypeTag) -> Result<Value, String> {
+    let value = match type_ {
+      TypeTag::Bool => match self.take(1)?[0] {
+        0 => Value::Bool(false),
+        1 => Value::Bool(true),
+        byte => return Err(format!("invalid boolean value {byte}")),
+      },
+      TypeTag::U8 => self.take(1)?[0].into(),
+      TypeTag::U16 => u16::from_le_bytes(self.take_array()?).into(),
+      TypeTag::U32 => u32::from_le_bytes(self.take_array()?).into(),
+      TypeTag::U64 => u64_value(u64::from_le_bytes(self.take_array()?)),
+      TypeTag::U128 => Value::String(u128::from_le_bytes(self.take_array()?).to_string()),
+      TypeTag::U256 => Value::String(U256::from_le_bytes(&self.take_array()?).to_string()),
+      TypeTag::Address | TypeTag::Signer => Value::String(
+        IotaAddress::from_bytes(self.take(32)?)
+          .map_err(|e| e.to_string())?
+          .to_string(),
+      ),
+      TypeTag::Vector(inner) => {
+        let len = self.uleb128()?;
+        (0..len)
+          .map(|_| self.read(inner))
+          .collect::<Result<Vec<_>, _>>()?
+          .into()
+      }
+      TypeTag::Struct(tag) => self.read_struct(tag)?,
+    };
+
+    Ok(value)
+  }
+
+  fn read_struct(&mut self, tag: &StructTag) -> Result<Value, String> {
+    let std = ObjectID::from_single_byte(1);
+    let framework = ObjectID::from_single_byte(2);
+
+    if is_struct(tag, std, "string", "String") || is_struct(tag, std, "ascii", "String") {
+      let len = self.uleb128()?;
+      let bytes = self.take(len)?;
+      std::str::from_utf8(bytes)
+        .map(|s| Value::String(s.to_owned()))
+        .map_err(|e| e.to_string())
+    } else if is_struct(tag, std, "option", "Option") && tag.type_params.len() == 1 {
+      match self.uleb128()? {
+        0 => Ok(Value::Null),
+        1 => self.read(&tag.type_params[0]),
+        _ => Err("an option cannot hold more than one value".to_owned()),
+      }
+    } else if is_struct(tag, framework, "object", "ID") {
+      self.read(&TypeTag::Address)
+    } else {
+      Err(format!("type {tag} cannot be used as a pure argument"))
+    }
+  }
+}
+
+/// JSON representation of a `u64`.
+pub(crate) fn u64_value(n: u64) -> Value {
+  Value::String(n.to_string())
+}
+
+/// JSON representation of a `UID`.
+pub(crate) fn uid(id: ObjectID) -> Value {
+  json!({ "id": id })
+}
+
+/// JSON representation of a `vector<u8>`.
+pub(crate) fn bytes_value(bytes: &[u8]) -> Value {
+  bytes.iter().copied().map(Value::from).collect::<Vec<_>>().into()
+}
+
+/// JSON representation of a `VecMap` with the given entries.
+pub(crate) fn vec_map(entries: impl IntoIterator<Item = (Value, Value)>) -> Value {
+  let contents: Vec<Value> = entries
+    .into_iter()
+    .map(|(key, value)| json!({ "key": key, "value": value }))
+    .collect();
+  json!({ "contents": contents })
+}
+
+/// JSON representation of a `VecSet` with the given items.
+pub(crate) fn vec_set(items: impl IntoIterator<Item = Value>) -> Value {
+  json!({ "contents": items.into_iter().collect::<Vec<_>>() })
+}
+
+/// Reads a `u64`, which nodes encode as a string but which may also come as a number.
+pub(crate) fn as_u64(value: &Value) -> Option<u64> {
+  match value {
+    Value::String(s) => s.parse().ok(),
+    Value::Number(n) => n.as_u64(),
+    _ => None,
+  }
+}
+
+pub(crate) fn as_id(value: &Value) -> Option<ObjectID> {
+  value.as_str().and_then(|s| s.parse().ok())
+}
+
+pub(crate) fn as_address(value: &Value) -> Option<IotaAddress> {
+  value.as_str().and_then(|s| s.parse().ok())
+}
+
+pub(crate) fn as_bytes(value: &Value) -> Option<Vec<u8>> {
+  value
+    .as_array()?
+    .iter()
+    .map(|byte| byte.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+    .collect()
+}
+
+/// Reads the ID out of a `UID`.
+pub(crate) fn as_uid(value: &Value) -> Option<ObjectID> {
+  as_id(value.get("id")?)
+}
+
+/// Returns the entries of a `VecMap`.
+pub(crate) fn vec_map_entries(value: &Value) -> Option<Vec<(Value, Value)>> {
+  value
+    .get("contents")?
+    .as_array()?
+    .iter()
+    .map(|entry| Some((entry.get("key")?.clone(), entry.get("value")?.clone())))
+    .collect()
+}
+
+/// Returns the items of a `VecSet`.
+pub(crate) fn vec_set_items(value: &Value) -> Option<Vec<Value>> {
+  value.get("contents")?.as_array().cloned()
+}
+
+/// A field of a Move event.
+pub(crate) enum EventField {
+  Id(ObjectID),
+  Address(IotaAddress),
+  Bool(bool),
+  U32(u32),
+  Bytes(Vec<u8>),
+}
+
+/// Encodes the fields of a Move event both as JSON and as BCS.
+pub(crate) fn encode_event(fields: Vec<(&str, EventField)>) -> (Value, Vec<u8>) {
+  let mut json = serde_json::Map::new();
+  let mut bcs = Vec::new();
+  for (name, field) in fields {
+    let value = match field {
+      EventField::Id(id) => {
+        bcs.extend_from_slice(id.as_ref());
+        json!(id)
+      }
+      EventField::Address(address) => {
+        bcs.extend_from_slice(address.as_ref());
+        json!(address)
+      }
+      EventField::Bool(b) => {
+        bcs.push(b as u8);
+        Value::Bool(b)
+      }
+      EventField::U32(n) => {
+        bcs.extend_from_slice(&n.to_le_bytes());
+        Value::from(n)
+      }
+      EventField::Bytes(bytes) => {
+        write_uleb128(&mut bcs, bytes.len());
+        bcs.extend_from_slice(&bytes);
+        bytes_value(&bytes)
+      }
+    };
+    json.insert(name.to_owned(), value);
+  }
+
+  (Value::Object(json), bcs)
+}
+
+fn write_uleb128(out: &mut Vec<u8>, mut n: usize) {
+  while n >= 0x80 {
+    out.push((n as u8 & 0x7f) | 0x80);
+    n >>= 7;
+  }
+  out.push(n as u8);
+}
@@ -0,0 +1,400 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use identity_core::common::Url;
+use identity_did::DID as _;
+use identity_iota_interaction::move_types::language_storage::StructTag;
+use identity_iota_interaction::rpc_types::IotaObjectDataFilter;
+use identity_iota_interaction::rpc_types::IotaObjectResponseQuery;
+use identity_iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::transaction::ProgrammableTransaction;
+use identity_iota_interaction::types::STARDUST_PACKAGE_ID;
+use identity_iota_interaction::IotaClientTrait;
+use identity_iota_interaction::IotaKeySignature;
+use identity_iota_interaction::OptionalSync;
+use secret_storage::Signer;
+use serde::Deserialize;
+use serde::Serialize;
+use tokio::sync::OnceCell;
+
+use crate::rebased::client::IdentityClient;
+use crate::rebased::client::IdentityClientReadOnly;
+use crate::rebased::transaction_batch::merge_programmable_transactions;
+use crate::rebased::transaction_builder::Transaction;
+use crate::rebased::transaction_builder::TransactionBuilder;
+use crate::rebased::Error;
+use crate::IotaDID;
+use crate::IotaDocument;
+use crate::StateMetadataDocument;
+
+use super::get_alias;
+use super::lookup;
+use super::Identity;
+use super::MigrateLegacyIdentity;
+use super::OnChainIdentity;
+use super::UnmigratedAlias;
+
+/// The default maximum number of legacy identities migrated in a single transaction.
+pub const DEFAULT_MIGRATION_BATCH_SIZE: usize = 25;
+
+/// The name of the dynamic field an `AliasOutput` stores its `Alias` in.
+const ALIAS_FIELD_NAME: &[u8] = b"alias";
+
+/// Returns all the [`UnmigratedAlias`]es whose `AliasOutput` is owned by `address`.
+pub async fn get_aliases_owned_by(
+  client: &IdentityClientReadOnly,
+  address: IotaAddress,
+) -> Result<Vec<UnmigratedAlias>, Error> {
+  let tag = StructTag::from_str(&format!(
+    "{STARDUST_PACKAGE_ID}::alias_output::AliasOutput<0x2::iota::IOTA>"
+  ))
+  .expect("valid move type");
+  let filter = IotaObjectResponseQuery::new_with_filter(IotaObjectDataFilter::StructType(tag));
+
+  let mut alias_output_ids = vec![];
+  let mut cursor = None;
+  loop {
+    let page = client
+      .read_api()
+      .get_owned_objects(address, Some(filter.clone()), cursor, None)
+      .await?;
+    alias_output_ids.extend(
+      page
+        .data
+        .into_iter()
+        .filter_map(|res| res.data)
+        .map(|data| data.object_id),
+    );
+    cursor = page.next_cursor;
+
+    if !page.has_next_page {
+      break;
+    }
+  }
+
+  let mut aliases = Vec::with_capacity(alias_output_ids.len());
+  for alias_output_id in alias_output_ids {
+    let dynamic_field_name = serde_json::from_value(serde_json::json!({
+      "type": "vector<u8>",
+      "value": ALIAS_FIELD_NAME,
+    }))
+    .expect("valid move value");
+    let alias_id = client
+      .read_api()
+      .get_dynamic_field_object(alias_output_id, dynamic_field_name)
+      .await
+      .map_err(|e| Error::RpcError(e.to_string()))?
+      .data
+      .map(|data| data.object_id)
+      .ok_or_else(|| Error::ObjectLookup(format!("alias output {alias_output_id} doesn't contain an alias")))?;
+
+    if let Some(alias) = get_alias(client, alias_id).await? {
+      aliases.push(alias);
+    }
+  }
+
+  Ok(aliases)
+}
+
+/// A [Transaction] that migrates several legacy Identities to new [OnChainIdentity]s at once.
+///
+/// The migrated identities are returned in the same order as their aliases were given.
+pub struct MigrateLegacyIdentities {
+  migrations: Vec<MigrateLegacyIdentity>,
+  cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl MigrateLegacyIdentities {
+  /// Returns a new [MigrateLegacyIdentities] transaction, migrating all the given `aliases`.
+  pub fn new(aliases: impl IntoIterator<Item = UnmigratedAlias>) -> Self {
+    Self {
+      migrations: aliases.into_iter().map(MigrateLegacyIdentity::new).collect(),
+      cached_ptb: OnceCell::new(),
+    }
+  }
+
+  async fn make_ptb(&self, client: &IdentityClientReadOnly) -> Result<ProgrammableTransaction, Error> {
+    let mut migrations = self.migrations.iter();
+    let first = migrations
+      .next()
+      .ok_or_else(|| Error::TransactionBuildingFailed("no legacy identity to migrate".to_owned()))?;
+
+    let mut ptb = first.build_programmable_transaction(client).await?;
+    for migration in migrations {
+      let migration_ptb = migration.build_programmable_transaction(client).await?;
+      ptb = merge_programmable_transactions(ptb, migration_ptb)?;
+    }
+
+    Ok(ptb)
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for MigrateLegacyIdentities {
+  type Output = Vec<OnChainIdentity>;
+
+  async fn build_programmable_transaction(
+    &self,
+    client: &IdentityClientReadOnly,
+  ) -> Result<ProgrammableTransaction, Error> {
+    self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+  }
+
+  async fn apply(
+    self,
+    mut effects: IotaTransactionBlockEffects,
+    client: &IdentityClientReadOnly,
+  ) -> (Result<Self::Output, Error>, IotaTransactionBlockEffects) {
+    let mut identities = Vec::with_capacity(self.migrations.len());
+    for migration in self.migrations {
+      let (result, remaining_effects) = migration.apply(effects, client).await;
+      effects = remaining_effects;
+      match result {
+        Ok(identity) => identities.push(identity),
+        Err(e) => return (Err(e), effects),
+      }
+    }
+
+    (Ok(identities), effects)
+  }
+}
+
+/// Migrates many legacy Identities, using as few transactions as possible.
+///
+/// Legacy identities are migrated in batches of at most [BulkMigration::batch_size] identities,
+/// each batch being migrated in a single transaction. After each batch, the DID Document of every
+/// migrated identity is resolved and compared against its legacy counterpart.
+/// ## Notes
+/// A batch is executed atomically: if the migration of any of its identities fails, none of them
+/// is migrated. Failed migrations are recorded in the returned [MigrationReport] and can be retried
+/// by running a new [BulkMigration], as already migrated aliases are no longer discoverable through
+/// [get_aliases_owned_by].
+///
+/// When a batch's transaction is executed but its outcome can't be processed, its identities are looked
+/// up in the migration registry. Those that can't be found are reported as [MigrationReport::unknown].
+#[derive(Debug, Clone)]
+pub struct BulkMigration {
+  aliases: Vec<UnmigratedAlias>,
+  batch_size: usize,
+}
+
+impl BulkMigration {
+  /// Returns a new [BulkMigration] for the given `aliases`.
+  pub fn new(aliases: impl IntoIterator<Item = UnmigratedAlias>) -> Self {
+    Self {
+      aliases: aliases.into_iter().collect(),
+      batch_size: DEFAULT_MIGRATION_BATCH_SIZE,
+    }
+  }
+
+  /// Returns a new [BulkMigration] for all the legacy identities whose `AliasOutput` is owned by `address`.
+  pub async fn for_address(client: &IdentityClientReadOnly, address: IotaAddress) -> Result<Self, Error> {
+    get_aliases_owned_by(client, address).await.map(Self::new)
+  }
+
+  /// Sets the maximum number of identities to migrate in a single transaction.
+  /// Defaults to [DEFAULT_MIGRATION_BATCH_SIZE].
+  pub fn batch_size(mut self, batch_size: usize) -> Self {
+    self.batch_size = batch_size.max(1);
+    self
+  }
+
+  /// Returns the aliases this [BulkMigration] will migrate.
+  pub fn aliases(&self) -> &[UnmigratedAlias] {
+    &self.aliases
+  }
+
+  /// Migrates all the legacy identities of this [BulkMigration], returning a report of the migration.
+  ///
+  /// The `AliasOutput`s of the identities must be owned by `client`'s sender.
+  pub async fn execute<S>(self, client: &IdentityClient<S>) -> Result<MigrationReport, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+  {
+    let mut report = MigrationReport::default();
+
+    // Only aliases that actually contain a DID Document can be migrated.
+    let mut migratable = Vec::with_capacity(self.aliases.len());
+    for alias in self.aliases {
+      let legacy_did = IotaDID::from_object_id(&alias.id.object_id().to_string(), client.network());
+      match Identity::Legacy(alias.clone()).did_document(client.network()) {
+        Ok(legacy_doc) => migratable.push((alias, legacy_doc)),
+        Err(e) => report.failed.push(FailedMigration {
+          legacy_did,
+          error: e.to_string(),
+        }),
+      }
+    }
+
+    for batch in migratable.chunks(self.batch_size) {
+      let tx = MigrateLegacyIdentities::new(batch.iter().map(|(alias, _)| alias.clone()));
+      let identities: Vec<Option<OnChainIdentity>> = match TransactionBuilder::new(tx).build_and_execute(client).await {
+        Ok(tx_output) => tx_output.output.into_iter().map(Some).collect(),
+        // The transaction has been executed on-chain, only its effects couldn't be applied:
+        // the migrated identities are recovered from the migration registry.
+        Err(e @ Error::TransactionOffChainApplicationFailure { .. }) => {
+          let mut identities = Vec::with_capacity(batch.len());
+          for (alias, legacy_doc) in batch {
+            let identity = lookup(client, *alias.id.object_id()).await.ok().flatten();
+            if identity.is_none() {
+              report.unknown.push(FailedMigration {
+                legacy_did: legacy_doc.id().clone(),
+                error: e.to_string(),
+              });
+            }
+            identities.push(identity);
+          }
+          identities
+        }
+        Err(e) => {
+          report
+            .failed
+            .extend(batch.iter().map(|(_, legacy_doc)| FailedMigration {
+              legacy_did: legacy_doc.id().clone(),
+              error: e.to_string(),
+            }));
+          continue;
+        }
+      };
+
+      for ((_, legacy_doc), identity) in batch.iter().zip(identities) {
+        let Some(identity) = identity else {
+          continue;
+        };
+        let did = identity.did_document().id().clone();
+        let verified = client
+          .resolve_did(&did)
+          .await
+          .is_ok_and(|migrated_doc| documents_match(legacy_doc, migrated_doc));
+
+        report.migrated.push(MigratedIdentity {
+          legacy_did: legacy_doc.id().clone(),
+          did,
+          identity_id: identity.id(),
+          verified,
+        });
+      }
+    }
+
+    Ok(report)
+  }
+}
+
+/// Returns whether `migrated_doc` has the same content as `legacy_doc`, disregarding their DIDs and the
+/// `alsoKnownAs` entry that links `migrated_doc` to its legacy DID.
+fn documents_match(legacy_doc: &IotaDocument, mut migrated_doc: IotaDocument) -> bool {
+  let legacy_did: Url = legacy_doc.id().to_url().into();
+  migrated_doc.also_known_as_mut().remove(&legacy_did);
+
+  StateMetadataDocument::from(legacy_doc.clone()).document == StateMetadataDocument::from(migrated_doc).document
+}
+
+/// The outcome of a [BulkMigration].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationReport {
+  migrated: Vec<MigratedIdentity>,
+  failed: Vec<FailedMigration>,
+  #[serde(default)]
+  unknown: Vec<FailedMigration>,
+}
+
+impl MigrationReport {
+  /// Returns the identities that have been migrated.
+  pub fn migrated(&self) -> &[MigratedIdentity] {
+    &self.migrated
+  }
+
+  /// Returns the identities that could not be migrated.
+  pub fn failed(&self) -> &[FailedMigration] {
+    &self.failed
+  }
+
+  /// Returns the identities whose migration transaction has been executed, but that could not be
+  /// found afterwards. They have most likely been migrated; [lookup] tells for sure.
+  pub fn unknown(&self) -> &[FailedMigration] {
+    &self.unknown
+  }
+
+  /// Returns the pairs of legacy DID and new DID of all migrated identities.
+  pub fn did_mappings(&self) -> impl Iterator<Item = (&IotaDID, &IotaDID)> {
+    self
+      .migrated
+      .iter()
+      .map(|migrated| (&migrated.legacy_did, &migrated.did))
+  }
+
+  /// Returns whether all identities have been migrated and their DID Documents verified.
+  pub fn is_successful(&self) -> bool {
+    self.failed.is_empty() && self.unknown.is_empty() && self.migrated.iter().all(|migrated| migrated.verified)
+  }
+}
+
+/// A legacy identity that has been migrated to an [OnChainIdentity].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigratedIdentity {
+  /// The DID of the legacy identity.
+  pub legacy_did: IotaDID,
+  /// The DID of the new [OnChainIdentity].
+  pub did: IotaDID,
+  /// The ID of the new [OnChainIdentity].
+  pub identity_id: ObjectID,
+  /// Whether the migrated DID Document resolves to the same content as the legacy one,
+  /// modulo its DID.
+  pub verified: bool,
+}
+
+/// A legacy identity whose migration failed, or could not be confirmed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedMigration {
+  /// The DID of the legacy identity.
+  pub legacy_did: IotaDID,
+  /// The reason the migration failed, or could not be confirmed.
+  pub error: String,
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_verification::MethodScope;
+
+  use super::*;
+  use crate::test_utils::generate_method;
+  use crate::NetworkName;
+
+  fn document(did: &IotaDID) -> IotaDocument {
+    let mut document = IotaDocument::new_with_id(did.clone());
+    document
+      .insert_method(generate_method(did, "#key-1"), MethodScope::VerificationMethod)
+      .unwrap();
+
+    document
+  }
+
+  #[test]
+  fn documents_match_modulo_their_did() {
+    let network = NetworkName::try_from("iota").unwrap();
+    let legacy_did = IotaDID::from_object_id(&ObjectID::from_single_byte(1).to_string(), &network);
+    let did = IotaDID::from_object_id(&ObjectID::from_single_byte(2).to_string(), &network);
+    let legacy_doc = document(&legacy_did);
+
+    // Methods of the migrated document reference the new DID, and the legacy DID is in `alsoKnownAs`.
+    let mut migrated_doc = StateMetadataDocument::from(legacy_doc.clone())
+      .into_iota_document(&did)
+      .unwrap();
+    migrated_doc.also_known_as_mut().append(legacy_did.to_url().into());
+    assert!(documents_match(&legacy_doc, migrated_doc.clone()));
+
+    migrated_doc
+      .insert_method(generate_method(&did, "#key-2"), MethodScope::VerificationMethod)
+      .unwrap();
+    assert!(!documents_match(&legacy_doc, migrated_doc));
+  }
+}
@@ -7,6 +7,7 @@ use std::collections::HashSet;
 use crate::iota_interaction_adapter::IdentityMoveCallsAdapter;
 use crate::rebased::transaction_builder::Transaction;
 use crate::rebased::transaction_builder::TransactionBuilder;
+use crate::rebased::transaction_preview::DidDocumentDiff;
 use identity_iota_interaction::types::transaction::ProgrammableTransaction;
 use identity_iota_interaction::IdentityMoveCalls;
 use identity_iota_interaction::IotaTransactionBlockEffectsMutAPI as _;
@@ -588,6 +589,10 @@ impl Transaction for CreateIdentity {
     self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
   }
 
+  fn did_document_diff(&self) -> Result<Option<DidDocumentDiff>, Error> {
+    Ok(Some(DidDocumentDiff::new(None, Some(self.builder.did_doc.clone()))))
+  }
+
   async fn apply(
     mut self,
     mut effects: IotaTransactionBlockEffects,
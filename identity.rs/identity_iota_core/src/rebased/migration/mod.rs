@@ -2,12 +2,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod alias;
+mod bulk;
 mod controller_token;
 mod identity;
 mod multicontroller;
 mod registry;
 
 pub use alias::*;
+pub use bulk::*;
 pub use controller_token::*;
 pub use identity::*;
 pub use multicontroller::*;
@@ -6,15 +6,26 @@ pub mod assets;
 /// Module for handling client operations.
 pub mod client;
 mod error;
+/// Module for sponsoring transactions through gas stations.
+pub mod gas_station;
 mod iota;
+/// In-memory IOTA ledger for running identity transactions offline.
+#[cfg(all(feature = "local-ledger", not(target_arch = "wasm32")))]
+pub mod local_ledger;
 /// Module for handling migration operations.
 pub mod migration;
 /// Contains the operations of proposals.
 pub mod proposals;
 /// Module for handling transactions.
 pub mod transaction;
+/// Module for executing several transactions as a single one.
+pub mod transaction_batch;
 /// Module for creating transactions using a builder style pattern.
 pub mod transaction_builder;
+/// Module for previewing the effects of transactions before executing them.
+pub mod transaction_preview;
+/// Module for exporting transactions to be signed offline.
+pub mod unsigned_transaction;
 /// Contains utility functions.
 #[cfg(not(target_arch = "wasm32"))]
 pub mod utils;
@@ -229,6 +229,7 @@ where
       identity,
       ptb: bcs::from_bytes(&tx)?,
       chained_execution,
+      did_document_update: None,
       _action: PhantomData,
     }))
   }
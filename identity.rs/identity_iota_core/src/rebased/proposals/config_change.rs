@@ -269,6 +269,7 @@ impl ProposalT for Proposal<ConfigChange> {
       identity,
       ptb: bcs::from_bytes(&tx)?,
       chained_execution,
+      did_document_update: None,
       _action: PhantomData,
     }))
   }
@@ -310,6 +311,7 @@ impl ProposalT for Proposal<ConfigChange> {
     Ok(TransactionBuilder::new(ExecuteProposal {
       identity,
       ptb: bcs::from_bytes(&tx)?,
+      did_document_update: None,
       _action: PhantomData,
     }))
   }
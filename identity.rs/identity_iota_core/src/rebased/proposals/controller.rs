@@ -222,6 +222,7 @@ where
       identity,
       ptb: bcs::from_bytes(&ptb)?,
       chained_execution,
+      did_document_update: None,
       _action: PhantomData,
     }))
   }
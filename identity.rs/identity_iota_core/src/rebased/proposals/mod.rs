@@ -25,6 +25,7 @@ use crate::rebased::migration::get_identity;
 use crate::rebased::transaction::ProtoTransaction;
 use crate::rebased::transaction_builder::Transaction;
 use crate::rebased::transaction_builder::TransactionBuilder;
+use crate::rebased::transaction_preview::DidDocumentDiff;
 use async_trait::async_trait;
 pub use borrow::*;
 pub use config_change::*;
@@ -168,6 +169,7 @@ pub struct CreateProposal<'i, A> {
   identity: &'i mut OnChainIdentity,
   chained_execution: bool,
   ptb: ProgrammableTransaction,
+  did_document_update: Option<UpdateDidDocument>,
   _action: PhantomData<A>,
 }
 
@@ -194,6 +196,16 @@ where
     Ok(self.ptb.clone())
   }
 
+  fn did_document_diff(&self) -> Result<Option<DidDocumentDiff>, Error> {
+    // The DID Document is only updated when the proposal is executed right-away.
+    match &self.did_document_update {
+      Some(update) if self.chained_execution => {
+        DidDocumentDiff::from_update(self.identity.did_document(), update.did_document_bytes()).map(Some)
+      }
+      _ => Ok(None),
+    }
+  }
+
   async fn apply(
     self,
     effects: IotaTransactionBlockEffects,
@@ -242,6 +254,7 @@ where
 pub struct ExecuteProposal<'i, A> {
   ptb: ProgrammableTransaction,
   identity: &'i mut OnChainIdentity,
+  did_document_update: Option<UpdateDidDocument>,
   _action: PhantomData<A>,
 }
 
@@ -266,6 +279,13 @@ where
   ) -> Result<ProgrammableTransaction, Error> {
     Ok(self.ptb.clone())
   }
+  fn did_document_diff(&self) -> Result<Option<DidDocumentDiff>, Error> {
+    self
+      .did_document_update
+      .as_ref()
+      .map(|update| DidDocumentDiff::from_update(self.identity.did_document(), update.did_document_bytes()))
+      .transpose()
+  }
   async fn apply(
     self,
     effects: IotaTransactionBlockEffects,
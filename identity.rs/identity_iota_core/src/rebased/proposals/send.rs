@@ -148,6 +148,7 @@ impl ProposalT for Proposal<SendAction> {
       identity,
       ptb: bcs::from_bytes(&tx)?,
       chained_execution: can_execute,
+      did_document_update: None,
       _action: PhantomData,
     }))
   }
@@ -202,6 +203,7 @@ impl ProposalT for Proposal<SendAction> {
     Ok(TransactionBuilder::new(ExecuteProposal {
       identity,
       ptb: bcs::from_bytes(&tx)?,
+      did_document_update: None,
       _action: PhantomData,
     }))
   }
@@ -118,6 +118,7 @@ impl ProposalT for Proposal<UpdateDidDocument> {
       identity,
       ptb,
       chained_execution,
+      did_document_update: Some(action),
       _action: PhantomData,
     }))
   }
@@ -161,6 +162,7 @@ impl ProposalT for Proposal<UpdateDidDocument> {
     Ok(TransactionBuilder::new(ExecuteProposal {
       identity,
       ptb,
+      did_document_update: Some(self.into_action()),
       _action: PhantomData,
     }))
   }
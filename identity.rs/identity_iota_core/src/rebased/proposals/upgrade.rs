@@ -87,6 +87,7 @@ impl ProposalT for Proposal<Upgrade> {
       identity,
       ptb: bcs::from_bytes(&tx)?,
       chained_execution,
+      did_document_update: None,
       _action: PhantomData,
     }))
   }
@@ -124,6 +125,7 @@ impl ProposalT for Proposal<Upgrade> {
     Ok(TransactionBuilder::new(ExecuteProposal {
       identity,
       ptb: bcs::from_bytes(&tx)?,
+      did_document_update: None,
       _action: PhantomData,
     }))
   }
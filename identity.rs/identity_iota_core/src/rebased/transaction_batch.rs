@@ -0,0 +1,560 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use async_trait::async_trait;
+use identity_iota_interaction::ident_str;
+use identity_iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::base_types::ObjectRef;
+use identity_iota_interaction::types::transaction::Argument;
+use identity_iota_interaction::types::transaction::CallArg;
+use identity_iota_interaction::types::transaction::Command;
+use identity_iota_interaction::types::transaction::ObjectArg;
+use identity_iota_interaction::types::transaction::ProgrammableMoveCall;
+use identity_iota_interaction::types::transaction::ProgrammableTransaction;
+use identity_iota_interaction::types::IOTA_FRAMEWORK_PACKAGE_ID;
+use identity_iota_interaction::OptionalSend;
+use identity_iota_interaction::OptionalSync;
+use tokio::sync::OnceCell;
+
+use super::client::IdentityClientReadOnly;
+use super::transaction_builder::Transaction;
+use super::transaction_preview::DidDocumentDiff;
+use super::Error;
+
+/// Two [Transaction]s executed together, atomically, as a single programmable transaction.
+///
+/// A [BatchedTransaction] is itself a [Transaction], which allows for batching any number of transactions
+/// by nesting them, e.g. through [TransactionBuilder::batch_with]. The output of a [BatchedTransaction] is
+/// the tuple of its transactions' outputs, in the order the transactions have been batched.
+///
+/// ## Notes
+/// The commands of the batched transactions are executed in order, but each transaction is built on its
+/// own and cannot use the values returned by the transactions that precede it in the batch. Transactions
+/// that do are [DependentTransaction]s, batched through [TransactionBuilder::batch_with_dependent].
+/// Objects that are used as input by more than one transaction are only passed once.
+///
+/// [TransactionBuilder::batch_with]: super::transaction_builder::TransactionBuilder::batch_with
+/// [TransactionBuilder::batch_with_dependent]: super::transaction_builder::TransactionBuilder::batch_with_dependent
+#[derive(Debug)]
+pub struct BatchedTransaction<A, B> {
+  first: A,
+  second: B,
+  cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl<A, B> BatchedTransaction<A, B> {
+  /// Batches `first` and `second` together.
+  pub fn new(first: A, second: B) -> Self {
+    Self {
+      first,
+      second,
+      cached_ptb: OnceCell::new(),
+    }
+  }
+
+  /// Returns the transactions in this batch, consuming it.
+  pub fn into_inner(self) -> (A, B) {
+    (self.first, self.second)
+  }
+}
+
+impl<A, B> BatchedTransaction<A, B>
+where
+  A: Transaction,
+  B: Transaction,
+{
+  async fn make_ptb(&self, client: &IdentityClientReadOnly) -> Result<ProgrammableTransaction, Error> {
+    let first = self.first.build_programmable_transaction(client).await?;
+    let second = self.second.build_programmable_transaction(client).await?;
+
+    merge_programmable_transactions(first, second)
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<A, B> Transaction for BatchedTransaction<A, B>
+where
+  A: Transaction + OptionalSend + OptionalSync,
+  B: Transaction + OptionalSend + OptionalSync,
+  A::Output: OptionalSend,
+{
+  type Output = (A::Output, B::Output);
+
+  async fn build_programmable_transaction(
+    &self,
+    client: &IdentityClientReadOnly,
+  ) -> Result<ProgrammableTransaction, Error> {
+    self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+  }
+
+  /// Returns the changes the transactions of this batch make to a DID Document, if any.
+  /// ## Notes
+  /// A [DidDocumentDiff] describes the changes to a single DID Document; an [Error::InvalidArgument] is
+  /// returned when more than one transaction of this batch makes changes to a DID Document.
+  fn did_document_diff(&self) -> Result<Option<DidDocumentDiff>, Error> {
+    match (self.first.did_document_diff()?, self.second.did_document_diff()?) {
+      (Some(_), Some(_)) => Err(Error::InvalidArgument(
+        "more than one transaction in this batch changes a DID Document; their changes cannot be described by a single diff"
+          .to_owned(),
+      )),
+      (first, second) => Ok(first.or(second)),
+    }
+  }
+
+  async fn apply(
+    self,
+    effects: IotaTransactionBlockEffects,
+    client: &IdentityClientReadOnly,
+  ) -> (Result<Self::Output, Error>, IotaTransactionBlockEffects) {
+    // Each transaction consumes its own effects, leaving the remaining ones to the transactions that follow.
+    let (first_result, effects) = self.first.apply(effects, client).await;
+    let (second_result, effects) = self.second.apply(effects, client).await;
+
+    let result = first_result.and_then(|first_output| second_result.map(|second_output| (first_output, second_output)));
+    (result, effects)
+  }
+}
+
+/// A transaction that is batched after another one and uses the values returned by its commands.
+///
+/// Whereas a [Transaction] builds its programmable transaction on its own, a [DependentTransaction] extends
+/// the programmable transaction of the transaction it's batched after. Its commands can therefore take the
+/// results of that transaction's commands as arguments, through [Argument::Result] and
+/// [Argument::NestedResult], e.g. to make use of an object created earlier in the batch.
+///
+/// See [TransactionBuilder::batch_with_dependent].
+///
+/// [TransactionBuilder::batch_with_dependent]: super::transaction_builder::TransactionBuilder::batch_with_dependent
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait DependentTransaction {
+  /// Output type for this transaction.
+  type Output;
+
+  /// Extends `ptb` - the programmable transaction of the transaction this one depends on - with this
+  /// transaction's inputs and commands.
+  async fn extend_programmable_transaction(
+    &self,
+    ptb: ProgrammableTransaction,
+    client: &IdentityClientReadOnly,
+  ) -> Result<ProgrammableTransaction, Error>;
+
+  /// Parses a transaction result in order to compute its effects.
+  /// See [Transaction::apply].
+  async fn apply(
+    self,
+    effects: IotaTransactionBlockEffects,
+    client: &IdentityClientReadOnly,
+  ) -> (Result<Self::Output, Error>, IotaTransactionBlockEffects);
+}
+
+/// A [Transaction] followed by a [DependentTransaction], executed together, atomically, as a single
+/// programmable transaction.
+///
+/// The output of a [DependentBatchedTransaction] is the pair of its transactions' outputs.
+#[derive(Debug)]
+pub struct DependentBatchedTransaction<A, B> {
+  first: A,
+  second: B,
+  cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl<A, B> DependentBatchedTransaction<A, B> {
+  /// Batches `second` after `first`, which it depends on.
+  pub fn new(first: A, second: B) -> Self {
+    Self {
+      first,
+      second,
+      cached_ptb: OnceCell::new(),
+    }
+  }
+
+  /// Returns the transactions in this batch, consuming it.
+  pub fn into_inner(self) -> (A, B) {
+    (self.first, self.second)
+  }
+}
+
+impl<A, B> DependentBatchedTransaction<A, B>
+where
+  A: Transaction,
+  B: DependentTransaction,
+{
+  async fn make_ptb(&self, client: &IdentityClientReadOnly) -> Result<ProgrammableTransaction, Error> {
+    let first = self.first.build_programmable_transaction(client).await?;
+    self.second.extend_programmable_transaction(first, client).await
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<A, B> Transaction for DependentBatchedTransaction<A, B>
+where
+  A: Transaction + OptionalSend + OptionalSync,
+  B: DependentTransaction + OptionalSend + OptionalSync,
+  A::Output: OptionalSend,
+{
+  type Output = (A::Output, B::Output);
+
+  async fn build_programmable_transaction(
+    &self,
+    client: &IdentityClientReadOnly,
+  ) -> Result<ProgrammableTransaction, Error> {
+    self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+  }
+
+  fn did_document_diff(&self) -> Result<Option<DidDocumentDiff>, Error> {
+    self.first.did_document_diff()
+  }
+
+  async fn apply(
+    self,
+    effects: IotaTransactionBlockEffects,
+    client: &IdentityClientReadOnly,
+  ) -> (Result<Self::Output, Error>, IotaTransactionBlockEffects) {
+    let (first_result, effects) = self.first.apply(effects, client).await;
+    let (second_result, effects) = self.second.apply(effects, client).await;
+
+    let result = first_result.and_then(|first_output| second_result.map(|second_output| (first_output, second_output)));
+    (result, effects)
+  }
+}
+
+/// A [DependentTransaction] that sends objects owned by the sender to the identity created by the
+/// transaction it's batched after, e.g. to hand over assets to an identity as part of its creation.
+///
+/// ## Example
+/// ```ignore
+/// let (identity, _) = client
+///   .create_identity(document)
+///   .finish()
+///   .batch_with_dependent(SendToNewIdentity::new([asset_id]))
+///   .build_and_execute(&client)
+///   .await?
+///   .output;
+/// ```
+#[derive(Debug, Clone)]
+pub struct SendToNewIdentity {
+  objects: Vec<ObjectID>,
+}
+
+impl SendToNewIdentity {
+  /// Sends `objects` to the newly created identity.
+  pub fn new(objects: impl IntoIterator<Item = ObjectID>) -> Self {
+    Self {
+      objects: objects.into_iter().collect(),
+    }
+  }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl DependentTransaction for SendToNewIdentity {
+  type Output = ();
+
+  async fn extend_programmable_transaction(
+    &self,
+    ptb: ProgrammableTransaction,
+    client: &IdentityClientReadOnly,
+  ) -> Result<ProgrammableTransaction, Error> {
+    let mut object_refs = Vec::with_capacity(self.objects.len());
+    for object_id in &self.objects {
+      let object_ref = client
+        .get_object_ref_by_id(*object_id)
+        .await?
+        .ok_or_else(|| Error::ObjectLookup(format!("object {object_id} doesn't exist")))?;
+      object_refs.push(object_ref.reference.to_object_ref());
+    }
+
+    send_to_new_identity(ptb, client.package_id(), object_refs)
+  }
+
+  async fn apply(
+    self,
+    effects: IotaTransactionBlockEffects,
+    _client: &IdentityClientReadOnly,
+  ) -> (Result<Self::Output, Error>, IotaTransactionBlockEffects) {
+    (Ok(()), effects)
+  }
+}
+
+/// Extends `ptb`, whose last command creates an identity, with the transfer of `objects` to that identity.
+fn send_to_new_identity(
+  mut ptb: ProgrammableTransaction,
+  package_id: ObjectID,
+  objects: Vec<ObjectRef>,
+) -> Result<ProgrammableTransaction, Error> {
+  let creates_identity = matches!(
+    ptb.commands.last(),
+    Some(Command::MoveCall(call))
+      if call.package == package_id && call.module.as_str() == "identity" && call.function.as_str().starts_with("new")
+  );
+  if !creates_identity {
+    return Err(Error::TransactionBuildingFailed(
+      "objects can only be sent to an identity created by the preceding transaction".to_owned(),
+    ));
+  }
+  let identity_id = Argument::Result(last_command_index(&ptb)?);
+
+  let objects = objects
+    .into_iter()
+    .map(|object_ref| {
+      merge_input(
+        &mut ptb.inputs,
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(object_ref)),
+      )
+    })
+    .map(|input| input.map(Argument::Input))
+    .collect::<Result<Vec<_>, _>>()?;
+
+  ptb.commands.push(Command::MoveCall(Box::new(ProgrammableMoveCall {
+    package: IOTA_FRAMEWORK_PACKAGE_ID,
+    module: ident_str!("object").into(),
+    function: ident_str!("id_to_address").into(),
+    type_arguments: vec![],
+    arguments: vec![identity_id],
+  })));
+  let identity_address = Argument::Result(last_command_index(&ptb)?);
+  ptb.commands.push(Command::TransferObjects(objects, identity_address));
+
+  Ok(ptb)
+}
+
+fn last_command_index(ptb: &ProgrammableTransaction) -> Result<u16, Error> {
+  u16::try_from(ptb.commands.len() - 1)
+    .map_err(|_| Error::TransactionBuildingFailed("too many commands in a batched transaction".to_owned()))
+}
+
+/// Merges two [ProgrammableTransaction]s into one, executing the commands of `first` before
+/// those of `second`.
+/// # Failures
+/// This function fails if an object is used by both transactions in incompatible ways, e.g. as
+/// both an owned and a shared object.
+pub(crate) fn merge_programmable_transactions(
+  first: ProgrammableTransaction,
+  second: ProgrammableTransaction,
+) -> Result<ProgrammableTransaction, Error> {
+  let ProgrammableTransaction {
+    mut inputs,
+    mut commands,
+  } = first;
+  let result_offset = u16::try_from(commands.len())
+    .map_err(|_| Error::TransactionBuildingFailed("too many commands in a batched transaction".to_owned()))?;
+
+  let input_indexes = second
+    .inputs
+    .into_iter()
+    .map(|input| merge_input(&mut inputs, input))
+    .collect::<Result<Vec<_>, _>>()?;
+  let remap = |arg: Argument| match arg {
+    Argument::GasCoin => Argument::GasCoin,
+    Argument::Input(i) => Argument::Input(input_indexes[i as usize]),
+    Argument::Result(i) => Argument::Result(i + result_offset),
+    Argument::NestedResult(i, j) => Argument::NestedResult(i + result_offset, j),
+  };
+  let remap_all = |args: Vec<Argument>| args.into_iter().map(remap).collect::<Vec<_>>();
+
+  for command in second.commands {
+    let command = match command {
+      Command::MoveCall(mut call) => {
+        call.arguments = remap_all(std::mem::take(&mut call.arguments));
+        Command::MoveCall(call)
+      }
+      Command::TransferObjects(objects, recipient) => Command::TransferObjects(remap_all(objects), remap(recipient)),
+      Command::SplitCoins(coin, amounts) => Command::SplitCoins(remap(coin), remap_all(amounts)),
+      Command::MergeCoins(coin, coins) => Command::MergeCoins(remap(coin), remap_all(coins)),
+      Command::Publish(modules, deps) => Command::Publish(modules, deps),
+      Command::MakeMoveVec(type_tag, elements) => Command::MakeMoveVec(type_tag, remap_all(elements)),
+      Command::Upgrade(modules, deps, package, ticket) => Command::Upgrade(modules, deps, package, remap(ticket)),
+    };
+    commands.push(command);
+  }
+
+  Ok(ProgrammableTransaction { inputs, commands })
+}
+
+/// Adds `input` to `inputs` - unless it references an object that is already there - returning its index.
+fn merge_input(inputs: &mut Vec<CallArg>, input: CallArg) -> Result<u16, Error> {
+  let existing_position = match &input {
+    CallArg::Object(obj_arg) => inputs
+      .iter()
+      .position(|arg| matches!(arg, CallArg::Object(other) if object_id(other) == object_id(obj_arg))),
+    _ => None,
+  };
+
+  let position = if let Some(position) = existing_position {
+    let CallArg::Object(obj_arg) = input else {
+      unreachable!("only objects are deduplicated");
+    };
+    match (&mut inputs[position], obj_arg) {
+      (
+        CallArg::Object(ObjectArg::SharedObject { mutable, .. }),
+        ObjectArg::SharedObject {
+          mutable: other_mutable, ..
+        },
+      ) => *mutable |= other_mutable,
+      (CallArg::Object(ObjectArg::ImmOrOwnedObject(_)), ObjectArg::ImmOrOwnedObject(_)) => (),
+      (CallArg::Object(ObjectArg::Receiving(_)), ObjectArg::Receiving(_)) => (),
+      (_, obj_arg) => {
+        return Err(Error::TransactionBuildingFailed(format!(
+          "object {} is used in incompatible ways by the batched transactions",
+          object_id(&obj_arg)
+        )))
+      }
+    }
+    position
+  } else {
+    inputs.push(input);
+    inputs.len() - 1
+  };
+
+  u16::try_from(position)
+    .map_err(|_| Error::TransactionBuildingFailed("too many inputs in a batched transaction".to_owned()))
+}
+
+fn object_id(obj_arg: &ObjectArg) -> ObjectID {
+  match obj_arg {
+    ObjectArg::ImmOrOwnedObject((id, _, _)) => *id,
+    ObjectArg::SharedObject { id, .. } => *id,
+    ObjectArg::Receiving((id, _, _)) => *id,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_iota_interaction::types::base_types::ObjectDigest;
+  use identity_iota_interaction::types::base_types::SequenceNumber;
+
+  use super::*;
+
+  fn move_call(arguments: Vec<Argument>) -> Command {
+    Command::MoveCall(Box::new(ProgrammableMoveCall {
+      package: ObjectID::ZERO,
+      module: "module".parse().unwrap(),
+      function: "function".parse().unwrap(),
+      type_arguments: vec![],
+      arguments,
+    }))
+  }
+
+  fn shared_object(id: ObjectID, mutable: bool) -> CallArg {
+    CallArg::Object(ObjectArg::SharedObject {
+      id,
+      initial_shared_version: SequenceNumber::from_u64(1),
+      mutable,
+    })
+  }
+
+  #[test]
+  fn merged_transaction_remaps_inputs_and_results() {
+    let shared_id = ObjectID::from_single_byte(1);
+    let owned_ref = (
+      ObjectID::from_single_byte(2),
+      SequenceNumber::from_u64(3),
+      ObjectDigest::MIN,
+    );
+    let first = ProgrammableTransaction {
+      inputs: vec![shared_object(shared_id, false), CallArg::Pure(vec![1])],
+      commands: vec![move_call(vec![Argument::Input(0), Argument::Input(1)])],
+    };
+    let second = ProgrammableTransaction {
+      inputs: vec![
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(owned_ref)),
+        shared_object(shared_id, true),
+      ],
+      commands: vec![
+        move_call(vec![Argument::Input(1), Argument::Input(0)]),
+        Command::TransferObjects(vec![Argument::NestedResult(0, 1)], Argument::GasCoin),
+      ],
+    };
+
+    let merged = merge_programmable_transactions(first, second).unwrap();
+
+    assert_eq!(
+      merged.inputs,
+      vec![
+        shared_object(shared_id, true),
+        CallArg::Pure(vec![1]),
+        CallArg::Object(ObjectArg::ImmOrOwnedObject(owned_ref)),
+      ]
+    );
+    assert_eq!(merged.commands.len(), 3);
+    assert_eq!(
+      merged.commands[1],
+      move_call(vec![Argument::Input(0), Argument::Input(2)])
+    );
+    assert_eq!(
+      merged.commands[2],
+      Command::TransferObjects(vec![Argument::NestedResult(1, 1)], Argument::GasCoin)
+    );
+  }
+
+  #[test]
+  fn objects_are_sent_to_the_identity_created_by_the_previous_transaction() {
+    let package_id = ObjectID::from_single_byte(9);
+    let object_ref = (
+      ObjectID::from_single_byte(2),
+      SequenceNumber::from_u64(3),
+      ObjectDigest::MIN,
+    );
+    let create_identity = Command::MoveCall(Box::new(ProgrammableMoveCall {
+      package: package_id,
+      module: "identity".parse().unwrap(),
+      function: "new".parse().unwrap(),
+      type_arguments: vec![],
+      arguments: vec![Argument::Input(0)],
+    }));
+    let ptb = ProgrammableTransaction {
+      inputs: vec![CallArg::Pure(vec![0])],
+      commands: vec![create_identity],
+    };
+
+    let extended = send_to_new_identity(ptb, package_id, vec![object_ref]).unwrap();
+
+    assert_eq!(extended.inputs.len(), 2);
+    assert_eq!(
+      extended.inputs[1],
+      CallArg::Object(ObjectArg::ImmOrOwnedObject(object_ref))
+    );
+    let Command::MoveCall(id_to_address) = &extended.commands[1] else {
+      panic!("expected a move call");
+    };
+    assert_eq!(id_to_address.function.as_str(), "id_to_address");
+    assert_eq!(id_to_address.arguments, vec![Argument::Result(0)]);
+    assert_eq!(
+      extended.commands[2],
+      Command::TransferObjects(vec![Argument::Input(1)], Argument::Result(1))
+    );
+  }
+
+  #[test]
+  fn objects_cannot_be_sent_without_a_new_identity() {
+    let ptb = ProgrammableTransaction {
+      inputs: vec![],
+      commands: vec![move_call(vec![])],
+    };
+
+    assert!(send_to_new_identity(ptb, ObjectID::from_single_byte(9), vec![]).is_err());
+  }
+
+  #[test]
+  fn merging_incompatible_object_usages_fails() {
+    let id = ObjectID::from_single_byte(1);
+    let first = ProgrammableTransaction {
+      inputs: vec![shared_object(id, true)],
+      commands: vec![],
+    };
+    let second = ProgrammableTransaction {
+      inputs: vec![CallArg::Object(ObjectArg::ImmOrOwnedObject((
+        id,
+        SequenceNumber::from_u64(1),
+        ObjectDigest::MIN,
+      )))],
+      commands: vec![],
+    };
+
+    assert!(merge_programmable_transactions(first, second).is_err());
+  }
+}
@@ -6,6 +6,8 @@ use std::ops::Deref;
 use anyhow::Context as _;
 use async_trait::async_trait;
 use cfg_if::cfg_if;
+use identity_iota_interaction::rpc_types::DryRunTransactionBlockResponse;
+use identity_iota_interaction::rpc_types::IotaExecutionStatus;
 use identity_iota_interaction::rpc_types::IotaTransactionBlockEffects;
 use identity_iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI as _;
 use identity_iota_interaction::rpc_types::IotaTransactionBlockResponseOptions;
@@ -30,12 +32,27 @@ use secret_storage::Signer;
 
 use super::client::IdentityClient;
 use super::client::IdentityClientReadOnly;
+use super::gas_station::GasStation;
+use super::gas_station::DEFAULT_RESERVATION_DURATION;
+use super::gas_station::DEFAULT_SPONSORED_GAS_BUDGET;
 #[cfg(not(target_arch = "wasm32"))]
 use super::transaction::TransactionOutput;
 #[cfg(target_arch = "wasm32")]
 use super::transaction::TransactionOutputInternal as TransactionOutput;
+use super::transaction_batch::BatchedTransaction;
+use super::transaction_batch::DependentBatchedTransaction;
+use super::transaction_batch::DependentTransaction;
+use super::transaction_preview::DidDocumentDiff;
+use super::transaction_preview::TransactionPreview;
+use super::unsigned_transaction::UnsignedTransaction;
 use super::Error;
 
+/// The margin, as a percentage of the estimated gas budget, that is added to the gas budget
+/// of transactions whose budget is estimated by dry running them.
+pub const DEFAULT_GAS_BUDGET_MARGIN: u64 = 10;
+/// The gas budget used when dry running a transaction whose budget hasn't been set.
+const DRY_RUN_GAS_BUDGET: u64 = 50_000_000;
+
 /// An operation that combines a transaction with its off-chain effects.
 #[cfg_attr(feature = "send-sync", async_trait)]
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
@@ -49,6 +66,14 @@ pub trait Transaction {
     client: &IdentityClientReadOnly,
   ) -> Result<ProgrammableTransaction, Error>;
 
+  /// Returns the changes this transaction makes to a DID Document, if any.
+  /// ## Notes
+  /// This is used to preview the effects of this transaction through [TransactionBuilder::dry_run].
+  /// The default implementation returns `None`.
+  fn did_document_diff(&self) -> Result<Option<DidDocumentDiff>, Error> {
+    Ok(None)
+  }
+
   /// Parses a transaction result in order to compute its effects.
   /// ## Notes
   /// [Transaction::apply] implementations should make sure to properly consume
@@ -138,6 +163,7 @@ pub struct TransactionBuilder<Tx> {
   programmable_tx: Option<ProgrammableTransaction>,
   sender: Option<IotaAddress>,
   gas: PartialGasData,
+  gas_budget_margin: u64,
   signatures: Vec<Signature>,
   tx: Tx,
 }
@@ -158,6 +184,7 @@ where
     Self {
       tx: effect,
       gas: PartialGasData::default(),
+      gas_budget_margin: DEFAULT_GAS_BUDGET_MARGIN,
       signatures: vec![],
       sender: None,
       programmable_tx: None,
@@ -286,9 +313,15 @@ where
     let programmable_tx = self.programmable_tx.expect("just computed it");
     let client_address = client.sender_address();
     let sender = self.sender.unwrap_or(client_address);
-    let gas_data = complete_gas_data_for_tx(&programmable_tx, self.gas, client)
-      .await
-      .map_err(|e| Error::GasIssue(e.to_string()))?;
+    let gas_data = complete_gas_data_for_tx(
+      &programmable_tx,
+      self.gas,
+      client,
+      sender,
+      client_address,
+      self.gas_budget_margin,
+    )
+    .await?;
 
     let tx_data = TransactionData::new_with_gas_data(
       TransactionKind::ProgrammableTransaction(programmable_tx),
@@ -329,59 +362,243 @@ where
     // Build the transaction into its parts.
     let (tx_data, signatures, tx) = self.build(client).await?;
 
-    // Execute and wait for the transaction to be confirmed.
-    let dyn_tx_block = client
-      .quorum_driver_api()
-      .execute_transaction_block(
-        tx_data,
-        signatures,
-        Some(IotaTransactionBlockResponseOptions::full_content()),
-        Some(ExecuteTransactionRequestType::WaitForLocalExecution),
-      )
-      .await?;
+    execute_and_apply(tx_data, signatures, tx, client).await
+  }
+
+  /// Builds and executes this transaction, having its gas paid by `gas_station`.
+  ///
+  /// Gas coins able to cover this transaction's budget - estimated by dry running the transaction within
+  /// [DEFAULT_SPONSORED_GAS_BUDGET] if no budget was set - are reserved through `gas_station`, which is then asked to
+  /// sign the transaction as its sponsor. The reservation is released once the transaction has been executed, or as
+  /// soon as any step fails. If the transaction fails and its reservation can't be released either,
+  /// [Error::GasReservationNotReleased] is returned.
+  /// ## Notes
+  /// Any gas payment or gas owner previously set on this builder is replaced by the gas station's ones.
+  pub async fn build_and_execute_with_gas_station<S, G>(
+    mut self,
+    client: &IdentityClient<S>,
+    gas_station: &G,
+  ) -> Result<TransactionOutput<Tx::Output>, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+    G: GasStation + OptionalSync,
+  {
+    let sender = self.sender.unwrap_or(client.sender_address());
+    let programmable_tx = self.get_or_init_programmable_tx(client).await?.clone();
+    // The gas station's coins are not known yet: the transaction is dry run with its sender as the gas owner.
+    let gas = PartialGasData {
+      payment: vec![],
+      owner: None,
+      ..self.gas.clone()
+    };
+    let (_, GasData { price, budget, .. }) = dry_run_transaction(
+      &programmable_tx,
+      &gas,
+      client,
+      sender,
+      sender,
+      DEFAULT_SPONSORED_GAS_BUDGET,
+      self.gas_budget_margin,
+    )
+    .await?;
+    let reservation = gas_station.reserve_gas(budget, DEFAULT_RESERVATION_DURATION).await?;
+    let reservation_id = reservation.reservation_id;
+    self.gas = GasData {
+      payment: reservation.gas_payment(),
+      owner: reservation.sponsor_address,
+      price,
+      budget,
+    }
+    .into();
+
+    let result = async move {
+      let (tx_data, mut signatures, tx) = self.build(client).await?;
+      let sponsor_signature = gas_station.sponsor_transaction(reservation_id, &tx_data).await?;
+      signatures.push(sponsor_signature);
+
+      execute_and_apply(tx_data, signatures, tx, client).await
+    }
+    .await;
+
+    // The reservation is released whatever the outcome. When the transaction failed, a failure to release it is
+    // reported alongside the transaction's error. When the transaction was executed it is not, as returning an
+    // error would hide the executed transaction's output; the gas station frees the coins once the reservation
+    // expires.
+    let release_result = gas_station.release_reservation(reservation_id).await;
+
+    match (result, release_result) {
+      (Err(source), Err(release_error)) => Err(Error::GasReservationNotReleased {
+        source: Box::new(source),
+        reservation_id,
+        release_error: Box::new(release_error),
+      }),
+      (result, _) => result,
+    }
+  }
+
+  /// Exports this transaction as an [UnsignedTransaction] that can be signed offline, e.g. on an air-gapped
+  /// machine, through [UnsignedTransaction::sign].
+  ///
+  /// Missing gas information is filled in a best effort manner, using the sender - that must have been set through
+  /// [TransactionBuilder::with_sender] - as the default gas owner.
+  /// The gas information is then fixed in this builder, making sure any signature over the returned
+  /// transaction is valid for this builder too.
+  /// ## Notes
+  /// Signatures collected offline can be added back through [TransactionBuilder::with_external_signature].
+  pub async fn export_unsigned(&mut self, client: &IdentityClientReadOnly) -> Result<UnsignedTransaction, Error> {
+    let sender = self
+      .sender
+      .ok_or_else(|| Error::TransactionBuildingFailed("missing sender".to_owned()))?;
+    let programmable_tx = self.get_or_init_programmable_tx(client).await?.clone();
+    let gas_data = complete_gas_data_for_tx(
+      &programmable_tx,
+      self.gas.clone(),
+      client,
+      sender,
+      sender,
+      self.gas_budget_margin,
+    )
+    .await?;
+    self.gas = gas_data.clone().into();
 
-    // Get the transaction's effects, making sure they are successful.
-    let tx_effects = dyn_tx_block
-      .effects()
-      .ok_or_else(|| Error::TransactionUnexpectedResponse("missing effects in response".to_owned()))?
-      .clone();
-    let tx_status = tx_effects.status();
-    if tx_status.is_err() {
-      return Err(Error::TransactionUnexpectedResponse(format!(
-        "errors in transaction's effects: {}",
-        tx_status
+    UnsignedTransaction::new(TransactionData::new_with_gas_data(
+      TransactionKind::ProgrammableTransaction(programmable_tx),
+      sender,
+      gas_data,
+    ))
+  }
+
+  /// Dry runs this transaction, returning a [TransactionPreview] of its effects - e.g. created and mutated
+  /// objects, balance changes, and changes to a DID Document - without signing nor executing it.
+  ///
+  /// When no sender or gas owner were supplied, client's address is used. Missing gas payment is simulated
+  /// by the node.
+  /// When no gas budget was set, the gas used during the dry run - increased by the gas budget margin, see
+  /// [TransactionBuilder::with_gas_budget_margin] - is set as this transaction's gas budget.
+  /// # Failures
+  /// This method fails if the transaction would fail once executed, e.g. for a lack of gas.
+  pub async fn dry_run<S>(&mut self, client: &IdentityClient<S>) -> Result<TransactionPreview, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+  {
+    let client_address = client.sender_address();
+    let sender = self.sender.unwrap_or(client_address);
+    let programmable_tx = self.get_or_init_programmable_tx(client).await?.clone();
+    let (response, gas_data) = dry_run_transaction(
+      &programmable_tx,
+      &self.gas,
+      client,
+      sender,
+      client_address,
+      DRY_RUN_GAS_BUDGET,
+      self.gas_budget_margin,
+    )
+    .await?;
+    self.gas.budget = Some(gas_data.budget);
+
+    let effects = &response.effects;
+    Ok(TransactionPreview {
+      gas_budget: gas_data.budget,
+      gas_cost_summary: effects.gas_cost_summary().clone(),
+      created_objects: effects.created().to_vec(),
+      mutated_objects: effects.mutated_excluding_gas(),
+      deleted_objects: effects
+        .all_deleted_objects()
+        .into_iter()
+        .map(|(obj_ref, _)| obj_ref.object_id)
+        .collect(),
+      balance_changes: response.balance_changes,
+      did_document_diff: self.tx.did_document_diff()?,
+    })
+  }
+
+  /// Executes this transaction, using only the signatures that were previously added to this builder.
+  ///
+  /// After the transaction has been successfully executed, the transaction's effect will be computed.
+  /// ## Notes
+  /// Unlike [TransactionBuilder::build_and_execute] this method doesn't require a signer, making
+  /// it suitable to execute transactions whose signatures were all collected offline.
+  /// # Failures
+  /// This method fails if the transaction is incomplete or if any of its required signatures is missing.
+  pub async fn execute(self, client: &IdentityClientReadOnly) -> Result<TransactionOutput<Tx::Output>, Error> {
+    let tx_data = self.complete_transaction_data()?;
+    let signers = self.signatures.iter().map(address_from_signature).collect_vec();
+    if let Some(missing_signer) = UnsignedTransaction::new(tx_data.clone())?
+      .required_signers()
+      .into_iter()
+      .find(|address| !signers.contains(address))
+    {
+      return Err(Error::TransactionBuildingFailed(format!(
+        "missing signature for address {missing_signer}"
       )));
     }
 
-    let (application_result, _remaining_effects) = tx.apply(tx_effects, client).await;
-    let response = {
-      cfg_if! {
-        if #[cfg(target_arch = "wasm32")] {
-          dyn_tx_block
-        } else {
-          dyn_tx_block.clone_native_response()
-        }
-      }
-    };
-    // Apply the off-chain logic of the transaction by parsing the transaction's effects.
-    // If the application goes awry, salvage the response by returning it alongside the error.
-    let output = match application_result {
-      Ok(output) => output,
-      Err(e) => {
-        #[cfg(not(target_arch = "wasm32"))]
-        let response = Box::new(response);
-        #[cfg(target_arch = "wasm32")]
-        // For WASM the response is passed in the error as its JSON-encoded string representation.
-        let response = response.as_native_response().to_string();
-        return Err(Error::TransactionOffChainApplicationFailure {
-          source: Box::new(e),
-          response,
-        });
-      }
-    };
+    execute_and_apply(tx_data, self.signatures, self.tx, client).await
+  }
+}
 
-    Ok(TransactionOutput { output, response })
+/// Executes the given transaction and applies its off-chain logic.
+async fn execute_and_apply<Tx>(
+  tx_data: TransactionData,
+  signatures: Vec<Signature>,
+  tx: Tx,
+  client: &IdentityClientReadOnly,
+) -> Result<TransactionOutput<Tx::Output>, Error>
+where
+  Tx: Transaction,
+{
+  // Execute and wait for the transaction to be confirmed.
+  let dyn_tx_block = client
+    .quorum_driver_api()
+    .execute_transaction_block(
+      tx_data,
+      signatures,
+      Some(IotaTransactionBlockResponseOptions::full_content()),
+      Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+    )
+    .await?;
+
+  // Get the transaction's effects, making sure they are successful.
+  let tx_effects = dyn_tx_block
+    .effects()
+    .ok_or_else(|| Error::TransactionUnexpectedResponse("missing effects in response".to_owned()))?
+    .clone();
+  let tx_status = tx_effects.status();
+  if tx_status.is_err() {
+    return Err(Error::TransactionUnexpectedResponse(format!(
+      "errors in transaction's effects: {}",
+      tx_status
+    )));
   }
+
+  let (application_result, _remaining_effects) = tx.apply(tx_effects, client).await;
+  let response = {
+    cfg_if! {
+      if #[cfg(target_arch = "wasm32")] {
+        dyn_tx_block
+      } else {
+        dyn_tx_block.clone_native_response()
+      }
+    }
+  };
+  // Apply the off-chain logic of the transaction by parsing the transaction's effects.
+  // If the application goes awry, salvage the response by returning it alongside the error.
+  let output = match application_result {
+    Ok(output) => output,
+    Err(e) => {
+      #[cfg(not(target_arch = "wasm32"))]
+      let response = Box::new(response);
+      #[cfg(target_arch = "wasm32")]
+      // For WASM the response is passed in the error as its JSON-encoded string representation.
+      let response = response.as_native_response().to_string();
+      return Err(Error::TransactionOffChainApplicationFailure {
+        source: Box::new(e),
+        response,
+      });
+    }
+  };
+
+  Ok(TransactionOutput { output, response })
 }
 
 impl<Tx> TransactionBuilder<Tx> {
@@ -402,6 +619,15 @@ impl<Tx> TransactionBuilder<Tx> {
     self
   }
 
+  /// Sets the margin, as a percentage, added to this transaction's gas budget when the
+  /// budget is estimated by dry running the transaction. Defaults to [DEFAULT_GAS_BUDGET_MARGIN].
+  /// ## Notes
+  /// The margin has no effect on budgets set through [TransactionBuilder::with_gas_budget].
+  pub fn with_gas_budget_margin(mut self, margin_percentage: u64) -> Self {
+    self.gas_budget_margin = margin_percentage;
+    self
+  }
+
   /// Sets the coins to use to cover the gas cost.
   pub fn with_gas_payment(mut self, coins: Vec<ObjectRef>) -> Self {
     self.gas.payment = coins;
@@ -426,6 +652,98 @@ impl<Tx> TransactionBuilder<Tx> {
     self
   }
 
+  /// Batches `other`'s transaction after this one, making both execute atomically in a single
+  /// programmable transaction, paid with a single gas payment. See [BatchedTransaction].
+  ///
+  /// The output of the resulting transaction is the pair of the two transactions' outputs.
+  /// ## Notes
+  /// Only this builder's sender and gas information are kept; `other`'s are discarded alongside
+  /// any signature previously added to either builder, as they would no longer be valid.
+  pub fn batch_with<Tx2>(self, other: TransactionBuilder<Tx2>) -> TransactionBuilder<BatchedTransaction<Tx, Tx2>> {
+    TransactionBuilder {
+      programmable_tx: None,
+      sender: self.sender,
+      gas: self.gas,
+      gas_budget_margin: self.gas_budget_margin,
+      signatures: vec![],
+      tx: BatchedTransaction::new(self.tx, other.tx),
+    }
+  }
+
+  /// Batches `next` after this transaction, making both execute atomically in a single programmable
+  /// transaction, paid with a single gas payment. Unlike [TransactionBuilder::batch_with], `next` can use
+  /// the values returned by this transaction's commands. See [DependentTransaction].
+  ///
+  /// The output of the resulting transaction is the pair of the two transactions' outputs.
+  /// ## Notes
+  /// Any signature previously added to this builder is discarded, as it would no longer be valid.
+  pub fn batch_with_dependent<Tx2>(self, next: Tx2) -> TransactionBuilder<DependentBatchedTransaction<Tx, Tx2>>
+  where
+    Tx2: DependentTransaction,
+  {
+    TransactionBuilder {
+      programmable_tx: None,
+      sender: self.sender,
+      gas: self.gas,
+      gas_budget_margin: self.gas_budget_margin,
+      signatures: vec![],
+      tx: DependentBatchedTransaction::new(self.tx, next),
+    }
+  }
+
+  /// Attempts to construct a [TransactionBuilder] from a transaction that was previously
+  /// exported through [TransactionBuilder::export_unsigned].
+  pub fn from_unsigned_transaction(unsigned_tx: UnsignedTransaction, effect: Tx) -> Result<Self, Error> {
+    Self::try_from_signed_transaction(unsigned_tx.into_transaction_data(), vec![], effect)
+  }
+
+  /// Adds a signature that was produced elsewhere - e.g. through [UnsignedTransaction::sign] on
+  /// an offline machine - to this transaction's signatures list.
+  /// # Failures
+  /// This method fails if:
+  /// - this transaction hasn't been completed yet, see [TransactionBuilder::export_unsigned];
+  /// - `signature` is not a valid signature for this transaction;
+  /// - `signature`'s signer is neither this transaction's sender nor its gas owner.
+  pub fn with_external_signature(mut self, signature: Signature) -> Result<Self, Error> {
+    let tx_data = self.complete_transaction_data()?;
+    let signer_address = address_from_signature(&signature);
+    if signer_address != tx_data.sender() && signer_address != tx_data.gas_owner() {
+      return Err(Error::TransactionBuildingFailed(format!(
+        "signer's address {signer_address} doesn't match the address of either the transaction sender or the gas owner"
+      )));
+    }
+
+    let intent_msg = IntentMessage::new(Intent::iota_transaction(), tx_data);
+    signature
+      .verify_secure(&intent_msg, signer_address, signature.scheme())
+      .map_err(|e| Error::TransactionBuildingFailed(format!("invalid signature: {e}")))?;
+
+    // Replace any previous signature by the same signer.
+    self
+      .signatures
+      .retain(|sig| address_from_signature(sig) != signer_address);
+    self.signatures.push(signature);
+
+    Ok(self)
+  }
+
+  /// Returns the complete [TransactionData] for this transaction, without contacting the network.
+  fn complete_transaction_data(&self) -> Result<TransactionData, Error> {
+    let pt = self.programmable_tx.clone().ok_or_else(|| {
+      Error::TransactionBuildingFailed("transaction has not been built yet; use `export_unsigned`".to_owned())
+    })?;
+    let sender = self
+      .sender
+      .ok_or_else(|| Error::TransactionBuildingFailed("missing sender".to_owned()))?;
+    let gas_data = GasData::try_from(self.gas.clone())?;
+
+    Ok(TransactionData::new_with_gas_data(
+      TransactionKind::ProgrammableTransaction(pt),
+      sender,
+      gas_data,
+    ))
+  }
+
   /// Attempts to construct a [TransactionBuilder] from a whole transaction.
   pub fn try_from_signed_transaction(
     tx_data: TransactionData,
@@ -445,6 +763,7 @@ impl<Tx> TransactionBuilder<Tx> {
       programmable_tx: Some(pt),
       sender: Some(sender),
       gas,
+      gas_budget_margin: DEFAULT_GAS_BUDGET_MARGIN,
       signatures,
       tx: effect,
     })
@@ -454,33 +773,42 @@ impl<Tx> TransactionBuilder<Tx> {
 /// Returns a best effort [GasData] for the given transaction, partial gas information, and client.
 /// ## Notes
 /// If a field is missing from gas data:
-/// - client's address is set as the gas owner;
+/// - `default_owner` is set as the gas owner;
 /// - current gas price is fetched from a node;
-/// - budget is calculated by dry running the transaction;
+/// - budget is estimated by dry running the transaction, and increased by `budget_margin` percent;
 /// - payment is set to whatever IOTA coins the gas owner has, that satisfy the tx's budget;
-async fn complete_gas_data_for_tx<S>(
+async fn complete_gas_data_for_tx(
   pt: &ProgrammableTransaction,
   partial_gas_data: PartialGasData,
-  client: &IdentityClient<S>,
-) -> anyhow::Result<GasData>
-where
-  S: Signer<IotaKeySignature>,
-{
-  let owner = partial_gas_data.owner.unwrap_or(client.sender_address());
-  let price = if let Some(price) = partial_gas_data.price {
-    price
-  } else {
-    client.read_api().get_reference_gas_price().await?
-  };
-  let budget = if let Some(budget) = partial_gas_data.budget {
-    budget
-  } else {
-    client.default_gas_budget(owner, pt).await?
+  client: &IdentityClientReadOnly,
+  sender: IotaAddress,
+  default_owner: IotaAddress,
+  budget_margin: u64,
+) -> Result<GasData, Error> {
+  let owner = partial_gas_data.owner.unwrap_or(default_owner);
+  let (price, budget) = match (partial_gas_data.price, partial_gas_data.budget) {
+    (Some(price), Some(budget)) => (price, budget),
+    _ => {
+      let (_, gas_data) = dry_run_transaction(
+        pt,
+        &partial_gas_data,
+        client,
+        sender,
+        default_owner,
+        DRY_RUN_GAS_BUDGET,
+        budget_margin,
+      )
+      .await?;
+      (gas_data.price, gas_data.budget)
+    }
   };
   let payment = if !partial_gas_data.payment.is_empty() {
     partial_gas_data.payment
   } else {
-    client.get_iota_coins_with_at_least_balance(owner, budget).await?
+    client
+      .get_iota_coins_with_at_least_balance(owner, budget)
+      .await
+      .map_err(|e| Error::GasIssue(e.to_string()))?
   };
 
   Ok(GasData {
@@ -491,6 +819,74 @@ where
   })
 }
 
+/// Dry runs the given transaction, returning the node's response alongside the [GasData]
+/// that should be used to execute it.
+/// ## Notes
+/// Missing gas information is filled as in [complete_gas_data_for_tx], except for payment,
+/// which is left empty - and simulated by the node - if missing.
+/// # Failures
+/// This function fails if the transaction's execution would fail.
+async fn dry_run_transaction(
+  pt: &ProgrammableTransaction,
+  partial_gas_data: &PartialGasData,
+  client: &IdentityClientReadOnly,
+  sender: IotaAddress,
+  default_owner: IotaAddress,
+  dry_run_budget: u64,
+  budget_margin: u64,
+) -> Result<(DryRunTransactionBlockResponse, GasData), Error> {
+  let price = if let Some(price) = partial_gas_data.price {
+    price
+  } else {
+    client
+      .read_api()
+      .get_reference_gas_price()
+      .await
+      .map_err(|e| Error::RpcError(e.to_string()))?
+  };
+  let gas_data = GasData {
+    payment: partial_gas_data.payment.clone(),
+    owner: partial_gas_data.owner.unwrap_or(default_owner),
+    price,
+    budget: partial_gas_data.budget.unwrap_or(dry_run_budget),
+  };
+  let tx_data = TransactionData::new_with_gas_data(
+    TransactionKind::ProgrammableTransaction(pt.clone()),
+    sender,
+    gas_data.clone(),
+  );
+
+  let response = client
+    .read_api()
+    .dry_run_transaction_block(tx_data)
+    .await
+    .map_err(|e| Error::RpcError(e.to_string()))?;
+  if let IotaExecutionStatus::Failure { error } = response.effects.status() {
+    return Err(if error.contains("InsufficientGas") {
+      Error::GasIssue(format!("transaction would run out of gas: {error}"))
+    } else {
+      Error::TransactionUnexpectedResponse(format!("transaction dry run failed: {error}"))
+    });
+  }
+
+  let budget = partial_gas_data
+    .budget
+    .unwrap_or_else(|| gas_budget_from_effects(&response.effects, price, budget_margin));
+
+  Ok((response, GasData { budget, ..gas_data }))
+}
+
+/// Computes a gas budget for a transaction from its (dry run) effects, increased by `margin` percent.
+fn gas_budget_from_effects(effects: &IotaTransactionBlockEffects, gas_price: u64, margin: u64) -> u64 {
+  let gas_summary = effects.gas_cost_summary();
+  // Same overhead used by the IOTA SDK when estimating a transaction's budget.
+  let overhead = gas_price.saturating_mul(1000);
+  let net_used = gas_summary.net_gas_usage().max(0) as u64;
+  let budget = overhead.saturating_add(net_used.max(gas_summary.computation_cost));
+
+  budget.saturating_add(budget.saturating_mul(margin) / 100)
+}
+
 /// Extract the signer's address from an IOTA [Signature].
 fn address_from_signature(signature: &Signature) -> IotaAddress {
   let scheme = signature.scheme();
@@ -0,0 +1,258 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_did::DIDUrl;
+use identity_iota_interaction::rpc_types::BalanceChange;
+use identity_iota_interaction::rpc_types::OwnedObjectRef;
+use identity_iota_interaction::types::base_types::ObjectID;
+use identity_iota_interaction::types::gas::GasCostSummary;
+use identity_verification::VerificationMethod;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::IotaDocument;
+use crate::StateMetadataDocument;
+
+use super::Error;
+
+/// The effects a transaction would have if it were executed, computed by dry running it.
+///
+/// A [TransactionPreview] is obtained through [TransactionBuilder::dry_run], and is meant to be
+/// reviewed before signing a transaction.
+///
+/// [TransactionBuilder::dry_run]: super::transaction_builder::TransactionBuilder::dry_run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPreview {
+  /// The gas budget that will be used for this transaction, margin included.
+  pub gas_budget: u64,
+  /// The gas this transaction consumed during its dry run.
+  pub gas_cost_summary: GasCostSummary,
+  /// The objects this transaction creates.
+  pub created_objects: Vec<OwnedObjectRef>,
+  /// The objects this transaction mutates, excluding the gas coin.
+  pub mutated_objects: Vec<OwnedObjectRef>,
+  /// The IDs of the objects this transaction deletes or wraps.
+  pub deleted_objects: Vec<ObjectID>,
+  /// The changes to the balances of the addresses involved in this transaction, gas included.
+  pub balance_changes: Vec<BalanceChange>,
+  /// The changes this transaction makes to a DID Document, if any.
+  pub did_document_diff: Option<DidDocumentDiff>,
+}
+
+/// The changes a transaction makes to a DID Document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentDiff {
+  previous: Option<IotaDocument>,
+  next: Option<IotaDocument>,
+}
+
+impl DidDocumentDiff {
+  /// Creates a new [DidDocumentDiff] between `previous` and `next`.
+  /// `previous` is `None` when the DID Document is being created, while `next`
+  /// is `None` when it is being deleted.
+  pub fn new(previous: Option<IotaDocument>, next: Option<IotaDocument>) -> Self {
+    Self { previous, next }
+  }
+
+  /// Creates a [DidDocumentDiff] for an update of `previous` to the packed DID Document `next_bytes`,
+  /// as stored on-chain.
+  /// ## Notes
+  /// - `None` bytes mean the DID Document is being deleted;
+  /// - empty bytes mean the DID Document is being deactivated.
+  pub(crate) fn from_update(previous: &IotaDocument, next_bytes: Option<&[u8]>) -> Result<Self, Error> {
+    let next = match next_bytes {
+      None => None,
+      Some([]) => {
+        let mut deactivated = IotaDocument::new_with_id(previous.id().clone());
+        deactivated.metadata.deactivated = Some(true);
+        Some(deactivated)
+      }
+      Some(bytes) => {
+        let mut next = StateMetadataDocument::unpack(bytes)
+          .and_then(|doc| doc.into_iota_document(previous.id()))
+          .map_err(|e| Error::DidDocParsingFailed(e.to_string()))?;
+        next.metadata.created = previous.metadata.created;
+        Some(next)
+      }
+    };
+
+    Ok(Self::new(Some(previous.clone()), next))
+  }
+
+  /// Returns the DID Document before the transaction, if any.
+  pub fn previous(&self) -> Option<&IotaDocument> {
+    self.previous.as_ref()
+  }
+
+  /// Returns the DID Document after the transaction, if any.
+  pub fn next(&self) -> Option<&IotaDocument> {
+    self.next.as_ref()
+  }
+
+  /// Returns whether this transaction creates a new DID Document.
+  pub fn is_creation(&self) -> bool {
+    self.previous.is_none() && self.next.is_some()
+  }
+
+  /// Returns whether this transaction deletes the DID Document.
+  pub fn is_deletion(&self) -> bool {
+    self.previous.is_some() && self.next.is_none()
+  }
+
+  /// Returns whether this transaction deactivates the DID Document.
+  pub fn is_deactivation(&self) -> bool {
+    let is_deactivated = |doc: &IotaDocument| doc.metadata.deactivated.unwrap_or_default();
+    self.next.as_ref().is_some_and(is_deactivated) && !self.previous.as_ref().is_some_and(is_deactivated)
+  }
+
+  /// Returns the IDs of the verification methods that are added by this transaction.
+  pub fn added_methods(&self) -> Vec<&DIDUrl> {
+    missing_from(methods(self.next.as_ref()), &methods(self.previous.as_ref()))
+  }
+
+  /// Returns the IDs of the verification methods that are removed by this transaction.
+  pub fn removed_methods(&self) -> Vec<&DIDUrl> {
+    missing_from(methods(self.previous.as_ref()), &methods(self.next.as_ref()))
+  }
+
+  /// Returns the IDs of the verification methods that exist both before and after this transaction,
+  /// but whose content - e.g. key material - is changed.
+  pub fn updated_methods(&self) -> Vec<&DIDUrl> {
+    let previous = methods(self.previous.as_ref());
+    methods(self.next.as_ref())
+      .into_iter()
+      .filter(|method| {
+        previous
+          .iter()
+          .any(|prev_method| prev_method.id() == method.id() && prev_method != method)
+      })
+      .map(VerificationMethod::id)
+      .collect()
+  }
+
+  /// Returns the IDs of the services that are added by this transaction.
+  pub fn added_services(&self) -> Vec<&DIDUrl> {
+    let previous = service_ids(self.previous.as_ref());
+    service_ids(self.next.as_ref())
+      .into_iter()
+      .filter(|id| !previous.contains(id))
+      .collect()
+  }
+
+  /// Returns the IDs of the services that are removed by this transaction.
+  pub fn removed_services(&self) -> Vec<&DIDUrl> {
+    let next = service_ids(self.next.as_ref());
+    service_ids(self.previous.as_ref())
+      .into_iter()
+      .filter(|id| !next.contains(id))
+      .collect()
+  }
+
+  /// Returns whether this transaction changes anything in the DID Document.
+  pub fn has_changes(&self) -> bool {
+    match (&self.previous, &self.next) {
+      (Some(previous), Some(next)) => previous.core_document() != next.core_document() || self.is_deactivation(),
+      (None, None) => false,
+      _ => true,
+    }
+  }
+}
+
+fn methods(document: Option<&IotaDocument>) -> Vec<&VerificationMethod> {
+  document.map(|doc| doc.methods(None)).unwrap_or_default()
+}
+
+fn service_ids(document: Option<&IotaDocument>) -> Vec<&DIDUrl> {
+  document
+    .map(|doc| doc.service().iter().map(|service| service.id()).collect())
+    .unwrap_or_default()
+}
+
+fn missing_from<'a>(methods: Vec<&'a VerificationMethod>, other: &[&VerificationMethod]) -> Vec<&'a DIDUrl> {
+  methods
+    .into_iter()
+    .filter(|method| !other.iter().any(|other_method| other_method.id() == method.id()))
+    .map(VerificationMethod::id)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::FromJson;
+  use identity_document::service::Service;
+  use identity_verification::MethodScope;
+
+  use super::*;
+  use crate::test_utils::generate_method;
+  use crate::IotaDID;
+  use crate::NetworkName;
+
+  fn document() -> IotaDocument {
+    let did = IotaDID::placeholder(&NetworkName::try_from("iota").unwrap());
+    let mut document = IotaDocument::new_with_id(did.clone());
+    document
+      .insert_method(generate_method(&did, "#key-1"), MethodScope::VerificationMethod)
+      .unwrap();
+    document
+      .insert_method(generate_method(&did, "#key-2"), MethodScope::VerificationMethod)
+      .unwrap();
+
+    document
+  }
+
+  #[test]
+  fn diff_reports_method_and_service_changes() {
+    let previous = document();
+    let did = previous.id().clone();
+    let key_1_id = did.to_url().join("#key-1").unwrap();
+    let key_2_id = did.to_url().join("#key-2").unwrap();
+    let key_3_id = did.to_url().join("#key-3").unwrap();
+    let service_id = did.to_url().join("#linked-domain").unwrap();
+
+    let mut next = previous.clone();
+    next.remove_method(&key_1_id).unwrap();
+    next.remove_method(&key_2_id).unwrap();
+    next
+      .insert_method(generate_method(&did, "#key-2"), MethodScope::VerificationMethod)
+      .unwrap();
+    next
+      .insert_method(generate_method(&did, "#key-3"), MethodScope::VerificationMethod)
+      .unwrap();
+    let service = Service::from_json(&format!(
+      r#"{{
+      "id":"{service_id}",
+      "type": "LinkedDomains",
+      "serviceEndpoint": "https://bar.example.com"
+    }}"#
+    ))
+    .unwrap();
+    next.insert_service(service).unwrap();
+
+    let diff = DidDocumentDiff::new(Some(previous), Some(next));
+    assert!(diff.has_changes());
+    assert_eq!(diff.added_methods(), vec![&key_3_id]);
+    assert_eq!(diff.removed_methods(), vec![&key_1_id]);
+    assert_eq!(diff.updated_methods(), vec![&key_2_id]);
+    assert_eq!(diff.added_services(), vec![&service_id]);
+    assert!(diff.removed_services().is_empty());
+  }
+
+  #[test]
+  fn diff_from_update_handles_deactivation_and_deletion() {
+    let previous = document();
+
+    let deactivation = DidDocumentDiff::from_update(&previous, Some(&[])).unwrap();
+    assert!(deactivation.is_deactivation());
+    assert_eq!(deactivation.removed_methods().len(), 2);
+
+    let deletion = DidDocumentDiff::from_update(&previous, None).unwrap();
+    assert!(deletion.is_deletion());
+    assert!(deletion.has_changes());
+
+    let packed = previous.clone().pack().unwrap();
+    let no_op = DidDocumentDiff::from_update(&previous, Some(&packed)).unwrap();
+    assert!(!no_op.has_changes());
+  }
+}
@@ -0,0 +1,272 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use fastcrypto::hash::Blake2b256;
+use fastcrypto::hash::HashFunction as _;
+use identity_iota_interaction::shared_crypto::intent::Intent;
+use identity_iota_interaction::types::base_types::IotaAddress;
+use identity_iota_interaction::types::base_types::ObjectRef;
+use identity_iota_interaction::types::crypto::Signature;
+use identity_iota_interaction::types::transaction::Argument;
+use identity_iota_interaction::types::transaction::CallArg;
+use identity_iota_interaction::types::transaction::Command;
+use identity_iota_interaction::types::transaction::ObjectArg;
+use identity_iota_interaction::types::transaction::ProgrammableTransaction;
+use identity_iota_interaction::types::transaction::TransactionData;
+use identity_iota_interaction::types::transaction::TransactionDataAPI as _;
+use identity_iota_interaction::types::transaction::TransactionKind;
+use identity_iota_interaction::IotaKeySignature;
+use identity_iota_interaction::OptionalSync;
+use secret_storage::Signer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Error;
+
+/// A fully specified - i.e. with complete gas information - transaction that still
+/// needs to be signed by one or more parties.
+///
+/// An [`UnsignedTransaction`] can be exported to its BCS representation, moved to
+/// an offline environment (e.g. an air-gapped machine holding a controller's key),
+/// and signed there through [`UnsignedTransaction::sign`]. The resulting signatures
+/// can then be attached to the originating [`TransactionBuilder`] through
+/// [`TransactionBuilder::with_external_signature`].
+///
+/// [`TransactionBuilder`]: super::transaction_builder::TransactionBuilder
+/// [`TransactionBuilder::with_external_signature`]: super::transaction_builder::TransactionBuilder::with_external_signature
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UnsignedTransaction(TransactionData);
+
+impl UnsignedTransaction {
+  /// Wraps the given [`TransactionData`].
+  /// # Failures
+  /// This function fails if `tx_data` is not a programmable transaction.
+  pub fn new(tx_data: TransactionData) -> Result<Self, Error> {
+    #[allow(irrefutable_let_patterns)]
+    let TransactionKind::ProgrammableTransaction(_) = tx_data.kind() else {
+      return Err(Error::TransactionBuildingFailed(
+        "only programmable transactions are supported".to_string(),
+      ));
+    };
+
+    Ok(Self(tx_data))
+  }
+
+  /// Deserializes an [`UnsignedTransaction`] from its BCS representation.
+  pub fn from_bcs(bytes: &[u8]) -> Result<Self, Error> {
+    let tx_data = bcs::from_bytes::<TransactionData>(bytes)?;
+    Self::new(tx_data)
+  }
+
+  /// Returns the BCS representation of this transaction.
+  pub fn to_bcs(&self) -> Result<Vec<u8>, Error> {
+    Ok(bcs::to_bytes(&self.0)?)
+  }
+
+  /// Returns a reference to the wrapped [`TransactionData`].
+  pub fn transaction_data(&self) -> &TransactionData {
+    &self.0
+  }
+
+  /// Consumes this [`UnsignedTransaction`] returning the wrapped [`TransactionData`].
+  pub fn into_transaction_data(self) -> TransactionData {
+    self.0
+  }
+
+  /// Returns the [`ProgrammableTransaction`] contained in this transaction.
+  pub fn programmable_transaction(&self) -> &ProgrammableTransaction {
+    #[allow(irrefutable_let_patterns)]
+    let TransactionKind::ProgrammableTransaction(pt) = self.0.kind() else {
+      unreachable!("checked on construction");
+    };
+    pt
+  }
+
+  /// Returns the addresses whose signatures are required for this transaction to be executed,
+  /// i.e. the sender's and - if different - the gas owner's.
+  pub fn required_signers(&self) -> Vec<IotaAddress> {
+    let sender = self.0.sender();
+    let gas_owner = self.0.gas_owner();
+    if sender == gas_owner {
+      vec![sender]
+    } else {
+      vec![sender, gas_owner]
+    }
+  }
+
+  /// Returns the digest that has to be signed in order to produce a valid signature for this transaction.
+  ///
+  /// This is the Blake2b-256 hash of the transaction's intent message, and can be used
+  /// to sign this transaction with tools that do not understand IOTA transactions,
+  /// e.g. an HSM. The resulting signature must be encoded as an IOTA [`Signature`]
+  /// - flag || signature || public key - before being attached to the transaction.
+  pub fn signing_digest(&self) -> Result<[u8; 32], Error> {
+    let tx_data_bcs = self.to_bcs()?;
+    let mut hasher = Blake2b256::default();
+    hasher.update(Intent::iota_transaction().to_bytes());
+    hasher.update(&tx_data_bcs);
+
+    Ok(hasher.finalize().digest)
+  }
+
+  /// Signs this transaction with the given `signer`.
+  /// ## Notes
+  /// This method doesn't require any network connection and is therefore suitable
+  /// to be used in offline environments.
+  pub async fn sign<S>(&self, signer: &S) -> Result<Signature, Error>
+  where
+    S: Signer<IotaKeySignature> + OptionalSync,
+  {
+    signer
+      .sign(&self.0)
+      .await
+      .map_err(|e| Error::TransactionSigningFailed(e.to_string()))
+  }
+
+  /// Returns a human-readable summary of this transaction, meant to be reviewed
+  /// by a signer before they sign it.
+  pub fn summary(&self) -> TransactionSummary {
+    TransactionSummary::from(self)
+  }
+}
+
+impl TryFrom<TransactionData> for UnsignedTransaction {
+  type Error = Error;
+  fn try_from(value: TransactionData) -> Result<Self, Self::Error> {
+    Self::new(value)
+  }
+}
+
+impl From<UnsignedTransaction> for TransactionData {
+  fn from(value: UnsignedTransaction) -> Self {
+    value.0
+  }
+}
+
+/// A human-readable description of an [`UnsignedTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSummary {
+  /// The address executing the transaction.
+  pub sender: IotaAddress,
+  /// The address paying for the transaction.
+  pub gas_owner: IotaAddress,
+  /// The maximum amount of gas, in NANOs, this transaction can use.
+  pub gas_budget: u64,
+  /// The gas price, in NANOs, for this transaction.
+  pub gas_price: u64,
+  /// The coins used to pay for gas.
+  pub gas_payment: Vec<String>,
+  /// A description of each of the transaction's inputs.
+  pub inputs: Vec<String>,
+  /// A description of each of the transaction's commands, e.g. Move calls.
+  pub commands: Vec<String>,
+}
+
+impl From<&UnsignedTransaction> for TransactionSummary {
+  fn from(tx: &UnsignedTransaction) -> Self {
+    let tx_data = tx.transaction_data();
+    let pt = tx.programmable_transaction();
+
+    Self {
+      sender: tx_data.sender(),
+      gas_owner: tx_data.gas_owner(),
+      gas_budget: tx_data.gas_budget(),
+      gas_price: tx_data.gas_price(),
+      gas_payment: tx_data.gas().iter().map(fmt_object_ref).collect(),
+      inputs: pt.inputs.iter().map(fmt_call_arg).collect(),
+      commands: pt.commands.iter().map(fmt_command).collect(),
+    }
+  }
+}
+
+impl Display for TransactionSummary {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "sender: {}", self.sender)?;
+    writeln!(f, "gas owner: {}", self.gas_owner)?;
+    writeln!(f, "gas budget: {}", self.gas_budget)?;
+    writeln!(f, "gas price: {}", self.gas_price)?;
+    writeln!(f, "gas payment:")?;
+    for coin in &self.gas_payment {
+      writeln!(f, "  {coin}")?;
+    }
+    writeln!(f, "inputs:")?;
+    for (i, input) in self.inputs.iter().enumerate() {
+      writeln!(f, "  Input({i}): {input}")?;
+    }
+    writeln!(f, "commands:")?;
+    for (i, command) in self.commands.iter().enumerate() {
+      writeln!(f, "  Result({i}): {command}")?;
+    }
+
+    Ok(())
+  }
+}
+
+fn fmt_object_ref((id, version, digest): &ObjectRef) -> String {
+  format!("{id} (version {version}, digest {digest})")
+}
+
+fn fmt_call_arg(arg: &CallArg) -> String {
+  match arg {
+    CallArg::Pure(bytes) => format!("pure {}", prefix_hex::encode(bytes.as_slice())),
+    CallArg::Object(ObjectArg::ImmOrOwnedObject(obj_ref)) => format!("object {}", fmt_object_ref(obj_ref)),
+    CallArg::Object(ObjectArg::SharedObject {
+      id,
+      initial_shared_version,
+      mutable,
+    }) => format!(
+      "shared object {id} (initial version {initial_shared_version}, {})",
+      if *mutable { "mutable" } else { "immutable" }
+    ),
+    CallArg::Object(ObjectArg::Receiving(obj_ref)) => format!("receiving object {}", fmt_object_ref(obj_ref)),
+  }
+}
+
+fn fmt_args(args: &[Argument]) -> String {
+  args.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+}
+
+fn fmt_command(command: &Command) -> String {
+  match command {
+    Command::MoveCall(call) => {
+      let type_args = if call.type_arguments.is_empty() {
+        String::default()
+      } else {
+        let type_args = call
+          .type_arguments
+          .iter()
+          .map(ToString::to_string)
+          .collect::<Vec<_>>()
+          .join(", ");
+        format!("<{type_args}>")
+      };
+      format!(
+        "MoveCall {}::{}::{}{type_args}({})",
+        call.package,
+        call.module,
+        call.function,
+        fmt_args(&call.arguments)
+      )
+    }
+    Command::TransferObjects(objects, recipient) => {
+      format!("TransferObjects([{}], {recipient})", fmt_args(objects))
+    }
+    Command::SplitCoins(coin, amounts) => format!("SplitCoins({coin}, [{}])", fmt_args(amounts)),
+    Command::MergeCoins(coin, coins) => format!("MergeCoins({coin}, [{}])", fmt_args(coins)),
+    Command::Publish(modules, deps) => format!("Publish({} modules, {} dependencies)", modules.len(), deps.len()),
+    Command::MakeMoveVec(type_tag, elements) => {
+      let type_tag = type_tag.as_ref().map(|tag| format!("<{tag}>")).unwrap_or_default();
+      format!("MakeMoveVec{type_tag}([{}])", fmt_args(elements))
+    }
+    Command::Upgrade(modules, deps, package, ticket) => format!(
+      "Upgrade({package}, {} modules, {} dependencies, {ticket})",
+      modules.len(),
+      deps.len()
+    ),
+  }
+}
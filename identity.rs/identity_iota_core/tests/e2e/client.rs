@@ -4,6 +4,8 @@
 use crate::common::get_funded_test_client;
 use crate::common::TestClient;
 use identity_iota_core::rebased::migration;
+use identity_iota_core::rebased::transaction_builder::TransactionBuilder;
+use identity_iota_core::rebased::unsigned_transaction::UnsignedTransaction;
 use identity_iota_core::IotaDocument;
 use iota_sdk::types::crypto::SignatureScheme;
 
@@ -95,3 +97,60 @@ async fn client_with_new_secp256k1_keytool_signer_works() -> anyhow::Result<()>
 
   Ok(())
 }
+
+#[tokio::test]
+async fn transaction_can_be_signed_offline_by_sender_and_sponsor() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let sender_client = test_client.new_user_client().await?;
+  let sponsor_client = test_client.new_user_client().await?;
+
+  let mut tx_builder = sender_client
+    .create_identity(IotaDocument::new(sender_client.network()))
+    .finish()
+    .with_sender(sender_client.sender_address())
+    .with_gas_owner(sponsor_client.sender_address());
+  let unsigned_tx = tx_builder.export_unsigned(&sender_client).await?;
+
+  assert_eq!(
+    unsigned_tx.required_signers(),
+    vec![sender_client.sender_address(), sponsor_client.sender_address()]
+  );
+  assert!(unsigned_tx
+    .summary()
+    .commands
+    .iter()
+    .any(|command| command.contains("identity::new")));
+
+  // Signatures are produced separately, from the transaction's BCS encoding only.
+  let tx_bcs = unsigned_tx.to_bcs()?;
+  let sender_signature = UnsignedTransaction::from_bcs(&tx_bcs)?
+    .sign(sender_client.signer())
+    .await?;
+  let sponsor_signature = UnsignedTransaction::from_bcs(&tx_bcs)?
+    .sign(sponsor_client.signer())
+    .await?;
+
+  // Signatures by parties other than the sender and the sponsor are rejected.
+  let unrelated_signature = unsigned_tx.sign(test_client.signer()).await?;
+  let tx_builder = tx_builder.with_external_signature(sender_signature)?;
+  let Err(_) = TransactionBuilder::from_unsigned_transaction(
+    unsigned_tx.clone(),
+    sender_client
+      .create_identity(IotaDocument::new(sender_client.network()))
+      .finish()
+      .into_inner(),
+  )?
+  .with_external_signature(unrelated_signature) else {
+    anyhow::bail!("a signature from an unrelated address was accepted");
+  };
+
+  let identity = tx_builder
+    .with_external_signature(sponsor_signature)?
+    .execute(&test_client)
+    .await?
+    .output;
+
+  assert!(!identity.has_deleted_did());
+
+  Ok(())
+}
@@ -8,6 +8,10 @@ use async_trait::async_trait;
 use identity_iota_core::rebased::client::IdentityClient;
 use identity_iota_core::rebased::client::IdentityClientReadOnly;
 use identity_iota_core::rebased::keytool::KeytoolSigner;
+#[cfg(feature = "local-ledger")]
+use identity_iota_core::rebased::local_ledger::LocalLedger;
+#[cfg(feature = "local-ledger")]
+use identity_iota_core::rebased::local_ledger::LocalSigner;
 use identity_iota_core::rebased::transaction_builder::Transaction;
 use identity_iota_core::rebased::transaction_builder::TransactionBuilder;
 use identity_iota_core::rebased::utils::request_funds;
@@ -35,9 +39,12 @@ use iota_sdk::rpc_types::IotaObjectDataOptions;
 use iota_sdk::rpc_types::IotaObjectResponse;
 use iota_sdk::types::base_types::IotaAddress;
 use iota_sdk::types::base_types::ObjectID;
+use iota_sdk::types::crypto::PublicKey;
+use iota_sdk::types::crypto::Signature;
 use iota_sdk::types::crypto::SignatureScheme;
 use iota_sdk::types::object::Owner;
 use iota_sdk::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use iota_sdk::types::transaction::TransactionData;
 use iota_sdk::types::TypeTag;
 use iota_sdk::types::IOTA_FRAMEWORK_PACKAGE_ID;
 use iota_sdk::IotaClient;
@@ -46,6 +53,7 @@ use iota_sdk::IOTA_LOCAL_NETWORK_URL;
 use lazy_static::lazy_static;
 use move_core_types::ident_str;
 use move_core_types::language_storage::StructTag;
+use secret_storage::Error as SecretStorageError;
 use secret_storage::Signer;
 use serde::Deserialize;
 use serde_json::Value;
@@ -91,6 +99,14 @@ pub async fn get_funded_test_client() -> anyhow::Result<TestClient> {
   TestClient::new().await
 }
 
+/// Returns whether the tests should run against an in-memory [`LocalLedger`] instead of an IOTA node.
+/// For that the env variable `IOTA_IDENTITY_LOCAL_LEDGER` must be set to `true`, with the `local-ledger` feature.
+fn use_local_ledger() -> bool {
+  std::env::var("IOTA_IDENTITY_LOCAL_LEDGER")
+    .map(|v| v.to_lowercase() == "true")
+    .unwrap_or(false)
+}
+
 async fn init(iota_client: &IotaClient) -> anyhow::Result<ObjectID> {
   let network_id = iota_client.read_api().get_chain_identifier().await?;
   let address = get_active_address().await?;
@@ -205,14 +221,54 @@ async fn get_balance(address: IotaAddress) -> anyhow::Result<u64> {
   Ok(balance)
 }
 
+/// The signer of a [`TestClient`]: the IOTA CLI's keytool when testing against a node,
+/// or an in-memory key when testing against a [`LocalLedger`].
+#[derive(Clone)]
+pub enum TestSigner {
+  Keytool(Arc<KeytoolSigner>),
+  #[cfg(feature = "local-ledger")]
+  Local(LocalSigner),
+}
+
+#[async_trait]
+impl Signer<IotaKeySignature> for TestSigner {
+  type KeyId = IotaAddress;
+
+  fn key_id(&self) -> Self::KeyId {
+    match self {
+      Self::Keytool(signer) => signer.key_id(),
+      #[cfg(feature = "local-ledger")]
+      Self::Local(signer) => signer.key_id(),
+    }
+  }
+
+  async fn public_key(&self) -> Result<PublicKey, SecretStorageError> {
+    match self {
+      Self::Keytool(signer) => signer.public_key().await,
+      #[cfg(feature = "local-ledger")]
+      Self::Local(signer) => signer.public_key().await,
+    }
+  }
+
+  async fn sign(&self, data: &TransactionData) -> Result<Signature, SecretStorageError> {
+    match self {
+      Self::Keytool(signer) => signer.sign(data).await,
+      #[cfg(feature = "local-ledger")]
+      Self::Local(signer) => signer.sign(data).await,
+    }
+  }
+}
+
 #[derive(Clone)]
 pub struct TestClient {
-  client: Arc<IdentityClient<KeytoolSigner>>,
+  client: Arc<IdentityClient<TestSigner>>,
   storage: Arc<MemStorage>,
+  #[cfg(feature = "local-ledger")]
+  ledger: Option<LocalLedger>,
 }
 
 impl Deref for TestClient {
-  type Target = IdentityClient<KeytoolSigner>;
+  type Target = IdentityClient<TestSigner>;
   fn deref(&self) -> &Self::Target {
     &self.client
   }
@@ -220,10 +276,35 @@ impl Deref for TestClient {
 
 impl TestClient {
   pub async fn new() -> anyhow::Result<Self> {
+    if use_local_ledger() {
+      return Self::new_local(SignatureScheme::ED25519).await;
+    }
     let active_address = get_active_address().await?;
     Self::new_from_address(active_address).await
   }
 
+  /// Returns a client connected to a new [`LocalLedger`], using a funded key of type `key_type`.
+  #[cfg(feature = "local-ledger")]
+  async fn new_local(key_type: SignatureScheme) -> anyhow::Result<Self> {
+    let ledger = LocalLedger::new();
+    let signer = ledger.new_signer(key_type)?;
+    ledger.request_funds(signer.address());
+
+    let identity_client = IdentityClientReadOnly::new_with_local_ledger(ledger.clone()).await?;
+    let client = IdentityClient::new(identity_client, TestSigner::Local(signer)).await?;
+
+    Ok(TestClient {
+      client: Arc::new(client),
+      storage: Arc::new(Storage::new(JwkMemStore::new(), KeyIdMemstore::new())),
+      ledger: Some(ledger),
+    })
+  }
+
+  #[cfg(not(feature = "local-ledger"))]
+  async fn new_local(_key_type: SignatureScheme) -> anyhow::Result<Self> {
+    anyhow::bail!("testing against an in-memory ledger requires the `local-ledger` feature")
+  }
+
   pub async fn new_from_address(address: IotaAddress) -> anyhow::Result<Self> {
     let api_endpoint = std::env::var("API_ENDPOINT").unwrap_or_else(|_| IOTA_LOCAL_NETWORK_URL.to_string());
     let client = IotaClientBuilder::default().build(&api_endpoint).await?;
@@ -237,15 +318,20 @@ impl TestClient {
     let storage = Arc::new(Storage::new(JwkMemStore::new(), KeyIdMemstore::new()));
     let identity_client = IdentityClientReadOnly::new_with_pkg_id(client, package_id).await?;
     let signer = KeytoolSigner::builder().build()?;
-    let client = IdentityClient::new(identity_client, signer).await?;
+    let client = IdentityClient::new(identity_client, TestSigner::Keytool(Arc::new(signer))).await?;
 
     Ok(TestClient {
       client: Arc::new(client),
       storage,
+      #[cfg(feature = "local-ledger")]
+      ledger: None,
     })
   }
 
   pub async fn new_with_key_type(key_type: SignatureScheme) -> anyhow::Result<Self> {
+    if use_local_ledger() {
+      return Self::new_local(key_type).await;
+    }
     let address = make_address(key_type).await?;
     Self::new_from_address(address).await
   }
@@ -273,10 +359,26 @@ impl TestClient {
     self.client.package_id()
   }
 
-  pub fn signer(&self) -> &KeytoolSigner {
+  pub fn signer(&self) -> &TestSigner {
     self.client.signer()
   }
 
+  /// Returns the [`LocalLedger`] this client is connected to, if any.
+  #[cfg(feature = "local-ledger")]
+  pub fn ledger(&self) -> Option<&LocalLedger> {
+    self.ledger.as_ref()
+  }
+
+  /// Sends funds to `address`, using the local ledger when testing against it.
+  pub async fn fund(&self, address: IotaAddress) -> anyhow::Result<()> {
+    #[cfg(feature = "local-ledger")]
+    if let Some(ledger) = &self.ledger {
+      ledger.request_funds(address);
+      return Ok(());
+    }
+    request_funds(&address).await
+  }
+
   pub async fn store_key_id_for_verification_method(
     &self,
     identity_client: IdentityClient<StorageSigner<'_, JwkMemStore, KeyIdMemstore>>,
@@ -308,7 +410,7 @@ impl TestClient {
 
     let user_client = IdentityClient::new((*self.client).clone(), signer).await?;
 
-    request_funds(&user_client.sender_address()).await?;
+    self.fund(user_client.sender_address()).await?;
 
     Ok(user_client)
   }
@@ -0,0 +1,124 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use crate::common::get_funded_test_client;
+use identity_iota_core::rebased::gas_station::GasStation;
+use identity_iota_core::rebased::gas_station::LocalGasStation;
+use identity_iota_core::IotaDocument;
+use iota_sdk::rpc_types::IotaTransactionBlockEffectsAPI as _;
+use iota_sdk::types::base_types::IotaAddress;
+use iota_sdk::types::object::Owner;
+
+#[tokio::test]
+async fn identity_creation_can_be_paid_by_a_gas_station() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+  let gas_station = LocalGasStation::new((*identity_client).clone(), test_client.signer().clone()).await?;
+
+  let tx_output = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .build_and_execute_with_gas_station(&identity_client, &gas_station)
+    .await?;
+
+  let gas_object_owner = tx_output
+    .response
+    .effects
+    .as_ref()
+    .expect("response has effects")
+    .gas_object()
+    .owner;
+  assert_eq!(gas_object_owner, Owner::AddressOwner(gas_station.sponsor_address()));
+  assert_eq!(gas_station.active_reservations(), 0);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn gas_station_releases_reservation_on_failure() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+  let gas_station = LocalGasStation::new((*identity_client).clone(), test_client.signer().clone()).await?;
+
+  // A budget that is way too small for the transaction makes its execution fail.
+  let result = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .with_gas_budget(1)
+    .build_and_execute_with_gas_station(&identity_client, &gas_station)
+    .await;
+
+  assert!(result.is_err());
+  assert_eq!(gas_station.active_reservations(), 0);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn gas_station_releases_reservation_on_execution_failure() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+  let gas_station = LocalGasStation::new((*identity_client).clone(), test_client.signer().clone()).await?;
+
+  // Gas is reserved, as the dry run succeeds for any sender, but the client cannot sign for a random sender,
+  // making the transaction's execution fail.
+  let result = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .with_sender(IotaAddress::random_for_testing_only())
+    .build_and_execute_with_gas_station(&identity_client, &gas_station)
+    .await;
+
+  assert!(result.is_err());
+  assert_eq!(gas_station.active_reservations(), 0);
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn gas_station_rejects_transactions_not_matching_the_reservation() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+  let gas_station = LocalGasStation::new((*identity_client).clone(), test_client.signer().clone()).await?;
+
+  let reservation = gas_station.reserve_gas(10_000_000, Duration::from_secs(10)).await?;
+  // The transaction uses the user's own coins instead of the reserved ones.
+  let (tx_data, _, _) = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .build(&identity_client)
+    .await?;
+
+  assert!(gas_station
+    .sponsor_transaction(reservation.reservation_id, &tx_data)
+    .await
+    .is_err());
+
+  gas_station.release_reservation(reservation.reservation_id).await?;
+  assert!(gas_station
+    .release_reservation(reservation.reservation_id)
+    .await
+    .is_err());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn gas_station_reservations_expire() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let gas_station = LocalGasStation::new((**test_client).clone(), test_client.signer().clone()).await?;
+
+  let reservation = gas_station.reserve_gas(10_000_000, Duration::from_secs(1)).await?;
+  assert_eq!(gas_station.active_reservations(), 1);
+
+  tokio::time::sleep(Duration::from_secs(2)).await;
+  assert_eq!(gas_station.active_reservations(), 0);
+  assert!(gas_station
+    .release_reservation(reservation.reservation_id)
+    .await
+    .is_err());
+
+  Ok(())
+}
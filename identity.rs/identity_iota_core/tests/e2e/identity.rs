@@ -10,6 +10,8 @@ use identity_iota_core::rebased::client::get_object_id_from_did;
 use identity_iota_core::rebased::migration::has_previous_version;
 use identity_iota_core::rebased::migration::Identity;
 use identity_iota_core::rebased::proposals::ProposalResult;
+use identity_iota_core::rebased::transaction_batch::SendToNewIdentity;
+use identity_iota_core::rebased::Error;
 use identity_iota_core::IotaDID;
 use identity_iota_core::IotaDocument;
 use identity_verification::MethodScope;
@@ -94,6 +96,131 @@ async fn updating_onchain_identity_did_doc_with_single_controller_works() -> any
   Ok(())
 }
 
+#[tokio::test]
+async fn dry_run_previews_did_doc_update_and_estimates_gas() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let mut identity = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+  let identity_id = identity.id();
+
+  let updated_did_doc = {
+    let mut doc = identity.did_document().clone();
+    doc.insert_method(
+      VerificationMethod::new_from_jwk(
+        doc.id().clone(),
+        identity_client.signer().public_key().clone(),
+        Some(identity_client.signer().key_id().as_str()),
+      )?,
+      MethodScope::VerificationMethod,
+    )?;
+    doc
+  };
+  let new_method_id = updated_did_doc
+    .id()
+    .to_url()
+    .join(format!("#{}", identity_client.signer().key_id().as_str()))?;
+
+  let controller_token = identity
+    .get_controller_token(&identity_client)
+    .await?
+    .expect("this address is a controller");
+
+  let mut tx = identity
+    .update_did_document(updated_did_doc, &controller_token)
+    .finish(&identity_client)
+    .await?
+    .with_gas_budget_margin(20);
+  let preview = tx.dry_run(&identity_client).await?;
+
+  assert!(preview.gas_budget > preview.gas_cost_summary.computation_cost);
+  assert!(preview.mutated_objects.iter().any(|obj| obj.object_id() == identity_id));
+  let diff = preview.did_document_diff.expect("DID document is updated");
+  assert_eq!(diff.added_methods(), vec![&new_method_id]);
+  assert!(diff.removed_methods().is_empty());
+
+  // The estimated budget is enough to execute the transaction.
+  tx.build_and_execute(&identity_client).await?;
+  let identity = identity_client.get_identity(identity_id).await?;
+  let Identity::FullFledged(identity) = identity else {
+    anyhow::bail!("resolved identity should be an onchain identity");
+  };
+  assert!(identity.did_document().resolve_method(&new_method_id, None).is_some());
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn batched_identity_creations_are_executed_in_a_single_transaction() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let first_tx = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish();
+  let second_tx = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish();
+
+  let tx_output = first_tx
+    .batch_with(second_tx)
+    .build_and_execute(&identity_client)
+    .await?;
+  let (first_identity, second_identity) = tx_output.output;
+
+  assert_ne!(first_identity.id(), second_identity.id());
+  for identity in [&first_identity, &second_identity] {
+    let resolved = identity_client.resolve_did(identity.did_document().id()).await?;
+    assert_eq!(&resolved, identity.did_document());
+  }
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn batch_changing_more_than_one_did_document_cannot_be_previewed() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let first_tx = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish();
+  let second_tx = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish();
+
+  let result = first_tx.batch_with(second_tx).dry_run(&identity_client).await;
+  assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+  Ok(())
+}
+
+#[tokio::test]
+async fn batched_identity_creation_can_send_objects_to_the_new_identity() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+  let coin = common::get_test_coin(identity_client.sender_address(), &identity_client).await?;
+
+  // The coin is sent to the identity created earlier in the same transaction.
+  let (identity, ()) = identity_client
+    .create_identity(IotaDocument::new(identity_client.network()))
+    .finish()
+    .batch_with_dependent(SendToNewIdentity::new([coin]))
+    .build_and_execute(&identity_client)
+    .await?
+    .output;
+
+  let coin_ref = identity_client.get_object_ref_by_id(coin).await?.expect("coin exists");
+  assert_eq!(coin_ref.owner, Owner::AddressOwner(identity.id().into()));
+
+  Ok(())
+}
+
 #[tokio::test]
 async fn approving_proposal_works() -> anyhow::Result<()> {
   let test_client = get_funded_test_client().await?;
@@ -4,5 +4,6 @@
 mod asset;
 mod client;
 pub mod common;
+mod gas_station;
 mod identity;
 mod migration;
@@ -2,7 +2,17 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::common::get_funded_test_client;
+#[cfg(feature = "local-ledger")]
+use crate::common::TEST_DOC;
+#[cfg(feature = "local-ledger")]
+use identity_core::common::Timestamp;
 use identity_iota_core::rebased::migration;
+#[cfg(feature = "local-ledger")]
+use identity_iota_core::rebased::migration::BulkMigration;
+#[cfg(feature = "local-ledger")]
+use identity_iota_core::IotaDID;
+#[cfg(feature = "local-ledger")]
+use identity_iota_core::IotaDocument;
 use iota_sdk::types::base_types::ObjectID;
 
 #[tokio::test]
@@ -15,3 +25,113 @@ async fn migration_registry_is_found() -> anyhow::Result<()> {
 
   Ok(())
 }
+
+#[tokio::test]
+async fn bulk_migration_of_address_without_aliases_is_a_no_op() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  let identity_client = test_client.new_user_client().await?;
+
+  let bulk_migration =
+    migration::BulkMigration::for_address(&identity_client, identity_client.sender_address()).await?;
+  assert!(bulk_migration.aliases().is_empty());
+
+  let report = bulk_migration.execute(&identity_client).await?;
+  assert!(report.migrated().is_empty());
+  assert!(report.is_successful());
+
+  Ok(())
+}
+
+#[cfg(feature = "local-ledger")]
+#[tokio::test]
+async fn bulk_migration_migrates_legacy_aliases() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  // Legacy aliases can only be added to the local ledger.
+  let Some(ledger) = test_client.ledger() else {
+    return Ok(());
+  };
+  let identity_client = test_client.new_user_client().await?;
+  let alias_ids = [
+    ledger.add_legacy_alias(identity_client.sender_address(), Some(TEST_DOC)),
+    ledger.add_legacy_alias(identity_client.sender_address(), Some(TEST_DOC)),
+  ];
+
+  let bulk_migration = BulkMigration::for_address(&identity_client, identity_client.sender_address()).await?;
+  assert_eq!(bulk_migration.aliases().len(), 2);
+
+  let report = bulk_migration.execute(&identity_client).await?;
+  assert!(report.is_successful());
+  assert_eq!(report.migrated().len(), 2);
+
+  for alias_id in alias_ids {
+    let legacy_did = IotaDID::from_object_id(&alias_id.to_string(), identity_client.network());
+    let identity = migration::lookup(&identity_client, alias_id)
+      .await?
+      .expect("alias has been migrated");
+    let migrated = report
+      .migrated()
+      .iter()
+      .find(|migrated| migrated.legacy_did == legacy_did)
+      .expect("alias is part of the report");
+    assert_eq!(migrated.identity_id, identity.id());
+    assert!(identity
+      .did_document()
+      .also_known_as()
+      .contains(&legacy_did.to_url().into()));
+  }
+
+  // Migrated aliases are gone.
+  let bulk_migration = BulkMigration::for_address(&identity_client, identity_client.sender_address()).await?;
+  assert!(bulk_migration.aliases().is_empty());
+
+  Ok(())
+}
+
+#[cfg(feature = "local-ledger")]
+#[tokio::test]
+async fn bulk_migration_reports_failed_migrations() -> anyhow::Result<()> {
+  let test_client = get_funded_test_client().await?;
+  // Legacy aliases can only be added to the local ledger.
+  let Some(ledger) = test_client.ledger() else {
+    return Ok(());
+  };
+  let identity_client = test_client.new_user_client().await?;
+  let sender = identity_client.sender_address();
+
+  // A DID Document created in the future can't be migrated on-chain.
+  let mut future_doc = IotaDocument::new(identity_client.network());
+  future_doc.metadata.created = Some(Timestamp::parse("2100-01-01T00:00:00Z")?);
+  let future_doc = future_doc.pack()?;
+
+  let migrated_alias = ledger.add_legacy_alias(sender, Some(TEST_DOC));
+  let future_alias = ledger.add_legacy_alias(sender, Some(&future_doc));
+  let not_a_did_alias = ledger.add_legacy_alias(sender, Some(b"not a DID document"));
+  let legacy_did = |alias_id: ObjectID| IotaDID::from_object_id(&alias_id.to_string(), identity_client.network());
+
+  // Every batch is migrated in its own transaction, so the failure of one doesn't affect the others.
+  let report = BulkMigration::for_address(&identity_client, sender)
+    .await?
+    .batch_size(1)
+    .execute(&identity_client)
+    .await?;
+  assert!(!report.is_successful());
+
+  assert_eq!(report.migrated().len(), 1);
+  assert_eq!(report.migrated()[0].legacy_did, legacy_did(migrated_alias));
+  assert!(report.migrated()[0].verified);
+
+  let mut failed: Vec<_> = report.failed().iter().map(|failed| failed.legacy_did.clone()).collect();
+  let mut expected_failed = vec![legacy_did(future_alias), legacy_did(not_a_did_alias)];
+  failed.sort_by_key(ToString::to_string);
+  expected_failed.sort_by_key(ToString::to_string);
+  assert_eq!(failed, expected_failed);
+
+  assert!(migration::lookup(&identity_client, migrated_alias).await?.is_some());
+  assert!(migration::lookup(&identity_client, future_alias).await?.is_none());
+
+  // Aliases that failed to migrate can still be retried.
+  let remaining = BulkMigration::for_address(&identity_client, sender).await?;
+  assert_eq!(remaining.aliases().len(), 2);
+
+  Ok(())
+}
@@ -3,6 +3,7 @@
 
 use crate::error::IotaRpcResult;
 use crate::rpc_types::CoinPage;
+use crate::rpc_types::DryRunTransactionBlockResponse;
 use crate::rpc_types::EventFilter;
 use crate::rpc_types::EventPage;
 use crate::rpc_types::IotaObjectData;
@@ -125,6 +126,9 @@ pub trait ReadTrait {
 
   async fn get_chain_identifier(&self) -> Result<String, Self::Error>;
 
+  /// Dry runs a transaction, returning its effects without executing it.
+  async fn dry_run_transaction_block(&self, tx_data: TransactionData) -> IotaRpcResult<DryRunTransactionBlockResponse>;
+
   async fn get_dynamic_field_object(
     &self,
     parent_object_id: ObjectID,
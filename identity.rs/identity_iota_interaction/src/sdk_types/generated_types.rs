@@ -61,6 +61,23 @@ impl ExecuteTransactionBlockParams {
   }
 }
 
+/// Simulates the execution of a transaction block without committing it to the ledger.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DryRunTransactionBlockParams {
+  /// BCS serialized transaction data bytes without its type tag, as base-64 encoded string.
+  transaction_block: Base64,
+}
+
+impl DryRunTransactionBlockParams {
+  pub fn new(tx_data: &TransactionData) -> Self {
+    let tx_data_bcs = bcs::to_bytes(tx_data).expect("this serialization cannot fail");
+    DryRunTransactionBlockParams {
+      transaction_block: Base64::from_bytes(&tx_data_bcs),
+    }
+  }
+}
+
 /// Return the dynamic field object information for a specified object
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -12,11 +12,13 @@ use schemars::JsonSchema;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_with::serde_as;
+use serde_with::DisplayFromStr;
 
 use crate::{iota_types::{base_types::EpochId, digests::{TransactionDigest, TransactionEventsDigest}, gas::GasCostSummary, storage::{DeleteKind, WriteKind}}, types::{
     base_types::{ObjectID, SequenceNumber}, execution_status::ExecutionStatus, object::Owner, quorum_driver_types::ExecuteTransactionRequestType,
-    iota_serde::{BigInt, SequenceNumber as AsSequenceNumber},
+    iota_serde::{BigInt, IotaTypeTag, SequenceNumber as AsSequenceNumber},
 }};
+use crate::move_core_types::language_storage::TypeTag;
 
 use super::iota_object::IotaObjectRef;
 
@@ -190,6 +192,31 @@ impl From<ExecutionStatus> for IotaExecutionStatus {
     }
 }
 
+#[serde_as]
+#[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChange {
+    /// Owner of the balance change
+    pub owner: Owner,
+    #[schemars(with = "String")]
+    #[serde_as(as = "IotaTypeTag")]
+    pub coin_type: TypeTag,
+    /// The amount indicate the balance value changes,
+    /// negative amount means spending coin value and positive means receiving coin value.
+    #[schemars(with = "String")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: i128,
+}
+
+// Only the fields of the DryRunTransactionBlockResponse defined by the IOTA SDK
+// that are used by the identity crates are mirrored here.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "DryRunTransactionBlockResponse", rename_all = "camelCase")]
+pub struct DryRunTransactionBlockResponse {
+    pub effects: IotaTransactionBlockEffects,
+    pub balance_changes: Vec<BalanceChange>,
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename = "OwnedObjectRef")]
 pub struct OwnedObjectRef {
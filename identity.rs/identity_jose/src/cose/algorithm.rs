@@ -0,0 +1,62 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use coset::iana;
+use coset::iana::EnumI64 as _;
+use coset::Algorithm;
+use coset::RegisteredLabelWithPrivate;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jws::JwsAlgorithm;
+
+/// Returns the COSE algorithm corresponding to the JWS algorithm `alg`.
+///
+/// [More Info](https://www.rfc-editor.org/rfc/rfc9053)
+pub fn cose_algorithm(alg: &JwsAlgorithm) -> Result<iana::Algorithm> {
+  let cose_alg = match alg {
+    JwsAlgorithm::HS256 => iana::Algorithm::HMAC_256_256,
+    JwsAlgorithm::HS384 => iana::Algorithm::HMAC_384_384,
+    JwsAlgorithm::HS512 => iana::Algorithm::HMAC_512_512,
+    JwsAlgorithm::RS256 => iana::Algorithm::RS256,
+    JwsAlgorithm::RS384 => iana::Algorithm::RS384,
+    JwsAlgorithm::RS512 => iana::Algorithm::RS512,
+    JwsAlgorithm::PS256 => iana::Algorithm::PS256,
+    JwsAlgorithm::PS384 => iana::Algorithm::PS384,
+    JwsAlgorithm::PS512 => iana::Algorithm::PS512,
+    JwsAlgorithm::ES256 => iana::Algorithm::ES256,
+    JwsAlgorithm::ES384 => iana::Algorithm::ES384,
+    JwsAlgorithm::ES512 => iana::Algorithm::ES512,
+    JwsAlgorithm::ES256K => iana::Algorithm::ES256K,
+    JwsAlgorithm::EdDSA => iana::Algorithm::EdDSA,
+    _ => return Err(Error::UnsupportedCoseAlgorithm(alg.name().to_owned())),
+  };
+
+  Ok(cose_alg)
+}
+
+/// Returns the JWS algorithm corresponding to the COSE algorithm `alg`.
+pub fn jws_algorithm(alg: &Algorithm) -> Result<JwsAlgorithm> {
+  let RegisteredLabelWithPrivate::Assigned(cose_alg) = alg else {
+    return Err(Error::UnsupportedCoseAlgorithm(format!("{alg:?}")));
+  };
+  let jws_alg = match cose_alg {
+    iana::Algorithm::HMAC_256_256 => JwsAlgorithm::HS256,
+    iana::Algorithm::HMAC_384_384 => JwsAlgorithm::HS384,
+    iana::Algorithm::HMAC_512_512 => JwsAlgorithm::HS512,
+    iana::Algorithm::RS256 => JwsAlgorithm::RS256,
+    iana::Algorithm::RS384 => JwsAlgorithm::RS384,
+    iana::Algorithm::RS512 => JwsAlgorithm::RS512,
+    iana::Algorithm::PS256 => JwsAlgorithm::PS256,
+    iana::Algorithm::PS384 => JwsAlgorithm::PS384,
+    iana::Algorithm::PS512 => JwsAlgorithm::PS512,
+    iana::Algorithm::ES256 => JwsAlgorithm::ES256,
+    iana::Algorithm::ES384 => JwsAlgorithm::ES384,
+    iana::Algorithm::ES512 => JwsAlgorithm::ES512,
+    iana::Algorithm::ES256K => JwsAlgorithm::ES256K,
+    iana::Algorithm::EdDSA => JwsAlgorithm::EdDSA,
+    other => return Err(Error::UnsupportedCoseAlgorithm(other.to_i64().to_string())),
+  };
+
+  Ok(jws_alg)
+}
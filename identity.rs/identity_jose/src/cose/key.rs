@@ -0,0 +1,248 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use ciborium::Value;
+use coset::iana;
+use coset::iana::EnumI64 as _;
+use coset::CoseKey;
+use coset::KeyType;
+use coset::Label;
+
+use super::cose_algorithm;
+use super::jws_algorithm;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jwk::EcCurve;
+use crate::jwk::EcxCurve;
+use crate::jwk::EdCurve;
+use crate::jwk::Jwk;
+use crate::jwk::JwkParams;
+use crate::jwk::JwkParamsEc;
+use crate::jwk::JwkParamsOct;
+use crate::jwk::JwkParamsOkp;
+use crate::jwk::JwkParamsRsa;
+use crate::jws::JwsAlgorithm;
+use crate::jwu;
+
+impl Jwk {
+  /// Converts this [`Jwk`] into a `COSE_Key`, including any private key material.
+  ///
+  /// The `kid` and `alg` parameters are carried over. Only `EC`, `OKP`, `RSA` and `oct` keys are supported.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc9052#section-7)
+  pub fn to_cose_key(&self) -> Result<CoseKey> {
+    let mut key = match self.params() {
+      JwkParams::Okp(params) => okp_to_cose_key(params)?,
+      JwkParams::Ec(params) => ec_to_cose_key(params)?,
+      JwkParams::Rsa(params) => rsa_to_cose_key(params)?,
+      JwkParams::Oct(JwkParamsOct { k }) => CoseKey {
+        kty: KeyType::Assigned(iana::KeyType::Symmetric),
+        params: vec![param(iana::SymmetricKeyParameter::K.to_i64(), jwu::decode_b64(k)?)],
+        ..Default::default()
+      },
+      #[cfg(feature = "pqc")]
+      JwkParams::Akp(_) => return Err(Error::KeyError("AKP")),
+    };
+
+    if let Some(kid) = self.kid() {
+      key.key_id = kid.as_bytes().to_vec();
+    }
+    if let Some(alg) = self.alg() {
+      let alg: JwsAlgorithm = alg.parse()?;
+      key.alg = Some(coset::Algorithm::Assigned(cose_algorithm(&alg)?));
+    }
+
+    Ok(key)
+  }
+
+  /// Creates a [`Jwk`] from a `COSE_Key`, including any private key material.
+  ///
+  /// The key ID, which must be valid UTF-8, is carried over as the `kid` and the algorithm as the `alg`.
+  pub fn from_cose_key(key: &CoseKey) -> Result<Self> {
+    let params: JwkParams = match &key.kty {
+      KeyType::Assigned(iana::KeyType::OKP) => okp_from_cose_key(key)?.into(),
+      KeyType::Assigned(iana::KeyType::EC2) => ec_from_cose_key(key)?.into(),
+      KeyType::Assigned(iana::KeyType::RSA) => rsa_from_cose_key(key)?.into(),
+      KeyType::Assigned(iana::KeyType::Symmetric) => JwkParamsOct {
+        k: required_bytes(key, iana::SymmetricKeyParameter::K.to_i64(), "k")?,
+      }
+      .into(),
+      _ => return Err(Error::KeyError("COSE_Key")),
+    };
+
+    let mut jwk = Jwk::from_params(params);
+    if !key.key_id.is_empty() {
+      let kid = std::str::from_utf8(&key.key_id).map_err(Error::InvalidUtf8)?;
+      jwk.set_kid(kid);
+    }
+    if let Some(alg) = &key.alg {
+      jwk.set_alg(jws_algorithm(alg)?.name());
+    }
+
+    Ok(jwk)
+  }
+}
+
+fn okp_to_cose_key(params: &JwkParamsOkp) -> Result<CoseKey> {
+  let crv = match params.crv.as_str() {
+    crv if crv == EdCurve::Ed25519.name() => iana::EllipticCurve::Ed25519,
+    crv if crv == EdCurve::Ed448.name() => iana::EllipticCurve::Ed448,
+    crv if crv == EcxCurve::X25519.name() => iana::EllipticCurve::X25519,
+    crv if crv == EcxCurve::X448.name() => iana::EllipticCurve::X448,
+    _ => return Err(Error::KeyError("OKP")),
+  };
+
+  let mut cose_params = vec![
+    (
+      Label::Int(iana::OkpKeyParameter::Crv.to_i64()),
+      Value::from(crv.to_i64()),
+    ),
+    param(iana::OkpKeyParameter::X.to_i64(), jwu::decode_b64(&params.x)?),
+  ];
+  if let Some(d) = &params.d {
+    cose_params.push(param(iana::OkpKeyParameter::D.to_i64(), jwu::decode_b64(d)?));
+  }
+
+  Ok(CoseKey {
+    kty: KeyType::Assigned(iana::KeyType::OKP),
+    params: cose_params,
+    ..Default::default()
+  })
+}
+
+fn okp_from_cose_key(key: &CoseKey) -> Result<JwkParamsOkp> {
+  let crv = match curve(key, iana::OkpKeyParameter::Crv.to_i64())? {
+    iana::EllipticCurve::Ed25519 => EdCurve::Ed25519.name(),
+    iana::EllipticCurve::Ed448 => EdCurve::Ed448.name(),
+    iana::EllipticCurve::X25519 => EcxCurve::X25519.name(),
+    iana::EllipticCurve::X448 => EcxCurve::X448.name(),
+    _ => return Err(Error::KeyError("OKP")),
+  };
+
+  Ok(JwkParamsOkp {
+    crv: crv.to_owned(),
+    x: required_bytes(key, iana::OkpKeyParameter::X.to_i64(), "x")?,
+    d: optional_bytes(key, iana::OkpKeyParameter::D.to_i64(), "d")?,
+  })
+}
+
+fn ec_to_cose_key(params: &JwkParamsEc) -> Result<CoseKey> {
+  let crv = match params.crv.as_str() {
+    crv if crv == EcCurve::P256.name() => iana::EllipticCurve::P_256,
+    crv if crv == EcCurve::P384.name() => iana::EllipticCurve::P_384,
+    crv if crv == EcCurve::P521.name() => iana::EllipticCurve::P_521,
+    crv if crv == EcCurve::Secp256K1.name() => iana::EllipticCurve::Secp256k1,
+    _ => return Err(Error::KeyError("EC")),
+  };
+
+  let mut cose_params = vec![
+    (
+      Label::Int(iana::Ec2KeyParameter::Crv.to_i64()),
+      Value::from(crv.to_i64()),
+    ),
+    param(iana::Ec2KeyParameter::X.to_i64(), jwu::decode_b64(&params.x)?),
+    param(iana::Ec2KeyParameter::Y.to_i64(), jwu::decode_b64(&params.y)?),
+  ];
+  if let Some(d) = &params.d {
+    cose_params.push(param(iana::Ec2KeyParameter::D.to_i64(), jwu::decode_b64(d)?));
+  }
+
+  Ok(CoseKey {
+    kty: KeyType::Assigned(iana::KeyType::EC2),
+    params: cose_params,
+    ..Default::default()
+  })
+}
+
+fn ec_from_cose_key(key: &CoseKey) -> Result<JwkParamsEc> {
+  let crv = match curve(key, iana::Ec2KeyParameter::Crv.to_i64())? {
+    iana::EllipticCurve::P_256 => EcCurve::P256,
+    iana::EllipticCurve::P_384 => EcCurve::P384,
+    iana::EllipticCurve::P_521 => EcCurve::P521,
+    iana::EllipticCurve::Secp256k1 => EcCurve::Secp256K1,
+    _ => return Err(Error::KeyError("EC2")),
+  };
+
+  Ok(JwkParamsEc {
+    crv: crv.name().to_owned(),
+    x: required_bytes(key, iana::Ec2KeyParameter::X.to_i64(), "x")?,
+    // Compressed points (boolean `y`) are not supported.
+    y: required_bytes(key, iana::Ec2KeyParameter::Y.to_i64(), "y")?,
+    d: optional_bytes(key, iana::Ec2KeyParameter::D.to_i64(), "d")?,
+  })
+}
+
+fn rsa_to_cose_key(params: &JwkParamsRsa) -> Result<CoseKey> {
+  if params.oth.is_some() {
+    return Err(Error::KeyError("RSA"));
+  }
+
+  let mut cose_params = vec![
+    param(iana::RsaKeyParameter::N.to_i64(), jwu::decode_b64(&params.n)?),
+    param(iana::RsaKeyParameter::E.to_i64(), jwu::decode_b64(&params.e)?),
+  ];
+  let private_params = [
+    (iana::RsaKeyParameter::D, &params.d),
+    (iana::RsaKeyParameter::P, &params.p),
+    (iana::RsaKeyParameter::Q, &params.q),
+    (iana::RsaKeyParameter::DP, &params.dp),
+    (iana::RsaKeyParameter::DQ, &params.dq),
+    (iana::RsaKeyParameter::QInv, &params.qi),
+  ];
+  for (label, value) in private_params {
+    if let Some(value) = value {
+      cose_params.push(param(label.to_i64(), jwu::decode_b64(value)?));
+    }
+  }
+
+  Ok(CoseKey {
+    kty: KeyType::Assigned(iana::KeyType::RSA),
+    params: cose_params,
+    ..Default::default()
+  })
+}
+
+fn rsa_from_cose_key(key: &CoseKey) -> Result<JwkParamsRsa> {
+  let optional = |label: iana::RsaKeyParameter, name: &'static str| optional_bytes(key, label.to_i64(), name);
+
+  Ok(JwkParamsRsa {
+    n: required_bytes(key, iana::RsaKeyParameter::N.to_i64(), "n")?,
+    e: required_bytes(key, iana::RsaKeyParameter::E.to_i64(), "e")?,
+    d: optional(iana::RsaKeyParameter::D, "d")?,
+    p: optional(iana::RsaKeyParameter::P, "p")?,
+    q: optional(iana::RsaKeyParameter::Q, "q")?,
+    dp: optional(iana::RsaKeyParameter::DP, "dp")?,
+    dq: optional(iana::RsaKeyParameter::DQ, "dq")?,
+    qi: optional(iana::RsaKeyParameter::QInv, "qi")?,
+    oth: None,
+  })
+}
+
+fn param(label: i64, value: Vec<u8>) -> (Label, Value) {
+  (Label::Int(label), Value::Bytes(value))
+}
+
+fn find_param(key: &CoseKey, label: i64) -> Option<&Value> {
+  key
+    .params
+    .iter()
+    .find_map(|(key_label, value)| (*key_label == Label::Int(label)).then_some(value))
+}
+
+fn curve(key: &CoseKey, label: i64) -> Result<iana::EllipticCurve> {
+  find_param(key, label)
+    .and_then(Value::as_integer)
+    .and_then(|crv| i64::try_from(crv).ok())
+    .and_then(iana::EllipticCurve::from_i64)
+    .ok_or(Error::InvalidParam("crv"))
+}
+
+fn optional_bytes(key: &CoseKey, label: i64, name: &'static str) -> Result<Option<String>> {
+  find_param(key, label)
+    .map(|value| value.as_bytes().map(jwu::encode_b64).ok_or(Error::InvalidParam(name)))
+    .transpose()
+}
+
+fn required_bytes(key: &CoseKey, label: i64, name: &'static str) -> Result<String> {
+  optional_bytes(key, label, name)?.ok_or(Error::MissingParam(name))
+}
@@ -0,0 +1,20 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! CBOR Object Signing and Encryption ([COSE](https://www.rfc-editor.org/rfc/rfc9052))
+//!
+//! Provides `COSE_Sign1` encoding and verification, with signatures verified by the same [`JwsVerifier`]
+//! implementations used for JWS, as well as conversions between [`Jwk`] and `COSE_Key`.
+//!
+//! [`JwsVerifier`]: crate::jws::JwsVerifier
+//! [`Jwk`]: crate::jwk::Jwk
+
+mod algorithm;
+mod key;
+mod sign1;
+
+pub use self::algorithm::*;
+pub use self::sign1::*;
+
+pub use ciborium;
+pub use coset;
@@ -0,0 +1,130 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use coset::Algorithm;
+use coset::CborSerializable as _;
+use coset::CoseSign1;
+use coset::Header;
+use coset::ProtectedHeader;
+use coset::TaggedCborSerializable as _;
+
+use super::jws_algorithm;
+use crate::error::Error;
+use crate::error::Result;
+use crate::jwk::Jwk;
+use crate::jws::JwsAlgorithm;
+use crate::jws::JwsVerifier;
+use crate::jws::VerificationInput;
+
+/// An encoder for `COSE_Sign1` messages.
+///
+/// Like the [`CompactJwsEncoder`](crate::jws::CompactJwsEncoder), it prepares the bytes to be signed, so that the
+/// signature can be produced externally, e.g. by a key storage, before completing the message.
+///
+/// [More Info](https://www.rfc-editor.org/rfc/rfc9052#section-4.2)
+#[derive(Debug, Clone)]
+pub struct CoseSign1Encoder {
+  sign1: CoseSign1,
+  signing_input: Vec<u8>,
+}
+
+impl CoseSign1Encoder {
+  /// Prepares a `COSE_Sign1` over `payload` with the given `protected` header, which must contain the signature
+  /// algorithm. If `detached` is `true`, the payload is not included in the message.
+  ///
+  /// MAC algorithms, such as `HMAC 256/256`, are rejected as they cannot be used with `COSE_Sign1`.
+  pub fn new(protected: Header, payload: &[u8], detached: bool) -> Result<Self> {
+    protected
+      .alg
+      .as_ref()
+      .ok_or(Error::ProtectedHeaderWithoutAlg)
+      .and_then(signature_algorithm)?;
+
+    let sign1 = CoseSign1 {
+      protected: ProtectedHeader {
+        original_data: None,
+        header: protected,
+      },
+      payload: (!detached).then(|| payload.to_vec()),
+      ..Default::default()
+    };
+    let signing_input = sign1.tbs_detached_data(payload, &[]);
+
+    Ok(Self { sign1, signing_input })
+  }
+
+  /// The bytes to be signed, i.e. the encoded `Sig_structure`.
+  pub fn signing_input(&self) -> &[u8] {
+    &self.signing_input
+  }
+
+  /// Completes the message with the given `signature` over [`Self::signing_input`].
+  pub fn into_cose_sign1(self, signature: &[u8]) -> CoseSign1 {
+    let mut sign1 = self.sign1;
+    sign1.signature = signature.to_vec();
+    sign1
+  }
+
+  /// Completes the message with the given `signature` and encodes it as a tagged `COSE_Sign1`.
+  pub fn into_bytes(self, signature: &[u8]) -> Result<Vec<u8>> {
+    self
+      .into_cose_sign1(signature)
+      .to_tagged_vec()
+      .map_err(|err| Error::InvalidCbor(err.to_string()))
+  }
+}
+
+/// Decodes a `COSE_Sign1` message, either tagged or untagged.
+pub fn decode_cose_sign1(bytes: &[u8]) -> Result<CoseSign1> {
+  CoseSign1::from_tagged_slice(bytes)
+    .or_else(|_| CoseSign1::from_slice(bytes))
+    .map_err(|err| Error::InvalidCbor(err.to_string()))
+}
+
+/// Verifies the signature of a `COSE_Sign1` message with `public_key`, delegating the cryptographic verification to
+/// `verifier`.
+///
+/// The signature algorithm is taken from the protected header and must match the `alg` of `public_key`, if set.
+/// MAC algorithms are rejected.
+/// `detached_payload` must be provided if the message doesn't include its payload.
+pub fn verify_cose_sign1<V: JwsVerifier>(
+  sign1: &CoseSign1,
+  detached_payload: Option<&[u8]>,
+  verifier: &V,
+  public_key: &Jwk,
+) -> Result<()> {
+  let alg = sign1
+    .protected
+    .header
+    .alg
+    .as_ref()
+    .ok_or(Error::ProtectedHeaderWithoutAlg)
+    .and_then(signature_algorithm)?;
+  public_key.check_alg(alg.name())?;
+
+  let signing_input = match (detached_payload, &sign1.payload) {
+    (Some(payload), _) => sign1.tbs_detached_data(payload, &[]),
+    (None, Some(_)) => sign1.tbs_data(&[]),
+    (None, None) => return Err(Error::InvalidContent("missing payload")),
+  };
+  let input = VerificationInput {
+    alg,
+    signing_input: signing_input.into_boxed_slice(),
+    decoded_signature: sign1.signature.clone().into_boxed_slice(),
+  };
+
+  verifier
+    .verify(input, public_key)
+    .map_err(Error::SignatureVerificationError)
+}
+
+/// Returns the JWS algorithm corresponding to the COSE algorithm `alg`, unless it is a MAC algorithm.
+fn signature_algorithm(alg: &Algorithm) -> Result<JwsAlgorithm> {
+  let alg = jws_algorithm(alg)?;
+  match alg {
+    JwsAlgorithm::HS256 | JwsAlgorithm::HS384 | JwsAlgorithm::HS512 => {
+      Err(Error::UnsupportedCoseAlgorithm(alg.name().to_owned()))
+    }
+    _ => Ok(alg),
+  }
+}
@@ -0,0 +1,282 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use ciborium::Value;
+use coset::Label;
+use identity_core::common::OneOrMany;
+
+use crate::error::Error;
+use crate::error::Result;
+
+const ISS: i64 = 1;
+const SUB: i64 = 2;
+const AUD: i64 = 3;
+const EXP: i64 = 4;
+const NBF: i64 = 5;
+const IAT: i64 = 6;
+const CTI: i64 = 7;
+
+/// CBOR Web Token Claims
+///
+/// The CBOR counterpart of [`JwtClaims`](crate::jwt::JwtClaims), where registered claims are identified by integer
+/// labels.
+///
+/// [More Info](https://www.rfc-editor.org/rfc/rfc8392#section-3)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CwtClaims {
+  /// Identifies the principal that issued the CWT.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc8392#section-3.1.1)
+  iss: Option<String>,
+  /// Identifies the principal that is the subject of the CWT.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc8392#section-3.1.2)
+  sub: Option<String>,
+  /// Identifies the recipients that the CWT is intended for.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc8392#section-3.1.3)
+  aud: Option<OneOrMany<String>>,
+  /// Identifies the expiration time on or after which the CWT MUST NOT be accepted for processing.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc8392#section-3.1.4)
+  exp: Option<i64>,
+  /// Identifies the time before which the CWT MUST NOT be accepted for processing.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc8392#section-3.1.5)
+  nbf: Option<i64>,
+  /// Identifies the time at which the CWT was issued.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc8392#section-3.1.6)
+  iat: Option<i64>,
+  /// Provides a unique identifier for the CWT.
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc8392#section-3.1.7)
+  cti: Option<Vec<u8>>,
+  /// Any other claims, by label.
+  custom: BTreeMap<Label, Value>,
+}
+
+impl CwtClaims {
+  /// Create a new `CwtClaims` set.
+  pub const fn new() -> Self {
+    Self {
+      iss: None,
+      sub: None,
+      aud: None,
+      exp: None,
+      nbf: None,
+      iat: None,
+      cti: None,
+      custom: BTreeMap::new(),
+    }
+  }
+
+  /// Returns the value for the issuer claim (iss).
+  pub fn iss(&self) -> Option<&str> {
+    self.iss.as_deref()
+  }
+
+  /// Sets a value for the issuer claim (iss).
+  pub fn set_iss(&mut self, value: impl Into<String>) {
+    self.iss = Some(value.into());
+  }
+
+  /// Returns the value for the subject claim (sub).
+  pub fn sub(&self) -> Option<&str> {
+    self.sub.as_deref()
+  }
+
+  /// Sets a value for the subject claim (sub).
+  pub fn set_sub(&mut self, value: impl Into<String>) {
+    self.sub = Some(value.into());
+  }
+
+  /// Returns the values for the audience claim (aud).
+  pub fn aud(&self) -> Option<&[String]> {
+    self.aud.as_deref()
+  }
+
+  /// Sets values for the audience claim (aud).
+  pub fn set_aud(&mut self, value: impl IntoIterator<Item = impl Into<String>>) {
+    self.aud = Some(value.into_iter().map(Into::into).collect());
+  }
+
+  /// Returns the time for the expires at claim (exp).
+  pub fn exp(&self) -> Option<i64> {
+    self.exp
+  }
+
+  /// Sets a time for the expires at claim (exp).
+  pub fn set_exp(&mut self, value: impl Into<i64>) {
+    self.exp = Some(value.into());
+  }
+
+  /// Returns the time for the not before claim (nbf).
+  pub fn nbf(&self) -> Option<i64> {
+    self.nbf
+  }
+
+  /// Sets a time for the not before claim (nbf).
+  pub fn set_nbf(&mut self, value: impl Into<i64>) {
+    self.nbf = Some(value.into());
+  }
+
+  /// Returns the time for the issued at claim (iat).
+  pub fn iat(&self) -> Option<i64> {
+    self.iat
+  }
+
+  /// Sets a time for the issued at claim (iat).
+  pub fn set_iat(&mut self, value: impl Into<i64>) {
+    self.iat = Some(value.into());
+  }
+
+  /// Returns the value for the CWT ID claim (cti).
+  pub fn cti(&self) -> Option<&[u8]> {
+    self.cti.as_deref()
+  }
+
+  /// Sets a value for the CWT ID claim (cti).
+  pub fn set_cti(&mut self, value: impl Into<Vec<u8>>) {
+    self.cti = Some(value.into());
+  }
+
+  /// Returns a reference to the custom CWT claims.
+  pub fn custom(&self) -> &BTreeMap<Label, Value> {
+    &self.custom
+  }
+
+  /// Returns a mutable reference to the custom CWT claims.
+  pub fn custom_mut(&mut self) -> &mut BTreeMap<Label, Value> {
+    &mut self.custom
+  }
+
+  /// Sets the value of the custom claim identified by `label`.
+  ///
+  /// Labels of registered claims are ignored when encoding, use the dedicated setters instead.
+  pub fn set_custom(&mut self, label: Label, value: Value) {
+    self.custom.insert(label, value);
+  }
+
+  /// Encodes the claims set as a CBOR map.
+  pub fn to_cbor_value(&self) -> Value {
+    let text = |value: &String| Value::Text(value.clone());
+    let registered = [
+      (ISS, self.iss.as_ref().map(text)),
+      (SUB, self.sub.as_ref().map(text)),
+      (
+        AUD,
+        self.aud.as_ref().map(|aud| match aud {
+          OneOrMany::One(aud) => text(aud),
+          OneOrMany::Many(aud) => Value::Array(aud.iter().map(text).collect()),
+        }),
+      ),
+      (EXP, self.exp.map(Value::from)),
+      (NBF, self.nbf.map(Value::from)),
+      (IAT, self.iat.map(Value::from)),
+      (CTI, self.cti.clone().map(Value::Bytes)),
+    ];
+
+    let registered = registered
+      .into_iter()
+      .filter_map(|(label, value)| Some((Value::from(label), value?)));
+    let custom = self
+      .custom
+      .iter()
+      .filter(|(label, _)| registered_label(label).is_none())
+      .map(|(label, value)| (label_to_value(label), value.clone()));
+
+    Value::Map(registered.chain(custom).collect())
+  }
+
+  /// Decodes a claims set from a CBOR map.
+  pub fn from_cbor_value(value: Value) -> Result<Self> {
+    let Value::Map(entries) = value else {
+      return Err(Error::InvalidCbor("expected a CWT claims set map".to_owned()));
+    };
+
+    let mut claims = Self::new();
+    for (label, value) in entries {
+      let label = match label {
+        Value::Integer(label) => {
+          Label::Int(i64::try_from(label).map_err(|_| Error::InvalidCbor("claim label out of range".to_owned()))?)
+        }
+        Value::Text(label) => Label::Text(label),
+        _ => return Err(Error::InvalidCbor("invalid claim label".to_owned())),
+      };
+
+      match registered_label(&label) {
+        Some(ISS) => claims.iss = Some(into_text(value, "iss")?),
+        Some(SUB) => claims.sub = Some(into_text(value, "sub")?),
+        Some(AUD) => {
+          claims.aud = Some(match value {
+            Value::Array(aud) => OneOrMany::Many(
+              aud
+                .into_iter()
+                .map(|aud| into_text(aud, "aud"))
+                .collect::<Result<_>>()?,
+            ),
+            aud => OneOrMany::One(into_text(aud, "aud")?),
+          })
+        }
+        Some(EXP) => claims.exp = Some(numeric_date(&value, "exp")?),
+        Some(NBF) => claims.nbf = Some(numeric_date(&value, "nbf")?),
+        Some(IAT) => claims.iat = Some(numeric_date(&value, "iat")?),
+        Some(CTI) => match value {
+          Value::Bytes(cti) => claims.cti = Some(cti),
+          _ => return Err(Error::InvalidClaim("cti")),
+        },
+        _ => {
+          claims.custom.insert(label, value);
+        }
+      }
+    }
+
+    Ok(claims)
+  }
+
+  /// Serializes the claims set to CBOR bytes.
+  pub fn to_vec(&self) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    ciborium::into_writer(&self.to_cbor_value(), &mut bytes).map_err(|err| Error::InvalidCbor(err.to_string()))?;
+    Ok(bytes)
+  }
+
+  /// Deserializes a claims set from CBOR bytes.
+  pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+    let value: Value = ciborium::from_reader(bytes).map_err(|err| Error::InvalidCbor(err.to_string()))?;
+    Self::from_cbor_value(value)
+  }
+}
+
+fn registered_label(label: &Label) -> Option<i64> {
+  match label {
+    Label::Int(label @ ISS..=CTI) => Some(*label),
+    _ => None,
+  }
+}
+
+fn label_to_value(label: &Label) -> Value {
+  match label {
+    Label::Int(label) => Value::from(*label),
+    Label::Text(label) => Value::Text(label.clone()),
+  }
+}
+
+fn into_text(value: Value, claim: &'static str) -> Result<String> {
+  match value {
+    Value::Text(text) => Ok(text),
+    _ => Err(Error::InvalidClaim(claim)),
+  }
+}
+
+/// Parses a NumericDate, truncating fractional seconds.
+fn numeric_date(value: &Value, claim: &'static str) -> Result<i64> {
+  match value {
+    Value::Integer(date) => i64::try_from(*date).map_err(|_| Error::InvalidClaim(claim)),
+    Value::Float(date) if date.is_finite() => Ok(*date as i64),
+    _ => Err(Error::InvalidClaim(claim)),
+  }
+}
@@ -0,0 +1,8 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! CBOR Web Tokens ([CWT](https://www.rfc-editor.org/rfc/rfc8392))
+
+mod claims;
+
+pub use self::claims::*;
@@ -49,4 +49,10 @@ pub enum Error {
   /// Caused by a missing `alg` claim in the protected header.
   #[error("missing alg in protected header")]
   ProtectedHeaderWithoutAlg,
+  /// Caused by invalid CBOR encoding or decoding.
+  #[error("invalid cbor: {0}")]
+  InvalidCbor(String),
+  /// Caused by an algorithm that has no COSE counterpart, or vice versa.
+  #[error("unsupported COSE algorithm `{0}`")]
+  UnsupportedCoseAlgorithm(String),
 }
@@ -13,6 +13,8 @@ use crate::jwk::EcxCurve;
 use crate::jwk::EdCurve;
 use crate::jwk::JwkOperation;
 use crate::jwk::JwkParams;
+#[cfg(feature = "pqc")]
+use crate::jwk::JwkParamsAkp;
 use crate::jwk::JwkParamsEc;
 use crate::jwk::JwkParamsOct;
 use crate::jwk::JwkParamsOkp;
@@ -24,6 +26,9 @@ use crate::jwu::encode_b64;
 /// A SHA256 JSON Web Key Thumbprint.
 pub type JwkThumbprintSha256 = [u8; SHA256_LEN];
 
+/// The prefix of a [RFC9278](https://www.rfc-editor.org/rfc/rfc9278) JWK Thumbprint URI using `SHA2-256`.
+pub const JWK_THUMBPRINT_URI_SHA256_PREFIX: &str = "urn:ietf:params:oauth:jwk-thumbprint:sha-256:";
+
 /// JSON Web Key.
 ///
 /// [More Info](https://tools.ietf.org/html/rfc7517#section-4)
@@ -261,6 +266,10 @@ impl Jwk {
       (JwkType::Okp, value @ JwkParams::Okp(_)) => {
         self.set_params_unchecked(value);
       }
+      #[cfg(feature = "pqc")]
+      (JwkType::Akp, value @ JwkParams::Akp(_)) => {
+        self.set_params_unchecked(value);
+      }
       (_, _) => {
         return Err(Error::InvalidParam("`params` type does not match `kty`"));
       }
@@ -339,6 +348,24 @@ impl Jwk {
     }
   }
 
+  /// Returns the [`JwkParamsAkp`] in this JWK if it is of type `Akp`.
+  #[cfg(feature = "pqc")]
+  pub fn try_akp_params(&self) -> Result<&JwkParamsAkp> {
+    match self.params() {
+      JwkParams::Akp(params) => Ok(params),
+      _ => Err(Error::KeyError("Akp")),
+    }
+  }
+
+  /// Returns a mutable reference to the [`JwkParamsAkp`] in this JWK if it is of type `Akp`.
+  #[cfg(feature = "pqc")]
+  pub fn try_akp_params_mut(&mut self) -> Result<&mut JwkParamsAkp> {
+    match self.params_mut() {
+      JwkParams::Akp(params) => Ok(params),
+      _ => Err(Error::KeyError("Akp")),
+    }
+  }
+
   // ===========================================================================
   // Thumbprint
   // ===========================================================================
@@ -367,6 +394,14 @@ impl Jwk {
     out
   }
 
+  /// Creates a JWK Thumbprint URI of the JSON Web Key according to [RFC9278](https://www.rfc-editor.org/rfc/rfc9278),
+  /// e.g. `urn:ietf:params:oauth:jwk-thumbprint:sha-256:<thumbprint>`.
+  ///
+  /// `SHA2-256` is used as the hash function *H*.
+  pub fn thumbprint_sha256_uri(&self) -> String {
+    format!("{JWK_THUMBPRINT_URI_SHA256_PREFIX}{}", self.thumbprint_sha256_b64())
+  }
+
   /// Creates the JSON string of the JSON Web Key according to [RFC7638](https://tools.ietf.org/html/rfc7638),
   /// which is used as the input for the JWK thumbprint hashing procedure.
   /// This can be used as input for a custom hash function.
@@ -387,6 +422,12 @@ impl Jwk {
       JwkParams::Okp(JwkParamsOkp { crv, x, .. }) => {
         format!(r#"{{"crv":"{crv}","kty":"{kty}","x":"{x}"}}"#)
       }
+      // The `alg` parameter is a required member of AKP keys, hence it is part of the thumbprint.
+      #[cfg(feature = "pqc")]
+      JwkParams::Akp(JwkParamsAkp { public, .. }) => {
+        let alg: &str = self.alg().unwrap_or_default();
+        format!(r#"{{"alg":"{alg}","kty":"{kty}","pub":"{public}"}}"#)
+      }
     }
   }
 
@@ -439,6 +480,8 @@ impl Jwk {
       JwkParams::Rsa(params) => params.is_private(),
       JwkParams::Oct(_) => true,
       JwkParams::Okp(params) => params.is_private(),
+      #[cfg(feature = "pqc")]
+      JwkParams::Akp(params) => params.is_private(),
     }
   }
 
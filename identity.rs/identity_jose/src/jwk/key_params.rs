@@ -28,6 +28,9 @@ pub enum JwkParams {
   Oct(JwkParamsOct),
   /// Octet Key Pairs parameters.
   Okp(JwkParamsOkp),
+  /// Algorithm Key Pair parameters.
+  #[cfg(feature = "pqc")]
+  Akp(JwkParamsAkp),
 }
 
 impl JwkParams {
@@ -38,6 +41,8 @@ impl JwkParams {
       JwkType::Rsa => Self::Rsa(JwkParamsRsa::new()),
       JwkType::Oct => Self::Oct(JwkParamsOct::new()),
       JwkType::Okp => Self::Okp(JwkParamsOkp::new()),
+      #[cfg(feature = "pqc")]
+      JwkType::Akp => Self::Akp(JwkParamsAkp::new()),
     }
   }
 
@@ -48,6 +53,8 @@ impl JwkParams {
       Self::Rsa(inner) => inner.kty(),
       Self::Oct(inner) => inner.kty(),
       Self::Okp(inner) => inner.kty(),
+      #[cfg(feature = "pqc")]
+      Self::Akp(inner) => inner.kty(),
     }
   }
 
@@ -59,6 +66,8 @@ impl JwkParams {
       Self::Okp(inner) => Some(Self::Okp(inner.to_public())),
       Self::Ec(inner) => Some(Self::Ec(inner.to_public())),
       Self::Rsa(inner) => Some(Self::Rsa(inner.to_public())),
+      #[cfg(feature = "pqc")]
+      Self::Akp(inner) => Some(Self::Akp(inner.to_public())),
       Self::Oct(_) => None,
     }
   }
@@ -70,6 +79,8 @@ impl JwkParams {
       Self::Ec(value) => value.is_public(),
       Self::Rsa(value) => value.is_public(),
       Self::Oct(value) => value.is_public(),
+      #[cfg(feature = "pqc")]
+      Self::Akp(value) => value.is_public(),
     }
   }
 }
@@ -464,3 +475,73 @@ impl From<JwkParamsOkp> for JwkParams {
     Self::Okp(other)
   }
 }
+
+// =============================================================================
+// Jwk Params Akp
+// =============================================================================
+
+/// Parameters for Algorithm Key Pairs.
+///
+/// The algorithm the key is meant to be used with is identified by the `alg` parameter of the
+/// enclosing JWK, which is mandatory for this key type.
+///
+/// [More Info](https://datatracker.ietf.org/doc/html/draft-ietf-cose-dilithium#name-algorithm-key-pair-type)
+#[cfg(feature = "pqc")]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize, serde::Serialize, Zeroize)]
+#[zeroize(drop)]
+pub struct JwkParamsAkp {
+  /// The public key as a base64url-encoded value.
+  #[serde(rename = "pub")]
+  pub public: String, // Public Key
+  /// The private key as a base64url-encoded value.
+  #[serde(rename = "priv", skip_serializing_if = "Option::is_none")]
+  pub private: Option<String>, // Private Key
+}
+
+#[cfg(feature = "pqc")]
+impl Default for JwkParamsAkp {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+#[cfg(feature = "pqc")]
+impl JwkParamsAkp {
+  /// Creates new JWK AKP Params.
+  pub const fn new() -> Self {
+    Self {
+      public: String::new(),
+      private: None,
+    }
+  }
+
+  /// Returns the key type `kty`.
+  pub const fn kty(&self) -> JwkType {
+    JwkType::Akp
+  }
+
+  /// Returns a clone with _all_ private key components unset.
+  pub fn to_public(&self) -> Self {
+    Self {
+      public: self.public.clone(),
+      private: None,
+    }
+  }
+
+  /// Returns `true` if _all_ private key components of the key are unset, `false` otherwise.
+  pub fn is_public(&self) -> bool {
+    self.private.is_none()
+  }
+
+  /// Returns `true` if _all_ private key components of the key are set, `false` otherwise.
+  pub fn is_private(&self) -> bool {
+    self.private.is_some()
+  }
+}
+
+#[cfg(feature = "pqc")]
+impl From<JwkParamsAkp> for JwkParams {
+  fn from(other: JwkParamsAkp) -> Self {
+    Self::Akp(other)
+  }
+}
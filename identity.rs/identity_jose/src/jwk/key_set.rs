@@ -9,6 +9,8 @@ use core::slice::SliceIndex;
 use zeroize::Zeroize;
 
 use crate::jwk::Jwk;
+use crate::jwk::JwkUse;
+use crate::jwk::JWK_THUMBPRINT_URI_SHA256_PREFIX;
 
 /// JSON Web Key Set.
 ///
@@ -56,6 +58,42 @@ impl JwkSet {
       .collect()
   }
 
+  /// Returns the key whose [RFC7638](https://tools.ietf.org/html/rfc7638) `SHA2-256` thumbprint matches
+  /// `thumbprint`, if any.
+  ///
+  /// `thumbprint` can either be base64url-encoded, as returned by [`Jwk::thumbprint_sha256_b64`], or a
+  /// thumbprint URI, as returned by [`Jwk::thumbprint_sha256_uri`].
+  pub fn get_by_thumbprint(&self, thumbprint: &str) -> Option<&Jwk> {
+    let thumbprint: &str = thumbprint
+      .strip_prefix(JWK_THUMBPRINT_URI_SHA256_PREFIX)
+      .unwrap_or(thumbprint);
+
+    self.keys.iter().find(|key| key.thumbprint_sha256_b64() == thumbprint)
+  }
+
+  /// Returns a list of keys whose `alg` matches the given `alg`.
+  pub fn get_by_alg(&self, alg: &str) -> Vec<&Jwk> {
+    self.keys.iter().filter(|key| key.alg() == Some(alg)).collect()
+  }
+
+  /// Returns a list of keys whose `use` matches the given `use_`.
+  pub fn get_by_use(&self, use_: JwkUse) -> Vec<&Jwk> {
+    self.keys.iter().filter(|key| key.use_() == Some(use_)).collect()
+  }
+
+  /// Returns `true` if none of the keys in the set contain private key material.
+  pub fn is_public(&self) -> bool {
+    self.keys.iter().all(Jwk::is_public)
+  }
+
+  /// Returns a copy of the set in which _all_ private key components are unset.
+  ///
+  /// Keys with `kty = oct` are omitted, as this key type is not considered public by this library.
+  /// See [`Jwk::to_public`].
+  pub fn to_public(&self) -> JwkSet {
+    self.keys.iter().filter_map(Jwk::to_public).collect()
+  }
+
   /// Adds a new `key` to the set.
   pub fn add(&mut self, key: impl Into<Jwk>) {
     self.keys.push(key.into());
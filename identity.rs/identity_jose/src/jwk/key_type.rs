@@ -22,6 +22,10 @@ pub enum JwkType {
   /// Octet string key pairs.
   #[serde(rename = "OKP")]
   Okp,
+  /// Algorithm Key Pair, used by post-quantum algorithms such as ML-DSA and SLH-DSA.
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "AKP")]
+  Akp,
 }
 
 impl JwkType {
@@ -32,6 +36,8 @@ impl JwkType {
       Self::Rsa => "RSA",
       Self::Oct => "oct",
       Self::Okp => "OKP",
+      #[cfg(feature = "pqc")]
+      Self::Akp => "AKP",
     }
   }
 }
@@ -44,6 +44,74 @@ pub enum JwsAlgorithm {
   NONE,
   /// EdDSA signature algorithms
   EdDSA,
+  /// ML-DSA-44 as specified in FIPS 204
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "ML-DSA-44")]
+  ML_DSA_44,
+  /// ML-DSA-65 as specified in FIPS 204
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "ML-DSA-65")]
+  ML_DSA_65,
+  /// ML-DSA-87 as specified in FIPS 204
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "ML-DSA-87")]
+  ML_DSA_87,
+  /// SLH-DSA-SHA2-128s as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHA2-128s")]
+  SLH_DSA_SHA2_128s,
+  /// SLH-DSA-SHAKE-128s as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHAKE-128s")]
+  SLH_DSA_SHAKE_128s,
+  /// SLH-DSA-SHA2-128f as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHA2-128f")]
+  SLH_DSA_SHA2_128f,
+  /// SLH-DSA-SHAKE-128f as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHAKE-128f")]
+  SLH_DSA_SHAKE_128f,
+  /// SLH-DSA-SHA2-192s as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHA2-192s")]
+  SLH_DSA_SHA2_192s,
+  /// SLH-DSA-SHAKE-192s as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHAKE-192s")]
+  SLH_DSA_SHAKE_192s,
+  /// SLH-DSA-SHA2-192f as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHA2-192f")]
+  SLH_DSA_SHA2_192f,
+  /// SLH-DSA-SHAKE-192f as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHAKE-192f")]
+  SLH_DSA_SHAKE_192f,
+  /// SLH-DSA-SHA2-256s as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHA2-256s")]
+  SLH_DSA_SHA2_256s,
+  /// SLH-DSA-SHAKE-256s as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHAKE-256s")]
+  SLH_DSA_SHAKE_256s,
+  /// SLH-DSA-SHA2-256f as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHA2-256f")]
+  SLH_DSA_SHA2_256f,
+  /// SLH-DSA-SHAKE-256f as specified in FIPS 205
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "SLH-DSA-SHAKE-256f")]
+  SLH_DSA_SHAKE_256f,
+  /// Hybrid signature combining ML-DSA-44 and Ed25519 (Composite ML-DSA)
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "ML-DSA-44-Ed25519")]
+  ML_DSA_44_Ed25519,
+  /// Hybrid signature combining ML-DSA-65 and Ed25519 (Composite ML-DSA)
+  #[cfg(feature = "pqc")]
+  #[serde(rename = "ML-DSA-65-Ed25519")]
+  ML_DSA_65_Ed25519,
   /// Custom algorithm
   #[cfg(feature = "custom_alg")]
   #[serde(untagged)]
@@ -73,6 +141,40 @@ impl JwsAlgorithm {
     Self::ES256K,
     Self::NONE,
     Self::EdDSA,
+    #[cfg(feature = "pqc")]
+    Self::ML_DSA_44,
+    #[cfg(feature = "pqc")]
+    Self::ML_DSA_65,
+    #[cfg(feature = "pqc")]
+    Self::ML_DSA_87,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHA2_128s,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHAKE_128s,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHA2_128f,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHAKE_128f,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHA2_192s,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHAKE_192s,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHA2_192f,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHAKE_192f,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHA2_256s,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHAKE_256s,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHA2_256f,
+    #[cfg(feature = "pqc")]
+    Self::SLH_DSA_SHAKE_256f,
+    #[cfg(feature = "pqc")]
+    Self::ML_DSA_44_Ed25519,
+    #[cfg(feature = "pqc")]
+    Self::ML_DSA_65_Ed25519,
   ];
 
   /// Returns the JWS algorithm as a `str` slice.
@@ -94,6 +196,40 @@ impl JwsAlgorithm {
       Self::ES256K => "ES256K",
       Self::NONE => "none",
       Self::EdDSA => "EdDSA",
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_44 => "ML-DSA-44",
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_65 => "ML-DSA-65",
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_87 => "ML-DSA-87",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_128s => "SLH-DSA-SHA2-128s",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_128s => "SLH-DSA-SHAKE-128s",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_128f => "SLH-DSA-SHA2-128f",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_128f => "SLH-DSA-SHAKE-128f",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_192s => "SLH-DSA-SHA2-192s",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_192s => "SLH-DSA-SHAKE-192s",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_192f => "SLH-DSA-SHA2-192f",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_192f => "SLH-DSA-SHAKE-192f",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_256s => "SLH-DSA-SHA2-256s",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_256s => "SLH-DSA-SHAKE-256s",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_256f => "SLH-DSA-SHA2-256f",
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_256f => "SLH-DSA-SHAKE-256f",
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_44_Ed25519 => "ML-DSA-44-Ed25519",
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_65_Ed25519 => "ML-DSA-65-Ed25519",
     }
   }
 
@@ -116,9 +252,101 @@ impl JwsAlgorithm {
       Self::ES256K => "ES256K".to_string(),
       Self::NONE => "none".to_string(),
       Self::EdDSA => "EdDSA".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_44 => "ML-DSA-44".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_65 => "ML-DSA-65".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_87 => "ML-DSA-87".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_128s => "SLH-DSA-SHA2-128s".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_128s => "SLH-DSA-SHAKE-128s".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_128f => "SLH-DSA-SHA2-128f".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_128f => "SLH-DSA-SHAKE-128f".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_192s => "SLH-DSA-SHA2-192s".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_192s => "SLH-DSA-SHAKE-192s".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_192f => "SLH-DSA-SHA2-192f".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_192f => "SLH-DSA-SHAKE-192f".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_256s => "SLH-DSA-SHA2-256s".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_256s => "SLH-DSA-SHAKE-256s".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHA2_256f => "SLH-DSA-SHA2-256f".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::SLH_DSA_SHAKE_256f => "SLH-DSA-SHAKE-256f".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_44_Ed25519 => "ML-DSA-44-Ed25519".to_string(),
+      #[cfg(feature = "pqc")]
+      Self::ML_DSA_65_Ed25519 => "ML-DSA-65-Ed25519".to_string(),
       Self::Custom(name) => name.clone(),
     }
   }
+
+  /// Returns `true` if this is one of the ML-DSA algorithms.
+  #[cfg(feature = "pqc")]
+  pub fn is_ml_dsa(&self) -> bool {
+    matches!(self, Self::ML_DSA_44 | Self::ML_DSA_65 | Self::ML_DSA_87)
+  }
+
+  /// Returns `true` if this is one of the SLH-DSA algorithms.
+  #[cfg(feature = "pqc")]
+  pub fn is_slh_dsa(&self) -> bool {
+    matches!(
+      self,
+      Self::SLH_DSA_SHA2_128s
+        | Self::SLH_DSA_SHAKE_128s
+        | Self::SLH_DSA_SHA2_128f
+        | Self::SLH_DSA_SHAKE_128f
+        | Self::SLH_DSA_SHA2_192s
+        | Self::SLH_DSA_SHAKE_192s
+        | Self::SLH_DSA_SHA2_192f
+        | Self::SLH_DSA_SHAKE_192f
+        | Self::SLH_DSA_SHA2_256s
+        | Self::SLH_DSA_SHAKE_256s
+        | Self::SLH_DSA_SHA2_256f
+        | Self::SLH_DSA_SHAKE_256f
+    )
+  }
+
+  /// Returns `true` if this is a hybrid algorithm, combining a post-quantum signature with a
+  /// traditional one.
+  #[cfg(feature = "pqc")]
+  pub fn is_hybrid(&self) -> bool {
+    matches!(self, Self::ML_DSA_44_Ed25519 | Self::ML_DSA_65_Ed25519)
+  }
+
+  /// Returns `true` if this is a hybrid algorithm, combining a post-quantum signature with a
+  /// traditional one.
+  ///
+  /// Always `false` when feature `pqc` is disabled.
+  #[cfg(not(feature = "pqc"))]
+  pub fn is_hybrid(&self) -> bool {
+    false
+  }
+
+  /// Returns `true` if signatures produced with this algorithm are expected to resist attacks
+  /// carried out with a quantum computer, i.e. if this is a post-quantum or a hybrid algorithm.
+  #[cfg(feature = "pqc")]
+  pub fn is_post_quantum(&self) -> bool {
+    self.is_ml_dsa() || self.is_slh_dsa() || self.is_hybrid()
+  }
+
+  /// Returns `true` if signatures produced with this algorithm are expected to resist attacks
+  /// carried out with a quantum computer, i.e. if this is a post-quantum or a hybrid algorithm.
+  ///
+  /// Always `false` when feature `pqc` is disabled.
+  #[cfg(not(feature = "pqc"))]
+  pub fn is_post_quantum(&self) -> bool {
+    false
+  }
 }
 
 impl FromStr for JwsAlgorithm {
@@ -141,6 +369,40 @@ impl FromStr for JwsAlgorithm {
       "ES256K" => Ok(Self::ES256K),
       "none" => Ok(Self::NONE),
       "EdDSA" => Ok(Self::EdDSA),
+      #[cfg(feature = "pqc")]
+      "ML-DSA-44" => Ok(Self::ML_DSA_44),
+      #[cfg(feature = "pqc")]
+      "ML-DSA-65" => Ok(Self::ML_DSA_65),
+      #[cfg(feature = "pqc")]
+      "ML-DSA-87" => Ok(Self::ML_DSA_87),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHA2-128s" => Ok(Self::SLH_DSA_SHA2_128s),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHAKE-128s" => Ok(Self::SLH_DSA_SHAKE_128s),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHA2-128f" => Ok(Self::SLH_DSA_SHA2_128f),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHAKE-128f" => Ok(Self::SLH_DSA_SHAKE_128f),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHA2-192s" => Ok(Self::SLH_DSA_SHA2_192s),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHAKE-192s" => Ok(Self::SLH_DSA_SHAKE_192s),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHA2-192f" => Ok(Self::SLH_DSA_SHA2_192f),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHAKE-192f" => Ok(Self::SLH_DSA_SHAKE_192f),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHA2-256s" => Ok(Self::SLH_DSA_SHA2_256s),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHAKE-256s" => Ok(Self::SLH_DSA_SHAKE_256s),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHA2-256f" => Ok(Self::SLH_DSA_SHA2_256f),
+      #[cfg(feature = "pqc")]
+      "SLH-DSA-SHAKE-256f" => Ok(Self::SLH_DSA_SHAKE_256f),
+      #[cfg(feature = "pqc")]
+      "ML-DSA-44-Ed25519" => Ok(Self::ML_DSA_44_Ed25519),
+      #[cfg(feature = "pqc")]
+      "ML-DSA-65-Ed25519" => Ok(Self::ML_DSA_65_Ed25519),
       #[cfg(feature = "custom_alg")]
       value => Ok(Self::Custom(value.to_string())),
       #[cfg(not(feature = "custom_alg"))]
@@ -0,0 +1,93 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::JwsVerifier;
+use super::SignatureVerificationError;
+use super::SignatureVerificationErrorKind;
+use super::VerificationInput;
+use crate::jwk::Jwk;
+use crate::jws::JwsAlgorithm;
+
+/// A [`JwsVerifier`] that delegates verification to one of several [`JwsVerifier`]s, based on the `alg`
+/// of the JWS being verified.
+///
+/// This allows combining verifiers of different algorithm families - e.g. EdDSA, ECDSA and RSA - into
+/// a single [`JwsVerifier`].
+///
+/// ## Example
+/// ```
+/// # use identity_jose::jws::CompositeJwsVerifier;
+/// # use identity_jose::jws::JwsAlgorithm;
+/// # use identity_jose::jws::JwsVerifierFn;
+/// # use identity_jose::jws::SignatureVerificationErrorKind;
+/// # use identity_jose::jws::VerificationInput;
+/// # use identity_jose::jwk::Jwk;
+/// let verifier = CompositeJwsVerifier::new().with_verifier(
+///   [JwsAlgorithm::ES384, JwsAlgorithm::ES512],
+///   JwsVerifierFn::from(|_input: VerificationInput, _key: &Jwk| {
+///     // Verify the signature.
+///     Ok(())
+///   }),
+/// );
+///
+/// assert!(verifier.supports(&JwsAlgorithm::ES384));
+/// assert!(!verifier.supports(&JwsAlgorithm::EdDSA));
+/// ```
+#[derive(Default)]
+pub struct CompositeJwsVerifier {
+  // Verifiers are shared by the algorithms they handle and dropped once none of them is left.
+  verifier_by_alg: HashMap<JwsAlgorithm, Arc<dyn JwsVerifier + Send + Sync>>,
+}
+
+impl CompositeJwsVerifier {
+  /// Creates a new [`CompositeJwsVerifier`] that doesn't support any algorithm.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Uses `verifier` to verify JWSs whose `alg` is one of `algorithms`.
+  ///
+  /// Algorithms that were previously handled by another verifier are handled by `verifier` instead.
+  pub fn with_verifier<V>(mut self, algorithms: impl IntoIterator<Item = JwsAlgorithm>, verifier: V) -> Self
+  where
+    V: JwsVerifier + Send + Sync + 'static,
+  {
+    let verifier: Arc<dyn JwsVerifier + Send + Sync> = Arc::new(verifier);
+    self
+      .verifier_by_alg
+      .extend(algorithms.into_iter().map(|alg| (alg, Arc::clone(&verifier))));
+
+    self
+  }
+
+  /// Returns whether JWSs using `alg` can be verified by this verifier.
+  pub fn supports(&self, alg: &JwsAlgorithm) -> bool {
+    self.verifier_by_alg.contains_key(alg)
+  }
+
+  /// Returns an iterator over the algorithms supported by this verifier.
+  pub fn supported_algorithms(&self) -> impl Iterator<Item = &JwsAlgorithm> {
+    self.verifier_by_alg.keys()
+  }
+}
+
+impl JwsVerifier for CompositeJwsVerifier {
+  fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    let Some(verifier) = self.verifier_by_alg.get(&input.alg) else {
+      return Err(SignatureVerificationErrorKind::UnsupportedAlg.into());
+    };
+
+    verifier.verify(input, public_key)
+  }
+}
+
+impl std::fmt::Debug for CompositeJwsVerifier {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("CompositeJwsVerifier")
+      .field("supported_algorithms", &self.verifier_by_alg.keys().collect::<Vec<_>>())
+      .finish_non_exhaustive()
+  }
+}
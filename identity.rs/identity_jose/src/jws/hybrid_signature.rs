@@ -0,0 +1,173 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::hashes::sha::SHA512;
+use crypto::hashes::sha::SHA512_LEN;
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jws::JwsAlgorithm;
+
+/// The prefix of every message representative.
+const PREFIX: &[u8] = b"CompositeAlgorithmSignatures2025";
+
+/// The label of `id-MLDSA44-Ed25519-SHA512`.
+const ML_DSA_44_ED25519_LABEL: &[u8] = b"COMPSIG-MLDSA44-Ed25519-SHA512";
+/// The label of `id-MLDSA65-Ed25519-SHA512`.
+const ML_DSA_65_ED25519_LABEL: &[u8] = b"COMPSIG-MLDSA65-Ed25519-SHA512";
+
+/// Length in bytes of an Ed25519 public key.
+const ED25519_PUBLIC_KEY_LEN: usize = 32;
+/// Length in bytes of an Ed25519 signature.
+const ED25519_SIGNATURE_LEN: usize = 64;
+
+/// Describes how a hybrid JWS algorithm combines an ML-DSA signature with a traditional one,
+/// following [Composite ML-DSA, draft 07](https://datatracker.ietf.org/doc/draft-ietf-lamps-pq-composite-sigs/07/).
+///
+/// Hybrid keys are encoded as AKP JWKs whose `pub` (respectively `priv`) parameter is the concatenation of
+/// the ML-DSA and the traditional public (respectively private) keys. Likewise, a hybrid signature is the
+/// concatenation of the ML-DSA signature and the traditional signature, both computed over the
+/// [message representative](HybridSignatureAlgorithm::message_representative) of the JWS signing input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HybridSignatureAlgorithm {
+  alg: JwsAlgorithm,
+  pq_alg: JwsAlgorithm,
+  traditional_alg: JwsAlgorithm,
+  label: &'static [u8],
+}
+
+impl HybridSignatureAlgorithm {
+  /// Returns the [`HybridSignatureAlgorithm`] corresponding to `alg`, if `alg` is a hybrid algorithm.
+  pub fn from_jws_algorithm(alg: &JwsAlgorithm) -> Option<Self> {
+    let (alg, pq_alg, label) = match alg {
+      JwsAlgorithm::ML_DSA_44_Ed25519 => (
+        JwsAlgorithm::ML_DSA_44_Ed25519,
+        JwsAlgorithm::ML_DSA_44,
+        ML_DSA_44_ED25519_LABEL,
+      ),
+      JwsAlgorithm::ML_DSA_65_Ed25519 => (
+        JwsAlgorithm::ML_DSA_65_Ed25519,
+        JwsAlgorithm::ML_DSA_65,
+        ML_DSA_65_ED25519_LABEL,
+      ),
+      _ => return None,
+    };
+
+    Some(Self {
+      alg,
+      pq_alg,
+      traditional_alg: JwsAlgorithm::EdDSA,
+      label,
+    })
+  }
+
+  /// Returns the hybrid [`JwsAlgorithm`].
+  pub fn alg(&self) -> &JwsAlgorithm {
+    &self.alg
+  }
+
+  /// Returns the post-quantum component of this hybrid algorithm.
+  pub fn pq_algorithm(&self) -> &JwsAlgorithm {
+    &self.pq_alg
+  }
+
+  /// Returns the traditional component of this hybrid algorithm.
+  pub fn traditional_algorithm(&self) -> &JwsAlgorithm {
+    &self.traditional_alg
+  }
+
+  /// Returns the label that separates this hybrid algorithm from other ones, which is also used as the
+  /// context string of the ML-DSA component.
+  pub fn label(&self) -> &'static [u8] {
+    self.label
+  }
+
+  /// Computes the message representative `M'` that both component algorithms sign, i.e.
+  /// `Prefix || Label || len(ctx) || ctx || SHA512(message)` with an empty `ctx`.
+  pub fn message_representative(&self, message: &[u8]) -> Vec<u8> {
+    let mut digest = [0; SHA512_LEN];
+    SHA512(message, &mut digest);
+
+    let mut representative: Vec<u8> = Vec::with_capacity(PREFIX.len() + self.label.len() + 1 + SHA512_LEN);
+    representative.extend_from_slice(PREFIX);
+    representative.extend_from_slice(self.label);
+    representative.push(0);
+    representative.extend_from_slice(&digest);
+
+    representative
+  }
+
+  /// Concatenates the encoded keys or signatures of the two components.
+  pub fn combine(&self, pq_component: &[u8], traditional_component: &[u8]) -> Vec<u8> {
+    [pq_component, traditional_component].concat()
+  }
+
+  /// Splits a hybrid public key into its post-quantum and traditional components.
+  pub fn split_public_key<'a>(&self, public_key: &'a [u8]) -> Result<(&'a [u8], &'a [u8])> {
+    split_at_end(public_key, ED25519_PUBLIC_KEY_LEN).ok_or(Error::KeyError("hybrid public key"))
+  }
+
+  /// Splits a hybrid signature into its post-quantum and traditional components.
+  pub fn split_signature<'a>(&self, signature: &'a [u8]) -> Result<(&'a [u8], &'a [u8])> {
+    split_at_end(signature, ED25519_SIGNATURE_LEN).ok_or(Error::InvalidContent("invalid hybrid signature length"))
+  }
+}
+
+/// Splits `bytes` such that the second slice is `traditional_len` bytes long and the first one is non-empty.
+fn split_at_end(bytes: &[u8], traditional_len: usize) -> Option<(&[u8], &[u8])> {
+  bytes
+    .len()
+    .checked_sub(traditional_len)
+    .filter(|&mid| mid > 0)
+    .map(|mid| bytes.split_at(mid))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn only_hybrid_algorithms_are_mapped() {
+    let hybrid = HybridSignatureAlgorithm::from_jws_algorithm(&JwsAlgorithm::ML_DSA_44_Ed25519).unwrap();
+    assert_eq!(hybrid.pq_algorithm(), &JwsAlgorithm::ML_DSA_44);
+    assert_eq!(hybrid.traditional_algorithm(), &JwsAlgorithm::EdDSA);
+
+    assert!(HybridSignatureAlgorithm::from_jws_algorithm(&JwsAlgorithm::ML_DSA_44).is_none());
+    assert!(HybridSignatureAlgorithm::from_jws_algorithm(&JwsAlgorithm::EdDSA).is_none());
+  }
+
+  #[test]
+  fn message_representative_is_label_separated() {
+    let ml_dsa_44 = HybridSignatureAlgorithm::from_jws_algorithm(&JwsAlgorithm::ML_DSA_44_Ed25519).unwrap();
+    let ml_dsa_65 = HybridSignatureAlgorithm::from_jws_algorithm(&JwsAlgorithm::ML_DSA_65_Ed25519).unwrap();
+
+    // Prefix || "COMPSIG-MLDSA44-Ed25519-SHA512" || 0x00 || SHA512("payload").
+    let expected: &str = concat!(
+      "436f6d706f73697465416c676f726974686d5369676e61747572657332303235",
+      "434f4d505349472d4d4c44534134342d456432353531392d534841353132",
+      "00",
+      "70b33ce9c9047e30f917e7ea13e42f7767008c3f4f9c9baf49e4390fc625549e",
+      "9625eee39b94545074e8a1824cf3f238463b11bc03d97348e0fc2999ca1fff7f",
+    );
+    let representative = ml_dsa_44.message_representative(b"payload");
+    assert_eq!(hex(&representative), expected);
+    assert_ne!(representative, ml_dsa_65.message_representative(b"payload"));
+  }
+
+  fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+  }
+
+  #[test]
+  fn split_rejects_short_inputs() {
+    let hybrid = HybridSignatureAlgorithm::from_jws_algorithm(&JwsAlgorithm::ML_DSA_65_Ed25519).unwrap();
+
+    let signature = hybrid.combine(&[1; 100], &[2; ED25519_SIGNATURE_LEN]);
+    let (pq, traditional) = hybrid.split_signature(&signature).unwrap();
+    assert_eq!(pq, &[1; 100]);
+    assert_eq!(traditional, &[2; ED25519_SIGNATURE_LEN]);
+
+    assert!(hybrid.split_signature(&[0; ED25519_SIGNATURE_LEN]).is_err());
+    assert!(hybrid.split_public_key(&[0; 10]).is_err());
+  }
+}
@@ -15,6 +15,8 @@ mod decoder;
 mod encoding;
 mod format;
 mod header;
+#[cfg(feature = "pqc")]
+mod hybrid_signature;
 mod recipient;
 
 pub use self::algorithm::*;
@@ -24,4 +26,6 @@ pub use self::decoder::*;
 pub use self::encoding::*;
 pub use self::format::*;
 pub use self::header::*;
+#[cfg(feature = "pqc")]
+pub use self::hybrid_signature::*;
 pub use self::recipient::*;
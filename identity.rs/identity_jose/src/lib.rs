@@ -15,6 +15,10 @@
   clippy::missing_safety_doc
 )]
 
+#[cfg(feature = "cose")]
+pub mod cose;
+#[cfg(feature = "cose")]
+pub mod cwt;
 pub mod error;
 pub mod jose;
 pub mod jwk;
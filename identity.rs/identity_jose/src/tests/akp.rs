@@ -0,0 +1,57 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jwk::Jwk;
+use crate::jwk::JwkParamsAkp;
+use crate::jwk::JwkType;
+use crate::jws::JwsAlgorithm;
+
+#[test]
+fn akp_jwk_roundtrip() {
+  let json = r#"{"kty":"AKP","alg":"ML-DSA-44","pub":"cHVibGlj","priv":"cHJpdmF0ZQ"}"#;
+  let jwk: Jwk = serde_json::from_str(json).unwrap();
+
+  assert_eq!(jwk.kty(), JwkType::Akp);
+  assert_eq!(jwk.alg(), Some("ML-DSA-44"));
+  let params: &JwkParamsAkp = jwk.try_akp_params().unwrap();
+  assert_eq!(params.public, "cHVibGlj");
+  assert_eq!(params.private.as_deref(), Some("cHJpdmF0ZQ"));
+  assert!(jwk.is_private());
+
+  let public: Jwk = jwk.to_public().unwrap();
+  assert!(public.is_public());
+  assert_eq!(public.alg(), jwk.alg());
+
+  let roundtrip: Jwk = serde_json::from_value(serde_json::to_value(&public).unwrap()).unwrap();
+  assert_eq!(roundtrip, public);
+}
+
+#[test]
+fn akp_thumbprint_includes_alg() {
+  let mut jwk: Jwk = Jwk::from_params(JwkParamsAkp {
+    public: "cHVibGlj".to_owned(),
+    private: None,
+  });
+  jwk.set_alg("ML-DSA-44");
+  assert_eq!(
+    jwk.thumbprint_hash_input(),
+    r#"{"alg":"ML-DSA-44","kty":"AKP","pub":"cHVibGlj"}"#
+  );
+
+  let mut other: Jwk = jwk.clone();
+  other.set_alg("ML-DSA-65");
+  assert_ne!(jwk.thumbprint_sha256(), other.thumbprint_sha256());
+}
+
+#[cfg(not(feature = "custom_alg"))]
+#[test]
+fn post_quantum_algorithms_roundtrip() {
+  for alg in JwsAlgorithm::ALL.iter().filter(|alg| alg.is_post_quantum()) {
+    let parsed: JwsAlgorithm = alg.name().parse().unwrap();
+    assert_eq!(&parsed, alg);
+    assert_eq!(serde_json::to_value(alg).unwrap(), alg.name());
+    assert!(alg.is_ml_dsa() || alg.is_slh_dsa() || alg.is_hybrid());
+  }
+  assert!(JwsAlgorithm::ML_DSA_44_Ed25519.is_hybrid());
+  assert!(!JwsAlgorithm::EdDSA.is_post_quantum());
+}
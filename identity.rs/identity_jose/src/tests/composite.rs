@@ -0,0 +1,97 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jwk::Jwk;
+use crate::jwk::JwkParamsOct;
+use crate::jws::CompactJwsEncoder;
+use crate::jws::CompositeJwsVerifier;
+use crate::jws::Decoder;
+use crate::jws::JwsAlgorithm;
+use crate::jws::JwsHeader;
+use crate::jws::JwsVerifierFn;
+use crate::jws::SignatureVerificationErrorKind;
+use crate::jwu;
+use crate::tests::ed25519;
+use crate::tests::hs256;
+
+fn encode(alg: JwsAlgorithm, claims: &[u8], sign: impl FnOnce(&[u8]) -> Vec<u8>) -> String {
+  let mut header: JwsHeader = JwsHeader::new();
+  header.set_alg(alg);
+  let encoder: CompactJwsEncoder<'_> = CompactJwsEncoder::new(claims, &header).unwrap();
+  let signature = sign(encoder.signing_input());
+  encoder.into_jws(&signature)
+}
+
+#[test]
+fn test_composite_verifier_dispatches_on_alg() {
+  let claims: &[u8] = br#"{"key":"value"}"#;
+  let verifier = CompositeJwsVerifier::new()
+    .with_verifier([JwsAlgorithm::EdDSA], JwsVerifierFn::from(ed25519::verify))
+    .with_verifier([JwsAlgorithm::HS256], JwsVerifierFn::from(hs256::verify));
+  let decoder = Decoder::new();
+
+  let secret_key = crypto::signatures::ed25519::SecretKey::generate().unwrap();
+  let ed25519_jwk: Jwk = {
+    let mut params = crate::jwk::JwkParamsOkp::new();
+    params.crv = "Ed25519".into();
+    params.x = jwu::encode_b64(secret_key.public_key().as_slice());
+    Jwk::from_params(params)
+  };
+  let eddsa_jws = encode(JwsAlgorithm::EdDSA, claims, |input| {
+    secret_key.sign(input).to_bytes().to_vec()
+  });
+
+  let mut oct_params = JwkParamsOct::new();
+  oct_params.k = jwu::encode_b64([7; 32]);
+  let hmac_jwk: Jwk = Jwk::from_params(oct_params);
+  let hs256_jws = encode(JwsAlgorithm::HS256, claims, |input| {
+    hs256::sign(input, &hmac_jwk).as_ref().to_vec()
+  });
+
+  for (jws, jwk) in [(&eddsa_jws, &ed25519_jwk), (&hs256_jws, &hmac_jwk)] {
+    let token = decoder
+      .decode_compact_serialization(jws.as_bytes(), None)
+      .and_then(|decoded| decoded.verify(&verifier, jwk))
+      .unwrap();
+    assert_eq!(token.claims, claims);
+  }
+
+  // Algorithms without a registered verifier are rejected.
+  let es256_jws = encode(JwsAlgorithm::ES256, claims, |_| vec![0; 64]);
+  let err = decoder
+    .decode_compact_serialization(es256_jws.as_bytes(), None)
+    .and_then(|decoded| decoded.verify(&verifier, &ed25519_jwk))
+    .unwrap_err();
+  assert!(matches!(
+    err,
+    crate::error::Error::SignatureVerificationError(ref err)
+      if matches!(err.kind(), SignatureVerificationErrorKind::UnsupportedAlg)
+  ));
+}
+
+#[test]
+fn test_composite_verifier_is_send_and_sync() {
+  fn assert_send_sync<T: Send + Sync>() {}
+  assert_send_sync::<CompositeJwsVerifier>();
+}
+
+#[test]
+fn test_composite_verifier_drops_overridden_verifiers() {
+  let marker = std::sync::Arc::new(());
+  let overridden_marker = std::sync::Arc::clone(&marker);
+  let verifier = CompositeJwsVerifier::new()
+    .with_verifier(
+      [JwsAlgorithm::EdDSA, JwsAlgorithm::HS256],
+      JwsVerifierFn::from(move |_input: crate::jws::VerificationInput, _key: &Jwk| {
+        let _ = &overridden_marker;
+        Ok(())
+      }),
+    )
+    .with_verifier([JwsAlgorithm::EdDSA], JwsVerifierFn::from(ed25519::verify));
+  assert_eq!(std::sync::Arc::strong_count(&marker), 2);
+
+  let verifier = verifier.with_verifier([JwsAlgorithm::HS256], JwsVerifierFn::from(hs256::verify));
+  assert_eq!(std::sync::Arc::strong_count(&marker), 1);
+  assert!(verifier.supports(&JwsAlgorithm::EdDSA));
+  assert!(verifier.supports(&JwsAlgorithm::HS256));
+}
@@ -0,0 +1,162 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use coset::iana;
+use coset::HeaderBuilder;
+use coset::Label;
+
+use crate::cose::decode_cose_sign1;
+use crate::cose::verify_cose_sign1;
+use crate::cose::CoseSign1Encoder;
+use crate::cwt::CwtClaims;
+use crate::error::Error;
+use crate::jwk::Jwk;
+use crate::jws::JwsVerifier;
+use crate::jws::JwsVerifierFn;
+use crate::jws::VerificationInput;
+use crate::tests::ed25519;
+
+const ED25519_JWK: &str = r#"{"kty":"OKP","crv":"Ed25519","d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo","alg":"EdDSA"}"#;
+const P256_JWK: &str = r#"{"kty":"EC","crv":"P-256","x":"MKBCTNIcKUSDii11ySs3526iDZ8AiTo7Tu6KPAqv7D4","y":"4Etl6SRW2YiLUrN5vfvVHuhp7x8PxltmWWlbbM4IFyM","d":"870MB6gfuTJ4HtUnUvYMyJpr5eUZNP4Bk43bVdj3eAE","kid":"1"}"#;
+
+fn verifier() -> impl JwsVerifier {
+  JwsVerifierFn::from(|input: VerificationInput, key: &Jwk| ed25519::verify(input, key))
+}
+
+#[test]
+fn test_jwk_cose_key_roundtrip() {
+  for json in [ED25519_JWK, P256_JWK] {
+    let jwk: Jwk = serde_json::from_str(json).unwrap();
+    let cose_key = jwk.to_cose_key().unwrap();
+    assert_eq!(Jwk::from_cose_key(&cose_key).unwrap(), jwk);
+
+    let public = jwk.to_public().unwrap();
+    assert_eq!(Jwk::from_cose_key(&public.to_cose_key().unwrap()).unwrap(), public);
+  }
+}
+
+#[test]
+fn test_cose_key_encoding() {
+  let jwk: Jwk = serde_json::from_str(P256_JWK).unwrap();
+  let cose_key = jwk.to_public().unwrap().to_cose_key().unwrap();
+
+  assert_eq!(cose_key.kty, coset::KeyType::Assigned(iana::KeyType::EC2));
+  assert_eq!(cose_key.key_id, b"1");
+  // crv, x and y.
+  assert_eq!(cose_key.params.len(), 3);
+  assert!(cose_key
+    .params
+    .iter()
+    .all(|(label, _)| *label != Label::Int(iana::Ec2KeyParameter::D as i64)));
+}
+
+#[test]
+fn test_cose_sign1_roundtrip() {
+  let secret: Jwk = serde_json::from_str(ED25519_JWK).unwrap();
+  let public: Jwk = secret.to_public().unwrap();
+  let protected = HeaderBuilder::new()
+    .algorithm(iana::Algorithm::EdDSA)
+    .key_id(b"key-1".to_vec())
+    .build();
+
+  let encoder = CoseSign1Encoder::new(protected.clone(), b"payload", false).unwrap();
+  let signature = ed25519::sign(encoder.signing_input(), &secret);
+  let bytes = encoder.into_bytes(signature.as_ref()).unwrap();
+
+  let sign1 = decode_cose_sign1(&bytes).unwrap();
+  assert_eq!(sign1.payload.as_deref(), Some(b"payload".as_slice()));
+  assert_eq!(sign1.protected.header.key_id, b"key-1");
+  verify_cose_sign1(&sign1, None, &verifier(), &public).unwrap();
+
+  let mut tampered = sign1.clone();
+  tampered.payload = Some(b"other payload".to_vec());
+  assert!(matches!(
+    verify_cose_sign1(&tampered, None, &verifier(), &public),
+    Err(Error::SignatureVerificationError(_))
+  ));
+
+  // Detached payloads must be provided for verification.
+  let encoder = CoseSign1Encoder::new(protected, b"payload", true).unwrap();
+  let signature = ed25519::sign(encoder.signing_input(), &secret);
+  let sign1 = encoder.into_cose_sign1(signature.as_ref());
+  assert!(sign1.payload.is_none());
+  assert!(verify_cose_sign1(&sign1, None, &verifier(), &public).is_err());
+  verify_cose_sign1(&sign1, Some(b"payload"), &verifier(), &public).unwrap();
+}
+
+#[test]
+fn test_cose_sign1_requires_alg() {
+  let protected = HeaderBuilder::new().key_id(b"key-1".to_vec()).build();
+  assert!(matches!(
+    CoseSign1Encoder::new(protected, b"payload", false),
+    Err(Error::ProtectedHeaderWithoutAlg)
+  ));
+}
+
+#[test]
+fn test_cose_sign1_rejects_mac_algorithms() {
+  let protected = HeaderBuilder::new().algorithm(iana::Algorithm::HMAC_256_256).build();
+  assert!(matches!(
+    CoseSign1Encoder::new(protected.clone(), b"payload", false),
+    Err(Error::UnsupportedCoseAlgorithm(_))
+  ));
+
+  // A message whose signature is actually a MAC, e.g. computed with a symmetric key shared with the verifier.
+  let sign1 = coset::CoseSign1Builder::new()
+    .protected(protected)
+    .payload(b"payload".to_vec())
+    .signature(vec![0; 32])
+    .build();
+  let key: Jwk = serde_json::from_str(r#"{"kty":"oct","k":"c2VjcmV0","alg":"HS256"}"#).unwrap();
+  let verifier = JwsVerifierFn::from(|_: VerificationInput, _: &Jwk| Ok(()));
+  assert!(matches!(
+    verify_cose_sign1(&sign1, None, &verifier, &key),
+    Err(Error::UnsupportedCoseAlgorithm(_))
+  ));
+}
+
+#[test]
+fn test_cwt_claims_roundtrip() {
+  let mut claims = CwtClaims::new();
+  claims.set_iss("did:example:issuer");
+  claims.set_sub("did:example:subject");
+  claims.set_aud(["did:example:verifier"]);
+  claims.set_exp(1_700_000_000);
+  claims.set_nbf(1_600_000_000);
+  claims.set_iat(1_600_000_000);
+  claims.set_cti(b"id".to_vec());
+  claims.set_custom(Label::Text("vc".to_owned()), ciborium::Value::Bool(true));
+  claims.set_custom(Label::Int(-65537), ciborium::Value::from(42));
+
+  let decoded = CwtClaims::from_slice(&claims.to_vec().unwrap()).unwrap();
+  assert_eq!(decoded, claims);
+  assert_eq!(decoded.aud(), Some(["did:example:verifier".to_owned()].as_slice()));
+  assert_eq!(
+    decoded.custom().get(&Label::Text("vc".to_owned())),
+    Some(&ciborium::Value::Bool(true))
+  );
+}
+
+#[test]
+fn test_cwt_claims_registered_labels() {
+  let mut claims = CwtClaims::new();
+  claims.set_iss("issuer");
+  claims.set_exp(10);
+
+  let ciborium::Value::Map(entries) = claims.to_cbor_value() else {
+    panic!("expected a map");
+  };
+  assert_eq!(
+    entries,
+    vec![
+      (ciborium::Value::from(1), ciborium::Value::Text("issuer".to_owned())),
+      (ciborium::Value::from(4), ciborium::Value::from(10)),
+    ]
+  );
+
+  let invalid = ciborium::Value::Map(vec![(ciborium::Value::from(1), ciborium::Value::from(1))]);
+  assert!(matches!(
+    CwtClaims::from_cbor_value(invalid),
+    Err(Error::InvalidClaim("iss"))
+  ));
+}
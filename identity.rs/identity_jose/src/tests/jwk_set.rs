@@ -0,0 +1,67 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::jwk::Jwk;
+use crate::jwk::JwkSet;
+use crate::jwk::JwkUse;
+
+fn jwk_set() -> JwkSet {
+  serde_json::from_value(serde_json::json!({
+    "keys": [
+      {
+        "kty": "OKP",
+        "crv": "Ed25519",
+        "use": "sig",
+        "alg": "EdDSA",
+        "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+        "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"
+      },
+      {
+        "kty": "OKP",
+        "crv": "X25519",
+        "use": "enc",
+        "x": "3p7bfXt9wbTTW2HC7OQ1Nz-DQ8hbeGdNrfx-FG-IK08"
+      },
+      {
+        "kty": "oct",
+        "alg": "HS256",
+        "k": "AyM1SysPpbyDfgZld3umj1qzKObwVMkoqQ-EstJQLr_T-1qS0gZH75aKtMN3Yj0iPS4hcgUuTwjAzZr1Z9CAow"
+      }
+    ]
+  }))
+  .unwrap()
+}
+
+#[test]
+fn lookup_by_thumbprint() {
+  let set: JwkSet = jwk_set();
+  let ed25519: &Jwk = &set[0];
+
+  let thumbprint: String = ed25519.thumbprint_sha256_b64();
+  assert_eq!(set.get_by_thumbprint(&thumbprint), Some(ed25519));
+  assert_eq!(set.get_by_thumbprint(&ed25519.thumbprint_sha256_uri()), Some(ed25519));
+  assert!(set.get_by_thumbprint("unknown").is_none());
+}
+
+#[test]
+fn lookup_by_alg_and_use() {
+  let set: JwkSet = jwk_set();
+
+  assert_eq!(set.get_by_alg("EdDSA"), vec![&set[0]]);
+  assert_eq!(set.get_by_alg("HS256"), vec![&set[2]]);
+  assert!(set.get_by_alg("ES256").is_empty());
+
+  assert_eq!(set.get_by_use(JwkUse::Encryption), vec![&set[1]]);
+  assert!(set.get_by_use(JwkUse::Proof).is_empty());
+}
+
+#[test]
+fn to_public_filters_private_material() {
+  let set: JwkSet = jwk_set();
+  assert!(!set.is_public());
+
+  let public: JwkSet = set.to_public();
+  assert!(public.is_public());
+  assert_eq!(public.len(), 2);
+  assert_eq!(public[0].thumbprint_sha256_b64(), set[0].thumbprint_sha256_b64());
+}
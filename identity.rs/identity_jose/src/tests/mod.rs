@@ -1,9 +1,15 @@
 // Copyright 2020-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "pqc")]
+mod akp;
+mod composite;
+#[cfg(feature = "cose")]
+mod cose;
 mod ed25519;
 mod es256;
 mod hs256;
+mod jwk_set;
 mod rfc7515;
 mod rfc7517;
 mod rfc7638;
@@ -0,0 +1,27 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_jose::jwk::Jwk;
+use identity_jose::jwk::JwkParamsAkp;
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jws::SignatureVerificationError;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jwu;
+
+/// Decodes the public key of the AKP `jwk`, making sure the key is meant to be used with `alg`.
+pub(crate) fn decode_public_key(jwk: &Jwk, alg: &JwsAlgorithm) -> Result<Vec<u8>, SignatureVerificationError> {
+  let params: &JwkParamsAkp = jwk
+    .try_akp_params()
+    .map_err(|_| SignatureVerificationErrorKind::UnsupportedKeyType)?;
+
+  // The `alg` parameter is mandatory for AKP keys and binds the key to a single algorithm.
+  if !jwk.alg().is_some_and(|value| value == alg.name()) {
+    return Err(
+      SignatureVerificationError::new(SignatureVerificationErrorKind::UnsupportedKeyParams)
+        .with_custom_message(format!("expected an AKP key to be used with {alg}")),
+    );
+  }
+
+  jwu::decode_b64(&params.public)
+    .map_err(|err| SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err))
+}
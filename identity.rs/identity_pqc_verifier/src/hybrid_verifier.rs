@@ -0,0 +1,67 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_eddsa_verifier::Ed25519Verifier;
+use identity_jose::jwk::EdCurve;
+use identity_jose::jwk::Jwk;
+use identity_jose::jwk::JwkParamsOkp;
+use identity_jose::jws::HybridSignatureAlgorithm;
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jws::SignatureVerificationError;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jws::VerificationInput;
+use identity_jose::jwu;
+
+use crate::MlDsaVerifier;
+
+/// A verifier that can handle hybrid algorithms combining ML-DSA with Ed25519, i.e.
+/// [`JwsAlgorithm::ML_DSA_44_Ed25519`](identity_jose::jws::JwsAlgorithm::ML_DSA_44_Ed25519) and
+/// [`JwsAlgorithm::ML_DSA_65_Ed25519`](identity_jose::jws::JwsAlgorithm::ML_DSA_65_Ed25519).
+///
+/// A hybrid signature is only valid if both its ML-DSA and its Ed25519 components are valid.
+/// See [`HybridSignatureAlgorithm`] for how hybrid keys and signatures are encoded.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct HybridVerifier {}
+
+impl HybridVerifier {
+  /// Verify a JWS signature secured with one of the hybrid algorithms, using an AKP `public_key`.
+  ///
+  /// This function is useful when one is building a [`JwsVerifier`](identity_jose::jws::JwsVerifier) that
+  /// handles hybrid signatures in the same manner as the [`HybridVerifier`] hence extending its capabilities.
+  pub fn verify(input: &VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    let hybrid: HybridSignatureAlgorithm =
+      HybridSignatureAlgorithm::from_jws_algorithm(&input.alg).ok_or(SignatureVerificationErrorKind::UnsupportedAlg)?;
+
+    let public_key: Vec<u8> = crate::akp::decode_public_key(public_key, &input.alg)?;
+    let (pq_public_key, traditional_public_key) = hybrid.split_public_key(&public_key).map_err(|err| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
+    })?;
+    let (pq_signature, traditional_signature) = hybrid.split_signature(&input.decoded_signature).map_err(|err| {
+      SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
+    })?;
+
+    let message: Vec<u8> = hybrid.message_representative(&input.signing_input);
+
+    MlDsaVerifier::verify_raw(
+      hybrid.pq_algorithm(),
+      pq_public_key,
+      &message,
+      hybrid.label(),
+      pq_signature,
+    )?;
+
+    let traditional_jwk: Jwk = Jwk::from_params(JwkParamsOkp {
+      crv: EdCurve::Ed25519.name().to_owned(),
+      x: jwu::encode_b64(traditional_public_key),
+      d: None,
+    });
+    let traditional_input = VerificationInput {
+      alg: JwsAlgorithm::EdDSA,
+      signing_input: message.into(),
+      decoded_signature: traditional_signature.into(),
+    };
+
+    Ed25519Verifier::verify(traditional_input, &traditional_jwk)
+  }
+}
@@ -0,0 +1,34 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![doc = include_str!("./../README.md")]
+#![warn(
+  rust_2018_idioms,
+  unreachable_pub,
+  missing_docs,
+  rustdoc::missing_crate_level_docs,
+  rustdoc::broken_intra_doc_links,
+  rustdoc::private_intra_doc_links,
+  rustdoc::private_doc_tests,
+  clippy::missing_safety_doc
+)]
+
+mod akp;
+#[cfg(feature = "hybrid")]
+mod hybrid_verifier;
+#[cfg(feature = "ml-dsa")]
+mod ml_dsa_verifier;
+mod pqc_jws_verifier;
+#[cfg(feature = "slh-dsa")]
+mod slh_dsa_verifier;
+
+#[cfg(feature = "hybrid")]
+pub use hybrid_verifier::*;
+#[cfg(feature = "ml-dsa")]
+pub use ml_dsa_verifier::*;
+pub use pqc_jws_verifier::*;
+#[cfg(feature = "slh-dsa")]
+pub use slh_dsa_verifier::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,84 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Deref;
+
+use identity_jose::jwk::Jwk;
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jws::SignatureVerificationError;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jws::VerificationInput;
+use ml_dsa::EncodedSignature;
+use ml_dsa::EncodedVerifyingKey;
+use ml_dsa::MlDsa44;
+use ml_dsa::MlDsa65;
+use ml_dsa::MlDsa87;
+use ml_dsa::MlDsaParams;
+use ml_dsa::Signature;
+use ml_dsa::VerifyingKey;
+
+/// A verifier that can handle the
+/// [`JwsAlgorithm::ML_DSA_44`](identity_jose::jws::JwsAlgorithm::ML_DSA_44),
+/// [`JwsAlgorithm::ML_DSA_65`](identity_jose::jws::JwsAlgorithm::ML_DSA_65) and
+/// [`JwsAlgorithm::ML_DSA_87`](identity_jose::jws::JwsAlgorithm::ML_DSA_87) algorithms.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct MlDsaVerifier {}
+
+impl MlDsaVerifier {
+  /// Verify a JWS signature secured with one of the ML-DSA algorithms, using an AKP `public_key`.
+  ///
+  /// This function is useful when one is building a [`JwsVerifier`](identity_jose::jws::JwsVerifier) that
+  /// handles ML-DSA in the same manner as the [`MlDsaVerifier`] hence extending its capabilities.
+  pub fn verify(input: &VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    let public_key: Vec<u8> = crate::akp::decode_public_key(public_key, &input.alg)?;
+    Self::verify_raw(
+      &input.alg,
+      &public_key,
+      &input.signing_input,
+      &[],
+      input.decoded_signature.deref(),
+    )
+  }
+
+  /// Verifies the ML-DSA `signature` over `message` with context string `ctx` against the
+  /// encoded `public_key`, according to the parameter set identified by `alg`.
+  pub fn verify_raw(
+    alg: &JwsAlgorithm,
+    public_key: &[u8],
+    message: &[u8],
+    ctx: &[u8],
+    signature: &[u8],
+  ) -> Result<(), SignatureVerificationError> {
+    match alg {
+      JwsAlgorithm::ML_DSA_44 => verify_with_params::<MlDsa44>(public_key, message, ctx, signature),
+      JwsAlgorithm::ML_DSA_65 => verify_with_params::<MlDsa65>(public_key, message, ctx, signature),
+      JwsAlgorithm::ML_DSA_87 => verify_with_params::<MlDsa87>(public_key, message, ctx, signature),
+      _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+    }
+  }
+}
+
+fn verify_with_params<P: MlDsaParams>(
+  public_key: &[u8],
+  message: &[u8],
+  ctx: &[u8],
+  signature: &[u8],
+) -> Result<(), SignatureVerificationError> {
+  let encoded_key = EncodedVerifyingKey::<P>::try_from(public_key).map_err(|_| {
+    SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure)
+      .with_custom_message("invalid ML-DSA public key length")
+  })?;
+  let verifying_key = VerifyingKey::<P>::decode(&encoded_key);
+
+  let encoded_signature =
+    EncodedSignature::<P>::try_from(signature).map_err(|_| SignatureVerificationErrorKind::InvalidSignature)?;
+  let signature: Signature<P> =
+    Signature::decode(&encoded_signature).ok_or(SignatureVerificationErrorKind::InvalidSignature)?;
+
+  if verifying_key.verify_with_context(message, ctx, &signature) {
+    Ok(())
+  } else {
+    Err(SignatureVerificationErrorKind::InvalidSignature.into())
+  }
+}
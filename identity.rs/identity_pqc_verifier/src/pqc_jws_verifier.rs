@@ -0,0 +1,41 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_jose::jwk::Jwk;
+use identity_jose::jws::JwsVerifier;
+use identity_jose::jws::SignatureVerificationError;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jws::VerificationInput;
+
+/// An implementor of [`JwsVerifier`] that can handle post-quantum algorithms.
+///
+/// The following algorithms are supported, if the respective feature on the crate is activated:
+///
+/// - ML-DSA, e.g. [`JwsAlgorithm::ML_DSA_44`](identity_jose::jws::JwsAlgorithm::ML_DSA_44).
+/// - SLH-DSA, e.g. [`JwsAlgorithm::SLH_DSA_SHA2_128s`](identity_jose::jws::JwsAlgorithm::SLH_DSA_SHA2_128s).
+/// - Hybrid ML-DSA and Ed25519, e.g.
+///   [`JwsAlgorithm::ML_DSA_44_Ed25519`](identity_jose::jws::JwsAlgorithm::ML_DSA_44_Ed25519).
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct PqcJwsVerifier {}
+
+impl JwsVerifier for PqcJwsVerifier {
+  // Allow unused variables in case of no-default-features.
+  #[allow(unused_variables)]
+  fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    #[cfg(feature = "ml-dsa")]
+    if input.alg.is_ml_dsa() {
+      return crate::MlDsaVerifier::verify(&input, public_key);
+    }
+    #[cfg(feature = "slh-dsa")]
+    if input.alg.is_slh_dsa() {
+      return crate::SlhDsaVerifier::verify(&input, public_key);
+    }
+    #[cfg(feature = "hybrid")]
+    if input.alg.is_hybrid() {
+      return crate::HybridVerifier::verify(&input, public_key);
+    }
+
+    Err(SignatureVerificationErrorKind::UnsupportedAlg.into())
+  }
+}
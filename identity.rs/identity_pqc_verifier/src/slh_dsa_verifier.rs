@@ -0,0 +1,78 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Deref;
+
+use identity_jose::jwk::Jwk;
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jws::SignatureVerificationError;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jws::VerificationInput;
+use slh_dsa::ParameterSet;
+use slh_dsa::Sha2_128f;
+use slh_dsa::Sha2_128s;
+use slh_dsa::Sha2_192f;
+use slh_dsa::Sha2_192s;
+use slh_dsa::Sha2_256f;
+use slh_dsa::Sha2_256s;
+use slh_dsa::Shake128f;
+use slh_dsa::Shake128s;
+use slh_dsa::Shake192f;
+use slh_dsa::Shake192s;
+use slh_dsa::Shake256f;
+use slh_dsa::Shake256s;
+use slh_dsa::Signature;
+use slh_dsa::VerifyingKey;
+
+/// A verifier that can handle the SLH-DSA algorithms, e.g.
+/// [`JwsAlgorithm::SLH_DSA_SHA2_128s`](identity_jose::jws::JwsAlgorithm::SLH_DSA_SHA2_128s).
+///
+/// All the parameter sets defined in FIPS 205 are supported.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct SlhDsaVerifier {}
+
+impl SlhDsaVerifier {
+  /// Verify a JWS signature secured with one of the SLH-DSA algorithms, using an AKP `public_key`.
+  ///
+  /// This function is useful when one is building a [`JwsVerifier`](identity_jose::jws::JwsVerifier) that
+  /// handles SLH-DSA in the same manner as the [`SlhDsaVerifier`] hence extending its capabilities.
+  pub fn verify(input: &VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    let public_key: Vec<u8> = crate::akp::decode_public_key(public_key, &input.alg)?;
+    let message: &[u8] = &input.signing_input;
+    let signature: &[u8] = input.decoded_signature.deref();
+
+    match input.alg {
+      JwsAlgorithm::SLH_DSA_SHA2_128s => verify_with_params::<Sha2_128s>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHAKE_128s => verify_with_params::<Shake128s>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHA2_128f => verify_with_params::<Sha2_128f>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHAKE_128f => verify_with_params::<Shake128f>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHA2_192s => verify_with_params::<Sha2_192s>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHAKE_192s => verify_with_params::<Shake192s>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHA2_192f => verify_with_params::<Sha2_192f>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHAKE_192f => verify_with_params::<Shake192f>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHA2_256s => verify_with_params::<Sha2_256s>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHAKE_256s => verify_with_params::<Shake256s>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHA2_256f => verify_with_params::<Sha2_256f>(&public_key, message, signature),
+      JwsAlgorithm::SLH_DSA_SHAKE_256f => verify_with_params::<Shake256f>(&public_key, message, signature),
+      _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+    }
+  }
+}
+
+fn verify_with_params<P: ParameterSet>(
+  public_key: &[u8],
+  message: &[u8],
+  signature: &[u8],
+) -> Result<(), SignatureVerificationError> {
+  let verifying_key = VerifyingKey::<P>::try_from(public_key).map_err(|err| {
+    SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
+  })?;
+  let signature = Signature::<P>::try_from(signature).map_err(|err| {
+    SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
+  })?;
+
+  verifying_key
+    .try_verify_with_context(message, &[], &signature)
+    .map_err(|err| SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err))
+}
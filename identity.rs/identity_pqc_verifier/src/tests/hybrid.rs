@@ -0,0 +1,67 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use identity_jose::jwk::Jwk;
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jws::JwsVerifier;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jws::VerificationInput;
+use identity_jose::jwu;
+use serde_json::Value;
+
+use crate::PqcJwsVerifier;
+
+/// Signatures produced with OpenSSL, see `tests/fixtures/generate_hybrid_vectors.py`.
+const VECTORS: &str = include_str!("../../tests/fixtures/hybrid-ml-dsa-ed25519.json");
+
+fn vectors() -> Vec<(VerificationInput, Jwk)> {
+  let vectors: Vec<Value> = serde_json::from_str(VECTORS).unwrap();
+  vectors
+    .into_iter()
+    .map(|vector| {
+      let input = VerificationInput {
+        alg: JwsAlgorithm::from_str(vector["alg"].as_str().unwrap()).unwrap(),
+        signing_input: vector["signing_input"].as_str().unwrap().as_bytes().into(),
+        decoded_signature: jwu::decode_b64(vector["signature"].as_str().unwrap()).unwrap().into(),
+      };
+      let jwk: Jwk = serde_json::from_value(vector["jwk"].clone()).unwrap();
+      (input, jwk)
+    })
+    .collect()
+}
+
+#[test]
+fn verify_independently_produced_signatures() {
+  let verifier = PqcJwsVerifier::default();
+
+  let vectors = vectors();
+  assert_eq!(vectors.len(), 2);
+  for (input, jwk) in vectors {
+    assert!(verifier.verify(input, &jwk).is_ok());
+  }
+}
+
+#[test]
+fn tampered_component_is_rejected() {
+  let verifier = PqcJwsVerifier::default();
+
+  for (input, jwk) in vectors() {
+    let signature: &[u8] = &input.decoded_signature;
+
+    // Flip a bit of the ML-DSA component, then of the Ed25519 component.
+    for position in [0, signature.len() - 1] {
+      let mut tampered: Vec<u8> = signature.to_vec();
+      tampered[position] ^= 1;
+      let tampered_input = VerificationInput {
+        alg: input.alg,
+        signing_input: input.signing_input.clone(),
+        decoded_signature: tampered.into(),
+      };
+
+      let err = verifier.verify(tampered_input, &jwk).unwrap_err();
+      assert!(matches!(err.kind(), SignatureVerificationErrorKind::InvalidSignature));
+    }
+  }
+}
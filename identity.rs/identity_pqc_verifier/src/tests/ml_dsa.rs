@@ -0,0 +1,67 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jws::JwsVerifier;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jws::VerificationInput;
+use ml_dsa::KeyGen;
+use ml_dsa::MlDsa44;
+use ml_dsa::MlDsa65;
+use ml_dsa::MlDsa87;
+use ml_dsa::MlDsaParams;
+
+use super::akp_jwk;
+use crate::PqcJwsVerifier;
+
+const SIGNING_INPUT: &[u8] = b"eyJhbGciOiJNTC1EU0EtNDQifQ.cGF5bG9hZA";
+
+/// Signs [`SIGNING_INPUT`] with a fresh key pair and returns the verification input and the encoded public key.
+fn sign<P: MlDsaParams>(alg: JwsAlgorithm) -> (VerificationInput, Vec<u8>) {
+  let key_pair = P::key_gen(&mut rand::thread_rng());
+  let signature = key_pair.signing_key().sign_deterministic(SIGNING_INPUT, &[]).unwrap();
+
+  let input = VerificationInput {
+    alg,
+    signing_input: SIGNING_INPUT.into(),
+    decoded_signature: signature.encode().to_vec().into(),
+  };
+
+  (input, key_pair.verifying_key().encode().to_vec())
+}
+
+#[test]
+fn verify_ml_dsa() {
+  let verifier = PqcJwsVerifier::default();
+
+  for (input, public_key) in [
+    sign::<MlDsa44>(JwsAlgorithm::ML_DSA_44),
+    sign::<MlDsa65>(JwsAlgorithm::ML_DSA_65),
+    sign::<MlDsa87>(JwsAlgorithm::ML_DSA_87),
+  ] {
+    let jwk = akp_jwk(input.alg, &public_key);
+    assert!(verifier.verify(input, &jwk).is_ok());
+  }
+}
+
+#[test]
+fn tampered_signing_input_is_rejected() {
+  let (mut input, public_key) = sign::<MlDsa44>(JwsAlgorithm::ML_DSA_44);
+  let jwk = akp_jwk(JwsAlgorithm::ML_DSA_44, &public_key);
+  input.signing_input = b"eyJhbGciOiJNTC1EU0EtNDQifQ.dGFtcGVyZWQ".as_slice().into();
+
+  let err = PqcJwsVerifier::default().verify(input, &jwk).unwrap_err();
+  assert!(matches!(err.kind(), SignatureVerificationErrorKind::InvalidSignature));
+}
+
+#[test]
+fn key_bound_to_another_algorithm_is_rejected() {
+  let (input, public_key) = sign::<MlDsa44>(JwsAlgorithm::ML_DSA_44);
+  let jwk = akp_jwk(JwsAlgorithm::ML_DSA_65, &public_key);
+
+  let err = PqcJwsVerifier::default().verify(input, &jwk).unwrap_err();
+  assert!(matches!(
+    err.kind(),
+    SignatureVerificationErrorKind::UnsupportedKeyParams
+  ));
+}
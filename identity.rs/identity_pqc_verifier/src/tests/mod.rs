@@ -0,0 +1,24 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "hybrid")]
+mod hybrid;
+#[cfg(feature = "ml-dsa")]
+mod ml_dsa;
+#[cfg(feature = "slh-dsa")]
+mod slh_dsa;
+
+use identity_jose::jwk::Jwk;
+use identity_jose::jwk::JwkParamsAkp;
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jwu;
+
+/// Encodes `public_key` as an AKP JWK to be used with `alg`.
+fn akp_jwk(alg: JwsAlgorithm, public_key: &[u8]) -> Jwk {
+  let mut jwk = Jwk::from_params(JwkParamsAkp {
+    public: jwu::encode_b64(public_key),
+    private: None,
+  });
+  jwk.set_alg(alg.name());
+  jwk
+}
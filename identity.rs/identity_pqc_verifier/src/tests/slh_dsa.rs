@@ -0,0 +1,40 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_jose::jws::JwsAlgorithm;
+use identity_jose::jws::JwsVerifier;
+use identity_jose::jws::SignatureVerificationErrorKind;
+use identity_jose::jws::VerificationInput;
+use slh_dsa::Sha2_128f;
+use slh_dsa::SigningKey;
+
+use super::akp_jwk;
+use crate::PqcJwsVerifier;
+
+const SIGNING_INPUT: &[u8] = b"eyJhbGciOiJTTEgtRFNBLVNIQTItMTI4ZiJ9.cGF5bG9hZA";
+
+#[test]
+fn verify_slh_dsa() {
+  let signing_key = SigningKey::<Sha2_128f>::new(&mut rand::thread_rng());
+  let signature = signing_key.try_sign_with_context(SIGNING_INPUT, &[], None).unwrap();
+  let jwk = akp_jwk(JwsAlgorithm::SLH_DSA_SHA2_128f, &signing_key.verifying_key().to_bytes());
+
+  let input = VerificationInput {
+    alg: JwsAlgorithm::SLH_DSA_SHA2_128f,
+    signing_input: SIGNING_INPUT.into(),
+    decoded_signature: signature.to_bytes().to_vec().into(),
+  };
+  assert!(PqcJwsVerifier::default().verify(input, &jwk).is_ok());
+
+  // The same key cannot be used to verify signatures of another parameter set.
+  let input = VerificationInput {
+    alg: JwsAlgorithm::SLH_DSA_SHAKE_128f,
+    signing_input: SIGNING_INPUT.into(),
+    decoded_signature: signature.to_bytes().to_vec().into(),
+  };
+  let err = PqcJwsVerifier::default().verify(input, &jwk).unwrap_err();
+  assert!(matches!(
+    err.kind(),
+    SignatureVerificationErrorKind::UnsupportedKeyParams
+  ));
+}
@@ -0,0 +1,50 @@
+# Copyright 2020-2025 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+# Generates `hybrid-ml-dsa-ed25519.json`, signing JWS inputs with the hybrid ML-DSA + Ed25519 algorithms as
+# specified in draft-ietf-lamps-pq-composite-sigs-07 using OpenSSL (through pyca/cryptography >= 46) rather than
+# the RustCrypto implementations used by this crate.
+#
+# Usage: python3 generate_hybrid_vectors.py > hybrid-ml-dsa-ed25519.json
+
+import base64
+import hashlib
+import json
+
+from cryptography.hazmat.primitives.asymmetric import ed25519
+from cryptography.hazmat.primitives.asymmetric import mldsa
+
+PREFIX = b"CompositeAlgorithmSignatures2025"
+
+ALGORITHMS = [
+    ("ML-DSA-44-Ed25519", b"COMPSIG-MLDSA44-Ed25519-SHA512", mldsa.MLDSA44PrivateKey, 1),
+    ("ML-DSA-65-Ed25519", b"COMPSIG-MLDSA65-Ed25519-SHA512", mldsa.MLDSA65PrivateKey, 2),
+]
+
+
+def b64(data: bytes) -> str:
+    return base64.urlsafe_b64encode(data).rstrip(b"=").decode()
+
+
+def vector(alg, label, ml_dsa, seed):
+    ml_dsa_key = ml_dsa.from_seed_bytes(bytes([seed]) * 32)
+    ed25519_key = ed25519.Ed25519PrivateKey.from_private_bytes(bytes([seed + 0x10]) * 32)
+
+    header = b64(json.dumps({"alg": alg}, separators=(",", ":")).encode())
+    payload = b64(b'{"iss":"did:example:issuer"}')
+    signing_input = f"{header}.{payload}".encode()
+
+    # M' = Prefix || Label || len(ctx) || ctx || SHA512(M), with an empty ctx.
+    representative = PREFIX + label + bytes([0]) + hashlib.sha512(signing_input).digest()
+    signature = ml_dsa_key.sign(representative, label) + ed25519_key.sign(representative)
+    public_key = ml_dsa_key.public_key().public_bytes_raw() + ed25519_key.public_key().public_bytes_raw()
+
+    return {
+        "alg": alg,
+        "jwk": {"kty": "AKP", "alg": alg, "pub": b64(public_key)},
+        "signing_input": signing_input.decode(),
+        "signature": b64(signature),
+    }
+
+
+print(json.dumps([vector(*algorithm) for algorithm in ALGORITHMS], indent=2))
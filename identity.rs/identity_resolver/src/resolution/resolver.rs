@@ -326,12 +326,18 @@ mod iota_handler {
       ///   clients.
       /// - This function does not validate the provided configuration. Ensure that the provided network name
       ///   corresponds with the client, possibly by using `client.network_name()`.
-      pub fn attach_multiple_iota_handlers<CLI, I>(&mut self, clients: I)
+      pub fn attach_multiple_iota_handlers<CLI, N, I>(&mut self, clients: I)
       where
         CLI: DidResolutionHandler + Send + Sync + 'static,
-        I: IntoIterator<Item = (&'static str, CLI)>,
+        N: Into<String>,
+        I: IntoIterator<Item = (N, CLI)>,
       {
-        let arc_clients = Arc::new(clients.into_iter().collect::<HashMap<&'static str, CLI>>());
+        let arc_clients = Arc::new(
+          clients
+            .into_iter()
+            .map(|(network, client)| (network.into(), client))
+            .collect::<HashMap<String, CLI>>(),
+        );
 
         let handler = move |did: IotaDID| {
           let future_client = arc_clients.clone();
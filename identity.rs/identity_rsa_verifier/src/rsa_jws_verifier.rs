@@ -0,0 +1,120 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ops::Deref;
+
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkParamsRsa;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jws::JwsVerifier;
+use identity_verification::jws::SignatureVerificationError;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use identity_verification::jws::VerificationInput;
+use identity_verification::jwu;
+use rsa::pkcs1v15;
+use rsa::pss;
+use rsa::signature::Verifier;
+use rsa::traits::PublicKeyParts;
+use rsa::BigUint;
+use rsa::RsaPublicKey;
+use sha2::Sha256;
+use sha2::Sha384;
+use sha2::Sha512;
+
+/// The minimum size, in bits, of the RSA keys accepted by [`RsaJwsVerifier`], as
+/// mandated by [RFC 7518](https://www.rfc-editor.org/rfc/rfc7518#section-3.3).
+pub const MIN_RSA_KEY_SIZE: usize = 2048;
+
+/// An implementor of [`JwsVerifier`](identity_verification::jws::JwsVerifier)
+/// that can handle RSA algorithms.
+///
+/// The following algorithms are supported:
+///
+/// - [`JwsAlgorithm::RS256`](identity_verification::jws::JwsAlgorithm::RS256).
+/// - [`JwsAlgorithm::RS384`](identity_verification::jws::JwsAlgorithm::RS384).
+/// - [`JwsAlgorithm::RS512`](identity_verification::jws::JwsAlgorithm::RS512).
+/// - [`JwsAlgorithm::PS256`](identity_verification::jws::JwsAlgorithm::PS256).
+/// - [`JwsAlgorithm::PS384`](identity_verification::jws::JwsAlgorithm::PS384).
+/// - [`JwsAlgorithm::PS512`](identity_verification::jws::JwsAlgorithm::PS512).
+///
+/// Keys shorter than [`MIN_RSA_KEY_SIZE`] bits are rejected.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct RsaJwsVerifier {}
+
+impl RsaJwsVerifier {
+  /// The algorithms supported by [`RsaJwsVerifier`].
+  pub const ALGORITHMS: [JwsAlgorithm; 6] = [
+    JwsAlgorithm::RS256,
+    JwsAlgorithm::RS384,
+    JwsAlgorithm::RS512,
+    JwsAlgorithm::PS256,
+    JwsAlgorithm::PS384,
+    JwsAlgorithm::PS512,
+  ];
+}
+
+impl JwsVerifier for RsaJwsVerifier {
+  fn verify(&self, input: VerificationInput, public_key: &Jwk) -> Result<(), SignatureVerificationError> {
+    // Fail early on unsupported algorithms, before decoding the key.
+    if !Self::ALGORITHMS.contains(&input.alg) {
+      return Err(SignatureVerificationErrorKind::UnsupportedAlg.into());
+    }
+
+    let public_key: RsaPublicKey = decode_public_key(public_key)?;
+
+    match input.alg {
+      JwsAlgorithm::RS256 => verify_with(pkcs1v15::VerifyingKey::<Sha256>::new(public_key), &input),
+      JwsAlgorithm::RS384 => verify_with(pkcs1v15::VerifyingKey::<Sha384>::new(public_key), &input),
+      JwsAlgorithm::RS512 => verify_with(pkcs1v15::VerifyingKey::<Sha512>::new(public_key), &input),
+      JwsAlgorithm::PS256 => verify_with(pss::VerifyingKey::<Sha256>::new(public_key), &input),
+      JwsAlgorithm::PS384 => verify_with(pss::VerifyingKey::<Sha384>::new(public_key), &input),
+      JwsAlgorithm::PS512 => verify_with(pss::VerifyingKey::<Sha512>::new(public_key), &input),
+      _ => Err(SignatureVerificationErrorKind::UnsupportedAlg.into()),
+    }
+  }
+}
+
+/// Decodes the RSA public key contained in `jwk`.
+fn decode_public_key(jwk: &Jwk) -> Result<RsaPublicKey, SignatureVerificationError> {
+  let params: &JwkParamsRsa = jwk
+    .try_rsa_params()
+    .map_err(|_| SignatureVerificationErrorKind::UnsupportedKeyType)?;
+
+  let decode_uint = |value: &str| {
+    jwu::decode_b64(value)
+      .map(|bytes| BigUint::from_bytes_be(&bytes))
+      .map_err(|err| {
+        SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
+      })
+  };
+  let n = decode_uint(&params.n)?;
+  let e = decode_uint(&params.e)?;
+
+  let public_key = RsaPublicKey::new(n, e).map_err(|err| {
+    SignatureVerificationError::new(SignatureVerificationErrorKind::KeyDecodingFailure).with_source(err)
+  })?;
+
+  if public_key.size() * 8 < MIN_RSA_KEY_SIZE {
+    return Err(
+      SignatureVerificationError::new(SignatureVerificationErrorKind::UnsupportedKeyParams)
+        .with_custom_message(format!("RSA keys must be at least {MIN_RSA_KEY_SIZE} bits long")),
+    );
+  }
+
+  Ok(public_key)
+}
+
+fn verify_with<V, S>(verifying_key: V, input: &VerificationInput) -> Result<(), SignatureVerificationError>
+where
+  V: Verifier<S>,
+  S: for<'a> TryFrom<&'a [u8], Error = rsa::signature::Error>,
+{
+  let signature = S::try_from(input.decoded_signature.deref()).map_err(|err| {
+    SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err)
+  })?;
+
+  verifying_key
+    .verify(&input.signing_input, &signature)
+    .map_err(|err| SignatureVerificationError::new(SignatureVerificationErrorKind::InvalidSignature).with_source(err))
+}
@@ -0,0 +1,76 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Ensures we can verify JWTs signed with RSA keys by other libraries.
+
+use identity_verification::jwk::Jwk;
+use identity_verification::jws;
+use identity_verification::jws::SignatureVerificationErrorKind;
+use josekit::jws::JwsHeader;
+use josekit::jws::JwsSigner;
+use josekit::jwt::JwtPayload;
+
+use crate::RsaJwsVerifier;
+
+fn encode_jwt(signer: &dyn JwsSigner) -> String {
+  let mut src_header = JwsHeader::new();
+  src_header.set_token_type("JWT");
+  let mut src_payload = JwtPayload::new();
+  src_payload.set_claim("key", Some("value".into())).unwrap();
+  josekit::jwt::encode_with_signer(&src_payload, &src_header, signer).unwrap()
+}
+
+fn verify(jwt: &str, public_key: &Jwk) -> Result<(), identity_verification::jose::error::Error> {
+  jws::Decoder::new()
+    .decode_compact_serialization(jwt.as_bytes(), None)
+    .and_then(|decoded| decoded.verify(&RsaJwsVerifier::default(), public_key))
+    .map(|_| ())
+}
+
+#[test]
+fn test_rsassa_pkcs1_v1_5_josekit() {
+  for alg in [josekit::jws::RS256, josekit::jws::RS384, josekit::jws::RS512] {
+    let key_pair = alg.generate_key_pair(2048).unwrap();
+    let signer = alg.signer_from_jwk(&key_pair.to_jwk_key_pair()).unwrap();
+    let public_key: Jwk = serde_json::from_str(&key_pair.to_jwk_public_key().to_string()).unwrap();
+
+    assert!(verify(&encode_jwt(&signer), &public_key).is_ok());
+  }
+}
+
+#[test]
+fn test_rsassa_pss_josekit() {
+  for alg in [josekit::jws::PS256, josekit::jws::PS384, josekit::jws::PS512] {
+    let key_pair = alg.generate_key_pair(2048).unwrap();
+    let signer = alg.signer_from_jwk(&key_pair.to_jwk_key_pair()).unwrap();
+    let public_key: Jwk = serde_json::from_str(&key_pair.to_jwk_public_key().to_string()).unwrap();
+
+    assert!(verify(&encode_jwt(&signer), &public_key).is_ok());
+  }
+}
+
+#[test]
+fn test_wrong_key_fails() {
+  let alg = josekit::jws::PS256;
+  let key_pair = alg.generate_key_pair(2048).unwrap();
+  let signer = alg.signer_from_jwk(&key_pair.to_jwk_key_pair()).unwrap();
+  let other_public_key: Jwk =
+    serde_json::from_str(&alg.generate_key_pair(2048).unwrap().to_jwk_public_key().to_string()).unwrap();
+
+  assert!(verify(&encode_jwt(&signer), &other_public_key).is_err());
+}
+
+#[test]
+fn test_short_keys_are_rejected() {
+  let alg = josekit::jws::RS256;
+  let key_pair = alg.generate_key_pair(1024).unwrap();
+  let signer = alg.signer_from_jwk(&key_pair.to_jwk_key_pair()).unwrap();
+  let public_key: Jwk = serde_json::from_str(&key_pair.to_jwk_public_key().to_string()).unwrap();
+
+  let err = verify(&encode_jwt(&signer), &public_key).unwrap_err();
+  assert!(matches!(
+    err,
+    identity_verification::jose::error::Error::SignatureVerificationError(ref err)
+      if matches!(err.kind(), SignatureVerificationErrorKind::UnsupportedKeyParams)
+  ));
+}
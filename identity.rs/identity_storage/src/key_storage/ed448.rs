@@ -0,0 +1,72 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use ed448_goldilocks_plus::SecretKey;
+use ed448_goldilocks_plus::SigningKey;
+use identity_verification::jose::jwk::EdCurve;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkParamsOkp;
+use identity_verification::jose::jwu;
+
+use crate::key_storage::KeyStorageError;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::key_storage::KeyStorageResult;
+
+/// The length of an Ed448 private key, in bytes.
+const SECRET_KEY_LENGTH: usize = 57;
+
+/// Generates a new Ed448 private key, encoded as a JWK.
+pub(crate) fn generate_jwk() -> Jwk {
+  let signing_key = SigningKey::generate(&mut rand::thread_rng());
+
+  let mut params = JwkParamsOkp::new();
+  params.crv = EdCurve::Ed448.name().to_string();
+  params.x = jwu::encode_b64(signing_key.verifying_key().to_bytes());
+  params.d = Some(jwu::encode_b64(signing_key.secret_key()));
+  Jwk::from_params(params)
+}
+
+/// Signs `data` with the Ed448 private key `jwk`.
+pub(crate) fn sign(jwk: &Jwk, data: &[u8]) -> KeyStorageResult<Vec<u8>> {
+  let signing_key = jwk_to_signing_key(jwk)?;
+  Ok(signing_key.sign_raw(data).to_bytes().to_vec())
+}
+
+fn jwk_to_signing_key(jwk: &Jwk) -> KeyStorageResult<SigningKey> {
+  let params: &JwkParamsOkp = jwk.try_okp_params().map_err(|err| {
+    KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+      .with_custom_message("expected Okp parameters")
+      .with_source(err)
+  })?;
+
+  if params
+    .try_ed_curve()
+    .map_err(|err| KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType).with_source(err))?
+    != EdCurve::Ed448
+  {
+    return Err(
+      KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+        .with_custom_message(format!("expected an {} key", EdCurve::Ed448.name())),
+    );
+  }
+
+  let sk: [u8; SECRET_KEY_LENGTH] = params
+    .d
+    .as_deref()
+    .map(jwu::decode_b64)
+    .ok_or_else(|| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message("expected Jwk `d` param to be present")
+    })?
+    .map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("unable to decode `d` param")
+        .with_source(err)
+    })?
+    .try_into()
+    .map_err(|_| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message(format!("expected key of length {SECRET_KEY_LENGTH}"))
+    })?;
+
+  Ok(SigningKey::from(SecretKey::clone_from_slice(&sk)))
+}
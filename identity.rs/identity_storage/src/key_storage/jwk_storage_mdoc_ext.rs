@@ -0,0 +1,88 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use identity_credential::mdoc::CborValue;
+use identity_credential::mdoc::Document;
+use identity_credential::mdoc::ItemsRequest;
+use identity_credential::mdoc::Mdoc;
+use identity_credential::mdoc::MdocBuilder;
+use identity_verification::jwk::Jwk;
+use identity_verification::jws::JwsAlgorithm;
+
+use crate::JwkStorage;
+use crate::KeyId;
+use crate::KeyStorageError;
+use crate::KeyStorageErrorKind;
+use crate::KeyStorageResult;
+
+/// Extension to the [`JwkStorage`] to sign ISO/IEC 18013-5 mdocs and their presentations.
+///
+/// This trait is implemented for every [`JwkStorage`].
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+pub trait JwkStorageMdocExt: JwkStorage {
+  /// Builds the mdoc described by `builder` and signs its mobile security object with the private key identified by
+  /// `key_id`, using the algorithm of the corresponding `public_key` (see [`Jwk::alg`](Jwk::alg())).
+  async fn sign_mdoc(&self, key_id: &KeyId, public_key: &Jwk, builder: MdocBuilder) -> KeyStorageResult<Mdoc>;
+
+  /// Presents the data elements of `mdoc` requested in `request`, signing the presentation with the device key
+  /// identified by `key_id`, using the algorithm of the corresponding `public_key`.
+  ///
+  /// The `session_transcript` binds the presentation to the session established with the verifier.
+  async fn present_mdoc(
+    &self,
+    key_id: &KeyId,
+    public_key: &Jwk,
+    mdoc: &Mdoc,
+    request: &ItemsRequest,
+    session_transcript: &CborValue,
+  ) -> KeyStorageResult<Document>;
+}
+
+#[cfg_attr(not(feature = "send-sync-storage"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync-storage", async_trait)]
+impl<K: JwkStorage> JwkStorageMdocExt for K {
+  async fn sign_mdoc(&self, key_id: &KeyId, public_key: &Jwk, builder: MdocBuilder) -> KeyStorageResult<Mdoc> {
+    let unsigned = builder.build(signature_algorithm(public_key)?).map_err(mdoc_error)?;
+    let signature = self.sign(key_id, &unsigned.signing_input(), public_key).await?;
+
+    Ok(unsigned.finish(signature))
+  }
+
+  async fn present_mdoc(
+    &self,
+    key_id: &KeyId,
+    public_key: &Jwk,
+    mdoc: &Mdoc,
+    request: &ItemsRequest,
+    session_transcript: &CborValue,
+  ) -> KeyStorageResult<Document> {
+    let unsigned = mdoc
+      .present(request, session_transcript, signature_algorithm(public_key)?)
+      .map_err(mdoc_error)?;
+    let signature = self.sign(key_id, &unsigned.signing_input(), public_key).await?;
+
+    Ok(unsigned.finish(signature))
+  }
+}
+
+fn signature_algorithm(public_key: &Jwk) -> KeyStorageResult<JwsAlgorithm> {
+  public_key
+    .alg()
+    .and_then(|alg| JwsAlgorithm::from_str(alg).ok())
+    .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::UnsupportedSignatureAlgorithm))
+}
+
+fn mdoc_error(err: identity_credential::mdoc::Error) -> KeyStorageError {
+  let kind = match err {
+    identity_credential::mdoc::Error::UnsupportedAlgorithm(_) => KeyStorageErrorKind::UnsupportedSignatureAlgorithm,
+    identity_credential::mdoc::Error::UnsupportedKey(_) => KeyStorageErrorKind::UnsupportedKeyType,
+    _ => KeyStorageErrorKind::SerializationError,
+  };
+  KeyStorageError::new(kind)
+    .with_custom_message("failed to prepare the mdoc for signing")
+    .with_source(err)
+}
@@ -11,6 +11,8 @@ use fastcrypto::ed25519::Ed25519KeyPair;
 use fastcrypto::ed25519::Ed25519Signature;
 use fastcrypto::traits::KeyPair as _;
 use fastcrypto::traits::Signer;
+#[cfg(feature = "memstore-nist-ec")]
+use identity_verification::jose::jwk::EcCurve;
 use identity_verification::jose::jwk::EdCurve;
 use identity_verification::jose::jwk::Jwk;
 use identity_verification::jose::jwk::JwkType;
@@ -23,7 +25,17 @@ use tokio::sync::RwLockWriteGuard;
 
 use super::ed25519;
 use super::ed25519::jwk_to_keypair;
+#[cfg(feature = "memstore-ed448")]
+use super::ed448;
 use super::jwk_gen_output::JwkGenOutput;
+#[cfg(feature = "memstore-pqc")]
+use super::pqc;
+#[cfg(feature = "memstore-rsa")]
+use super::rsa_keys;
+#[cfg(feature = "memstore-nist-ec")]
+use super::secp384r1;
+#[cfg(feature = "memstore-nist-ec")]
+use super::secp521r1;
 use super::KeyId;
 use super::KeyStorageError;
 use super::KeyStorageErrorKind;
@@ -63,8 +75,18 @@ impl JwkStorage for JwkMemStore {
 
     check_key_alg_compatibility(key_type, &alg)?;
 
-    let keypair = match key_type {
-      MemStoreKeyType::Ed25519 => Ed25519KeyPair::generate(&mut rand::thread_rng()),
+    let mut jwk: Jwk = match key_type {
+      MemStoreKeyType::Ed25519 => ed25519::encode_jwk(Ed25519KeyPair::generate(&mut rand::thread_rng())),
+      #[cfg(feature = "memstore-ed448")]
+      MemStoreKeyType::Ed448 => ed448::generate_jwk(),
+      #[cfg(feature = "memstore-nist-ec")]
+      MemStoreKeyType::P384 => secp384r1::generate_jwk(),
+      #[cfg(feature = "memstore-nist-ec")]
+      MemStoreKeyType::P521 => secp521r1::generate_jwk(),
+      #[cfg(feature = "memstore-rsa")]
+      MemStoreKeyType::Rsa => rsa_keys::generate_jwk()?,
+      #[cfg(feature = "memstore-pqc")]
+      MemStoreKeyType::MlDsa | MemStoreKeyType::SlhDsa | MemStoreKeyType::MlDsaEd25519 => pqc::generate_jwk(&alg)?,
       other => {
         return Err(
           KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
@@ -75,7 +97,6 @@ impl JwkStorage for JwkMemStore {
 
     let kid: KeyId = random_key_id();
 
-    let mut jwk: Jwk = ed25519::encode_jwk(keypair);
     jwk.set_alg(alg.name());
     jwk.set_kid(jwk.thumbprint_sha256_b64());
     let public_jwk: Jwk = jwk.to_public().expect("should only panic if kty == oct");
@@ -137,22 +158,63 @@ impl JwkStorage for JwkMemStore {
         JwsAlgorithm::from_str(alg_str).map_err(|_| KeyStorageErrorKind::UnsupportedSignatureAlgorithm)
       })?;
 
-    // Check that `kty` is `Okp` and `crv = Ed25519`.
-    match alg {
+    // Check that the key type of `public_key` matches `alg`.
+    let key_type = match alg {
       JwsAlgorithm::EdDSA => {
         let okp_params = public_key.try_okp_params().map_err(|err| {
           KeyStorageError::new(KeyStorageErrorKind::Unspecified)
             .with_custom_message(format!("expected a Jwk with Okp params in order to sign with {alg}"))
             .with_source(err)
         })?;
-        if okp_params.crv != EdCurve::Ed25519.name() {
+        match okp_params.try_ed_curve() {
+          Ok(EdCurve::Ed25519) => MemStoreKeyType::Ed25519,
+          #[cfg(feature = "memstore-ed448")]
+          Ok(EdCurve::Ed448) => MemStoreKeyType::Ed448,
+          _ => {
+            return Err(
+              KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message(format!(
+                "expected a Jwk with a supported Okp crv in order to sign with {alg}"
+              )),
+            );
+          }
+        }
+      }
+      #[cfg(feature = "memstore-nist-ec")]
+      JwsAlgorithm::ES384 => {
+        check_ec_curve(public_key, EcCurve::P384, &alg)?;
+        MemStoreKeyType::P384
+      }
+      #[cfg(feature = "memstore-nist-ec")]
+      JwsAlgorithm::ES512 => {
+        check_ec_curve(public_key, EcCurve::P521, &alg)?;
+        MemStoreKeyType::P521
+      }
+      #[cfg(feature = "memstore-rsa")]
+      JwsAlgorithm::RS256
+      | JwsAlgorithm::RS384
+      | JwsAlgorithm::RS512
+      | JwsAlgorithm::PS256
+      | JwsAlgorithm::PS384
+      | JwsAlgorithm::PS512 => {
+        public_key.try_rsa_params().map_err(|err| {
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message(format!("expected a Jwk with RSA params in order to sign with {alg}"))
+            .with_source(err)
+        })?;
+        MemStoreKeyType::Rsa
+      }
+      #[cfg(feature = "memstore-pqc")]
+      alg if alg.is_post_quantum() => {
+        let key_type = MemStoreKeyType::try_from(public_key)?;
+        check_key_alg_compatibility(key_type, &alg)?;
+        if !public_key.alg().is_some_and(|value| value == alg.name()) {
           return Err(
             KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message(format!(
-              "expected Jwk with Okp {} crv in order to sign with {alg}",
-              EdCurve::Ed25519
+              "expected an AKP Jwk bound to {alg} in order to sign with {alg}"
             )),
           );
         }
+        key_type
       }
       other => {
         return Err(
@@ -166,8 +228,23 @@ impl JwkStorage for JwkMemStore {
     let jwk: &Jwk = jwk_store
       .get(key_id)
       .ok_or_else(|| KeyStorageError::new(KeyStorageErrorKind::KeyNotFound))?;
-    let secret_key = jwk_to_keypair(jwk)?;
-    Ok(Signer::<Ed25519Signature>::sign(&secret_key, data).as_ref().to_vec())
+    match key_type {
+      MemStoreKeyType::Ed25519 => {
+        let secret_key = jwk_to_keypair(jwk)?;
+        Ok(Signer::<Ed25519Signature>::sign(&secret_key, data).as_ref().to_vec())
+      }
+      #[cfg(feature = "memstore-ed448")]
+      MemStoreKeyType::Ed448 => ed448::sign(jwk, data),
+      #[cfg(feature = "memstore-nist-ec")]
+      MemStoreKeyType::P384 => secp384r1::sign(jwk, data),
+      #[cfg(feature = "memstore-nist-ec")]
+      MemStoreKeyType::P521 => secp521r1::sign(jwk, data),
+      #[cfg(feature = "memstore-rsa")]
+      MemStoreKeyType::Rsa => rsa_keys::sign(jwk, &alg, data),
+      #[cfg(feature = "memstore-pqc")]
+      MemStoreKeyType::MlDsa | MemStoreKeyType::SlhDsa | MemStoreKeyType::MlDsaEd25519 => pqc::sign(jwk, &alg, data),
+      MemStoreKeyType::BLS12381G2 => unreachable!("BLS12381G2 keys are not used for JWS"),
+    }
   }
 
   async fn delete(&self, key_id: &KeyId) -> KeyStorageResult<()> {
@@ -188,6 +265,20 @@ impl JwkStorage for JwkMemStore {
 #[derive(Debug, Copy, Clone)]
 enum MemStoreKeyType {
   Ed25519,
+  #[cfg(feature = "memstore-ed448")]
+  Ed448,
+  #[cfg(feature = "memstore-nist-ec")]
+  P384,
+  #[cfg(feature = "memstore-nist-ec")]
+  P521,
+  #[cfg(feature = "memstore-rsa")]
+  Rsa,
+  #[cfg(feature = "memstore-pqc")]
+  MlDsa,
+  #[cfg(feature = "memstore-pqc")]
+  SlhDsa,
+  #[cfg(feature = "memstore-pqc")]
+  MlDsaEd25519,
   BLS12381G2,
 }
 
@@ -196,6 +287,50 @@ impl JwkMemStore {
   /// The Ed25519 key type.
   pub const ED25519_KEY_TYPE: KeyType = KeyType::from_static_str(Self::ED25519_KEY_TYPE_STR);
 
+  #[cfg(feature = "memstore-ed448")]
+  const ED448_KEY_TYPE_STR: &'static str = "Ed448";
+  /// The Ed448 key type.
+  #[cfg(feature = "memstore-ed448")]
+  pub const ED448_KEY_TYPE: KeyType = KeyType::from_static_str(Self::ED448_KEY_TYPE_STR);
+
+  #[cfg(feature = "memstore-nist-ec")]
+  const P384_KEY_TYPE_STR: &'static str = "P384";
+  /// The P-384 elliptic curve key type.
+  #[cfg(feature = "memstore-nist-ec")]
+  pub const P384_KEY_TYPE: KeyType = KeyType::from_static_str(Self::P384_KEY_TYPE_STR);
+
+  #[cfg(feature = "memstore-nist-ec")]
+  const P521_KEY_TYPE_STR: &'static str = "P521";
+  /// The P-521 elliptic curve key type.
+  #[cfg(feature = "memstore-nist-ec")]
+  pub const P521_KEY_TYPE: KeyType = KeyType::from_static_str(Self::P521_KEY_TYPE_STR);
+
+  #[cfg(feature = "memstore-rsa")]
+  const RSA_KEY_TYPE_STR: &'static str = "RSA";
+  /// The RSA key type. Generated keys are 2048 bits long.
+  #[cfg(feature = "memstore-rsa")]
+  pub const RSA_KEY_TYPE: KeyType = KeyType::from_static_str(Self::RSA_KEY_TYPE_STR);
+
+  #[cfg(feature = "memstore-pqc")]
+  const ML_DSA_KEY_TYPE_STR: &'static str = "ML-DSA";
+  /// The ML-DSA key type, usable with [`JwsAlgorithm::ML_DSA_44`], [`JwsAlgorithm::ML_DSA_65`] and
+  /// [`JwsAlgorithm::ML_DSA_87`].
+  #[cfg(feature = "memstore-pqc")]
+  pub const ML_DSA_KEY_TYPE: KeyType = KeyType::from_static_str(Self::ML_DSA_KEY_TYPE_STR);
+
+  #[cfg(feature = "memstore-pqc")]
+  const SLH_DSA_KEY_TYPE_STR: &'static str = "SLH-DSA";
+  /// The SLH-DSA key type, usable with any of the SLH-DSA algorithms.
+  #[cfg(feature = "memstore-pqc")]
+  pub const SLH_DSA_KEY_TYPE: KeyType = KeyType::from_static_str(Self::SLH_DSA_KEY_TYPE_STR);
+
+  #[cfg(feature = "memstore-pqc")]
+  const ML_DSA_ED25519_KEY_TYPE_STR: &'static str = "ML-DSA-Ed25519";
+  /// The hybrid ML-DSA and Ed25519 key type, usable with [`JwsAlgorithm::ML_DSA_44_Ed25519`] and
+  /// [`JwsAlgorithm::ML_DSA_65_Ed25519`].
+  #[cfg(feature = "memstore-pqc")]
+  pub const ML_DSA_ED25519_KEY_TYPE: KeyType = KeyType::from_static_str(Self::ML_DSA_ED25519_KEY_TYPE_STR);
+
   const BLS12381G2_KEY_TYPE_STR: &'static str = "BLS12381G2";
   /// The BLS12381G2 key type
   pub const BLS12381G2_KEY_TYPE: KeyType = KeyType::from_static_str(Self::BLS12381G2_KEY_TYPE_STR);
@@ -205,6 +340,20 @@ impl MemStoreKeyType {
   const fn name(&self) -> &'static str {
     match self {
       MemStoreKeyType::Ed25519 => JwkMemStore::ED25519_KEY_TYPE_STR,
+      #[cfg(feature = "memstore-ed448")]
+      MemStoreKeyType::Ed448 => JwkMemStore::ED448_KEY_TYPE_STR,
+      #[cfg(feature = "memstore-nist-ec")]
+      MemStoreKeyType::P384 => JwkMemStore::P384_KEY_TYPE_STR,
+      #[cfg(feature = "memstore-nist-ec")]
+      MemStoreKeyType::P521 => JwkMemStore::P521_KEY_TYPE_STR,
+      #[cfg(feature = "memstore-rsa")]
+      MemStoreKeyType::Rsa => JwkMemStore::RSA_KEY_TYPE_STR,
+      #[cfg(feature = "memstore-pqc")]
+      MemStoreKeyType::MlDsa => JwkMemStore::ML_DSA_KEY_TYPE_STR,
+      #[cfg(feature = "memstore-pqc")]
+      MemStoreKeyType::SlhDsa => JwkMemStore::SLH_DSA_KEY_TYPE_STR,
+      #[cfg(feature = "memstore-pqc")]
+      MemStoreKeyType::MlDsaEd25519 => JwkMemStore::ML_DSA_ED25519_KEY_TYPE_STR,
       MemStoreKeyType::BLS12381G2 => JwkMemStore::BLS12381G2_KEY_TYPE_STR,
     }
   }
@@ -222,6 +371,20 @@ impl TryFrom<&KeyType> for MemStoreKeyType {
   fn try_from(value: &KeyType) -> Result<Self, Self::Error> {
     match value.as_str() {
       JwkMemStore::ED25519_KEY_TYPE_STR => Ok(MemStoreKeyType::Ed25519),
+      #[cfg(feature = "memstore-ed448")]
+      JwkMemStore::ED448_KEY_TYPE_STR => Ok(MemStoreKeyType::Ed448),
+      #[cfg(feature = "memstore-nist-ec")]
+      JwkMemStore::P384_KEY_TYPE_STR => Ok(MemStoreKeyType::P384),
+      #[cfg(feature = "memstore-nist-ec")]
+      JwkMemStore::P521_KEY_TYPE_STR => Ok(MemStoreKeyType::P521),
+      #[cfg(feature = "memstore-rsa")]
+      JwkMemStore::RSA_KEY_TYPE_STR => Ok(MemStoreKeyType::Rsa),
+      #[cfg(feature = "memstore-pqc")]
+      JwkMemStore::ML_DSA_KEY_TYPE_STR => Ok(MemStoreKeyType::MlDsa),
+      #[cfg(feature = "memstore-pqc")]
+      JwkMemStore::SLH_DSA_KEY_TYPE_STR => Ok(MemStoreKeyType::SlhDsa),
+      #[cfg(feature = "memstore-pqc")]
+      JwkMemStore::ML_DSA_ED25519_KEY_TYPE_STR => Ok(MemStoreKeyType::MlDsaEd25519),
       JwkMemStore::BLS12381G2_KEY_TYPE_STR => Ok(MemStoreKeyType::BLS12381G2),
       _ => Err(KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)),
     }
@@ -245,6 +408,8 @@ impl TryFrom<&Jwk> for MemStoreKeyType {
             .with_source(err)
         })? {
           EdCurve::Ed25519 => Ok(MemStoreKeyType::Ed25519),
+          #[cfg(feature = "memstore-ed448")]
+          EdCurve::Ed448 => Ok(MemStoreKeyType::Ed448),
           curve => Err(
             KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
               .with_custom_message(format!("{curve} not supported")),
@@ -257,6 +422,19 @@ impl TryFrom<&Jwk> for MemStoreKeyType {
             .with_custom_message("expected EC parameters for a JWK with `kty` Ec")
             .with_source(err)
         })?;
+        match ec_params.try_ec_curve() {
+          #[cfg(feature = "memstore-nist-ec")]
+          Ok(EcCurve::P384) => return Ok(MemStoreKeyType::P384),
+          #[cfg(feature = "memstore-nist-ec")]
+          Ok(EcCurve::P521) => return Ok(MemStoreKeyType::P521),
+          Ok(curve) => {
+            return Err(
+              KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+                .with_custom_message(format!("{curve} not supported")),
+            )
+          }
+          Err(_) => (),
+        }
         match ec_params.try_bls_curve().map_err(|err| {
           KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
             .with_custom_message("only Ed curves are supported for signing")
@@ -269,6 +447,31 @@ impl TryFrom<&Jwk> for MemStoreKeyType {
           ),
         }
       }
+      #[cfg(feature = "memstore-rsa")]
+      JwkType::Rsa => Ok(MemStoreKeyType::Rsa),
+      // AKP keys are bound to a single algorithm, which determines the key type.
+      #[cfg(feature = "memstore-pqc")]
+      JwkType::Akp => {
+        let alg: JwsAlgorithm = jwk
+          .alg()
+          .and_then(|alg| JwsAlgorithm::from_str(alg).ok())
+          .ok_or_else(|| {
+            KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+              .with_custom_message("expected an AKP Jwk with a supported `alg` parameter")
+          })?;
+        if alg.is_ml_dsa() {
+          Ok(MemStoreKeyType::MlDsa)
+        } else if alg.is_slh_dsa() {
+          Ok(MemStoreKeyType::SlhDsa)
+        } else if alg.is_hybrid() {
+          Ok(MemStoreKeyType::MlDsaEd25519)
+        } else {
+          Err(
+            KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+              .with_custom_message(format!("AKP keys cannot be used with {alg}")),
+          )
+        }
+      }
       other => Err(
         KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
           .with_custom_message(format!("Jwk `kty` {other} not supported")),
@@ -292,6 +495,28 @@ fn random_key_id() -> KeyId {
 fn check_key_alg_compatibility(key_type: MemStoreKeyType, alg: &JwsAlgorithm) -> KeyStorageResult<()> {
   match (key_type, alg) {
     (MemStoreKeyType::Ed25519, JwsAlgorithm::EdDSA) => Ok(()),
+    #[cfg(feature = "memstore-ed448")]
+    (MemStoreKeyType::Ed448, JwsAlgorithm::EdDSA) => Ok(()),
+    #[cfg(feature = "memstore-nist-ec")]
+    (MemStoreKeyType::P384, JwsAlgorithm::ES384) => Ok(()),
+    #[cfg(feature = "memstore-nist-ec")]
+    (MemStoreKeyType::P521, JwsAlgorithm::ES512) => Ok(()),
+    #[cfg(feature = "memstore-rsa")]
+    (
+      MemStoreKeyType::Rsa,
+      JwsAlgorithm::RS256
+      | JwsAlgorithm::RS384
+      | JwsAlgorithm::RS512
+      | JwsAlgorithm::PS256
+      | JwsAlgorithm::PS384
+      | JwsAlgorithm::PS512,
+    ) => Ok(()),
+    #[cfg(feature = "memstore-pqc")]
+    (MemStoreKeyType::MlDsa, alg) if alg.is_ml_dsa() => Ok(()),
+    #[cfg(feature = "memstore-pqc")]
+    (MemStoreKeyType::SlhDsa, alg) if alg.is_slh_dsa() => Ok(()),
+    #[cfg(feature = "memstore-pqc")]
+    (MemStoreKeyType::MlDsaEd25519, alg) if alg.is_hybrid() => Ok(()),
     (key_type, alg) => Err(
       KeyStorageError::new(crate::key_storage::KeyStorageErrorKind::KeyAlgorithmMismatch)
         .with_custom_message(format!("`cannot use key type `{key_type}` with algorithm `{alg}`")),
@@ -299,6 +524,24 @@ fn check_key_alg_compatibility(key_type: MemStoreKeyType, alg: &JwsAlgorithm) ->
   }
 }
 
+/// Check that `public_key` is an EC key on `curve`, as required to sign with `alg`.
+#[cfg(feature = "memstore-nist-ec")]
+fn check_ec_curve(public_key: &Jwk, curve: EcCurve, alg: &JwsAlgorithm) -> KeyStorageResult<()> {
+  let ec_params = public_key.try_ec_params().map_err(|err| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_custom_message(format!("expected a Jwk with EC params in order to sign with {alg}"))
+      .with_source(err)
+  })?;
+  if ec_params.crv != curve.name() {
+    return Err(
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message(format!("expected Jwk with EC {curve} crv in order to sign with {alg}")),
+    );
+  }
+
+  Ok(())
+}
+
 #[cfg(feature = "jpt-bbs-plus")]
 mod bbs_plus_impl {
   use std::str::FromStr as _;
@@ -455,8 +698,26 @@ mod tests {
   use crate::key_storage::tests::utils::generate_ed25519;
   use fastcrypto::traits::ToFromBytes as _;
   use fastcrypto::traits::VerifyingKey as _;
-  use identity_verification::jose::jwk::EcCurve;
+  #[cfg(feature = "memstore-nist-ec")]
+  use identity_ecdsa_verifier::Secp384R1Verifier;
+  #[cfg(feature = "memstore-nist-ec")]
+  use identity_ecdsa_verifier::Secp521R1Verifier;
+  #[cfg(feature = "memstore-ed448")]
+  use identity_eddsa_verifier::Ed448Verifier;
+  #[cfg(feature = "memstore-pqc")]
+  use identity_pqc_verifier::PqcJwsVerifier;
+  #[cfg(feature = "memstore-rsa")]
+  use identity_rsa_verifier::RsaJwsVerifier;
   use identity_verification::jose::jwk::JwkParamsEc;
+  #[cfg(any(feature = "memstore-pqc", feature = "memstore-rsa"))]
+  use identity_verification::jose::jws::JwsVerifier as _;
+  #[cfg(any(
+    feature = "memstore-ed448",
+    feature = "memstore-nist-ec",
+    feature = "memstore-rsa",
+    feature = "memstore-pqc"
+  ))]
+  use identity_verification::jose::jws::VerificationInput;
 
   use super::*;
 
@@ -480,6 +741,119 @@ mod tests {
     store.delete(&key_id).await.unwrap();
   }
 
+  /// Generates a key of `key_type`, signs a message with it and returns the resulting verification input
+  /// alongside the public key.
+  #[cfg(any(
+    feature = "memstore-ed448",
+    feature = "memstore-nist-ec",
+    feature = "memstore-rsa",
+    feature = "memstore-pqc"
+  ))]
+  async fn generate_and_sign_with(key_type: KeyType, alg: JwsAlgorithm) -> (VerificationInput, Jwk) {
+    let test_msg: &[u8] = b"test";
+    let store: JwkMemStore = JwkMemStore::new();
+
+    let JwkGenOutput { key_id, jwk } = store.generate(key_type, alg).await.unwrap();
+    let public_key: Jwk = jwk.to_public().unwrap();
+    let signature = store.sign(&key_id, test_msg, &public_key).await.unwrap();
+    assert!(store.exists(&key_id).await.unwrap());
+
+    let input = VerificationInput {
+      alg,
+      signing_input: test_msg.into(),
+      decoded_signature: signature.into(),
+    };
+
+    (input, public_key)
+  }
+
+  #[cfg(feature = "memstore-ed448")]
+  #[tokio::test]
+  async fn generate_and_sign_ed448() {
+    let (input, public_key) = generate_and_sign_with(JwkMemStore::ED448_KEY_TYPE, JwsAlgorithm::EdDSA).await;
+    assert_eq!(
+      public_key.try_okp_params().unwrap().try_ed_curve().unwrap(),
+      EdCurve::Ed448
+    );
+
+    assert!(Ed448Verifier::verify(input, &public_key).is_ok());
+  }
+
+  #[cfg(feature = "memstore-nist-ec")]
+  #[tokio::test]
+  async fn generate_and_sign_es384() {
+    let (input, public_key) = generate_and_sign_with(JwkMemStore::P384_KEY_TYPE, JwsAlgorithm::ES384).await;
+    assert!(Secp384R1Verifier::verify(&input, &public_key).is_ok());
+  }
+
+  #[cfg(feature = "memstore-nist-ec")]
+  #[tokio::test]
+  async fn generate_and_sign_es512() {
+    let (input, public_key) = generate_and_sign_with(JwkMemStore::P521_KEY_TYPE, JwsAlgorithm::ES512).await;
+    assert!(Secp521R1Verifier::verify(&input, &public_key).is_ok());
+  }
+
+  #[cfg(feature = "memstore-rsa")]
+  #[tokio::test]
+  async fn generate_and_sign_rsa() {
+    for alg in RsaJwsVerifier::ALGORITHMS {
+      let (input, public_key) = generate_and_sign_with(JwkMemStore::RSA_KEY_TYPE, alg).await;
+      assert!(RsaJwsVerifier::default().verify(input, &public_key).is_ok());
+    }
+  }
+
+  #[cfg(feature = "memstore-pqc")]
+  #[tokio::test]
+  async fn generate_and_sign_post_quantum() {
+    let verifier = PqcJwsVerifier::default();
+
+    for (key_type, alg) in [
+      (JwkMemStore::ML_DSA_KEY_TYPE, JwsAlgorithm::ML_DSA_44),
+      (JwkMemStore::ML_DSA_KEY_TYPE, JwsAlgorithm::ML_DSA_87),
+      (JwkMemStore::SLH_DSA_KEY_TYPE, JwsAlgorithm::SLH_DSA_SHAKE_128f),
+      (JwkMemStore::ML_DSA_ED25519_KEY_TYPE, JwsAlgorithm::ML_DSA_44_Ed25519),
+      (JwkMemStore::ML_DSA_ED25519_KEY_TYPE, JwsAlgorithm::ML_DSA_65_Ed25519),
+    ] {
+      let (input, public_key) = generate_and_sign_with(key_type, alg).await;
+      assert_eq!(public_key.kty(), JwkType::Akp);
+      assert!(verifier.verify(input, &public_key).is_ok());
+    }
+  }
+
+  #[cfg(feature = "memstore-pqc")]
+  #[tokio::test]
+  async fn post_quantum_key_type_alg_mismatch() {
+    let store: JwkMemStore = JwkMemStore::new();
+
+    let err = store
+      .generate(JwkMemStore::ML_DSA_KEY_TYPE, JwsAlgorithm::ML_DSA_44_Ed25519)
+      .await
+      .unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::KeyAlgorithmMismatch));
+
+    let err = store
+      .generate(JwkMemStore::SLH_DSA_KEY_TYPE, JwsAlgorithm::EdDSA)
+      .await
+      .unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::KeyAlgorithmMismatch));
+  }
+
+  #[cfg(feature = "memstore-nist-ec")]
+  #[tokio::test]
+  async fn sign_with_mismatching_curve_fails() {
+    let store: JwkMemStore = JwkMemStore::new();
+
+    let JwkGenOutput { key_id, jwk } = store
+      .generate(JwkMemStore::P384_KEY_TYPE, JwsAlgorithm::ES384)
+      .await
+      .unwrap();
+    let mut public_key: Jwk = jwk.to_public().unwrap();
+    public_key.set_alg(JwsAlgorithm::ES512.name());
+
+    let err = store.sign(&key_id, b"test", &public_key).await.unwrap_err();
+    assert!(matches!(err.kind(), KeyStorageErrorKind::Unspecified));
+  }
+
   #[tokio::test]
   async fn insert() {
     let store: JwkMemStore = JwkMemStore::new();
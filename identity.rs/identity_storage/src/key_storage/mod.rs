@@ -11,10 +11,14 @@
 pub mod bls;
 #[cfg(any(feature = "memstore", feature = "keytool"))]
 mod ed25519;
+#[cfg(feature = "memstore-ed448")]
+mod ed448;
 mod jwk_gen_output;
 mod jwk_storage;
 #[cfg(feature = "jpt-bbs-plus")]
 mod jwk_storage_bbs_plus_ext;
+#[cfg(feature = "mdoc")]
+mod jwk_storage_mdoc_ext;
 mod key_id;
 mod key_storage_error;
 mod key_type;
@@ -22,10 +26,18 @@ mod key_type;
 mod keytool;
 #[cfg(feature = "memstore")]
 mod memstore;
+#[cfg(feature = "memstore-pqc")]
+mod pqc;
+#[cfg(feature = "memstore-rsa")]
+mod rsa_keys;
 #[cfg(feature = "keytool")]
 mod secp256k1;
 #[cfg(feature = "keytool")]
 mod secp256r1;
+#[cfg(feature = "memstore-nist-ec")]
+mod secp384r1;
+#[cfg(feature = "memstore-nist-ec")]
+mod secp521r1;
 
 #[cfg(test)]
 pub(crate) mod tests;
@@ -36,6 +48,8 @@ pub mod public_modules {
   pub use super::jwk_storage::*;
   #[cfg(feature = "jpt-bbs-plus")]
   pub use super::jwk_storage_bbs_plus_ext::*;
+  #[cfg(feature = "mdoc")]
+  pub use super::jwk_storage_mdoc_ext::*;
   pub use super::key_id::*;
   pub use super::key_storage_error::*;
   pub use super::key_type::*;
@@ -0,0 +1,219 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::ed25519::Ed25519Signature;
+use fastcrypto::traits::KeyPair as _;
+use fastcrypto::traits::Signer;
+use fastcrypto::traits::ToFromBytes as _;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkParamsAkp;
+use identity_verification::jose::jws::HybridSignatureAlgorithm;
+use identity_verification::jose::jws::JwsAlgorithm;
+use identity_verification::jose::jwu;
+use ml_dsa::KeyGen;
+use ml_dsa::MlDsa44;
+use ml_dsa::MlDsa65;
+use ml_dsa::MlDsa87;
+use ml_dsa::MlDsaParams;
+use ml_dsa::B32;
+use rand::RngCore as _;
+use slh_dsa::ParameterSet;
+
+use crate::key_storage::KeyStorageError;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::key_storage::KeyStorageResult;
+
+/// Length in bytes of the seed from which ML-DSA key pairs are derived.
+const ML_DSA_SEED_LEN: usize = 32;
+/// Length in bytes of an Ed25519 private key.
+const ED25519_PRIVATE_KEY_LEN: usize = 32;
+
+/// Calls `$f::<P>($args)` where `P` is the SLH-DSA parameter set corresponding to `$alg`.
+macro_rules! with_slh_dsa_params {
+  ($alg:expr, $f:ident($($args:expr),*)) => {
+    match $alg {
+      JwsAlgorithm::SLH_DSA_SHA2_128s => $f::<slh_dsa::Sha2_128s>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHAKE_128s => $f::<slh_dsa::Shake128s>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHA2_128f => $f::<slh_dsa::Sha2_128f>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHAKE_128f => $f::<slh_dsa::Shake128f>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHA2_192s => $f::<slh_dsa::Sha2_192s>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHAKE_192s => $f::<slh_dsa::Shake192s>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHA2_192f => $f::<slh_dsa::Sha2_192f>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHAKE_192f => $f::<slh_dsa::Shake192f>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHA2_256s => $f::<slh_dsa::Sha2_256s>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHAKE_256s => $f::<slh_dsa::Shake256s>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHA2_256f => $f::<slh_dsa::Sha2_256f>($($args),*),
+      JwsAlgorithm::SLH_DSA_SHAKE_256f => $f::<slh_dsa::Shake256f>($($args),*),
+      other => Err(unsupported_alg(other)),
+    }
+  };
+}
+
+/// Generates a new AKP private key to be used with the post-quantum or hybrid algorithm `alg`.
+pub(crate) fn generate_jwk(alg: &JwsAlgorithm) -> KeyStorageResult<Jwk> {
+  let (public, private) = if alg.is_ml_dsa() {
+    let seed: [u8; ML_DSA_SEED_LEN] = random_seed();
+    (ml_dsa_public_key(alg, &seed)?, seed.to_vec())
+  } else if alg.is_slh_dsa() {
+    with_slh_dsa_params!(alg, slh_dsa_generate())?
+  } else if let Some(hybrid) = HybridSignatureAlgorithm::from_jws_algorithm(alg) {
+    let seed: [u8; ML_DSA_SEED_LEN] = random_seed();
+    let ed25519_key_pair = Ed25519KeyPair::generate(&mut rand::thread_rng());
+    let ed25519_public_key: Vec<u8> = ed25519_key_pair.public().as_ref().to_vec();
+
+    let public = hybrid.combine(&ml_dsa_public_key(hybrid.pq_algorithm(), &seed)?, &ed25519_public_key);
+    let private = hybrid.combine(&seed, ed25519_key_pair.private().as_ref());
+    (public, private)
+  } else {
+    return Err(unsupported_alg(alg));
+  };
+
+  let mut jwk = Jwk::from_params(JwkParamsAkp {
+    public: jwu::encode_b64(public),
+    private: Some(jwu::encode_b64(private)),
+  });
+  jwk.set_alg(alg.name());
+
+  Ok(jwk)
+}
+
+/// Signs `data` with the AKP private key `jwk`, using the post-quantum or hybrid algorithm `alg`.
+pub(crate) fn sign(jwk: &Jwk, alg: &JwsAlgorithm, data: &[u8]) -> KeyStorageResult<Vec<u8>> {
+  let private_key: Vec<u8> = decode_private_key(jwk)?;
+
+  if alg.is_ml_dsa() {
+    ml_dsa_sign(alg, &private_key, data, &[])
+  } else if alg.is_slh_dsa() {
+    with_slh_dsa_params!(alg, slh_dsa_sign(&private_key, data))
+  } else if let Some(hybrid) = HybridSignatureAlgorithm::from_jws_algorithm(alg) {
+    if private_key.len() != ML_DSA_SEED_LEN + ED25519_PRIVATE_KEY_LEN {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message("invalid hybrid private key length"),
+      );
+    }
+    let (seed, ed25519_private_key) = private_key.split_at(ML_DSA_SEED_LEN);
+    let message: Vec<u8> = hybrid.message_representative(data);
+
+    let pq_signature = ml_dsa_sign(hybrid.pq_algorithm(), seed, &message, hybrid.label())?;
+    let ed25519_key_pair = Ed25519KeyPair::from_bytes(ed25519_private_key)
+      .map_err(|_| KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message("invalid key"))?;
+    let traditional_signature = Signer::<Ed25519Signature>::sign(&ed25519_key_pair, &message);
+
+    Ok(hybrid.combine(&pq_signature, traditional_signature.as_ref()))
+  } else {
+    Err(unsupported_alg(alg))
+  }
+}
+
+fn decode_private_key(jwk: &Jwk) -> KeyStorageResult<Vec<u8>> {
+  let params: &JwkParamsAkp = jwk.try_akp_params().map_err(|err| {
+    KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+      .with_custom_message("expected AKP parameters")
+      .with_source(err)
+  })?;
+
+  params
+    .private
+    .as_deref()
+    .map(jwu::decode_b64)
+    .ok_or_else(|| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("expected Jwk `priv` param to be present")
+    })?
+    .map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("unable to decode `priv` param")
+        .with_source(err)
+    })
+}
+
+fn random_seed() -> [u8; ML_DSA_SEED_LEN] {
+  let mut seed = [0; ML_DSA_SEED_LEN];
+  rand::thread_rng().fill_bytes(&mut seed);
+  seed
+}
+
+fn unsupported_alg(alg: &JwsAlgorithm) -> KeyStorageError {
+  KeyStorageError::new(KeyStorageErrorKind::UnsupportedSignatureAlgorithm)
+    .with_custom_message(format!("{alg} is not a post-quantum algorithm"))
+}
+
+// ===========================================================================
+// ML-DSA
+// ===========================================================================
+
+/// Derives the encoded ML-DSA public key for `alg` from `seed`.
+fn ml_dsa_public_key(alg: &JwsAlgorithm, seed: &[u8]) -> KeyStorageResult<Vec<u8>> {
+  fn public_key<P: MlDsaParams>(seed: &B32) -> Vec<u8> {
+    P::key_gen_internal(seed).verifying_key().encode().to_vec()
+  }
+
+  let seed: B32 = ml_dsa_seed(seed)?;
+  match alg {
+    JwsAlgorithm::ML_DSA_44 => Ok(public_key::<MlDsa44>(&seed)),
+    JwsAlgorithm::ML_DSA_65 => Ok(public_key::<MlDsa65>(&seed)),
+    JwsAlgorithm::ML_DSA_87 => Ok(public_key::<MlDsa87>(&seed)),
+    other => Err(unsupported_alg(other)),
+  }
+}
+
+/// Signs `data` with context string `ctx` using the ML-DSA key pair for `alg` derived from `seed`.
+fn ml_dsa_sign(alg: &JwsAlgorithm, seed: &[u8], data: &[u8], ctx: &[u8]) -> KeyStorageResult<Vec<u8>> {
+  fn sign<P: MlDsaParams>(seed: &B32, data: &[u8], ctx: &[u8]) -> KeyStorageResult<Vec<u8>> {
+    P::key_gen_internal(seed)
+      .signing_key()
+      .sign_deterministic(data, ctx)
+      .map(|signature| signature.encode().to_vec())
+      .map_err(|err| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message("ML-DSA signing failed")
+          .with_source(err)
+      })
+  }
+
+  let seed: B32 = ml_dsa_seed(seed)?;
+  match alg {
+    JwsAlgorithm::ML_DSA_44 => sign::<MlDsa44>(&seed, data, ctx),
+    JwsAlgorithm::ML_DSA_65 => sign::<MlDsa65>(&seed, data, ctx),
+    JwsAlgorithm::ML_DSA_87 => sign::<MlDsa87>(&seed, data, ctx),
+    other => Err(unsupported_alg(other)),
+  }
+}
+
+fn ml_dsa_seed(seed: &[u8]) -> KeyStorageResult<B32> {
+  B32::try_from(seed).map_err(|_| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_custom_message(format!("expected an ML-DSA seed of length {ML_DSA_SEED_LEN}"))
+  })
+}
+
+// ===========================================================================
+// SLH-DSA
+// ===========================================================================
+
+/// Generates a new SLH-DSA key pair, returning the encoded public and private keys.
+fn slh_dsa_generate<P: ParameterSet>() -> KeyStorageResult<(Vec<u8>, Vec<u8>)> {
+  let signing_key = slh_dsa::SigningKey::<P>::new(&mut rand::thread_rng());
+  Ok((
+    signing_key.verifying_key().to_bytes().to_vec(),
+    signing_key.to_bytes().to_vec(),
+  ))
+}
+
+fn slh_dsa_sign<P: ParameterSet>(private_key: &[u8], data: &[u8]) -> KeyStorageResult<Vec<u8>> {
+  let signing_key = slh_dsa::SigningKey::<P>::try_from(private_key).map_err(|err| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_custom_message("invalid SLH-DSA private key")
+      .with_source(err)
+  })?;
+
+  signing_key
+    .try_sign_with_context(data, &[], None)
+    .map(|signature| signature.to_bytes().to_vec())
+    .map_err(|err| {
+      KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+        .with_custom_message("SLH-DSA signing failed")
+        .with_source(err)
+    })
+}
@@ -0,0 +1,116 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jwk::Jwk;
+use identity_verification::jwk::JwkParamsRsa;
+use identity_verification::jws::JwsAlgorithm;
+use identity_verification::jwu;
+use rsa::pkcs1v15;
+use rsa::pss;
+use rsa::signature::RandomizedSigner as _;
+use rsa::signature::SignatureEncoding as _;
+use rsa::signature::Signer as _;
+use rsa::traits::PrivateKeyParts as _;
+use rsa::traits::PublicKeyParts as _;
+use rsa::BigUint;
+use rsa::RsaPrivateKey;
+use sha2::Sha256;
+use sha2::Sha384;
+use sha2::Sha512;
+
+use crate::key_storage::KeyStorageError;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::key_storage::KeyStorageResult;
+
+/// The size, in bits, of the RSA keys generated by the memstore.
+const RSA_KEY_SIZE: usize = 2048;
+
+/// Generates a new RSA private key, encoded as a JWK.
+pub(crate) fn generate_jwk() -> KeyStorageResult<Jwk> {
+  let private_key = RsaPrivateKey::new(&mut rand::thread_rng(), RSA_KEY_SIZE).map_err(|err| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_custom_message("failed to generate RSA key")
+      .with_source(err)
+  })?;
+
+  let encode = |value: &BigUint| jwu::encode_b64(value.to_bytes_be());
+  let [p, q] = [&private_key.primes()[0], &private_key.primes()[1]];
+  let params = JwkParamsRsa {
+    n: encode(private_key.n()),
+    e: encode(private_key.e()),
+    d: Some(encode(private_key.d())),
+    p: Some(encode(p)),
+    q: Some(encode(q)),
+    dp: private_key.dp().map(encode),
+    dq: private_key.dq().map(encode),
+    qi: private_key.crt_coefficient().as_ref().map(encode),
+    oth: None,
+  };
+
+  Ok(Jwk::from_params(params))
+}
+
+/// Signs `data` with the RSA private key `jwk`, using `alg`.
+pub(crate) fn sign(jwk: &Jwk, alg: &JwsAlgorithm, data: &[u8]) -> KeyStorageResult<Vec<u8>> {
+  let private_key = jwk_to_private_key(jwk)?;
+  let mut rng = rand::thread_rng();
+
+  let signature = match alg {
+    JwsAlgorithm::RS256 => pkcs1v15::SigningKey::<Sha256>::new(private_key).sign(data).to_vec(),
+    JwsAlgorithm::RS384 => pkcs1v15::SigningKey::<Sha384>::new(private_key).sign(data).to_vec(),
+    JwsAlgorithm::RS512 => pkcs1v15::SigningKey::<Sha512>::new(private_key).sign(data).to_vec(),
+    JwsAlgorithm::PS256 => pss::SigningKey::<Sha256>::new(private_key)
+      .sign_with_rng(&mut rng, data)
+      .to_vec(),
+    JwsAlgorithm::PS384 => pss::SigningKey::<Sha384>::new(private_key)
+      .sign_with_rng(&mut rng, data)
+      .to_vec(),
+    JwsAlgorithm::PS512 => pss::SigningKey::<Sha512>::new(private_key)
+      .sign_with_rng(&mut rng, data)
+      .to_vec(),
+    other => {
+      return Err(
+        KeyStorageError::new(KeyStorageErrorKind::UnsupportedSignatureAlgorithm)
+          .with_custom_message(format!("{other} is not an RSA algorithm")),
+      )
+    }
+  };
+
+  Ok(signature)
+}
+
+fn jwk_to_private_key(jwk: &Jwk) -> KeyStorageResult<RsaPrivateKey> {
+  let params: &JwkParamsRsa = jwk.try_rsa_params().map_err(|err| {
+    KeyStorageError::new(KeyStorageErrorKind::UnsupportedKeyType)
+      .with_custom_message("expected RSA parameters")
+      .with_source(err)
+  })?;
+
+  let decode = |name: &str, value: Option<&str>| -> KeyStorageResult<BigUint> {
+    value
+      .ok_or_else(|| {
+        KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+          .with_custom_message(format!("expected Jwk `{name}` param to be present"))
+      })
+      .and_then(|value| {
+        jwu::decode_b64(value).map_err(|err| {
+          KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+            .with_custom_message(format!("unable to decode `{name}` param"))
+            .with_source(err)
+        })
+      })
+      .map(|bytes| BigUint::from_bytes_be(&bytes))
+  };
+
+  let n = decode("n", Some(&params.n))?;
+  let e = decode("e", Some(&params.e))?;
+  let d = decode("d", params.d.as_deref())?;
+  let p = decode("p", params.p.as_deref())?;
+  let q = decode("q", params.q.as_deref())?;
+
+  RsaPrivateKey::from_components(n, e, d, vec![p, q]).map_err(|err| {
+    KeyStorageError::new(KeyStorageErrorKind::Unspecified)
+      .with_custom_message("invalid RSA private key")
+      .with_source(err)
+  })
+}
@@ -0,0 +1,32 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jwk::Jwk;
+use p521::ecdsa::signature::Signer as _;
+use p521::ecdsa::Signature;
+use p521::ecdsa::SigningKey;
+use p521::SecretKey;
+
+use crate::key_storage::KeyStorageError;
+use crate::key_storage::KeyStorageErrorKind;
+use crate::key_storage::KeyStorageResult;
+
+/// Generates a new P-521 private key, encoded as a JWK.
+pub(crate) fn generate_jwk() -> Jwk {
+  let sk = SecretKey::random(&mut rand::thread_rng());
+  serde_json::from_str(&sk.to_jwk_string()).expect("valid JWK encoded P-521 key")
+}
+
+/// Signs `data` with the P-521 private key `jwk`, using ECDSA with SHA-512.
+pub(crate) fn sign(jwk: &Jwk, data: &[u8]) -> KeyStorageResult<Vec<u8>> {
+  let invalid_key =
+    || KeyStorageError::new(KeyStorageErrorKind::Unspecified).with_custom_message("invalid P-521 private key");
+  let sk = serde_json::to_string(jwk)
+    .ok()
+    .and_then(|jwk_str| SecretKey::from_jwk_str(&jwk_str).ok())
+    .ok_or_else(invalid_key)?;
+  let signing_key = SigningKey::from_slice(&sk.to_bytes()).map_err(|_| invalid_key())?;
+  let signature: Signature = signing_key.sign(data);
+
+  Ok(signature.to_bytes().to_vec())
+}
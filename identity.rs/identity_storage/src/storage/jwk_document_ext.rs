@@ -16,13 +16,19 @@ use crate::key_storage::KeyType;
 
 use async_trait::async_trait;
 use identity_core::common::Object;
+use identity_core::convert::ToJson;
 use identity_credential::credential::Credential;
+#[cfg(feature = "cose")]
+use identity_credential::credential::Cwt;
 use identity_credential::credential::Jws;
 use identity_credential::credential::Jwt;
+use identity_credential::credential::CNF_CLAIM;
 use identity_credential::presentation::JwtPresentationOptions;
 use identity_credential::presentation::Presentation;
 use identity_did::DIDUrl;
 use identity_document::document::CoreDocument;
+#[cfg(feature = "cose")]
+use identity_verification::jose::cose;
 use identity_verification::jose::jws::CompactJwsEncoder;
 use identity_verification::jose::jws::CompactJwsEncodingOptions;
 use identity_verification::jose::jws::JwsAlgorithm;
@@ -103,6 +109,12 @@ pub trait JwkDocumentExt: private::Sealed {
   /// private key backed by the `storage` in accordance with the passed `options`.
   ///
   /// The `custom_claims` can be used to set additional claims on the resulting JWT.
+  ///
+  /// If the method identified by `fragment` holds a hybrid key, the JWT is secured with a hybrid
+  /// (traditional and post-quantum) signature. Set [`JwsSignatureOptions::hybrid()`] to make sure this is the case.
+  ///
+  /// The credential can be bound to a holder key with [`JwsSignatureOptions::key_binding()`], which sets the `cnf`
+  /// claim, overriding a `cnf` entry in `custom_claims`.
   async fn create_credential_jwt<K, I, T>(
     &self,
     credential: &Credential<T>,
@@ -135,6 +147,26 @@ pub trait JwkDocumentExt: private::Sealed {
     I: KeyIdStorage,
     T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
     CRED: ToOwned<Owned = CRED> + Serialize + DeserializeOwned + Clone + Sync;
+
+  /// Produces a CWT where the payload is produced from the given `credential`, i.e. a `COSE_Sign1` over the CWT
+  /// claims set corresponding to the JWT claims of [`JwkDocumentExt::create_credential_jwt`].
+  ///
+  /// The `kid` in the protected header is the UTF-8 encoded `id` of the method identified by `fragment` and the
+  /// signature will be produced by the corresponding private key backed by the `storage`.
+  ///
+  /// The `custom_claims` can be used to set additional claims on the resulting CWT.
+  #[cfg(feature = "cose")]
+  async fn create_credential_cwt<K, I, T>(
+    &self,
+    credential: &Credential<T>,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    custom_claims: Option<Object>,
+  ) -> StorageResult<Cwt>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync;
 }
 
 mod private {
@@ -362,6 +394,9 @@ impl JwkDocumentExt for CoreDocument {
       .unwrap_or("")
       .parse()
       .map_err(|_| Error::InvalidJwsAlgorithm)?;
+    if options.hybrid && !alg.is_hybrid() {
+      return Err(Error::InvalidJwsAlgorithm);
+    }
 
     // Create JWS header in accordance with options.
     let header: JwsHeader = {
@@ -463,6 +498,17 @@ impl JwkDocumentExt for CoreDocument {
       )));
     }
 
+    let custom_claims: Option<Object> = match &options.key_binding {
+      Some(key_binding) => {
+        let cnf = key_binding
+          .to_json_value()
+          .map_err(|err| Error::EncodingError(err.into()))?;
+        let mut claims: Object = custom_claims.unwrap_or_default();
+        claims.insert(CNF_CLAIM.to_owned(), cnf);
+        Some(claims)
+      }
+      None => custom_claims,
+    };
     let payload = credential
       .serialize_jwt(custom_claims)
       .map_err(Error::ClaimsSerializationError)?;
@@ -506,6 +552,60 @@ impl JwkDocumentExt for CoreDocument {
       .await
       .map(|jws| Jwt::new(jws.into()))
   }
+
+  #[cfg(feature = "cose")]
+  async fn create_credential_cwt<K, I, T>(
+    &self,
+    credential: &Credential<T>,
+    storage: &Storage<K, I>,
+    fragment: &str,
+    custom_claims: Option<Object>,
+  ) -> StorageResult<Cwt>
+  where
+    K: JwkStorage,
+    I: KeyIdStorage,
+    T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
+  {
+    // Obtain the method corresponding to the given fragment.
+    let method: &VerificationMethod = self.resolve_method(fragment, None).ok_or(Error::MethodNotFound)?;
+    let MethodData::PublicKeyJwk(ref jwk) = method.data() else {
+      return Err(Error::NotPublicKeyJwk);
+    };
+
+    // Extract the COSE algorithm.
+    let alg: JwsAlgorithm = jwk
+      .alg()
+      .unwrap_or("")
+      .parse()
+      .map_err(|_| Error::InvalidJwsAlgorithm)?;
+    let alg = cose::cose_algorithm(&alg).map_err(|_| Error::InvalidJwsAlgorithm)?;
+
+    let protected = cose::coset::HeaderBuilder::new()
+      .algorithm(alg)
+      .key_id(method.id().to_string().into_bytes())
+      .build();
+    let payload: Vec<u8> = credential
+      .serialize_cwt(custom_claims)
+      .map_err(Error::ClaimsSerializationError)?
+      .to_vec()
+      .map_err(|err| Error::EncodingError(err.into()))?;
+
+    // Get the key identifier corresponding to the given method from the KeyId storage.
+    let method_digest: MethodDigest = MethodDigest::new(method).map_err(Error::MethodDigestConstructionError)?;
+    let key_id = <I as KeyIdStorage>::get_key_id(storage.key_id_storage(), &method_digest)
+      .await
+      .map_err(Error::KeyIdStorageError)?;
+
+    let encoder =
+      cose::CoseSign1Encoder::new(protected, &payload, false).map_err(|err| Error::EncodingError(err.into()))?;
+    let signature = <K as JwkStorage>::sign(storage.key_storage(), &key_id, encoder.signing_input(), jwk)
+      .await
+      .map_err(Error::KeyStorageError)?;
+    encoder
+      .into_bytes(&signature)
+      .map(Cwt::new)
+      .map_err(|err| Error::EncodingError(err.into()))
+  }
 }
 
 /// Attempt to revert key generation. If this succeeds the original `source_error` is returned,
@@ -626,5 +726,24 @@ mod iota_document {
         .create_presentation_jwt(presentation, storage, fragment, options, jwt_options)
         .await
     }
+
+    #[cfg(feature = "cose")]
+    async fn create_credential_cwt<K, I, T>(
+      &self,
+      credential: &Credential<T>,
+      storage: &Storage<K, I>,
+      fragment: &str,
+      custom_claims: Option<Object>,
+    ) -> StorageResult<Cwt>
+    where
+      K: JwkStorage,
+      I: KeyIdStorage,
+      T: ToOwned<Owned = T> + Serialize + DeserializeOwned + Sync,
+    {
+      self
+        .core_document()
+        .create_credential_cwt(credential, storage, fragment, custom_claims)
+        .await
+    }
   }
 }
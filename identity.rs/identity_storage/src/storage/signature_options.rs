@@ -3,6 +3,7 @@
 
 use identity_core::common::Object;
 use identity_core::common::Url;
+use identity_credential::credential::KeyBinding;
 
 /// Options for creating a JSON Web Signature.
 #[non_exhaustive]
@@ -60,6 +61,22 @@ pub struct JwsSignatureOptions {
   /// Additional header parameters.
   #[serde(skip_serializing_if = "Option::is_none")]
   pub custom_header_parameters: Option<Object>,
+
+  /// Whether the JWS must be secured with a hybrid signature, combining a post-quantum and a
+  /// traditional algorithm.
+  ///
+  /// If set, signing fails unless the verification method holds a hybrid key, e.g. one generated
+  /// for `ML-DSA-44-Ed25519`.
+  pub hybrid: bool,
+
+  /// The key to bind a credential to, embedded as the `cnf` claim of the JWT.
+  ///
+  /// Only taken into account when creating credential JWTs, see
+  /// [`JwkDocumentExt::create_credential_jwt`](crate::JwkDocumentExt::create_credential_jwt).
+  ///
+  /// [More Info](https://www.rfc-editor.org/rfc/rfc7800#section-3.1)
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub key_binding: Option<KeyBinding>,
 }
 
 impl JwsSignatureOptions {
@@ -121,4 +138,16 @@ impl JwsSignatureOptions {
     self.custom_header_parameters = Some(value);
     self
   }
+
+  /// Replace the value of the `hybrid` field.
+  pub fn hybrid(mut self, value: bool) -> Self {
+    self.hybrid = value;
+    self
+  }
+
+  /// Replace the value of the `key_binding` field.
+  pub fn key_binding(mut self, value: KeyBinding) -> Self {
+    self.key_binding = Some(value);
+    self
+  }
 }
@@ -299,3 +299,83 @@ async fn custom_header_parameters() {
     "test-value".to_owned()
   );
 }
+
+#[cfg(feature = "memstore-pqc")]
+#[tokio::test]
+async fn hybrid_signature_can_be_required() {
+  let (mut document, storage, ed25519_fragment, credential) = setup().await;
+  let hybrid_fragment: String = document
+    .generate_method(
+      &storage,
+      JwkMemStore::ML_DSA_ED25519_KEY_TYPE,
+      JwsAlgorithm::ML_DSA_44_Ed25519,
+      None,
+      MethodScope::assertion_method(),
+    )
+    .await
+    .unwrap();
+
+  // Requesting a hybrid signature from a non-hybrid method fails.
+  let err = document
+    .create_credential_jwt(
+      &credential,
+      &storage,
+      ed25519_fragment.as_ref(),
+      &JwsSignatureOptions::default().hybrid(true),
+      None,
+    )
+    .await
+    .unwrap_err();
+  assert!(matches!(
+    err,
+    crate::storage::JwkStorageDocumentError::InvalidJwsAlgorithm
+  ));
+
+  let hybrid_jws = document
+    .create_credential_jwt(
+      &credential,
+      &storage,
+      hybrid_fragment.as_ref(),
+      &JwsSignatureOptions::default().hybrid(true),
+      None,
+    )
+    .await
+    .unwrap();
+  let ed25519_jws = document
+    .create_credential_jwt(
+      &credential,
+      &storage,
+      ed25519_fragment.as_ref(),
+      &JwsSignatureOptions::default(),
+      None,
+    )
+    .await
+    .unwrap();
+
+  let options = JwtCredentialValidationOptions::default()
+    .verification_options(JwsVerificationOptions::default().require_hybrid(true));
+
+  let validator = identity_credential::validator::JwtCredentialValidator::with_signature_verifier(
+    identity_pqc_verifier::PqcJwsVerifier::default(),
+  );
+  assert!(validator
+    .validate::<_, Object>(
+      &hybrid_jws,
+      &document,
+      &options,
+      identity_credential::validator::FailFast::FirstError,
+    )
+    .is_ok());
+
+  // A valid non-hybrid signature is rejected when a hybrid one is required.
+  let validator =
+    identity_credential::validator::JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default());
+  assert!(validator
+    .validate::<_, Object>(
+      &ed25519_jws,
+      &document,
+      &options,
+      identity_credential::validator::FailFast::FirstError,
+    )
+    .is_err());
+}
@@ -0,0 +1,222 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Object;
+use identity_credential::credential::Jwt;
+use identity_credential::credential::KeyBinding;
+use identity_credential::presentation::JwtPresentationOptions;
+use identity_credential::presentation::Presentation;
+use identity_credential::presentation::PresentationBuilder;
+use identity_credential::validator::CompoundJwtPresentationValidationError;
+use identity_credential::validator::DecodedJwtPresentation;
+use identity_credential::validator::FailFast;
+use identity_credential::validator::JwtCredentialValidationOptions;
+use identity_credential::validator::JwtCredentialValidator;
+use identity_credential::validator::JwtCredentialValidatorUtils;
+use identity_credential::validator::JwtPresentationValidationOptions;
+use identity_credential::validator::JwtPresentationValidator;
+use identity_credential::validator::JwtValidationError;
+use identity_did::DID;
+use identity_document::document::CoreDocument;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jwk::Jwk;
+
+use crate::storage::tests::test_utils::create_verification_method;
+use crate::storage::tests::test_utils::generate_credential;
+use crate::storage::tests::test_utils::setup_coredocument;
+use crate::storage::tests::test_utils::Setup;
+use crate::JwkDocumentExt;
+use crate::JwsSignatureOptions;
+
+fn holder_jwk(setup: &Setup<CoreDocument, CoreDocument>) -> Jwk {
+  setup
+    .subject_doc
+    .resolve_method(setup.subject_method_fragment.as_str(), None)
+    .unwrap()
+    .data()
+    .try_public_key_jwk()
+    .unwrap()
+    .clone()
+}
+
+fn holder_kid(setup: &Setup<CoreDocument, CoreDocument>) -> String {
+  setup
+    .subject_doc
+    .resolve_method(setup.subject_method_fragment.as_str(), None)
+    .unwrap()
+    .id()
+    .to_string()
+}
+
+async fn issue_bound_credential(setup: &Setup<CoreDocument, CoreDocument>, key_binding: KeyBinding) -> Jwt {
+  let credential = generate_credential(&setup.issuer_doc, &[&setup.subject_doc], None, None).credential;
+  setup
+    .issuer_doc
+    .create_credential_jwt(
+      &credential,
+      &setup.issuer_storage,
+      &setup.issuer_method_fragment,
+      &JwsSignatureOptions::default().key_binding(key_binding),
+      None,
+    )
+    .await
+    .unwrap()
+}
+
+async fn present(setup: &Setup<CoreDocument, CoreDocument>, credential: Jwt, attach_jwk: bool) -> Jwt {
+  let presentation: Presentation<Jwt> = PresentationBuilder::new(setup.subject_doc.id().to_url().into(), Object::new())
+    .credential(credential)
+    .build()
+    .unwrap();
+
+  setup
+    .subject_doc
+    .create_presentation_jwt(
+      &presentation,
+      &setup.subject_storage,
+      &setup.subject_method_fragment,
+      &JwsSignatureOptions::default().attach_jwk_to_header(attach_jwk),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap()
+}
+
+fn validate_presentation(
+  presentation: &Jwt,
+  key_binding: &KeyBinding,
+  holder: Option<&CoreDocument>,
+) -> Result<DecodedJwtPresentation<Jwt>, CompoundJwtPresentationValidationError> {
+  JwtPresentationValidator::with_signature_verifier(EdDSAJwsVerifier::default()).validate_with_key_binding(
+    presentation,
+    key_binding,
+    holder,
+    &JwtPresentationValidationOptions::default(),
+  )
+}
+
+#[tokio::test]
+async fn test_key_bound_presentation() {
+  let setup = setup_coredocument(None, None).await;
+  let holder_jwk = holder_jwk(&setup);
+  let credential_jwt = issue_bound_credential(&setup, KeyBinding::from_jwk_thumbprint(&holder_jwk)).await;
+
+  // The binding is recovered from the validated credential.
+  let decoded_credential = JwtCredentialValidator::with_signature_verifier(EdDSAJwsVerifier::default())
+    .validate::<_, Object>(
+      &credential_jwt,
+      &setup.issuer_doc,
+      &JwtCredentialValidationOptions::default(),
+      FailFast::FirstError,
+    )
+    .unwrap();
+  let key_binding = JwtCredentialValidatorUtils::extract_key_binding(&decoded_credential)
+    .unwrap()
+    .unwrap();
+  assert_eq!(key_binding, KeyBinding::from_jwk_thumbprint(&holder_jwk));
+
+  let presentation_jwt = present(&setup, credential_jwt, false).await;
+  let decoded_presentation = validate_presentation(&presentation_jwt, &key_binding, Some(&setup.subject_doc)).unwrap();
+  assert_eq!(decoded_presentation.presentation.verifiable_credential.len(), 1);
+
+  // The bound key is resolved from the holder's document only.
+  let error = validate_presentation(&presentation_jwt, &key_binding, None).unwrap_err();
+  assert!(matches!(
+    error.presentation_validation_errors.as_slice(),
+    [JwtValidationError::MethodDataLookupError { .. }]
+  ));
+}
+
+#[tokio::test]
+async fn test_key_bound_presentation_with_embedded_jwk() {
+  let setup = setup_coredocument(None, None).await;
+  let key_binding = KeyBinding::from_jwk(&holder_jwk(&setup)).unwrap();
+  let credential_jwt = issue_bound_credential(&setup, key_binding.clone()).await;
+
+  // The key is taken from the binding, so the holder's document is not needed.
+  let presentation_jwt = present(&setup, credential_jwt, false).await;
+  assert!(validate_presentation(&presentation_jwt, &key_binding, None).is_ok());
+}
+
+#[tokio::test]
+async fn test_key_bound_presentation_requires_bound_key() {
+  let setup = setup_coredocument(None, None).await;
+  let other_jwk = create_verification_method()
+    .data()
+    .try_public_key_jwk()
+    .unwrap()
+    .clone();
+  let key_binding = KeyBinding::from_jwk_thumbprint(&other_jwk);
+  let credential_jwt = issue_bound_credential(&setup, key_binding.clone()).await;
+  let presentation_jwt = present(&setup, credential_jwt, true).await;
+
+  // The bound key is not a method of the holder.
+  let error = validate_presentation(&presentation_jwt, &key_binding, Some(&setup.subject_doc)).unwrap_err();
+  assert!(matches!(
+    error.presentation_validation_errors.as_slice(),
+    [JwtValidationError::KeyBindingMismatch]
+  ));
+
+  // A presentation signed by another key fails signature verification against an embedded key.
+  let key_binding = KeyBinding::from_jwk(&other_jwk).unwrap();
+  let error = validate_presentation(&presentation_jwt, &key_binding, None).unwrap_err();
+  assert!(matches!(
+    error.presentation_validation_errors.as_slice(),
+    [JwtValidationError::Signature { .. }]
+  ));
+}
+
+#[tokio::test]
+async fn test_kid_bound_presentation() {
+  let setup = setup_coredocument(None, None).await;
+  let key_binding = KeyBinding::Kid(holder_kid(&setup));
+  let credential_jwt = issue_bound_credential(&setup, key_binding.clone()).await;
+  let presentation_jwt = present(&setup, credential_jwt, false).await;
+
+  assert!(validate_presentation(&presentation_jwt, &key_binding, Some(&setup.subject_doc)).is_ok());
+
+  // The presentation must be issued by the holder whose document the key was resolved from.
+  let error = validate_presentation(&presentation_jwt, &key_binding, Some(&setup.issuer_doc)).unwrap_err();
+  assert!(matches!(
+    error.presentation_validation_errors.as_slice(),
+    [JwtValidationError::KeyBindingMismatch]
+  ));
+}
+
+#[tokio::test]
+async fn test_kid_bound_presentation_rejects_foreign_header_key() {
+  let setup = setup_coredocument(None, None).await;
+  let key_binding = KeyBinding::Kid(holder_kid(&setup));
+  let credential_jwt = issue_bound_credential(&setup, key_binding.clone()).await;
+
+  // Someone else presents the credential, signing with their own key, attaching it to the header and claiming the
+  // bound key identifier.
+  let presentation: Presentation<Jwt> = PresentationBuilder::new(setup.subject_doc.id().to_url().into(), Object::new())
+    .credential(credential_jwt)
+    .build()
+    .unwrap();
+  let presentation_jwt = setup
+    .issuer_doc
+    .create_presentation_jwt(
+      &presentation,
+      &setup.issuer_storage,
+      &setup.issuer_method_fragment,
+      &JwsSignatureOptions::default()
+        .attach_jwk_to_header(true)
+        .kid(holder_kid(&setup)),
+      &JwtPresentationOptions::default(),
+    )
+    .await
+    .unwrap();
+
+  let error = validate_presentation(&presentation_jwt, &key_binding, Some(&setup.subject_doc)).unwrap_err();
+  assert!(matches!(
+    error.presentation_validation_errors.as_slice(),
+    [JwtValidationError::Signature { .. }]
+  ));
+  let error = validate_presentation(&presentation_jwt, &key_binding, None).unwrap_err();
+  assert!(matches!(
+    error.presentation_validation_errors.as_slice(),
+    [JwtValidationError::MethodDataLookupError { .. }]
+  ));
+}
@@ -5,5 +5,6 @@ mod api;
 mod credential_jws;
 mod credential_validation;
 mod kb_jwt;
+mod key_binding;
 mod presentation_validation;
 pub(crate) mod test_utils;
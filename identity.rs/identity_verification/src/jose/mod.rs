@@ -4,6 +4,20 @@
 
 // Re-export necessary types from `identity_jose`.
 
+#[cfg(feature = "cose")]
+pub mod cose {
+  //! Reexport of [identity_jose::cose].
+
+  pub use identity_jose::cose::*;
+}
+
+#[cfg(feature = "cose")]
+pub mod cwt {
+  //! Reexport of [identity_jose::cwt].
+
+  pub use identity_jose::cwt::*;
+}
+
 pub mod jwk {
   //! Reexport of [identity_jose::jwk].
 
@@ -14,6 +14,7 @@ use serde_json::Value;
 
 use crate::error::Error;
 use crate::error::Result;
+use crate::verification_method::multikey;
 
 /// Supported verification method data formats.
 #[allow(clippy::large_enum_variant)]
@@ -45,6 +46,17 @@ impl MethodData {
     Self::PublicKeyMultibase(BaseEncoding::encode_multibase(&data, None))
   }
 
+  /// Creates a new [`MethodData::PublicKeyMultibase`] variant holding the public key of `jwk`, encoded as a
+  /// [Multikey](https://www.w3.org/TR/controller-document/#multikey).
+  ///
+  /// # Errors
+  /// Fails if `jwk` contains private key material or is not an `OKP` key on one of the
+  /// `Ed25519`, `X25519`, `Ed448` or `X448` curves. With feature `multikey-ec`, `EC` keys on the
+  /// `P-256`, `P-384` and `secp256k1` curves are supported as well.
+  pub fn try_multibase_from_jwk(jwk: &Jwk) -> Result<Self> {
+    multikey::encode(jwk).map(Self::new_multibase)
+  }
+
   /// Creates a new `MethodData` variant from custom data.
   pub fn new_custom(data: impl Into<CustomMethodData>) -> Self {
     Self::Custom(data.into())
@@ -83,6 +95,23 @@ impl MethodData {
     self.public_key_jwk().ok_or(Error::NotPublicKeyJwk)
   }
 
+  /// Returns the public key contained in this `MethodData` as a [`Jwk`].
+  ///
+  /// [`MethodData::PublicKeyMultibase`] values are expected to be [Multikeys](https://www.w3.org/TR/controller-document/#multikey),
+  /// see [`MethodData::try_multibase_from_jwk`].
+  ///
+  /// # Errors
+  /// Fails if this `MethodData` is neither a [`Jwk`] nor a supported multibase-encoded Multikey.
+  pub fn try_to_jwk(&self) -> Result<Jwk> {
+    match self {
+      Self::PublicKeyJwk(jwk) => Ok(jwk.clone()),
+      Self::PublicKeyMultibase(_) => self.try_decode().and_then(|multikey| multikey::decode(&multikey)),
+      Self::PublicKeyBase58(_) | Self::Custom(_) => Err(Error::InvalidMethodDataTransformation(
+        "method data is neither a JWK nor a multikey",
+      )),
+    }
+  }
+
   /// Returns the custom method data, if any.
   pub fn custom(&self) -> Option<&CustomMethodData> {
     if let Self::Custom(method_data) = self {
@@ -189,4 +218,20 @@ mod tests {
     });
     assert_eq!(custom, target_method_data);
   }
+
+  #[test]
+  fn jwk_multibase_roundtrip() {
+    let jwk: Jwk = serde_json::from_value(json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"
+    }))
+    .unwrap();
+
+    let method_data = MethodData::try_multibase_from_jwk(&jwk).unwrap();
+    assert!(matches!(method_data, MethodData::PublicKeyMultibase(ref multibase) if multibase.starts_with("z6Mk")));
+    assert_eq!(method_data.try_to_jwk().unwrap(), jwk);
+    assert_eq!(MethodData::PublicKeyJwk(jwk.clone()).try_to_jwk().unwrap(), jwk);
+    assert!(MethodData::new_base58([0; 32]).try_to_jwk().is_err());
+  }
 }
@@ -13,6 +13,7 @@ mod method_ref;
 mod method_relationship;
 mod method_scope;
 mod method_type;
+mod multikey;
 
 pub use self::builder::MethodBuilder;
 pub use self::material::CustomMethodData;
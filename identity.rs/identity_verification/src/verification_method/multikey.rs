@@ -0,0 +1,286 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Conversions between [`Jwk`]s and [Multikey](https://www.w3.org/TR/controller-document/#multikey) encoded
+//! public keys, i.e. raw public keys prefixed by the varint-encoded multicodec identifier of their key type.
+
+use crate::error::Error;
+use crate::error::Result;
+use crate::jose::jwk::Jwk;
+use crate::jose::jwk::JwkParams;
+#[cfg(feature = "multikey-ec")]
+use crate::jose::jwk::JwkParamsEc;
+use crate::jose::jwk::JwkParamsOkp;
+use crate::jose::jwu;
+
+/// Multicodec prefixes and public key lengths of the supported `OKP` curves.
+///
+/// See the [multicodec table](https://github.com/multiformats/multicodec/blob/master/table.csv).
+const OKP_CODECS: [(&str, &[u8], usize); 4] = [
+  // ed25519-pub (0xed)
+  ("Ed25519", &[0xed, 0x01], 32),
+  // x25519-pub (0xec)
+  ("X25519", &[0xec, 0x01], 32),
+  // ed448-pub (0x1203)
+  ("Ed448", &[0x83, 0x24], 57),
+  // x448-pub (0x1204)
+  ("X448", &[0x84, 0x24], 56),
+];
+
+/// Multicodec prefixes of the supported `EC` curves, whose public keys are encoded as compressed points.
+#[cfg(feature = "multikey-ec")]
+const EC_CODECS: [(&str, &[u8]); 3] = [
+  // p256-pub (0x1200)
+  ("P-256", &[0x80, 0x24]),
+  // p384-pub (0x1201)
+  ("P-384", &[0x81, 0x24]),
+  // secp256k1-pub (0xe7)
+  ("secp256k1", &[0xe7, 0x01]),
+];
+
+/// Encodes the public key contained in `jwk` as a multicodec-prefixed public key.
+pub(crate) fn encode(jwk: &Jwk) -> Result<Vec<u8>> {
+  if !jwk.is_public() {
+    return Err(Error::PrivateKeyMaterialExposed);
+  }
+  match jwk.params() {
+    JwkParams::Okp(params) => encode_okp(params),
+    #[cfg(feature = "multikey-ec")]
+    JwkParams::Ec(params) => encode_ec(params),
+    _ => Err(Error::InvalidMethodDataTransformation(
+      "unsupported key type for multikey encoding",
+    )),
+  }
+}
+
+fn encode_okp(params: &JwkParamsOkp) -> Result<Vec<u8>> {
+  let (_, prefix, key_len) = OKP_CODECS
+    .iter()
+    .find(|(crv, _, _)| *crv == params.crv)
+    .ok_or(Error::InvalidMethodDataTransformation("unsupported multikey curve"))?;
+
+  let public_key: Vec<u8> =
+    jwu::decode_b64(&params.x).map_err(|_| Error::InvalidMethodDataTransformation("invalid JWK `x` parameter"))?;
+  if public_key.len() != *key_len {
+    return Err(Error::InvalidMethodDataTransformation("invalid public key length"));
+  }
+
+  Ok([*prefix, public_key.as_slice()].concat())
+}
+
+/// Encodes an `EC` public key as a multicodec-prefixed compressed point.
+#[cfg(feature = "multikey-ec")]
+fn encode_ec(params: &JwkParamsEc) -> Result<Vec<u8>> {
+  let (_, prefix) = EC_CODECS
+    .iter()
+    .find(|(crv, _)| *crv == params.crv)
+    .ok_or(Error::InvalidMethodDataTransformation("unsupported multikey curve"))?;
+
+  let x: Vec<u8> =
+    jwu::decode_b64(&params.x).map_err(|_| Error::InvalidMethodDataTransformation("invalid JWK `x` parameter"))?;
+  let y: Vec<u8> =
+    jwu::decode_b64(&params.y).map_err(|_| Error::InvalidMethodDataTransformation("invalid JWK `y` parameter"))?;
+  if x.len() != y.len() {
+    return Err(Error::InvalidMethodDataTransformation("invalid public key length"));
+  }
+  let uncompressed: Vec<u8> = [&[0x04][..], &x, &y].concat();
+  let compressed: Vec<u8> =
+    ec::compress(&params.crv, &uncompressed).ok_or(Error::InvalidMethodDataTransformation("invalid EC public key"))?;
+
+  Ok([*prefix, compressed.as_slice()].concat())
+}
+
+/// Decodes a multicodec-prefixed public key into a public [`Jwk`].
+pub(crate) fn decode(multikey: &[u8]) -> Result<Jwk> {
+  #[cfg(feature = "multikey-ec")]
+  if let Some((crv, point)) = EC_CODECS
+    .iter()
+    .find_map(|(crv, prefix)| multikey.strip_prefix(*prefix).map(|point| (*crv, point)))
+  {
+    return decode_ec(crv, point);
+  }
+
+  let (crv, public_key) = OKP_CODECS
+    .iter()
+    .find_map(|(crv, prefix, key_len)| {
+      multikey
+        .strip_prefix(*prefix)
+        .filter(|public_key| public_key.len() == *key_len)
+        .map(|public_key| (*crv, public_key))
+    })
+    .ok_or(Error::InvalidMethodDataTransformation(
+      "unsupported or invalid multikey",
+    ))?;
+
+  let mut params = JwkParamsOkp::new();
+  params.crv = crv.to_owned();
+  params.x = jwu::encode_b64(public_key);
+
+  Ok(Jwk::from_params(params))
+}
+
+/// Decodes the compressed point of a key on `crv` into a public [`Jwk`].
+#[cfg(feature = "multikey-ec")]
+fn decode_ec(crv: &str, point: &[u8]) -> Result<Jwk> {
+  let uncompressed: Vec<u8> = ec::decompress(crv, point).ok_or(Error::InvalidMethodDataTransformation(
+    "unsupported or invalid multikey",
+  ))?;
+  let (x, y) = uncompressed[1..].split_at((uncompressed.len() - 1) / 2);
+
+  let mut params = JwkParamsEc::new();
+  params.crv = crv.to_owned();
+  params.x = jwu::encode_b64(x);
+  params.y = jwu::encode_b64(y);
+
+  Ok(Jwk::from_params(params))
+}
+
+/// Conversions between uncompressed and compressed SEC1 points, validating that the point lies on the curve.
+#[cfg(feature = "multikey-ec")]
+mod ec {
+  use k256::elliptic_curve::sec1::ToEncodedPoint;
+
+  /// Compresses the uncompressed SEC1 point `point` on `crv`.
+  pub(super) fn compress(crv: &str, point: &[u8]) -> Option<Vec<u8>> {
+    convert(crv, point, true)
+  }
+
+  /// Decompresses the compressed SEC1 point `point` on `crv`.
+  pub(super) fn decompress(crv: &str, point: &[u8]) -> Option<Vec<u8>> {
+    // Only compressed points are valid multikeys.
+    if !matches!(point.first(), Some(0x02 | 0x03)) {
+      return None;
+    }
+    convert(crv, point, false)
+  }
+
+  fn convert(crv: &str, point: &[u8], compress: bool) -> Option<Vec<u8>> {
+    let encoded = match crv {
+      "P-256" => p256::PublicKey::from_sec1_bytes(point)
+        .ok()?
+        .to_encoded_point(compress)
+        .as_bytes()
+        .to_vec(),
+      "P-384" => p384::PublicKey::from_sec1_bytes(point)
+        .ok()?
+        .to_encoded_point(compress)
+        .as_bytes()
+        .to_vec(),
+      "secp256k1" => k256::PublicKey::from_sec1_bytes(point)
+        .ok()?
+        .to_encoded_point(compress)
+        .as_bytes()
+        .to_vec(),
+      _ => return None,
+    };
+
+    Some(encoded)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use identity_core::convert::BaseEncoding;
+  use identity_core::convert::FromJson;
+
+  use super::*;
+
+  #[test]
+  fn ed25519_multikey_roundtrip() {
+    // Example taken from https://www.w3.org/TR/vc-di-eddsa/#example-an-ed25519-public-key-encoded-as-a-multikey
+    let multibase = "z6MkrJVnaZkeFzdQyMZu1cgjg7k1pZZ6pvBQ7XJPt4swbTQ2";
+    let multikey: Vec<u8> = BaseEncoding::decode_multibase(multibase).unwrap();
+
+    let jwk: Jwk = decode(&multikey).unwrap();
+    assert_eq!(jwk.try_okp_params().unwrap().crv, "Ed25519");
+    assert_eq!(encode(&jwk).unwrap(), multikey);
+  }
+
+  /// Multikeys and the coordinates of the same public keys, computed with OpenSSL.
+  #[cfg(feature = "multikey-ec")]
+  const EC_VECTORS: [(&str, &str, &str, &str); 3] = [
+    (
+      "P-256",
+      "zDnaegQT58hkZsLibupcgbGQ11PnxvDwYMVLEuZhpD4eiUpxp",
+      "7VeEp1OR3EOtzULbxMk46AaQx1s_QwkEnVB2aS-Nr-k",
+      "ftXos9lN1B9nfQ4l9upbMySVu9t0kj6rvp59LB0JoIo",
+    ),
+    (
+      "P-384",
+      "z82Lm5917WwUaQK7MXAB3fjFjKrLUTd3qAvKe68QfaoeMBRzr8TSgeUdjgWHFcfqmtQnv2c",
+      "-4-LKNsZNmFyUWyNk7amynH-5yseAF5oJQyBBymsIMEteHuBlP2phofOJ-fQWjQp",
+      "-7D7gQnf9nS0rdbXFEu52HwMB2JHIN6a4pOYom75FfQEl4OOH4gvToez09g03ndN",
+    ),
+    (
+      "secp256k1",
+      "zQ3shiHBMVqmStfKQw8rRoatXmACDd7aVk9SBvZ5PPkoVfUWa",
+      "NhJUgVEHq1X5T4Jeqfg9SRVRvPqheQRxyW-OzL7uda8",
+      "RTlWzx6oCu8xB780V-hN735k4OBmA0ifSx9YBhUHaMs",
+    ),
+  ];
+
+  #[cfg(feature = "multikey-ec")]
+  #[test]
+  fn ec_multikey_roundtrip() {
+    for (crv, multibase, x, y) in EC_VECTORS {
+      let multikey: Vec<u8> = BaseEncoding::decode_multibase(multibase).unwrap();
+
+      let jwk: Jwk = decode(&multikey).unwrap();
+      let params = jwk.try_ec_params().unwrap();
+      assert_eq!(params.crv, crv);
+      assert_eq!(params.x, x);
+      assert_eq!(params.y, y);
+
+      assert_eq!(encode(&jwk).unwrap(), multikey);
+    }
+  }
+
+  #[cfg(feature = "multikey-ec")]
+  #[test]
+  fn invalid_ec_points_are_rejected() {
+    let (_, multibase, x, _) = EC_VECTORS[0];
+
+    // There is no point on P-256 whose x coordinate is 1.
+    let mut not_on_curve: Vec<u8> = vec![0x80, 0x24, 0x02];
+    not_on_curve.extend_from_slice(&[0; 31]);
+    not_on_curve.push(1);
+    assert!(decode(&not_on_curve).is_err());
+
+    // Uncompressed points are not valid multikeys.
+    let multikey: Vec<u8> = BaseEncoding::decode_multibase(multibase).unwrap();
+    let uncompressed: Vec<u8> = [&[0x80, 0x24, 0x04][..], &multikey[3..], &multikey[3..]].concat();
+    assert!(decode(&uncompressed).is_err());
+
+    let off_curve = Jwk::from_json_value(serde_json::json!({
+      "kty": "EC",
+      "crv": "P-256",
+      "x": x,
+      "y": x
+    }))
+    .unwrap();
+    assert!(encode(&off_curve).is_err());
+  }
+
+  #[test]
+  fn unsupported_keys_are_rejected() {
+    let ec_jwk = Jwk::from_json_value(serde_json::json!({
+      "kty": "EC",
+      "crv": "P-521",
+      "x": "AHKZLLOsCOzz5cY97ewNUajB957y-C-U88c3v13nmGZx6sYl_oJXu9A5RkTKqjqvjyekWF-7ytDyRXYgCF5cj0Kt",
+      "y": "AdymlHvOiLxXkEhayXQnNCvDX4h9htZaCJN34kfmC6pV5OhQHiraVySsUdaQkAgDPrwQrJmbnX9cwlGfP-HqHZR1"
+    }))
+    .unwrap();
+    assert!(encode(&ec_jwk).is_err());
+
+    let private_jwk = Jwk::from_json_value(serde_json::json!({
+      "kty": "OKP",
+      "crv": "Ed25519",
+      "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo",
+      "d": "nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"
+    }))
+    .unwrap();
+    assert!(matches!(encode(&private_jwk), Err(Error::PrivateKeyMaterialExposed)));
+
+    assert!(decode(&[0xed, 0x01, 0x00]).is_err());
+  }
+}
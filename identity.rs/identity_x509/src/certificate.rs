@@ -0,0 +1,90 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::convert::Base;
+use identity_core::convert::BaseEncoding;
+use sha1::Sha1;
+use sha2::Digest as _;
+use sha2::Sha256;
+use x509_parser::certificate::X509Certificate;
+use x509_parser::prelude::FromDer as _;
+
+use crate::Error;
+use crate::Result;
+
+/// A chain of DER-encoded X.509 certificates, leaf certificate first, in which every certificate is issued by
+/// its successor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificateChain {
+  certificates: Vec<Vec<u8>>,
+}
+
+impl CertificateChain {
+  /// Creates a new [`CertificateChain`] from a list of DER-encoded certificates, leaf certificate first.
+  ///
+  /// # Errors
+  /// Fails if `certificates` is empty or contains a certificate that cannot be decoded.
+  pub fn new(certificates: Vec<Vec<u8>>) -> Result<Self> {
+    if certificates.is_empty() {
+      return Err(Error::EmptyChain);
+    }
+    for certificate in certificates.iter() {
+      parse_certificate(certificate)?;
+    }
+
+    Ok(Self { certificates })
+  }
+
+  /// Creates a new [`CertificateChain`] from the value of a JWS or JWK `x5c` parameter, i.e. a list of
+  /// base64-encoded DER certificates.
+  pub fn from_x5c(x5c: &[impl AsRef<str>]) -> Result<Self> {
+    x5c
+      .iter()
+      .map(|certificate| {
+        BaseEncoding::decode(certificate.as_ref(), Base::Base64Pad)
+          .map_err(|err| Error::InvalidEncoding(format!("invalid x5c entry: {err}")))
+      })
+      .collect::<Result<Vec<Vec<u8>>>>()
+      .and_then(Self::new)
+  }
+
+  /// Returns the DER encoding of the leaf certificate.
+  pub fn leaf(&self) -> &[u8] {
+    &self.certificates[0]
+  }
+
+  /// Returns the DER-encoded certificates of the chain, leaf certificate first.
+  pub fn certificates(&self) -> &[Vec<u8>] {
+    &self.certificates
+  }
+
+  /// Returns the chain encoded as the value of an `x5c` parameter.
+  pub fn to_x5c(&self) -> Vec<String> {
+    self
+      .certificates
+      .iter()
+      .map(|certificate| BaseEncoding::encode(certificate, Base::Base64Pad))
+      .collect()
+  }
+
+  /// Returns the base64url-encoded SHA-1 thumbprint of the leaf certificate, i.e. the expected value
+  /// of the `x5t` header parameter.
+  pub fn leaf_thumbprint_sha1_b64(&self) -> String {
+    identity_verification::jwu::encode_b64(Sha1::digest(self.leaf()))
+  }
+
+  /// Returns the base64url-encoded SHA-256 thumbprint of the leaf certificate, i.e. the expected value
+  /// of the `x5t#S256` header parameter.
+  pub fn leaf_thumbprint_sha256_b64(&self) -> String {
+    identity_verification::jwu::encode_b64(Sha256::digest(self.leaf()))
+  }
+}
+
+/// Parses a DER-encoded certificate, rejecting trailing data.
+pub(crate) fn parse_certificate(der: &[u8]) -> Result<X509Certificate<'_>> {
+  match X509Certificate::from_der(der) {
+    Ok((rest, certificate)) if rest.is_empty() => Ok(certificate),
+    Ok(_) => Err(Error::InvalidEncoding("trailing data after certificate".to_owned())),
+    Err(err) => Err(Error::InvalidEncoding(err.to_string())),
+  }
+}
@@ -0,0 +1,338 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Timestamp;
+use identity_verification::jose::jwk::Jwk;
+use x509_parser::certificate::X509Certificate;
+use x509_parser::prelude::FromDer as _;
+use x509_parser::revocation_list::CertificateRevocationList;
+use x509_parser::time::ASN1Time;
+
+use crate::certificate::parse_certificate;
+use crate::jwk::spki_to_jwk;
+use crate::name_constraints::check_name_constraints;
+use crate::CertificateChain;
+use crate::Error;
+use crate::Result;
+
+/// Extensions whose semantics are understood, and enforced where applicable, by [`X509ChainValidator`]. Any other
+/// extension marked as critical causes a chain to be rejected.
+const SUPPORTED_CRITICAL_EXTENSIONS: &[&str] = &[
+  OID_BASIC_CONSTRAINTS,
+  OID_KEY_USAGE,
+  OID_EXTENDED_KEY_USAGE,
+  OID_NAME_CONSTRAINTS,
+  OID_SUBJECT_ALT_NAME,
+  OID_SUBJECT_KEY_IDENTIFIER,
+  OID_AUTHORITY_KEY_IDENTIFIER,
+];
+const OID_BASIC_CONSTRAINTS: &str = "2.5.29.19";
+const OID_KEY_USAGE: &str = "2.5.29.15";
+const OID_EXTENDED_KEY_USAGE: &str = "2.5.29.37";
+const OID_NAME_CONSTRAINTS: &str = "2.5.29.30";
+const OID_SUBJECT_ALT_NAME: &str = "2.5.29.17";
+const OID_SUBJECT_KEY_IDENTIFIER: &str = "2.5.29.14";
+const OID_AUTHORITY_KEY_IDENTIFIER: &str = "2.5.29.35";
+
+const OID_KP_SERVER_AUTH: &str = "1.3.6.1.5.5.7.3.1";
+const OID_KP_CLIENT_AUTH: &str = "1.3.6.1.5.5.7.3.2";
+const OID_KP_CODE_SIGNING: &str = "1.3.6.1.5.5.7.3.3";
+const OID_KP_EMAIL_PROTECTION: &str = "1.3.6.1.5.5.7.3.4";
+const OID_KP_TIME_STAMPING: &str = "1.3.6.1.5.5.7.3.8";
+const OID_KP_OCSP_SIGNING: &str = "1.3.6.1.5.5.7.3.9";
+
+/// Validates X.509 certificate chains against a set of trust anchors, optionally checking
+/// certificate revocation lists (CRLs).
+///
+/// A chain is accepted if:
+/// - every certificate was issued, and signed, by its successor,
+/// - every issuing certificate is a certificate authority allowed to sign certificates and its path length constraint
+///   is respected,
+/// - every certificate is valid at the validation time,
+/// - the last certificate is either a trust anchor or was issued by one,
+/// - no certificate is revoked by one of the configured CRLs,
+/// - no certificate has a critical extension that is not understood,
+/// - the names of every certificate respect the name constraints of the certificate authorities above it,
+/// - every certificate with an extended key usage extension allows one of the accepted extended key usages,
+/// - the leaf certificate may be used for digital signatures.
+#[derive(Debug, Clone, Default)]
+pub struct X509ChainValidator {
+  trust_anchors: Vec<Vec<u8>>,
+  crls: Vec<Vec<u8>>,
+  extended_key_usages: Vec<String>,
+  validation_time: Option<Timestamp>,
+  require_revocation_info: bool,
+}
+
+impl X509ChainValidator {
+  /// Creates a new [`X509ChainValidator`] trusting the given DER-encoded certificates.
+  ///
+  /// # Errors
+  /// Fails if one of the trust anchors cannot be decoded.
+  pub fn new(trust_anchors: impl IntoIterator<Item = Vec<u8>>) -> Result<Self> {
+    trust_anchors
+      .into_iter()
+      .try_fold(Self::default(), |validator, anchor| validator.with_trust_anchor(anchor))
+  }
+
+  /// Adds a DER-encoded trust anchor.
+  pub fn with_trust_anchor(mut self, trust_anchor: Vec<u8>) -> Result<Self> {
+    parse_certificate(&trust_anchor)?;
+    self.trust_anchors.push(trust_anchor);
+    Ok(self)
+  }
+
+  /// Adds a DER-encoded certificate revocation list.
+  ///
+  /// CRLs are matched to certificates by issuer name and their signature is verified
+  /// when the chain is validated.
+  pub fn with_crl(mut self, crl: Vec<u8>) -> Result<Self> {
+    parse_crl(&crl)?;
+    self.crls.push(crl);
+    Ok(self)
+  }
+
+  /// Validates chains at `validation_time` instead of the current time.
+  pub fn at_time(mut self, validation_time: Timestamp) -> Self {
+    self.validation_time = Some(validation_time);
+    self
+  }
+
+  /// Whether every certificate of the chain, except the trust anchor, must be covered by a CRL.
+  ///
+  /// Default: `false`.
+  pub fn require_revocation_info(mut self, value: bool) -> Self {
+    self.require_revocation_info = value;
+    self
+  }
+
+  /// Accepts certificates whose extended key usage extension contains `oid`, e.g. `1.0.18013.5.1.2` for
+  /// ISO/IEC 18013-5 mDL document signers.
+  ///
+  /// Certificates without extended key usage extension, or allowing any extended key usage, are always accepted.
+  pub fn with_extended_key_usage(mut self, oid: impl Into<String>) -> Self {
+    self.extended_key_usages.push(oid.into());
+    self
+  }
+
+  /// Returns the DER-encoded trust anchors.
+  pub fn trust_anchors(&self) -> &[Vec<u8>] {
+    &self.trust_anchors
+  }
+
+  /// Validates `chain` and returns the public key of its leaf certificate.
+  pub fn validate(&self, chain: &CertificateChain) -> Result<Jwk> {
+    let time = self.validation_time.unwrap_or_else(Timestamp::now_utc);
+    let time = ASN1Time::from_timestamp(time.to_unix()).map_err(|err| Error::InvalidEncoding(err.to_string()))?;
+
+    let certificates = chain
+      .certificates()
+      .iter()
+      .map(|certificate| parse_certificate(certificate))
+      .collect::<Result<Vec<_>>>()?;
+    let anchors = self
+      .trust_anchors
+      .iter()
+      .map(|anchor| parse_certificate(anchor))
+      .collect::<Result<Vec<_>>>()?;
+
+    for (position, certificate) in certificates.iter().enumerate() {
+      if !certificate.validity().is_valid_at(time) {
+        return Err(Error::CertificateNotValid(position));
+      }
+      check_critical_extensions(certificate, position)?;
+      self.check_extended_key_usage(certificate, position)?;
+    }
+
+    // Each certificate must be issued by its successor.
+    for (position, pair) in certificates.windows(2).enumerate() {
+      check_issued_by(&pair[0], &pair[1], position)?;
+      // `position` intermediate certificates lie between the issuer and the leaf.
+      check_path_len(&pair[1], position, position + 1)?;
+    }
+
+    // The last certificate must be a trust anchor or be issued by one.
+    let last_position = certificates.len() - 1;
+    let last = &certificates[last_position];
+    let last_der = &chain.certificates()[last_position];
+    let is_anchor = self.trust_anchors.iter().any(|anchor| anchor == last_der);
+    let anchor = if is_anchor {
+      None
+    } else {
+      let anchor = anchors
+        .iter()
+        .find(|anchor| {
+          anchor.subject().as_raw() == last.issuer().as_raw()
+            && last.verify_signature(Some(anchor.public_key())).is_ok()
+        })
+        .ok_or(Error::UntrustedChain)?;
+      if !anchor.validity().is_valid_at(time) {
+        return Err(Error::UntrustedChain);
+      }
+      check_ca(anchor, last_position + 1)?;
+      check_path_len(anchor, last_position, last_position + 1)?;
+      Some(anchor)
+    };
+
+    // Name constraints of a certificate authority apply to all certificates below it.
+    for (issuer_position, issuer) in certificates
+      .iter()
+      .enumerate()
+      .skip(1)
+      .chain(anchor.map(|anchor| (certificates.len(), anchor)))
+    {
+      for (position, certificate) in certificates[..issuer_position].iter().enumerate() {
+        check_name_constraints(issuer, certificate, position)?;
+      }
+    }
+
+    // Trust anchors are not subject to revocation checks.
+    let checked = if is_anchor { last_position } else { certificates.len() };
+    for position in 0..checked {
+      let issuer = certificates.get(position + 1).or(anchor).ok_or(Error::UntrustedChain)?;
+      self.check_revocation(&certificates[position], issuer, position, time)?;
+    }
+
+    let leaf = &certificates[0];
+    if let Some(key_usage) = leaf
+      .key_usage()
+      .map_err(|err| Error::InvalidEncoding(err.to_string()))?
+    {
+      if !key_usage.value.digital_signature() {
+        return Err(Error::InvalidKeyUsage);
+      }
+    }
+
+    spki_to_jwk(leaf.public_key())
+  }
+
+  /// Decodes the value of an `x5c` parameter and validates the resulting chain, returning
+  /// the public key of its leaf certificate.
+  pub fn validate_x5c(&self, x5c: &[impl AsRef<str>]) -> Result<Jwk> {
+    self.validate(&CertificateChain::from_x5c(x5c)?)
+  }
+
+  fn check_extended_key_usage(&self, certificate: &X509Certificate<'_>, position: usize) -> Result<()> {
+    let Some(extended_key_usage) = certificate
+      .extended_key_usage()
+      .map_err(|err| Error::InvalidEncoding(err.to_string()))?
+    else {
+      return Ok(());
+    };
+    let extended_key_usage = extended_key_usage.value;
+    if extended_key_usage.any {
+      return Ok(());
+    }
+
+    let known = [
+      (extended_key_usage.server_auth, OID_KP_SERVER_AUTH),
+      (extended_key_usage.client_auth, OID_KP_CLIENT_AUTH),
+      (extended_key_usage.code_signing, OID_KP_CODE_SIGNING),
+      (extended_key_usage.email_protection, OID_KP_EMAIL_PROTECTION),
+      (extended_key_usage.time_stamping, OID_KP_TIME_STAMPING),
+      (extended_key_usage.ocsp_signing, OID_KP_OCSP_SIGNING),
+    ]
+    .into_iter()
+    .filter_map(|(allowed, oid)| allowed.then(|| oid.to_owned()));
+    let other = extended_key_usage.other.iter().map(|oid| oid.to_id_string());
+
+    if known.chain(other).any(|oid| self.extended_key_usages.contains(&oid)) {
+      Ok(())
+    } else {
+      Err(Error::InvalidExtendedKeyUsage(position))
+    }
+  }
+
+  fn check_revocation(
+    &self,
+    certificate: &X509Certificate<'_>,
+    issuer: &X509Certificate<'_>,
+    position: usize,
+    time: ASN1Time,
+  ) -> Result<()> {
+    let mut covered = false;
+    for crl in self.crls.iter() {
+      let crl = parse_crl(crl)?;
+      if crl.issuer().as_raw() != certificate.issuer().as_raw() {
+        continue;
+      }
+      crl
+        .verify_signature(issuer.public_key())
+        .map_err(|_| Error::InvalidCrl("signature verification failed"))?;
+      if crl.next_update().is_some_and(|next_update| next_update < time) {
+        return Err(Error::InvalidCrl("outdated"));
+      }
+      if crl
+        .iter_revoked_certificates()
+        .any(|revoked| revoked.raw_serial() == certificate.raw_serial())
+      {
+        return Err(Error::Revoked(position));
+      }
+      covered = true;
+    }
+
+    if self.require_revocation_info && !covered {
+      return Err(Error::MissingRevocationInfo(position));
+    }
+
+    Ok(())
+  }
+}
+
+fn check_critical_extensions(certificate: &X509Certificate<'_>, position: usize) -> Result<()> {
+  let unsupported = certificate.extensions().iter().any(|extension| {
+    extension.critical && !SUPPORTED_CRITICAL_EXTENSIONS.contains(&extension.oid.to_id_string().as_str())
+  });
+  if unsupported {
+    Err(Error::UnsupportedCriticalExtension(position))
+  } else {
+    Ok(())
+  }
+}
+
+fn check_issued_by(certificate: &X509Certificate<'_>, issuer: &X509Certificate<'_>, position: usize) -> Result<()> {
+  if certificate.issuer().as_raw() != issuer.subject().as_raw() {
+    return Err(Error::IssuerMismatch(position));
+  }
+  check_ca(issuer, position + 1)?;
+  certificate
+    .verify_signature(Some(issuer.public_key()))
+    .map_err(|_| Error::InvalidSignature(position))
+}
+
+fn check_ca(certificate: &X509Certificate<'_>, position: usize) -> Result<()> {
+  let is_ca = certificate
+    .basic_constraints()
+    .map_err(|err| Error::InvalidEncoding(err.to_string()))?
+    .is_some_and(|constraints| constraints.value.ca);
+  let can_sign_certificates = certificate
+    .key_usage()
+    .map_err(|err| Error::InvalidEncoding(err.to_string()))?
+    .map_or(true, |key_usage| key_usage.value.key_cert_sign());
+
+  if is_ca && can_sign_certificates {
+    Ok(())
+  } else {
+    Err(Error::NotCertificateAuthority(position))
+  }
+}
+
+/// Checks that `certificate` allows `path_len` intermediate certificates to follow it.
+fn check_path_len(certificate: &X509Certificate<'_>, path_len: usize, position: usize) -> Result<()> {
+  let max_path_len = certificate
+    .basic_constraints()
+    .map_err(|err| Error::InvalidEncoding(err.to_string()))?
+    .and_then(|constraints| constraints.value.path_len_constraint);
+  match max_path_len {
+    Some(max_path_len) if path_len > max_path_len as usize => Err(Error::PathLengthExceeded(position)),
+    _ => Ok(()),
+  }
+}
+
+fn parse_crl(der: &[u8]) -> Result<CertificateRevocationList<'_>> {
+  match CertificateRevocationList::from_der(der) {
+    Ok((rest, crl)) if rest.is_empty() => Ok(crl),
+    Ok(_) => Err(Error::InvalidEncoding("trailing data after CRL".to_owned())),
+    Err(err) => Err(Error::InvalidEncoding(err.to_string())),
+  }
+}
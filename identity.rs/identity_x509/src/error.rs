@@ -0,0 +1,71 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Alias for a [`Result`][::core::result::Result] with the error type [`Error`].
+pub type Result<T, E = Error> = ::core::result::Result<T, E>;
+
+/// Errors that can occur when validating X.509 certificate chains.
+#[derive(Debug, thiserror::Error, strum::IntoStaticStr)]
+#[non_exhaustive]
+pub enum Error {
+  /// The certificate chain is empty.
+  #[error("empty certificate chain")]
+  EmptyChain,
+  /// A certificate or CRL could not be decoded.
+  #[error("invalid encoding: {0}")]
+  InvalidEncoding(String),
+  /// A certificate is expired or not yet valid.
+  #[error("certificate at position {0} of the chain is expired or not yet valid")]
+  CertificateNotValid(usize),
+  /// A certificate was not issued by the next certificate in the chain.
+  #[error("certificate at position {0} of the chain was not issued by its successor")]
+  IssuerMismatch(usize),
+  /// A certificate's signature could not be verified.
+  #[error("invalid signature on certificate at position {0} of the chain")]
+  InvalidSignature(usize),
+  /// A certificate that issued another certificate is not a certificate authority allowed to sign certificates.
+  #[error("certificate at position {0} of the chain is not a certificate authority")]
+  NotCertificateAuthority(usize),
+  /// A certificate authority's path length constraint is exceeded.
+  #[error("path length constraint of the certificate at position {0} of the chain is exceeded")]
+  PathLengthExceeded(usize),
+  /// A certificate has a critical extension that is not understood.
+  #[error("certificate at position {0} of the chain has an unsupported critical extension")]
+  UnsupportedCriticalExtension(usize),
+  /// A certificate's names violate the name constraints of a certificate authority above it.
+  #[error("certificate at position {0} of the chain violates a name constraint")]
+  NameConstraintViolation(usize),
+  /// A certificate authority constrains a name form that cannot be checked.
+  #[error("unsupported name constraint")]
+  UnsupportedNameConstraint,
+  /// A certificate's extended key usage doesn't allow any of the accepted usages.
+  #[error("extended key usage of the certificate at position {0} of the chain is not accepted")]
+  InvalidExtendedKeyUsage(usize),
+  /// The leaf certificate is not allowed to be used to verify signatures.
+  #[error("the leaf certificate is not allowed to be used for digital signatures")]
+  InvalidKeyUsage,
+  /// The chain doesn't lead to any of the trust anchors.
+  #[error("the certificate chain doesn't lead to a trusted certificate")]
+  UntrustedChain,
+  /// A certificate of the chain is revoked.
+  #[error("certificate at position {0} of the chain is revoked")]
+  Revoked(usize),
+  /// No valid CRL was provided for a certificate of the chain, while one is required.
+  #[error("missing revocation information for certificate at position {0} of the chain")]
+  MissingRevocationInfo(usize),
+  /// A CRL is invalid, e.g. its signature cannot be verified or it is outdated.
+  #[error("invalid certificate revocation list: {0}")]
+  InvalidCrl(&'static str),
+  /// The key of the leaf certificate cannot be represented as a JWK.
+  #[error("unsupported public key: {0}")]
+  UnsupportedKey(&'static str),
+  /// The leaf certificate doesn't belong to the expected issuer.
+  #[error("the leaf certificate doesn't identify issuer \"{0}\"")]
+  IssuerBindingMismatch(String),
+  /// A certificate thumbprint in the JWS header doesn't match the leaf certificate.
+  #[error("the `{0}` header parameter doesn't match the leaf certificate")]
+  ThumbprintMismatch(&'static str),
+  /// The JWS could not be decoded or its signature is invalid.
+  #[error("JWS verification failed")]
+  Jws(#[source] identity_verification::jose::error::Error),
+}
@@ -0,0 +1,61 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use identity_credential::validator::IssuerKeyResolutionError;
+use identity_credential::validator::X5cChainValidator;
+use identity_verification::jose::jwk::Jwk;
+use x509_parser::extensions::GeneralName;
+
+use crate::certificate::parse_certificate;
+use crate::CertificateChain;
+use crate::Error;
+use crate::Result;
+use crate::X509ChainValidator;
+
+impl X509ChainValidator {
+  /// Validates `chain` and checks that its leaf certificate identifies `issuer`, returning the leaf's
+  /// public key.
+  ///
+  /// The leaf certificate identifies `issuer` if one of its subject alternative names is either a URI equal to
+  /// `issuer` or a DNS name equal to the host of `issuer`.
+  pub fn validate_for_issuer(&self, issuer: &str, chain: &CertificateChain) -> Result<Jwk> {
+    let public_key = self.validate(chain)?;
+    if !identifies_issuer(chain.leaf(), issuer)? {
+      return Err(Error::IssuerBindingMismatch(issuer.to_owned()));
+    }
+
+    Ok(public_key)
+  }
+}
+
+impl X5cChainValidator for X509ChainValidator {
+  fn validate_chain(&self, issuer: &str, x5c: &[String]) -> Result<Jwk, IssuerKeyResolutionError> {
+    CertificateChain::from_x5c(x5c)
+      .and_then(|chain| self.validate_for_issuer(issuer, &chain))
+      .map_err(|err| IssuerKeyResolutionError::InvalidCertificateChain(anyhow::Error::new(err)))
+  }
+}
+
+fn identifies_issuer(leaf: &[u8], issuer: &str) -> Result<bool> {
+  let leaf = parse_certificate(leaf)?;
+  let Some(san) = leaf
+    .subject_alternative_name()
+    .map_err(|err| Error::InvalidEncoding(err.to_string()))?
+  else {
+    return Ok(false);
+  };
+  let issuer_host = Url::parse(issuer)
+    .ok()
+    .and_then(|url| url.host_str().map(str::to_owned));
+
+  Ok(san.value.general_names.iter().any(|name| {
+    match name {
+      GeneralName::URI(uri) => *uri == issuer,
+      GeneralName::DNSName(dns_name) => issuer_host
+        .as_deref()
+        .is_some_and(|host| host.eq_ignore_ascii_case(dns_name)),
+      _ => false,
+    }
+  }))
+}
@@ -0,0 +1,100 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jose::jwk::EcCurve;
+use identity_verification::jose::jwk::EdCurve;
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jwk::JwkParamsEc;
+use identity_verification::jose::jwk::JwkParamsOkp;
+use identity_verification::jose::jwk::JwkParamsRsa;
+use identity_verification::jwu;
+use x509_parser::public_key::PublicKey;
+use x509_parser::x509::SubjectPublicKeyInfo;
+
+use crate::Error;
+use crate::Result;
+
+const OID_ED25519: &str = "1.3.101.112";
+const OID_ED448: &str = "1.3.101.113";
+const OID_EC_PUBLIC_KEY: &str = "1.2.840.10045.2.1";
+const OID_RSA_ENCRYPTION: &str = "1.2.840.113549.1.1.1";
+const OID_P256: &str = "1.2.840.10045.3.1.7";
+const OID_P384: &str = "1.3.132.0.34";
+const OID_P521: &str = "1.3.132.0.35";
+
+/// Converts the DER-encoded `SubjectPublicKeyInfo` of a certificate into a public [`Jwk`].
+///
+/// Ed25519, Ed448, P-256, P-384, P-521 and RSA keys are supported.
+pub(crate) fn spki_to_jwk(spki: &SubjectPublicKeyInfo<'_>) -> Result<Jwk> {
+  match spki.algorithm.algorithm.to_id_string().as_str() {
+    OID_ED25519 => okp_to_jwk(EdCurve::Ed25519, 32, spki.subject_public_key.data.as_ref()),
+    OID_ED448 => okp_to_jwk(EdCurve::Ed448, 57, spki.subject_public_key.data.as_ref()),
+    OID_EC_PUBLIC_KEY => {
+      let curve = spki
+        .algorithm
+        .parameters
+        .as_ref()
+        .and_then(|parameters| parameters.as_oid().ok())
+        .ok_or(Error::UnsupportedKey("missing elliptic curve parameters"))?;
+      let (curve, coordinate_len) = match curve.to_id_string().as_str() {
+        OID_P256 => (EcCurve::P256, 32),
+        OID_P384 => (EcCurve::P384, 48),
+        OID_P521 => (EcCurve::P521, 66),
+        _ => return Err(Error::UnsupportedKey("unsupported elliptic curve")),
+      };
+
+      // Only uncompressed points (0x04 || x || y) can be represented as a JWK.
+      let point: &[u8] = spki.subject_public_key.data.as_ref();
+      if point.len() != 1 + 2 * coordinate_len || point[0] != 0x04 {
+        return Err(Error::UnsupportedKey("invalid or compressed elliptic curve point"));
+      }
+      let (x, y) = point[1..].split_at(coordinate_len);
+      let params = JwkParamsEc {
+        crv: curve.name().to_owned(),
+        x: jwu::encode_b64(x),
+        y: jwu::encode_b64(y),
+        d: None,
+      };
+      Ok(Jwk::from_params(params))
+    }
+    OID_RSA_ENCRYPTION => {
+      let Ok(PublicKey::RSA(key)) = spki.parsed() else {
+        return Err(Error::UnsupportedKey("invalid RSA public key"));
+      };
+      let params = JwkParamsRsa {
+        n: jwu::encode_b64(strip_leading_zeros(key.modulus)),
+        e: jwu::encode_b64(strip_leading_zeros(key.exponent)),
+        d: None,
+        p: None,
+        q: None,
+        dp: None,
+        dq: None,
+        qi: None,
+        oth: None,
+      };
+      Ok(Jwk::from_params(params))
+    }
+    _ => Err(Error::UnsupportedKey("unsupported key algorithm")),
+  }
+}
+
+fn okp_to_jwk(curve: EdCurve, key_len: usize, public_key: &[u8]) -> Result<Jwk> {
+  if public_key.len() != key_len {
+    return Err(Error::UnsupportedKey("invalid Edwards curve public key"));
+  }
+  let params = JwkParamsOkp {
+    crv: curve.name().to_owned(),
+    x: jwu::encode_b64(public_key),
+    d: None,
+  };
+  Ok(Jwk::from_params(params))
+}
+
+/// DER integers are signed, JWK integers are unsigned big-endian octet sequences without leading zeros.
+fn strip_leading_zeros(bytes: &[u8]) -> &[u8] {
+  let start = bytes
+    .iter()
+    .position(|byte| *byte != 0)
+    .unwrap_or(bytes.len().saturating_sub(1));
+  &bytes[start..]
+}
@@ -0,0 +1,52 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_verification::jose::jwk::Jwk;
+use identity_verification::jose::jws::DecodedJws;
+use identity_verification::jose::jws::Decoder;
+use identity_verification::jose::jws::JwsHeader;
+use identity_verification::jose::jws::JwsVerifier;
+
+use crate::CertificateChain;
+use crate::Error;
+use crate::Result;
+use crate::X509ChainValidator;
+
+impl X509ChainValidator {
+  /// Decodes a JWS in compact serialization and verifies its signature using the key of the leaf certificate of
+  /// the `x5c` chain found in its protected header, after validating that chain.
+  ///
+  /// The `x5t` and `x5t#S256` header parameters, when present, must match the leaf certificate.
+  pub fn verify_jws<'a, V>(&self, jws: &'a str, verifier: &V) -> Result<DecodedJws<'a>>
+  where
+    V: JwsVerifier,
+  {
+    let validation_item = Decoder::new()
+      .decode_compact_serialization(jws.as_bytes(), None)
+      .map_err(Error::Jws)?;
+    let header = validation_item.protected_header().ok_or(Error::Jws(
+      identity_verification::jose::error::Error::MissingHeader("missing protected header"),
+    ))?;
+    let public_key = x5c_header_key(header, self)?;
+
+    validation_item.verify(verifier, &public_key).map_err(Error::Jws)
+  }
+}
+
+/// Validates the `x5c` certificate chain of a JWS header and returns the public key of its leaf certificate.
+fn x5c_header_key(header: &JwsHeader, validator: &X509ChainValidator) -> Result<Jwk> {
+  let x5c = header.x5c().ok_or(Error::EmptyChain)?;
+  let chain = CertificateChain::from_x5c(x5c)?;
+
+  if header.x5t().is_some_and(|x5t| x5t != chain.leaf_thumbprint_sha1_b64()) {
+    return Err(Error::ThumbprintMismatch("x5t"));
+  }
+  if header
+    .x5t_s256()
+    .is_some_and(|x5t_s256| x5t_s256 != chain.leaf_thumbprint_sha256_b64())
+  {
+    return Err(Error::ThumbprintMismatch("x5t#S256"));
+  }
+
+  validator.validate(&chain)
+}
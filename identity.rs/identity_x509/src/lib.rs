@@ -0,0 +1,30 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#![doc = include_str!("./../README.md")]
+#![warn(
+  rust_2018_idioms,
+  unreachable_pub,
+  missing_docs,
+  rustdoc::missing_crate_level_docs,
+  rustdoc::broken_intra_doc_links,
+  rustdoc::private_intra_doc_links,
+  rustdoc::private_doc_tests,
+  clippy::missing_safety_doc
+)]
+
+mod certificate;
+mod chain_validator;
+mod error;
+#[cfg(feature = "sd-jwt-vc")]
+mod issuer_key;
+mod jwk;
+mod jws;
+mod name_constraints;
+
+pub use certificate::*;
+pub use chain_validator::*;
+pub use error::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,151 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use identity_core::common::Url;
+use x509_parser::certificate::X509Certificate;
+use x509_parser::extensions::GeneralName;
+use x509_parser::extensions::GeneralSubtree;
+use x509_parser::extensions::NameConstraints;
+use x509_parser::x509::X509Name;
+
+use crate::Error;
+use crate::Result;
+
+/// Checks the names of `certificate`, found at `position` in the chain, against the name constraints of
+/// `issuer`, i.e. of a certificate authority above it in the chain, as described in RFC 5280, section 4.2.1.10.
+///
+/// DNS names, URIs, email addresses, IP addresses and directory names are supported. Constraints on any
+/// other name form are rejected.
+pub(crate) fn check_name_constraints(
+  issuer: &X509Certificate<'_>,
+  certificate: &X509Certificate<'_>,
+  position: usize,
+) -> Result<()> {
+  let Some(constraints) = issuer
+    .name_constraints()
+    .map_err(|err| Error::InvalidEncoding(err.to_string()))?
+  else {
+    return Ok(());
+  };
+  let constraints: &NameConstraints<'_> = constraints.value;
+  let permitted: &[GeneralSubtree<'_>] = constraints.permitted_subtrees.as_deref().unwrap_or_default();
+  let excluded: &[GeneralSubtree<'_>] = constraints.excluded_subtrees.as_deref().unwrap_or_default();
+
+  let subject = GeneralName::DirectoryName(certificate.subject().clone());
+  let alternative_names = certificate
+    .subject_alternative_name()
+    .map_err(|err| Error::InvalidEncoding(err.to_string()))?
+    .map(|san| san.value.general_names.as_slice())
+    .unwrap_or_default();
+  // An empty subject is not subject to directory name constraints.
+  let subject_names = certificate.subject().iter().next().is_some().then_some(&subject);
+
+  for name in subject_names.into_iter().chain(alternative_names) {
+    // Names must lie within one of the permitted subtrees of the same name form, if there are any.
+    let mut constrained = false;
+    let mut permitted_match = false;
+    for subtree in permitted {
+      if let Some(matched) = matches(name, &subtree.base)? {
+        constrained = true;
+        permitted_match |= matched;
+      }
+    }
+    if constrained && !permitted_match {
+      return Err(Error::NameConstraintViolation(position));
+    }
+
+    for subtree in excluded {
+      if matches(name, &subtree.base)?.unwrap_or(false) {
+        return Err(Error::NameConstraintViolation(position));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Whether `name` lies within the subtree `base`, or `None` if they are different name forms.
+fn matches(name: &GeneralName<'_>, base: &GeneralName<'_>) -> Result<Option<bool>> {
+  let matched = match (name, base) {
+    (GeneralName::DNSName(name), GeneralName::DNSName(base)) => dns_name_matches(name, base),
+    (GeneralName::URI(uri), GeneralName::URI(base)) => uri_matches(uri, base),
+    (GeneralName::RFC822Name(email), GeneralName::RFC822Name(base)) => email_matches(email, base),
+    (GeneralName::IPAddress(address), GeneralName::IPAddress(base)) => ip_address_matches(address, base),
+    (GeneralName::DirectoryName(name), GeneralName::DirectoryName(base)) => directory_name_matches(name, base),
+    (
+      _,
+      GeneralName::DNSName(_)
+      | GeneralName::URI(_)
+      | GeneralName::RFC822Name(_)
+      | GeneralName::IPAddress(_)
+      | GeneralName::DirectoryName(_),
+    ) => return Ok(None),
+    _ => return Err(Error::UnsupportedNameConstraint),
+  };
+
+  Ok(Some(matched))
+}
+
+/// `base` matches the DNS name itself and all of its subdomains, a leading period only its subdomains.
+fn dns_name_matches(name: &str, base: &str) -> bool {
+  let name = name.to_ascii_lowercase();
+  let base = base.to_ascii_lowercase();
+  if base.is_empty() {
+    return true;
+  }
+  if base.starts_with('.') {
+    name.ends_with(&base)
+  } else {
+    name == base || name.ends_with(&format!(".{base}"))
+  }
+}
+
+/// `base` constrains the host of the URI: without leading period it must match exactly, with leading period it
+/// matches all subdomains.
+fn uri_matches(uri: &str, base: &str) -> bool {
+  let Some(host) = Url::parse(uri)
+    .ok()
+    .and_then(|url| url.host_str().map(str::to_ascii_lowercase))
+  else {
+    return false;
+  };
+  let base = base.to_ascii_lowercase();
+  if base.starts_with('.') {
+    host.ends_with(&base)
+  } else {
+    host == base
+  }
+}
+
+/// `base` is either a mailbox, a host or, with leading period, all subdomains of a host.
+fn email_matches(email: &str, base: &str) -> bool {
+  let Some((_, host)) = email.rsplit_once('@') else {
+    return false;
+  };
+  if base.contains('@') {
+    return email.eq_ignore_ascii_case(base);
+  }
+  let host = host.to_ascii_lowercase();
+  let base = base.to_ascii_lowercase();
+  if base.starts_with('.') {
+    host.ends_with(&base)
+  } else {
+    host == base
+  }
+}
+
+/// `base` is an IPv4 or IPv6 address followed by its subnet mask.
+fn ip_address_matches(address: &[u8], base: &[u8]) -> bool {
+  let (network, mask) = base.split_at(base.len() / 2);
+  base.len() == 2 * address.len()
+    && address
+      .iter()
+      .zip(network.iter().zip(mask))
+      .all(|(byte, (network, mask))| byte & mask == network & mask)
+}
+
+/// `base` matches all names starting with its relative distinguished names.
+fn directory_name_matches(name: &X509Name<'_>, base: &X509Name<'_>) -> bool {
+  let mut name = name.iter();
+  base.iter().all(|base_rdn| name.next() == Some(base_rdn))
+}
@@ -0,0 +1,165 @@
+# Copyright 2020-2025 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+# Generates the DER-encoded certificates and CRLs used by the tests of this crate, using pyca/cryptography >= 44.
+#
+# All keys are derived from fixed secrets and ECDSA signatures are deterministic (RFC 6979), so running this script
+# again reproduces the same files.
+#
+# Usage: python3 generate_fixtures.py
+
+import datetime
+import hashlib
+import os
+
+from cryptography import x509
+from cryptography.hazmat.primitives import hashes
+from cryptography.hazmat.primitives.asymmetric import ec
+from cryptography.hazmat.primitives.asymmetric import ed25519
+from cryptography.hazmat.primitives.serialization import Encoding
+from cryptography.x509.oid import NameOID
+
+OUT_DIR = os.path.dirname(os.path.abspath(__file__))
+
+NOT_BEFORE = datetime.datetime(2026, 1, 1, tzinfo=datetime.timezone.utc)
+NOT_AFTER = datetime.datetime(2126, 1, 1, tzinfo=datetime.timezone.utc)
+
+ROOT_KEY = ec.derive_private_key(
+    int.from_bytes(hashlib.sha256(b"identity test root ca").digest(), "big"), ec.SECP256R1()
+)
+INTERMEDIATE_KEY = ec.derive_private_key(
+    int.from_bytes(hashlib.sha256(b"identity test intermediate ca").digest(), "big"), ec.SECP256R1()
+)
+# Must match `LEAF_SEED` in `../mod.rs`.
+LEAF_KEY = ed25519.Ed25519PrivateKey.from_private_bytes(
+    bytes.fromhex("b7ab4c294f67497ca4c31e4b9efb1163d6aed76b2268543bde7ac3171fa450b3")
+)
+
+ROOT_NAME = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "Identity Test Root CA")])
+INTERMEDIATE_NAME = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "Identity Test Intermediate CA")])
+LEAF_NAME = x509.Name([x509.NameAttribute(NameOID.COMMON_NAME, "issuer.example.com")])
+
+# ISO/IEC 18013-5 mDL document signer.
+OID_MDL_DS = x509.ObjectIdentifier("1.0.18013.5.1.2")
+# An extension no validator knows about.
+OID_UNKNOWN_EXTENSION = x509.ObjectIdentifier("1.3.6.1.4.1.99999.1")
+
+CA_KEY_USAGE = x509.KeyUsage(False, False, False, False, False, True, True, False, False)
+LEAF_KEY_USAGE = x509.KeyUsage(True, False, False, False, False, False, False, False, False)
+
+
+def ca_certificate(serial, name, key, issuer_name, issuer_key, path_length, name_constraints=None):
+    builder = (
+        x509.CertificateBuilder()
+        .serial_number(serial)
+        .subject_name(name)
+        .issuer_name(issuer_name)
+        .public_key(key.public_key())
+        .not_valid_before(NOT_BEFORE)
+        .not_valid_after(NOT_AFTER)
+        .add_extension(x509.BasicConstraints(ca=True, path_length=path_length), critical=True)
+        .add_extension(CA_KEY_USAGE, critical=True)
+        .add_extension(x509.SubjectKeyIdentifier.from_public_key(key.public_key()), critical=False)
+    )
+    if issuer_key is not key:
+        builder = builder.add_extension(
+            x509.AuthorityKeyIdentifier.from_issuer_public_key(issuer_key.public_key()), critical=False
+        )
+    if name_constraints is not None:
+        builder = builder.add_extension(name_constraints, critical=True)
+    return builder.sign(issuer_key, hashes.SHA256(), ecdsa_deterministic=True)
+
+
+def leaf_certificate(serial, extra_extensions=()):
+    builder = (
+        x509.CertificateBuilder()
+        .serial_number(serial)
+        .subject_name(LEAF_NAME)
+        .issuer_name(INTERMEDIATE_NAME)
+        .public_key(LEAF_KEY.public_key())
+        .not_valid_before(NOT_BEFORE)
+        .not_valid_after(NOT_AFTER)
+        .add_extension(x509.BasicConstraints(ca=False, path_length=None), critical=True)
+        .add_extension(LEAF_KEY_USAGE, critical=True)
+        .add_extension(
+            x509.SubjectAlternativeName(
+                [
+                    x509.UniformResourceIdentifier("https://issuer.example.com"),
+                    x509.DNSName("issuer.example.com"),
+                ]
+            ),
+            critical=False,
+        )
+        .add_extension(x509.SubjectKeyIdentifier.from_public_key(LEAF_KEY.public_key()), critical=False)
+        .add_extension(
+            x509.AuthorityKeyIdentifier.from_issuer_public_key(INTERMEDIATE_KEY.public_key()), critical=False
+        )
+    )
+    for extension, critical in extra_extensions:
+        builder = builder.add_extension(extension, critical=critical)
+    return builder.sign(INTERMEDIATE_KEY, hashes.SHA256(), ecdsa_deterministic=True)
+
+
+def crl(number, revoked_serials):
+    builder = (
+        x509.CertificateRevocationListBuilder()
+        .issuer_name(INTERMEDIATE_NAME)
+        .last_update(NOT_BEFORE)
+        .next_update(NOT_AFTER)
+        .add_extension(
+            x509.AuthorityKeyIdentifier.from_issuer_public_key(INTERMEDIATE_KEY.public_key()), critical=False
+        )
+        .add_extension(x509.CRLNumber(number), critical=False)
+    )
+    for serial in revoked_serials:
+        builder = builder.add_revoked_certificate(
+            x509.RevokedCertificateBuilder().serial_number(serial).revocation_date(NOT_BEFORE).build()
+        )
+    return builder.sign(INTERMEDIATE_KEY, hashes.SHA256(), ecdsa_deterministic=True)
+
+
+def name_constraints(domain):
+    # DNS name constraints match subdomains, URI host constraints do so only with a leading period.
+    return x509.NameConstraints(
+        permitted_subtrees=[x509.DNSName(domain), x509.UniformResourceIdentifier(f".{domain}")],
+        excluded_subtrees=None,
+    )
+
+
+def main():
+    root = ca_certificate(1, ROOT_NAME, ROOT_KEY, ROOT_NAME, ROOT_KEY, None)
+    intermediate = ca_certificate(2, INTERMEDIATE_NAME, INTERMEDIATE_KEY, ROOT_NAME, ROOT_KEY, 0)
+    leaf = leaf_certificate(3)
+
+    # Alternative intermediates with the same name and key, restricting the names of the certificates they issue.
+    permitted_intermediate = ca_certificate(
+        4, INTERMEDIATE_NAME, INTERMEDIATE_KEY, ROOT_NAME, ROOT_KEY, 0, name_constraints("example.com")
+    )
+    forbidden_intermediate = ca_certificate(
+        5, INTERMEDIATE_NAME, INTERMEDIATE_KEY, ROOT_NAME, ROOT_KEY, 0, name_constraints("example.org")
+    )
+
+    # Alternative leaves for the same key.
+    critical_extension_leaf = leaf_certificate(
+        6, [(x509.UnrecognizedExtension(OID_UNKNOWN_EXTENSION, b"\x05\x00"), True)]
+    )
+    mdl_ds_leaf = leaf_certificate(7, [(x509.ExtendedKeyUsage([OID_MDL_DS]), True)])
+
+    fixtures = {
+        "root.der": root,
+        "int.der": intermediate,
+        "leaf.der": leaf,
+        "int_nc_permitted.der": permitted_intermediate,
+        "int_nc_forbidden.der": forbidden_intermediate,
+        "leaf_critical_extension.der": critical_extension_leaf,
+        "leaf_eku_mdl_ds.der": mdl_ds_leaf,
+        "int_empty.crl.der": crl(1, []),
+        "int_revoked.crl.der": crl(2, [leaf.serial_number]),
+    }
+    for file_name, fixture in fixtures.items():
+        with open(os.path.join(OUT_DIR, file_name), "wb") as file:
+            file.write(fixture.public_bytes(Encoding.DER))
+
+
+if __name__ == "__main__":
+    main()
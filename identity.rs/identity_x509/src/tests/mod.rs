@@ -0,0 +1,276 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::signatures::ed25519::SecretKey;
+use identity_core::common::Timestamp;
+use identity_eddsa_verifier::EdDSAJwsVerifier;
+use identity_verification::jose::jwk::JwkParams;
+use identity_verification::jwu;
+use x509_parser::prelude::FromDer as _;
+use x509_parser::x509::SubjectPublicKeyInfo;
+
+use crate::jwk::spki_to_jwk;
+use crate::CertificateChain;
+use crate::Error;
+use crate::X509ChainValidator;
+
+const ROOT: &[u8] = include_bytes!("fixtures/root.der");
+const INTERMEDIATE: &[u8] = include_bytes!("fixtures/int.der");
+const LEAF: &[u8] = include_bytes!("fixtures/leaf.der");
+const EMPTY_CRL: &[u8] = include_bytes!("fixtures/int_empty.crl.der");
+const REVOKED_LEAF_CRL: &[u8] = include_bytes!("fixtures/int_revoked.crl.der");
+const PERMITTED_NAMES_INTERMEDIATE: &[u8] = include_bytes!("fixtures/int_nc_permitted.der");
+const FORBIDDEN_NAMES_INTERMEDIATE: &[u8] = include_bytes!("fixtures/int_nc_forbidden.der");
+const CRITICAL_EXTENSION_LEAF: &[u8] = include_bytes!("fixtures/leaf_critical_extension.der");
+const MDL_DS_LEAF: &[u8] = include_bytes!("fixtures/leaf_eku_mdl_ds.der");
+const OID_MDL_DS: &str = "1.0.18013.5.1.2";
+const LEAF_SEED: &str = "b7ab4c294f67497ca4c31e4b9efb1163d6aed76b2268543bde7ac3171fa450b3";
+const ISSUER: &str = "https://issuer.example.com";
+
+fn validator() -> X509ChainValidator {
+  X509ChainValidator::new([ROOT.to_vec()])
+    .unwrap()
+    .at_time(Timestamp::parse("2030-01-01T00:00:00Z").unwrap())
+}
+
+fn chain() -> CertificateChain {
+  CertificateChain::new(vec![LEAF.to_vec(), INTERMEDIATE.to_vec()]).unwrap()
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+  (0..hex.len())
+    .step_by(2)
+    .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+    .collect()
+}
+
+fn leaf_secret_key() -> SecretKey {
+  SecretKey::from_bytes(&decode_hex(LEAF_SEED).try_into().unwrap())
+}
+
+fn sign_jws(header: serde_json::Value, payload: &[u8]) -> String {
+  let signing_input = format!(
+    "{}.{}",
+    jwu::encode_b64_json(&header).unwrap(),
+    jwu::encode_b64(payload)
+  );
+  let signature = leaf_secret_key().sign(signing_input.as_bytes()).to_bytes();
+  format!("{signing_input}.{}", jwu::encode_b64(signature))
+}
+
+#[test]
+fn valid_chain_yields_leaf_key() {
+  let jwk = validator().validate(&chain()).unwrap();
+  let JwkParams::Okp(params) = jwk.params() else {
+    panic!("expected an OKP key");
+  };
+  assert_eq!(params.crv, "Ed25519");
+  assert_eq!(
+    jwu::decode_b64(&params.x).unwrap(),
+    leaf_secret_key().public_key().to_bytes().to_vec()
+  );
+}
+
+#[test]
+fn chain_ending_with_trust_anchor_is_valid() {
+  let chain = CertificateChain::new(vec![LEAF.to_vec(), INTERMEDIATE.to_vec(), ROOT.to_vec()]).unwrap();
+  assert!(validator().validate(&chain).is_ok());
+}
+
+#[test]
+fn x5c_roundtrip() {
+  let chain = chain();
+  assert_eq!(CertificateChain::from_x5c(&chain.to_x5c()).unwrap(), chain);
+  assert!(validator().validate_x5c(&chain.to_x5c()).is_ok());
+}
+
+#[test]
+fn untrusted_chain_is_rejected() {
+  let validator = X509ChainValidator::new([INTERMEDIATE.to_vec()])
+    .unwrap()
+    .at_time(Timestamp::parse("2030-01-01T00:00:00Z").unwrap());
+  let chain = CertificateChain::new(vec![INTERMEDIATE.to_vec(), ROOT.to_vec()]).unwrap();
+  assert!(matches!(validator.validate(&chain), Err(Error::UntrustedChain)));
+
+  let no_anchors = X509ChainValidator::default().at_time(Timestamp::parse("2030-01-01T00:00:00Z").unwrap());
+  assert!(matches!(
+    no_anchors.validate(&self::chain()),
+    Err(Error::UntrustedChain)
+  ));
+}
+
+#[test]
+fn broken_chain_is_rejected() {
+  let chain = CertificateChain::new(vec![LEAF.to_vec(), ROOT.to_vec()]).unwrap();
+  assert!(matches!(validator().validate(&chain), Err(Error::IssuerMismatch(0))));
+}
+
+#[test]
+fn leaf_cannot_issue_certificates() {
+  let chain = CertificateChain::new(vec![INTERMEDIATE.to_vec(), LEAF.to_vec()]).unwrap();
+  assert!(validator().validate(&chain).is_err());
+}
+
+#[test]
+fn certificates_are_checked_against_validation_time() {
+  let validator = validator().at_time(Timestamp::parse("2025-01-01T00:00:00Z").unwrap());
+  assert!(matches!(
+    validator.validate(&chain()),
+    Err(Error::CertificateNotValid(0))
+  ));
+}
+
+#[test]
+fn revoked_leaf_is_rejected() {
+  let validator = validator().with_crl(REVOKED_LEAF_CRL.to_vec()).unwrap();
+  assert!(matches!(validator.validate(&chain()), Err(Error::Revoked(0))));
+}
+
+#[test]
+fn revocation_info_can_be_required() {
+  let validator = validator().require_revocation_info(true);
+  assert!(matches!(
+    validator.validate(&chain()),
+    Err(Error::MissingRevocationInfo(_))
+  ));
+
+  // The intermediate certificate is issued by the root, which publishes no CRL.
+  let validator = validator.with_crl(EMPTY_CRL.to_vec()).unwrap();
+  assert!(matches!(
+    validator.validate(&chain()),
+    Err(Error::MissingRevocationInfo(1))
+  ));
+}
+
+#[test]
+fn non_revoked_leaf_is_accepted() {
+  let validator = validator().with_crl(EMPTY_CRL.to_vec()).unwrap();
+  assert!(validator.validate(&chain()).is_ok());
+}
+
+#[test]
+fn unsupported_critical_extension_is_rejected() {
+  let chain = CertificateChain::new(vec![CRITICAL_EXTENSION_LEAF.to_vec(), INTERMEDIATE.to_vec()]).unwrap();
+  assert!(matches!(
+    validator().validate(&chain),
+    Err(Error::UnsupportedCriticalExtension(0))
+  ));
+}
+
+#[test]
+fn name_constraints_are_enforced() {
+  let chain = CertificateChain::new(vec![LEAF.to_vec(), PERMITTED_NAMES_INTERMEDIATE.to_vec()]).unwrap();
+  assert!(validator().validate(&chain).is_ok());
+
+  let chain = CertificateChain::new(vec![LEAF.to_vec(), FORBIDDEN_NAMES_INTERMEDIATE.to_vec()]).unwrap();
+  assert!(matches!(
+    validator().validate(&chain),
+    Err(Error::NameConstraintViolation(0))
+  ));
+}
+
+#[test]
+fn extended_key_usage_must_be_accepted() {
+  let chain = CertificateChain::new(vec![MDL_DS_LEAF.to_vec(), INTERMEDIATE.to_vec()]).unwrap();
+  assert!(matches!(
+    validator().validate(&chain),
+    Err(Error::InvalidExtendedKeyUsage(0))
+  ));
+  assert!(validator().with_extended_key_usage(OID_MDL_DS).validate(&chain).is_ok());
+}
+
+#[test]
+fn ed448_and_p521_keys_are_converted() {
+  let ed448_spki = decode_hex(
+    "3043300506032b6571033a0018d0a70e42a742dfb561279893385061d7b4dad8f6feed4791eaab66b2f4a4f02fc09462a8bfb1842d0bac60e8a1b3e55ba2407f33226f3800",
+  );
+  let jwk = spki_to_jwk(&SubjectPublicKeyInfo::from_der(&ed448_spki).unwrap().1).unwrap();
+  let JwkParams::Okp(params) = jwk.params() else {
+    panic!("expected an OKP key");
+  };
+  assert_eq!(params.crv, "Ed448");
+  assert_eq!(
+    params.x,
+    "GNCnDkKnQt-1YSeYkzhQYde02tj2_u1HkeqrZrL0pPAvwJRiqL-xhC0LrGDoobPlW6JAfzMibzgA"
+  );
+
+  let p521_spki = decode_hex(
+    "30819b301006072a8648ce3d020106052b81040023038186000400076ca1fa9613a70e8b2cecd8f64877968e32b91b16cd65166ca44b1439883a919e263c09cff2f7f39e770ebda9057d2f1198804a1c160f0bb99eb9ae6fab28f78b00b3121fe54f8e83d9bdea643e8f7a01fb6e0791d4d459ac5ba1ea434b87d2d6b9d6476a5f521ee0f6b8de3ce4f3474660235f88240ca7d0ddba59a07d493692bd6b",
+  );
+  let jwk = spki_to_jwk(&SubjectPublicKeyInfo::from_der(&p521_spki).unwrap().1).unwrap();
+  let JwkParams::Ec(params) = jwk.params() else {
+    panic!("expected an EC key");
+  };
+  assert_eq!(params.crv, "P-521");
+  assert_eq!(
+    params.x,
+    "AAdsofqWE6cOiyzs2PZId5aOMrkbFs1lFmykSxQ5iDqRniY8Cc_y9_Oedw69qQV9LxGYgEocFg8LuZ65rm-rKPeL"
+  );
+  assert_eq!(
+    params.y,
+    "ALMSH-VPjoPZvepkPo96AftuB5HU1FmsW6HqQ0uH0ta51kdqX1Ie4Pa43jzk80dGYCNfiCQMp9DdulmgfUk2kr1r"
+  );
+}
+
+#[test]
+fn leaf_must_identify_issuer() {
+  let validator = validator();
+  assert!(validator.validate_for_issuer(ISSUER, &chain()).is_ok());
+  assert!(validator
+    .validate_for_issuer("https://issuer.example.com/tenant", &chain())
+    .is_ok());
+  assert!(matches!(
+    validator.validate_for_issuer("https://other.example.com", &chain()),
+    Err(Error::IssuerBindingMismatch(_))
+  ));
+}
+
+#[test]
+fn jws_is_verified_with_x5c_leaf_key() {
+  let chain = chain();
+  let jws = sign_jws(
+    serde_json::json!({
+      "alg": "EdDSA",
+      "x5c": chain.to_x5c(),
+      "x5t#S256": chain.leaf_thumbprint_sha256_b64(),
+    }),
+    b"payload",
+  );
+
+  let decoded = validator().verify_jws(&jws, &EdDSAJwsVerifier::default()).unwrap();
+  assert_eq!(decoded.claims.as_ref(), b"payload");
+}
+
+#[test]
+fn jws_with_mismatching_thumbprint_is_rejected() {
+  let chain = chain();
+  let jws = sign_jws(
+    serde_json::json!({
+      "alg": "EdDSA",
+      "x5c": chain.to_x5c(),
+      "x5t": jwu::encode_b64([0; 20]),
+    }),
+    b"payload",
+  );
+
+  assert!(matches!(
+    validator().verify_jws(&jws, &EdDSAJwsVerifier::default()),
+    Err(Error::ThumbprintMismatch("x5t"))
+  ));
+}
+
+#[cfg(feature = "sd-jwt-vc")]
+#[tokio::test]
+async fn issuer_key_resolver_uses_x509_chain() {
+  use identity_credential::validator::InMemoryFetcher;
+  use identity_credential::validator::JwtIssuerKeyResolver;
+  use identity_verification::jose::jws::JwsHeader;
+
+  let resolver = JwtIssuerKeyResolver::new(InMemoryFetcher::new()).with_x5c_validator(validator());
+  let mut header = JwsHeader::new();
+  header.set_x5c(chain().to_x5c());
+
+  let jwk = resolver.resolve(ISSUER, &header).await.unwrap();
+  assert_eq!(jwk, validator().validate(&chain()).unwrap());
+  assert!(resolver.resolve("https://other.example.com", &header).await.is_err());
+}